@@ -66,3 +66,9 @@ pub mod codegen {
     #[doc(inline)]
     pub use oxc_codegen::*;
 }
+
+#[cfg(feature = "isolated_declarations")]
+pub mod isolated_declarations {
+    #[doc(inline)]
+    pub use oxc_isolated_declarations::*;
+}
@@ -0,0 +1,103 @@
+use std::cell::Cell;
+
+use crate::{Allocator, Box, Vec};
+
+/// Clone a value into an [Allocator].
+///
+/// Arena allocated values can't implement [Clone], their references are tied to the allocator
+/// they live in. `clone_in` deep clones a value into the `'new_alloc` allocator, which can be
+/// the allocator of the value itself or another one.
+///
+/// Derive it for AST nodes with `#[derive(CloneIn)]` from `oxc_macros`.
+pub trait CloneIn<'new_alloc>: Sized {
+    type Cloned;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned;
+}
+
+impl<'alloc, T: CloneIn<'alloc>> CloneIn<'alloc> for Option<T> {
+    type Cloned = Option<T::Cloned>;
+
+    fn clone_in(&self, allocator: &'alloc Allocator) -> Self::Cloned {
+        self.as_ref().map(|value| value.clone_in(allocator))
+    }
+}
+
+impl<'old_alloc, 'new_alloc, T> CloneIn<'new_alloc> for Box<'old_alloc, T>
+where
+    T: CloneIn<'new_alloc>,
+    T::Cloned: 'new_alloc,
+{
+    type Cloned = Box<'new_alloc, T::Cloned>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        Box(allocator.alloc(self.0.clone_in(allocator)))
+    }
+}
+
+impl<'old_alloc, 'new_alloc, T> CloneIn<'new_alloc> for Vec<'old_alloc, T>
+where
+    T: CloneIn<'new_alloc>,
+    T::Cloned: 'new_alloc,
+{
+    type Cloned = Vec<'new_alloc, T::Cloned>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        Vec::from_iter_in(self.iter().map(|value| value.clone_in(allocator)), allocator)
+    }
+}
+
+impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for &'old_alloc str {
+    type Cloned = &'new_alloc str;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        allocator.alloc_str(self)
+    }
+}
+
+/// The cells of the AST hold the symbol and reference ids assigned by semantic analysis, which
+/// don't belong to a clone. A cloned cell is empty.
+impl<'alloc, T: Copy> CloneIn<'alloc> for Cell<Option<T>> {
+    type Cloned = Self;
+
+    fn clone_in(&self, _: &'alloc Allocator) -> Self::Cloned {
+        Self::new(None)
+    }
+}
+
+macro_rules! impl_clone_in_for_copy {
+    ($($ty:ty),*) => {
+        $(
+            impl<'alloc> CloneIn<'alloc> for $ty {
+                type Cloned = Self;
+
+                #[inline]
+                fn clone_in(&self, _: &'alloc Allocator) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_in_for_copy!(bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, char);
+
+#[cfg(test)]
+mod test {
+    use super::CloneIn;
+    use crate::{Allocator, Box, Vec};
+
+    #[test]
+    fn clone_into_another_allocator() {
+        let allocator = Allocator::default();
+        let vec = Vec::from_iter_in([Some(Box(allocator.alloc("a"))), None], &allocator);
+
+        let other = Allocator::default();
+        let cloned = vec.clone_in(&other);
+        drop(vec);
+        drop(allocator);
+        assert_eq!(cloned.len(), 2);
+        assert_eq!(cloned[0].as_deref().copied(), Some("a"));
+        assert!(cloned[1].is_none());
+    }
+}
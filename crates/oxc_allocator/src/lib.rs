@@ -1,9 +1,11 @@
 use std::ops::Deref;
 
 mod arena;
+mod clone_in;
 
 pub use arena::{Box, String, Vec};
 use bumpalo::Bump;
+pub use clone_in::CloneIn;
 
 #[derive(Default)]
 pub struct Allocator {
@@ -0,0 +1,139 @@
+//! [Flow](https://flow.org/en/docs/types/)
+//!
+//! Flow annotations with the same syntax as TypeScript, e.g. `x: string` or `type A = B`,
+//! are parsed into the TypeScript nodes.
+
+use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ShiftSpan};
+use oxc_span::Span;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::ast::{
+    BindingIdentifier, Expression, Modifiers, TSSignature, TSType, TSTypeAnnotation,
+    TSTypeParameterDeclaration,
+};
+
+/// `(expression: T)`
+///
+/// <https://flow.org/en/docs/types/casting/>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowTypeCastExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub expression: Expression<'a>,
+    pub type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+}
+
+/// `?T`
+///
+/// <https://flow.org/en/docs/types/maybe/>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowNullableType<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub type_annotation: TSType<'a>,
+}
+
+/// `{| a: A |}`
+///
+/// <https://flow.org/en/docs/types/objects/#exact-and-inexact-object-types>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowExactObjectType<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub members: Vec<'a, TSSignature<'a>>,
+}
+
+/// `{ a: A, ... }`, an object type which is explicitly inexact
+///
+/// <https://flow.org/en/docs/types/objects/#exact-and-inexact-object-types>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowInexactObjectType<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub members: Vec<'a, TSSignature<'a>>,
+}
+
+/// `...A` in an object type
+///
+/// <https://flow.org/en/docs/types/objects/#object-type-spread>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowObjectTypeSpread<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub argument: TSType<'a>,
+}
+
+/// `*`
+///
+/// <https://flow.org/en/docs/types/utilities/#toc-existential-type>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowExistsType {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+}
+
+/// `+` (read-only) or `-` (write-only) before a property, e.g. `{ +a: A }`
+///
+/// <https://flow.org/en/docs/lang/variance/>
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowVariance {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub kind: FlowVarianceKind,
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
+pub enum FlowVarianceKind {
+    Plus,
+    Minus,
+}
+
+/// `%checks` or `%checks(expression)` after the return type of a function
+///
+/// <https://flow.org/en/docs/types/functions/#predicate-functions>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowPredicate<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// `None` for `%checks`, which is inferred from the function body
+    pub value: Option<Expression<'a>>,
+}
+
+/// `declare module.exports: T`
+///
+/// <https://flow.org/en/docs/libdefs/creation/#toc-declaring-a-commonjs-module>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowDeclareModuleExports<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+}
+
+/// `opaque type A: Super = T`
+///
+/// <https://flow.org/en/docs/types/opaque-types/>
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowOpaqueTypeDeclaration<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub id: BindingIdentifier,
+    pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    pub supertype: Option<TSType<'a>>,
+    /// `None` for `declare opaque type A;`
+    pub type_annotation: Option<TSType<'a>>,
+    /// Valid Modifiers: `declare`, `export`
+    pub modifiers: Modifiers<'a>,
+}
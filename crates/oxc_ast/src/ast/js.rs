@@ -1,12 +1,14 @@
 use std::{cell::Cell, fmt, hash::Hash};
 
 use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ShiftSpan};
 use oxc_span::{Atom, SourceType, Span};
 use oxc_syntax::{
     operator::{
         AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
     },
     reference::{ReferenceFlag, ReferenceId},
+    scope::ScopeId,
     symbol::SymbolId,
 };
 #[cfg(feature = "serde")]
@@ -15,7 +17,7 @@ use serde::Serialize;
 #[allow(clippy::wildcard_imports)]
 use crate::ast::*;
 
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct Program<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -24,6 +26,19 @@ pub struct Program<'a> {
     pub directives: Vec<'a, Directive>,
     pub hashbang: Option<Hashbang>,
     pub body: Vec<'a, Statement<'a>>,
+    /// The root scope, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for Program<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.source_type.hash(state);
+        self.directives.hash(state);
+        self.hashbang.hash(state);
+        self.body.hash(state);
+    }
 }
 
 impl<'a> Program<'a> {
@@ -39,7 +54,7 @@ impl<'a> Program<'a> {
 }
 
 /// Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Expression<'a> {
     BooleanLiteral(Box<'a, BooleanLiteral>),
@@ -87,6 +102,8 @@ pub enum Expression<'a> {
     TSTypeAssertion(Box<'a, TSTypeAssertion<'a>>),
     TSNonNullExpression(Box<'a, TSNonNullExpression<'a>>),
     TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>),
+
+    FlowTypeCastExpression(Box<'a, FlowTypeCastExpression<'a>>),
 }
 
 impl<'a> Expression<'a> {
@@ -201,6 +218,7 @@ impl<'a> Expression<'a> {
             Expression::TSInstantiationExpression(expr) => expr.expression.get_inner_expression(),
             Expression::TSNonNullExpression(expr) => expr.expression.get_inner_expression(),
             Expression::TSTypeAssertion(expr) => expr.expression.get_inner_expression(),
+            Expression::FlowTypeCastExpression(expr) => expr.expression.get_inner_expression(),
             _ => self,
         }
     }
@@ -266,7 +284,7 @@ impl<'a> Expression<'a> {
 }
 
 /// Identifier Name
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IdentifierName {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -281,7 +299,7 @@ impl IdentifierName {
 }
 
 /// Identifier Reference
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IdentifierReference {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -307,7 +325,7 @@ impl IdentifierReference {
 }
 
 /// Binding Identifier
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BindingIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -331,7 +349,7 @@ impl BindingIdentifier {
 }
 
 /// Label Identifier
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LabelIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -340,7 +358,7 @@ pub struct LabelIdentifier {
 }
 
 /// This Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ThisExpression {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -348,7 +366,7 @@ pub struct ThisExpression {
 }
 
 /// Array Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -358,7 +376,7 @@ pub struct ArrayExpression<'a> {
 }
 
 /// Array Expression Element
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ArrayExpressionElement<'a> {
     SpreadElement(Box<'a, SpreadElement<'a>>),
@@ -367,7 +385,7 @@ pub enum ArrayExpressionElement<'a> {
 }
 
 /// Object Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -383,14 +401,14 @@ impl<'a> ObjectExpression<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ObjectPropertyKind<'a> {
     ObjectProperty(Box<'a, ObjectProperty<'a>>),
     SpreadProperty(Box<'a, SpreadElement<'a>>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectProperty<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -404,7 +422,7 @@ pub struct ObjectProperty<'a> {
     pub computed: bool,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum PropertyKey<'a> {
     Identifier(Box<'a, IdentifierName>),
@@ -462,7 +480,7 @@ impl<'a> PropertyKey<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum PropertyKind {
     Init,
@@ -471,7 +489,7 @@ pub enum PropertyKind {
 }
 
 /// Template Literal
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TemplateLiteral<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -491,7 +509,7 @@ impl<'a> TemplateLiteral<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TaggedTemplateExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -501,7 +519,7 @@ pub struct TaggedTemplateExpression<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TemplateElement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -512,7 +530,7 @@ pub struct TemplateElement {
 
 /// See [template-strings-cooked-vs-raw](https://exploringjs.com/impatient-js/ch_template-literals.html#template-strings-cooked-vs-raw)
 /// for more info
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TemplateElementValue {
     /// A raw interpretation where backslashes do not have special meaning.
@@ -527,7 +545,7 @@ pub struct TemplateElementValue {
 }
 
 /// Member Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum MemberExpression<'a> {
     ComputedMemberExpression(ComputedMemberExpression<'a>),
@@ -556,6 +574,14 @@ impl<'a> MemberExpression<'a> {
         }
     }
 
+    pub fn object_mut(&mut self) -> &mut Expression<'a> {
+        match self {
+            MemberExpression::ComputedMemberExpression(expr) => &mut expr.object,
+            MemberExpression::StaticMemberExpression(expr) => &mut expr.object,
+            MemberExpression::PrivateFieldExpression(expr) => &mut expr.object,
+        }
+    }
+
     pub fn static_property_name(&self) -> Option<&str> {
         match self {
             MemberExpression::ComputedMemberExpression(expr) => match &expr.expression {
@@ -601,7 +627,7 @@ impl<'a> MemberExpression<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ComputedMemberExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -611,7 +637,7 @@ pub struct ComputedMemberExpression<'a> {
     pub optional: bool, // for optional chaining
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct StaticMemberExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -621,7 +647,7 @@ pub struct StaticMemberExpression<'a> {
     pub optional: bool, // for optional chaining
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct PrivateFieldExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -632,7 +658,7 @@ pub struct PrivateFieldExpression<'a> {
 }
 
 /// Call Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct CallExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -685,7 +711,7 @@ impl<'a> CallExpression<'a> {
 }
 
 /// New Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct NewExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -696,7 +722,7 @@ pub struct NewExpression<'a> {
 }
 
 /// Meta Property `new.target` | `import.meta`
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct MetaProperty {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -706,7 +732,7 @@ pub struct MetaProperty {
 }
 
 /// Spread Element
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SpreadElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -715,7 +741,7 @@ pub struct SpreadElement<'a> {
 }
 
 /// Argument
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Argument<'a> {
     SpreadElement(Box<'a, SpreadElement<'a>>),
@@ -729,7 +755,7 @@ impl Argument<'_> {
 }
 
 /// Update Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct UpdateExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -740,7 +766,7 @@ pub struct UpdateExpression<'a> {
 }
 
 /// Unary Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct UnaryExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -750,7 +776,7 @@ pub struct UnaryExpression<'a> {
 }
 
 /// Binary Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BinaryExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -761,7 +787,7 @@ pub struct BinaryExpression<'a> {
 }
 
 /// Private Identifier in Shift Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct PrivateInExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -772,7 +798,7 @@ pub struct PrivateInExpression<'a> {
 }
 
 /// Binary Logical Operators
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LogicalExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -783,7 +809,7 @@ pub struct LogicalExpression<'a> {
 }
 
 /// Conditional Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ConditionalExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -794,7 +820,7 @@ pub struct ConditionalExpression<'a> {
 }
 
 /// Assignment Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -805,7 +831,7 @@ pub struct AssignmentExpression<'a> {
 }
 
 /// Destructuring Assignment
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTarget<'a> {
     SimpleAssignmentTarget(SimpleAssignmentTarget<'a>),
@@ -829,7 +855,7 @@ impl<'a> AssignmentTarget<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum SimpleAssignmentTarget<'a> {
     AssignmentTargetIdentifier(Box<'a, IdentifierReference>),
@@ -852,14 +878,14 @@ impl<'a> SimpleAssignmentTarget<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetPattern<'a> {
     ArrayAssignmentTarget(Box<'a, ArrayAssignmentTarget<'a>>),
     ObjectAssignmentTarget(Box<'a, ObjectAssignmentTarget<'a>>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayAssignmentTarget<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -869,7 +895,7 @@ pub struct ArrayAssignmentTarget<'a> {
     pub trailing_comma: Option<Span>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectAssignmentTarget<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -878,7 +904,7 @@ pub struct ObjectAssignmentTarget<'a> {
     pub rest: Option<AssignmentTarget<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetMaybeDefault<'a> {
     AssignmentTarget(AssignmentTarget<'a>),
@@ -903,7 +929,7 @@ impl<'a> AssignmentTargetMaybeDefault<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetWithDefault<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -912,7 +938,7 @@ pub struct AssignmentTargetWithDefault<'a> {
     pub init: Expression<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetProperty<'a> {
     AssignmentTargetPropertyIdentifier(Box<'a, AssignmentTargetPropertyIdentifier<'a>>),
@@ -920,7 +946,7 @@ pub enum AssignmentTargetProperty<'a> {
 }
 
 /// Assignment Property - Identifier Reference
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetPropertyIdentifier<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -930,7 +956,7 @@ pub struct AssignmentTargetPropertyIdentifier<'a> {
 }
 
 /// Assignment Property - Property Name
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetPropertyProperty<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -940,7 +966,7 @@ pub struct AssignmentTargetPropertyProperty<'a> {
 }
 
 /// Sequence Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SequenceExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -948,7 +974,7 @@ pub struct SequenceExpression<'a> {
     pub expressions: Vec<'a, Expression<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct Super {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -956,7 +982,7 @@ pub struct Super {
 }
 
 /// Await Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AwaitExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -964,7 +990,7 @@ pub struct AwaitExpression<'a> {
     pub argument: Expression<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ChainExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -972,7 +998,7 @@ pub struct ChainExpression<'a> {
     pub expression: ChainElement<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ChainElement<'a> {
     CallExpression(Box<'a, CallExpression<'a>>),
@@ -980,7 +1006,7 @@ pub enum ChainElement<'a> {
 }
 
 /// Parenthesized Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ParenthesizedExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -989,7 +1015,7 @@ pub struct ParenthesizedExpression<'a> {
 }
 
 /// Statements
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Statement<'a> {
     // Statements
@@ -1017,7 +1043,7 @@ pub enum Statement<'a> {
 }
 
 /// Directive Prologue
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct Directive {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1030,7 +1056,7 @@ pub struct Directive {
 }
 
 /// Hashbang
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct Hashbang {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1039,16 +1065,26 @@ pub struct Hashbang {
 }
 
 /// Block Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BlockStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub body: Vec<'a, Statement<'a>>,
+    /// The scope of the block, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for BlockStatement<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.body.hash(state);
+    }
 }
 
 /// Declarations and the Variable Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Declaration<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -1061,6 +1097,9 @@ pub enum Declaration<'a> {
     TSEnumDeclaration(Box<'a, TSEnumDeclaration<'a>>),
     TSModuleDeclaration(Box<'a, TSModuleDeclaration<'a>>),
     TSImportEqualsDeclaration(Box<'a, TSImportEqualsDeclaration<'a>>),
+
+    FlowOpaqueTypeDeclaration(Box<'a, FlowOpaqueTypeDeclaration<'a>>),
+    FlowDeclareModuleExports(Box<'a, FlowDeclareModuleExports<'a>>),
 }
 
 impl<'a> Declaration<'a> {
@@ -1075,7 +1114,7 @@ impl<'a> Declaration<'a> {
 }
 
 /// Variable Declaration
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct VariableDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1092,7 +1131,7 @@ impl<'a> VariableDeclaration<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum VariableDeclarationKind {
     Var,
@@ -1129,7 +1168,7 @@ impl fmt::Display for VariableDeclarationKind {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct VariableDeclarator<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1143,7 +1182,7 @@ pub struct VariableDeclarator<'a> {
 
 /// Using Declaration
 /// <https://github.com/tc39/proposal-explicit-resource-management>
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct UsingDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1154,7 +1193,7 @@ pub struct UsingDeclaration<'a> {
 }
 
 /// Empty Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct EmptyStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1162,7 +1201,7 @@ pub struct EmptyStatement {
 }
 
 /// Expression Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExpressionStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1171,7 +1210,7 @@ pub struct ExpressionStatement<'a> {
 }
 
 /// If Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IfStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1182,7 +1221,7 @@ pub struct IfStatement<'a> {
 }
 
 /// Do-While Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct DoWhileStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1192,7 +1231,7 @@ pub struct DoWhileStatement<'a> {
 }
 
 /// While Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct WhileStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1202,7 +1241,7 @@ pub struct WhileStatement<'a> {
 }
 
 /// For Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1211,9 +1250,22 @@ pub struct ForStatement<'a> {
     pub test: Option<Expression<'a>>,
     pub update: Option<Expression<'a>>,
     pub body: Statement<'a>,
+    /// The scope of a lexical declaration in `init`, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for ForStatement<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.init.hash(state);
+        self.test.hash(state);
+        self.update.hash(state);
+        self.body.hash(state);
+    }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ForStatementInit<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -1230,7 +1282,7 @@ impl<'a> ForStatementInit<'a> {
 }
 
 /// For-In Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForInStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1238,10 +1290,22 @@ pub struct ForInStatement<'a> {
     pub left: ForStatementLeft<'a>,
     pub right: Expression<'a>,
     pub body: Statement<'a>,
+    /// The scope of a lexical declaration in `left`, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for ForInStatement<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.left.hash(state);
+        self.right.hash(state);
+        self.body.hash(state);
+    }
 }
 
 /// For-Of Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForOfStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1250,9 +1314,22 @@ pub struct ForOfStatement<'a> {
     pub left: ForStatementLeft<'a>,
     pub right: Expression<'a>,
     pub body: Statement<'a>,
+    /// The scope of a lexical declaration in `left`, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for ForOfStatement<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.r#await.hash(state);
+        self.left.hash(state);
+        self.right.hash(state);
+        self.body.hash(state);
+    }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ForStatementLeft<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -1269,7 +1346,7 @@ impl<'a> ForStatementLeft<'a> {
 }
 
 /// Continue Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ContinueStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1278,7 +1355,7 @@ pub struct ContinueStatement {
 }
 
 /// Break Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BreakStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1287,7 +1364,7 @@ pub struct BreakStatement {
 }
 
 /// Return Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ReturnStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1296,7 +1373,7 @@ pub struct ReturnStatement<'a> {
 }
 
 /// With Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct WithStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1306,16 +1383,27 @@ pub struct WithStatement<'a> {
 }
 
 /// Switch Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SwitchStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub discriminant: Expression<'a>,
     pub cases: Vec<'a, SwitchCase<'a>>,
+    /// The scope of the cases, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for SwitchStatement<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.discriminant.hash(state);
+        self.cases.hash(state);
+    }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SwitchCase<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1331,7 +1419,7 @@ impl<'a> SwitchCase<'a> {
 }
 
 /// Labelled Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LabeledStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1341,7 +1429,7 @@ pub struct LabeledStatement<'a> {
 }
 
 /// Throw Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ThrowStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1350,7 +1438,7 @@ pub struct ThrowStatement<'a> {
 }
 
 /// Try Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TryStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1360,17 +1448,28 @@ pub struct TryStatement<'a> {
     pub finalizer: Option<Box<'a, BlockStatement<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct CatchClause<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub param: Option<BindingPattern<'a>>,
     pub body: Box<'a, BlockStatement<'a>>,
+    /// The scope of the parameter and the body, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for CatchClause<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.param.hash(state);
+        self.body.hash(state);
+    }
 }
 
 /// Debugger Statement
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct DebuggerStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1378,7 +1477,7 @@ pub struct DebuggerStatement {
 }
 
 /// Destructuring Binding Patterns
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct BindingPattern<'a> {
     pub kind: BindingPatternKind<'a>,
@@ -1386,7 +1485,7 @@ pub struct BindingPattern<'a> {
     pub optional: bool,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum BindingPatternKind<'a> {
     /// `const a = 1`
@@ -1412,7 +1511,7 @@ impl<'a> BindingPatternKind<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1421,7 +1520,7 @@ pub struct AssignmentPattern<'a> {
     pub right: Expression<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1436,7 +1535,7 @@ impl<'a> ObjectPattern<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BindingProperty<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1447,7 +1546,7 @@ pub struct BindingProperty<'a> {
     pub computed: bool,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1462,7 +1561,7 @@ impl<'a> ArrayPattern<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct RestElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1471,7 +1570,7 @@ pub struct RestElement<'a> {
 }
 
 /// Function Definitions
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub struct Function<'a> {
     pub r#type: FunctionType,
@@ -1485,8 +1584,30 @@ pub struct Function<'a> {
     pub body: Option<Box<'a, FunctionBody<'a>>>,
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
     pub return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+    /// Flow `%checks`
+    pub predicate: Option<Box<'a, FlowPredicate<'a>>>,
     /// Valid modifiers: `export`, `default`, `async`
     pub modifiers: Modifiers<'a>,
+    /// The scope of the function, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for Function<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.r#type.hash(state);
+        self.span.hash(state);
+        self.id.hash(state);
+        self.expression.hash(state);
+        self.generator.hash(state);
+        self.r#async.hash(state);
+        self.params.hash(state);
+        self.body.hash(state);
+        self.type_parameters.hash(state);
+        self.return_type.hash(state);
+        self.predicate.hash(state);
+        self.modifiers.hash(state);
+    }
 }
 
 impl<'a> Function<'a> {
@@ -1519,7 +1640,7 @@ impl<'a> Function<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum FunctionType {
     FunctionDeclaration,
@@ -1527,7 +1648,7 @@ pub enum FunctionType {
     TSDeclareFunction,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct FormalParameters<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1547,7 +1668,7 @@ impl<'a> FormalParameters<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct FormalParameter<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1558,7 +1679,7 @@ pub struct FormalParameter<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum FormalParameterKind {
     /// <https://tc39.es/ecma262/#prod-FormalParameters>
@@ -1577,13 +1698,24 @@ impl<'a> FormalParameters<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct FunctionBody<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub directives: Vec<'a, Directive>,
     pub statements: Vec<'a, Statement<'a>>,
+    /// The scope of the function, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for FunctionBody<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.directives.hash(state);
+        self.statements.hash(state);
+    }
 }
 
 impl<'a> FunctionBody<'a> {
@@ -1593,7 +1725,7 @@ impl<'a> FunctionBody<'a> {
 }
 
 /// Arrow Function Definitions
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct ArrowExpression<'a> {
     pub span: Span,
@@ -1607,10 +1739,29 @@ pub struct ArrowExpression<'a> {
 
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
     pub return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+    /// Flow `%checks`
+    pub predicate: Option<Box<'a, FlowPredicate<'a>>>,
+    /// The scope of the function, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for ArrowExpression<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.expression.hash(state);
+        self.generator.hash(state);
+        self.r#async.hash(state);
+        self.params.hash(state);
+        self.body.hash(state);
+        self.type_parameters.hash(state);
+        self.return_type.hash(state);
+        self.predicate.hash(state);
+    }
 }
 
 /// Generator Function Definitions
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct YieldExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1620,7 +1771,7 @@ pub struct YieldExpression<'a> {
 }
 
 /// Class Definitions
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub struct Class<'a> {
     pub r#type: ClassType,
@@ -1635,6 +1786,24 @@ pub struct Class<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
     /// Valid Modifiers: `export`, `abstract`
     pub modifiers: Modifiers<'a>,
+    /// The scope of a class expression, set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for Class<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.r#type.hash(state);
+        self.span.hash(state);
+        self.id.hash(state);
+        self.super_class.hash(state);
+        self.body.hash(state);
+        self.type_parameters.hash(state);
+        self.super_type_parameters.hash(state);
+        self.implements.hash(state);
+        self.decorators.hash(state);
+        self.modifiers.hash(state);
+    }
 }
 
 impl<'a> Class<'a> {
@@ -1655,14 +1824,14 @@ impl<'a> Class<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum ClassType {
     ClassDeclaration,
     ClassExpression,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ClassBody<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1670,7 +1839,7 @@ pub struct ClassBody<'a> {
     pub body: Vec<'a, ClassElement<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ClassElement<'a> {
     StaticBlock(Box<'a, StaticBlock<'a>>),
@@ -1763,7 +1932,7 @@ impl<'a> ClassElement<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct MethodDefinition<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1779,7 +1948,7 @@ pub struct MethodDefinition<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct PropertyDefinition<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1793,12 +1962,14 @@ pub struct PropertyDefinition<'a> {
     pub optional: bool,
     pub definite: bool,
     pub readonly: bool,
+    /// Flow `+a` or `-a`
+    pub variance: Option<FlowVariance>,
     pub type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
     pub accessibility: Option<TSAccessibility>,
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum MethodDefinitionKind {
     Constructor,
@@ -1807,7 +1978,7 @@ pub enum MethodDefinitionKind {
     Set,
 }
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct PrivateIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1815,15 +1986,25 @@ pub struct PrivateIdentifier {
     pub name: Atom,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct StaticBlock<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub body: Vec<'a, Statement<'a>>,
+    /// Set by the semantic analysis
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scope_id: Cell<Option<ScopeId>>,
+}
+
+impl<'a> Hash for StaticBlock<'a> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.span.hash(state);
+        self.body.hash(state);
+    }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ModuleDeclaration<'a> {
     /// import hello from './world.js';
@@ -1859,7 +2040,7 @@ impl<'a> ModuleDeclaration<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AccessorProperty<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1868,9 +2049,10 @@ pub struct AccessorProperty<'a> {
     pub value: Option<Expression<'a>>,
     pub computed: bool,
     pub r#static: bool,
+    pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1879,7 +2061,7 @@ pub struct ImportExpression<'a> {
     pub arguments: Vec<'a, Expression<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1891,7 +2073,7 @@ pub struct ImportDeclaration<'a> {
     pub import_kind: ImportOrExportKind,              // `import type { foo } from 'bar'`
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ImportDeclarationSpecifier {
     /// import {imported} from "source"
@@ -1905,7 +2087,7 @@ pub enum ImportDeclarationSpecifier {
 
 // import {imported} from "source"
 // import {imported as local} from "source"
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1916,7 +2098,7 @@ pub struct ImportSpecifier {
 }
 
 // import local from "source"
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportDefaultSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1925,7 +2107,7 @@ pub struct ImportDefaultSpecifier {
 }
 
 // import * as local from "source"
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportNamespaceSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1933,7 +2115,7 @@ pub struct ImportNamespaceSpecifier {
     pub local: BindingIdentifier,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportAttribute {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1942,7 +2124,7 @@ pub struct ImportAttribute {
     pub value: StringLiteral,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ImportAttributeKey {
     Identifier(IdentifierName),
@@ -1958,7 +2140,7 @@ impl ImportAttributeKey {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportNamedDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1971,7 +2153,7 @@ pub struct ExportNamedDeclaration<'a> {
 
 impl<'a> ExportNamedDeclaration<'a> {
     pub fn is_typescript_syntax(&self) -> bool {
-        self.export_kind == ImportOrExportKind::Type
+        self.export_kind.is_type()
             || self.declaration.as_ref().map_or(false, Declaration::is_typescript_syntax)
     }
 }
@@ -1980,7 +2162,7 @@ impl<'a> ExportNamedDeclaration<'a> {
 /// export default HoistableDeclaration
 /// export default ClassDeclaration
 /// export default AssignmentExpression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportDefaultDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1995,7 +2177,7 @@ impl<'a> ExportDefaultDeclaration<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportAllDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -2012,7 +2194,7 @@ impl<'a> ExportAllDeclaration<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -2022,7 +2204,7 @@ pub struct ExportSpecifier {
     pub export_kind: ImportOrExportKind, // `export type *`
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ExportDefaultDeclarationKind<'a> {
     Expression(Expression<'a>),
@@ -2059,7 +2241,7 @@ impl<'a> ExportDefaultDeclarationKind<'a> {
 // support:
 //   import {"\0 any unicode" as foo} from "";
 //   export {foo as "\0 any unicode"};
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ModuleExportName {
     Identifier(IdentifierName),
@@ -1,12 +1,13 @@
 //! [`JSDoc`](https://github.com/microsoft/TypeScript/blob/54a554d8af2657630307cbfa8a3e4f3946e36507/src/compiler/types.ts#L393)
 
+use oxc_macros::{CloneIn, ContentEq, ShiftSpan};
 use oxc_span::Span;
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
 use crate::ast::TSType;
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSDocNullableType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -15,7 +16,7 @@ pub struct JSDocNullableType<'a> {
     pub postfix: bool,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSDocUnknownType {
     #[cfg_attr(feature = "serde", serde(flatten))]
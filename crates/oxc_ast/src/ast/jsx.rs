@@ -1,6 +1,7 @@
 //! [JSX](https://facebook.github.io/jsx)
 
 use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ShiftSpan};
 use oxc_span::{Atom, Span};
 #[cfg(feature = "serde")]
 use serde::Serialize;
@@ -11,7 +12,7 @@ use crate::ast::*;
 // 1.2 JSX Elements
 
 /// JSX Element
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -22,7 +23,7 @@ pub struct JSXElement<'a> {
 }
 
 /// JSX Opening Element
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXOpeningElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -34,7 +35,7 @@ pub struct JSXOpeningElement<'a> {
 }
 
 /// JSX Closing Element
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXClosingElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -43,7 +44,7 @@ pub struct JSXClosingElement<'a> {
 }
 
 /// JSX Fragment
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXFragment<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -53,14 +54,14 @@ pub struct JSXFragment<'a> {
     pub children: Vec<'a, JSXChild<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXOpeningFragment {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXClosingFragment {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -68,7 +69,7 @@ pub struct JSXClosingFragment {
 }
 
 /// JSX Element Name
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXElementName<'a> {
     /// `<Apple />`
@@ -80,7 +81,7 @@ pub enum JSXElementName<'a> {
 }
 
 /// JSX Namespaced Name
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXNamespacedName {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -96,7 +97,7 @@ impl std::fmt::Display for JSXNamespacedName {
 }
 
 /// JSX Member Expression
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXMemberExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -114,14 +115,14 @@ impl<'a> JSXMemberExpression<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXMemberExpressionObject<'a> {
     Identifier(JSXIdentifier),
     MemberExpression(Box<'a, JSXMemberExpression<'a>>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXExpressionContainer<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -129,14 +130,14 @@ pub struct JSXExpressionContainer<'a> {
     pub expression: JSXExpression<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXExpression<'a> {
     Expression(Expression<'a>),
     EmptyExpression(JSXEmptyExpression),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXEmptyExpression {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -146,7 +147,7 @@ pub struct JSXEmptyExpression {
 // 1.3 JSX Attributes
 
 /// JSX Attributes
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXAttributeItem<'a> {
     Attribute(Box<'a, JSXAttribute<'a>>),
@@ -154,7 +155,7 @@ pub enum JSXAttributeItem<'a> {
 }
 
 /// JSX Attribute
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXAttribute<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -170,7 +171,7 @@ impl<'a> JSXAttribute<'a> {
 }
 
 /// JSX Spread Attribute
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXSpreadAttribute<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -179,7 +180,7 @@ pub struct JSXSpreadAttribute<'a> {
 }
 
 /// JSX Attribute Name
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXAttributeName<'a> {
     Identifier(JSXIdentifier),
@@ -187,7 +188,7 @@ pub enum JSXAttributeName<'a> {
 }
 
 /// JSX Attribute Value
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXAttributeValue<'a> {
     StringLiteral(StringLiteral),
@@ -196,7 +197,7 @@ pub enum JSXAttributeValue<'a> {
     Fragment(Box<'a, JSXFragment<'a>>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -207,7 +208,7 @@ pub struct JSXIdentifier {
 // 1.4 JSX Children
 
 /// JSX Child
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXChild<'a> {
     Text(JSXText),
@@ -217,7 +218,7 @@ pub enum JSXChild<'a> {
     Spread(JSXSpreadChild<'a>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXSpreadChild<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -225,7 +226,7 @@ pub struct JSXSpreadChild<'a> {
     pub expression: Expression<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXText {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -7,12 +7,14 @@ use std::{
 
 use bitflags::bitflags;
 use num_bigint::BigInt;
-use oxc_span::{Atom, Span};
+use oxc_allocator::{Allocator, CloneIn};
+use oxc_macros::{CloneIn, ContentEq, ShiftSpan};
+use oxc_span::{Atom, ContentEq, ShiftSpan, Span};
 use oxc_syntax::NumberBase;
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BooleanLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -34,7 +36,7 @@ impl BooleanLiteral {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct NullLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -53,7 +55,7 @@ impl NullLiteral {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct NumberLiteral<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -113,7 +115,27 @@ pub struct BigintLiteral {
     pub value: BigInt,
 }
 
-#[derive(Debug, Clone, Hash)]
+impl<'alloc> CloneIn<'alloc> for BigintLiteral {
+    type Cloned = Self;
+
+    fn clone_in(&self, _: &'alloc Allocator) -> Self {
+        self.clone()
+    }
+}
+
+impl ContentEq for BigintLiteral {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl ShiftSpan for BigintLiteral {
+    fn shift_span(&mut self, from: u32, to: u32) {
+        self.span.shift_span(from, to);
+    }
+}
+
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct RegExpLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -124,7 +146,7 @@ pub struct RegExpLiteral {
     pub regex: RegExp,
 }
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct RegExp {
     pub pattern: Atom,
@@ -170,6 +192,8 @@ impl TryFrom<char> for RegExpFlags {
     }
 }
 
+oxc_span::impl_ast_leaf_traits!(RegExpFlags);
+
 impl fmt::Display for RegExpFlags {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.contains(Self::G) {
@@ -200,11 +224,11 @@ impl fmt::Display for RegExpFlags {
     }
 }
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct EmptyObject;
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct StringLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
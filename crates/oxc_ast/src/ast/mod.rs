@@ -1,9 +1,10 @@
 //! AST Definitions
 
+mod flow;
 mod js;
 mod jsdoc;
 mod jsx;
 mod literal;
 mod ts;
 
-pub use self::{js::*, jsdoc::*, jsx::*, literal::*, ts::*};
+pub use self::{flow::*, js::*, jsdoc::*, jsx::*, literal::*, ts::*};
@@ -4,6 +4,7 @@
 //! [Archived TypeScript spec](https://github.com/microsoft/TypeScript/blob/3c99d50da5a579d9fa92d02664b1b66d4ff55944/doc/spec-ARCHIVED.md)
 
 use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ShiftSpan};
 use oxc_span::{Atom, Span};
 #[cfg(feature = "serde")]
 use serde::Serialize;
@@ -14,7 +15,7 @@ use crate::ast::*;
 /// Enum Declaration
 ///
 /// `const_opt` enum `BindingIdentifier` { `EnumBody_opt` }
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSEnumDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -27,7 +28,7 @@ pub struct TSEnumDeclaration<'a> {
 /// Enum Body
 ///
 /// A scope must be created on the enum body so this abstraction exists
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSEnumBody<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -35,7 +36,7 @@ pub struct TSEnumBody<'a> {
     pub members: Vec<'a, TSEnumMember<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSEnumMember<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -44,7 +45,7 @@ pub struct TSEnumMember<'a> {
     pub initializer: Option<Expression<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum TSEnumMemberName<'a> {
     Identifier(IdentifierName),
@@ -55,7 +56,7 @@ pub enum TSEnumMemberName<'a> {
     NumberLiteral(NumberLiteral<'a>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeAnnotation<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -63,7 +64,7 @@ pub struct TSTypeAnnotation<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSLiteralType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -71,7 +72,7 @@ pub struct TSLiteralType<'a> {
     pub literal: TSLiteral<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged, rename_all = "camelCase"))]
 pub enum TSLiteral<'a> {
     BooleanLiteral(Box<'a, BooleanLiteral>),
@@ -84,7 +85,7 @@ pub enum TSLiteral<'a> {
     UnaryExpression(Box<'a, UnaryExpression<'a>>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged, rename_all = "camelCase"))]
 pub enum TSType<'a> {
     // Keyword
@@ -124,6 +125,11 @@ pub enum TSType<'a> {
     // JSDoc
     JSDocNullableType(Box<'a, JSDocNullableType<'a>>),
     JSDocUnknownType(Box<'a, JSDocUnknownType>),
+    // Flow
+    FlowNullableType(Box<'a, FlowNullableType<'a>>),
+    FlowExactObjectType(Box<'a, FlowExactObjectType<'a>>),
+    FlowInexactObjectType(Box<'a, FlowInexactObjectType<'a>>),
+    FlowExistsType(Box<'a, FlowExistsType>),
 }
 
 impl<'a> TSType<'a> {
@@ -135,7 +141,7 @@ impl<'a> TSType<'a> {
 /// `SomeType extends OtherType ? TrueType : FalseType;`
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/conditional-types.html#handbook-content>
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSConditionalType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -149,7 +155,7 @@ pub struct TSConditionalType<'a> {
 /// string | string[] | (() => string) | { s: string }
 ///
 /// <https://www.typescriptlang.org/docs/handbook/typescript-in-5-minutes-func.html#unions>
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSUnionType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -160,7 +166,7 @@ pub struct TSUnionType<'a> {
 /// type `ColorfulCircle` = Colorful & Circle;
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#intersection-types>
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSIntersectionType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -171,7 +177,7 @@ pub struct TSIntersectionType<'a> {
 /// keyof unique readonly
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/keyof-types.html>
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "TSTypeOperator"))]
 pub struct TSTypeOperatorType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -180,7 +186,7 @@ pub struct TSTypeOperatorType<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged, rename_all = "lowercase"))]
 pub enum TSTypeOperator {
     Keyof,
@@ -202,7 +208,7 @@ impl TSTypeOperator {
 /// `let myArray: string[] = ["hello", "world"];`
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#the-array-type>
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSArrayType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -213,7 +219,7 @@ pub struct TSArrayType<'a> {
 /// `type I1 = Person["age" | "name"];`
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/indexed-access-types.html#handbook-content>
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSIndexedAccessType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -225,7 +231,7 @@ pub struct TSIndexedAccessType<'a> {
 /// type `StringNumberPair` = [string, number];
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#tuple-types>
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTupleType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -233,7 +239,7 @@ pub struct TSTupleType<'a> {
     pub element_types: Vec<'a, TSTupleElement<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSNamedTupleMember<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -243,7 +249,7 @@ pub struct TSNamedTupleMember<'a> {
     pub optional: bool,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSOptionalType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -251,7 +257,7 @@ pub struct TSOptionalType<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSRestType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -259,7 +265,7 @@ pub struct TSRestType<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged, rename_all = "camelCase"))]
 pub enum TSTupleElement<'a> {
     TSType(TSType<'a>),
@@ -268,91 +274,91 @@ pub enum TSTupleElement<'a> {
     TSNamedTupleMember(Box<'a, TSNamedTupleMember<'a>>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSAnyKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSStringKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSBooleanKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSNumberKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSNeverKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSUnknownKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSNullKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSUndefinedKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSVoidKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSSymbolKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSThisKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSObjectKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TSBigIntKeyword {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -362,7 +368,7 @@ pub struct TSBigIntKeyword {
 /// type C = A;
 /// type D = B.a;
 /// type E = D.c.b.a;
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeReference<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -374,7 +380,7 @@ pub struct TSTypeReference<'a> {
 /// TypeName:
 ///     IdentifierReference
 ///     NamespaceName . IdentifierReference
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum TSTypeName<'a> {
     IdentifierReference(Box<'a, IdentifierReference>),
@@ -407,7 +413,7 @@ impl<'a> TSTypeName<'a> {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSQualifiedName<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -416,7 +422,7 @@ pub struct TSQualifiedName<'a> {
     pub right: IdentifierName,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeParameterInstantiation<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -424,7 +430,7 @@ pub struct TSTypeParameterInstantiation<'a> {
     pub params: Vec<'a, TSType<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeParameter<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -437,7 +443,7 @@ pub struct TSTypeParameter<'a> {
     pub r#const: bool,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeParameterDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -445,7 +451,7 @@ pub struct TSTypeParameterDeclaration<'a> {
     pub params: Vec<'a, Box<'a, TSTypeParameter<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeAliasDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -457,21 +463,21 @@ pub struct TSTypeAliasDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSAbstractMethodDefinition<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub method_definition: MethodDefinition<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSAbstractPropertyDefinition<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub property_definition: PropertyDefinition<'a>,
 }
 
-#[derive(Debug, Hash, Clone, PartialEq, Eq, Copy)]
+#[derive(Debug, Hash, Clone, PartialEq, Eq, Copy, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum TSAccessibility {
     Private,
@@ -479,7 +485,7 @@ pub enum TSAccessibility {
     Public,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSClassImplements<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -491,7 +497,7 @@ pub struct TSClassImplements<'a> {
 /// Interface Declaration
 ///
 ///   interface `BindingIdentifier` `TypeParameters_opt` `InterfaceExtendsClause_opt` `ObjectType`
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInterfaceDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -504,7 +510,7 @@ pub struct TSInterfaceDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInterfaceBody<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -512,7 +518,7 @@ pub struct TSInterfaceBody<'a> {
     pub body: Vec<'a, TSSignature<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSPropertySignature<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -520,11 +526,13 @@ pub struct TSPropertySignature<'a> {
     pub computed: bool,
     pub optional: bool,
     pub readonly: bool,
+    /// Flow `+a` or `-a`
+    pub variance: Option<FlowVariance>,
     pub key: PropertyKey<'a>,
     pub type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged, rename_all = "camelCase"))]
 pub enum TSSignature<'a> {
     TSIndexSignature(Box<'a, TSIndexSignature<'a>>),
@@ -532,9 +540,10 @@ pub enum TSSignature<'a> {
     TSCallSignatureDeclaration(Box<'a, TSCallSignatureDeclaration<'a>>),
     TSConstructSignatureDeclaration(Box<'a, TSConstructSignatureDeclaration<'a>>),
     TSMethodSignature(Box<'a, TSMethodSignature<'a>>),
+    FlowObjectTypeSpread(Box<'a, FlowObjectTypeSpread<'a>>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSIndexSignature<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -543,7 +552,7 @@ pub struct TSIndexSignature<'a> {
     pub type_annotation: Box<'a, TSTypeAnnotation<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSCallSignatureDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -553,7 +562,7 @@ pub struct TSCallSignatureDeclaration<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum TSMethodSignatureKind {
     Method,
@@ -561,7 +570,7 @@ pub enum TSMethodSignatureKind {
     Set,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSMethodSignature<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -575,7 +584,7 @@ pub struct TSMethodSignature<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSConstructSignatureDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -585,7 +594,7 @@ pub struct TSConstructSignatureDeclaration<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSIndexSignatureName<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -594,7 +603,7 @@ pub struct TSIndexSignatureName<'a> {
     pub type_annotation: Box<'a, TSTypeAnnotation<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInterfaceHeritage<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -603,7 +612,7 @@ pub struct TSInterfaceHeritage<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypePredicate<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -613,14 +622,14 @@ pub struct TSTypePredicate<'a> {
     pub type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged, rename_all = "camelCase"))]
 pub enum TSTypePredicateName {
     Identifier(IdentifierName),
     This(TSThisKeyword),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSModuleDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -631,7 +640,7 @@ pub struct TSModuleDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum TSModuleDeclarationName {
     Identifier(IdentifierName),
@@ -647,14 +656,14 @@ impl TSModuleDeclarationName {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum TSModuleDeclarationBody<'a> {
     TSModuleDeclaration(Box<'a, TSModuleDeclaration<'a>>),
     TSModuleBlock(Box<'a, TSModuleBlock<'a>>),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSModuleBlock<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -662,7 +671,7 @@ pub struct TSModuleBlock<'a> {
     pub body: Vec<'a, Statement<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeLiteral<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -670,7 +679,7 @@ pub struct TSTypeLiteral<'a> {
     pub members: Vec<'a, TSSignature<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInferType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -678,7 +687,7 @@ pub struct TSInferType<'a> {
     pub type_parameter: Box<'a, TSTypeParameter<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeQuery<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -687,7 +696,7 @@ pub struct TSTypeQuery<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSImportType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -698,7 +707,7 @@ pub struct TSImportType<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSFunctionType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -708,7 +717,7 @@ pub struct TSFunctionType<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSConstructorType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -719,7 +728,7 @@ pub struct TSConstructorType<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSMappedType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -731,7 +740,7 @@ pub struct TSMappedType<'a> {
     pub readonly: TSMappedTypeModifierOperator,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged, rename_all = "camelCase"))]
 pub enum TSMappedTypeModifierOperator {
     True,
@@ -742,7 +751,7 @@ pub enum TSMappedTypeModifierOperator {
     None,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTemplateLiteralType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -751,7 +760,7 @@ pub struct TSTemplateLiteralType<'a> {
     pub types: Vec<'a, TSType<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSAsExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -760,7 +769,7 @@ pub struct TSAsExpression<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSSatisfiesExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -769,7 +778,7 @@ pub struct TSSatisfiesExpression<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeAssertion<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -778,7 +787,7 @@ pub struct TSTypeAssertion<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSImportEqualsDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -789,14 +798,14 @@ pub struct TSImportEqualsDeclaration<'a> {
     pub import_kind: ImportOrExportKind,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged, rename_all = "camelCase"))]
 pub enum TSModuleReference<'a> {
     TypeName(TSTypeName<'a>),
     ExternalModuleReference(TSExternalModuleReference),
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSExternalModuleReference {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -804,7 +813,7 @@ pub struct TSExternalModuleReference {
     pub expression: StringLiteral,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSNonNullExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -812,7 +821,7 @@ pub struct TSNonNullExpression<'a> {
     pub expression: Expression<'a>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct Decorator<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -820,7 +829,7 @@ pub struct Decorator<'a> {
     pub expression: Expression<'a>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub enum ModifierKind {
     Abstract,
@@ -840,7 +849,7 @@ pub enum ModifierKind {
     Override,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct Modifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -848,7 +857,7 @@ pub struct Modifier {
     pub kind: ModifierKind,
 }
 
-#[derive(Debug, Default, Hash)]
+#[derive(Debug, Default, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(transparent))]
 pub struct Modifiers<'a>(Option<Vec<'a, Modifier>>);
 
@@ -875,7 +884,7 @@ impl<'a> Modifiers<'a> {
 /// Export Assignment in non-module files
 ///
 /// `export = foo`
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSExportAssignment<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -886,7 +895,7 @@ pub struct TSExportAssignment<'a> {
 /// Namespace Export Declaration in declaration files
 ///
 /// `export as namespace foo`
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSNamespaceExportDeclaration {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -894,7 +903,7 @@ pub struct TSNamespaceExportDeclaration {
     pub id: IdentifierName,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInstantiationExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -903,11 +912,13 @@ pub struct TSInstantiationExpression<'a> {
     pub type_parameters: Box<'a, TSTypeParameterInstantiation<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ShiftSpan)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub enum ImportOrExportKind {
     Value,
     Type,
+    /// Flow `import typeof A from 'a'`
+    Typeof,
 }
 
 impl ImportOrExportKind {
@@ -915,7 +926,12 @@ impl ImportOrExportKind {
         matches!(self, Self::Value)
     }
 
+    /// `type` or `typeof`, which are removed from the output
     pub fn is_type(&self) -> bool {
-        matches!(self, Self::Type)
+        matches!(self, Self::Type | Self::Typeof)
+    }
+
+    pub fn is_typeof(&self) -> bool {
+        matches!(self, Self::Typeof)
     }
 }
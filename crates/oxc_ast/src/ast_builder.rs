@@ -6,7 +6,7 @@
 )]
 
 use num_bigint::BigInt;
-use std::mem;
+use std::{cell::Cell, mem};
 
 use oxc_allocator::{Allocator, Box, String, Vec};
 use oxc_span::{Atom, GetSpan, SourceType, Span};
@@ -98,7 +98,7 @@ impl<'a> AstBuilder<'a> {
         hashbang: Option<Hashbang>,
         body: Vec<'a, Statement<'a>>,
     ) -> Program<'a> {
-        Program { span, source_type, directives, hashbang, body }
+        Program { span, source_type, directives, hashbang, body, scope_id: Cell::default() }
     }
 
     /* ---------- Constructors ---------- */
@@ -199,13 +199,11 @@ impl<'a> AstBuilder<'a> {
     }
 
     pub fn block(&self, span: Span, body: Vec<'a, Statement<'a>>) -> Box<'a, BlockStatement<'a>> {
-        self.alloc(BlockStatement { span, body })
+        self.alloc(BlockStatement { span, body, scope_id: Cell::default() })
     }
 
     pub fn block_statement(&self, block: Box<'a, BlockStatement<'a>>) -> Statement<'a> {
-        Statement::BlockStatement(
-            self.alloc(BlockStatement { span: block.span, body: block.unbox().body }),
-        )
+        Statement::BlockStatement(block)
     }
 
     pub fn break_statement(&self, span: Span, label: Option<LabelIdentifier>) -> Statement<'a> {
@@ -257,7 +255,13 @@ impl<'a> AstBuilder<'a> {
         right: Expression<'a>,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::ForInStatement(self.alloc(ForInStatement { span, left, right, body }))
+        Statement::ForInStatement(self.alloc(ForInStatement {
+            span,
+            left,
+            right,
+            body,
+            scope_id: Cell::default(),
+        }))
     }
 
     pub fn for_of_statement(
@@ -268,7 +272,14 @@ impl<'a> AstBuilder<'a> {
         right: Expression<'a>,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::ForOfStatement(self.alloc(ForOfStatement { span, r#await, left, right, body }))
+        Statement::ForOfStatement(self.alloc(ForOfStatement {
+            span,
+            r#await,
+            left,
+            right,
+            body,
+            scope_id: Cell::default(),
+        }))
     }
 
     pub fn for_statement(
@@ -279,7 +290,14 @@ impl<'a> AstBuilder<'a> {
         update: Option<Expression<'a>>,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::ForStatement(self.alloc(ForStatement { span, init, test, update, body }))
+        Statement::ForStatement(self.alloc(ForStatement {
+            span,
+            init,
+            test,
+            update,
+            body,
+            scope_id: Cell::default(),
+        }))
     }
 
     pub fn if_statement(
@@ -311,7 +329,12 @@ impl<'a> AstBuilder<'a> {
         discriminant: Expression<'a>,
         cases: Vec<'a, SwitchCase<'a>>,
     ) -> Statement<'a> {
-        Statement::SwitchStatement(self.alloc(SwitchStatement { span, discriminant, cases }))
+        Statement::SwitchStatement(self.alloc(SwitchStatement {
+            span,
+            discriminant,
+            cases,
+            scope_id: Cell::default(),
+        }))
     }
 
     pub fn switch_case(
@@ -343,7 +366,7 @@ impl<'a> AstBuilder<'a> {
         param: Option<BindingPattern<'a>>,
         body: Box<'a, BlockStatement<'a>>,
     ) -> Box<'a, CatchClause<'a>> {
-        self.alloc(CatchClause { span, param, body })
+        self.alloc(CatchClause { span, param, body, scope_id: Cell::default() })
     }
 
     pub fn while_statement(
@@ -398,6 +421,7 @@ impl<'a> AstBuilder<'a> {
         body: Box<'a, FunctionBody<'a>>,
         type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
         return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        predicate: Option<Box<'a, FlowPredicate<'a>>>,
     ) -> Expression<'a> {
         Expression::ArrowExpression(self.alloc(ArrowExpression {
             span,
@@ -408,6 +432,8 @@ impl<'a> AstBuilder<'a> {
             body,
             type_parameters,
             return_type,
+            predicate,
+            scope_id: Cell::default(),
         }))
     }
 
@@ -743,6 +769,7 @@ impl<'a> AstBuilder<'a> {
         body: Option<Box<'a, FunctionBody<'a>>>,
         type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
         return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        predicate: Option<Box<'a, FlowPredicate<'a>>>,
         modifiers: Modifiers<'a>,
     ) -> Box<'a, Function<'a>> {
         self.alloc(Function {
@@ -756,7 +783,9 @@ impl<'a> AstBuilder<'a> {
             body,
             type_parameters,
             return_type,
+            predicate,
             modifiers,
+            scope_id: Cell::default(),
         })
     }
 
@@ -766,7 +795,7 @@ impl<'a> AstBuilder<'a> {
         directives: Vec<'a, Directive>,
         statements: Vec<'a, Statement<'a>>,
     ) -> Box<'a, FunctionBody<'a>> {
-        self.alloc(FunctionBody { span, directives, statements })
+        self.alloc(FunctionBody { span, directives, statements, scope_id: Cell::default() })
     }
 
     /* ---------- Class ---------- */
@@ -795,6 +824,7 @@ impl<'a> AstBuilder<'a> {
             implements,
             decorators,
             modifiers,
+            scope_id: Cell::default(),
         })
     }
 
@@ -811,7 +841,7 @@ impl<'a> AstBuilder<'a> {
     }
 
     pub fn static_block(&self, span: Span, body: Vec<'a, Statement<'a>>) -> ClassElement<'a> {
-        ClassElement::StaticBlock(self.alloc(StaticBlock { span, body }))
+        ClassElement::StaticBlock(self.alloc(StaticBlock { span, body, scope_id: Cell::default() }))
     }
 
     pub fn class_property(
@@ -834,6 +864,7 @@ impl<'a> AstBuilder<'a> {
             optional: false,
             definite: false,
             readonly: false,
+            variance: None,
             type_annotation: None,
             accessibility: None,
             decorators,
@@ -847,6 +878,7 @@ impl<'a> AstBuilder<'a> {
         value: Option<Expression<'a>>,
         computed: bool,
         r#static: bool,
+        decorators: Vec<'a, Decorator<'a>>,
     ) -> ClassElement<'a> {
         ClassElement::AccessorProperty(self.alloc(AccessorProperty {
             span,
@@ -854,6 +886,7 @@ impl<'a> AstBuilder<'a> {
             value,
             computed,
             r#static,
+            decorators,
         }))
     }
 
@@ -1280,6 +1313,7 @@ impl<'a> AstBuilder<'a> {
         computed: bool,
         optional: bool,
         readonly: bool,
+        variance: Option<FlowVariance>,
         key: PropertyKey<'a>,
         type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
     ) -> TSSignature<'a> {
@@ -1288,6 +1322,7 @@ impl<'a> AstBuilder<'a> {
             computed,
             optional,
             readonly,
+            variance,
             key,
             type_annotation,
         }))
@@ -1458,13 +1493,14 @@ impl<'a> AstBuilder<'a> {
         &self,
         span: Span,
         id: BindingIdentifier,
+        body_span: Span,
         members: Vec<'a, TSEnumMember<'a>>,
         modifiers: Modifiers<'a>,
     ) -> Declaration<'a> {
         Declaration::TSEnumDeclaration(self.alloc(TSEnumDeclaration {
             span,
             id,
-            body: TSEnumBody { span, members },
+            body: TSEnumBody { span: body_span, members },
             modifiers,
         }))
     }
@@ -1664,4 +1700,83 @@ impl<'a> AstBuilder<'a> {
     pub fn js_doc_unknown_type(&self, span: Span) -> TSType<'a> {
         TSType::JSDocUnknownType(self.alloc(JSDocUnknownType { span }))
     }
+
+    /* Flow */
+    pub fn flow_type_cast_expression(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+    ) -> Expression<'a> {
+        Expression::FlowTypeCastExpression(self.alloc(FlowTypeCastExpression {
+            span,
+            expression,
+            type_annotation,
+        }))
+    }
+
+    pub fn flow_nullable_type(&self, span: Span, type_annotation: TSType<'a>) -> TSType<'a> {
+        TSType::FlowNullableType(self.alloc(FlowNullableType { span, type_annotation }))
+    }
+
+    pub fn flow_exact_object_type(
+        &self,
+        span: Span,
+        members: Vec<'a, TSSignature<'a>>,
+    ) -> TSType<'a> {
+        TSType::FlowExactObjectType(self.alloc(FlowExactObjectType { span, members }))
+    }
+
+    pub fn flow_inexact_object_type(
+        &self,
+        span: Span,
+        members: Vec<'a, TSSignature<'a>>,
+    ) -> TSType<'a> {
+        TSType::FlowInexactObjectType(self.alloc(FlowInexactObjectType { span, members }))
+    }
+
+    pub fn flow_object_type_spread(&self, span: Span, argument: TSType<'a>) -> TSSignature<'a> {
+        TSSignature::FlowObjectTypeSpread(self.alloc(FlowObjectTypeSpread { span, argument }))
+    }
+
+    pub fn flow_exists_type(&self, span: Span) -> TSType<'a> {
+        TSType::FlowExistsType(self.alloc(FlowExistsType { span }))
+    }
+
+    pub fn flow_predicate(
+        &self,
+        span: Span,
+        value: Option<Expression<'a>>,
+    ) -> Box<'a, FlowPredicate<'a>> {
+        self.alloc(FlowPredicate { span, value })
+    }
+
+    pub fn flow_declare_module_exports(
+        &self,
+        span: Span,
+        type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+    ) -> Declaration<'a> {
+        Declaration::FlowDeclareModuleExports(
+            self.alloc(FlowDeclareModuleExports { span, type_annotation }),
+        )
+    }
+
+    pub fn flow_opaque_type_declaration(
+        &self,
+        span: Span,
+        id: BindingIdentifier,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        supertype: Option<TSType<'a>>,
+        type_annotation: Option<TSType<'a>>,
+        modifiers: Modifiers<'a>,
+    ) -> Declaration<'a> {
+        Declaration::FlowOpaqueTypeDeclaration(self.alloc(FlowOpaqueTypeDeclaration {
+            span,
+            id,
+            type_parameters,
+            supertype,
+            type_annotation,
+            modifiers,
+        }))
+    }
 }
@@ -152,6 +152,10 @@ pub enum AstKind<'a> {
     TSTypeParameterInstantiation(&'a TSTypeParameterInstantiation<'a>),
 
     TSPropertySignature(&'a TSPropertySignature<'a>),
+
+    FlowTypeCastExpression(&'a FlowTypeCastExpression<'a>),
+    FlowOpaqueTypeDeclaration(&'a FlowOpaqueTypeDeclaration<'a>),
+    FlowDeclareModuleExports(&'a FlowDeclareModuleExports<'a>),
 }
 
 // SAFETY:
@@ -181,6 +185,7 @@ impl<'a> AstKind<'a> {
         || matches!(self, Self::ModuleDeclaration(_) | Self::TSEnumDeclaration(_) | Self::TSModuleDeclaration(_)
             | Self::VariableDeclaration(_) | Self::TSInterfaceDeclaration(_)
             | Self::TSTypeAliasDeclaration(_) | Self::TSImportEqualsDeclaration(_)
+            | Self::FlowOpaqueTypeDeclaration(_) | Self::FlowDeclareModuleExports(_)
         )
     }
 
@@ -401,6 +406,10 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSTypeParameterInstantiation(x) => x.span,
 
             Self::TSPropertySignature(x) => x.span,
+
+            Self::FlowTypeCastExpression(x) => x.span,
+            Self::FlowOpaqueTypeDeclaration(x) => x.span,
+            Self::FlowDeclareModuleExports(x) => x.span,
         }
     }
 }
@@ -569,6 +578,10 @@ impl<'a> AstKind<'a> {
             Self::TSTypeParameterInstantiation(_) => "TSTypeParameterInstantiation".into(),
 
             Self::TSPropertySignature(_) => "TSPropertySignature".into(),
+
+            Self::FlowTypeCastExpression(_) => "FlowTypeCastExpression".into(),
+            Self::FlowOpaqueTypeDeclaration(_) => "FlowOpaqueTypeDeclaration".into(),
+            Self::FlowDeclareModuleExports(_) => "FlowDeclareModuleExports".into(),
         }
     }
 }
@@ -0,0 +1,804 @@
+use oxc_allocator::Vec;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{
+    AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+};
+use serde::{ser::SerializeMap, Serializer};
+
+use super::{
+    or_empty, serialize_as_is, serialize_node, serialize_variants, Concat, Context, Node,
+    SerializeESTree,
+};
+use crate::ast::*;
+
+serialize_as_is!(
+    AssignmentOperator,
+    BinaryOperator,
+    LogicalOperator,
+    UnaryOperator,
+    UpdateOperator,
+    PropertyKind,
+    VariableDeclarationKind,
+    MethodDefinitionKind,
+    TemplateElementValue,
+);
+
+impl SerializeESTree for Program<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Program", self.span)?;
+        let source_type = if self.source_type.is_module() { "module" } else { "script" };
+        node.value("sourceType", source_type)?;
+        node.field("body", &Concat(&self.directives, &self.body))?;
+        node.end()
+    }
+}
+
+serialize_variants!(Expression<'_> {
+    BooleanLiteral,
+    NullLiteral,
+    NumberLiteral,
+    BigintLiteral,
+    RegExpLiteral,
+    StringLiteral,
+    TemplateLiteral,
+    Identifier,
+    MetaProperty,
+    Super,
+    ArrayExpression,
+    ArrowExpression,
+    AssignmentExpression,
+    AwaitExpression,
+    BinaryExpression,
+    CallExpression,
+    ChainExpression,
+    ClassExpression,
+    ConditionalExpression,
+    FunctionExpression,
+    ImportExpression,
+    LogicalExpression,
+    MemberExpression,
+    NewExpression,
+    ObjectExpression,
+    ParenthesizedExpression,
+    SequenceExpression,
+    TaggedTemplateExpression,
+    ThisExpression,
+    UnaryExpression,
+    UpdateExpression,
+    YieldExpression,
+    PrivateInExpression,
+    JSXElement,
+    JSXFragment,
+    TSAsExpression,
+    TSSatisfiesExpression,
+    TSTypeAssertion,
+    TSNonNullExpression,
+    TSInstantiationExpression,
+    FlowTypeCastExpression,
+});
+
+serialize_node!(IdentifierName => "Identifier" { "name": name });
+serialize_node!(IdentifierReference => "Identifier" { "name": name });
+serialize_node!(BindingIdentifier => "Identifier" { "name": name });
+serialize_node!(LabelIdentifier => "Identifier" { "name": name });
+serialize_node!(PrivateIdentifier => "PrivateIdentifier" { "name": name });
+serialize_node!(ThisExpression => "ThisExpression" {});
+serialize_node!(Super => "Super" {});
+
+serialize_node!(ArrayExpression<'_> => "ArrayExpression" { "elements": elements });
+
+impl SerializeESTree for ArrayExpressionElement<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::SpreadElement(spread) => spread.serialize_estree(ctx, s),
+            Self::Expression(expr) => expr.serialize_estree(ctx, s),
+            Self::Elision(_) => s.serialize_none(),
+        }
+    }
+}
+
+serialize_node!(ObjectExpression<'_> => "ObjectExpression" { "properties": properties });
+serialize_variants!(ObjectPropertyKind<'_> { ObjectProperty, SpreadProperty });
+
+serialize_node!(ObjectProperty<'_> => "Property" {
+    "key": key,
+    "value": value,
+    "kind": kind,
+    "method": method,
+    "shorthand": shorthand,
+    "computed": computed,
+});
+
+serialize_variants!(PropertyKey<'_> { Identifier, PrivateIdentifier, Expression });
+
+serialize_node!(TemplateLiteral<'_> => "TemplateLiteral" {
+    "quasis": quasis,
+    "expressions": expressions,
+});
+serialize_node!(TaggedTemplateExpression<'_> => "TaggedTemplateExpression" {
+    "tag": tag,
+    "quasi": quasi,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TemplateElement => "TemplateElement" { "value": value, "tail": tail });
+
+impl SerializeESTree for MemberExpression<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "MemberExpression", self.span())?;
+        match self {
+            Self::ComputedMemberExpression(expr) => {
+                node.field("object", &expr.object)?;
+                node.field("property", &expr.expression)?;
+            }
+            Self::StaticMemberExpression(expr) => {
+                node.field("object", &expr.object)?;
+                node.field("property", &expr.property)?;
+            }
+            Self::PrivateFieldExpression(expr) => {
+                node.field("object", &expr.object)?;
+                node.field("property", &expr.field)?;
+            }
+        }
+        node.value("computed", &matches!(self, Self::ComputedMemberExpression(_)))?;
+        node.field("optional", &self.optional())?;
+        node.end()
+    }
+}
+
+serialize_node!(CallExpression<'_> => "CallExpression" {
+    "callee": callee,
+    "arguments": arguments,
+    "optional": optional,
+    "typeParameters": type_parameters,
+});
+serialize_node!(NewExpression<'_> => "NewExpression" {
+    "callee": callee,
+    "arguments": arguments,
+    "typeParameters": type_parameters,
+});
+serialize_node!(MetaProperty => "MetaProperty" { "meta": meta, "property": property });
+serialize_node!(SpreadElement<'_> => "SpreadElement" { "argument": argument });
+serialize_variants!(Argument<'_> { SpreadElement, Expression });
+
+serialize_node!(UpdateExpression<'_> => "UpdateExpression" {
+    "operator": operator,
+    "prefix": prefix,
+    "argument": argument,
+});
+
+impl SerializeESTree for UnaryExpression<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "UnaryExpression", self.span)?;
+        node.field("operator", &self.operator)?;
+        node.value("prefix", &true)?;
+        node.field("argument", &self.argument)?;
+        node.end()
+    }
+}
+
+serialize_node!(BinaryExpression<'_> => "BinaryExpression" {
+    "left": left,
+    "operator": operator,
+    "right": right,
+});
+serialize_node!(PrivateInExpression<'_> => "BinaryExpression" {
+    "left": left,
+    "operator": operator,
+    "right": right,
+});
+serialize_node!(LogicalExpression<'_> => "LogicalExpression" {
+    "left": left,
+    "operator": operator,
+    "right": right,
+});
+serialize_node!(ConditionalExpression<'_> => "ConditionalExpression" {
+    "test": test,
+    "consequent": consequent,
+    "alternate": alternate,
+});
+serialize_node!(AssignmentExpression<'_> => "AssignmentExpression" {
+    "operator": operator,
+    "left": left,
+    "right": right,
+});
+
+serialize_variants!(AssignmentTarget<'_> { SimpleAssignmentTarget, AssignmentTargetPattern });
+serialize_variants!(SimpleAssignmentTarget<'_> {
+    AssignmentTargetIdentifier,
+    MemberAssignmentTarget,
+    TSAsExpression,
+    TSSatisfiesExpression,
+    TSNonNullExpression,
+    TSTypeAssertion,
+});
+serialize_variants!(AssignmentTargetPattern<'_> { ArrayAssignmentTarget, ObjectAssignmentTarget });
+serialize_variants!(AssignmentTargetMaybeDefault<'_> {
+    AssignmentTarget,
+    AssignmentTargetWithDefault,
+});
+serialize_variants!(AssignmentTargetProperty<'_> {
+    AssignmentTargetPropertyIdentifier,
+    AssignmentTargetPropertyProperty,
+});
+
+impl SerializeESTree for ArrayAssignmentTarget<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "ArrayPattern", self.span)?;
+        let rest = self.rest.as_ref().map(AssignmentTargetRest);
+        node.field("elements", &Concat(&self.elements, rest.as_ref().map_or(&[][..], as_slice)))?;
+        node.end()
+    }
+}
+
+impl SerializeESTree for ObjectAssignmentTarget<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "ObjectPattern", self.span)?;
+        let rest = self.rest.as_ref().map(AssignmentTargetRest);
+        node.field(
+            "properties",
+            &Concat(&self.properties, rest.as_ref().map_or(&[][..], as_slice)),
+        )?;
+        node.end()
+    }
+}
+
+fn as_slice<T>(value: &T) -> &[T] {
+    std::slice::from_ref(value)
+}
+
+/// The rest of an assignment target, which has no span for the `...`
+struct AssignmentTargetRest<'e, 'a>(&'e AssignmentTarget<'a>);
+
+impl SerializeESTree for AssignmentTargetRest<'_, '_> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let span = self.0.span();
+        let start = ctx.source_text[..span.start as usize].rfind("...").unwrap_or_default();
+        let mut node = ctx.node(s, "RestElement", Span::new(start as u32, span.end))?;
+        node.field("argument", self.0)?;
+        node.end()
+    }
+}
+
+serialize_node!(AssignmentTargetWithDefault<'_> => "AssignmentPattern" {
+    "left": binding,
+    "right": init,
+});
+
+impl SerializeESTree for AssignmentTargetPropertyIdentifier<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Property", self.span)?;
+        node.field("key", &self.binding)?;
+        match &self.init {
+            Some(init) => {
+                let span = Span::new(self.binding.span.start, self.span.end);
+                node.field("value", &DefaultValue { span, left: &self.binding, right: init })?;
+            }
+            None => node.field("value", &self.binding)?,
+        }
+        property_flags(&mut node, true, false)?;
+        node.end()
+    }
+}
+
+/// `a = 1` in `({ a = 1 } = b)`
+struct DefaultValue<'e, 'a> {
+    span: Span,
+    left: &'e IdentifierReference,
+    right: &'e Expression<'a>,
+}
+
+serialize_node!(DefaultValue<'_, '_> => "AssignmentPattern" { "left": left, "right": right });
+
+impl SerializeESTree for AssignmentTargetPropertyProperty<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Property", self.span)?;
+        node.field("key", &self.name)?;
+        node.field("value", &self.binding)?;
+        let computed = ctx.source_text(self.span).starts_with('[');
+        property_flags(&mut node, false, computed)?;
+        node.end()
+    }
+}
+
+/// The fields of a `Property` in a pattern
+fn property_flags<M: SerializeMap>(
+    node: &mut Node<M>,
+    shorthand: bool,
+    computed: bool,
+) -> Result<(), M::Error> {
+    node.value("kind", "init")?;
+    node.value("method", &false)?;
+    node.value("shorthand", &shorthand)?;
+    node.value("computed", &computed)
+}
+
+serialize_node!(SequenceExpression<'_> => "SequenceExpression" { "expressions": expressions });
+serialize_node!(AwaitExpression<'_> => "AwaitExpression" { "argument": argument });
+serialize_node!(ChainExpression<'_> => "ChainExpression" { "expression": expression });
+serialize_variants!(ChainElement<'_> { CallExpression, MemberExpression });
+
+/// ESTree has no parenthesized expressions
+impl SerializeESTree for ParenthesizedExpression<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        self.expression.serialize_estree(ctx, s)
+    }
+}
+
+serialize_variants!(Statement<'_> {
+    BlockStatement,
+    BreakStatement,
+    ContinueStatement,
+    DebuggerStatement,
+    DoWhileStatement,
+    EmptyStatement,
+    ExpressionStatement,
+    ForInStatement,
+    ForOfStatement,
+    ForStatement,
+    IfStatement,
+    LabeledStatement,
+    ReturnStatement,
+    SwitchStatement,
+    ThrowStatement,
+    TryStatement,
+    WhileStatement,
+    WithStatement,
+    ModuleDeclaration,
+    Declaration,
+});
+
+serialize_node!(Directive => "ExpressionStatement" {
+    "expression": expression,
+    "directive": directive,
+});
+serialize_node!(BlockStatement<'_> => "BlockStatement" { "body": body });
+
+serialize_variants!(Declaration<'_> {
+    VariableDeclaration,
+    FunctionDeclaration,
+    ClassDeclaration,
+    UsingDeclaration,
+    TSTypeAliasDeclaration,
+    TSInterfaceDeclaration,
+    TSEnumDeclaration,
+    TSModuleDeclaration,
+    TSImportEqualsDeclaration,
+    FlowOpaqueTypeDeclaration,
+    FlowDeclareModuleExports,
+});
+
+impl SerializeESTree for VariableDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "VariableDeclaration", self.span)?;
+        node.field("kind", &self.kind)?;
+        node.field("declarations", &self.declarations)?;
+        node.modifiers(&self.modifiers)?;
+        node.end()
+    }
+}
+
+serialize_node!(VariableDeclarator<'_> => "VariableDeclarator" {
+    "id": id,
+    "init": init,
+    "definite": definite,
+});
+
+impl SerializeESTree for UsingDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "VariableDeclaration", self.span)?;
+        node.value("kind", if self.is_await { "await using" } else { "using" })?;
+        node.field("declarations", &self.declarations)?;
+        node.end()
+    }
+}
+
+serialize_node!(EmptyStatement => "EmptyStatement" {});
+serialize_node!(ExpressionStatement<'_> => "ExpressionStatement" { "expression": expression });
+serialize_node!(IfStatement<'_> => "IfStatement" {
+    "test": test,
+    "consequent": consequent,
+    "alternate": alternate,
+});
+serialize_node!(DoWhileStatement<'_> => "DoWhileStatement" { "body": body, "test": test });
+serialize_node!(WhileStatement<'_> => "WhileStatement" { "test": test, "body": body });
+serialize_node!(ForStatement<'_> => "ForStatement" {
+    "init": init,
+    "test": test,
+    "update": update,
+    "body": body,
+});
+serialize_variants!(ForStatementInit<'_> { VariableDeclaration, Expression, UsingDeclaration });
+serialize_node!(ForInStatement<'_> => "ForInStatement" {
+    "left": left,
+    "right": right,
+    "body": body,
+});
+serialize_node!(ForOfStatement<'_> => "ForOfStatement" {
+    "await": r#await,
+    "left": left,
+    "right": right,
+    "body": body,
+});
+serialize_variants!(ForStatementLeft<'_> {
+    VariableDeclaration,
+    AssignmentTarget,
+    UsingDeclaration,
+});
+serialize_node!(ContinueStatement => "ContinueStatement" { "label": label });
+serialize_node!(BreakStatement => "BreakStatement" { "label": label });
+serialize_node!(ReturnStatement<'_> => "ReturnStatement" { "argument": argument });
+serialize_node!(WithStatement<'_> => "WithStatement" { "object": object, "body": body });
+serialize_node!(SwitchStatement<'_> => "SwitchStatement" {
+    "discriminant": discriminant,
+    "cases": cases,
+});
+serialize_node!(SwitchCase<'_> => "SwitchCase" { "test": test, "consequent": consequent });
+serialize_node!(LabeledStatement<'_> => "LabeledStatement" { "label": label, "body": body });
+serialize_node!(ThrowStatement<'_> => "ThrowStatement" { "argument": argument });
+serialize_node!(TryStatement<'_> => "TryStatement" {
+    "block": block,
+    "handler": handler,
+    "finalizer": finalizer,
+});
+serialize_node!(CatchClause<'_> => "CatchClause" { "param": param, "body": body });
+serialize_node!(DebuggerStatement => "DebuggerStatement" {});
+
+impl SerializeESTree for BindingPattern<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        self.serialize_pattern(ctx, s, None)
+    }
+}
+
+impl<'a> BindingPattern<'a> {
+    /// Serialize the pattern, with the type annotation and the decorators of a parameter
+    fn serialize_pattern<S: Serializer>(
+        &self,
+        ctx: &Context,
+        s: S,
+        decorators: Option<&Vec<'a, Decorator<'a>>>,
+    ) -> Result<S::Ok, S::Error> {
+        let span = self.kind.span();
+        let span =
+            self.type_annotation.as_ref().map_or(span, |t| Span::new(span.start, t.span.end));
+        let mut node = match &self.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                let mut node = ctx.node(s, "Identifier", span)?;
+                node.field("name", &ident.name)?;
+                node
+            }
+            BindingPatternKind::ObjectPattern(pattern) => {
+                let mut node = ctx.node(s, "ObjectPattern", span)?;
+                let rest = pattern.rest.as_ref().map_or(&[][..], as_slice);
+                node.field("properties", &Concat(&pattern.properties, rest))?;
+                node
+            }
+            BindingPatternKind::ArrayPattern(pattern) => {
+                let mut node = ctx.node(s, "ArrayPattern", span)?;
+                let rest = pattern.rest.as_ref().map_or(&[][..], as_slice);
+                node.field("elements", &Concat(&pattern.elements, rest))?;
+                node
+            }
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                let mut node = ctx.node(s, "AssignmentPattern", span)?;
+                node.field("left", &pattern.left)?;
+                node.field("right", &pattern.right)?;
+                node
+            }
+        };
+        if self.optional {
+            node.value("optional", &true)?;
+        }
+        if let Some(type_annotation) = &self.type_annotation {
+            node.field("typeAnnotation", type_annotation)?;
+        }
+        if let Some(decorators) = decorators.filter(|decorators| !decorators.is_empty()) {
+            node.field("decorators", decorators)?;
+        }
+        node.end()
+    }
+}
+
+impl SerializeESTree for BindingProperty<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Property", self.span)?;
+        node.field("key", &self.key)?;
+        node.field("value", &self.value)?;
+        property_flags(&mut node, self.shorthand, self.computed)?;
+        node.end()
+    }
+}
+
+serialize_node!(RestElement<'_> => "RestElement" { "argument": argument });
+
+impl SerializeESTree for Function<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let kind = match self.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression if self.body.is_none() => {
+                "TSEmptyBodyFunctionExpression"
+            }
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+        };
+        let mut node = ctx.node(s, kind, self.span)?;
+        node.field("id", &self.id)?;
+        node.field("expression", &self.expression)?;
+        node.field("generator", &self.generator)?;
+        node.field("async", &self.r#async)?;
+        node.field("params", &self.params)?;
+        node.field("body", &self.body)?;
+        node.field("typeParameters", &self.type_parameters)?;
+        node.field("returnType", &self.return_type)?;
+        if let Some(predicate) = &self.predicate {
+            node.field("predicate", predicate)?;
+        }
+        node.modifiers(&self.modifiers)?;
+        node.end()
+    }
+}
+
+/// The parameters are a list of patterns, followed by the rest element
+impl SerializeESTree for FormalParameters<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let rest = self.rest.as_ref().map_or(&[][..], as_slice);
+        Concat(&self.items, rest).serialize_estree(ctx, s)
+    }
+}
+
+impl SerializeESTree for FormalParameter<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        if self.accessibility.is_none() && !self.readonly {
+            return self.pattern.serialize_pattern(ctx, s, Some(&self.decorators));
+        }
+        let mut node = ctx.node(s, "TSParameterProperty", self.span)?;
+        node.field("accessibility", &self.accessibility)?;
+        node.field("readonly", &self.readonly)?;
+        node.field("parameter", &self.pattern)?;
+        node.field("decorators", &self.decorators)?;
+        node.end()
+    }
+}
+
+impl SerializeESTree for FunctionBody<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "BlockStatement", self.span)?;
+        node.field("body", &Concat(&self.directives, &self.statements))?;
+        node.end()
+    }
+}
+
+impl SerializeESTree for ArrowExpression<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "ArrowFunctionExpression", self.span)?;
+        node.value("id", &())?;
+        node.field("expression", &self.expression)?;
+        node.field("generator", &self.generator)?;
+        node.field("async", &self.r#async)?;
+        node.field("params", &self.params)?;
+        // The expression body is wrapped in a function body in the oxc AST
+        match (self.expression, self.body.statements.first()) {
+            (true, Some(Statement::ExpressionStatement(stmt))) => {
+                node.field("body", &stmt.expression)?;
+            }
+            _ => node.field("body", &self.body)?,
+        }
+        node.field("typeParameters", &self.type_parameters)?;
+        node.field("returnType", &self.return_type)?;
+        if let Some(predicate) = &self.predicate {
+            node.field("predicate", predicate)?;
+        }
+        node.end()
+    }
+}
+
+serialize_node!(YieldExpression<'_> => "YieldExpression" {
+    "delegate": delegate,
+    "argument": argument,
+});
+
+impl SerializeESTree for Class<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let kind = match self.r#type {
+            ClassType::ClassDeclaration => "ClassDeclaration",
+            ClassType::ClassExpression => "ClassExpression",
+        };
+        let mut node = ctx.node(s, kind, self.span)?;
+        node.field("id", &self.id)?;
+        node.field("superClass", &self.super_class)?;
+        node.field("body", &self.body)?;
+        node.field("typeParameters", &self.type_parameters)?;
+        node.field("superTypeParameters", &self.super_type_parameters)?;
+        node.field("implements", or_empty(&self.implements))?;
+        node.field("decorators", &self.decorators)?;
+        node.modifiers(&self.modifiers)?;
+        node.end()
+    }
+}
+
+serialize_node!(ClassBody<'_> => "ClassBody" { "body": body });
+serialize_variants!(ClassElement<'_> {
+    StaticBlock,
+    MethodDefinition,
+    PropertyDefinition,
+    AccessorProperty,
+    TSAbstractMethodDefinition,
+    TSAbstractPropertyDefinition,
+    TSIndexSignature,
+});
+
+impl SerializeESTree for MethodDefinition<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        self.serialize_method(ctx, s, "MethodDefinition")
+    }
+}
+
+impl SerializeESTree for TSAbstractMethodDefinition<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        self.method_definition.serialize_method(ctx, s, "TSAbstractMethodDefinition")
+    }
+}
+
+impl<'a> MethodDefinition<'a> {
+    fn serialize_method<S: Serializer>(
+        &self,
+        ctx: &Context,
+        s: S,
+        kind: &str,
+    ) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, kind, self.span)?;
+        node.field("key", &self.key)?;
+        node.field("value", &self.value)?;
+        node.field("kind", &self.kind)?;
+        node.field("computed", &self.computed)?;
+        node.field("static", &self.r#static)?;
+        node.field("override", &self.r#override)?;
+        node.field("optional", &self.optional)?;
+        node.field("accessibility", &self.accessibility)?;
+        node.field("decorators", &self.decorators)?;
+        node.end()
+    }
+}
+
+impl SerializeESTree for PropertyDefinition<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        self.serialize_property(ctx, s, "PropertyDefinition")
+    }
+}
+
+impl SerializeESTree for TSAbstractPropertyDefinition<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        self.property_definition.serialize_property(ctx, s, "TSAbstractPropertyDefinition")
+    }
+}
+
+impl<'a> PropertyDefinition<'a> {
+    fn serialize_property<S: Serializer>(
+        &self,
+        ctx: &Context,
+        s: S,
+        kind: &str,
+    ) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, kind, self.span)?;
+        node.field("key", &self.key)?;
+        node.field("value", &self.value)?;
+        node.field("computed", &self.computed)?;
+        node.field("static", &self.r#static)?;
+        node.field("declare", &self.declare)?;
+        node.field("override", &self.r#override)?;
+        node.field("optional", &self.optional)?;
+        node.field("definite", &self.definite)?;
+        node.field("readonly", &self.readonly)?;
+        if let Some(variance) = &self.variance {
+            node.field("variance", variance)?;
+        }
+        node.field("typeAnnotation", &self.type_annotation)?;
+        node.field("accessibility", &self.accessibility)?;
+        node.field("decorators", &self.decorators)?;
+        node.end()
+    }
+}
+
+serialize_node!(StaticBlock<'_> => "StaticBlock" { "body": body });
+serialize_node!(AccessorProperty<'_> => "AccessorProperty" {
+    "key": key,
+    "value": value,
+    "computed": computed,
+    "static": r#static,
+    "decorators": decorators,
+});
+
+impl SerializeESTree for ImportExpression<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "ImportExpression", self.span)?;
+        node.field("source", &self.source)?;
+        node.field("options", &self.arguments.first())?;
+        node.end()
+    }
+}
+
+serialize_variants!(ModuleDeclaration<'_> {
+    ImportDeclaration,
+    ExportAllDeclaration,
+    ExportDefaultDeclaration,
+    ExportNamedDeclaration,
+    TSExportAssignment,
+    TSNamespaceExportDeclaration,
+});
+
+impl SerializeESTree for ImportOrExportKind {
+    fn serialize_estree<S: Serializer>(&self, _ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(match self {
+            Self::Value => "value",
+            Self::Type => "type",
+            Self::Typeof => "typeof",
+        })
+    }
+}
+
+impl SerializeESTree for ImportDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "ImportDeclaration", self.span)?;
+        node.field("specifiers", or_empty(&self.specifiers))?;
+        node.field("source", &self.source)?;
+        node.field("attributes", or_empty(&self.assertions))?;
+        node.field("importKind", &self.import_kind)?;
+        node.end()
+    }
+}
+
+serialize_variants!(ImportDeclarationSpecifier {
+    ImportSpecifier,
+    ImportDefaultSpecifier,
+    ImportNamespaceSpecifier,
+});
+serialize_node!(ImportSpecifier => "ImportSpecifier" {
+    "imported": imported,
+    "local": local,
+    "importKind": import_kind,
+});
+serialize_node!(ImportDefaultSpecifier => "ImportDefaultSpecifier" { "local": local });
+serialize_node!(ImportNamespaceSpecifier => "ImportNamespaceSpecifier" { "local": local });
+serialize_node!(ImportAttribute => "ImportAttribute" { "key": key, "value": value });
+serialize_variants!(ImportAttributeKey { Identifier, StringLiteral });
+
+impl SerializeESTree for ExportNamedDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "ExportNamedDeclaration", self.span)?;
+        node.field("declaration", &self.declaration)?;
+        node.field("specifiers", &self.specifiers)?;
+        node.field("source", &self.source)?;
+        node.field("exportKind", &self.export_kind)?;
+        node.value("attributes", &[(); 0])?;
+        node.end()
+    }
+}
+
+serialize_node!(ExportDefaultDeclaration<'_> => "ExportDefaultDeclaration" {
+    "declaration": declaration,
+});
+
+impl SerializeESTree for ExportAllDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "ExportAllDeclaration", self.span)?;
+        node.field("exported", &self.exported)?;
+        node.field("source", &self.source)?;
+        node.field("attributes", or_empty(&self.assertions))?;
+        node.field("exportKind", &self.export_kind)?;
+        node.end()
+    }
+}
+
+serialize_node!(ExportSpecifier => "ExportSpecifier" {
+    "local": local,
+    "exported": exported,
+    "exportKind": export_kind,
+});
+serialize_variants!(ExportDefaultDeclarationKind<'_> {
+    Expression,
+    FunctionDeclaration,
+    ClassDeclaration,
+    TSInterfaceDeclaration,
+    TSEnumDeclaration,
+});
+serialize_variants!(ModuleExportName { Identifier, StringLiteral });
@@ -0,0 +1,61 @@
+use serde::Serializer;
+
+use super::{serialize_node, serialize_variants, Context, SerializeESTree};
+use crate::ast::*;
+
+serialize_node!(JSXElement<'_> => "JSXElement" {
+    "openingElement": opening_element,
+    "closingElement": closing_element,
+    "children": children,
+});
+serialize_node!(JSXOpeningElement<'_> => "JSXOpeningElement" {
+    "selfClosing": self_closing,
+    "name": name,
+    "attributes": attributes,
+    "typeParameters": type_parameters,
+});
+serialize_node!(JSXClosingElement<'_> => "JSXClosingElement" { "name": name });
+serialize_node!(JSXFragment<'_> => "JSXFragment" {
+    "openingFragment": opening_fragment,
+    "closingFragment": closing_fragment,
+    "children": children,
+});
+serialize_node!(JSXOpeningFragment => "JSXOpeningFragment" {});
+serialize_node!(JSXClosingFragment => "JSXClosingFragment" {});
+serialize_variants!(JSXElementName<'_> { Identifier, NamespacedName, MemberExpression });
+serialize_node!(JSXNamespacedName => "JSXNamespacedName" {
+    "namespace": namespace,
+    "name": property,
+});
+serialize_node!(JSXMemberExpression<'_> => "JSXMemberExpression" {
+    "object": object,
+    "property": property,
+});
+serialize_variants!(JSXMemberExpressionObject<'_> { Identifier, MemberExpression });
+serialize_node!(JSXExpressionContainer<'_> => "JSXExpressionContainer" {
+    "expression": expression,
+});
+serialize_variants!(JSXExpression<'_> { Expression, EmptyExpression });
+serialize_node!(JSXEmptyExpression => "JSXEmptyExpression" {});
+serialize_variants!(JSXAttributeItem<'_> { Attribute, SpreadAttribute });
+serialize_node!(JSXAttribute<'_> => "JSXAttribute" { "name": name, "value": value });
+serialize_node!(JSXSpreadAttribute<'_> => "JSXSpreadAttribute" { "argument": argument });
+serialize_variants!(JSXAttributeName<'_> { Identifier, NamespacedName });
+serialize_variants!(JSXAttributeValue<'_> {
+    StringLiteral,
+    ExpressionContainer,
+    Element,
+    Fragment,
+});
+serialize_node!(JSXIdentifier => "JSXIdentifier" { "name": name });
+serialize_variants!(JSXChild<'_> { Text, Element, Fragment, ExpressionContainer, Spread });
+serialize_node!(JSXSpreadChild<'_> => "JSXSpreadChild" { "expression": expression });
+
+impl SerializeESTree for JSXText {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "JSXText", self.span)?;
+        node.field("value", &self.value)?;
+        node.value("raw", ctx.source_text(self.span))?;
+        node.end()
+    }
+}
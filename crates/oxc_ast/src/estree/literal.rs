@@ -0,0 +1,65 @@
+use serde::Serializer;
+
+use super::{serialize_as_is, Context, SerializeESTree};
+use crate::ast::*;
+
+serialize_as_is!(RegExp);
+
+impl SerializeESTree for BooleanLiteral {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Literal", self.span)?;
+        node.field("value", &self.value)?;
+        node.value("raw", self.as_str())?;
+        node.end()
+    }
+}
+
+impl SerializeESTree for NullLiteral {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Literal", self.span)?;
+        node.value("value", &())?;
+        node.value("raw", "null")?;
+        node.end()
+    }
+}
+
+impl SerializeESTree for NumberLiteral<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Literal", self.span)?;
+        node.field("value", &self.value)?;
+        node.value("raw", ctx.source_text(self.span))?;
+        node.end()
+    }
+}
+
+/// `value` is `null` where bigints can't be represented, and `bigint` is the digits
+impl SerializeESTree for BigintLiteral {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let raw = ctx.source_text(self.span);
+        let mut node = ctx.node(s, "Literal", self.span)?;
+        node.value("value", &())?;
+        node.value("raw", raw)?;
+        node.value("bigint", &raw.trim_end_matches('n').replace('_', ""))?;
+        node.end()
+    }
+}
+
+/// `value` is `null` where regular expressions can't be represented, e.g. in JSON
+impl SerializeESTree for RegExpLiteral {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Literal", self.span)?;
+        node.value("value", &())?;
+        node.value("raw", ctx.source_text(self.span))?;
+        node.field("regex", &self.regex)?;
+        node.end()
+    }
+}
+
+impl SerializeESTree for StringLiteral {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "Literal", self.span)?;
+        node.field("value", &self.value)?;
+        node.value("raw", ctx.source_text(self.span))?;
+        node.end()
+    }
+}
@@ -0,0 +1,292 @@
+//! [ESTree](https://github.com/estree/estree) compatible serialization
+//!
+//! The serde output of the AST follows the oxc node shapes, e.g. `BindingIdentifier`,
+//! `AssignmentTarget` and `FormalParameters`. [Program::to_estree] serializes the AST in the
+//! shapes used by ESTree tools, and [TS-ESTree](https://typescript-eslint.io/packages/typescript-estree)
+//! for TypeScript, without building the oxc shapes first:
+//! * Identifiers are `Identifier`, literals are `Literal` with `raw`
+//! * Patterns, assignment targets and parameters are `Pattern`s
+//! * Directives are `ExpressionStatement`s with a `directive`
+//! * Every node has `start` and `end` in UTF-16 code units, `range` and `loc`
+
+mod js;
+mod jsx;
+mod literal;
+mod ts;
+
+use oxc_allocator::{Box, Vec};
+use oxc_span::{Atom, Span};
+use serde::{
+    ser::{SerializeMap, SerializeSeq},
+    Serialize, Serializer,
+};
+
+use crate::ast::{ModifierKind, Modifiers, Program};
+
+/// The ESTree serialization of a [Program], returned by [Program::to_estree].
+pub struct ESTree<'p, 'a> {
+    program: &'p Program<'a>,
+    ctx: Context<'p>,
+}
+
+impl<'a> Program<'a> {
+    /// Serialize into an ESTree compatible AST, e.g. with `serde_json::to_string`.
+    pub fn to_estree<'p>(&'p self, source_text: &'p str) -> ESTree<'p, 'a> {
+        ESTree { program: self, ctx: Context::new(source_text) }
+    }
+}
+
+impl<'p, 'a> Serialize for ESTree<'p, 'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.program.serialize_estree(&self.ctx, serializer)
+    }
+}
+
+/// Serialization of a node in its ESTree shape
+trait SerializeESTree {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error>;
+}
+
+/// A node serialized in its ESTree shape
+struct ESTreeRef<'e, T: ?Sized>(&'e T, &'e Context<'e>);
+
+impl<'e, T: SerializeESTree + ?Sized> Serialize for ESTreeRef<'e, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_estree(self.1, serializer)
+    }
+}
+
+impl<T: SerializeESTree> SerializeESTree for Box<'_, T> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize_estree(ctx, s)
+    }
+}
+
+impl<T: SerializeESTree> SerializeESTree for Option<T> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => value.serialize_estree(ctx, s),
+            None => s.serialize_none(),
+        }
+    }
+}
+
+impl<T: SerializeESTree + ?Sized> SerializeESTree for &T {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize_estree(ctx, s)
+    }
+}
+
+impl<T: SerializeESTree> SerializeESTree for [T] {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        Concat(self, &[] as &[T]).serialize_estree(ctx, s)
+    }
+}
+
+impl<T: SerializeESTree> SerializeESTree for Vec<'_, T> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize_estree(ctx, s)
+    }
+}
+
+/// The items of an optional list, e.g. `implements` which is `[]` rather than `null` in ESTree
+fn or_empty<'e, T>(list: &'e Option<Vec<'_, T>>) -> &'e [T] {
+    list.as_ref().map_or(&[], |list| list.as_slice())
+}
+
+/// Two lists serialized as one, e.g. the directives and the statements of a function body
+struct Concat<'e, A, B>(&'e [A], &'e [B]);
+
+impl<A: SerializeESTree, B: SerializeESTree> SerializeESTree for Concat<'_, A, B> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut seq = s.serialize_seq(Some(self.0.len() + self.1.len()))?;
+        for item in self.0 {
+            seq.serialize_element(&ESTreeRef(item, ctx))?;
+        }
+        for item in self.1 {
+            seq.serialize_element(&ESTreeRef(item, ctx))?;
+        }
+        seq.end()
+    }
+}
+
+/// Values with the same shape in ESTree, e.g. names and operators
+macro_rules! serialize_as_is {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl SerializeESTree for $ty {
+                fn serialize_estree<S: Serializer>(
+                    &self,
+                    _ctx: &Context,
+                    s: S,
+                ) -> Result<S::Ok, S::Error> {
+                    serde::Serialize::serialize(self, s)
+                }
+            }
+        )*
+    };
+}
+
+use serialize_as_is;
+
+serialize_as_is!(bool, f64, Atom);
+
+/// The untagged enums, which are serialized as their variant
+macro_rules! serialize_variants {
+    ($ty:ty { $($variant:ident),* $(,)? }) => {
+        impl SerializeESTree for $ty {
+            fn serialize_estree<S: Serializer>(
+                &self,
+                ctx: &Context,
+                s: S,
+            ) -> Result<S::Ok, S::Error> {
+                match self {
+                    $(Self::$variant(it) => it.serialize_estree(ctx, s),)*
+                }
+            }
+        }
+    };
+}
+
+use serialize_variants;
+
+/// Nodes which are serialized as `type`, the position and the fields, e.g.
+/// `serialize_node!(IfStatement<'_> => "IfStatement" { "test": test, .. })`
+macro_rules! serialize_node {
+    ($ty:ty => $kind:literal { $($key:literal: $field:ident),* $(,)? }) => {
+        impl SerializeESTree for $ty {
+            fn serialize_estree<S: Serializer>(
+                &self,
+                ctx: &Context,
+                s: S,
+            ) -> Result<S::Ok, S::Error> {
+                #[allow(unused_mut)]
+                let mut node = ctx.node(s, $kind, self.span)?;
+                $(node.field($key, &self.$field)?;)*
+                node.end()
+            }
+        }
+    };
+}
+
+use serialize_node;
+
+/// An ESTree node being serialized, started by [Context::node]
+struct Node<'e, M> {
+    map: M,
+    ctx: &'e Context<'e>,
+}
+
+impl<'e, M: SerializeMap> Node<'e, M> {
+    /// Serialize a child node, or a value which is the same in ESTree
+    fn field<T: SerializeESTree + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), M::Error> {
+        self.map.serialize_entry(key, &ESTreeRef(value, self.ctx))
+    }
+
+    /// Serialize a value which is not part of the AST, e.g. a `kind` which is implied by the node
+    fn value<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), M::Error> {
+        self.map.serialize_entry(key, value)
+    }
+
+    /// The `declare`, `abstract` and `const` flags of the TypeScript modifiers
+    fn modifiers(&mut self, modifiers: &Modifiers) -> Result<(), M::Error> {
+        let flags = [
+            ("declare", ModifierKind::Declare),
+            ("abstract", ModifierKind::Abstract),
+            ("const", ModifierKind::Const),
+        ];
+        for (flag, kind) in flags {
+            if modifiers.contains(kind) {
+                self.value(flag, &true)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<M::Ok, M::Error> {
+        self.map.end()
+    }
+}
+
+/// The source text and the conversion of the byte offsets of the spans into ESTree positions
+struct Context<'s> {
+    source_text: &'s str,
+    /// Byte offsets of the line starts
+    lines: std::vec::Vec<usize>,
+    /// UTF-16 offset of each byte offset, `None` for ascii source text
+    utf16: Option<std::vec::Vec<u32>>,
+}
+
+#[derive(Serialize)]
+struct Location {
+    start: Position,
+    end: Position,
+}
+
+/// 1-based line and 0-based column in UTF-16 code units
+#[derive(Serialize)]
+struct Position {
+    line: usize,
+    column: u32,
+}
+
+// Offsets are `u32` spans, so they fit in `usize`
+#[allow(clippy::cast_possible_truncation)]
+impl<'s> Context<'s> {
+    fn new(source_text: &'s str) -> Self {
+        let mut lines = vec![0];
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\r' if chars.peek().is_some_and(|(_, c)| *c == '\n') => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => lines.push(i + c.len_utf8()),
+                _ => {}
+            }
+        }
+
+        let utf16 = (!source_text.is_ascii()).then(|| {
+            let mut utf16 = std::vec::Vec::with_capacity(source_text.len() + 1);
+            let mut offset = 0;
+            for c in source_text.chars() {
+                utf16.extend(std::iter::repeat(offset).take(c.len_utf8()));
+                offset += c.len_utf16() as u32;
+            }
+            utf16.push(offset);
+            utf16
+        });
+
+        Self { source_text, lines, utf16 }
+    }
+
+    /// Start serializing a node with its `type`, `start`, `end`, `range` and `loc`
+    fn node<S: Serializer>(
+        &self,
+        s: S,
+        kind: &str,
+        span: Span,
+    ) -> Result<Node<'_, S::SerializeMap>, S::Error> {
+        let (start, end) = (self.utf16(span.start), self.utf16(span.end));
+        let mut map = s.serialize_map(None)?;
+        map.serialize_entry("type", kind)?;
+        map.serialize_entry("start", &start)?;
+        map.serialize_entry("end", &end)?;
+        map.serialize_entry("range", &[start, end])?;
+        let loc = Location { start: self.position(span.start), end: self.position(span.end) };
+        map.serialize_entry("loc", &loc)?;
+        Ok(Node { map, ctx: self })
+    }
+
+    fn utf16(&self, offset: u32) -> u32 {
+        self.utf16.as_ref().map_or(offset, |utf16| utf16[(offset as usize).min(utf16.len() - 1)])
+    }
+
+    fn position(&self, offset: u32) -> Position {
+        let line = self.lines.partition_point(|start| *start <= offset as usize);
+        let column = self.utf16(offset) - self.utf16(self.lines[line - 1] as u32);
+        Position { line, column }
+    }
+
+    fn source_text(&self, span: Span) -> &'s str {
+        self.source_text.get(span.start as usize..span.end as usize).unwrap_or_default()
+    }
+}
@@ -0,0 +1,369 @@
+use serde::Serializer;
+
+use super::{
+    or_empty, serialize_as_is, serialize_node, serialize_variants, Context, SerializeESTree,
+};
+use crate::ast::*;
+
+serialize_as_is!(TSAccessibility, TSMethodSignatureKind, FlowVarianceKind);
+
+impl SerializeESTree for TSEnumDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "TSEnumDeclaration", self.span)?;
+        node.field("id", &self.id)?;
+        node.field("members", &self.body.members)?;
+        node.modifiers(&self.modifiers)?;
+        node.end()
+    }
+}
+
+serialize_node!(TSEnumMember<'_> => "TSEnumMember" { "id": id, "initializer": initializer });
+serialize_variants!(TSEnumMemberName<'_> {
+    Identifier,
+    StringLiteral,
+    ComputedPropertyName,
+    NumberLiteral,
+});
+serialize_node!(TSTypeAnnotation<'_> => "TSTypeAnnotation" { "typeAnnotation": type_annotation });
+serialize_node!(TSLiteralType<'_> => "TSLiteralType" { "literal": literal });
+serialize_variants!(TSLiteral<'_> {
+    BooleanLiteral,
+    NullLiteral,
+    NumberLiteral,
+    BigintLiteral,
+    RegExpLiteral,
+    StringLiteral,
+    TemplateLiteral,
+    UnaryExpression,
+});
+serialize_variants!(TSType<'_> {
+    TSAnyKeyword,
+    TSBigIntKeyword,
+    TSBooleanKeyword,
+    TSNeverKeyword,
+    TSNullKeyword,
+    TSNumberKeyword,
+    TSObjectKeyword,
+    TSStringKeyword,
+    TSSymbolKeyword,
+    TSThisKeyword,
+    TSUndefinedKeyword,
+    TSUnknownKeyword,
+    TSVoidKeyword,
+    TSArrayType,
+    TSConditionalType,
+    TSConstructorType,
+    TSFunctionType,
+    TSImportType,
+    TSIndexedAccessType,
+    TSInferType,
+    TSIntersectionType,
+    TSLiteralType,
+    TSMappedType,
+    TSQualifiedName,
+    TSTemplateLiteralType,
+    TSTupleType,
+    TSTypeLiteral,
+    TSTypeOperatorType,
+    TSTypePredicate,
+    TSTypeQuery,
+    TSTypeReference,
+    TSUnionType,
+    JSDocNullableType,
+    JSDocUnknownType,
+    FlowNullableType,
+    FlowExactObjectType,
+    FlowInexactObjectType,
+    FlowExistsType,
+});
+serialize_node!(TSConditionalType<'_> => "TSConditionalType" {
+    "checkType": check_type,
+    "extendsType": extends_type,
+    "trueType": true_type,
+    "falseType": false_type,
+});
+serialize_node!(TSUnionType<'_> => "TSUnionType" { "types": types });
+serialize_node!(TSIntersectionType<'_> => "TSIntersectionType" { "types": types });
+serialize_node!(TSTypeOperatorType<'_> => "TSTypeOperator" {
+    "operator": operator,
+    "typeAnnotation": type_annotation,
+});
+
+impl SerializeESTree for TSTypeOperator {
+    fn serialize_estree<S: Serializer>(&self, _ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(match self {
+            Self::Keyof => "keyof",
+            Self::Unique => "unique",
+            Self::Readonly => "readonly",
+        })
+    }
+}
+
+serialize_node!(TSArrayType<'_> => "TSArrayType" { "elementType": element_type });
+serialize_node!(TSIndexedAccessType<'_> => "TSIndexedAccessType" {
+    "objectType": object_type,
+    "indexType": index_type,
+});
+serialize_node!(TSTupleType<'_> => "TSTupleType" { "elementTypes": element_types });
+serialize_node!(TSNamedTupleMember<'_> => "TSNamedTupleMember" {
+    "elementType": element_type,
+    "label": label,
+    "optional": optional,
+});
+serialize_node!(TSOptionalType<'_> => "TSOptionalType" { "typeAnnotation": type_annotation });
+serialize_node!(TSRestType<'_> => "TSRestType" { "typeAnnotation": type_annotation });
+serialize_variants!(TSTupleElement<'_> {
+    TSType,
+    TSOptionalType,
+    TSRestType,
+    TSNamedTupleMember,
+});
+
+serialize_node!(TSAnyKeyword => "TSAnyKeyword" {});
+serialize_node!(TSStringKeyword => "TSStringKeyword" {});
+serialize_node!(TSBooleanKeyword => "TSBooleanKeyword" {});
+serialize_node!(TSNumberKeyword => "TSNumberKeyword" {});
+serialize_node!(TSNeverKeyword => "TSNeverKeyword" {});
+serialize_node!(TSUnknownKeyword => "TSUnknownKeyword" {});
+serialize_node!(TSNullKeyword => "TSNullKeyword" {});
+serialize_node!(TSUndefinedKeyword => "TSUndefinedKeyword" {});
+serialize_node!(TSVoidKeyword => "TSVoidKeyword" {});
+serialize_node!(TSSymbolKeyword => "TSSymbolKeyword" {});
+serialize_node!(TSThisKeyword => "TSThisType" {});
+serialize_node!(TSObjectKeyword => "TSObjectKeyword" {});
+serialize_node!(TSBigIntKeyword => "TSBigIntKeyword" {});
+
+serialize_node!(TSTypeReference<'_> => "TSTypeReference" {
+    "typeName": type_name,
+    "typeParameters": type_parameters,
+});
+serialize_variants!(TSTypeName<'_> { IdentifierReference, QualifiedName });
+serialize_node!(TSQualifiedName<'_> => "TSQualifiedName" { "left": left, "right": right });
+serialize_node!(TSTypeParameterInstantiation<'_> => "TSTypeParameterInstantiation" {
+    "params": params,
+});
+serialize_node!(TSTypeParameter<'_> => "TSTypeParameter" {
+    "name": name,
+    "constraint": constraint,
+    "default": default,
+    "in": r#in,
+    "out": out,
+    "const": r#const,
+});
+serialize_node!(TSTypeParameterDeclaration<'_> => "TSTypeParameterDeclaration" {
+    "params": params,
+});
+
+impl SerializeESTree for TSTypeAliasDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "TSTypeAliasDeclaration", self.span)?;
+        node.field("id", &self.id)?;
+        node.field("typeAnnotation", &self.type_annotation)?;
+        node.field("typeParameters", &self.type_parameters)?;
+        node.modifiers(&self.modifiers)?;
+        node.end()
+    }
+}
+
+serialize_node!(TSClassImplements<'_> => "TSClassImplements" {
+    "expression": expression,
+    "typeParameters": type_parameters,
+});
+
+impl SerializeESTree for TSInterfaceDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "TSInterfaceDeclaration", self.span)?;
+        node.field("id", &self.id)?;
+        node.field("body", &self.body)?;
+        node.field("typeParameters", &self.type_parameters)?;
+        node.field("extends", or_empty(&self.extends))?;
+        node.modifiers(&self.modifiers)?;
+        node.end()
+    }
+}
+
+serialize_node!(TSInterfaceBody<'_> => "TSInterfaceBody" { "body": body });
+impl SerializeESTree for TSPropertySignature<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "TSPropertySignature", self.span)?;
+        node.field("computed", &self.computed)?;
+        node.field("optional", &self.optional)?;
+        node.field("readonly", &self.readonly)?;
+        if let Some(variance) = &self.variance {
+            node.field("variance", variance)?;
+        }
+        node.field("key", &self.key)?;
+        node.field("typeAnnotation", &self.type_annotation)?;
+        node.end()
+    }
+}
+
+serialize_variants!(TSSignature<'_> {
+    TSIndexSignature,
+    TSPropertySignature,
+    TSCallSignatureDeclaration,
+    TSConstructSignatureDeclaration,
+    TSMethodSignature,
+    FlowObjectTypeSpread,
+});
+serialize_node!(TSIndexSignature<'_> => "TSIndexSignature" {
+    "parameters": parameters,
+    "typeAnnotation": type_annotation,
+});
+serialize_node!(TSCallSignatureDeclaration<'_> => "TSCallSignatureDeclaration" {
+    "params": params,
+    "returnType": return_type,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TSMethodSignature<'_> => "TSMethodSignature" {
+    "key": key,
+    "computed": computed,
+    "optional": optional,
+    "kind": kind,
+    "params": params,
+    "returnType": return_type,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TSConstructSignatureDeclaration<'_> => "TSConstructSignatureDeclaration" {
+    "params": params,
+    "returnType": return_type,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TSIndexSignatureName<'_> => "Identifier" {
+    "name": name,
+    "typeAnnotation": type_annotation,
+});
+serialize_node!(TSInterfaceHeritage<'_> => "TSInterfaceHeritage" {
+    "expression": expression,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TSTypePredicate<'_> => "TSTypePredicate" {
+    "parameterName": parameter_name,
+    "asserts": asserts,
+    "typeAnnotation": type_annotation,
+});
+serialize_variants!(TSTypePredicateName { Identifier, This });
+
+impl SerializeESTree for TSModuleDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "TSModuleDeclaration", self.span)?;
+        node.field("id", &self.id)?;
+        node.field("body", &self.body)?;
+        node.modifiers(&self.modifiers)?;
+        node.end()
+    }
+}
+
+serialize_variants!(TSModuleDeclarationName { Identifier, StringLiteral });
+serialize_variants!(TSModuleDeclarationBody<'_> { TSModuleDeclaration, TSModuleBlock });
+serialize_node!(TSModuleBlock<'_> => "TSModuleBlock" { "body": body });
+serialize_node!(TSTypeLiteral<'_> => "TSTypeLiteral" { "members": members });
+serialize_node!(TSInferType<'_> => "TSInferType" { "typeParameter": type_parameter });
+serialize_node!(TSTypeQuery<'_> => "TSTypeQuery" {
+    "exprName": expr_name,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TSImportType<'_> => "TSImportType" {
+    "isTypeOf": is_type_of,
+    "parameter": parameter,
+    "qualifier": qualifier,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TSFunctionType<'_> => "TSFunctionType" {
+    "params": params,
+    "returnType": return_type,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TSConstructorType<'_> => "TSConstructorType" {
+    "abstract": r#abstract,
+    "params": params,
+    "returnType": return_type,
+    "typeParameters": type_parameters,
+});
+serialize_node!(TSMappedType<'_> => "TSMappedType" {
+    "typeParameter": type_parameter,
+    "nameType": name_type,
+    "typeAnnotation": type_annotation,
+    "optional": optional,
+    "readonly": readonly,
+});
+
+/// `true`, `"+"` or `"-"` for `?`, `+?` and `-?`
+impl SerializeESTree for TSMappedTypeModifierOperator {
+    fn serialize_estree<S: Serializer>(&self, _ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::True => s.serialize_bool(true),
+            Self::Plus => s.serialize_str("+"),
+            Self::Minus => s.serialize_str("-"),
+            Self::None => s.serialize_bool(false),
+        }
+    }
+}
+
+serialize_node!(TSTemplateLiteralType<'_> => "TSTemplateLiteralType" {
+    "quasis": quasis,
+    "types": types,
+});
+serialize_node!(TSAsExpression<'_> => "TSAsExpression" {
+    "expression": expression,
+    "typeAnnotation": type_annotation,
+});
+serialize_node!(TSSatisfiesExpression<'_> => "TSSatisfiesExpression" {
+    "expression": expression,
+    "typeAnnotation": type_annotation,
+});
+serialize_node!(TSTypeAssertion<'_> => "TSTypeAssertion" {
+    "expression": expression,
+    "typeAnnotation": type_annotation,
+});
+serialize_node!(TSImportEqualsDeclaration<'_> => "TSImportEqualsDeclaration" {
+    "id": id,
+    "moduleReference": module_reference,
+    "isExport": is_export,
+    "importKind": import_kind,
+});
+serialize_variants!(TSModuleReference<'_> { TypeName, ExternalModuleReference });
+serialize_node!(TSExternalModuleReference => "TSExternalModuleReference" {
+    "expression": expression,
+});
+serialize_node!(TSNonNullExpression<'_> => "TSNonNullExpression" { "expression": expression });
+serialize_node!(Decorator<'_> => "Decorator" { "expression": expression });
+serialize_node!(TSExportAssignment<'_> => "TSExportAssignment" { "expression": expression });
+serialize_node!(TSNamespaceExportDeclaration => "TSNamespaceExportDeclaration" { "id": id });
+serialize_node!(TSInstantiationExpression<'_> => "TSInstantiationExpression" {
+    "expression": expression,
+    "typeParameters": type_parameters,
+});
+
+serialize_node!(JSDocNullableType<'_> => "JSDocNullableType" {
+    "typeAnnotation": type_annotation,
+    "postfix": postfix,
+});
+serialize_node!(JSDocUnknownType => "JSDocUnknownType" {});
+
+serialize_node!(FlowTypeCastExpression<'_> => "FlowTypeCastExpression" {
+    "expression": expression,
+    "typeAnnotation": type_annotation,
+});
+serialize_node!(FlowNullableType<'_> => "FlowNullableType" { "typeAnnotation": type_annotation });
+serialize_node!(FlowExactObjectType<'_> => "FlowExactObjectType" { "members": members });
+serialize_node!(FlowInexactObjectType<'_> => "FlowInexactObjectType" { "members": members });
+serialize_node!(FlowObjectTypeSpread<'_> => "FlowObjectTypeSpread" { "argument": argument });
+serialize_node!(FlowExistsType => "FlowExistsType" {});
+serialize_node!(FlowVariance => "FlowVariance" { "kind": kind });
+serialize_node!(FlowPredicate<'_> => "FlowPredicate" { "value": value });
+serialize_node!(FlowDeclareModuleExports<'_> => "FlowDeclareModuleExports" {
+    "typeAnnotation": type_annotation,
+});
+
+impl SerializeESTree for FlowOpaqueTypeDeclaration<'_> {
+    fn serialize_estree<S: Serializer>(&self, ctx: &Context, s: S) -> Result<S::Ok, S::Error> {
+        let mut node = ctx.node(s, "FlowOpaqueTypeDeclaration", self.span)?;
+        node.field("id", &self.id)?;
+        node.field("typeParameters", &self.type_parameters)?;
+        node.field("supertype", &self.supertype)?;
+        node.field("typeAnnotation", &self.type_annotation)?;
+        node.modifiers(&self.modifiers)?;
+        node.end()
+    }
+}
@@ -6,9 +6,13 @@
 //! * `Identifier` is replaced with explicit `BindingIdentifier`, `IdentifierReference`, `IdentifierName` per spec
 //! * `AssignmentExpression`.`left` `Pattern` is replaced with `AssignmentTarget`
 //!
+//! `Program::to_estree` serializes the AST in the ESTree shapes instead.
+//!
 //! ## Cargo Features
 //! * `"serde"` enables support for serde serialization
 
+#[cfg(feature = "serde")]
+mod estree;
 #[cfg(feature = "serde")]
 mod serialize;
 
@@ -24,6 +28,8 @@ mod visit_mut;
 
 pub use num_bigint::BigUint;
 
+#[cfg(feature = "serde")]
+pub use crate::estree::ESTree;
 pub use crate::{
     ast_builder::AstBuilder,
     ast_kind::AstKind,
@@ -72,6 +72,7 @@ impl<'a> GetSpan for Expression<'a> {
             Self::TSTypeAssertion(e) => e.span,
             Self::TSNonNullExpression(e) => e.span,
             Self::TSInstantiationExpression(e) => e.span,
+            Self::FlowTypeCastExpression(e) => e.span,
         }
     }
 }
@@ -181,6 +182,8 @@ impl<'a> GetSpan for Declaration<'a> {
             Self::TSEnumDeclaration(decl) => decl.span,
             Self::TSModuleDeclaration(decl) => decl.span,
             Self::TSImportEqualsDeclaration(decl) => decl.span,
+            Self::FlowOpaqueTypeDeclaration(decl) => decl.span,
+            Self::FlowDeclareModuleExports(decl) => decl.span,
         }
     }
 }
@@ -287,6 +290,7 @@ impl<'a> GetSpan for TSSignature<'a> {
             Self::TSCallSignatureDeclaration(decl) => decl.span,
             Self::TSConstructSignatureDeclaration(decl) => decl.span,
             Self::TSMethodSignature(sig) => sig.span,
+            Self::FlowObjectTypeSpread(spread) => spread.span,
         }
     }
 }
@@ -328,6 +332,10 @@ impl<'a> GetSpan for TSType<'a> {
             Self::TSObjectKeyword(t) => t.span,
             Self::JSDocNullableType(t) => t.span,
             Self::JSDocUnknownType(t) => t.span,
+            Self::FlowNullableType(t) => t.span,
+            Self::FlowExactObjectType(t) => t.span,
+            Self::FlowInexactObjectType(t) => t.span,
+            Self::FlowExistsType(t) => t.span,
         }
     }
 }
@@ -401,3 +409,9 @@ impl<'a> GetSpan for JSXAttributeName<'a> {
         }
     }
 }
+
+impl<'a> GetSpan for TSEnumMember<'a> {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
@@ -376,6 +376,9 @@ pub trait Visit<'a>: Sized {
         if let Some(annotation) = &func.return_type {
             self.visit_ts_type_annotation(annotation);
         }
+        if let Some(predicate) = &func.predicate {
+            self.visit_flow_predicate(predicate);
+        }
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -600,6 +603,7 @@ pub trait Visit<'a>: Sized {
             Expression::TSInstantiationExpression(expr) => {
                 self.visit_ts_instantiation_expression(expr);
             }
+            Expression::FlowTypeCastExpression(expr) => self.visit_flow_type_cast_expression(expr),
         }
     }
 
@@ -676,6 +680,9 @@ pub trait Visit<'a>: Sized {
         if let Some(parameters) = &expr.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        if let Some(predicate) = &expr.predicate {
+            self.visit_flow_predicate(predicate);
+        }
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -1389,6 +1396,12 @@ pub trait Visit<'a>: Sized {
             Declaration::TSInterfaceDeclaration(decl) => {
                 self.visit_ts_interface_declaration(decl);
             }
+            Declaration::FlowOpaqueTypeDeclaration(decl) => {
+                self.visit_flow_opaque_type_declaration(decl);
+            }
+            Declaration::FlowDeclareModuleExports(decl) => {
+                self.visit_flow_declare_module_exports(decl);
+            }
         }
     }
 
@@ -1510,6 +1523,9 @@ pub trait Visit<'a>: Sized {
             TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+            TSType::FlowNullableType(ty) => self.visit_flow_nullable_type(ty),
+            TSType::FlowExactObjectType(ty) => self.visit_flow_exact_object_type(ty),
+            TSType::FlowInexactObjectType(ty) => self.visit_flow_inexact_object_type(ty),
             _ => {}
         }
     }
@@ -1706,6 +1722,9 @@ pub trait Visit<'a>: Sized {
             TSSignature::TSConstructSignatureDeclaration(sig) => {
                 self.visit_ts_construct_signature_declaration(sig);
             }
+            TSSignature::FlowObjectTypeSpread(spread) => {
+                self.visit_flow_object_type_spread(spread);
+            }
         }
     }
 
@@ -1767,4 +1786,63 @@ pub trait Visit<'a>: Sized {
             self.visit_ts_type_annotation(annotation);
         }
     }
+
+    /* ----------  Flow ---------- */
+
+    fn visit_flow_type_cast_expression(&mut self, expr: &FlowTypeCastExpression<'a>) {
+        let kind = AstKind::FlowTypeCastExpression(self.alloc(expr));
+        self.enter_node(kind);
+        self.visit_expression(&expr.expression);
+        self.visit_ts_type_annotation(&expr.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_flow_predicate(&mut self, predicate: &FlowPredicate<'a>) {
+        if let Some(value) = &predicate.value {
+            self.visit_expression(value);
+        }
+    }
+
+    fn visit_flow_declare_module_exports(&mut self, decl: &FlowDeclareModuleExports<'a>) {
+        let kind = AstKind::FlowDeclareModuleExports(self.alloc(decl));
+        self.enter_node(kind);
+        self.visit_ts_type_annotation(&decl.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_flow_opaque_type_declaration(&mut self, decl: &FlowOpaqueTypeDeclaration<'a>) {
+        let kind = AstKind::FlowOpaqueTypeDeclaration(self.alloc(decl));
+        self.enter_node(kind);
+        self.visit_binding_identifier(&decl.id);
+        if let Some(parameters) = &decl.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(supertype) = &decl.supertype {
+            self.visit_ts_type(supertype);
+        }
+        if let Some(annotation) = &decl.type_annotation {
+            self.visit_ts_type(annotation);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_flow_nullable_type(&mut self, ty: &FlowNullableType<'a>) {
+        self.visit_ts_type(&ty.type_annotation);
+    }
+
+    fn visit_flow_exact_object_type(&mut self, ty: &FlowExactObjectType<'a>) {
+        for signature in &ty.members {
+            self.visit_ts_signature(signature);
+        }
+    }
+
+    fn visit_flow_inexact_object_type(&mut self, ty: &FlowInexactObjectType<'a>) {
+        for signature in &ty.members {
+            self.visit_ts_signature(signature);
+        }
+    }
+
+    fn visit_flow_object_type_spread(&mut self, spread: &FlowObjectTypeSpread<'a>) {
+        self.visit_ts_type(&spread.argument);
+    }
 }
@@ -239,6 +239,9 @@ pub trait VisitMut<'a>: Sized {
         if let Some(annotation) = &mut func.return_type {
             self.visit_ts_type_annotation(annotation);
         }
+        if let Some(predicate) = &mut func.predicate {
+            self.visit_flow_predicate(predicate);
+        }
     }
 
     fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
@@ -305,7 +308,7 @@ pub trait VisitMut<'a>: Sized {
             ClassElement::StaticBlock(block) => self.visit_static_block(block),
             ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
             ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
-            ClassElement::AccessorProperty(_def) => { /* TODO */ }
+            ClassElement::AccessorProperty(def) => self.visit_accessor_property(def),
             ClassElement::TSAbstractMethodDefinition(def) => {
                 self.visit_method_definition(&mut def.method_definition);
             }
@@ -341,6 +344,16 @@ pub trait VisitMut<'a>: Sized {
         }
     }
 
+    fn visit_accessor_property(&mut self, def: &mut AccessorProperty<'a>) {
+        for decorator in def.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut def.key);
+        if let Some(value) = &mut def.value {
+            self.visit_expression(value);
+        }
+    }
+
     /* ----------  Expression ---------- */
 
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
@@ -398,6 +411,7 @@ pub trait VisitMut<'a>: Sized {
             Expression::TSInstantiationExpression(expr) => {
                 self.visit_ts_instantiation_expression(expr);
             }
+            Expression::FlowTypeCastExpression(expr) => self.visit_flow_type_cast_expression(expr),
         }
     }
 
@@ -445,6 +459,9 @@ pub trait VisitMut<'a>: Sized {
         if let Some(parameters) = &mut expr.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        if let Some(predicate) = &mut expr.predicate {
+            self.visit_flow_predicate(predicate);
+        }
     }
 
     fn visit_await_expression(&mut self, expr: &mut AwaitExpression<'a>) {
@@ -984,6 +1001,12 @@ pub trait VisitMut<'a>: Sized {
             Declaration::TSInterfaceDeclaration(decl) => {
                 self.visit_ts_interface_declaration(decl);
             }
+            Declaration::FlowOpaqueTypeDeclaration(decl) => {
+                self.visit_flow_opaque_type_declaration(decl);
+            }
+            Declaration::FlowDeclareModuleExports(decl) => {
+                self.visit_flow_declare_module_exports(decl);
+            }
         }
     }
 
@@ -1073,6 +1096,9 @@ pub trait VisitMut<'a>: Sized {
             TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+            TSType::FlowNullableType(ty) => self.visit_flow_nullable_type(ty),
+            TSType::FlowExactObjectType(ty) => self.visit_flow_exact_object_type(ty),
+            TSType::FlowInexactObjectType(ty) => self.visit_flow_inexact_object_type(ty),
             _ => {}
         }
     }
@@ -1228,6 +1254,9 @@ pub trait VisitMut<'a>: Sized {
             TSSignature::TSConstructSignatureDeclaration(sig) => {
                 self.visit_ts_construct_signature_declaration(sig);
             }
+            TSSignature::FlowObjectTypeSpread(spread) => {
+                self.visit_flow_object_type_spread(spread);
+            }
         }
     }
 
@@ -1286,4 +1315,54 @@ pub trait VisitMut<'a>: Sized {
             self.visit_ts_type_annotation(annotation);
         }
     }
+
+    /* ----------  Flow ---------- */
+
+    fn visit_flow_type_cast_expression(&mut self, expr: &mut FlowTypeCastExpression<'a>) {
+        self.visit_expression(&mut expr.expression);
+        self.visit_ts_type_annotation(&mut expr.type_annotation);
+    }
+
+    fn visit_flow_predicate(&mut self, predicate: &mut FlowPredicate<'a>) {
+        if let Some(value) = &mut predicate.value {
+            self.visit_expression(value);
+        }
+    }
+
+    fn visit_flow_declare_module_exports(&mut self, decl: &mut FlowDeclareModuleExports<'a>) {
+        self.visit_ts_type_annotation(&mut decl.type_annotation);
+    }
+
+    fn visit_flow_opaque_type_declaration(&mut self, decl: &mut FlowOpaqueTypeDeclaration<'a>) {
+        self.visit_binding_identifier(&mut decl.id);
+        if let Some(parameters) = &mut decl.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(supertype) = &mut decl.supertype {
+            self.visit_ts_type(supertype);
+        }
+        if let Some(annotation) = &mut decl.type_annotation {
+            self.visit_ts_type(annotation);
+        }
+    }
+
+    fn visit_flow_nullable_type(&mut self, ty: &mut FlowNullableType<'a>) {
+        self.visit_ts_type(&mut ty.type_annotation);
+    }
+
+    fn visit_flow_exact_object_type(&mut self, ty: &mut FlowExactObjectType<'a>) {
+        for signature in ty.members.iter_mut() {
+            self.visit_ts_signature(signature);
+        }
+    }
+
+    fn visit_flow_inexact_object_type(&mut self, ty: &mut FlowInexactObjectType<'a>) {
+        for signature in ty.members.iter_mut() {
+            self.visit_ts_signature(signature);
+        }
+    }
+
+    fn visit_flow_object_type_spread(&mut self, spread: &mut FlowObjectTypeSpread<'a>) {
+        self.visit_ts_type(&mut spread.argument);
+    }
 }
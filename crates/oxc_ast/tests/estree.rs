@@ -0,0 +1,170 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use serde_json::{json, Value};
+
+fn estree(source_text: &str) -> Value {
+    estree_with_source_type(source_text, SourceType::default().with_module(true))
+}
+
+fn estree_with_source_type(source_text: &str, source_type: SourceType) -> Value {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+    serde_json::to_value(ret.program.to_estree(source_text)).unwrap()
+}
+
+/// The expression of the first statement
+fn expression(source_text: &str) -> Value {
+    estree(source_text)["body"][0]["expression"].clone()
+}
+
+#[test]
+fn literal() {
+    let literal = expression("'a\\n'");
+    assert_eq!(literal["type"], "Literal");
+    assert_eq!(literal["value"], "a\n");
+    assert_eq!(literal["raw"], "'a\\n'");
+
+    let literal = expression("0x10");
+    assert_eq!((&literal["value"], &literal["raw"]), (&json!(16.0), &json!("0x10")));
+
+    let literal = expression("null");
+    assert_eq!((&literal["value"], &literal["raw"]), (&Value::Null, &json!("null")));
+
+    let literal = expression("true");
+    assert_eq!((&literal["value"], &literal["raw"]), (&json!(true), &json!("true")));
+
+    let literal = expression("1_000n");
+    assert_eq!(literal["value"], Value::Null);
+    assert_eq!(literal["raw"], "1_000n");
+    assert_eq!(literal["bigint"], "1000");
+
+    let literal = expression("/a+/gu");
+    assert_eq!(literal["value"], Value::Null);
+    assert_eq!(literal["raw"], "/a+/gu");
+    assert_eq!(literal["regex"], json!({ "pattern": "a+", "flags": "gu" }));
+}
+
+#[test]
+fn property() {
+    let object = expression("({ a, b: 1, [c]: 2, d() {} })");
+    assert_eq!(object["type"], "ObjectExpression");
+    let properties = object["properties"].as_array().unwrap();
+    let fields = |property: &Value| {
+        (
+            property["type"].clone(),
+            property["kind"].clone(),
+            property["shorthand"].clone(),
+            property["computed"].clone(),
+            property["method"].clone(),
+        )
+    };
+    let init = json!("init");
+    assert_eq!(
+        fields(&properties[0]),
+        (json!("Property"), init.clone(), json!(true), json!(false), json!(false))
+    );
+    assert_eq!(properties[0]["key"]["type"], "Identifier");
+    assert_eq!(properties[0]["value"]["name"], "a");
+    assert_eq!(
+        fields(&properties[1]),
+        (json!("Property"), init.clone(), json!(false), json!(false), json!(false))
+    );
+    assert_eq!(
+        fields(&properties[2]),
+        (json!("Property"), init.clone(), json!(false), json!(true), json!(false))
+    );
+    assert_eq!(
+        fields(&properties[3]),
+        (json!("Property"), init, json!(false), json!(false), json!(true))
+    );
+    assert_eq!(properties[3]["value"]["type"], "FunctionExpression");
+
+    let pattern = &estree("const { a = 1, b: [c] } = d")["body"][0]["declarations"][0]["id"];
+    assert_eq!(pattern["type"], "ObjectPattern");
+    let properties = pattern["properties"].as_array().unwrap();
+    assert_eq!(properties[0]["type"], "Property");
+    assert_eq!(properties[0]["shorthand"], true);
+    assert_eq!(properties[0]["value"]["type"], "AssignmentPattern");
+    assert_eq!(properties[1]["type"], "Property");
+    assert_eq!(properties[1]["value"]["type"], "ArrayPattern");
+}
+
+#[test]
+fn chain_expression() {
+    let chain = expression("a?.b()");
+    assert_eq!(chain["type"], "ChainExpression");
+    assert_eq!((&chain["start"], &chain["end"]), (&json!(0), &json!(6)));
+    let call = &chain["expression"];
+    assert_eq!(call["type"], "CallExpression");
+    assert_eq!(call["optional"], false);
+    assert_eq!(call["callee"]["type"], "MemberExpression");
+    assert_eq!(call["callee"]["optional"], true);
+    assert_eq!(call["callee"]["computed"], false);
+    assert_eq!(call["callee"]["property"]["name"], "b");
+}
+
+#[test]
+fn template_element_value() {
+    let template = expression("`a\\tb${c}`");
+    assert_eq!(template["type"], "TemplateLiteral");
+    let quasis = template["quasis"].as_array().unwrap();
+    assert_eq!(quasis[0]["type"], "TemplateElement");
+    assert_eq!(quasis[0]["value"], json!({ "raw": "a\\tb", "cooked": "a\tb" }));
+    assert_eq!(quasis[0]["tail"], false);
+    assert_eq!(quasis[1]["value"], json!({ "raw": "", "cooked": "" }));
+    assert_eq!(quasis[1]["tail"], true);
+
+    // Invalid escapes are allowed in tagged templates, without a cooked value
+    let tagged = expression("tag`\\unicode`");
+    let quasi = &tagged["quasi"]["quasis"][0];
+    assert_eq!(quasi["value"], json!({ "raw": "\\unicode", "cooked": null }));
+}
+
+#[test]
+fn position() {
+    let program = estree("'😀';\nx");
+    let statement = &program["body"][1];
+    assert_eq!(statement["expression"]["name"], "x");
+    assert_eq!((&statement["start"], &statement["end"]), (&json!(6), &json!(7)));
+    assert_eq!(statement["range"], json!([6, 7]));
+    assert_eq!(
+        statement["loc"],
+        json!({ "start": { "line": 2, "column": 0 }, "end": { "line": 2, "column": 1 } })
+    );
+    let literal = &program["body"][0]["expression"];
+    assert_eq!(literal["range"], json!([0, 4]));
+    assert_eq!(literal["loc"]["end"], json!({ "line": 1, "column": 4 }));
+}
+
+#[test]
+fn flow() {
+    let source_type = SourceType::default().with_module(true).with_flow(true);
+    let source_text = "(a: any);
+type B = { +b: string, ...C, ... };
+function d(x: mixed): boolean %checks { return !!x; }
+declare module.exports: *;";
+    let program = estree_with_source_type(source_text, source_type);
+
+    let cast = &program["body"][0]["expression"];
+    assert_eq!(cast["type"], "FlowTypeCastExpression");
+    assert_eq!(cast["expression"]["name"], "a");
+    assert_eq!(cast["typeAnnotation"]["typeAnnotation"]["type"], "TSAnyKeyword");
+
+    let object = &program["body"][1]["typeAnnotation"];
+    assert_eq!(object["type"], "FlowInexactObjectType");
+    let variance = &object["members"][0]["variance"];
+    assert_eq!((&variance["type"], &variance["kind"]), (&json!("FlowVariance"), &json!("plus")));
+    assert_eq!(variance["range"], json!([21, 22]));
+    assert_eq!(object["members"][1]["type"], "FlowObjectTypeSpread");
+    assert_eq!(object["members"][1]["argument"]["type"], "TSTypeReference");
+
+    let predicate = &program["body"][2]["predicate"];
+    assert_eq!(predicate["type"], "FlowPredicate");
+    assert_eq!(predicate["value"], Value::Null);
+
+    let declaration = &program["body"][3];
+    assert_eq!(declaration["type"], "FlowDeclareModuleExports");
+    assert_eq!(declaration["typeAnnotation"]["typeAnnotation"]["type"], "FlowExistsType");
+}
@@ -0,0 +1,9 @@
+// @flow
+import type { Node } from './node';
+
+type Props = {| +name: ?string, ...Node |};
+
+export function greet(props: Props): string %checks {
+  debugger;
+  return (props.name: any);
+}
@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use oxc_allocator::Allocator;
+use oxc_linter::partial_loader::{JavaScriptSource, PartialLoader};
 use oxc_parser::Parser;
 use oxc_prettier::{Prettier, PrettierOptions};
 use oxc_span::SourceType;
@@ -49,10 +50,24 @@ impl Runner for FormatRunner {
 impl FormatRunner {
     fn format(path: &Path) {
         let source_text = std::fs::read_to_string(path).unwrap();
-        let allocator = Allocator::default();
-        let source_type = SourceType::from_path(path).unwrap();
-        let ret = Parser::new(&allocator, &source_text, source_type).parse();
-        let _ = Prettier::new(&allocator, &source_text, ret.trivias, PrettierOptions::default())
+        let sources = SourceType::from_path(path).map_or_else(
+            |_| {
+                path.extension()
+                    .and_then(|ext| PartialLoader::parse(&ext.to_string_lossy(), &source_text))
+                    .unwrap_or_default()
+            },
+            |source_type| vec![JavaScriptSource::new(&source_text, source_type, 0)],
+        );
+        for source in sources {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source.source_text, source.source_type).parse();
+            let _ = Prettier::new(
+                &allocator,
+                source.source_text,
+                ret.trivias,
+                PrettierOptions::default(),
+            )
             .build(&ret.program);
+        }
     }
 }
@@ -4,6 +4,7 @@ use std::{
 };
 
 use ignore::{overrides::OverrideBuilder, DirEntry};
+use oxc_linter::partial_loader::LINT_PARTIAL_LOADER_EXT;
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::IgnoreOptions;
@@ -108,6 +109,8 @@ impl Walk {
             return false;
         }
         let Some(extension) = dir_entry.path().extension() else { return false };
-        VALID_EXTENSIONS.contains(&extension.to_string_lossy().as_ref())
+        let extension = extension.to_string_lossy();
+        VALID_EXTENSIONS.contains(&extension.as_ref())
+            || LINT_PARTIAL_LOADER_EXT.contains(&extension.as_ref())
     }
 }
@@ -882,6 +882,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for Expression<'a> {
             Self::TSTypeAssertion(e) => e.expression.gen_expr(p, precedence, ctx),
             Self::TSNonNullExpression(e) => e.expression.gen_expr(p, precedence, ctx),
             Self::TSInstantiationExpression(e) => e.expression.gen_expr(p, precedence, ctx),
+            Self::FlowTypeCastExpression(e) => e.expression.gen_expr(p, precedence, ctx),
         }
     }
 }
@@ -1683,7 +1684,10 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for NewExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         p.wrap(precedence > self.precedence(), |p| {
             p.print_str(b"new ");
-            self.callee.gen_expr(p, self.precedence(), ctx);
+            // `new (foo())()` and `new (foo().bar)()` call `foo` before `new`
+            p.wrap(has_call_in_member_chain(&self.callee), |p| {
+                self.callee.gen_expr(p, self.precedence(), ctx);
+            });
             p.wrap(true, |p| {
                 p.print_list(&self.arguments, ctx);
             });
@@ -1691,6 +1695,15 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for NewExpression<'a> {
     }
 }
 
+fn has_call_in_member_chain(expr: &Expression) -> bool {
+    match expr {
+        Expression::CallExpression(_) => true,
+        Expression::MemberExpression(member) => has_call_in_member_chain(member.object()),
+        Expression::ParenthesizedExpression(e) => has_call_in_member_chain(&e.expression),
+        _ => false,
+    }
+}
+
 impl<const MINIFY: bool> Gen<MINIFY> for MetaProperty {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
         self.meta.gen(p, ctx);
@@ -2022,6 +2035,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for PropertyDefinition<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for AccessorProperty<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
+        self.decorators.gen(p, ctx);
         if self.r#static {
             p.print_str(b"static ");
         }
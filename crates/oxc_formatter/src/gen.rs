@@ -457,7 +457,9 @@ impl<'a> Gen for Declaration<'a> {
             | Self::TSInterfaceDeclaration(_)
             | Self::TSEnumDeclaration(_)
             | Self::TSModuleDeclaration(_)
-            | Self::TSImportEqualsDeclaration(_) => {}
+            | Self::TSImportEqualsDeclaration(_)
+            | Self::FlowOpaqueTypeDeclaration(_)
+            | Self::FlowDeclareModuleExports(_) => {}
         }
     }
 }
@@ -804,6 +806,7 @@ impl<'a> Gen for Expression<'a> {
             Self::TSTypeAssertion(expr) => expr.expression.gen(p),
             Self::TSNonNullExpression(expr) => expr.expression.gen(p),
             Self::TSInstantiationExpression(expr) => expr.expression.gen(p),
+            Self::FlowTypeCastExpression(expr) => expr.expression.gen(p),
         }
     }
 }
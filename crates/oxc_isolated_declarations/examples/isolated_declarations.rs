@@ -0,0 +1,39 @@
+use std::{env, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_isolated_declarations::IsolatedDeclarations;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+// Instruction:
+// 1. create a `test.ts`
+// 2. run `cargo run -p oxc_isolated_declarations --example isolated_declarations`
+
+fn main() {
+    let name = env::args().nth(1).unwrap_or_else(|| "test.ts".to_string());
+    let path = Path::new(&name);
+    let source_text = std::fs::read_to_string(path).unwrap_or_else(|_| panic!("{name} not found"));
+    let source_type = SourceType::from_path(path).unwrap();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+
+    if !ret.errors.is_empty() {
+        for error in ret.errors {
+            let error = error.with_source_code(source_text.clone());
+            println!("{error:?}");
+        }
+        return;
+    }
+
+    println!("Original:");
+    println!("{source_text}");
+
+    let ret = IsolatedDeclarations::new(&source_text).build(&ret.program);
+    println!("Declarations:");
+    println!("{}", ret.source_text);
+
+    for error in ret.errors {
+        let error = error.with_source_code(source_text.clone());
+        println!("{error:?}");
+    }
+}
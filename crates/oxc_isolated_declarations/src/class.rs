@@ -0,0 +1,354 @@
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_span::{Atom, GetSpan};
+
+use crate::{
+    diagnostics, function::ReturnTypeKind, inferrer::is_entity_name_expression,
+    IsolatedDeclarations,
+};
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// `export declare abstract class A<T> extends B<T> implements C { ... }`
+    ///
+    /// Method bodies and initializers are removed, private members are emitted without types
+    /// and all `#private` members are collapsed into a single `#private;`.
+    pub(crate) fn emit_class(&mut self, class: &Class<'a>, prefix: &str, declare: bool) {
+        self.print_indent();
+        self.print_str(prefix);
+        if declare {
+            self.print_declare();
+        }
+        if class.modifiers.contains(ModifierKind::Abstract) {
+            self.print_str("abstract ");
+        }
+        self.print_str("class");
+        if let Some(id) = &class.id {
+            self.print_str(" ");
+            self.print_str(id.name.as_str());
+        }
+        self.print_type_parameters(class.type_parameters.as_deref());
+        if let Some(super_class) = &class.super_class {
+            if !is_entity_name_expression(super_class) {
+                self.error(diagnostics::ExtendsClauseExpression(super_class.span()));
+            }
+            self.print_str(" extends ");
+            self.print_source(super_class);
+            if let Some(type_parameters) = &class.super_type_parameters {
+                self.print_span(type_parameters.span);
+            }
+        }
+        if let Some(implements) = &class.implements {
+            self.print_str(" implements ");
+            for (i, implement) in implements.iter().enumerate() {
+                if i > 0 {
+                    self.print_str(", ");
+                }
+                self.print_span(implement.span);
+            }
+        }
+        self.print_str(" {\n");
+        self.indent();
+        if class
+            .body
+            .body
+            .iter()
+            .any(|element| element.property_key().is_some_and(PropertyKey::is_private_identifier))
+        {
+            self.print_line("#private;");
+        }
+        let overloaded = Self::collect_method_overloads(&class.body);
+        for element in &class.body.body {
+            self.emit_class_element(element, &class.body, &overloaded);
+        }
+        self.dedent();
+        self.print_line("}");
+    }
+
+    /// Methods with body-less signatures, their implementations are not emitted.
+    fn collect_method_overloads(body: &ClassBody<'a>) -> FxHashSet<(bool, Atom)> {
+        body.body
+            .iter()
+            .filter_map(|element| match element {
+                ClassElement::MethodDefinition(method) if method.value.body.is_none() => {
+                    let name = if method.kind == MethodDefinitionKind::Constructor {
+                        Some(Atom::from("constructor"))
+                    } else {
+                        method.key.static_name()
+                    };
+                    name.map(|name| (method.r#static, name))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn emit_class_element(
+        &mut self,
+        element: &ClassElement<'a>,
+        body: &ClassBody<'a>,
+        overloaded: &FxHashSet<(bool, Atom)>,
+    ) {
+        match element {
+            ClassElement::StaticBlock(_) => {}
+            ClassElement::MethodDefinition(method) => {
+                self.emit_method(method, false, body, overloaded);
+            }
+            ClassElement::TSAbstractMethodDefinition(method) => {
+                self.emit_method(&method.method_definition, true, body, overloaded);
+            }
+            ClassElement::PropertyDefinition(property) => self.emit_property(property, false),
+            ClassElement::TSAbstractPropertyDefinition(property) => {
+                self.emit_property(&property.property_definition, true);
+            }
+            ClassElement::AccessorProperty(property) => {
+                if property.key.is_private_identifier() {
+                    return;
+                }
+                self.print_indent();
+                if property.r#static {
+                    self.print_str("static ");
+                }
+                self.print_str("accessor ");
+                self.print_property_key(&property.key, property.computed);
+                self.print_str(": ");
+                let ty = property.value.as_ref().and_then(|value| self.infer_type(value, true));
+                if let Some(ty) = ty {
+                    self.print_str(&ty);
+                } else {
+                    self.error(diagnostics::PropertyMustHaveExplicitType(property.key.span()));
+                    self.print_str("unknown");
+                }
+                self.print_str(";\n");
+            }
+            ClassElement::TSIndexSignature(signature) => self.print_verbatim("", signature.span),
+        }
+    }
+
+    fn print_modifiers(
+        &mut self,
+        accessibility: Option<TSAccessibility>,
+        r#static: bool,
+        r#abstract: bool,
+        readonly: bool,
+    ) {
+        match accessibility {
+            Some(TSAccessibility::Private) => self.print_str("private "),
+            Some(TSAccessibility::Protected) => self.print_str("protected "),
+            Some(TSAccessibility::Public) => self.print_str("public "),
+            None => {}
+        }
+        if r#static {
+            self.print_str("static ");
+        }
+        if r#abstract {
+            self.print_str("abstract ");
+        }
+        if readonly {
+            self.print_str("readonly ");
+        }
+    }
+
+    fn emit_method(
+        &mut self,
+        method: &MethodDefinition<'a>,
+        r#abstract: bool,
+        body: &ClassBody<'a>,
+        overloaded: &FxHashSet<(bool, Atom)>,
+    ) {
+        if method.key.is_private_identifier() {
+            return;
+        }
+        if method.kind == MethodDefinitionKind::Constructor {
+            self.emit_constructor(method, overloaded);
+            return;
+        }
+        if method.value.body.is_some()
+            && method
+                .key
+                .static_name()
+                .is_some_and(|name| overloaded.contains(&(method.r#static, name)))
+        {
+            return;
+        }
+        self.print_indent();
+        self.print_modifiers(method.accessibility, method.r#static, r#abstract, false);
+        let is_private = matches!(method.accessibility, Some(TSAccessibility::Private));
+        match method.kind {
+            MethodDefinitionKind::Get => self.print_str("get "),
+            MethodDefinitionKind::Set => self.print_str("set "),
+            _ => {}
+        }
+        self.print_property_key(&method.key, method.computed);
+        if method.optional {
+            self.print_str("?");
+        }
+        if is_private && method.kind == MethodDefinitionKind::Method {
+            // Signatures of private methods are not visible, `private foo;`
+            self.print_str(";\n");
+            return;
+        }
+        let func = &method.value;
+        match method.kind {
+            MethodDefinitionKind::Get => {
+                self.print_str("()");
+                let return_type = func.return_type.as_deref().or_else(|| {
+                    Self::find_accessor_pair(body, method, MethodDefinitionKind::Set)
+                        .and_then(|setter| setter.value.params.items.first())
+                        .and_then(|param| param.pattern.type_annotation.as_deref())
+                });
+                if let Some(return_type) = return_type.filter(|_| !is_private) {
+                    self.print_type_annotation(return_type);
+                } else if !is_private {
+                    self.error(diagnostics::AccessorMustHaveExplicitReturnType(method.key.span()));
+                    self.print_str(": unknown");
+                }
+            }
+            MethodDefinitionKind::Set => {
+                self.print_str("(");
+                if let Some(param) = func.params.items.first() {
+                    self.print_binding_pattern_kind(&param.pattern.kind);
+                    let param_type = param.pattern.type_annotation.as_deref().or_else(|| {
+                        Self::find_accessor_pair(body, method, MethodDefinitionKind::Get)
+                            .and_then(|getter| getter.value.return_type.as_deref())
+                    });
+                    if let Some(param_type) = param_type.filter(|_| !is_private) {
+                        self.print_type_annotation(param_type);
+                    } else if !is_private {
+                        self.error(diagnostics::AccessorMustHaveExplicitReturnType(
+                            method.key.span(),
+                        ));
+                        self.print_str(": unknown");
+                    }
+                }
+                self.print_str(")");
+            }
+            _ => self.print_function_signature(func, ReturnTypeKind::Method),
+        }
+        self.print_str(";\n");
+    }
+
+    /// Find the getter of a setter or the setter of a getter.
+    fn find_accessor_pair<'b>(
+        body: &'b ClassBody<'a>,
+        method: &MethodDefinition<'a>,
+        kind: MethodDefinitionKind,
+    ) -> Option<&'b MethodDefinition<'a>> {
+        let name = method.key.static_name()?;
+        body.body.iter().find_map(|element| match element {
+            ClassElement::MethodDefinition(pair)
+                if pair.kind == kind
+                    && pair.r#static == method.r#static
+                    && pair.key.static_name().is_some_and(|n| n == name) =>
+            {
+                Some(&**pair)
+            }
+            _ => None,
+        })
+    }
+
+    /// Parameter properties are emitted as properties before the constructor,
+    /// `constructor(private a: string)` -> `private a; constructor(a: string);`
+    fn emit_constructor(
+        &mut self,
+        method: &MethodDefinition<'a>,
+        overloaded: &FxHashSet<(bool, Atom)>,
+    ) {
+        let params = &method.value.params;
+        for param in &params.items {
+            if param.accessibility.is_none() && !param.readonly {
+                continue;
+            }
+            let (pattern, init) = match &param.pattern.kind {
+                BindingPatternKind::AssignmentPattern(assignment) => {
+                    (&assignment.left, Some(&assignment.right))
+                }
+                _ => (&param.pattern, None),
+            };
+            self.print_indent();
+            self.print_modifiers(param.accessibility, false, false, param.readonly);
+            self.print_binding_pattern_kind(&pattern.kind);
+            if pattern.optional || init.is_some() {
+                self.print_str("?");
+            }
+            if !matches!(param.accessibility, Some(TSAccessibility::Private)) {
+                if let Some(type_annotation) = &pattern.type_annotation {
+                    self.print_type_annotation(type_annotation);
+                } else if let Some(ty) = init.and_then(|init| self.infer_type(init, true)) {
+                    self.print_str(": ");
+                    self.print_str(&ty);
+                } else {
+                    self.error(diagnostics::ParameterMustHaveExplicitType(param.span));
+                    self.print_str(": unknown");
+                }
+            }
+            self.print_str(";\n");
+        }
+
+        if method.value.body.is_some() && overloaded.contains(&(false, Atom::from("constructor"))) {
+            return;
+        }
+        self.print_indent();
+        self.print_modifiers(method.accessibility, false, false, false);
+        if matches!(method.accessibility, Some(TSAccessibility::Private)) {
+            self.print_str("constructor();\n");
+            return;
+        }
+        self.print_str("constructor");
+        self.print_formal_parameters(params);
+        self.print_str(";\n");
+    }
+
+    /// `public static readonly a: number;`
+    fn emit_property(&mut self, property: &PropertyDefinition<'a>, r#abstract: bool) {
+        if property.key.is_private_identifier() {
+            return;
+        }
+        self.print_indent();
+        self.print_modifiers(
+            property.accessibility,
+            property.r#static,
+            r#abstract,
+            property.readonly,
+        );
+        self.print_property_key(&property.key, property.computed);
+        if property.optional {
+            self.print_str("?");
+        }
+        if matches!(property.accessibility, Some(TSAccessibility::Private)) {
+            self.print_str(";\n");
+            return;
+        }
+        if let Some(type_annotation) = &property.type_annotation {
+            self.print_type_annotation(type_annotation);
+        } else {
+            let value = property.value.as_ref();
+            match value.and_then(|value| self.infer_type(value, !property.readonly)) {
+                // `readonly a = 1` keeps the literal initializer.
+                Some(ty) if property.readonly && value.is_some_and(is_literal) => {
+                    self.print_str(" = ");
+                    self.print_str(&ty);
+                }
+                Some(ty) => {
+                    self.print_str(": ");
+                    self.print_str(&ty);
+                }
+                None => {
+                    self.error(diagnostics::PropertyMustHaveExplicitType(property.key.span()));
+                    self.print_str(": unknown");
+                }
+            }
+        }
+        self.print_str(";\n");
+    }
+}
+
+fn is_literal(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::NumberLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::BooleanLiteral(_)
+    )
+}
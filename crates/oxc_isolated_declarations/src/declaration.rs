@@ -0,0 +1,184 @@
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{diagnostics, IsolatedDeclarations};
+
+impl<'a> IsolatedDeclarations<'a> {
+    pub(crate) fn emit_declaration(&mut self, decl: &Declaration<'a>, export: bool) {
+        let prefix = if export { "export " } else { "" };
+        match decl {
+            Declaration::VariableDeclaration(decl) => self.emit_variable_declaration(decl, prefix),
+            Declaration::FunctionDeclaration(func) => self.emit_function(func, prefix, true),
+            Declaration::ClassDeclaration(class) => self.emit_class(class, prefix, true),
+            Declaration::TSEnumDeclaration(decl) => self.emit_enum(decl, prefix),
+            Declaration::TSModuleDeclaration(decl) => self.emit_module(decl, prefix),
+            Declaration::TSTypeAliasDeclaration(decl) => self.print_verbatim(prefix, decl.span),
+            Declaration::TSInterfaceDeclaration(decl) => self.print_verbatim(prefix, decl.span),
+            Declaration::TSImportEqualsDeclaration(decl) => {
+                self.print_verbatim(prefix, decl.span);
+            }
+            // `using` declarations are block scoped and can not be exported.
+            // Flow declarations are not in TypeScript declaration files.
+            Declaration::UsingDeclaration(_)
+            | Declaration::FlowOpaqueTypeDeclaration(_)
+            | Declaration::FlowDeclareModuleExports(_) => {}
+        }
+    }
+
+    /// `export declare const a: number, b = 1;`
+    fn emit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>, prefix: &str) {
+        let mut declarators = vec![];
+        for declarator in &decl.declarations {
+            if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+                declarators.push((ident, declarator));
+            } else {
+                self.error(diagnostics::BindingElementExport(declarator.id.span()));
+            }
+        }
+        if declarators.is_empty() {
+            return;
+        }
+        self.print_indent();
+        self.print_str(prefix);
+        self.print_declare();
+        self.print_str(decl.kind.as_str());
+        self.print_str(" ");
+        for (i, (ident, declarator)) in declarators.into_iter().enumerate() {
+            if i > 0 {
+                self.print_str(", ");
+            }
+            self.print_str(ident.name.as_str());
+            if let Some(type_annotation) = &declarator.id.type_annotation {
+                self.print_type_annotation(type_annotation);
+                continue;
+            }
+            let Some(init) = &declarator.init else {
+                self.error(diagnostics::VariableMustHaveExplicitType(declarator.id.span()));
+                self.print_str(": unknown");
+                continue;
+            };
+            let is_const = decl.kind.is_const();
+            match self.infer_type(init, !is_const) {
+                // `const a = 1` keeps the initializer, e.g. `declare const a = 1;`
+                Some(ty) if is_const && is_literal_type(init) => {
+                    self.print_str(" = ");
+                    self.print_str(&ty);
+                }
+                Some(ty) => {
+                    self.print_str(": ");
+                    self.print_str(&ty);
+                }
+                None => {
+                    self.error(diagnostics::VariableMustHaveExplicitType(declarator.id.span()));
+                    self.print_str(": unknown");
+                }
+            }
+        }
+        self.print_str(";\n");
+    }
+
+    /// `export declare const enum A { B = 0, C = 1 }`
+    fn emit_enum(&mut self, decl: &TSEnumDeclaration<'a>, prefix: &str) {
+        self.print_indent();
+        self.print_str(prefix);
+        self.print_declare();
+        if decl.modifiers.contains(ModifierKind::Const) {
+            self.print_str("const ");
+        }
+        self.print_str("enum ");
+        self.print_str(decl.id.name.as_str());
+        self.print_str(" {\n");
+        self.indent();
+        // Members without initializers are auto-incremented from the previous numeric member.
+        let mut next_value = Some(0f64);
+        for member in &decl.body.members {
+            self.print_indent();
+            self.print_span(Span::new(member.span.start, member_name_end(member)));
+            match &member.initializer {
+                Some(init) => {
+                    next_value = evaluate_number(init).map(|value| value + 1.0);
+                    self.print_str(" = ");
+                    self.print_source(init);
+                }
+                None => {
+                    if let Some(value) = next_value {
+                        self.print_str(&format!(" = {value}"));
+                        next_value = Some(value + 1.0);
+                    }
+                }
+            }
+            self.print_str(",\n");
+        }
+        self.dedent();
+        self.print_line("}");
+    }
+
+    /// `export declare namespace A { ... }`
+    fn emit_module(&mut self, decl: &TSModuleDeclaration<'a>, prefix: &str) {
+        // Ambient modules, e.g. `declare module "foo" {}` and `declare global {}`, are already declarations.
+        if self.ambient || decl.modifiers.contains(ModifierKind::Declare) {
+            self.print_indent();
+            self.print_str(prefix);
+            // The span of the declaration does not include the `declare` modifier.
+            if !self.source(decl.span).starts_with("declare") {
+                self.print_declare();
+            }
+            self.print_span(decl.span);
+            self.print_str("\n");
+            return;
+        }
+        self.print_indent();
+        self.print_str(prefix);
+        self.print_declare();
+        self.print_str("namespace ");
+        self.print_str(decl.id.name().as_str());
+        let mut body = &decl.body;
+        while let TSModuleDeclarationBody::TSModuleDeclaration(decl) = body {
+            self.print_str(".");
+            self.print_str(decl.id.name().as_str());
+            body = &decl.body;
+        }
+        let TSModuleDeclarationBody::TSModuleBlock(block) = body else { unreachable!() };
+        self.print_str(" {\n");
+        self.indent();
+        let ambient = std::mem::replace(&mut self.ambient, true);
+        self.emit_statements(&block.body, /* exports_only */ true);
+        self.ambient = ambient;
+        self.dedent();
+        self.print_line("}");
+    }
+}
+
+/// Literal initializers of `const` declarations are kept as is, e.g. `declare const a = 1;`
+fn is_literal_type(expr: &Expression) -> bool {
+    match expr {
+        Expression::NumberLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::BooleanLiteral(_) => true,
+        Expression::UnaryExpression(unary) => unary.operator == UnaryOperator::UnaryNegation,
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        _ => false,
+    }
+}
+
+fn member_name_end(member: &TSEnumMember) -> u32 {
+    match &member.id {
+        TSEnumMemberName::Identifier(ident) => ident.span.end,
+        TSEnumMemberName::StringLiteral(lit) => lit.span.end,
+        TSEnumMemberName::ComputedPropertyName(expr) => expr.span().end,
+        TSEnumMemberName::NumberLiteral(lit) => lit.span.end,
+    }
+}
+
+fn evaluate_number(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(lit) => Some(lit.value),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            evaluate_number(&unary.argument).map(|value| -value)
+        }
+        Expression::ParenthesizedExpression(expr) => evaluate_number(&expr.expression),
+        _ => None,
+    }
+}
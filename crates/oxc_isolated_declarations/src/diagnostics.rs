@@ -0,0 +1,67 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_span::Span;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "TS9007: Function must have an explicit return type annotation with --isolatedDeclarations."
+)]
+#[diagnostic()]
+pub struct FunctionMustHaveExplicitReturnType(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9008: Method must have an explicit return type annotation with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct MethodMustHaveExplicitReturnType(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9009: At least one accessor must have an explicit return type annotation with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct AccessorMustHaveExplicitReturnType(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9010: Variable must have an explicit type annotation with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct VariableMustHaveExplicitType(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9011: Parameter must have an explicit type annotation with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct ParameterMustHaveExplicitType(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9012: Property must have an explicit type annotation with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct PropertyMustHaveExplicitType(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9013: Expression type can't be inferred with --isolatedDeclarations.")]
+#[diagnostic(help("Add an explicit type annotation."))]
+pub struct InferenceFailed(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9015: Objects that contain spread assignments can't be inferred with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct ObjectWithSpreadAssignments(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9017: Only const arrays can be inferred with --isolatedDeclarations.")]
+#[diagnostic(help("Add a type annotation or use `as const`."))]
+pub struct OnlyConstArrays(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9019: Binding elements can't be exported directly with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct BindingElementExport(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9021: Extends clause can't contain an expression with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct ExtendsClauseExpression(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS9037: Default exports can't be inferred with --isolatedDeclarations.")]
+#[diagnostic()]
+pub struct DefaultExportInferred(#[label] pub Span);
@@ -0,0 +1,173 @@
+use oxc_ast::ast::*;
+use oxc_span::Span;
+
+use crate::{diagnostics, IsolatedDeclarations};
+
+/// Which diagnostic to report when a return type is missing.
+#[derive(Debug, Clone, Copy)]
+pub enum ReturnTypeKind {
+    Function,
+    Method,
+}
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// `export declare function foo<T>(a: T): T;`
+    pub(crate) fn emit_function(&mut self, func: &Function<'a>, prefix: &str, declare: bool) {
+        if func.body.is_some()
+            && func.id.as_ref().is_some_and(|id| self.overloaded.contains(&id.name))
+        {
+            // The implementation signature of an overloaded function is not visible.
+            return;
+        }
+        self.print_indent();
+        self.print_str(prefix);
+        if declare {
+            self.print_declare();
+        }
+        self.print_str("function");
+        if let Some(id) = &func.id {
+            self.print_str(" ");
+            self.print_str(id.name.as_str());
+        }
+        self.print_function_signature(func, ReturnTypeKind::Function);
+        self.print_str(";\n");
+    }
+
+    /// `<T>(a: T): T` of a function or method
+    pub(crate) fn print_function_signature(&mut self, func: &Function<'a>, kind: ReturnTypeKind) {
+        self.print_type_parameters(func.type_parameters.as_deref());
+        self.print_formal_parameters(&func.params);
+        self.print_return_type(func.return_type.as_deref(), func.span, kind);
+    }
+
+    pub(crate) fn print_return_type(
+        &mut self,
+        return_type: Option<&TSTypeAnnotation<'a>>,
+        span: Span,
+        kind: ReturnTypeKind,
+    ) {
+        if let Some(return_type) = return_type {
+            self.print_type_annotation(return_type);
+        } else {
+            match kind {
+                ReturnTypeKind::Function => {
+                    self.error(diagnostics::FunctionMustHaveExplicitReturnType(span));
+                }
+                ReturnTypeKind::Method => {
+                    self.error(diagnostics::MethodMustHaveExplicitReturnType(span));
+                }
+            }
+            self.print_str(": unknown");
+        }
+    }
+
+    /// `(a: T, b?: U, ...rest: V[])`
+    ///
+    /// Initializers are removed and the parameter becomes optional
+    /// when it is not followed by a required parameter.
+    pub(crate) fn print_formal_parameters(&mut self, params: &FormalParameters<'a>) {
+        self.print_str("(");
+        // Index of the last required parameter, parameters before it can not be optional.
+        let last_required = params.items.iter().rposition(|param| {
+            !param.pattern.optional
+                && !matches!(param.pattern.kind, BindingPatternKind::AssignmentPattern(_))
+        });
+        for (i, param) in params.items.iter().enumerate() {
+            if i > 0 {
+                self.print_str(", ");
+            }
+            let optional = last_required.map_or(true, |last| i > last);
+            self.print_formal_parameter(param, optional);
+        }
+        if let Some(rest) = &params.rest {
+            if !params.items.is_empty() {
+                self.print_str(", ");
+            }
+            self.print_str("...");
+            self.print_binding_pattern_kind(&rest.argument.kind);
+            if let Some(type_annotation) = &rest.argument.type_annotation {
+                self.print_type_annotation(type_annotation);
+            } else {
+                self.error(diagnostics::ParameterMustHaveExplicitType(rest.span));
+                self.print_str(": unknown[]");
+            }
+        }
+        self.print_str(")");
+    }
+
+    fn print_formal_parameter(&mut self, param: &FormalParameter<'a>, optional: bool) {
+        let (pattern, init) = match &param.pattern.kind {
+            BindingPatternKind::AssignmentPattern(assignment) => {
+                (&assignment.left, Some(&assignment.right))
+            }
+            _ => (&param.pattern, None),
+        };
+        self.print_binding_pattern_kind(&pattern.kind);
+        if pattern.optional || (init.is_some() && optional) {
+            self.print_str("?");
+        }
+        if let Some(type_annotation) = &pattern.type_annotation {
+            self.print_type_annotation(type_annotation);
+        } else if let Some(inferred) = init.and_then(|init| self.infer_type(init, true)) {
+            self.print_str(": ");
+            self.print_str(&inferred);
+        } else {
+            self.error(diagnostics::ParameterMustHaveExplicitType(param.span));
+            self.print_str(": unknown");
+        }
+        // `function foo(a = 1, b: string)` -> `declare function foo(a: number | undefined, b: string)`
+        if init.is_some() && !optional {
+            self.print_str(" | undefined");
+        }
+    }
+
+    /// Print the bound names of a pattern without initializers, e.g. `{ a, b: [c] }`
+    pub(crate) fn print_binding_pattern_kind(&mut self, kind: &BindingPatternKind<'a>) {
+        match kind {
+            BindingPatternKind::BindingIdentifier(ident) => self.print_str(ident.name.as_str()),
+            BindingPatternKind::ObjectPattern(pattern) => {
+                self.print_str("{ ");
+                for (i, property) in pattern.properties.iter().enumerate() {
+                    if i > 0 {
+                        self.print_str(", ");
+                    }
+                    if !property.shorthand {
+                        self.print_property_key(&property.key, property.computed);
+                        self.print_str(": ");
+                    }
+                    self.print_binding_pattern_kind(&property.value.kind);
+                }
+                if let Some(rest) = &pattern.rest {
+                    if !pattern.properties.is_empty() {
+                        self.print_str(", ");
+                    }
+                    self.print_str("...");
+                    self.print_binding_pattern_kind(&rest.argument.kind);
+                }
+                self.print_str(" }");
+            }
+            BindingPatternKind::ArrayPattern(pattern) => {
+                self.print_str("[");
+                for (i, element) in pattern.elements.iter().enumerate() {
+                    if i > 0 {
+                        self.print_str(", ");
+                    }
+                    if let Some(element) = element {
+                        self.print_binding_pattern_kind(&element.kind);
+                    }
+                }
+                if let Some(rest) = &pattern.rest {
+                    if !pattern.elements.is_empty() {
+                        self.print_str(", ");
+                    }
+                    self.print_str("...");
+                    self.print_binding_pattern_kind(&rest.argument.kind);
+                }
+                self.print_str("]");
+            }
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                self.print_binding_pattern_kind(&pattern.left.kind);
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{diagnostics, function::ReturnTypeKind, IsolatedDeclarations};
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// Infer the type of an initializer from its syntax alone.
+    ///
+    /// Literal types are widened to their primitive types when `widen` is set,
+    /// e.g. `let a = 1` is declared as `number` while `const a = 1` keeps the literal type `1`.
+    ///
+    /// Returns `None` when the type can not be inferred without a type checker.
+    pub(crate) fn infer_type(&mut self, expr: &Expression<'a>, widen: bool) -> Option<String> {
+        self.infer_expression(expr, widen, false)
+    }
+
+    fn infer_expression(
+        &mut self,
+        expr: &Expression<'a>,
+        widen: bool,
+        const_context: bool,
+    ) -> Option<String> {
+        let widen = widen && !const_context;
+        let ty = match expr {
+            Expression::NumberLiteral(lit) => widen_or(widen, "number", self.source(lit.span)),
+            Expression::BigintLiteral(lit) => widen_or(widen, "bigint", self.source(lit.span)),
+            Expression::StringLiteral(lit) => widen_or(widen, "string", self.source(lit.span)),
+            Expression::BooleanLiteral(lit) => {
+                widen_or(widen, "boolean", if lit.value { "true" } else { "false" })
+            }
+            Expression::NullLiteral(_) => "null".into(),
+            Expression::RegExpLiteral(_) => "RegExp".into(),
+            Expression::Identifier(ident) if ident.name == "undefined" => "undefined".into(),
+            Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+                if widen {
+                    "string".into()
+                } else {
+                    let raw = lit.quasis.first().map_or("", |quasi| quasi.value.raw.as_str());
+                    format!("\"{}\"", raw.replace('"', "\\\""))
+                }
+            }
+            Expression::TemplateLiteral(_) => "string".into(),
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation
+                    && matches!(
+                        unary.argument,
+                        Expression::NumberLiteral(_) | Expression::BigintLiteral(_)
+                    ) =>
+            {
+                let keyword = if matches!(unary.argument, Expression::NumberLiteral(_)) {
+                    "number"
+                } else {
+                    "bigint"
+                };
+                widen_or(widen, keyword, self.source(unary.span))
+            }
+            Expression::ParenthesizedExpression(e) => {
+                return self.infer_expression(&e.expression, widen, const_context)
+            }
+            Expression::TSAsExpression(e) if e.type_annotation.is_const_type_reference() => {
+                return self.infer_expression(&e.expression, false, true)
+            }
+            Expression::TSAsExpression(e) => self.source(e.type_annotation.span()).into(),
+            Expression::TSTypeAssertion(e) => self.source(e.type_annotation.span()).into(),
+            Expression::TSSatisfiesExpression(e) => {
+                return self.infer_expression(&e.expression, widen, const_context)
+            }
+            Expression::TSNonNullExpression(e) => {
+                return self.infer_expression(&e.expression, widen, const_context)
+            }
+            Expression::ArrowExpression(arrow) => {
+                let start = self.code.len();
+                self.print_type_parameters(arrow.type_parameters.as_deref());
+                self.print_formal_parameters(&arrow.params);
+                self.print_str(" => ");
+                if let Some(return_type) = &arrow.return_type {
+                    self.print_source(&return_type.type_annotation);
+                } else {
+                    self.error(diagnostics::FunctionMustHaveExplicitReturnType(arrow.span));
+                    self.print_str("unknown");
+                }
+                self.code.split_off(start)
+            }
+            Expression::FunctionExpression(func) => {
+                let start = self.code.len();
+                self.print_type_parameters(func.type_parameters.as_deref());
+                self.print_formal_parameters(&func.params);
+                self.print_str(" => ");
+                if let Some(return_type) = &func.return_type {
+                    self.print_source(&return_type.type_annotation);
+                } else {
+                    self.error(diagnostics::FunctionMustHaveExplicitReturnType(func.span));
+                    self.print_str("unknown");
+                }
+                self.code.split_off(start)
+            }
+            Expression::ObjectExpression(object) => {
+                return self.infer_object_expression(object, const_context)
+            }
+            Expression::ArrayExpression(array) => {
+                return self.infer_array_expression(array, const_context)
+            }
+            _ => return None,
+        };
+        Some(ty)
+    }
+
+    /// `{ a: 1, b() {} }` -> `{ a: number; b(): void; }`
+    fn infer_object_expression(
+        &mut self,
+        object: &ObjectExpression<'a>,
+        const_context: bool,
+    ) -> Option<String> {
+        let start = self.code.len();
+        let mut inferred = true;
+        self.print_str("{\n");
+        self.indent();
+        for property in &object.properties {
+            let property = match property {
+                ObjectPropertyKind::ObjectProperty(property) => property,
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    self.error(diagnostics::ObjectWithSpreadAssignments(spread.span));
+                    inferred = false;
+                    continue;
+                }
+            };
+            if property.computed
+                && !matches!(&property.key, PropertyKey::Expression(expr) if is_entity_name_expression(expr))
+            {
+                self.error(diagnostics::InferenceFailed(property.key.span()));
+                inferred = false;
+                continue;
+            }
+            self.print_indent();
+            match (&property.kind, &property.value) {
+                (PropertyKind::Get, Expression::FunctionExpression(func)) => {
+                    self.print_str("get ");
+                    self.print_property_key(&property.key, property.computed);
+                    self.print_str("()");
+                    self.print_return_type(
+                        func.return_type.as_deref(),
+                        func.span,
+                        ReturnTypeKind::Method,
+                    );
+                }
+                (PropertyKind::Set, Expression::FunctionExpression(func)) => {
+                    self.print_str("set ");
+                    self.print_property_key(&property.key, property.computed);
+                    self.print_formal_parameters(&func.params);
+                }
+                (_, Expression::FunctionExpression(func)) if property.method => {
+                    self.print_property_key(&property.key, property.computed);
+                    self.print_function_signature(func, ReturnTypeKind::Method);
+                }
+                (_, value) => {
+                    if const_context {
+                        self.print_str("readonly ");
+                    }
+                    self.print_property_key(&property.key, property.computed);
+                    self.print_str(": ");
+                    if let Some(ty) = self.infer_expression(value, true, const_context) {
+                        self.print_str(&ty);
+                    } else {
+                        self.error(diagnostics::InferenceFailed(value.span()));
+                        inferred = false;
+                    }
+                }
+            }
+            self.print_str(";\n");
+        }
+        self.dedent();
+        self.print_indent();
+        self.print_str("}");
+        let ty = self.code.split_off(start);
+        inferred.then_some(ty)
+    }
+
+    /// `[1, "a"] as const` -> `readonly [1, "a"]`
+    fn infer_array_expression(
+        &mut self,
+        array: &ArrayExpression<'a>,
+        const_context: bool,
+    ) -> Option<String> {
+        if !const_context {
+            self.error(diagnostics::OnlyConstArrays(array.span));
+            return None;
+        }
+        let mut elements = Vec::with_capacity(array.elements.len());
+        for element in &array.elements {
+            match element {
+                ArrayExpressionElement::Expression(expr) => {
+                    let Some(ty) = self.infer_expression(expr, false, true) else {
+                        self.error(diagnostics::InferenceFailed(expr.span()));
+                        return None;
+                    };
+                    elements.push(ty);
+                }
+                ArrayExpressionElement::Elision(_) => elements.push("undefined".into()),
+                ArrayExpressionElement::SpreadElement(spread) => {
+                    self.error(diagnostics::InferenceFailed(spread.span));
+                    return None;
+                }
+            }
+        }
+        Some(format!("readonly [{}]", elements.join(", ")))
+    }
+}
+
+fn widen_or(widen: bool, keyword: &str, literal: &str) -> String {
+    if widen { keyword } else { literal }.to_string()
+}
+
+/// Entity names are identifiers and property accesses on them, e.g. `Symbol.iterator`.
+/// Computed keys and `extends` clauses must be entity names.
+pub fn is_entity_name_expression(expr: &Expression) -> bool {
+    match expr {
+        Expression::Identifier(_) | Expression::StringLiteral(_) | Expression::NumberLiteral(_) => {
+            true
+        }
+        Expression::MemberExpression(member) => match &**member {
+            MemberExpression::StaticMemberExpression(member) => {
+                is_entity_name_expression(&member.object)
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,177 @@
+#![allow(clippy::wildcard_imports)]
+
+//! Isolated Declarations
+//!
+//! Emits `.d.ts` declaration files for modules that follow the `--isolatedDeclarations` rules,
+//! i.e. every exported symbol can have its type computed from the syntax of a single file.
+//!
+//! References:
+//! * <https://www.typescriptlang.org/tsconfig#isolatedDeclarations>
+//! * <https://github.com/microsoft/TypeScript/pull/53463>
+//! * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformers/declarations.ts>
+
+mod class;
+mod declaration;
+mod diagnostics;
+mod function;
+mod inferrer;
+mod module;
+mod visibility;
+
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::Error;
+use oxc_span::{Atom, GetSpan, Span};
+
+pub struct IsolatedDeclarationsReturn {
+    /// The emitted `.d.ts` source text
+    pub source_text: String,
+    pub errors: Vec<Error>,
+}
+
+pub struct IsolatedDeclarations<'a> {
+    source_text: &'a str,
+
+    /// Output Code
+    code: String,
+
+    errors: Vec<Error>,
+
+    /// Track the current indentation level
+    indentation: usize,
+
+    /// Inside an ambient context such as `declare namespace`, where `declare` is implied.
+    ambient: bool,
+
+    /// Names of functions which have overload signatures in the current statement list,
+    /// their implementations are not emitted.
+    overloaded: FxHashSet<Atom>,
+}
+
+impl<'a> IsolatedDeclarations<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self {
+            source_text,
+            code: String::with_capacity(source_text.len() / 2),
+            errors: vec![],
+            indentation: 0,
+            ambient: false,
+            overloaded: FxHashSet::default(),
+        }
+    }
+
+    pub fn build(mut self, program: &Program<'a>) -> IsolatedDeclarationsReturn {
+        let has_module_syntax =
+            program.body.iter().any(|stmt| matches!(stmt, Statement::ModuleDeclaration(_)));
+        let emitted_module_syntax = self.emit_statements(&program.body, has_module_syntax);
+        // `export {}` keeps the declaration file a module when all imports and exports were elided.
+        if has_module_syntax && !emitted_module_syntax {
+            self.print_line("export {};");
+        }
+        IsolatedDeclarationsReturn { source_text: self.code, errors: self.errors }
+    }
+
+    /// Functions with body-less signatures, e.g. `function foo(a: string): void;`
+    fn collect_overloads(stmts: &[Statement<'a>]) -> FxHashSet<Atom> {
+        stmts
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::Declaration(Declaration::FunctionDeclaration(func)) => Some(func),
+                Statement::ModuleDeclaration(decl) => match &**decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+                        Some(Declaration::FunctionDeclaration(func)) => Some(func),
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            })
+            .filter(|func| func.body.is_none())
+            .filter_map(|func| func.id.as_ref().map(|id| id.name.clone()))
+            .collect()
+    }
+
+    /* Printer */
+
+    fn source(&self, span: Span) -> &'a str {
+        span.source_text(self.source_text)
+    }
+
+    fn print_str(&mut self, s: &str) {
+        self.code.push_str(s);
+    }
+
+    fn print_span(&mut self, span: Span) {
+        let s = self.source(span);
+        self.print_str(s);
+    }
+
+    fn print_source<T: GetSpan>(&mut self, node: &T) {
+        self.print_span(node.span());
+    }
+
+    fn print_indent(&mut self) {
+        for _ in 0..self.indentation {
+            self.code.push_str("    ");
+        }
+    }
+
+    fn print_line(&mut self, s: &str) {
+        self.print_indent();
+        self.print_str(s);
+        self.code.push('\n');
+    }
+
+    fn indent(&mut self) {
+        self.indentation += 1;
+    }
+
+    fn dedent(&mut self) {
+        self.indentation -= 1;
+    }
+
+    /// Print a declaration copied verbatim from the source, e.g. an interface or a type alias.
+    fn print_verbatim(&mut self, prefix: &str, span: Span) {
+        self.print_indent();
+        self.print_str(prefix);
+        self.print_span(span);
+        if !self.code.ends_with(';') && !self.code.ends_with('}') {
+            self.print_str(";");
+        }
+        self.code.push('\n');
+    }
+
+    /// `: T` for an explicit type annotation
+    fn print_type_annotation(&mut self, annotation: &TSTypeAnnotation<'a>) {
+        self.print_str(": ");
+        self.print_source(&annotation.type_annotation);
+    }
+
+    fn print_type_parameters(&mut self, params: Option<&TSTypeParameterDeclaration<'a>>) {
+        if let Some(params) = params {
+            self.print_span(params.span);
+        }
+    }
+
+    fn print_property_key(&mut self, key: &PropertyKey<'a>, computed: bool) {
+        if computed {
+            self.print_str("[");
+            self.print_source(key);
+            self.print_str("]");
+        } else {
+            self.print_source(key);
+        }
+    }
+
+    /// Print `declare` unless it is implied by the surrounding ambient context.
+    fn print_declare(&mut self) {
+        if !self.ambient {
+            self.print_str("declare ");
+        }
+    }
+
+    fn error<T: Into<Error>>(&mut self, error: T) {
+        self.errors.push(error.into());
+    }
+}
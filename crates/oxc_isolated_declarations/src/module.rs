@@ -0,0 +1,65 @@
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+
+use crate::{diagnostics, IsolatedDeclarations};
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// Exports are kept, exported declarations are emitted with `export`.
+    pub(crate) fn emit_module_declaration(&mut self, decl: &ModuleDeclaration<'a>) {
+        match decl {
+            ModuleDeclaration::ImportDeclaration(decl) => {
+                self.emit_import(decl, None);
+            }
+            ModuleDeclaration::ExportAllDeclaration(decl) => self.print_verbatim("", decl.span),
+            ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(declaration) => self.emit_declaration(declaration, true),
+                None => self.print_verbatim("", decl.span),
+            },
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => self.emit_export_default(decl),
+            ModuleDeclaration::TSExportAssignment(decl) => {
+                self.emit_export_expression(&decl.expression, "export = ");
+            }
+            ModuleDeclaration::TSNamespaceExportDeclaration(decl) => {
+                self.print_verbatim("", decl.span);
+            }
+        }
+    }
+
+    fn emit_export_default(&mut self, decl: &ExportDefaultDeclaration<'a>) {
+        const PREFIX: &str = "export default ";
+        match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                self.emit_function(func, PREFIX, false);
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                self.emit_class(class, PREFIX, false);
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                self.print_verbatim(PREFIX, decl.span);
+            }
+            ExportDefaultDeclarationKind::TSEnumDeclaration(decl) => {
+                self.print_verbatim(PREFIX, decl.span);
+            }
+            ExportDefaultDeclarationKind::Expression(expr) => {
+                self.emit_export_expression(expr, PREFIX);
+            }
+        }
+    }
+
+    /// `export default a;` is kept as is,
+    /// other expressions are declared as `declare const _default: T; export default _default;`
+    fn emit_export_expression(&mut self, expr: &Expression<'a>, prefix: &str) {
+        if let Expression::Identifier(ident) = expr {
+            self.print_line(&format!("{prefix}{};", ident.name));
+            return;
+        }
+        let ty = self.infer_type(expr, false).unwrap_or_else(|| {
+            self.error(diagnostics::DefaultExportInferred(expr.span()));
+            "unknown".into()
+        });
+        self.print_indent();
+        self.print_declare();
+        self.print_str(&format!("const _default: {ty};\n"));
+        self.print_line(&format!("{prefix}_default;"));
+    }
+}
@@ -0,0 +1,247 @@
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::Error;
+use oxc_span::Atom;
+
+use crate::IsolatedDeclarations;
+
+/// The emitted declarations of a statement
+struct Emitted {
+    code: String,
+    errors: Vec<Error>,
+}
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// Emit the declarations of a statement list,
+    /// returns `true` if any import or export was emitted.
+    ///
+    /// In a module or a namespace, only the exports are emitted, together with the local
+    /// declarations and imports they reference. Top-level declarations of a script are global
+    /// and are all emitted.
+    pub(crate) fn emit_statements(&mut self, stmts: &[Statement<'a>], exports_only: bool) -> bool {
+        let overloaded = std::mem::replace(&mut self.overloaded, Self::collect_overloads(stmts));
+
+        // Statements are emitted first, and kept when they are visible
+        let mut emitted: Vec<Option<Emitted>> = stmts
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::ModuleDeclaration(decl) if decl.is_import() => None,
+                Statement::Declaration(decl) => {
+                    Some(self.emit_separately(|this| this.emit_declaration(decl, false)))
+                }
+                Statement::ModuleDeclaration(decl) => {
+                    Some(self.emit_separately(|this| this.emit_module_declaration(decl)))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut visible = vec![!exports_only; stmts.len()];
+        let mut referenced = FxHashSet::default();
+        if exports_only {
+            let mut pending = vec![];
+            for (i, stmt) in stmts.iter().enumerate() {
+                if emitted[i].is_some() && is_always_visible(stmt) {
+                    visible[i] = true;
+                    pending.push(i);
+                }
+            }
+            // Locals referenced by the visible declarations are visible as well
+            while let Some(i) = pending.pop() {
+                let code = emitted[i].as_ref().map_or("", |emitted| emitted.code.as_str());
+                for name in referenced_names(code) {
+                    if !referenced.insert(Atom::from(name)) {
+                        continue;
+                    }
+                    for (j, stmt) in stmts.iter().enumerate() {
+                        if !visible[j] && emitted[j].is_some() && declares(stmt, name) {
+                            visible[j] = true;
+                            pending.push(j);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut emitted_module_syntax = false;
+        for (i, stmt) in stmts.iter().enumerate() {
+            match stmt {
+                Statement::ModuleDeclaration(decl) => {
+                    if let ModuleDeclaration::ImportDeclaration(decl) = &**decl {
+                        emitted_module_syntax |=
+                            self.emit_import(decl, exports_only.then_some(&referenced));
+                    } else if visible[i] {
+                        emitted_module_syntax = true;
+                    }
+                }
+                Statement::Declaration(_) => {}
+                _ => continue,
+            }
+            if let Some(Emitted { code, errors }) = emitted[i].take().filter(|_| visible[i]) {
+                self.code.push_str(&code);
+                self.errors.extend(errors);
+            }
+        }
+        self.overloaded = overloaded;
+        emitted_module_syntax
+    }
+
+    /// Emit into a separate buffer, with the diagnostics of the emitted declarations
+    fn emit_separately<F: FnOnce(&mut Self)>(&mut self, emit: F) -> Emitted {
+        let code = std::mem::take(&mut self.code);
+        let errors = std::mem::take(&mut self.errors);
+        emit(self);
+        Emitted {
+            code: std::mem::replace(&mut self.code, code),
+            errors: std::mem::replace(&mut self.errors, errors),
+        }
+    }
+
+    /// `import { a, b } from 'a'` is emitted with the specifiers in `referenced`,
+    /// or as is when all specifiers are kept.
+    /// Imports without specifiers are kept for their side effects.
+    ///
+    /// Returns `true` if the import was emitted.
+    pub(crate) fn emit_import(
+        &mut self,
+        decl: &ImportDeclaration<'a>,
+        referenced: Option<&FxHashSet<Atom>>,
+    ) -> bool {
+        let (Some(specifiers), Some(referenced)) = (&decl.specifiers, referenced) else {
+            self.print_verbatim("", decl.span);
+            return true;
+        };
+        let is_referenced = |specifier: &ImportDeclarationSpecifier| {
+            let local = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local,
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+            };
+            referenced.contains(&local.name)
+        };
+        if specifiers.is_empty() || specifiers.iter().all(is_referenced) {
+            self.print_verbatim("", decl.span);
+            return true;
+        }
+        if !specifiers.iter().any(is_referenced) {
+            return false;
+        }
+
+        self.print_indent();
+        self.print_str("import ");
+        if decl.import_kind.is_type() {
+            self.print_str("type ");
+        }
+        let mut named = vec![];
+        let mut first = true;
+        for specifier in specifiers.iter().filter(|specifier| is_referenced(specifier)) {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => named.push(specifier),
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    self.print_span(specifier.span);
+                    first = false;
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    if !first {
+                        self.print_str(", ");
+                    }
+                    self.print_span(specifier.span);
+                    first = false;
+                }
+            }
+        }
+        if !named.is_empty() {
+            if !first {
+                self.print_str(", ");
+            }
+            self.print_str("{ ");
+            for (i, specifier) in named.into_iter().enumerate() {
+                if i > 0 {
+                    self.print_str(", ");
+                }
+                self.print_span(specifier.span);
+            }
+            self.print_str(" }");
+        }
+        self.print_str(" from ");
+        self.print_span(decl.source.span);
+        self.print_str(";\n");
+        true
+    }
+}
+
+/// Exports and ambient module declarations, which are emitted even when they are not referenced
+fn is_always_visible(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::ModuleDeclaration(decl) => !decl.is_import(),
+        // `declare module "a" {}` and `declare global {}`
+        Statement::Declaration(Declaration::TSModuleDeclaration(decl)) => {
+            matches!(decl.id, TSModuleDeclarationName::StringLiteral(_))
+                || decl.id.name().as_str() == "global"
+        }
+        _ => false,
+    }
+}
+
+/// Whether `stmt` declares a local named `name`
+fn declares(stmt: &Statement, name: &str) -> bool {
+    let Statement::Declaration(decl) = stmt else { return false };
+    match decl {
+        Declaration::VariableDeclaration(decl) => decl.declarations.iter().any(|declarator| {
+            matches!(&declarator.id.kind, BindingPatternKind::BindingIdentifier(ident) if ident.name == name)
+        }),
+        Declaration::FunctionDeclaration(func) => func.id.as_ref().is_some_and(|id| id.name == name),
+        Declaration::ClassDeclaration(class) => class.id.as_ref().is_some_and(|id| id.name == name),
+        Declaration::TSEnumDeclaration(decl) => decl.id.name == name,
+        Declaration::TSModuleDeclaration(decl) => decl.id.name().as_str() == name,
+        Declaration::TSTypeAliasDeclaration(decl) => decl.id.name == name,
+        Declaration::TSInterfaceDeclaration(decl) => decl.id.name == name,
+        Declaration::TSImportEqualsDeclaration(decl) => decl.id.name == name,
+        Declaration::UsingDeclaration(_)
+        | Declaration::FlowOpaqueTypeDeclaration(_)
+        | Declaration::FlowDeclareModuleExports(_) => false,
+    }
+}
+
+/// The identifiers in emitted declarations which may reference a local.
+///
+/// Property accesses (`a.b`), names of properties and parameters (`b: T`) and string literals
+/// are skipped.
+fn referenced_names(code: &str) -> impl Iterator<Item = &str> {
+    let bytes = code.as_bytes();
+    let is_ident_start = |b: u8| b.is_ascii_alphabetic() || b == b'_' || b == b'$' || b >= 0x80;
+    let is_ident_part = |b: u8| is_ident_start(b) || b.is_ascii_digit();
+    let mut names = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'\'' || b == b'"' {
+            i += 1;
+            while i < bytes.len() && bytes[i] != b {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i += 1;
+        } else if is_ident_start(b) {
+            let start = i;
+            while i < bytes.len() && is_ident_part(bytes[i]) {
+                i += 1;
+            }
+            let before = code[..start].trim_end();
+            let line_before = code[..start].trim_end_matches([' ', '\t']);
+            let is_member = before.ends_with('.') && !before.ends_with("...");
+            let is_key = code[i..].trim_start_matches('?').starts_with(':')
+                && (line_before.is_empty() || line_before.ends_with(['{', ';', ',', '(', '\n']));
+            if !is_member && !is_key {
+                names.push(&code[start..i]);
+            }
+        } else if b.is_ascii_digit() {
+            while i < bytes.len() && is_ident_part(bytes[i]) {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    names.into_iter()
+}
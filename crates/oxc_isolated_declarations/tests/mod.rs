@@ -0,0 +1,156 @@
+use oxc_allocator::Allocator;
+use oxc_isolated_declarations::IsolatedDeclarations;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn transform(source_text: &str) -> (String, usize) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_typescript(true).with_module(true);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "parse error for {source_text}");
+    let ret = IsolatedDeclarations::new(source_text).build(&ret.program);
+    (ret.source_text, ret.errors.len())
+}
+
+fn test(source_text: &str, expected: &str) {
+    let (printed, errors) = transform(source_text);
+    assert_eq!(errors, 0, "unexpected errors for {source_text}");
+    assert_eq!(expected, printed, "for source {source_text}");
+}
+
+fn test_errors(source_text: &str, expected_errors: usize) {
+    let (_, errors) = transform(source_text);
+    assert_eq!(expected_errors, errors, "for source {source_text}");
+}
+
+#[test]
+fn function() {
+    test(
+        "export function foo(a: string, b = 1): string { return a; }",
+        "export declare function foo(a: string, b?: number): string;\n",
+    );
+    test(
+        "export function foo(a = 1, b: string): void {}",
+        "export declare function foo(a: number | undefined, b: string): void;\n",
+    );
+    test(
+        "export function foo(a: number): number;\nexport function foo(a: string): string;\nexport function foo(a: any): any { return a; }",
+        "export declare function foo(a: number): number;\nexport declare function foo(a: string): string;\n",
+    );
+    test("export default function (): void {}", "export default function(): void;\n");
+}
+
+#[test]
+fn variable() {
+    test("export const a = 1, b = 'b';", "export declare const a = 1, b = 'b';\n");
+    test("export let a = 1;", "export declare let a: number;\n");
+    test("export const a: Foo = foo();", "export declare const a: Foo;\n");
+    test(
+        "export const a = { b: 1, c: [1, 'd'] as const };",
+        "export declare const a: {\n    b: number;\n    c: readonly [1, 'd'];\n};\n",
+    );
+    test(
+        "export const a = (b: string): number => 1;",
+        "export declare const a: (b: string) => number;\n",
+    );
+}
+
+#[test]
+fn class() {
+    test(
+        "export class A<T> extends B<T> implements C {
+    #a = 1;
+    private b = 2;
+    static c = 'c';
+    readonly d = 1;
+    constructor(public e: string, private f: number) { super(); }
+    get g(): number { return 1; }
+    set g(v) {}
+    h(a: T): T { return a; }
+    static {}
+}",
+        "export declare class A<T> extends B<T> implements C {
+    #private;
+    private b;
+    static c: string;
+    readonly d = 1;
+    public e: string;
+    private f;
+    constructor(e: string, f: number);
+    get g(): number;
+    set g(v: number);
+    h(a: T): T;
+}
+",
+    );
+    test(
+        "export abstract class A { abstract foo(): void; [key: string]: any; }",
+        "export declare abstract class A {\n    abstract foo(): void;\n    [key: string]: any;\n}\n",
+    );
+}
+
+#[test]
+fn declarations() {
+    test(
+        "export enum A { B, C = 5, D }",
+        "export declare enum A {\n    B = 0,\n    C = 5,\n    D = 6,\n}\n",
+    );
+    test("export interface A { b: string }", "export interface A { b: string }\n");
+    test("export type A = string | number;", "export type A = string | number;\n");
+    test(
+        "export namespace A { export const b = 1; }",
+        "export declare namespace A {\n    export const b = 1;\n}\n",
+    );
+    test(
+        "declare module 'a' { export const b: number; }",
+        "declare module 'a' { export const b: number; }\n",
+    );
+}
+
+#[test]
+fn module() {
+    test("import { a } from 'a';\nexport { a };", "import { a } from 'a';\nexport { a };\n");
+    test("export default foo;", "export default foo;\n");
+    test("export default 1;", "declare const _default: 1;\nexport default _default;\n");
+    test("import 'a';\nconst a = 1;\nfoo();", "import 'a';\n");
+}
+
+#[test]
+fn visibility() {
+    // Locals and imports are only emitted when an export references them
+    test(
+        "import { A, B } from 'a';\nimport C from 'c';\ntype D = A;\ninterface E {}\nexport const f: D = 1;",
+        "import { A } from 'a';\ntype D = A;\nexport declare const f: D;\n",
+    );
+    test("const a = 1;\nconst b = foo();\nexport { a };", "declare const a = 1;\nexport { a };\n");
+    test(
+        "function a(): void {}\nclass B {}\nexport function c(a: B): void {}",
+        "declare class B {\n}\nexport declare function c(a: B): void;\n",
+    );
+    test("const a = 1;\nexport default a;", "declare const a = 1;\nexport default a;\n");
+    test("import { a } from 'a';\nconst b = 1;\nexport {};", "export {};\n");
+    test("import { a } from 'a';\nconst b = 1;", "export {};\n");
+    test(
+        "namespace A { const b = 1; type C = string; export const d: C = ''; }",
+        "declare namespace A {\n    type C = string;\n    export const d: C;\n}\n",
+    );
+    // Scripts declare globals
+    test("const a = 1;", "declare const a = 1;\n");
+}
+
+#[test]
+fn errors() {
+    test_errors("export function foo() {}", 1);
+    test_errors("export function foo(a): void {}", 1);
+    test_errors("export let a = foo();", 1);
+    test_errors("export const a = [1];", 2);
+    test_errors("export const { a } = b;", 1);
+    test_errors("export const a = { ...b };", 2);
+    test_errors("export class A { foo() {} }", 1);
+    test_errors("export class A { get foo() { return 1; } }", 1);
+    test_errors("export class A extends foo() {}", 1);
+    test_errors("export default foo();", 1);
+    // Unreferenced locals are not emitted, nor diagnosed
+    test_errors("const a = foo();\nexport const b = 1;", 0);
+    test_errors("const a = foo();\nexport const b: typeof a = 1;", 1);
+}
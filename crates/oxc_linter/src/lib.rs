@@ -10,6 +10,7 @@ mod disable_directives;
 mod fixer;
 mod globals;
 mod options;
+pub mod partial_loader;
 pub mod rule;
 mod rule_timer;
 mod rules;
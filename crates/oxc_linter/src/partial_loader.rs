@@ -0,0 +1,287 @@
+//! Extract the JavaScript and TypeScript blocks from Vue, Svelte, Astro and HTML files
+
+use oxc_span::SourceType;
+
+/// File extensions handled by [PartialLoader]
+pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "svelte", "astro", "html"];
+
+/// A script block inside a host file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JavaScriptSource<'a> {
+    pub source_text: &'a str,
+    pub source_type: SourceType,
+    /// Byte offset of the block in the host file
+    pub start: u32,
+}
+
+impl<'a> JavaScriptSource<'a> {
+    pub fn new(source_text: &'a str, source_type: SourceType, start: u32) -> Self {
+        Self { source_text, source_type, start }
+    }
+
+    /// The text of the host file before the block with everything but line breaks replaced by
+    /// spaces, followed by the block.
+    /// The spans of the parsed text are then offsets into the host file.
+    pub fn padded_source_text(&self, host_text: &str) -> String {
+        let mut text = host_text.as_bytes()[..self.start as usize]
+            .iter()
+            .map(|&b| if matches!(b, b'\n' | b'\r') { b as char } else { ' ' })
+            .collect::<String>();
+        text.push_str(self.source_text);
+        text
+    }
+}
+
+pub struct PartialLoader;
+
+impl PartialLoader {
+    /// Extract the script blocks of a file with one of the [LINT_PARTIAL_LOADER_EXT] extensions.
+    /// Returns `None` for other extensions.
+    pub fn parse<'a>(ext: &str, source_text: &'a str) -> Option<Vec<JavaScriptSource<'a>>> {
+        let sources = match ext {
+            "vue" | "svelte" => {
+                parse_scripts(source_text, 0, |attrs| lang_source_type(attribute(attrs, "lang")))
+            }
+            "astro" => {
+                // The frontmatter is TypeScript, and so are the processed `<script>` tags
+                let source_type = SourceType::default().with_module(true).with_typescript(true);
+                let frontmatter = parse_frontmatter(source_text, source_type);
+                let from = frontmatter.map_or(0, |source| {
+                    source.start as usize + source.source_text.len() + "---".len()
+                });
+                frontmatter
+                    .into_iter()
+                    .chain(parse_scripts(source_text, from, |_| Some(source_type)))
+                    .collect()
+            }
+            "html" => parse_scripts(source_text, 0, |attrs| {
+                match attribute(attrs, "type").map(str::to_ascii_lowercase).as_deref() {
+                    None | Some("" | "text/javascript" | "application/javascript") => {
+                        Some(SourceType::default())
+                    }
+                    Some("module") => Some(SourceType::default().with_module(true)),
+                    // e.g. `importmap`, `application/json` or templates
+                    Some(_) => None,
+                }
+            }),
+            _ => return None,
+        };
+        Some(sources)
+    }
+}
+
+/// The source type of a `<script lang="..">` block in Vue or Svelte
+fn lang_source_type(lang: Option<&str>) -> Option<SourceType> {
+    let source_type = SourceType::default().with_module(true);
+    match lang {
+        None | Some("js") => Some(source_type),
+        Some("jsx") => Some(source_type.with_jsx(true)),
+        Some("ts") => Some(source_type.with_typescript(true)),
+        Some("tsx") => Some(source_type.with_typescript(true).with_jsx(true)),
+        Some(_) => None,
+    }
+}
+
+fn attribute<'a>(attrs: &[(&str, &'a str)], name: &str) -> Option<&'a str> {
+    attrs.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| *value)
+}
+
+/// The code between the `---` fences at the start of an Astro file
+#[allow(clippy::cast_possible_truncation)]
+fn parse_frontmatter(source_text: &str, source_type: SourceType) -> Option<JavaScriptSource> {
+    let rest = source_text.trim_start();
+    let start = source_text.len() - rest.len() + "---".len();
+    if !rest.starts_with("---") {
+        return None;
+    }
+    let end = start + source_text[start..].find("\n---")? + 1;
+    Some(JavaScriptSource::new(&source_text[start..end], source_type, start as u32))
+}
+
+/// Find the `<script>` blocks after `from`, skipping HTML comments.
+/// `source_type` is called with the attributes of each tag and returns `None` to skip the block.
+#[allow(clippy::cast_possible_truncation)]
+fn parse_scripts(
+    source_text: &str,
+    from: usize,
+    source_type: impl Fn(&[(&str, &str)]) -> Option<SourceType>,
+) -> Vec<JavaScriptSource> {
+    // Tag names are case-insensitive, lower case ascii keeps the byte offsets
+    let lower = source_text.to_ascii_lowercase();
+    let mut sources = vec![];
+    let mut pos = from;
+
+    while let Some(offset) = lower[pos..].find('<') {
+        let tag_start = pos + offset;
+        let rest = &lower[tag_start..];
+        if rest.starts_with("<!--") {
+            let Some(end) = rest.find("-->") else { break };
+            pos = tag_start + end + "-->".len();
+            continue;
+        }
+        pos = tag_start + 1;
+        if !rest.starts_with("<script")
+            || !rest[7..].starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        {
+            continue;
+        }
+
+        let attrs_start = tag_start + "<script".len();
+        let Some(tag_end) = find_tag_end(&source_text[attrs_start..]) else { break };
+        let attrs_text = &source_text[attrs_start..attrs_start + tag_end];
+        let content_start = attrs_start + tag_end + 1;
+        if attrs_text.trim_end().ends_with('/') {
+            pos = content_start;
+            continue;
+        }
+        let Some(content_len) = lower[content_start..].find("</script") else { break };
+        let content_end = content_start + content_len;
+        pos = content_end;
+
+        let content = &source_text[content_start..content_end];
+        if content.trim().is_empty() {
+            continue;
+        }
+        if let Some(source_type) = source_type(&parse_attributes(attrs_text)) {
+            sources.push(JavaScriptSource::new(content, source_type, content_start as u32));
+        }
+    }
+
+    sources
+}
+
+/// The offset of the `>` closing a tag, ignoring quoted attribute values
+fn find_tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '>') => return Some(i),
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse `name="value" name='value' name=value name` into pairs, with an empty value for
+/// attributes without one
+fn parse_attributes(text: &str) -> Vec<(&str, &str)> {
+    let is_name_end = |c: char| c.is_ascii_whitespace() || c == '=' || c == '/';
+    let mut attrs = vec![];
+    let mut rest = text;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+        if rest.is_empty() {
+            break;
+        }
+        let name_end = rest.find(is_name_end).unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+        let Some(value_text) = rest.strip_prefix('=') else {
+            attrs.push((name, ""));
+            continue;
+        };
+        let value_text = value_text.trim_start();
+        let (value, remaining) = if let Some(q @ ('"' | '\'')) = value_text.chars().next() {
+            let end = value_text[1..].find(q).map_or(value_text.len(), |end| end + 1);
+            (&value_text[1..end], value_text.get(end + 1..).unwrap_or_default())
+        } else {
+            let end =
+                value_text.find(|c: char| c.is_ascii_whitespace()).unwrap_or(value_text.len());
+            (&value_text[..end], &value_text[end..])
+        };
+        attrs.push((name, value));
+        rest = remaining;
+    }
+    attrs
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use super::{JavaScriptSource, PartialLoader};
+
+    fn parse<'a>(ext: &str, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+        PartialLoader::parse(ext, source_text).unwrap()
+    }
+
+    #[test]
+    fn unknown_extension() {
+        assert!(PartialLoader::parse("md", "<script>a</script>").is_none());
+    }
+
+    #[test]
+    fn vue() {
+        let source_text = r#"
+<template><div @click="a">{{ a }}</div></template>
+<script>
+export default {}
+</script>
+<script setup lang="ts" generic="T extends 'a' | 'b'">
+const a: T = 1
+</script>
+<script lang="coffee">a = 1</script>
+"#;
+        let sources = parse("vue", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "\nexport default {}\n");
+        assert_eq!(sources[0].source_type, SourceType::default().with_module(true));
+        assert_eq!(sources[1].source_text.trim(), "const a: T = 1");
+        assert!(sources[1].source_type.is_typescript());
+        for source in sources {
+            let start = source.start as usize;
+            assert_eq!(&source_text[start..start + source.source_text.len()], source.source_text);
+        }
+    }
+
+    #[test]
+    fn svelte() {
+        let source_text = "<script context=\"module\" lang=\"ts\">let a: number</script>\n\
+                           <SCRIPT>let b</SCRIPT>\n<scripts>c</scripts>";
+        let sources = parse("svelte", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "let a: number");
+        assert!(sources[0].source_type.is_typescript());
+        assert_eq!(sources[1].source_text, "let b");
+    }
+
+    #[test]
+    fn astro() {
+        let source_text = "---\nconst a = 1\n---\n<h1>{a}</h1>\n<script>let b</script>";
+        let sources = parse("astro", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, "\nconst a = 1\n");
+        assert_eq!(sources[0].start, 3);
+        assert_eq!(sources[1].source_text, "let b");
+        assert!(sources.iter().all(|source| source.source_type.is_typescript()));
+
+        assert_eq!(parse("astro", "<h1>a</h1>").len(), 0);
+    }
+
+    #[test]
+    fn html() {
+        let source_text = r#"<!-- <script>commented</script> -->
+<script src="a.js"></script>
+<script type="module">import a from "a"</script>
+<script type="importmap">{}</script>
+<script data-a="b>c">a</script>
+<script/>"#;
+        let sources = parse("html", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text, r#"import a from "a""#);
+        assert!(sources[0].source_type.is_module());
+        assert_eq!(sources[1].source_text, "a");
+        assert!(sources[1].source_type.is_script());
+    }
+
+    #[test]
+    fn padded_source_text() {
+        let source_text = "<p>é</p>\r\n<script>a</script>";
+        let source = parse("html", source_text)[0];
+        let padded = source.padded_source_text(source_text);
+        assert_eq!(padded.len(), source.start as usize + 1);
+        assert_eq!(padded, format!("{}\r\n{}a", " ".repeat(9), " ".repeat(8)));
+    }
+}
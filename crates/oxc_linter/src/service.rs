@@ -1,5 +1,6 @@
 use std::{
     collections::HashMap,
+    ffi::OsStr,
     fs,
     path::Path,
     rc::Rc,
@@ -12,12 +13,15 @@ use rustc_hash::FxHashSet;
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError};
-use oxc_parser::Parser;
-use oxc_resolver::{ResolveOptions, Resolver};
-use oxc_semantic::{ModuleRecord, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-
-use crate::{Fixer, LintContext, LintOptions, Linter, Message};
+use oxc_parser::{has_flow_pragma, Parser};
+use oxc_resolver::{PackageType, ResolveOptions, Resolver};
+use oxc_semantic::{ModuleRecord, ModuleRecordBuilder, SemanticBuilder};
+use oxc_span::{SourceType, UnknownExtension, VALID_EXTENSIONS};
+
+use crate::{
+    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
+    Fixer, LintContext, LintOptions, Linter, Message,
+};
 
 #[derive(Clone)]
 pub struct LintService {
@@ -70,11 +74,10 @@ impl LintService {
             .flat_map(|path| {
                 let source_type = SourceType::from_path(path).unwrap();
                 self.runtime.init_cache_state(path);
-                self.runtime.process_source(
+                self.runtime.process_sources(
                     path,
                     allocator,
-                    source_text,
-                    source_type,
+                    [(source_text, source_type)],
                     check_syntax_errors,
                     tx_error,
                 )
@@ -132,8 +135,33 @@ impl Runtime {
         })
     }
 
+    /// The source type of JavaScript files follows Node.js: `.mjs` files are modules,
+    /// `.cjs` files are scripts, and `.js` and `.jsx` files follow the "type" field of the
+    /// nearest package.json, or are detected from their syntax when there is none.
+    fn source_type(&self, path: &Path, ext: &str) -> Result<SourceType, UnknownExtension> {
+        let source_type = SourceType::from_path(path)?;
+        if source_type.is_typescript() {
+            return Ok(source_type);
+        }
+        let package_type =
+            || self.resolver.find_package_json(path).ok().flatten().and_then(|json| json.r#type);
+        Ok(match ext {
+            "mjs" => source_type,
+            "cjs" => source_type.with_script(true),
+            _ => match package_type() {
+                Some(PackageType::Module) => source_type,
+                Some(PackageType::CommonJs) => source_type.with_script(true),
+                None => source_type.with_unambiguous(true),
+            },
+        })
+    }
+
     fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
-        let Ok(source_type) = SourceType::from_path(path) else { return };
+        let Some(ext) = path.extension().and_then(OsStr::to_str) else { return };
+        let source_type = self.source_type(path, ext);
+        if source_type.is_err() && !LINT_PARTIAL_LOADER_EXT.contains(&ext) {
+            return;
+        }
 
         if self.init_cache_state(path) {
             return;
@@ -153,8 +181,26 @@ impl Runtime {
             }
         };
 
-        let mut messages =
-            self.process_source(path, &allocator, &source_text, source_type, true, tx_error);
+        let sources = source_type.map_or_else(
+            |_| PartialLoader::parse(ext, &source_text).unwrap_or_default(),
+            |source_type| {
+                // JavaScript files with a `@flow` pragma are parsed as Flow
+                let is_flow = source_type.is_javascript() && has_flow_pragma(&source_text);
+                vec![JavaScriptSource::new(&source_text, source_type.with_flow(is_flow), 0)]
+            },
+        );
+
+        // Blocks of Vue, Svelte, Astro and HTML files are padded to the length of the host
+        // file, so that the diagnostics and fixes are reported at the right offsets.
+        let sources = sources.into_iter().map(|source| {
+            let source_text = if source.start == 0 {
+                source.source_text
+            } else {
+                allocator.alloc_str(&source.padded_source_text(&source_text))
+            };
+            (source_text, source.source_type)
+        });
+        let mut messages = self.process_sources(path, &allocator, sources, true, tx_error);
 
         if self.linter.options().fix {
             let fix_result = Fixer::new(&source_text, messages).fix();
@@ -170,73 +216,117 @@ impl Runtime {
         }
     }
 
-    fn process_source<'a>(
+    /// Lint the script blocks of a file, which all share one module record.
+    fn process_sources<'a>(
         &self,
         path: &Path,
         allocator: &'a Allocator,
-        source_text: &'a str,
-        source_type: SourceType,
+        sources: impl IntoIterator<Item = (&'a str, SourceType)>,
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .allow_return_outside_function(true)
-            .parse();
-
-        if !ret.errors.is_empty() {
-            return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
-        };
-
-        let program = allocator.alloc(ret.program);
-
-        // Build the module record to unblock other threads from waiting for too long.
-        // The semantic model is not built at this stage.
-        let semantic_builder = SemanticBuilder::new(source_text, source_type)
-            .with_trivias(ret.trivias)
-            .with_check_syntax_error(check_syntax_errors)
-            .build_module_record(path.to_path_buf(), program);
-        let module_record = semantic_builder.module_record();
+        let mut messages = vec![];
+        let mut blocks = vec![];
+        for (source_text, source_type) in sources {
+            let ret = Parser::new(allocator, source_text, source_type)
+                .allow_return_outside_function(true)
+                .parse();
+
+            if !ret.errors.is_empty() {
+                messages.extend(ret.errors.into_iter().map(|err| Message::new(err, None)));
+                continue;
+            };
+
+            let program = allocator.alloc(ret.program);
+
+            // Build the module record to unblock other threads from waiting for too long.
+            // The semantic model is not built at this stage.
+            let semantic_builder = SemanticBuilder::new(source_text, program.source_type)
+                .with_trivias(ret.trivias)
+                .with_check_syntax_error(check_syntax_errors)
+                .build_module_record(path.to_path_buf(), program);
+            blocks.push((semantic_builder, &*program));
+        }
 
         if self.linter.options().import_plugin {
-            self.module_map
-                .insert(path.to_path_buf().into_boxed_path(), Arc::clone(&module_record));
+            // Other modules see the host file as one module, so the blocks of a file with several
+            // `<script>` tags are merged into one module record.
+            let module_record = match blocks.as_slice() {
+                [] => None,
+                [(semantic_builder, _)] => Some(semantic_builder.module_record()),
+                _ => {
+                    let mut builder = ModuleRecordBuilder::new(path.to_path_buf());
+                    for (_, program) in &blocks {
+                        builder.visit(program);
+                    }
+                    Some(Arc::new(builder.build()))
+                }
+            };
+            if let Some(module_record) = &module_record {
+                self.module_map
+                    .insert(path.to_path_buf().into_boxed_path(), Arc::clone(module_record));
+            }
             self.update_cache_state(path);
 
-            // Retrieve all dependency modules from this module.
-            let dir = path.parent().unwrap();
-            module_record
-                .requested_modules
-                .keys()
-                .par_bridge()
-                .map_with(&self.resolver, |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
-                })
-                .flatten()
-                .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
-                    let path = resolution.path();
-                    self.process_path(path, tx_error);
-                    if let Some(target_module_record) = self.module_map.get(path) {
-                        module_record
-                            .loaded_modules
-                            .insert(specifier.clone(), Arc::clone(&target_module_record));
+            if let Some(module_record) = module_record {
+                // Retrieve all dependency modules from this module.
+                let dir = path.parent().unwrap();
+                module_record
+                    .requested_modules
+                    .keys()
+                    .par_bridge()
+                    .map_with(&self.resolver, |resolver, specifier| {
+                        resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
+                    })
+                    .flatten()
+                    .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
+                        let path = resolution.path();
+                        self.process_path(path, tx_error);
+                        if let Some(target_module_record) = self.module_map.get(path) {
+                            module_record
+                                .loaded_modules
+                                .insert(specifier.clone(), Arc::clone(&target_module_record));
+                        }
+                    });
+
+                // Hand the dependencies over to the module record of each block.
+                if blocks.len() > 1 {
+                    for (semantic_builder, _) in &blocks {
+                        let block_module_record = semantic_builder.module_record();
+                        for specifier in block_module_record.requested_modules.keys() {
+                            if let Some(target_module_record) =
+                                module_record.loaded_modules.get(specifier)
+                            {
+                                block_module_record
+                                    .loaded_modules
+                                    .insert(specifier.clone(), Arc::clone(&target_module_record));
+                            }
+                        }
                     }
-                });
+                }
+            }
 
             // Stop if the current module is not marked for lint.
             if !self.paths.contains(path) {
-                return vec![];
+                return messages;
             }
         }
 
-        let semantic_ret = semantic_builder.build(program);
+        for (semantic_builder, program) in blocks {
+            let semantic_ret = semantic_builder.build(program);
 
-        if !semantic_ret.errors.is_empty() {
-            return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
-        };
+            if !semantic_ret.errors.is_empty() {
+                messages.extend(semantic_ret.errors.into_iter().map(|err| Message::new(err, None)));
+                continue;
+            };
 
-        let lint_ctx =
-            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
-        self.linter.run(lint_ctx)
+            let lint_ctx = LintContext::new(
+                path.to_path_buf().into_boxed_path(),
+                &Rc::new(semantic_ret.semantic),
+            );
+            messages.extend(self.linter.run(lint_ctx));
+        }
+        messages
     }
 
     fn init_cache_state(&self, path: &Path) -> bool {
@@ -294,3 +384,35 @@ impl Runtime {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{env, sync::Arc};
+
+    use oxc_diagnostics::DiagnosticService;
+    use oxc_span::Atom;
+
+    use super::Runtime;
+    use crate::{LintOptions, Linter};
+
+    #[test]
+    fn module_record_of_multiple_script_blocks() {
+        let cwd = env::current_dir().unwrap().join("fixtures/import").into_boxed_path();
+        let path = cwd.join("multiple-scripts.vue").into_boxed_path();
+        let linter = Linter::from_options(LintOptions::default().with_import_plugin(true));
+        let runtime = Runtime::new(cwd, &[path.clone()], linter);
+        let diagnostic_service = DiagnosticService::default();
+        runtime.process_path(&path, diagnostic_service.sender());
+
+        let module_record = Arc::clone(&runtime.module_map.get(&path).unwrap());
+        let requested_modules =
+            module_record.requested_modules.keys().map(Atom::as_str).collect::<Vec<_>>();
+        assert_eq!(requested_modules, ["./bar", "./named-exports"]);
+        assert!(module_record.export_default.is_some());
+        assert!(module_record.exported_bindings.contains_key("b"));
+        for (specifier, file) in [("./bar", "bar.js"), ("./named-exports", "named-exports.js")] {
+            let loaded_module = Arc::clone(&module_record.loaded_modules.get(specifier).unwrap());
+            assert_eq!(loaded_module.resolved_absolute_path.file_name(), Some(file.as_ref()));
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+/// `impl CloneIn`, cloning every field into the new allocator.
+///
+/// AST nodes have no type parameters and at most one lifetime, the lifetime of their allocator.
+pub fn derive_clone_in(input: &DeriveInput) -> Result<TokenStream> {
+    check_generics(input)?;
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, _) = fields_pattern(&data.fields, "field");
+            let cloned = fields_clone_in(&data.fields, "field");
+            quote! {
+                let Self #pattern = self;
+                #name #cloned
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let (pattern, _) = fields_pattern(&variant.fields, "field");
+                let cloned = fields_clone_in(&variant.fields, "field");
+                quote! { Self::#ident #pattern => #name::#ident #cloned }
+            });
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => return Err(Error::new_spanned(input, "unions are not supported")),
+    };
+
+    let (impl_generics, old_ty, new_ty) = if input.generics.lifetimes().next().is_some() {
+        (quote! { <'old_alloc, 'new_alloc> }, quote! { <'old_alloc> }, quote! { <'new_alloc> })
+    } else {
+        (quote! { <'new_alloc> }, quote! {}, quote! {})
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::oxc_allocator::CloneIn<'new_alloc> for #name #old_ty {
+            type Cloned = #name #new_ty;
+
+            fn clone_in(&self, allocator: &'new_alloc ::oxc_allocator::Allocator) -> Self::Cloned {
+                #body
+            }
+        }
+    })
+}
+
+/// `impl ContentEq`, comparing every field. Nodes of different enum variants are not equal.
+pub fn derive_content_eq(input: &DeriveInput) -> Result<TokenStream> {
+    check_generics(input)?;
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, this) = fields_pattern(&data.fields, "this");
+            let (other_pattern, other) = fields_pattern(&data.fields, "other");
+            quote! {
+                let Self #pattern = self;
+                let Self #other_pattern = other;
+                true #(&& ::oxc_span::ContentEq::content_eq(#this, #other))*
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let (pattern, this) = fields_pattern(&variant.fields, "this");
+                let (other_pattern, other) = fields_pattern(&variant.fields, "other");
+                quote! {
+                    (Self::#ident #pattern, Self::#ident #other_pattern) => {
+                        true #(&& ::oxc_span::ContentEq::content_eq(#this, #other))*
+                    }
+                }
+            });
+            let rest = (data.variants.len() > 1).then(|| quote! { _ => false, });
+            quote! {
+                match (self, other) {
+                    #(#arms)*
+                    #rest
+                }
+            }
+        }
+        Data::Union(_) => return Err(Error::new_spanned(input, "unions are not supported")),
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::oxc_span::ContentEq for #name #ty_generics #where_clause {
+            fn content_eq(&self, other: &Self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+/// `impl ShiftSpan`, shifting every field.
+pub fn derive_shift_span(input: &DeriveInput) -> Result<TokenStream> {
+    check_generics(input)?;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, fields) = fields_pattern(&data.fields, "field");
+            quote! {
+                let Self #pattern = self;
+                #(::oxc_span::ShiftSpan::shift_span(#fields, from, to);)*
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let (pattern, fields) = fields_pattern(&variant.fields, "field");
+                quote! {
+                    Self::#ident #pattern => {
+                        #(::oxc_span::ShiftSpan::shift_span(#fields, from, to);)*
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => return Err(Error::new_spanned(input, "unions are not supported")),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::oxc_span::ShiftSpan for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn shift_span(&mut self, from: u32, to: u32) {
+                #body
+            }
+        }
+    })
+}
+
+fn check_generics(input: &DeriveInput) -> Result<()> {
+    let generics = &input.generics;
+    if generics.type_params().next().is_some()
+        || generics.const_params().next().is_some()
+        || generics.lifetimes().count() > 1
+    {
+        return Err(Error::new_spanned(generics, "expected at most one lifetime"));
+    }
+    Ok(())
+}
+
+/// A pattern binding the fields to `{prefix}_{index}`, and the bindings.
+fn fields_pattern(fields: &Fields, prefix: &str) -> (TokenStream, Vec<Ident>) {
+    let bindings = (0..fields.len()).map(|i| format_ident!("{prefix}_{i}")).collect::<Vec<_>>();
+    let pattern = match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote! { { #(#names: #bindings),* } }
+        }
+        Fields::Unnamed(_) => quote! { ( #(#bindings),* ) },
+        Fields::Unit => quote! {},
+    };
+    (pattern, bindings)
+}
+
+/// The fields bound by [fields_pattern], cloned into `allocator`.
+fn fields_clone_in(fields: &Fields, prefix: &str) -> TokenStream {
+    let (_, bindings) = fields_pattern(fields, prefix);
+    let values = bindings
+        .iter()
+        .map(|binding| quote! { ::oxc_allocator::CloneIn::clone_in(#binding, allocator) });
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote! { { #(#names: #values),* } }
+        }
+        Fields::Unnamed(_) => quote! { ( #(#values),* ) },
+        Fields::Unit => quote! {},
+    }
+}
@@ -7,7 +7,7 @@ use std::{cmp::Ordering, mem, ops::Not};
 use num_bigint::BigInt;
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
-use oxc_span::{Atom, GetSpan, Span};
+use oxc_span::{Atom, ContentEq, GetSpan, Span};
 use oxc_syntax::{
     operator::{BinaryOperator, LogicalOperator, UnaryOperator},
     NumberBase,
@@ -233,6 +233,7 @@ impl<'a> Compressor<'a> {
                 }
                 LogicalOperator::Coalesce => None,
             },
+            Expression::ConditionalExpression(cond_expr) => self.try_fold_conditional(cond_expr),
             _ => None,
         };
         if let Some(folded_expr) = folded_expr {
@@ -931,6 +932,22 @@ impl<'a> Compressor<'a> {
         folded_expr
     }
 
+    /// `a ? b : b` -> `a, b`
+    fn try_fold_conditional(
+        &mut self,
+        cond_expr: &mut ConditionalExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        if cond_expr.consequent.content_ne(&cond_expr.alternate) {
+            return None;
+        }
+        let test = self.move_out_expression(&mut cond_expr.test);
+        let consequent = self.move_out_expression(&mut cond_expr.consequent);
+        let mut vec = self.ast.new_vec_with_capacity(2);
+        vec.push(test);
+        vec.push(consequent);
+        Some(self.ast.sequence_expression(cond_expr.span, vec))
+    }
+
     fn move_out_expression(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
         let null_literal = NullLiteral::new(expr.span());
         let null_expr = self.ast.literal_null_expression(null_literal);
@@ -8,6 +8,14 @@ fn addition_folding() {
     test("x+''", "x+'';");
 }
 
+#[test]
+fn conditional_folding() {
+    test("x ? y : y", "x,y;");
+    test("a = x ? y.z(1) : y.z(1)", "a=(x,y.z(1));");
+    test("x ? y : z", "x?y:z;");
+    test("x ? y.z(1) : y.z(2)", "x?y.z(1):y.z(2);");
+}
+
 #[test]
 fn addition_folding_snapshots() {
     test_snapshot(
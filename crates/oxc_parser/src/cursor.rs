@@ -14,6 +14,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token<'a>,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_pos: usize,
 }
 
 impl<'a> Parser<'a> {
@@ -100,10 +101,22 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Collect the current token with the `Kind` it is consumed as,
+    /// e.g. a keyword used as an identifier
+    fn collect_token(&mut self, kind: Kind) {
+        if let Some(tokens) = &mut self.tokens {
+            // The initial token before the first bump is an empty `Eof`
+            if !self.token.kind.is_eof() {
+                tokens.push(Token { kind, ..self.token.clone() });
+            }
+        }
+    }
+
     /// Move to the next token
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.collect_token(kind);
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_token();
     }
@@ -112,6 +125,7 @@ impl<'a> Parser<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance_for_jsx_child(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.collect_token(kind);
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_jsx_child();
     }
@@ -239,17 +253,26 @@ impl<'a> Parser<'a> {
             cur_token: self.token.clone(),
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_pos: self.tokens.as_ref().map_or(0, Vec::len),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos: errors_lens } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos: errors_lens,
+            tokens_pos,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_lens);
+        if let Some(tokens) = &mut self.tokens {
+            tokens.truncate(tokens_pos);
+        }
     }
 
     /// # Errors
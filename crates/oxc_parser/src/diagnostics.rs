@@ -6,9 +6,19 @@ use oxc_span::Span;
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("Flow is not supported")]
-#[diagnostic()]
+#[diagnostic(help("Parse Flow files with `SourceType::with_flow`"))]
 pub struct Flow(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Variance is not allowed on methods")]
+#[diagnostic()]
+pub struct FlowVarianceOnMethod(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Explicit inexact syntax is not allowed in exact object types")]
+#[diagnostic()]
+pub struct FlowInexactInExactObject(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Unexpected token")]
 #[diagnostic()]
@@ -0,0 +1,70 @@
+use oxc_allocator::Box;
+use oxc_ast::ast::{Expression, FlowPredicate, TSTypeAnnotation};
+use oxc_diagnostics::Result;
+use oxc_span::GetSpan;
+
+use crate::{lexer::Kind, Parser};
+
+type ReturnTypeAndPredicate<'a> =
+    (Option<Box<'a, TSTypeAnnotation<'a>>>, Option<Box<'a, FlowPredicate<'a>>>);
+
+impl<'a> Parser<'a> {
+    // test flow flow_type_cast_expression
+    // (a: any);
+    // f((b: string), (c: number));
+    // [(d: A)];
+    pub(crate) fn parse_flow_type_cast(
+        &mut self,
+        expression: Expression<'a>,
+    ) -> Result<Expression<'a>> {
+        if !self.flow_enabled() || !self.at(Kind::Colon) {
+            return Ok(expression);
+        }
+        let span = expression.span();
+        let Some(type_annotation) = self.parse_ts_type_annotation()? else { unreachable!() };
+        Ok(self.ast.flow_type_cast_expression(self.end_span(span), expression, type_annotation))
+    }
+
+    /// The return type of a function, which is followed by `%checks` in a Flow predicate function
+    pub(crate) fn parse_return_type_and_flow_predicate(
+        &mut self,
+    ) -> Result<ReturnTypeAndPredicate<'a>> {
+        if self.at(Kind::Colon) && self.is_nth_at_flow_predicate(1) {
+            self.bump_any(); // bump `:`
+            return Ok((None, Some(self.parse_flow_predicate()?)));
+        }
+        let return_type = self.parse_ts_return_type_annotation()?;
+        let predicate = if return_type.is_some() && self.is_nth_at_flow_predicate(0) {
+            Some(self.parse_flow_predicate()?)
+        } else {
+            None
+        };
+        Ok((return_type, predicate))
+    }
+
+    fn is_nth_at_flow_predicate(&mut self, n: u8) -> bool {
+        self.flow_enabled() && self.nth_at(n, Kind::Percent) && self.nth_at(n + 1, Kind::Ident)
+    }
+
+    // test flow flow_predicate
+    // function a(x: mixed): boolean %checks { return typeof x === "string"; }
+    // function b(x: mixed): %checks { return !!x; }
+    // declare function c(x: mixed): boolean %checks(typeof x === "string");
+    // const d = (x: mixed): %checks => typeof x === "string";
+    fn parse_flow_predicate(&mut self) -> Result<Box<'a, FlowPredicate<'a>>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `%`
+        if self.cur_src() != "checks" {
+            return Err(self.unexpected());
+        }
+        self.bump_any(); // bump `checks`
+        let value = if self.eat(Kind::LParen) {
+            let value = self.parse_expression()?;
+            self.expect(Kind::RParen)?;
+            Some(value)
+        } else {
+            None
+        };
+        Ok(self.ast.flow_predicate(self.end_span(span), value))
+    }
+}
@@ -0,0 +1,42 @@
+//! [Flow](https://flow.org/en/docs/types/)
+//!
+//! Flow shares most of its annotation syntax with TypeScript, so Flow sources are parsed with
+//! the TypeScript parser functions, and the syntax only in Flow is parsed here.
+
+mod expression;
+mod statement;
+mod types;
+
+/// Whether the comments before the code have a `@flow` or `@noflow` pragma,
+/// which [marks a JavaScript file as Flow](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow).
+///
+/// Parse these files with `SourceType::with_flow`.
+pub fn has_flow_pragma(source_text: &str) -> bool {
+    let mut rest = source_text
+        .strip_prefix("#!")
+        .map_or(source_text, |hashbang| hashbang.find('\n').map_or("", |end| &hashbang[end..]));
+    loop {
+        rest = rest.trim_start();
+        let (comment, after) = if let Some(line) = rest.strip_prefix("//") {
+            line.split_at(line.find('\n').unwrap_or(line.len()))
+        } else if let Some(block) = rest.strip_prefix("/*") {
+            let Some(end) = block.find("*/") else { return false };
+            (&block[..end], &block[end + 2..])
+        } else {
+            return false;
+        };
+        if is_flow_pragma(comment) {
+            return true;
+        }
+        rest = after;
+    }
+}
+
+fn is_flow_pragma(comment: &str) -> bool {
+    ["@flow", "@noflow"].iter().any(|pragma| {
+        comment.match_indices(pragma).any(|(i, _)| {
+            !comment[i + pragma.len()..]
+                .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        })
+    })
+}
@@ -0,0 +1,78 @@
+use oxc_ast::ast::{Declaration, Modifiers};
+use oxc_diagnostics::Result;
+use oxc_span::Span;
+
+use crate::{lexer::Kind, Parser};
+
+impl<'a> Parser<'a> {
+    /// `opaque type A`, `opaque` is not a keyword
+    pub(crate) fn is_at_flow_opaque_type(&mut self) -> bool {
+        self.flow_enabled()
+            && self.at(Kind::Ident)
+            && self.cur_src() == "opaque"
+            && self.peek_at(Kind::Type)
+            && !self.peek_token().is_on_new_line
+    }
+
+    // test flow flow_opaque_type
+    // opaque type A = string;
+    // opaque type B<T>: A = T;
+    // declare opaque type C: B<string>;
+    pub(crate) fn parse_flow_opaque_type_declaration(
+        &mut self,
+        span: Span,
+        modifiers: Modifiers<'a>,
+    ) -> Result<Declaration<'a>> {
+        self.bump_any(); // bump `opaque`
+        self.expect(Kind::Type)?;
+
+        let id = self.parse_binding_identifier()?;
+        let params = self.parse_ts_type_parameters()?;
+        let supertype = if self.eat(Kind::Colon) { Some(self.parse_ts_type()?) } else { None };
+        let annotation = if self.eat(Kind::Eq) { Some(self.parse_ts_type()?) } else { None };
+
+        self.asi()?;
+        Ok(self.ast.flow_opaque_type_declaration(
+            self.end_span(span),
+            id,
+            params,
+            supertype,
+            annotation,
+            modifiers,
+        ))
+    }
+
+    /// `declare module.exports`
+    pub(crate) fn is_at_flow_declare_module_exports(&mut self) -> bool {
+        self.flow_enabled()
+            && self.at(Kind::Declare)
+            && self.peek_at(Kind::Module)
+            && self.nth_at(2, Kind::Dot)
+    }
+
+    // test flow flow_declare_module_exports
+    // declare module.exports: { a: string };
+    // declare module "m" { declare module.exports: () => void; }
+    pub(crate) fn parse_flow_declare_module_exports(
+        &mut self,
+        span: Span,
+    ) -> Result<Declaration<'a>> {
+        self.bump_any(); // bump `module`
+        self.expect(Kind::Dot)?;
+        if self.cur_src() != "exports" {
+            return Err(self.unexpected());
+        }
+        self.bump_any(); // bump `exports`
+        let Some(type_annotation) = self.parse_ts_type_annotation()? else {
+            return Err(self.unexpected());
+        };
+        self.asi()?;
+        Ok(self.ast.flow_declare_module_exports(self.end_span(span), type_annotation))
+    }
+
+    /// The members of a Flow `declare class` are the ones of an object type,
+    /// which may be separated by `,`
+    pub(crate) fn eat_flow_declare_class_separator(&mut self) -> bool {
+        self.flow_enabled() && self.ctx.has_ambient() && self.eat(Kind::Comma)
+    }
+}
@@ -0,0 +1,147 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::{FlowVariance, FlowVarianceKind, TSSignature, TSType};
+use oxc_diagnostics::Result;
+use oxc_span::Span;
+
+use crate::{diagnostics, lexer::Kind, Parser};
+
+impl<'a> Parser<'a> {
+    // test flow flow_nullable_type
+    // type A = ?string;
+    // type B = ?string[];
+    // type C = ?() => void;
+    pub(crate) fn parse_flow_nullable_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `?`
+        let type_annotation = if self.is_at_function_type() {
+            self.parse_ts_function_type()?
+        } else {
+            self.parse_ts_primary_type()?
+        };
+        Ok(self.ast.flow_nullable_type(self.end_span(span), type_annotation))
+    }
+
+    /// `{|` or `{||}`
+    pub(crate) fn is_at_flow_exact_object_type(&mut self) -> bool {
+        self.flow_enabled()
+            && self.at(Kind::LCurly)
+            && matches!(self.peek_kind(), Kind::Pipe | Kind::Pipe2)
+    }
+
+    // test flow flow_exact_object_type
+    // type A = {| a: string, b?: number |};
+    // type B = {||};
+    // type C = {| a: string | number |};
+    pub(crate) fn parse_flow_exact_object_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly)?;
+        let members = if self.eat(Kind::Pipe2) {
+            self.ast.new_vec()
+        } else {
+            self.expect(Kind::Pipe)?;
+            let (members, inexact) = self.parse_flow_object_type_members(Kind::Pipe)?;
+            if let Some(inexact) = inexact {
+                self.error(diagnostics::FlowInexactInExactObject(inexact));
+            }
+            self.expect(Kind::Pipe)?;
+            members
+        };
+        self.expect(Kind::RCurly)?;
+        Ok(self.ast.flow_exact_object_type(self.end_span(span), members))
+    }
+
+    // test flow flow_object_type
+    // type A = { a: string, ...B, ...{ c: number } };
+    // type C = { a: string, ... };
+    // type D = { ... };
+    pub(crate) fn parse_flow_object_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly)?;
+        let (members, inexact) = self.parse_flow_object_type_members(Kind::RCurly)?;
+        self.expect(Kind::RCurly)?;
+        let span = self.end_span(span);
+        Ok(if inexact.is_some() {
+            self.ast.flow_inexact_object_type(span, members)
+        } else {
+            self.ast.ts_type_literal(span, members)
+        })
+    }
+
+    /// The members of an object type up to `close`, and the span of the trailing `...` which
+    /// makes the object type explicitly inexact
+    fn parse_flow_object_type_members(
+        &mut self,
+        close: Kind,
+    ) -> Result<(Vec<'a, TSSignature<'a>>, Option<Span>)> {
+        let mut members = self.ast.new_vec();
+        while !self.at(close) && !self.at(Kind::Eof) {
+            if self.at(Kind::Dot3)
+                && matches!(
+                    self.peek_kind(),
+                    Kind::RCurly | Kind::Pipe | Kind::Comma | Kind::Semicolon
+                )
+            {
+                let span = self.start_span();
+                self.bump_any(); // bump `...`
+                let span = self.end_span(span);
+                self.bump(Kind::Comma);
+                self.bump(Kind::Semicolon);
+                return Ok((members, Some(span)));
+            }
+            members.push(self.parse_ts_type_signature()?);
+        }
+        Ok((members, None))
+    }
+
+    /// `...A` in an object type
+    pub(crate) fn parse_flow_object_type_spread(&mut self) -> Result<TSSignature<'a>> {
+        let span = self.start_span();
+        self.bump_any(); // bump `...`
+        let argument = self.parse_ts_type()?;
+        self.bump(Kind::Comma);
+        self.bump(Kind::Semicolon);
+        Ok(self.ast.flow_object_type_spread(self.end_span(span), argument))
+    }
+
+    /// `static +a`
+    pub(crate) fn is_next_at_flow_variance(&mut self) -> bool {
+        self.flow_enabled() && matches!(self.peek_kind(), Kind::Plus | Kind::Minus)
+    }
+
+    // test flow flow_variance
+    // type A = { +a: string, -b: number };
+    // class C { +a: string; -b: number }
+    pub(crate) fn parse_flow_variance(&mut self) -> Option<FlowVariance> {
+        if !self.flow_enabled() {
+            return None;
+        }
+        let kind = match self.cur_kind() {
+            Kind::Plus => FlowVarianceKind::Plus,
+            Kind::Minus => FlowVarianceKind::Minus,
+            _ => return None,
+        };
+        let span = self.start_span();
+        self.bump_any();
+        Some(FlowVariance { span: self.end_span(span), kind })
+    }
+
+    /// Variance is only allowed on properties
+    pub(crate) fn check_flow_variance_on_method(&mut self, variance: Option<FlowVariance>) {
+        if let Some(variance) = variance {
+            self.error(diagnostics::FlowVarianceOnMethod(variance.span));
+        }
+    }
+
+    // test flow flow_exists_type
+    // type A = Array<*>;
+    pub(crate) fn parse_flow_exists_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.bump_any(); // bump `*`
+        self.ast.flow_exists_type(self.end_span(span))
+    }
+
+    /// The `|` of a union type, but not the `|` of `|}` which closes an exact object type
+    pub(crate) fn at_union_type_pipe(&mut self) -> bool {
+        self.at(Kind::Pipe) && !(self.flow_enabled() && self.peek_at(Kind::RCurly))
+    }
+}
@@ -172,7 +172,8 @@ impl<'a> Parser<'a> {
 
         let modifier = self.parse_class_element_modifiers(false);
 
-        let accessor = self.peek_kind().is_class_element_name_start() && self.eat(Kind::Accessor);
+        let mut accessor =
+            self.peek_kind().is_class_element_name_start() && self.eat(Kind::Accessor);
 
         let accessibility = modifier.accessibility();
 
@@ -190,7 +191,10 @@ impl<'a> Parser<'a> {
             }
 
             // static ...
-            if self.peek_kind().is_class_element_name_start() || self.peek_at(Kind::Star) {
+            if self.peek_kind().is_class_element_name_start()
+                || self.peek_at(Kind::Star)
+                || self.is_next_at_flow_variance()
+            {
                 self.bump(Kind::Static);
                 r#static = true;
             } else {
@@ -198,6 +202,11 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // static accessor ...
+        if r#static && !accessor && key_name.is_none() {
+            accessor = self.peek_kind().is_class_element_name_start() && self.eat(Kind::Accessor);
+        }
+
         // async ...
         if key_name.is_none() && self.at(Kind::Async) && !self.peek_at(Kind::Question) {
             if !self.peek_token().is_on_new_line
@@ -216,6 +225,9 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // Flow `+a: A` or `-a: A`
+        let variance = self.parse_flow_variance();
+
         // * ...
         if key_name.is_none() && self.eat(Kind::Star) {
             generator = true;
@@ -263,6 +275,7 @@ impl<'a> Parser<'a> {
         // LAngle for start of type parameters `foo<T>`
         //                                         ^
         if self.at(Kind::LParen) || self.at(Kind::LAngle) || r#async || generator {
+            self.check_flow_variance_on_method(variance);
             let definition = self.parse_class_method_definition(
                 span,
                 kind,
@@ -306,6 +319,7 @@ impl<'a> Parser<'a> {
                 accessibility,
                 optional,
                 definite,
+                variance,
             )?;
             if let Some((name, span)) = definition.prop_name() {
                 if name == "constructor" {
@@ -406,6 +420,7 @@ impl<'a> Parser<'a> {
         accessibility: Option<TSAccessibility>,
         optional: bool,
         definite: bool,
+        variance: Option<FlowVariance>,
     ) -> Result<ClassElement<'a>> {
         let type_annotation =
             if self.ts_enabled() { self.parse_ts_type_annotation()? } else { None };
@@ -418,7 +433,9 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
-        self.asi()?;
+        if !self.eat_flow_declare_class_separator() {
+            self.asi()?;
+        }
 
         let property_definition = PropertyDefinition {
             span: self.end_span(span),
@@ -429,6 +446,7 @@ impl<'a> Parser<'a> {
             declare,
             r#override,
             readonly,
+            variance,
             type_annotation,
             accessibility,
             optional,
@@ -465,8 +483,16 @@ impl<'a> Parser<'a> {
         computed: bool,
         r#static: bool,
     ) -> Result<ClassElement<'a>> {
+        let decorators = self.state.consume_decorators();
         let value =
             self.eat(Kind::Eq).then(|| self.parse_assignment_expression_base()).transpose()?;
-        Ok(self.ast.accessor_property(self.end_span(span), key, value, computed, r#static))
+        Ok(self.ast.accessor_property(
+            self.end_span(span),
+            key,
+            value,
+            computed,
+            r#static,
+            decorators,
+        ))
     }
 }
@@ -95,8 +95,8 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn parse_identifier_kind(&mut self, kind: Kind) -> (Span, Atom) {
         let span = self.start_span();
-        let name = match std::mem::take(&mut self.token.value) {
-            TokenValue::String(value) => value,
+        let name = match &self.token.value {
+            TokenValue::String(value) => *value,
             _ => "",
         };
         self.bump_remap(kind);
@@ -322,9 +322,7 @@ impl<'a> Parser<'a> {
         if !self.at(Kind::Str) {
             return Err(self.unexpected());
         }
-        let TokenValue::String(value) = std::mem::take(&mut self.token.value) else {
-            unreachable!()
-        };
+        let TokenValue::String(value) = self.token.value else { unreachable!() };
         let span = self.start_span();
         self.bump_any();
         Ok(StringLiteral { span: self.end_span(span), value: value.into() })
@@ -560,7 +558,7 @@ impl<'a> Parser<'a> {
                         _ => break,
                     }
                 }
-                Kind::Bang if !self.cur_token().is_on_new_line && self.ts_enabled() => {
+                Kind::Bang if !self.cur_token().is_on_new_line && self.ts_only_enabled() => {
                     self.bump_any();
                     self.ast.ts_non_null_expression(self.end_span(lhs_span), lhs)
                 }
@@ -800,7 +798,7 @@ impl<'a> Parser<'a> {
 
         if (self.at(Kind::LAngle) || self.at(Kind::ShiftLeft))
             && !self.source_type.is_jsx()
-            && self.ts_enabled()
+            && self.ts_only_enabled()
         {
             return self.parse_ts_type_assertion();
         }
@@ -892,8 +890,8 @@ impl<'a> Parser<'a> {
                 break;
             }
 
-            if self.ts_enabled() && matches!(kind, Kind::As | Kind::Satisfies) {
-                if self.cur_token().is_on_new_line {
+            if matches!(kind, Kind::As | Kind::Satisfies) {
+                if !self.ts_only_enabled() || self.cur_token().is_on_new_line {
                     break;
                 }
                 self.bump_any();
@@ -961,7 +959,7 @@ impl<'a> Parser<'a> {
             IsParenthesizedArrowFunction::Maybe => {
                 let pos = self.cur_token().start;
                 if !self.state.not_parenthesized_arrow.contains(&pos) {
-                    if let Ok((type_parameters, params, return_type, r#async, span)) =
+                    if let Ok((type_parameters, params, return_type, predicate, r#async, span)) =
                         self.try_parse(Parser::parse_parenthesized_arrow_function_head)
                     {
                         return self.parse_arrow_function_body(
@@ -969,6 +967,7 @@ impl<'a> Parser<'a> {
                             type_parameters,
                             params,
                             return_type,
+                            predicate,
                             r#async,
                         );
                     }
@@ -12,6 +12,7 @@ type ArrowFunctionHead<'a> = (
     Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
     Box<'a, FormalParameters<'a>>,
     Option<Box<'a, TSTypeAnnotation<'a>>>,
+    Option<Box<'a, FlowPredicate<'a>>>,
     bool,
     Span,
 );
@@ -54,6 +55,11 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn parse_function_body(&mut self) -> Result<Box<'a, FunctionBody<'a>>> {
+        if self.at(Kind::LCurly) {
+            if let Some(body) = self.reuse_function_body() {
+                return Ok(body);
+            }
+        }
         let span = self.start_span();
         self.expect(Kind::LCurly)?;
 
@@ -90,14 +96,19 @@ impl<'a> Parser<'a> {
 
         let params = self.parse_formal_parameters(FormalParameterKind::FormalParameter)?;
 
-        let return_type = self.parse_ts_return_type_annotation()?;
+        let (return_type, predicate) = self.parse_return_type_and_flow_predicate()?;
 
         let body = if self.at(Kind::LCurly) { Some(self.parse_function_body()?) } else { None };
 
         self.ctx =
             self.ctx.and_in(ctx.has_in()).and_await(ctx.has_await()).and_yield(ctx.has_yield());
 
-        if !self.ts_enabled() && body.is_none() {
+        // A function without a body is a TypeScript overload, or a Flow `declare function`
+        // or method of a `declare class`
+        let bodyless = self.ts_only_enabled()
+            || (self.flow_enabled()
+                && (func_kind == FunctionKind::TSDeclaration || self.ctx.has_ambient()));
+        if !bodyless && body.is_none() {
             return Err(self.unexpected());
         }
 
@@ -113,7 +124,9 @@ impl<'a> Parser<'a> {
             }
         };
 
-        if FunctionType::TSDeclareFunction == function_type {
+        if FunctionType::TSDeclareFunction == function_type
+            && !self.eat_flow_declare_class_separator()
+        {
             self.asi()?;
         }
 
@@ -128,6 +141,7 @@ impl<'a> Parser<'a> {
             body,
             type_parameters,
             return_type,
+            predicate,
             modifiers,
         ))
     }
@@ -255,6 +269,7 @@ impl<'a> Parser<'a> {
             body,
             None,
             None,
+            None,
         ))
     }
 
@@ -379,7 +394,8 @@ impl<'a> Parser<'a> {
                     || self.nth_at(offset + 1, Kind::This) =>
                 {
                     match self.nth_kind(offset + 2) {
-                        // '(a: ' must be a type annotation
+                        // '(a: ' is a Flow type cast, or else must be a type annotation
+                        Kind::Colon if self.flow_enabled() => IsParenthesizedArrowFunction::Maybe,
                         Kind::Colon => IsParenthesizedArrowFunction::True,
                         // * '(a = ': an initializer or a parenthesized assignment expression
                         // * '(a, ': separator to next parameter or a parenthesized sequence expression
@@ -413,7 +429,8 @@ impl<'a> Parser<'a> {
                     return IsParenthesizedArrowFunction::False;
                 }
 
-                if self.source_type.is_jsx() {
+                // Flow tries `<T>(x) => x` as an arrow function before JSX
+                if self.source_type.is_jsx() && !self.flow_enabled() {
                     return match self.nth_kind(offset + 2) {
                         Kind::Extends => {
                             let third_kind = self.nth_kind(offset + 3);
@@ -464,7 +481,7 @@ impl<'a> Parser<'a> {
 
         let params = self.parse_formal_parameters(FormalParameterKind::ArrowFormalParameters)?;
 
-        let return_type = self.parse_ts_return_type_annotation()?;
+        let (return_type, predicate) = self.parse_return_type_and_flow_predicate()?;
 
         self.ctx = self.ctx.and_await(has_await);
 
@@ -474,7 +491,7 @@ impl<'a> Parser<'a> {
 
         self.expect(Kind::Arrow)?;
 
-        Ok((type_parameters, params, return_type, r#async, span))
+        Ok((type_parameters, params, return_type, predicate, r#async, span))
     }
 
     /// [ConciseBody](https://tc39.es/ecma262/#prod-ConciseBody)
@@ -488,6 +505,7 @@ impl<'a> Parser<'a> {
         type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
         params: Box<'a, FormalParameters<'a>>,
         return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        predicate: Option<Box<'a, FlowPredicate<'a>>>,
         r#async: bool,
     ) -> Result<Expression<'a>> {
         let has_await = self.ctx.has_await();
@@ -515,6 +533,7 @@ impl<'a> Parser<'a> {
             body,
             type_parameters,
             return_type,
+            predicate,
         ))
     }
 
@@ -522,8 +541,15 @@ impl<'a> Parser<'a> {
     /// `ArrowFunction`[In, Yield, Await] :
     ///     `ArrowParameters`[?Yield, ?Await] [no `LineTerminator` here] => `ConciseBody`[?In]
     pub(crate) fn parse_parenthesized_arrow_function(&mut self) -> Result<Expression<'a>> {
-        let (type_parameters, params, return_type, r#async, span) =
+        let (type_parameters, params, return_type, predicate, r#async, span) =
             self.parse_parenthesized_arrow_function_head()?;
-        self.parse_arrow_function_body(span, type_parameters, params, return_type, r#async)
+        self.parse_arrow_function_body(
+            span,
+            type_parameters,
+            params,
+            return_type,
+            predicate,
+            r#async,
+        )
     }
 }
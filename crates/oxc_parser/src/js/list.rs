@@ -225,6 +225,7 @@ impl<'a> SeparatedList<'a> for SequenceExpressionList<'a> {
     // ParenthesizedExpression or ArrowFormalParameters later
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
         let element = p.parse_assignment_expression_base()?;
+        let element = p.parse_flow_type_cast(element)?;
         self.elements.push(element);
         Ok(())
     }
@@ -37,7 +37,7 @@ impl<'a> Parser<'a> {
 
         self.bump_any(); // advance `import`
 
-        if self.ts_enabled()
+        if self.ts_only_enabled()
             && ((self.cur_kind().is_binding_identifier() && self.peek_at(Kind::Eq))
                 || (self.at(Kind::Type)
                     && self.peek_kind().is_binding_identifier()
@@ -47,8 +47,12 @@ impl<'a> Parser<'a> {
             return Ok(Statement::Declaration(decl));
         }
 
-        // `import type ...`
-        let import_kind = self.parse_import_or_export_kind();
+        // `import type ...` or Flow `import typeof ...`
+        let import_kind = if self.flow_enabled() && self.eat(Kind::Typeof) {
+            ImportOrExportKind::Typeof
+        } else {
+            self.parse_import_or_export_kind()
+        };
 
         let specifiers = if self.at(Kind::Str) {
             // import "source"
@@ -181,10 +185,10 @@ impl<'a> Parser<'a> {
         self.bump_any(); // advance `export`
 
         let decl = match self.cur_kind() {
-            Kind::Eq if self.ts_enabled() => self
+            Kind::Eq if self.ts_only_enabled() => self
                 .parse_ts_export_assignment_declaration()
                 .map(ModuleDeclaration::TSExportAssignment),
-            Kind::As if self.peek_at(Kind::Namespace) && self.ts_enabled() => self
+            Kind::As if self.peek_at(Kind::Namespace) && self.ts_only_enabled() => self
                 .parse_ts_export_namespace()
                 .map(ModuleDeclaration::TSNamespaceExportDeclaration),
             Kind::Default => self
@@ -283,7 +287,7 @@ impl<'a> Parser<'a> {
         // For tc39/proposal-decorators
         // For more information, please refer to <https://babeljs.io/docs/babel-plugin-proposal-decorators#decoratorsbeforeexport>
         self.eat_decorators()?;
-        let modifiers = if self.ts_enabled() {
+        let modifiers = if self.ts_only_enabled() {
             self.eat_modifiers_before_declaration().1
         } else {
             Modifiers::empty()
@@ -316,7 +320,7 @@ impl<'a> Parser<'a> {
             Kind::Class => self
                 .parse_class_declaration(decl_span, /* modifiers */ Modifiers::empty())
                 .map(ExportDefaultDeclarationKind::ClassDeclaration)?,
-            _ if self.at(Kind::Abstract) && self.peek_at(Kind::Class) && self.ts_enabled() => {
+            _ if self.at(Kind::Abstract) && self.peek_at(Kind::Class) && self.ts_only_enabled() => {
                 // eat the abstract modifier
                 let (_, modifiers) = self.eat_modifiers_before_declaration();
                 self.parse_class_declaration(decl_span, modifiers)
@@ -324,7 +328,7 @@ impl<'a> Parser<'a> {
             }
             _ if self.at(Kind::Interface)
                 && !self.peek_token().is_on_new_line
-                && self.ts_enabled() =>
+                && self.ts_only_enabled() =>
             {
                 self.parse_ts_interface_declaration(decl_span, Modifiers::empty()).map(|decl| {
                     match decl {
@@ -390,9 +394,11 @@ impl<'a> Parser<'a> {
             } else if peek_kind.is_identifier_name() {
                 import_kind = ImportOrExportKind::Type;
             }
+        } else if self.flow_enabled() && self.at(Kind::Typeof) {
+            import_kind = ImportOrExportKind::Typeof;
         }
 
-        if import_kind == ImportOrExportKind::Type {
+        if !import_kind.is_value() {
             self.bump_any();
         }
         let (imported, local) = if self.peek_at(Kind::As) {
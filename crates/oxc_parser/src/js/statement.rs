@@ -35,18 +35,33 @@ impl<'a> Parser<'a> {
         is_top_level: bool,
     ) -> Result<(Vec<'a, Directive>, Vec<'a, Statement<'a>>)> {
         let mut directives = self.ast.new_vec();
+        let statements = self.parse_statement_list(is_top_level, Some(&mut directives))?;
+        Ok((directives, statements))
+    }
+
+    /// Parse a `StatementList`, collecting its directive prologue into `directives`.
+    /// Pass `None` to parse the rest of a list whose prologue has already ended.
+    pub(crate) fn parse_statement_list(
+        &mut self,
+        is_top_level: bool,
+        mut directives: Option<&mut Vec<'a, Directive>>,
+    ) -> Result<Vec<'a, Statement<'a>>> {
         let mut statements = self.ast.new_vec();
 
-        let mut expecting_diretives = true;
         while !self.at(Kind::Eof) {
+            if is_top_level && directives.is_none() && self.at_reused_statement() {
+                break;
+            }
             match self.cur_kind() {
                 Kind::RCurly if !is_top_level => break,
                 Kind::Import if !matches!(self.peek_kind(), Kind::Dot | Kind::LParen) => {
                     let stmt = self.parse_import_declaration()?;
+                    directives = None;
                     statements.push(stmt);
                 }
                 Kind::Export => {
                     let stmt = self.parse_export_declaration()?;
+                    directives = None;
                     statements.push(stmt);
                 }
                 Kind::At => {
@@ -59,7 +74,7 @@ impl<'a> Parser<'a> {
                     // Section 11.2.1 Directive Prologue
                     // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
                     // All other method are flawed, see test cases in [babel](https://github.com/babel/babel/blob/main/packages/babel-parser/test/fixtures/core/categorized/not-directive/input.js)
-                    if expecting_diretives {
+                    if let Some(directives) = directives.as_deref_mut() {
                         if let Statement::ExpressionStatement(expr) = &stmt {
                             if let Expression::StringLiteral(string) = &expr.expression {
                                 let src = &self.source_text
@@ -73,15 +88,15 @@ impl<'a> Parser<'a> {
                                 continue;
                             }
                         }
-                        expecting_diretives = false;
                     }
+                    directives = None;
 
                     statements.push(stmt);
                 }
             };
         }
 
-        Ok((directives, statements))
+        Ok(statements)
     }
 
     /// `StatementListItem`[Yield, Await, Return] :
@@ -118,7 +133,7 @@ impl<'a> Parser<'a> {
             // [+Return] ReturnStatement[?Yield, ?Await]
             Kind::Return => self.parse_return_statement(),
             Kind::Var => self.parse_variable_statement(stmt_ctx),
-            Kind::Const if !(self.ts_enabled() && self.is_at_enum_declaration()) => {
+            Kind::Const if !(self.ts_only_enabled() && self.is_at_enum_declaration()) => {
                 self.parse_variable_statement(stmt_ctx)
             }
             Kind::Let if !self.cur_token().escaped => self.parse_let(stmt_ctx),
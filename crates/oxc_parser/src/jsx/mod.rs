@@ -78,7 +78,7 @@ impl<'a> Parser<'a> {
         self.expect(Kind::LAngle)?;
         let name = self.parse_jsx_element_name()?;
         // <Component<TsType> for tsx
-        let type_parameters = if self.ts_enabled() {
+        let type_parameters = if self.ts_only_enabled() {
             let ctx = self.ctx;
             self.ctx = Context::default();
             let args = self.parse_ts_type_arguments()?;
@@ -2,7 +2,11 @@
 
 use std::fmt;
 
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 #[non_exhaustive]
 pub enum Kind {
     Undetermined,
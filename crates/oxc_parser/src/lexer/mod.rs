@@ -112,6 +112,17 @@ impl<'a> Lexer<'a> {
         self.lookahead.clear();
     }
 
+    /// Move the lexer to `offset`, which must be at a token boundary, discarding the current
+    /// token and the lookahead.
+    pub fn seek(&mut self, offset: u32) {
+        self.current = LexerCheckpoint {
+            chars: self.source[offset as usize..].chars(),
+            token: Token::default(),
+            errors_pos: self.errors.len(),
+        };
+        self.lookahead.clear();
+    }
+
     /// Find the nth lookahead token lazily
     pub fn lookahead(&mut self, n: u8) -> &Token<'a> {
         let n = n as usize;
@@ -2,10 +2,13 @@
 
 use oxc_ast::ast::RegExpFlags;
 use oxc_span::Span;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
 
 use super::kind::Kind;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub struct Token<'a> {
     /// Token Kind
     pub kind: Kind,
@@ -38,16 +41,31 @@ impl<'a> Token<'a> {
     }
 }
 
+/// The value of a literal or an identifier, with the escape sequences resolved.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum TokenValue<'a> {
     None,
     Number(f64),
-    BigInt(num_bigint::BigInt),
+    BigInt(
+        #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_bigint"))]
+        num_bigint::BigInt,
+    ),
     String(&'a str),
     RegExp(RegExp<'a>),
 }
 
+/// JavaScript has no JSON representation for bigints, they are serialized as decimal strings.
+#[cfg(feature = "serde")]
+fn serialize_bigint<S: Serializer>(
+    value: &num_bigint::BigInt,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct RegExp<'a> {
     pub pattern: &'a str,
     pub flags: RegExpFlags,
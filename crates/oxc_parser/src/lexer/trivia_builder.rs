@@ -6,10 +6,23 @@ pub struct TriviaBuilder {
 }
 
 impl TriviaBuilder {
-    pub fn build(self) -> Trivias {
+    pub fn build(mut self) -> Trivias {
+        // Rewinding the lexer lexes the comments after the checkpoint again
+        self.trivias.sort_unstable_by_key(|(start, _, _)| *start);
+        self.trivias.dedup_by_key(|(start, _, _)| *start);
         self.trivias
     }
 
+    /// Add comments which are already lexed, e.g. by a previous parse
+    pub fn extend(&mut self, trivias: impl IntoIterator<Item = (u32, u32, CommentKind)>) {
+        self.trivias.extend(trivias);
+    }
+
+    /// Remove the comments starting at or after `offset`
+    pub fn truncate(&mut self, offset: u32) {
+        self.trivias.retain(|(start, _, _)| *start < offset);
+    }
+
     /// skip leading `//`
     pub fn add_single_line_comment(&mut self, start: u32, end: u32) {
         self.trivias.push((start + 2, end, CommentKind::SingleLine));
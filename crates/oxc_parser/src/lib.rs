@@ -40,6 +40,15 @@
 #![doc = include_str!("../examples/parser.rs")]
 //! ```
 //!
+//! # Tokens
+//!
+//! The tokens consumed by the parser are returned in [ParserReturn::tokens]
+//! when enabled by [Parser::collect_tokens].
+//! They are lexed in the context decided by the parser, e.g. a `/` is lexed as the start of
+//! a regular expression or as a division operator, and a `}` as the end of a block or
+//! as the continuation of a template literal.
+//! The raw text of a token is `token.span().source_text(source_text)`.
+//!
 //! # Visitor
 //!
 //! See [oxc_ast::Visit] and [oxc_ast::VisitMut]
@@ -64,8 +73,11 @@
 mod context;
 mod cursor;
 mod list;
+mod reparse;
 mod state;
+mod unambiguous;
 
+mod flow;
 mod js;
 mod jsx;
 mod ts;
@@ -79,10 +91,11 @@ use oxc_ast::{ast::Program, AstBuilder, Trivias};
 use oxc_diagnostics::{Error, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
 
-use crate::{
-    lexer::{Kind, Lexer, Token},
-    state::ParserState,
+pub use crate::{
+    flow::has_flow_pragma,
+    lexer::{Kind, RegExp, Token, TokenValue},
 };
+use crate::{lexer::Lexer, reparse::Reparse, state::ParserState};
 
 /// Return value of parser consisting of AST, errors and comments
 ///
@@ -93,6 +106,8 @@ pub struct ParserReturn<'a> {
     pub program: Program<'a>,
     pub errors: Vec<Error>,
     pub trivias: Trivias,
+    /// The tokens consumed by the parser, empty unless enabled by [Parser::collect_tokens].
+    pub tokens: Vec<Token<'a>>,
     pub panicked: bool,
 }
 
@@ -118,9 +133,15 @@ pub struct Parser<'a> {
     /// The end range of the previous token
     prev_token_end: u32,
 
+    /// The consumed tokens, when they are collected
+    tokens: Option<Vec<Token<'a>>>,
+
     /// Parser state
     state: ParserState<'a>,
 
+    /// The reusable parts of the previous program, when reparsing
+    reparse: Option<Reparse<'a>>,
+
     /// Parsing context
     ctx: Context,
 
@@ -138,7 +159,9 @@ impl<'a> Parser<'a> {
             errors: vec![],
             token: Token::default(),
             prev_token_end: 0,
+            tokens: None,
             state: ParserState::new(allocator),
+            reparse: None,
             ctx: Self::default_context(source_type),
             ast: AstBuilder::new(allocator),
         }
@@ -154,12 +177,48 @@ impl<'a> Parser<'a> {
         self
     }
 
+    /// Collect the tokens consumed by the parser into [ParserReturn::tokens]
+    #[must_use]
+    pub fn collect_tokens(mut self, collect: bool) -> Self {
+        self.tokens = collect.then(Vec::new);
+        self
+    }
+
     /// Main entry point
     ///
     /// Returns an empty `Program` on unrecoverable error,
     /// Recoverable errors are stored inside `errors`.
+    ///
+    /// An [unambiguous](oxc_span::ModuleKind::Unambiguous) source is parsed as a module,
+    /// and parsed again as a script when no module syntax is found.
     pub fn parse(mut self) -> ParserReturn<'a> {
-        let (program, panicked) = match self.parse_program() {
+        if self.source_type.is_unambiguous() {
+            return self.parse_unambiguous();
+        }
+        let result = self.parse_program();
+        self.finish(result)
+    }
+
+    fn parse_unambiguous(mut self) -> ParserReturn<'a> {
+        let allocator = self.ast.allocator;
+        let (source_text, source_type) = (self.source_text, self.source_type);
+        let allow_return = self.ctx.has_return();
+        let collect_tokens = self.tokens.is_some();
+
+        self.source_type = source_type.with_module(true);
+        let result = self.parse_program();
+        let ret = self.finish(result);
+        if unambiguous::has_module_syntax(&ret.program) {
+            return ret;
+        }
+        Parser::new(allocator, source_text, source_type.with_script(true))
+            .allow_return_outside_function(allow_return)
+            .collect_tokens(collect_tokens)
+            .parse()
+    }
+
+    fn finish(mut self, result: Result<Program<'a>>) -> ParserReturn<'a> {
+        let (program, panicked) = match result {
             Ok(program) => (program, false),
             Err(error) => {
                 self.error(self.flow_error().unwrap_or(error));
@@ -175,7 +234,8 @@ impl<'a> Parser<'a> {
         };
         let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
         let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias, panicked }
+        let tokens = self.tokens.unwrap_or_default();
+        ParserReturn { program, errors, trivias, tokens, panicked }
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -196,17 +256,15 @@ impl<'a> Parser<'a> {
         match source_type.module_kind() {
             ModuleKind::Script => ctx,
             // for [top-level-await](https://tc39.es/proposal-top-level-await/)
-            ModuleKind::Module => ctx.and_await(true),
+            // unambiguous sources are parsed as a module first
+            ModuleKind::Module | ModuleKind::Unambiguous => ctx.and_await(true),
         }
     }
 
     /// Check for Flow declaration if the file cannot be parsed.
-    /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
+    /// The declaration must be [in the comments before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
     fn flow_error(&self) -> Option<Error> {
-        if self.source_type.is_javascript()
-            && (self.source_text.starts_with("// @flow")
-                || self.source_text.starts_with("/* @flow */"))
-        {
+        if self.source_type.is_javascript() && has_flow_pragma(self.source_text) {
             return Some(diagnostics::Flow(Span::new(0, 8)).into());
         }
         None
@@ -231,13 +289,27 @@ impl<'a> Parser<'a> {
         self.errors.push(error.into());
     }
 
+    /// Type annotations, Flow annotations are parsed with the TypeScript parser functions
     fn ts_enabled(&self) -> bool {
+        self.source_type.is_typescript() || self.source_type.is_flow()
+    }
+
+    /// Syntax which is only in TypeScript, e.g. `enum`, `namespace`, `x as T` and `x!`
+    fn ts_only_enabled(&self) -> bool {
         self.source_type.is_typescript()
     }
+
+    fn flow_enabled(&self) -> bool {
+        self.source_type.is_flow()
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use oxc_ast::ast::{
+        Argument, Declaration, Expression, ImportDeclarationSpecifier, ImportOrExportKind,
+        ModuleDeclaration, Statement, TSSignature, TSType,
+    };
 
     use super::*;
 
@@ -265,4 +337,441 @@ mod test {
         assert!(ret.program.is_empty());
         assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
     }
+
+    #[test]
+    fn flow_pragma() {
+        for source in [
+            "// @flow\n",
+            "/* @flow */ a;",
+            "/**\n * Copyright\n * @flow strict\n */\nimport a from 'a';",
+            "#!/usr/bin/env node\n// @noflow\n",
+        ] {
+            assert!(has_flow_pragma(source), "{source}");
+        }
+        for source in ["a; // @flow", "// @flowtype\n", "/* @flow", "'use strict';\n// @flow\n"] {
+            assert!(!has_flow_pragma(source), "{source}");
+        }
+    }
+
+    #[test]
+    fn flow() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true).with_flow(true);
+        let source = "// @flow
+import type { A } from './a';
+import typeof B from './b';
+import { typeof C, type D, E } from './c';
+export type T = ?string;
+export opaque type O: string = string;
+declare opaque type P;
+type Exact = {| a: number, b: ?string |};
+type Union = {| a: string | number |};
+type Empty = {||};
+type Callback = ?() => void;
+function f(x: ?number, y?: Array<?T>): Exact | null { return null; }
+class K<V> { prop: V; method(): void {} +covariant: V; static -contravariant = 1; }
+const opaque = 1;
+const cast = (opaque: any);
+type Spread = { a: string, ...Exact, +b: number, -c: number };
+type Inexact = { a: string, ... };
+type List = ?string[];
+type Exists = Array<*>;
+function isString(x: mixed): boolean %checks { return typeof x === 'string'; }
+declare function isNumber(x: mixed): boolean %checks(typeof x === 'number');
+declare module.exports: { f: typeof f };
+declare class D<V> { m(): void, static n(x: V): V; +p: V; [k: string]: V }
+const fn = async <T>(x: T): Promise<T> => x;
+";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let ret = Parser::new(&allocator, source, source_type.with_jsx(true)).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let jsx = Parser::new(&allocator, "<T>text</T>;", source_type.with_jsx(true)).parse();
+        assert!(jsx.errors.is_empty(), "{:?}", jsx.errors);
+
+        let Statement::ModuleDeclaration(decl) = &ret.program.body[1] else { panic!() };
+        let ModuleDeclaration::ImportDeclaration(import) = &**decl else { panic!() };
+        assert!(import.import_kind.is_typeof());
+        let Statement::ModuleDeclaration(decl) = &ret.program.body[2] else { panic!() };
+        let ModuleDeclaration::ImportDeclaration(import) = &**decl else { panic!() };
+        let kinds = import
+            .specifiers
+            .iter()
+            .flatten()
+            .map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(s) => s.import_kind,
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [ImportOrExportKind::Typeof, ImportOrExportKind::Type, ImportOrExportKind::Value]
+        );
+
+        let Statement::Declaration(Declaration::FlowOpaqueTypeDeclaration(decl)) =
+            &ret.program.body[5]
+        else {
+            panic!()
+        };
+        assert!(decl.supertype.is_none() && decl.type_annotation.is_none());
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) =
+            &ret.program.body[6]
+        else {
+            panic!()
+        };
+        let TSType::FlowExactObjectType(ty) = &decl.type_annotation else { panic!() };
+        assert_eq!(ty.members.len(), 2);
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) =
+            &ret.program.body[9]
+        else {
+            panic!()
+        };
+        let TSType::FlowNullableType(ty) = &decl.type_annotation else { panic!() };
+        assert!(matches!(ty.type_annotation, TSType::TSFunctionType(_)));
+        let Statement::Declaration(Declaration::VariableDeclaration(decl)) = &ret.program.body[13]
+        else {
+            panic!()
+        };
+        let Some(Expression::ParenthesizedExpression(expr)) = &decl.declarations[0].init else {
+            panic!()
+        };
+        assert!(matches!(expr.expression, Expression::FlowTypeCastExpression(_)));
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) =
+            &ret.program.body[14]
+        else {
+            panic!()
+        };
+        let TSType::TSTypeLiteral(ty) = &decl.type_annotation else { panic!() };
+        assert!(matches!(ty.members[1], TSSignature::FlowObjectTypeSpread(_)));
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) =
+            &ret.program.body[15]
+        else {
+            panic!()
+        };
+        assert!(matches!(decl.type_annotation, TSType::FlowInexactObjectType(_)));
+        // `?string[]` is `?(string[])`
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) =
+            &ret.program.body[16]
+        else {
+            panic!()
+        };
+        let TSType::FlowNullableType(ty) = &decl.type_annotation else { panic!() };
+        assert!(matches!(ty.type_annotation, TSType::TSArrayType(_)));
+        let Statement::Declaration(Declaration::FunctionDeclaration(func)) = &ret.program.body[19]
+        else {
+            panic!()
+        };
+        assert!(func.predicate.as_ref().is_some_and(|predicate| predicate.value.is_some()));
+        assert!(matches!(
+            ret.program.body[20],
+            Statement::Declaration(Declaration::FlowDeclareModuleExports(_))
+        ));
+
+        // Flow syntax is an error in JavaScript
+        let source_type = SourceType::default().with_module(true);
+        let ret = Parser::new(&allocator, "type A = {| a: string |}", source_type).parse();
+        assert!(!ret.errors.is_empty());
+
+        // TypeScript only syntax is an error in Flow
+        let source_type = SourceType::default().with_module(true).with_flow(true);
+        for source in [
+            "enum E {}",
+            "namespace N {}",
+            "x as T",
+            "x!",
+            "class C { private a: string }",
+            "type A = {| a: string, ... |}",
+            "class C { +m() {} }",
+            "class C { m(): void }",
+        ] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn unambiguous() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_unambiguous(true);
+        let parse = |source| {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            ret.program.source_type
+        };
+
+        for source in [
+            "import a from 'a'",
+            "export {}",
+            "function f() { import.meta.url }",
+            "await a",
+            "for await (const a of b);",
+        ] {
+            assert!(parse(source).is_module(), "{source}");
+        }
+        for source in
+            ["a", "var await = 1", "async function f() { await a }", "a <!-- b", "with (a) b"]
+        {
+            assert!(parse(source).is_script(), "{source}");
+        }
+    }
+
+    #[test]
+    fn tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let kinds = |source| {
+            let ret = Parser::new(&allocator, source, source_type).collect_tokens(true).parse();
+            assert!(ret.errors.is_empty());
+            ret.tokens.iter().map(|token| token.kind).collect::<Vec<_>>()
+        };
+
+        assert!(Parser::new(&allocator, "a", source_type).parse().tokens.is_empty());
+        assert_eq!(kinds("a / /b/g"), [Kind::Ident, Kind::Slash, Kind::RegExp]);
+        assert_eq!(
+            kinds("`a${b}c${d}e`"),
+            [
+                Kind::TemplateHead,
+                Kind::Ident,
+                Kind::TemplateMiddle,
+                Kind::Ident,
+                Kind::TemplateTail
+            ]
+        );
+        assert_eq!(
+            kinds("<a-b>c</a-b>"),
+            [
+                Kind::LAngle,
+                Kind::Ident,
+                Kind::RAngle,
+                Kind::JSXText,
+                Kind::LAngle,
+                Kind::Slash,
+                Kind::Ident,
+                Kind::RAngle
+            ]
+        );
+        // `let` is a keyword consumed as an identifier, and the arrow function is parsed
+        // after rewinding from the parenthesized expression.
+        assert_eq!(
+            kinds("let = (a) => a"),
+            [
+                Kind::Ident,
+                Kind::Eq,
+                Kind::LParen,
+                Kind::Ident,
+                Kind::RParen,
+                Kind::Arrow,
+                Kind::Ident
+            ]
+        );
+
+        let source = "a\n'\\x61' + b";
+        let ret = Parser::new(&allocator, source, source_type).collect_tokens(true).parse();
+        let token = &ret.tokens[1];
+        assert!(token.is_on_new_line);
+        assert!(!ret.tokens[2].is_on_new_line);
+        assert_eq!(token.span().source_text(source), "'\\x61'");
+        assert_eq!(token.value.get_string(), Some("a"));
+    }
+
+    /// The range of `before` replaced by `after`, as reported by an editor
+    #[allow(clippy::cast_possible_truncation)]
+    fn edit(before: &str, after: &str) -> Span {
+        let prefix = before.bytes().zip(after.bytes()).take_while(|(a, b)| a == b).count();
+        let suffix = before[prefix..]
+            .bytes()
+            .rev()
+            .zip(after[prefix..].bytes().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        Span::new(prefix as u32, (before.len() - suffix) as u32)
+    }
+
+    #[test]
+    fn reparse() {
+        let allocator = Allocator::default();
+        let check = |source_type: SourceType, before: &'static str, after: &'static str| {
+            let previous =
+                Parser::new(&allocator, before, source_type).collect_tokens(true).parse();
+            let ret = Parser::new(&allocator, after, source_type)
+                .collect_tokens(true)
+                .reparse(previous, edit(before, after));
+            let expected = Parser::new(&allocator, after, source_type).collect_tokens(true).parse();
+            assert_eq!(format!("{:?}", ret.program), format!("{:?}", expected.program), "{after}");
+            assert_eq!(ret.trivias, expected.trivias, "{after}");
+            assert_eq!(format!("{:?}", ret.tokens), format!("{:?}", expected.tokens), "{after}");
+            assert_eq!(ret.errors.len(), expected.errors.len(), "{after}");
+        };
+
+        let js = SourceType::default();
+        check(js, "'use strict'; a; /* b */ b; c; d", "'use strict'; a; /* b */ b; c; dd");
+        check(js, "'use strict'; a; b", "'use stric'; a; b");
+        check(js, "a; 'b'", "'b'");
+        check(js, "a; b; c\nd", "a; b; c\n(d)");
+        check(js, "a; b\nc; d", "a; b\n(c); d");
+        check(js, "a; b; function c() {}", "a; b; function c() { return }");
+        check(js, "a; b; 'c'", "a; b; 'c';");
+        check(js, "a; b; c", "a; b; c;; d(");
+        check(js, "a", "b");
+        check(js, "a; b; c; d; e", "a; b; x; y; z; c; d; e");
+        check(
+            js,
+            "a; /* 1 */ b; // 2\nc; d /* 3 */",
+            "a; /* 1 */ b; x /* 4 */; // 2\nc; d /* 3 */",
+        );
+        check(js, "a; b; c; d", "a; b; cc\nd");
+        check(js, "a; b; c; d", "a; b; x(\n c; d");
+        check(js, "a; b; c; `${d}`", "a; b; c + 1; `${d}`");
+        check(js, "a; b;\n@x class C {}", "a; b;\n@x class D {}");
+        check(
+            js,
+            "function f() { a } function g() { b }",
+            "function f() { a; } function g() { b }",
+        );
+        check(
+            js,
+            "(function () { function f() { a } function g() { b } })()",
+            "(function () { function f() { a } 1; function g() { b } })()",
+        );
+        check(
+            js,
+            "x(() => { await(1) }, function* () { yield })",
+            "x(async () => { await(1) }, function* () { yield })",
+        );
+        check(js, "x(function () { yield(1) })", "x(function* () { yield(1) })");
+        check(
+            js,
+            "x(function () { a }, function () { b })",
+            "x(function () { a }, function () { b; })",
+        );
+        check(
+            SourceType::default().with_module(true),
+            "import a from 'a'; 'b'; c",
+            "import a from 'a'; 'b'; d",
+        );
+        check(
+            SourceType::default().with_typescript(true),
+            "let a: T; b; enum C { D }",
+            "let a: U; b; enum C { D }",
+        );
+    }
+
+    #[test]
+    fn reparse_at_every_offset() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let before = "import a from 'a';
+// comment
+export function f(x, y = () => { return 1 }) {
+  if (x) { return /re/g.test(`${y}`) }
+  const g = async () => { await x; };
+  return class { m() { return x / 2 } }
+}
+/* block */ let b = f(function () { yield: 1 }, a => { a++ });
+label: for (const c of b) { continue label }
+export default { d() {}, get e() { return 1 } };
+";
+        for offset in 0..before.len() {
+            for edit in ["", ";", "\n", "(", "}", "async ", "x"] {
+                let end = if edit.is_empty() { offset + 1 } else { offset };
+                let after = format!("{}{edit}{}", &before[..offset], &before[end..]);
+                let after = allocator.alloc_str(&after);
+                let previous =
+                    Parser::new(&allocator, before, source_type).collect_tokens(true).parse();
+                #[allow(clippy::cast_possible_truncation)]
+                let edit = Span::new(offset as u32, end as u32);
+                let ret = Parser::new(&allocator, after, source_type)
+                    .collect_tokens(true)
+                    .reparse(previous, edit);
+                let expected =
+                    Parser::new(&allocator, after, source_type).collect_tokens(true).parse();
+                assert_eq!(
+                    format!("{:?}", ret.program),
+                    format!("{:?}", expected.program),
+                    "{after}"
+                );
+                assert_eq!(ret.trivias, expected.trivias, "{after}");
+                assert_eq!(
+                    format!("{:?}", ret.tokens),
+                    format!("{:?}", expected.tokens),
+                    "{after}"
+                );
+                assert_eq!(ret.errors.len(), expected.errors.len(), "{after}");
+            }
+        }
+    }
+
+    /// The addresses of the nodes of the statements, and of the function bodies in the call
+    /// arguments of expression statements
+    fn node_addresses(program: &Program) -> (std::vec::Vec<usize>, std::vec::Vec<usize>) {
+        let mut statements = vec![];
+        let mut bodies = vec![];
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExpressionStatement(stmt) => {
+                    statements.push(std::ptr::addr_of!(**stmt) as usize);
+                    let Expression::CallExpression(call) = &stmt.expression else { continue };
+                    for arg in &call.arguments {
+                        match arg {
+                            Argument::Expression(Expression::FunctionExpression(func)) => {
+                                let body = func.body.as_ref().unwrap();
+                                bodies.push(std::ptr::addr_of!(**body) as usize);
+                            }
+                            Argument::Expression(Expression::ArrowExpression(arrow)) => {
+                                bodies.push(std::ptr::addr_of!(*arrow.body) as usize);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Statement::Declaration(Declaration::FunctionDeclaration(func)) => {
+                    statements.push(std::ptr::addr_of!(**func) as usize);
+                }
+                _ => unreachable!(),
+            }
+        }
+        (statements, bodies)
+    }
+
+    #[test]
+    fn reparse_reuses_nodes() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let before = "a;\nb;\nfunction f() { c }\nx(function () { d }, () => { e });\ng;";
+        let after = "a;\nb;\nfunction f() { c }\nx(function () { d }, 1, () => { e });\ng;";
+        let previous = Parser::new(&allocator, before, source_type).parse();
+        let (statements, bodies) = node_addresses(&previous.program);
+
+        let ret =
+            Parser::new(&allocator, after, source_type).reparse(previous, edit(before, after));
+        let (new_statements, new_bodies) = node_addresses(&ret.program);
+        // The statements before and after the edit are moved, except for the last one before it
+        assert_eq!(new_statements[..2], statements[..2]);
+        assert_ne!(new_statements[2..4], statements[2..4]);
+        assert_eq!(new_statements[4], statements[4]);
+        // The function bodies in the edited statement are moved
+        assert_eq!(new_bodies, bodies);
+
+        let expected = Parser::new(&allocator, after, source_type).parse();
+        assert_eq!(format!("{:?}", ret.program), format!("{:?}", expected.program));
+    }
+
+    #[test]
+    fn clone_in_and_content_eq() {
+        use oxc_allocator::CloneIn;
+        use oxc_span::{ContentEq, GetSpan};
+
+        let allocator = Allocator::default();
+        let other = Allocator::default();
+        let source = "a + b.c(1, `d${e}`); x; a  +  b.c(1, `d${e}`); a + b.c(2, `d${e}`);";
+        let program = Parser::new(&allocator, source, SourceType::default()).parse().program;
+        let stmts = &program.body;
+        assert!(stmts[0].content_eq(&stmts[2]));
+        assert!(stmts[0].content_ne(&stmts[1]));
+        assert!(stmts[0].content_ne(&stmts[3]));
+
+        let cloned = program.clone_in(&other);
+        assert!(cloned.content_eq(&program));
+        assert_eq!(cloned.body[0].span(), stmts[0].span());
+    }
 }
@@ -0,0 +1,301 @@
+//! Incremental reparsing
+//!
+//! After an edit, the source text is parsed again from the last top-level statement before
+//! the edit, until the parser reaches the start of a top-level statement after the edit.
+//! The statements before and after are moved from the previous program into the new one,
+//! and the function bodies in between are reused when they are outside of the edit.
+
+use std::collections::{HashMap, VecDeque};
+
+use oxc_allocator::Box;
+use oxc_ast::{
+    ast::{ArrowExpression, Function, FunctionBody, Program, Statement},
+    AstBuilder, Trivias, VisitMut,
+};
+use oxc_span::{GetSpan, ShiftSpan, Span, SPAN};
+
+use crate::{context::Context, lexer::Token, Parser, ParserReturn};
+
+/// The parts of the previous program which may be reused while reparsing.
+pub struct Reparse<'a> {
+    /// The replaced range of the previous source text
+    edit: Span,
+
+    /// The end of the edit in the new source text
+    new_edit_end: u32,
+
+    /// The top-level statements of the previous program after the reused ones, which are not
+    /// reparsed yet
+    statements: VecDeque<Statement<'a>>,
+
+    /// The outermost function bodies of the previous statements which are being reparsed,
+    /// keyed by their start in the previous source text
+    function_bodies: HashMap<u32, ReusableFunctionBody<'a>>,
+
+    /// The previous offset and new offset of the first statement to reuse after the edit,
+    /// once the parser has reached it
+    resume: Option<(u32, u32)>,
+
+    trivias: Trivias,
+
+    tokens: Vec<Token<'a>>,
+}
+
+struct ReusableFunctionBody<'a> {
+    body: Box<'a, FunctionBody<'a>>,
+
+    /// The context the body was parsed in
+    ctx: Context,
+}
+
+impl<'a> Reparse<'a> {
+    /// The offset in the previous source text of `offset` in the new source text,
+    /// or `None` when it is in the edit
+    fn previous_offset(&self, offset: u32) -> Option<u32> {
+        if offset < self.edit.start {
+            Some(offset)
+        } else if offset >= self.new_edit_end {
+            Some(offset - self.new_edit_end + self.edit.end)
+        } else {
+            None
+        }
+    }
+
+    /// Collect the function bodies of the previous statements starting before `offset`,
+    /// or at `offset` when `inclusive`, which will not be reused as a whole.
+    fn take_statements_before(&mut self, offset: u32, inclusive: bool, ast: &AstBuilder<'a>) {
+        while let Some(stmt) = self.statements.front() {
+            let start = stmt.span().start;
+            if start > offset || (start == offset && !inclusive) {
+                break;
+            }
+            let mut stmt = self.statements.pop_front().unwrap();
+            FunctionBodies { ast, bodies: &mut self.function_bodies }.visit_statement(&mut stmt);
+        }
+    }
+
+    /// Add the comments and tokens of the previous source text in `range` to the ones
+    /// of `parser`, moved to `to`.
+    /// The text before the first token may be changed by the edit, so whether it is on a new
+    /// line is given by `is_on_new_line`.
+    fn reuse_trivias_and_tokens(
+        &self,
+        range: Span,
+        to: u32,
+        is_on_new_line: bool,
+        parser: &mut Parser<'a>,
+    ) {
+        let shift = |offset: u32| offset - range.start + to;
+
+        let first = self.trivias.partition_point(|(start, _, _)| *start < range.start);
+        let last = self.trivias.partition_point(|(start, _, _)| *start < range.end);
+        parser.lexer.trivia_builder.extend(
+            self.trivias[first..last]
+                .iter()
+                .map(|&(start, end, kind)| (shift(start), shift(end), kind)),
+        );
+
+        if let Some(tokens) = &mut parser.tokens {
+            let first = self.tokens.partition_point(|token| token.start < range.start);
+            let last = self.tokens.partition_point(|token| token.start < range.end);
+            tokens.extend(self.tokens[first..last].iter().enumerate().map(|(i, token)| Token {
+                start: shift(token.start),
+                end: shift(token.end),
+                is_on_new_line: if i == 0 { is_on_new_line } else { token.is_on_new_line },
+                ..token.clone()
+            }));
+        }
+    }
+}
+
+/// Moves the outermost function bodies out of the visited nodes.
+struct FunctionBodies<'r, 'a> {
+    ast: &'r AstBuilder<'a>,
+    bodies: &'r mut HashMap<u32, ReusableFunctionBody<'a>>,
+}
+
+impl<'r, 'a> FunctionBodies<'r, 'a> {
+    fn insert(&mut self, body: Box<'a, FunctionBody<'a>>, r#async: bool, generator: bool) {
+        // `parse_function_body` is called in the `In` context, with `Return` added
+        let ctx = Context::In.and_return(true).and_await(r#async).and_yield(generator);
+        self.bodies.insert(body.span.start, ReusableFunctionBody { body, ctx });
+    }
+}
+
+impl<'r, 'a> VisitMut<'a> for FunctionBodies<'r, 'a> {
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = func.body.take() {
+            self.insert(body, func.r#async, func.generator);
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        self.visit_formal_parameters(&mut expr.params);
+        if expr.expression {
+            // The body is an expression, wrapped in a statement by the parser
+            self.visit_function_body(&mut expr.body);
+        } else {
+            let empty = self.ast.function_body(SPAN, self.ast.new_vec(), self.ast.new_vec());
+            let body = std::mem::replace(&mut expr.body, empty);
+            self.insert(body, expr.r#async, false);
+        }
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Parse the source text after an edit, reusing the parts of `previous` which are not
+    /// affected by the edit.
+    ///
+    /// `previous` is the return value of parsing the source text before the edit,
+    /// and `edit` is the range of the previous source text which was replaced.
+    /// The source texts before and after `edit` must be the same.
+    /// The result is the same as [Parser::parse].
+    ///
+    /// * The top-level statements ending before the edit are moved into the new program as is,
+    ///   except for the last one, which is reparsed because the edit may continue it
+    ///   (e.g. inserting `(b)` after `a\n`).
+    /// * The source text is reparsed from there, until the parser reaches a top-level statement
+    ///   which started at the same position after the edit. This statement and the ones after
+    ///   it are moved into the new program, with their spans shifted by the edit.
+    /// * The reparsed function bodies which are outside of the edit are reused as well,
+    ///   when they are parsed in the same context (e.g. an `async` function).
+    ///
+    /// A full parse is done when `previous` has errors.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn reparse(mut self, previous: ParserReturn<'a>, edit: Span) -> ParserReturn<'a> {
+        let ParserReturn { mut program, errors, trivias, tokens, panicked } = previous;
+        let (previous_len, len) = (program.span.end, self.source_text.len() as u32);
+        if panicked
+            || !errors.is_empty()
+            || program.source_type != self.source_type
+            || (self.tokens.is_some() && tokens.is_empty())
+            || edit.start > edit.end
+            || edit.end > previous_len
+            || previous_len - edit.end > len
+        {
+            return self.parse();
+        }
+        let new_edit_end = len - (previous_len - edit.end);
+        if new_edit_end < edit.start {
+            return self.parse();
+        }
+
+        let reused = program
+            .body
+            .iter()
+            .take_while(|stmt| stmt.span().end < edit.start)
+            .count()
+            .saturating_sub(1);
+        let statements = program.body.drain(reused..).collect();
+        let reparse = Reparse {
+            edit,
+            new_edit_end,
+            statements,
+            function_bodies: HashMap::new(),
+            resume: None,
+            trivias,
+            tokens,
+        };
+
+        let result = if reused == 0 {
+            self.reparse = Some(reparse);
+            self.parse_program()
+        } else {
+            // Continue lexing from the end of the last reused statement
+            let resume = program.body[reused - 1].span().end;
+            reparse.reuse_trivias_and_tokens(Span::new(0, resume), 0, true, &mut self);
+            self.reparse = Some(reparse);
+            self.lexer.seek(resume);
+            self.token = self.lexer.next_token();
+            self.prev_token_end = resume;
+
+            self.parse_statement_list(/* is_top_level */ true, None).map(|statements| {
+                program.body.extend(statements);
+                program.span = Span::new(0, len);
+                program
+            })
+        };
+
+        let result = result.map(|mut program| {
+            self.reuse_statements_after_edit(&mut program, previous_len);
+            program
+        });
+        self.finish(result)
+    }
+
+    /// Move the previous statements after the one the parser stopped at into `program`.
+    fn reuse_statements_after_edit(&mut self, program: &mut Program<'a>, previous_len: u32) {
+        let Some(reparse) = self.reparse.take() else { return };
+        let Some((previous_start, start)) = reparse.resume else { return };
+
+        // The comments lexed after `start` are replaced by the previous ones
+        self.lexer.trivia_builder.truncate(start);
+        let range = Span::new(previous_start, previous_len);
+        reparse.reuse_trivias_and_tokens(range, start, self.token.is_on_new_line, self);
+        program.body.extend(reparse.statements.into_iter().map(|mut stmt| {
+            stmt.shift_span(previous_start, start);
+            stmt
+        }));
+    }
+
+    /// Whether the parser is at the start of a top-level statement of the previous program
+    /// after the edit, which is reused with the rest of the previous statements.
+    pub(crate) fn at_reused_statement(&mut self) -> bool {
+        let start = self.cur_token().start;
+        let Some(reparse) = &mut self.reparse else { return false };
+        if start < reparse.new_edit_end || !self.state.decorators.is_empty() {
+            return false;
+        }
+        let previous_start = start - reparse.new_edit_end + reparse.edit.end;
+        reparse.take_statements_before(previous_start, /* inclusive */ false, &self.ast);
+        if reparse.statements.front().map(|stmt| stmt.span().start) != Some(previous_start) {
+            return false;
+        }
+        reparse.resume = Some((previous_start, start));
+        true
+    }
+
+    /// The function body of the previous program at the current `{`,
+    /// when it is outside of the edit and parsed in the same context.
+    /// The parser is moved to the end of the body.
+    pub(crate) fn reuse_function_body(&mut self) -> Option<Box<'a, FunctionBody<'a>>> {
+        let start = self.cur_token().start;
+        let mut reparse = self.reparse.take()?;
+        let body = self.take_function_body(&mut reparse, start);
+        self.reparse = Some(reparse);
+        body
+    }
+
+    fn take_function_body(
+        &mut self,
+        reparse: &mut Reparse<'a>,
+        start: u32,
+    ) -> Option<Box<'a, FunctionBody<'a>>> {
+        let previous_start = reparse.previous_offset(start)?;
+        reparse.take_statements_before(previous_start, /* inclusive */ true, &self.ast);
+        let ReusableFunctionBody { mut body, ctx } =
+            reparse.function_bodies.remove(&previous_start)?;
+        let previous_end = body.span.end;
+        if ctx != self.ctx.and_return(true)
+            || (previous_start < reparse.edit.end && previous_end > reparse.edit.start)
+        {
+            // Reparse the body, reusing the function bodies in it
+            let mut bodies =
+                FunctionBodies { ast: &self.ast, bodies: &mut reparse.function_bodies };
+            bodies.visit_statements(&mut body.statements);
+            return None;
+        }
+
+        if previous_start != start {
+            body.shift_span(previous_start, start);
+        }
+        let range = Span::new(previous_start, previous_end);
+        reparse.reuse_trivias_and_tokens(range, start, self.token.is_on_new_line, self);
+        let end = body.span.end;
+        self.lexer.seek(end);
+        self.token = self.lexer.next_token();
+        self.prev_token_end = end;
+        Some(body)
+    }
+}
@@ -5,14 +5,18 @@ impl<'a> Parser<'a> {
     fn at_start_of_ts_declaration_worker(&mut self) -> bool {
         loop {
             match self.cur_kind() {
-                Kind::Var | Kind::Let | Kind::Const | Kind::Function | Kind::Class | Kind::Enum => {
+                Kind::Var | Kind::Let | Kind::Const | Kind::Function | Kind::Class => {
                     return true;
                 }
+                Kind::Enum => return self.ts_only_enabled(),
                 Kind::Interface | Kind::Type => {
                     self.bump_any();
                     return self.cur_kind().is_binding_identifier()
                         && !self.cur_token().is_on_new_line;
                 }
+                Kind::Ident if self.is_at_flow_opaque_type() => return true,
+                Kind::Declare if self.is_at_flow_declare_module_exports() => return true,
+                Kind::Namespace if !self.ts_only_enabled() => return false,
                 Kind::Module | Kind::Namespace => {
                     self.bump_any();
                     return !self.cur_token().is_on_new_line
@@ -21,6 +25,16 @@ impl<'a> Parser<'a> {
                 }
                 Kind::Abstract
                 | Kind::Accessor
+                | Kind::Private
+                | Kind::Protected
+                | Kind::Public
+                | Kind::Readonly
+                    if !self.ts_only_enabled() =>
+                {
+                    return false;
+                }
+                Kind::Abstract
+                | Kind::Accessor
                 | Kind::Async
                 | Kind::Declare
                 | Kind::Private
@@ -32,6 +46,7 @@ impl<'a> Parser<'a> {
                         return false;
                     }
                 }
+                Kind::Global if !self.ts_only_enabled() => return false,
                 Kind::Global => {
                     self.bump_any();
                     return matches!(self.cur_kind(), Kind::Ident | Kind::LCurly | Kind::Export);
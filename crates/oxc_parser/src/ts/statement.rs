@@ -33,8 +33,10 @@ impl<'a> Parser<'a> {
         self.bump_any(); // bump `enum`
 
         let id = self.parse_binding_identifier()?;
+        let body_span = self.start_span();
         let members = TSEnumMemberList::parse(self)?.members;
-        Ok(self.ast.ts_enum_declaration(span, id, members, modifiers))
+        let body_span = self.end_span(body_span);
+        Ok(self.ast.ts_enum_declaration(self.end_span(span), id, body_span, members, modifiers))
     }
 
     pub(crate) fn parse_ts_enum_member(&mut self) -> Result<TSEnumMember<'a>> {
@@ -111,7 +113,13 @@ impl<'a> Parser<'a> {
         let annotation = self.parse_ts_type()?;
 
         self.asi()?;
-        Ok(self.ast.ts_type_alias_declaration(span, id, annotation, params, modifiers))
+        Ok(self.ast.ts_type_alias_declaration(
+            self.end_span(span),
+            id,
+            annotation,
+            params,
+            modifiers,
+        ))
     }
 
     /** ---------------------  Interface  ------------------------ */
@@ -153,6 +161,9 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn parse_ts_type_signature(&mut self) -> Result<TSSignature<'a>> {
+        if self.flow_enabled() && self.at(Kind::Dot3) {
+            return self.parse_flow_object_type_spread();
+        }
         if self.is_at_ts_index_signature_member() {
             return self.parse_ts_index_signature_member();
         }
@@ -312,9 +323,14 @@ impl<'a> Parser<'a> {
         modifiers: Modifiers<'a>,
     ) -> Result<Declaration<'a>> {
         match self.cur_kind() {
+            Kind::Module if self.flow_enabled() && self.peek_at(Kind::Dot) => {
+                self.parse_flow_declare_module_exports(start_span)
+            }
+            Kind::Namespace if !self.ts_only_enabled() => Err(self.unexpected()),
             Kind::Namespace | Kind::Module => self
                 .parse_ts_namespace_or_module_declaration(modifiers)
                 .map(Declaration::TSModuleDeclaration),
+            Kind::Global if !self.ts_only_enabled() => Err(self.unexpected()),
             Kind::Global => {
                 let decl = if self.peek_at(Kind::LCurly) {
                     // valid syntax for
@@ -326,14 +342,19 @@ impl<'a> Parser<'a> {
                 Ok(Declaration::TSModuleDeclaration(decl))
             }
             Kind::Type => self.parse_ts_type_alias_declaration(start_span, modifiers),
-            Kind::Enum => self.parse_ts_enum_declaration(start_span, modifiers),
+            Kind::Ident if self.is_at_flow_opaque_type() => {
+                self.parse_flow_opaque_type_declaration(start_span, modifiers)
+            }
+            Kind::Enum if self.ts_only_enabled() => {
+                self.parse_ts_enum_declaration(start_span, modifiers)
+            }
             Kind::Interface if self.is_at_interface_declaration() => {
                 self.parse_ts_interface_declaration(start_span, modifiers)
             }
             Kind::Class => self
                 .parse_class_declaration(start_span, modifiers)
                 .map(Declaration::ClassDeclaration),
-            Kind::Import => {
+            Kind::Import if self.ts_only_enabled() => {
                 self.bump_any();
                 self.parse_ts_import_equals_declaration(start_span, true)
             }
@@ -349,7 +370,7 @@ impl<'a> Parser<'a> {
                 if declare {
                     self.parse_ts_declare_function(start_span, modifiers)
                         .map(Declaration::FunctionDeclaration)
-                } else if self.ts_enabled() {
+                } else if self.ts_only_enabled() {
                     self.parse_ts_function_impl(
                         start_span,
                         FunctionKind::Declaration { single_statement: true },
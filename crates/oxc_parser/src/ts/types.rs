@@ -249,18 +249,20 @@ impl<'a> Parser<'a> {
     // type C = A & C | C;
     fn parse_ts_union_type(&mut self) -> Result<TSType<'a>> {
         let span = self.start_span();
-        if self.at(Kind::Pipe) {
+        if self.at_union_type_pipe() {
             let mut types = self.ast.new_vec();
-            while self.eat(Kind::Pipe) {
+            while self.at_union_type_pipe() {
+                self.bump_any();
                 types.push(self.parse_ts_intersection_type()?);
             }
             Ok(self.ast.ts_union_type(self.end_span(span), types))
         } else {
             let first = self.parse_ts_intersection_type()?;
-            if self.at(Kind::Pipe) {
+            if self.at_union_type_pipe() {
                 let mut types = self.ast.new_vec();
                 types.push(first);
-                while self.eat(Kind::Pipe) {
+                while self.at_union_type_pipe() {
+                    self.bump_any();
                     types.push(self.parse_ts_intersection_type()?);
                 }
                 Ok(self.ast.ts_union_type(self.end_span(span), types))
@@ -296,11 +298,14 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_ts_primary_type(&mut self) -> Result<TSType<'a>> {
+    pub(crate) fn parse_ts_primary_type(&mut self) -> Result<TSType<'a>> {
         let span = self.start_span();
         if self.at(Kind::Infer) {
             return self.parse_ts_infer_type();
         }
+        if self.flow_enabled() && self.at(Kind::Question) {
+            return self.parse_flow_nullable_type();
+        }
 
         let mut operator = None;
 
@@ -367,6 +372,10 @@ impl<'a> Parser<'a> {
                 result
             }
             Kind::LBrack => self.parse_ts_tuple_type(),
+            Kind::LCurly if self.is_at_flow_exact_object_type() => {
+                self.parse_flow_exact_object_type()
+            }
+            Kind::LCurly if self.flow_enabled() => self.parse_flow_object_type(),
             Kind::LCurly => {
                 if self.is_at_mapped_type() {
                     self.parse_ts_mapped_type()
@@ -396,6 +405,7 @@ impl<'a> Parser<'a> {
             }
             Kind::Import => self.parse_ts_import_type(),
             Kind::Minus if self.peek_kind().is_number() => self.parse_ts_literal_type(),
+            Kind::Star if self.flow_enabled() => Ok(self.parse_flow_exists_type()),
             Kind::Question => self.parse_js_doc_unknown_or_nullable_type(),
             kind if kind.is_literal() => self.parse_ts_literal_type(),
             _ => {
@@ -540,7 +550,7 @@ impl<'a> Parser<'a> {
         Ok(self.ast.ts_tuple_type(self.end_span(span), elements))
     }
 
-    fn is_at_function_type(&mut self) -> bool {
+    pub(crate) fn is_at_function_type(&mut self) -> bool {
         if self.at(Kind::LAngle) {
             return true;
         }
@@ -799,7 +809,7 @@ impl<'a> Parser<'a> {
         ))
     }
 
-    fn parse_ts_function_type(&mut self) -> Result<TSType<'a>> {
+    pub(crate) fn parse_ts_function_type(&mut self) -> Result<TSType<'a>> {
         let span = self.start_span();
         let type_parameters = self.parse_ts_type_parameters()?;
         let params = self.parse_formal_parameters(FormalParameterKind::Signature)?;
@@ -972,6 +982,7 @@ impl<'a> Parser<'a> {
         &mut self,
     ) -> Result<TSSignature<'a>> {
         let span = self.start_span();
+        let variance = self.parse_flow_variance();
         let readonly = self.at(Kind::Readonly) && self.is_next_at_type_member_name();
 
         if readonly {
@@ -982,6 +993,7 @@ impl<'a> Parser<'a> {
         let optional = self.eat(Kind::Question);
 
         if self.at(Kind::LParen) || self.at(Kind::LAngle) {
+            self.check_flow_variance_on_method(variance);
             let TSSignature::TSCallSignatureDeclaration(call_signature) =
                 self.parse_ts_call_signature_member()?
             else {
@@ -1009,6 +1021,7 @@ impl<'a> Parser<'a> {
                 computed,
                 optional,
                 readonly,
+                variance,
                 key,
                 type_annotation,
             ))
@@ -1079,7 +1092,16 @@ impl<'a> Parser<'a> {
     ) -> ModifierFlags {
         let mut flags = ModifierFlags::empty();
 
-        if !self.ts_enabled() {
+        if !self.ts_only_enabled() {
+            // Flow `declare a: A`
+            if self.flow_enabled()
+                && !is_constructor_parameter
+                && self.at(Kind::Declare)
+                && self.is_nth_at_modifier(0, false)
+            {
+                self.bump_any();
+                flags.set(ModifierFlags::DECLARE, true);
+            }
             return flags;
         }
 
@@ -0,0 +1,40 @@
+//! Module syntax detection for [oxc_span::ModuleKind::Unambiguous]
+
+use oxc_ast::{ast::*, AstKind, Visit};
+
+/// Whether the program contains `import` / `export` declarations, `import.meta`
+/// or top-level `await`.
+pub fn has_module_syntax(program: &Program) -> bool {
+    if program.body.iter().any(|stmt| matches!(stmt, Statement::ModuleDeclaration(_))) {
+        return true;
+    }
+    let mut finder = ModuleSyntaxFinder::default();
+    finder.visit_program(program);
+    finder.found
+}
+
+#[derive(Default)]
+struct ModuleSyntaxFinder {
+    found: bool,
+    function_depth: usize,
+}
+
+impl<'a> Visit<'a> for ModuleSyntaxFinder {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::Function(_) | AstKind::ArrowExpression(_) => self.function_depth += 1,
+            AstKind::MetaProperty(meta) if meta.meta.name == "import" => self.found = true,
+            AstKind::AwaitExpression(_) if self.function_depth == 0 => self.found = true,
+            AstKind::ForOfStatement(stmt) if stmt.r#await && self.function_depth == 0 => {
+                self.found = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Function(_) | AstKind::ArrowExpression(_)) {
+            self.function_depth -= 1;
+        }
+    }
+}
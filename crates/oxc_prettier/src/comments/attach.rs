@@ -0,0 +1,360 @@
+//! Comment attachment
+//!
+//! Every comment is decorated with its enclosing node and the nodes right before and after it,
+//! then attached to one of them with a few special cases from [super::handle].
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/main/comments/attach.js>
+
+use std::collections::BTreeMap;
+
+use oxc_ast::{
+    ast::{CatchClause, Program},
+    AstKind, CommentKind, Visit,
+};
+use oxc_span::{GetSpan, Span};
+
+use super::{handle, AttachedComment, Comment, CommentPlacement};
+use crate::util::has_newline;
+
+pub struct DecoratedComment<'a> {
+    pub comment: Comment,
+    pub enclosing: Option<AstKind<'a>>,
+    pub preceding: Option<AstKind<'a>>,
+    pub following: Option<AstKind<'a>>,
+}
+
+pub struct CommentAttacher<'a> {
+    pub source_text: &'a str,
+    pub program_span: Span,
+    /// Whether the program has no statements
+    pub is_empty_program: bool,
+    pub comments: BTreeMap<Span, Vec<AttachedComment>>,
+}
+
+impl<'a> CommentAttacher<'a> {
+    fn add(&mut self, span: Span, comment: Comment, placement: CommentPlacement) {
+        let attached = AttachedComment { comment, placement, printed: false };
+        self.comments.entry(span).or_default().push(attached);
+    }
+
+    pub fn add_leading(&mut self, span: Span, comment: Comment) {
+        self.add(span, comment, CommentPlacement::Leading);
+    }
+
+    pub fn add_trailing(&mut self, span: Span, comment: Comment) {
+        self.add(span, comment, CommentPlacement::Trailing);
+    }
+
+    pub fn add_dangling(&mut self, span: Span, comment: Comment) {
+        self.add(span, comment, CommentPlacement::Dangling);
+    }
+}
+
+/// Attach the comments of the program to its nodes, keyed by the span of the nodes.
+pub fn attach_comments(
+    source_text: &str,
+    program: &Program<'_>,
+    trivias: &[(u32, u32, CommentKind)],
+) -> BTreeMap<Span, Vec<AttachedComment>> {
+    let mut attacher = CommentAttacher {
+        source_text,
+        program_span: program.span,
+        is_empty_program: program.body.is_empty(),
+        comments: BTreeMap::new(),
+    };
+    if trivias.is_empty() {
+        return attacher.comments;
+    }
+
+    let mut tree = NodeTree::default();
+    tree.visit_program(program);
+    tree.sort();
+
+    let decorated = trivias
+        .iter()
+        .map(|&(start, end, kind)| tree.decorate(Comment::new(source_text, start, end, kind)))
+        .collect::<Vec<_>>();
+
+    let mut ties_to_break: Vec<&DecoratedComment> = vec![];
+    for (i, context) in decorated.iter().enumerate() {
+        let DecoratedComment { comment, enclosing, preceding, following } = *context;
+        let is_last = i == decorated.len() - 1;
+        let enclosing = enclosing.map_or(program.span, |node| node.span());
+        if is_own_line_comment(source_text, &decorated, i) {
+            if handle::handle_own_line_comment(context, &mut attacher, is_last) {
+                continue;
+            }
+            match (preceding, following) {
+                (_, Some(following)) => attacher.add_leading(following.span(), comment),
+                (Some(preceding), None) => attacher.add_trailing(preceding.span(), comment),
+                (None, None) => attacher.add_dangling(enclosing, comment),
+            }
+        } else if is_end_of_line_comment(source_text, &decorated, i) {
+            if handle::handle_end_of_line_comment(context, &mut attacher, is_last) {
+                continue;
+            }
+            match (preceding, following) {
+                (Some(preceding), _) => attacher.add_trailing(preceding.span(), comment),
+                (None, Some(following)) => attacher.add_leading(following.span(), comment),
+                (None, None) => attacher.add_dangling(enclosing, comment),
+            }
+        } else {
+            if handle::handle_remaining_comment(context, &mut attacher, is_last) {
+                continue;
+            }
+            match (preceding, following) {
+                (Some(_), Some(following)) => {
+                    // Otherwise, text exists between the preceding and following nodes,
+                    // the comment is attached once all the comments in between are known.
+                    let following = following.span();
+                    if ties_to_break
+                        .last()
+                        .is_some_and(|tie| tie.following.map(|node| node.span()) != Some(following))
+                    {
+                        break_ties(&mut ties_to_break, &mut attacher);
+                    }
+                    ties_to_break.push(context);
+                }
+                (Some(preceding), None) => attacher.add_trailing(preceding.span(), comment),
+                (None, Some(following)) => attacher.add_leading(following.span(), comment),
+                (None, None) => attacher.add_dangling(enclosing, comment),
+            }
+        }
+    }
+    break_ties(&mut ties_to_break, &mut attacher);
+
+    for comments in attacher.comments.values_mut() {
+        comments.sort_by_key(|attached| attached.comment.span.start);
+    }
+    attacher.comments
+}
+
+/// Comments on the same line between two nodes, e.g. `a /* 1 */ /* 2 */ + b`,
+/// are trailing comments of the preceding node if there is any text between them and the following node.
+fn break_ties(ties: &mut Vec<&DecoratedComment>, attacher: &mut CommentAttacher) {
+    let Some(first) = ties.first() else { return };
+    let (Some(preceding), Some(following)) = (first.preceding, first.following) else { return };
+
+    let mut gap_end = following.span().start;
+    let mut index_of_first_leading_comment = ties.len();
+    while index_of_first_leading_comment > 0 {
+        let comment = ties[index_of_first_leading_comment - 1].comment;
+        let gap = &attacher.source_text[comment.span.end as usize..gap_end as usize];
+        if !is_horizontal_space(gap) {
+            break;
+        }
+        gap_end = comment.span.start;
+        index_of_first_leading_comment -= 1;
+    }
+
+    for (i, tie) in ties.iter().enumerate() {
+        if i < index_of_first_leading_comment {
+            attacher.add_trailing(preceding.span(), tie.comment);
+        } else {
+            attacher.add_leading(following.span(), tie.comment);
+        }
+    }
+    ties.clear();
+}
+
+/// Whether the comment starts its own line,
+/// looking through the comments on the same line before it.
+fn is_own_line_comment(source_text: &str, decorated: &[DecoratedComment], index: usize) -> bool {
+    let DecoratedComment { comment, preceding, .. } = decorated[index];
+    let mut start = comment.span.start;
+    if let Some(preceding) = preceding {
+        for other in decorated[..index].iter().rev() {
+            if other.preceding.map(|node| node.span()) != Some(preceding.span())
+                || !is_horizontal_space(
+                    &source_text[other.comment.span.end as usize..start as usize],
+                )
+            {
+                break;
+            }
+            start = other.comment.span.start;
+        }
+    }
+    has_newline(source_text, start, /* backwards */ true)
+}
+
+/// Whether the comment ends its line,
+/// looking through the comments on the same line after it.
+fn is_end_of_line_comment(source_text: &str, decorated: &[DecoratedComment], index: usize) -> bool {
+    let DecoratedComment { comment, following, .. } = decorated[index];
+    let mut end = comment.span.end;
+    if let Some(following) = following {
+        for other in &decorated[index + 1..] {
+            if other.following.map(|node| node.span()) != Some(following.span())
+                || !is_horizontal_space(
+                    &source_text[end as usize..other.comment.span.start as usize],
+                )
+            {
+                break;
+            }
+            end = other.comment.span.end;
+        }
+    }
+    has_newline(source_text, end, /* backwards */ false)
+}
+
+fn is_horizontal_space(text: &str) -> bool {
+    text.chars().all(|c| c.is_whitespace() && c != '\n' && c != '\r')
+}
+
+/// Nodes which comments can be attached to.
+///
+/// Wrapper nodes such as [AstKind::Argument] are skipped, the comments go to the node they wrap.
+fn can_attach_comment(kind: AstKind) -> bool {
+    !matches!(
+        kind,
+        AstKind::Program(_)
+            | AstKind::Hashbang(_)
+            | AstKind::EmptyStatement(_)
+            | AstKind::ForStatementInit(_)
+            | AstKind::FinallyClause(_)
+            | AstKind::ChainExpression(_)
+            | AstKind::PropertyKey(_)
+            | AstKind::Argument(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::SimpleAssignmentTarget(_)
+            | AstKind::AssignmentTargetWithDefault(_)
+            | AstKind::ArrayExpressionElement(_)
+            | AstKind::Elision(_)
+            | AstKind::ExpressionArrayElement(_)
+            | AstKind::ClassHeritage(_)
+            | AstKind::Decorator(_)
+    ) && !is_jsx(kind)
+        && !is_typescript(kind)
+}
+
+/// JSX nodes which are printed as a part of their parent element.
+fn is_jsx(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::JSXOpeningElement(_)
+            | AstKind::JSXElementName(_)
+            | AstKind::JSXAttributeItem(_)
+            | AstKind::JSXText(_)
+    )
+}
+
+fn is_typescript(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::TSModuleBlock(_)
+            | AstKind::TSTypeParameter(_)
+            | AstKind::TSTypeParameterDeclaration(_)
+            | AstKind::TSTypeParameterInstantiation(_)
+    )
+}
+
+struct Node<'a> {
+    kind: AstKind<'a>,
+    children: Vec<usize>,
+}
+
+/// The nodes which comments can be attached to, with the program at the root.
+#[derive(Default)]
+struct NodeTree<'a> {
+    nodes: Vec<Node<'a>>,
+    /// Indexes of the enclosing nodes
+    stack: Vec<usize>,
+    /// Whether each entered node was added to the tree
+    entered: Vec<bool>,
+}
+
+impl<'a> Visit<'a> for NodeTree<'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let is_root = self.nodes.is_empty();
+        if !is_root && !can_attach_comment(kind) {
+            self.entered.push(false);
+            return;
+        }
+        if let Some(&parent) = self.stack.last() {
+            // A node with the same span as its parent, e.g. the callee of `a?.()`,
+            // shares the comments of the parent.
+            if parent != 0 && self.nodes[parent].kind.span() == kind.span() {
+                self.entered.push(false);
+                return;
+            }
+            let index = self.nodes.len();
+            self.nodes[parent].children.push(index);
+        }
+        self.stack.push(self.nodes.len());
+        self.nodes.push(Node { kind, children: vec![] });
+        self.entered.push(true);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        if self.entered.pop() == Some(true) {
+            self.stack.pop();
+        }
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause<'a>) {
+        // The default visitor skips the body block, but the comments of an empty body belong to it.
+        let kind = AstKind::CatchClause(self.alloc(clause));
+        self.enter_node(kind);
+        if let Some(param) = &clause.param {
+            self.visit_binding_pattern(param);
+        }
+        self.visit_block_statement(&clause.body);
+        self.leave_node(kind);
+    }
+}
+
+impl<'a> NodeTree<'a> {
+    fn sort(&mut self) {
+        let starts = self.nodes.iter().map(|node| node.kind.span().start).collect::<Vec<_>>();
+        for node in &mut self.nodes {
+            node.children.sort_by_key(|&child| starts[child]);
+        }
+    }
+
+    /// Find the innermost node enclosing the comment, and the children of it around the comment.
+    fn decorate(&self, comment: Comment) -> DecoratedComment<'a> {
+        let mut enclosing = None;
+        let mut node = 0;
+        loop {
+            let children = &self.nodes[node].children;
+            let (mut left, mut right) = (0, children.len());
+            let mut preceding = None;
+            let mut following = None;
+            let mut enclosing_child = None;
+            while left < right {
+                let middle = (left + right) / 2;
+                let child = children[middle];
+                let span = self.nodes[child].kind.span();
+                if span.start <= comment.span.start && comment.span.end <= span.end {
+                    // The comment is completely contained by this child node
+                    enclosing_child = Some(child);
+                    break;
+                }
+                if span.end <= comment.span.start {
+                    // This child node falls completely before the comment
+                    preceding = Some(child);
+                    left = middle + 1;
+                } else if comment.span.end <= span.start {
+                    // This child node falls completely after the comment
+                    following = Some(child);
+                    right = middle;
+                } else {
+                    // The comment overlaps the child node, which should not happen
+                    break;
+                }
+            }
+            if let Some(child) = enclosing_child {
+                enclosing = Some(self.nodes[child].kind);
+                node = child;
+                continue;
+            }
+            return DecoratedComment {
+                comment,
+                enclosing,
+                preceding: preceding.map(|index| self.nodes[index].kind),
+                following: following.map(|index| self.nodes[index].kind),
+            };
+        }
+    }
+}
@@ -0,0 +1,582 @@
+//! Special cases of comment attachment for JavaScript
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/comments/handle-comments.js>
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_ast::AstKind;
+use oxc_span::GetSpan;
+
+use super::{
+    attach::{CommentAttacher, DecoratedComment},
+    Comment,
+};
+use crate::util::{
+    get_next_non_space_non_comment_character, get_next_non_space_non_comment_character_index,
+    has_newline_in_range,
+};
+
+type Handler = fn(&DecoratedComment, &mut CommentAttacher, bool) -> bool;
+
+pub fn handle_own_line_comment(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    is_last: bool,
+) -> bool {
+    let handlers: [Handler; 11] = [
+        handle_last_function_arg_comments,
+        handle_member_expression_comments,
+        handle_if_statement_comments,
+        handle_while_comments,
+        handle_try_statement_comments,
+        handle_for_comments,
+        handle_only_comments,
+        handle_assignment_pattern_comments,
+        handle_method_name_comments,
+        handle_labeled_statement_comments,
+        handle_break_and_continue_statement_comments,
+    ];
+    handlers.iter().any(|handler| handler(context, attacher, is_last))
+}
+
+pub fn handle_end_of_line_comment(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    is_last: bool,
+) -> bool {
+    let handlers: [Handler; 12] = [
+        handle_last_function_arg_comments,
+        handle_conditional_expression_comments,
+        handle_if_statement_comments,
+        handle_while_comments,
+        handle_try_statement_comments,
+        handle_labeled_statement_comments,
+        handle_call_expression_comments,
+        handle_only_comments,
+        handle_variable_declarator_comments,
+        handle_break_and_continue_statement_comments,
+        handle_switch_default_case_comments,
+        handle_last_binary_operator_operand,
+    ];
+    handlers.iter().any(|handler| handler(context, attacher, is_last))
+}
+
+pub fn handle_remaining_comment(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    is_last: bool,
+) -> bool {
+    let handlers: [Handler; 7] = [
+        handle_if_statement_comments,
+        handle_while_comments,
+        handle_comment_in_empty_parens,
+        handle_method_name_comments,
+        handle_only_comments,
+        handle_comment_after_arrow_params,
+        handle_break_and_continue_statement_comments,
+    ];
+    handlers.iter().any(|handler| handler(context, attacher, is_last))
+}
+
+/// Attach the comment to the first statement of a block-like node, or to the node itself if it is empty.
+fn add_block_statement_first_comment(
+    attacher: &mut CommentAttacher,
+    node: AstKind,
+    comment: Comment,
+) {
+    let first_statement = |stmts: &[Statement]| {
+        stmts.iter().find(|stmt| !matches!(stmt, Statement::EmptyStatement(_))).map(GetSpan::span)
+    };
+    let first = match node {
+        AstKind::BlockStatement(block) => first_statement(&block.body),
+        AstKind::FunctionBody(body) => first_statement(&body.statements),
+        AstKind::StaticBlock(block) => first_statement(&block.body),
+        AstKind::ObjectExpression(object) => object.properties.first().map(GetSpan::span),
+        AstKind::ArrayExpression(array) => array.elements.first().map(GetSpan::span),
+        _ => {
+            attacher.add_leading(node.span(), comment);
+            return;
+        }
+    };
+    match first {
+        Some(span) => attacher.add_leading(span, comment),
+        None => attacher.add_dangling(node.span(), comment),
+    }
+}
+
+fn add_block_or_not_comment(attacher: &mut CommentAttacher, node: AstKind, comment: Comment) {
+    if matches!(node, AstKind::BlockStatement(_)) {
+        add_block_statement_first_comment(attacher, node, comment);
+    } else {
+        attacher.add_leading(node.span(), comment);
+    }
+}
+
+fn is_identifier(node: AstKind) -> bool {
+    matches!(
+        node,
+        AstKind::IdentifierName(_)
+            | AstKind::IdentifierReference(_)
+            | AstKind::BindingIdentifier(_)
+            | AstKind::LabelIdentifier(_)
+    )
+}
+
+fn next_character(attacher: &CommentAttacher, comment: Comment) -> Option<char> {
+    get_next_non_space_non_comment_character(attacher.source_text, comment.span.end)
+}
+
+/// `function foo(a /* comment */) {}`
+/// `function foo(a) /* comment */ {}`
+fn handle_last_function_arg_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, preceding, enclosing, following } = *context;
+
+    if let (Some(preceding), Some(AstKind::FormalParameters(_))) = (preceding, enclosing) {
+        if matches!(
+            preceding,
+            AstKind::FormalParameter(_)
+                | AstKind::BindingIdentifier(_)
+                | AstKind::AssignmentPattern(_)
+                | AstKind::ObjectPattern(_)
+                | AstKind::ArrayPattern(_)
+                | AstKind::RestElement(_)
+        ) && next_character(attacher, comment) == Some(')')
+        {
+            attacher.add_trailing(preceding.span(), comment);
+            return true;
+        }
+    }
+
+    if let (Some(AstKind::Function(func)), Some(following @ AstKind::FunctionBody(_))) =
+        (enclosing, following)
+    {
+        if comment.span.start >= func.params.span.end {
+            add_block_statement_first_comment(attacher, following, comment);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// `foo /* comment */.bar`
+fn handle_member_expression_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, following, .. } = *context;
+    if let (Some(enclosing @ AstKind::MemberExpression(_)), Some(following)) =
+        (enclosing, following)
+    {
+        if is_identifier(following) {
+            attacher.add_leading(enclosing.span(), comment);
+            return true;
+        }
+    }
+    false
+}
+
+/// Comments around the test, the consequent and the `else` of an if statement.
+fn handle_if_statement_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, preceding, enclosing, following } = *context;
+    let (Some(AstKind::IfStatement(stmt)), Some(following)) = (enclosing, following) else {
+        return false;
+    };
+
+    // We unfortunately have no way using the AST or location of nodes to know
+    // if the comment is positioned before the condition parenthesis:
+    //   if (a /* comment */) {}
+    // The only workaround I found is to look at the next character to see if
+    // it is a ).
+    if next_character(attacher, comment) == Some(')') {
+        if let Some(preceding) = preceding {
+            attacher.add_trailing(preceding.span(), comment);
+            return true;
+        }
+        return false;
+    }
+
+    // Comments before `else`:
+    // - treat as trailing comments of the consequent, if the BlockStatement is not empty
+    // - treat as a dangling comment otherwise
+    if preceding.is_some_and(|node| node.span() == stmt.consequent.span())
+        && stmt.alternate.as_ref().is_some_and(|alternate| alternate.span() == following.span())
+    {
+        if preceding.is_some_and(|node| matches!(node, AstKind::BlockStatement(_))) {
+            attacher.add_trailing(stmt.consequent.span(), comment);
+        } else {
+            attacher.add_dangling(stmt.span, comment);
+        }
+        return true;
+    }
+
+    match following {
+        AstKind::BlockStatement(_) => {
+            add_block_statement_first_comment(attacher, following, comment);
+            true
+        }
+        AstKind::IfStatement(stmt) => {
+            match &stmt.consequent {
+                Statement::BlockStatement(block) => add_block_statement_first_comment(
+                    attacher,
+                    AstKind::BlockStatement(block),
+                    comment,
+                ),
+                consequent => attacher.add_leading(consequent.span(), comment),
+            }
+            true
+        }
+        // For comments positioned after the condition parenthesis in an if statement
+        // before the consequent without brackets on, such as
+        // if (a) /* comment */ true,
+        // we look at the next character to see if the following node
+        // is the consequent for the if statement
+        _ if stmt.consequent.span() == following.span() => {
+            attacher.add_leading(following.span(), comment);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn handle_while_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, preceding, enclosing, following } = *context;
+    let (Some(AstKind::WhileStatement(stmt)), Some(following)) = (enclosing, following) else {
+        return false;
+    };
+
+    if next_character(attacher, comment) == Some(')') {
+        if let Some(preceding) = preceding {
+            attacher.add_trailing(preceding.span(), comment);
+            return true;
+        }
+        return false;
+    }
+
+    if matches!(following, AstKind::BlockStatement(_)) {
+        add_block_statement_first_comment(attacher, following, comment);
+        return true;
+    }
+
+    if stmt.body.span() == following.span() {
+        attacher.add_leading(following.span(), comment);
+        return true;
+    }
+
+    false
+}
+
+/// `try {} /* comment */ catch (e) {}`
+fn handle_try_statement_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, preceding, enclosing, following } = *context;
+    let Some(following) = following else { return false };
+    match enclosing {
+        Some(AstKind::CatchClause(_)) => {
+            if let Some(preceding) = preceding {
+                attacher.add_trailing(preceding.span(), comment);
+                return true;
+            }
+        }
+        Some(AstKind::TryStatement(_)) => {}
+        _ => return false,
+    }
+
+    match following {
+        AstKind::BlockStatement(_) => {
+            add_block_statement_first_comment(attacher, following, comment);
+            true
+        }
+        AstKind::CatchClause(clause) => {
+            add_block_or_not_comment(attacher, AstKind::BlockStatement(&clause.body), comment);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// `for (a of /* comment */ b) {}`
+fn handle_for_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, .. } = *context;
+    match enclosing {
+        Some(enclosing @ (AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))) => {
+            attacher.add_leading(enclosing.span(), comment);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// A program which contains only comments
+fn handle_only_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, .. } = *context;
+    if enclosing.is_some() || !attacher.is_empty_program {
+        return false;
+    }
+    let span = attacher.program_span;
+    if is_last {
+        attacher.add_dangling(span, comment);
+    } else {
+        attacher.add_leading(span, comment);
+    }
+    true
+}
+
+/// `function foo(a = /* comment */ 1) {}`
+fn handle_assignment_pattern_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, .. } = *context;
+    match enclosing {
+        Some(enclosing @ AstKind::AssignmentPattern(_)) => {
+            attacher.add_leading(enclosing.span(), comment);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// `obj = { fn /* comment */ () {} };`
+fn handle_method_name_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, preceding, enclosing, .. } = *context;
+    let (Some(enclosing), Some(preceding)) = (enclosing, preceding) else { return false };
+    let key = match enclosing {
+        AstKind::ObjectProperty(prop) => &prop.key,
+        AstKind::MethodDefinition(method) => &method.key,
+        _ => return false,
+    };
+    let source_text = attacher.source_text;
+    if next_character(attacher, comment) == Some('(')
+        && is_identifier(preceding)
+        && key.span() == preceding.span()
+        // special Property case: { key: /*comment*/(value) };
+        // comment should be attached to value instead of key
+        && get_next_non_space_non_comment_character(source_text, preceding.span().end) != Some(':')
+    {
+        attacher.add_trailing(preceding.span(), comment);
+        return true;
+    }
+    false
+}
+
+fn handle_labeled_statement_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, .. } = *context;
+    match enclosing {
+        Some(enclosing @ AstKind::LabeledStatement(_)) => {
+            attacher.add_leading(enclosing.span(), comment);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// `break /* comment */;`
+fn handle_break_and_continue_statement_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, .. } = *context;
+    match enclosing {
+        Some(
+            AstKind::BreakStatement(BreakStatement { span, label: None })
+            | AstKind::ContinueStatement(ContinueStatement { span, label: None }),
+        ) => {
+            attacher.add_trailing(*span, comment);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// `a ? /* comment */ b : c`
+fn handle_conditional_expression_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, preceding, enclosing, following } = *context;
+    let (Some(AstKind::ConditionalExpression(_)), Some(following)) = (enclosing, following) else {
+        return false;
+    };
+    let is_same_line_as_preceding = preceding.is_some_and(|preceding| {
+        !has_newline_in_range(attacher.source_text, preceding.span().end, comment.span.start)
+    });
+    if is_same_line_as_preceding {
+        return false;
+    }
+    attacher.add_leading(following.span(), comment);
+    true
+}
+
+/// `foo( // comment
+///   a,
+/// )`
+fn handle_call_expression_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, preceding, enclosing, .. } = *context;
+    let (Some(enclosing), Some(preceding)) = (enclosing, preceding) else { return false };
+    let (callee, arguments) = match enclosing {
+        AstKind::CallExpression(call) => (&call.callee, &call.arguments),
+        AstKind::NewExpression(call) => (&call.callee, &call.arguments),
+        _ => return false,
+    };
+    if callee.span() != preceding.span() {
+        return false;
+    }
+    let Some(first) = arguments.first() else { return false };
+    attacher.add_leading(first.span(), comment);
+    true
+}
+
+/// `const a = // comment
+///   { b: 1 };`
+fn handle_variable_declarator_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, following, .. } = *context;
+    let (Some(AstKind::VariableDeclarator(_) | AstKind::AssignmentExpression(_)), Some(following)) =
+        (enclosing, following)
+    else {
+        return false;
+    };
+    if comment.is_block()
+        || matches!(
+            following,
+            AstKind::ObjectExpression(_)
+                | AstKind::ArrayExpression(_)
+                | AstKind::TemplateLiteral(_)
+                | AstKind::TaggedTemplateExpression(_)
+        )
+    {
+        add_block_statement_first_comment(attacher, following, comment);
+        return true;
+    }
+    false
+}
+
+/// `default: // comment`
+fn handle_switch_default_case_comments(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, following, .. } = *context;
+    let (Some(AstKind::SwitchCase(case)), Some(following)) = (enclosing, following) else {
+        return false;
+    };
+    if case.test.is_some() || case.consequent.first().map(GetSpan::span) != Some(following.span()) {
+        return false;
+    }
+    if matches!(following, AstKind::BlockStatement(_)) && comment.is_line() {
+        add_block_statement_first_comment(attacher, following, comment);
+    } else {
+        attacher.add_dangling(case.span, comment);
+    }
+    true
+}
+
+/// `a && // comment
+///   b`
+fn handle_last_binary_operator_operand(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, preceding, enclosing, following } = *context;
+    // "baseline" comment handling for the operand after an operator
+    let (Some(AstKind::LogicalExpression(expr)), Some(following)) = (enclosing, following) else {
+        return false;
+    };
+    if preceding.is_some_and(|preceding| preceding.span() == expr.left.span())
+        && following.span() == expr.right.span()
+        && matches!(following, AstKind::LogicalExpression(_) | AstKind::ParenthesizedExpression(_))
+    {
+        attacher.add_leading(following.span(), comment);
+        return true;
+    }
+    false
+}
+
+/// `foo(/* comment */)`, `function foo(/* comment */) {}`
+fn handle_comment_in_empty_parens(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, .. } = *context;
+    if next_character(attacher, comment) != Some(')') {
+        return false;
+    }
+    match enclosing {
+        Some(
+            AstKind::CallExpression(CallExpression { span, arguments, .. })
+            | AstKind::NewExpression(NewExpression { span, arguments, .. }),
+        ) if arguments.is_empty() => {
+            attacher.add_dangling(*span, comment);
+            true
+        }
+        Some(AstKind::FormalParameters(params)) if params.parameters_count() == 0 => {
+            attacher.add_dangling(params.span, comment);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// `(a /* comment */) => {}`, the comment is printed after the parameters
+fn handle_comment_after_arrow_params(
+    context: &DecoratedComment,
+    attacher: &mut CommentAttacher,
+    _is_last: bool,
+) -> bool {
+    let DecoratedComment { comment, enclosing, .. } = *context;
+    let Some(AstKind::ArrowExpression(arrow)) = enclosing else { return false };
+    let index =
+        get_next_non_space_non_comment_character_index(attacher.source_text, comment.span.end);
+    if index.is_some_and(|index| attacher.source_text[index as usize..].starts_with("=>")) {
+        attacher.add_dangling(arrow.span, comment);
+        return true;
+    }
+    false
+}
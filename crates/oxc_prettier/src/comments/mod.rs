@@ -0,0 +1,170 @@
+//! Comments
+//!
+//! Comments are attached to the nodes before printing, as leading, trailing or dangling comments,
+//! and printed along with the node they are attached to.
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/main/comments/attach.js>
+//! * <https://github.com/prettier/prettier/blob/main/src/main/comments/print.js>
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/comments/handle-comments.js>
+
+mod attach;
+mod handle;
+mod print;
+
+use bitflags::bitflags;
+use oxc_ast::CommentKind;
+use oxc_span::Span;
+
+use crate::{util::is_line_terminator, Prettier};
+
+pub use self::attach::attach_comments;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CommentFlags: u8 {
+        /// Check comment is a leading comment
+        const Leading        = 1 << 0;
+        /// Check comment is a trailing comment
+        const Trailing       = 1 << 1;
+        /// Check comment is a dangling comment
+        const Dangling       = 1 << 2;
+        /// Check comment is a block comment
+        const Block          = 1 << 3;
+        /// Check comment is a line comment
+        const Line           = 1 << 4;
+        /// Check comment is a `prettier-ignore` comment
+        const PrettierIgnore = 1 << 5;
+        /// Check comment is the first attached comment
+        const First          = 1 << 6;
+        /// Check comment is the last attached comment
+        const Last           = 1 << 7;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Comment {
+    /// The span of the comment, including `//`, `/*` and `*/`
+    pub span: Span,
+    pub kind: CommentKind,
+}
+
+impl Comment {
+    pub fn new(source_text: &str, start: u32, end: u32, kind: CommentKind) -> Self {
+        // The trivia spans do not include the comment delimiters,
+        // but line comments include the line terminator after them.
+        let end = if kind.is_multi_line() {
+            end + 2
+        } else {
+            let text = &source_text[start as usize..end as usize];
+            #[allow(clippy::cast_possible_truncation)]
+            text.find(is_line_terminator).map_or(end, |index| start + index as u32)
+        };
+        Self { span: Span::new(start - 2, end), kind }
+    }
+
+    pub fn is_block(self) -> bool {
+        self.kind.is_multi_line()
+    }
+
+    pub fn is_line(self) -> bool {
+        self.kind.is_single_line()
+    }
+
+    /// `// prettier-ignore` or `/* prettier-ignore */`
+    pub fn is_prettier_ignore(self, source_text: &str) -> bool {
+        let text = self.span.source_text(source_text);
+        let value = if self.is_block() { &text[2..text.len() - 2] } else { &text[2..] };
+        let value = value.strip_prefix('*').unwrap_or(value).trim_start();
+        value
+            .strip_prefix("prettier-ignore")
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentPlacement {
+    Leading,
+    Trailing,
+    Dangling,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AttachedComment {
+    pub comment: Comment,
+    pub placement: CommentPlacement,
+    pub printed: bool,
+}
+
+impl AttachedComment {
+    fn matches(&self, flags: CommentFlags, source_text: &str) -> bool {
+        !((flags.contains(CommentFlags::Leading) && self.placement != CommentPlacement::Leading)
+            || (flags.contains(CommentFlags::Trailing)
+                && self.placement != CommentPlacement::Trailing)
+            || (flags.contains(CommentFlags::Dangling)
+                && self.placement != CommentPlacement::Dangling)
+            || (flags.contains(CommentFlags::Block) && !self.comment.is_block())
+            || (flags.contains(CommentFlags::Line) && !self.comment.is_line())
+            || (flags.contains(CommentFlags::PrettierIgnore)
+                && !self.comment.is_prettier_ignore(source_text)))
+    }
+}
+
+impl<'a> Prettier<'a> {
+    /// Whether the node has an attached comment matching all of the `flags`.
+    pub(crate) fn has_comment(&self, span: Span, flags: CommentFlags) -> bool {
+        self.get_comments(span, flags).next().is_some()
+    }
+
+    /// The comments attached to the node which match all of the `flags`.
+    pub(crate) fn get_comments(
+        &self,
+        span: Span,
+        flags: CommentFlags,
+    ) -> impl Iterator<Item = &AttachedComment> + '_ {
+        let comments = self.comments.get(&span).map_or(&[][..], Vec::as_slice);
+        let len = comments.len();
+        comments.iter().enumerate().filter_map(move |(i, comment)| {
+            let position_matches = !(flags.contains(CommentFlags::First) && i != 0
+                || flags.contains(CommentFlags::Last) && i != len - 1);
+            (position_matches && comment.matches(flags, self.source_text)).then_some(comment)
+        })
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, PrettierOptions};
+
+    let tests = [
+        // leading
+        ("// leading\nfoo();\n", "// leading\nfoo();\n"),
+        ("/* leading */   foo();\n", "/* leading */ foo();\n"),
+        ("/* leading */\nfoo();\n", "/* leading */\nfoo();\n"),
+        ("// leading\n\nfoo();\n", "// leading\n\nfoo();\n"),
+        ("foo(a, /* leading */ b);\n", "foo(a, /* leading */ b);\n"),
+        // trailing
+        ("foo();   // trailing\n", "foo(); // trailing\n"),
+        ("foo(a /* trailing */);\n", "foo(a /* trailing */);\n"),
+        ("const a = 1; /* trailing */\n", "const a = 1; /* trailing */\n"),
+        // dangling
+        ("function f() {\n// dangling\n}\n", "function f() {\n  // dangling\n}\n"),
+        ("foo(/* dangling */);\n", "foo(/* dangling */);\n"),
+        ("const a = [// dangling\n];\n", "const a = [\n  // dangling\n];\n"),
+        ("const o = {\n// dangling\n};\n", "const o = {\n  // dangling\n};\n"),
+        ("if (a) {\n  // dangling\n}\n", "if (a) {\n  // dangling\n}\n"),
+        // prettier-ignore
+        (
+            "// prettier-ignore\nconst  a  =  [1,2,   3];\nconst  b  =  1;\n",
+            "// prettier-ignore\nconst  a  =  [1,2,   3];\nconst b = 1;\n",
+        ),
+        (
+            "const o = {\n  // prettier-ignore\n  a:   1,\n  b:   2,\n};\n",
+            "const o = {\n  // prettier-ignore\n  a:   1,\n  b: 2,\n};\n",
+        ),
+        ("/* prettier-ignore */\nfoo(  a  );\n", "/* prettier-ignore */\nfoo(  a  );\n"),
+        ("// prettier-ignored\nfoo(  a  );\n", "// prettier-ignored\nfoo(a);\n"),
+    ];
+
+    Tester::new("test.js", PrettierOptions::default()).test(&tests);
+}
@@ -0,0 +1,196 @@
+//! Printing of the attached comments
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/main/comments/print.js>
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/print/comment.js>
+
+use oxc_span::Span;
+
+use super::{AttachedComment, Comment, CommentPlacement};
+use crate::{
+    array,
+    doc::{Doc, Separator},
+    hardline, indent, line, line_suffix, ss,
+    util::{has_newline, is_previous_line_empty, skip_newline, skip_spaces},
+    Prettier,
+};
+
+impl<'a> Prettier<'a> {
+    /// Print the leading and trailing comments of the node around its doc.
+    pub(crate) fn print_comments(&mut self, span: Span, doc: Doc<'a>) -> Doc<'a> {
+        let comments =
+            self.take_comments(span, |comment| comment.placement != CommentPlacement::Dangling);
+        if comments.is_empty() {
+            return doc;
+        }
+
+        let mut parts = self.vec();
+        for comment in comments.iter().filter(|c| c.placement == CommentPlacement::Leading) {
+            parts.push(self.print_leading_comment(comment.comment));
+        }
+        parts.push(doc);
+        let mut previous = None;
+        for comment in comments.iter().filter(|c| c.placement == CommentPlacement::Trailing) {
+            let (doc, printed) = self.print_trailing_comment(comment.comment, previous);
+            parts.push(doc);
+            previous = Some(printed);
+        }
+        Doc::Array(parts)
+    }
+
+    /// Print the dangling comments of the node, separated by hardlines,
+    /// and indented on a new line if `indent` is true.
+    pub(crate) fn print_dangling_comments(&mut self, span: Span, indent: bool) -> Option<Doc<'a>> {
+        let comments =
+            self.take_comments(span, |comment| comment.placement == CommentPlacement::Dangling);
+        if comments.is_empty() {
+            return None;
+        }
+        let parts = comments.iter().map(|comment| self.print_comment(comment.comment)).collect();
+        let doc = Doc::Array(self.join(Separator::Hardline, parts));
+        Some(if indent { indent!(self, hardline!(), doc) } else { doc })
+    }
+
+    /// The original text of a node ignored by `// prettier-ignore`.
+    pub(crate) fn print_ignored(&self, span: Span) -> Doc<'a> {
+        Doc::Str(span.source_text(self.source_text))
+    }
+
+    /// Mark the comments of the node which match `filter` as printed, and return them.
+    fn take_comments(
+        &mut self,
+        span: Span,
+        filter: impl Fn(&AttachedComment) -> bool,
+    ) -> Vec<AttachedComment> {
+        let Some(comments) = self.comments.get_mut(&span) else { return vec![] };
+        comments
+            .iter_mut()
+            .filter(|comment| !comment.printed && filter(comment))
+            .map(|comment| {
+                comment.printed = true;
+                *comment
+            })
+            .collect()
+    }
+
+    fn print_leading_comment(&self, comment: Comment) -> Doc<'a> {
+        let mut parts = self.vec();
+        parts.push(self.print_comment(comment));
+
+        // Leading block comments should see if they need to stay on the
+        // same line or not.
+        if comment.is_block() {
+            if has_newline(self.source_text, comment.span.end, false) {
+                if has_newline(self.source_text, comment.span.start, true) {
+                    parts.push(hardline!());
+                } else {
+                    parts.push(line!());
+                }
+            } else {
+                parts.push(ss!(" "));
+            }
+        } else {
+            parts.push(hardline!());
+        }
+
+        let index = skip_spaces(self.source_text, comment.span.end as usize, false);
+        let index = skip_newline(self.source_text, index, false);
+        #[allow(clippy::cast_possible_truncation)]
+        if has_newline(self.source_text, index as u32, false) {
+            parts.push(hardline!());
+        }
+
+        Doc::Array(parts)
+    }
+
+    /// Returns the doc, and whether the comment is a block comment and is printed as a line suffix.
+    fn print_trailing_comment(
+        &self,
+        comment: Comment,
+        previous: Option<(bool, bool)>,
+    ) -> (Doc<'a>, (bool, bool)) {
+        let printed = self.print_comment(comment);
+        let is_block = comment.is_block();
+
+        if previous.is_some_and(|(is_block, has_line_suffix)| has_line_suffix && !is_block)
+            || has_newline(self.source_text, comment.span.start, true)
+        {
+            // This allows comments at the end of nested structures:
+            // {
+            //   x: 1,
+            //   y: 2
+            //   // A comment
+            // }
+            // Those kinds of comments are almost always leading comments, but
+            // here it doesn't go "outside" the block and turns it into a
+            // trailing comment for `2`. We can simulate the above by checking
+            // if this a comment on its own line; normal trailing comments are
+            // always at the end of another expression.
+            let is_line_before_empty = is_previous_line_empty(self.source_text, comment.span.start);
+            let mut suffix = self.vec();
+            suffix.push(hardline!());
+            if is_line_before_empty {
+                suffix.push(hardline!());
+            }
+            suffix.push(printed);
+            return (Doc::LineSuffix(suffix), (is_block, true));
+        }
+
+        if !is_block || previous.is_some_and(|(_, has_line_suffix)| has_line_suffix) {
+            let mut parts = self.vec();
+            parts.push(line_suffix!(self, ss!(" "), printed));
+            if !is_block {
+                parts.push(Doc::BreakParent);
+            }
+            return (Doc::Array(parts), (is_block, true));
+        }
+
+        (array!(self, ss!(" "), printed), (is_block, false))
+    }
+
+    fn print_comment(&self, comment: Comment) -> Doc<'a> {
+        let text = comment.span.source_text(self.source_text);
+        if comment.is_line() {
+            return Doc::Str(text.trim_end());
+        }
+        let value = &text[2..text.len() - 2];
+        if is_indentable_block_comment(value) {
+            return self.print_indentable_block_comment(value);
+        }
+        Doc::Str(text)
+    }
+
+    /// Re-indent the lines of
+    /// ```text
+    /// /**
+    ///  * comment
+    ///  */
+    /// ```
+    fn print_indentable_block_comment(&self, value: &'a str) -> Doc<'a> {
+        let lines = value.split('\n').collect::<Vec<_>>();
+        let last = lines.len() - 1;
+        let lines = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if i == 0 {
+                    Doc::Str(line.trim_end())
+                } else {
+                    let line = if i < last { line.trim() } else { line.trim_start() };
+                    array!(self, ss!(" "), Doc::Str(line))
+                }
+            })
+            .collect();
+        array!(self, ss!("/*"), Doc::Array(self.join(Separator::Hardline, lines)), ss!("*/"))
+    }
+}
+
+/// If the comment has multiple lines and every line starts with a star
+/// we can fix the indentation of each line.
+fn is_indentable_block_comment(value: &str) -> bool {
+    // The stars in the `/*` and `*/` delimiters are not included in the comment value,
+    // so add them back first.
+    let lines = format!("*{value}*");
+    let mut lines = lines.split('\n');
+    lines.clone().count() > 1 && lines.all(|line| line.trim_start().starts_with('*'))
+}
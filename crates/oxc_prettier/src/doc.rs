@@ -14,12 +14,19 @@ pub enum Doc<'a> {
     Array(Vec<'a, Doc<'a>>),
     /// Increase the level of indentation.
     Indent(Vec<'a, Doc<'a>>),
+    /// Increase the indentation by a fixed number of spaces or a string,
+    /// or decrease it, see [AlignKind].
+    Align(Align<'a>),
     /// Mark a group of items which the printer should try to fit on one line.
     /// This is the basic command to tell the printer when to break.
     /// Groups are usually nested, and the printer will try to fit everything on one line,
     /// but if it doesn't fit it will break the outermost group first and try again.
     /// It will continue breaking groups until everything fits (or there are no more groups to break).
-    Group(Vec<'a, Doc<'a>>),
+    Group(Group<'a>),
+    /// Expects the contents to be an array of alternating content and whitespace.
+    /// The printer fits as much content as possible on each line, breaking only the
+    /// whitespace which is needed, e.g. for the words of a paragraph.
+    Fill(Vec<'a, Doc<'a>>),
     /// Specify a line break.
     /// If an expression fits on one line, the line break will be replaced with a space.
     /// Line breaks always indent the next line with the current level of indentation.
@@ -30,8 +37,140 @@ pub enum Doc<'a> {
     /// Specify a line break that is **always** included in the output,
     /// no matter if the expression fits on one line or not.
     Hardline,
+    /// Specify a line break that is always included in the output,
+    /// and doesn't indent the next line. Used for template literals.
+    LiteralLine,
     /// Print something if the current `group` or the current element of `fill` breaks and something else if it doesn't.
-    IfBreak(Box<'a, Doc<'a>>),
+    IfBreak(IfBreak<'a>),
+    /// An optimized version of `if_break(indent(doc), doc, group_id)`.
+    IndentIfBreak(IndentIfBreak<'a>),
+    /// Print something at the end of the current line, before the next line break.
+    /// This is used for trailing comments, e.g. `a, // comment`.
+    LineSuffix(Vec<'a, Doc<'a>>),
+    /// Flush the pending [Doc::LineSuffix] with a hardline, if there is any.
+    /// e.g. `${a // comment` has to be printed as `${a // comment\n}`.
+    LineSuffixBoundary,
+    /// Force the enclosing groups to break.
+    BreakParent,
+    /// Mark the contents with a label, which does not affect the printing.
+    /// It is used to look back at how a child was printed, e.g. `member-chain`.
+    Label(Label<'a>),
+}
+
+#[derive(Debug)]
+pub struct Group<'a> {
+    pub contents: Vec<'a, Doc<'a>>,
+    /// Break the group even if the contents would fit on one line.
+    /// This is set by [crate::printer::Printer] for groups which contain a hard break.
+    pub should_break: bool,
+    /// For `conditionalGroup`: the other states to try in order if `contents` does not fit,
+    /// the last one is the most expanded state and is printed in break mode as a fallback.
+    pub expanded_states: Option<Vec<'a, Doc<'a>>>,
+    /// Used by [Doc::IfBreak] and [Doc::IndentIfBreak] to check whether this group is broken.
+    pub id: Option<GroupId>,
+}
+
+#[allow(unused)]
+impl<'a> Group<'a> {
+    pub fn new(contents: Vec<'a, Doc<'a>>) -> Self {
+        Self { contents, should_break: false, expanded_states: None, id: None }
+    }
+
+    /// Try `contents` first, then each of `expanded_states` in flat mode,
+    /// then the last of `expanded_states` in break mode.
+    pub fn new_conditional_group(
+        contents: Vec<'a, Doc<'a>>,
+        expanded_states: Vec<'a, Doc<'a>>,
+    ) -> Self {
+        Self { contents, should_break: false, expanded_states: Some(expanded_states), id: None }
+    }
+
+    pub fn with_break(mut self, should_break: bool) -> Self {
+        self.should_break = should_break;
+        self
+    }
+
+    pub fn with_id(mut self, id: GroupId) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GroupId(u32);
+
+#[derive(Debug)]
+pub struct IfBreak<'a> {
+    pub break_contents: Box<'a, Doc<'a>>,
+    pub flat_contents: Box<'a, Doc<'a>>,
+    /// Check the mode of the group with this id instead of the enclosing group.
+    pub group_id: Option<GroupId>,
+}
+
+#[derive(Debug)]
+pub struct IndentIfBreak<'a> {
+    pub contents: Vec<'a, Doc<'a>>,
+    pub group_id: GroupId,
+    /// Indent the contents if the group is flat instead.
+    pub negate: bool,
+}
+
+#[derive(Debug)]
+pub struct Align<'a> {
+    pub kind: AlignKind<'a>,
+    pub contents: Vec<'a, Doc<'a>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[allow(unused)]
+pub enum AlignKind<'a> {
+    /// Align by a number of spaces, which are converted to tabs together
+    /// with the enclosing indentation when `use_tabs` is set.
+    Spaces(usize),
+    /// Align by a string, e.g. `" * "` for the lines of a block comment.
+    String(&'a str),
+    /// Decrease the indentation by one level.
+    Dedent,
+    /// Decrease the indentation to the root set by [AlignKind::Root], or remove it.
+    DedentToRoot,
+    /// Mark the current indentation as the root for [AlignKind::DedentToRoot] and [Doc::LiteralLine].
+    Root,
+}
+
+#[derive(Debug)]
+pub struct Label<'a> {
+    pub label: &'static str,
+    pub contents: Vec<'a, Doc<'a>>,
+}
+
+#[allow(unused)]
+impl<'a> Doc<'a> {
+    /// The label of the doc, if it is a [Doc::Label].
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            Self::Label(label) => Some(label.label),
+            _ => None,
+        }
+    }
+
+    /// Whether the doc contains a forced break, i.e. a hard break or a broken group.
+    pub fn will_break(&self) -> bool {
+        let any_break = |docs: &Vec<'a, Doc<'a>>| docs.iter().any(Doc::will_break);
+        match self {
+            Self::Hardline | Self::LiteralLine | Self::BreakParent => true,
+            Self::Group(group) => group.should_break || any_break(&group.contents),
+            Self::Array(docs) | Self::Indent(docs) | Self::Fill(docs) | Self::LineSuffix(docs) => {
+                any_break(docs)
+            }
+            Self::Align(Align { contents, .. })
+            | Self::IndentIfBreak(IndentIfBreak { contents, .. })
+            | Self::Label(Label { contents, .. }) => any_break(contents),
+            Self::IfBreak(if_break) => {
+                if_break.break_contents.will_break() || if_break.flat_contents.will_break()
+            }
+            Self::Str(_) | Self::Line | Self::Softline | Self::LineSuffixBoundary => false,
+        }
+    }
 }
 
 pub struct DocPrinter<'a> {
@@ -52,34 +191,56 @@ impl<'a> DocPrinter<'a> {
                 str.push('"');
             }
             Doc::Array(docs) => {
-                str.push('[');
-                for (idx, doc) in docs.iter().enumerate() {
-                    str.push_str(&self.print(doc));
-                    if idx != docs.len() - 1 {
-                        str.push_str(", ");
-                    }
-                }
-                str.push(']');
+                str.push_str(&self.print_docs(docs));
             }
             Doc::Indent(contents) => {
-                str.push_str("indent([");
-                for (idx, doc) in contents.iter().enumerate() {
-                    str.push_str(&self.print(doc));
-                    if idx != contents.len() - 1 {
-                        str.push_str(", ");
-                    }
+                str.push_str("indent(");
+                str.push_str(&self.print_docs(contents));
+                str.push(')');
+            }
+            Doc::Align(align) => {
+                match align.kind {
+                    AlignKind::Spaces(n) => str.push_str(&std::format!("align({n}, ")),
+                    AlignKind::String(s) => str.push_str(&std::format!("align({s:?}, ")),
+                    AlignKind::Dedent => str.push_str("dedent("),
+                    AlignKind::DedentToRoot => str.push_str("dedentToRoot("),
+                    AlignKind::Root => str.push_str("markAsRoot("),
                 }
-                str.push_str("])");
+                str.push_str(&self.print_docs(&align.contents));
+                str.push(')');
             }
-            Doc::Group(contents) => {
-                str.push_str("group([");
-                for (idx, doc) in contents.iter().enumerate() {
-                    str.push_str(&self.print(doc));
-                    if idx != contents.len() - 1 {
+            Doc::Group(group) => {
+                if let Some(expanded_states) = &group.expanded_states {
+                    str.push_str("conditionalGroup([");
+                    str.push_str(&self.print_docs(&group.contents));
+                    for state in expanded_states {
                         str.push_str(", ");
+                        str.push_str(&self.print(state));
+                    }
+                    str.push(']');
+                } else {
+                    str.push_str("group(");
+                    str.push_str(&self.print_docs(&group.contents));
+                }
+                if group.should_break || group.id.is_some() {
+                    str.push_str(", { ");
+                    if group.should_break {
+                        str.push_str("shouldBreak: true");
                     }
+                    if let Some(id) = group.id {
+                        if group.should_break {
+                            str.push_str(", ");
+                        }
+                        str.push_str(&std::format!("id: Symbol({})", id.0));
+                    }
+                    str.push_str(" }");
                 }
-                str.push_str("])");
+                str.push(')');
+            }
+            Doc::Fill(parts) => {
+                str.push_str("fill(");
+                str.push_str(&self.print_docs(parts));
+                str.push(')');
             }
             Doc::Line => {
                 str.push_str("line");
@@ -90,15 +251,61 @@ impl<'a> DocPrinter<'a> {
             Doc::Hardline => {
                 str.push_str("hardline");
             }
-            Doc::IfBreak(break_contents) => {
+            Doc::LiteralLine => {
+                str.push_str("literalline");
+            }
+            Doc::IfBreak(if_break) => {
                 str.push_str("ifBreak(");
-                str.push_str(&self.print(break_contents));
+                str.push_str(&self.print(&if_break.break_contents));
+                str.push_str(", ");
+                str.push_str(&self.print(&if_break.flat_contents));
+                if let Some(id) = if_break.group_id {
+                    str.push_str(&std::format!(", {{ groupId: Symbol({}) }}", id.0));
+                }
+                str.push(')');
+            }
+            Doc::IndentIfBreak(indent_if_break) => {
+                str.push_str("indentIfBreak(");
+                str.push_str(&self.print_docs(&indent_if_break.contents));
+                str.push_str(&std::format!(", {{ groupId: Symbol({})", indent_if_break.group_id.0));
+                if indent_if_break.negate {
+                    str.push_str(", negate: true");
+                }
+                str.push_str(" })");
+            }
+            Doc::LineSuffix(contents) => {
+                str.push_str("lineSuffix(");
+                str.push_str(&self.print_docs(contents));
+                str.push(')');
+            }
+            Doc::LineSuffixBoundary => {
+                str.push_str("lineSuffixBoundary");
+            }
+            Doc::BreakParent => {
+                str.push_str("breakParent");
+            }
+            Doc::Label(label) => {
+                str.push_str(&std::format!("label({:?}, ", label.label));
+                str.push_str(&self.print_docs(&label.contents));
                 str.push(')');
             }
         }
 
         str
     }
+
+    fn print_docs(&mut self, docs: &Vec<'a, Doc<'a>>) -> String {
+        let mut str = String::new_in(self.allocator);
+        str.push('[');
+        for (idx, doc) in docs.iter().enumerate() {
+            str.push_str(&self.print(doc));
+            if idx != docs.len() - 1 {
+                str.push_str(", ");
+            }
+        }
+        str.push(']');
+        str
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -125,6 +332,58 @@ impl<'a> Prettier<'a> {
         Box(self.allocator.alloc(doc))
     }
 
+    /// A deep copy of the doc, for docs which are printed in more than one place,
+    /// e.g. in the contents and in the expanded states of a conditional group.
+    pub(crate) fn clone_doc(&self, doc: &Doc<'a>) -> Doc<'a> {
+        let clone_all = |docs: &Vec<'a, Doc<'a>>| {
+            let mut cloned = self.vec();
+            cloned.extend(docs.iter().map(|doc| self.clone_doc(doc)));
+            cloned
+        };
+        match doc {
+            Doc::Str(s) => Doc::Str(s),
+            Doc::Array(docs) => Doc::Array(clone_all(docs)),
+            Doc::Indent(docs) => Doc::Indent(clone_all(docs)),
+            Doc::Fill(docs) => Doc::Fill(clone_all(docs)),
+            Doc::LineSuffix(docs) => Doc::LineSuffix(clone_all(docs)),
+            Doc::Align(align) => {
+                Doc::Align(Align { kind: align.kind, contents: clone_all(&align.contents) })
+            }
+            Doc::Group(group) => Doc::Group(Group {
+                contents: clone_all(&group.contents),
+                should_break: group.should_break,
+                expanded_states: group.expanded_states.as_ref().map(clone_all),
+                id: group.id,
+            }),
+            Doc::IfBreak(if_break) => Doc::IfBreak(IfBreak {
+                break_contents: self.alloc(self.clone_doc(&if_break.break_contents)),
+                flat_contents: self.alloc(self.clone_doc(&if_break.flat_contents)),
+                group_id: if_break.group_id,
+            }),
+            Doc::IndentIfBreak(indent_if_break) => Doc::IndentIfBreak(IndentIfBreak {
+                contents: clone_all(&indent_if_break.contents),
+                group_id: indent_if_break.group_id,
+                negate: indent_if_break.negate,
+            }),
+            Doc::Label(label) => {
+                Doc::Label(Label { label: label.label, contents: clone_all(&label.contents) })
+            }
+            Doc::Line => Doc::Line,
+            Doc::Softline => Doc::Softline,
+            Doc::Hardline => Doc::Hardline,
+            Doc::LiteralLine => Doc::LiteralLine,
+            Doc::LineSuffixBoundary => Doc::LineSuffixBoundary,
+            Doc::BreakParent => Doc::BreakParent,
+        }
+    }
+
+    /// Create a new id for [Group::id].
+    #[allow(unused)]
+    pub(crate) fn next_id(&mut self) -> GroupId {
+        self.group_id_counter += 1;
+        GroupId(self.group_id_counter)
+    }
+
     #[allow(unused)]
     pub(crate) fn join(
         &self,
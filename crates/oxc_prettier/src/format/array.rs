@@ -1,7 +1,21 @@
+//! Printing of arrays and tuples
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/print/array.js>
+
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
 
-use crate::{doc::Doc, group, if_break, ss, Prettier};
+use crate::{
+    array,
+    comments::CommentFlags,
+    doc::{Doc, Group},
+    group, hardline, if_break, line, softline, ss,
+    util::is_next_line_empty,
+    Prettier, TrailingComma,
+};
 use oxc_allocator::Vec;
 
 use super::Format;
@@ -19,26 +33,65 @@ impl<'a, 'b> Array<'a, 'b> {
             Self::TSTupleType(tuple) => tuple.element_types.len(),
         }
     }
+
+    fn span(&self) -> Span {
+        match self {
+            Self::ArrayExpression(array) => array.span,
+            Self::TSTupleType(tuple) => tuple.span,
+        }
+    }
+
+    /// `[a, ,]` needs a trailing comma to keep the hole
+    fn has_trailing_hole(&self) -> bool {
+        match self {
+            Self::ArrayExpression(array) => {
+                matches!(array.elements.last(), Some(ArrayExpressionElement::Elision(_)))
+            }
+            Self::TSTupleType(_) => false,
+        }
+    }
 }
 
 pub(super) fn print_array<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Doc<'a> {
     if array.len() == 0 {
-        return ss!("[]");
+        return print_empty_array_elements(p, array);
     }
 
-    let mut parts = p.vec();
-    parts.push(ss!("["));
+    let id = p.next_id();
+    let should_break = should_break(array);
+    let should_use_concise_formatting = is_concisely_printed_array(p, array);
+
+    let trailing_comma = if array.has_trailing_hole() {
+        ss!(",")
+    } else if !matches!(p.options.trailing_comma, TrailingComma::All | TrailingComma::ES5) {
+        ss!("")
+    } else if should_use_concise_formatting {
+        if_break!(p, ",", "", Some(id))
+    } else {
+        if_break!(p, ",")
+    };
 
     let mut parts_inner = p.vec();
-    parts_inner.push(Doc::Softline);
-    parts_inner.extend(print_elements(p, array));
-    parts_inner.push(if_break!(p, ","));
+    parts_inner.push(softline!());
+    if should_use_concise_formatting {
+        parts_inner.push(print_array_items_concisely(p, array, trailing_comma));
+    } else {
+        parts_inner.push(Doc::Array(print_elements(p, array)));
+        parts_inner.push(trailing_comma);
+    }
 
-    parts.push(group!(p, Doc::Indent(parts_inner)));
-    parts.push(Doc::Softline);
+    let mut parts = p.vec();
+    parts.push(ss!("["));
+    parts.push(Doc::Indent(parts_inner));
+    parts.push(softline!());
     parts.push(ss!("]"));
 
-    Doc::Group(parts)
+    Doc::Group(Group::new(parts).with_break(should_break).with_id(id))
+}
+
+fn print_empty_array_elements<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Doc<'a> {
+    p.print_dangling_comments(array.span(), true)
+        .map_or_else(|| ss!("[]"), |dangling| group![p, ss!("["), dangling, softline!(), ss!("]")])
 }
 
 fn print_elements<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Vec<'a, Doc<'a>> {
@@ -46,25 +99,115 @@ fn print_elements<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Vec<'a, Do
     match array {
         Array::ArrayExpression(array) => {
             for (i, element) in array.elements.iter().enumerate() {
-                if i > 0 && i < array.elements.len() {
-                    parts.push(ss!(","));
-                    parts.push(Doc::Line);
+                if let ArrayExpressionElement::Elision(_) = element {
+                    parts.push(ss!(""));
+                } else {
+                    parts.push(group!(p, element.format(p)));
                 }
 
-                parts.push(element.format(p));
+                if i < array.elements.len() - 1 {
+                    parts.push(ss!(","));
+                    parts.push(line!());
+                    if !matches!(element, ArrayExpressionElement::Elision(_))
+                        && is_next_line_empty(p.source_text, element.span())
+                    {
+                        parts.push(softline!());
+                    }
+                }
             }
         }
         Array::TSTupleType(tuple) => {
             for (i, element) in tuple.element_types.iter().enumerate() {
-                if i > 0 && i < tuple.element_types.len() {
+                parts.push(group!(p, element.format(p)));
+
+                if i < tuple.element_types.len() - 1 {
                     parts.push(ss!(","));
-                    parts.push(Doc::Line);
+                    parts.push(line!());
                 }
-
-                parts.push(element.format(p));
             }
         }
     }
 
     parts
 }
+
+/// Numbers are filled into as few lines as possible, e.g. for lookup tables.
+fn print_array_items_concisely<'a>(
+    p: &mut Prettier<'a>,
+    array: &Array<'a, '_>,
+    trailing_comma: Doc<'a>,
+) -> Doc<'a> {
+    let Array::ArrayExpression(array) = array else { unreachable!() };
+    let mut trailing_comma = Some(trailing_comma);
+    let mut parts = p.vec();
+
+    for (i, element) in array.elements.iter().enumerate() {
+        let is_last = i == array.elements.len() - 1;
+        let separator = if is_last { trailing_comma.take().unwrap() } else { ss!(",") };
+        parts.push(array!(p, element.format(p), separator));
+
+        if !is_last {
+            if is_next_line_empty(p.source_text, element.span()) {
+                parts.push(array!(p, hardline!(), hardline!()));
+            } else if p.has_comment(
+                array.elements[i + 1].span(),
+                CommentFlags::Leading | CommentFlags::Line,
+            ) {
+                parts.push(hardline!());
+            } else {
+                parts.push(line!());
+            }
+        }
+    }
+
+    Doc::Fill(parts)
+}
+
+/// An array of numbers, e.g. `[1, -2, +3.5]`
+fn is_concisely_printed_array(p: &Prettier, array: &Array) -> bool {
+    let Array::ArrayExpression(array) = array else { return false };
+    array.elements.len() > 1
+        && array.elements.iter().all(|element| {
+            let is_number = match element {
+                ArrayExpressionElement::Expression(Expression::NumberLiteral(_)) => true,
+                ArrayExpressionElement::Expression(Expression::UnaryExpression(expr)) => {
+                    matches!(expr.operator, UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation)
+                        && matches!(expr.argument, Expression::NumberLiteral(_))
+                        && !p.has_comment(expr.argument.span(), CommentFlags::empty())
+                }
+                _ => false,
+            };
+            is_number && !p.has_comment(element.span(), CommentFlags::Trailing | CommentFlags::Line)
+        })
+}
+
+/// Break arrays of more than one object or array with more than one element,
+/// e.g. a matrix.
+fn should_break(array: &Array) -> bool {
+    let Array::ArrayExpression(array) = array else { return false };
+    array.elements.len() > 1
+        && array.elements.iter().enumerate().all(|(i, element)| {
+            let next = array.elements.get(i + 1);
+            match element {
+                ArrayExpressionElement::Expression(Expression::ObjectExpression(object)) => {
+                    object.properties.len() > 1
+                        && next.map_or(true, |next| {
+                            matches!(
+                                next,
+                                ArrayExpressionElement::Expression(Expression::ObjectExpression(_))
+                            )
+                        })
+                }
+                ArrayExpressionElement::Expression(Expression::ArrayExpression(array)) => {
+                    array.elements.len() > 1
+                        && next.map_or(true, |next| {
+                            matches!(
+                                next,
+                                ArrayExpressionElement::Expression(Expression::ArrayExpression(_))
+                            )
+                        })
+                }
+                _ => false,
+            }
+        })
+}
@@ -1,7 +1,9 @@
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
 
-use crate::{doc::Doc, ss, Format, Prettier};
+use crate::{comments::CommentFlags, doc::Doc, group, ss, ArrowParens, Format, Prettier};
+
+use super::{jsx, typescript};
 
 pub(super) fn print_arrow_function<'a>(
     p: &mut Prettier<'a>,
@@ -9,13 +11,35 @@ pub(super) fn print_arrow_function<'a>(
 ) -> Doc<'a> {
     let mut parts = p.vec();
 
-    parts.push(ss!("() => "));
+    if expr.r#async {
+        parts.push(ss!("async "));
+    }
+    if should_print_params_without_parens(p, expr) {
+        parts.push(expr.params.items[0].format(p));
+    } else {
+        if let Some(type_params) = &expr.type_parameters {
+            parts.push(typescript::print_arrow_type_parameters(p, type_params));
+        }
+        let params = expr.params.format(p);
+        let return_type = typescript::print_type_annotation(p, expr.return_type.as_deref());
+        parts.push(group!(p, params, return_type));
+        if let Some(predicate) = &expr.predicate {
+            parts.push(typescript::print_flow_predicate(p, predicate, expr.return_type.is_some()));
+        }
+    }
+    if let Some(dangling) = p.print_dangling_comments(expr.span, false) {
+        parts.push(ss!(" "));
+        parts.push(dangling);
+    }
+    parts.push(ss!(" => "));
     if expr.expression {
         let stmt = &expr.body.statements[0];
         match stmt {
             // ExpressionStatement will add a semicolon and Hardline, But we don't need it
             // So we only need to format the expression of the ExpressionStatement
-            Statement::ExpressionStatement(expr_stmt) => parts.push(expr_stmt.expression.format(p)),
+            Statement::ExpressionStatement(expr_stmt) => {
+                parts.push(jsx::maybe_wrap_jsx_element_in_parens(p, &expr_stmt.expression));
+            }
             _ => parts.push(stmt.format(p)),
         }
     } else {
@@ -24,3 +48,72 @@ pub(super) fn print_arrow_function<'a>(
 
     Doc::Array(parts)
 }
+
+/// `x => x` with `arrowParens: "avoid"`
+fn should_print_params_without_parens(p: &Prettier, expr: &ArrowExpression) -> bool {
+    if !matches!(p.options.arrow_parens, ArrowParens::Avoid) {
+        return false;
+    }
+    let params = &expr.params;
+    if params.items.len() != 1 || params.rest.is_some() {
+        return false;
+    }
+    let param = &params.items[0];
+    expr.type_parameters.is_none()
+        && expr.return_type.is_none()
+        && expr.predicate.is_none()
+        && param.accessibility.is_none()
+        && !param.readonly
+        && param.decorators.is_empty()
+        && param.pattern.type_annotation.is_none()
+        && !param.pattern.optional
+        && matches!(param.pattern.kind, BindingPatternKind::BindingIdentifier(_))
+        && !p.has_comment(params.span, CommentFlags::empty())
+        && !p.has_comment(param.span, CommentFlags::empty())
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, PrettierOptions};
+
+    let tests = [
+        ("const f = () => 1;\n", "const f = () => 1;\n"),
+        (
+            "const f = async (a, b = 1, ...c) => { return a; };\n",
+            "const f = async (a, b = 1, ...c) => {\n  return a;\n};\n",
+        ),
+        ("const f = x => x;\n", "const f = (x) => x;\n"),
+        ("const f = (/* dangling */) => 1;\n", "const f = (/* dangling */) => 1;\n"),
+    ];
+    Tester::new("test.js", PrettierOptions::default()).test(&tests);
+
+    let tests = [
+        (
+            "const f = <T,>(a: T, b?: string): T => a;\n",
+            "const f = <T>(a: T, b?: string): T => a;\n",
+        ),
+        (
+            "const f = async (): Promise<void> => {};\n",
+            "const f = async (): Promise<void> => {};\n",
+        ),
+    ];
+    Tester::new("test.ts", PrettierOptions::default()).test(&tests);
+
+    let tests = [
+        ("const id = <T,>(x: T) => x;\n", "const id = <T,>(x: T) => x;\n"),
+        ("const id = <T extends {}>(x: T) => x;\n", "const id = <T extends {}>(x: T) => x;\n"),
+        ("const f = <T, U>(x: T, y: U) => x;\n", "const f = <T, U>(x: T, y: U) => x;\n"),
+    ];
+    Tester::new("test.tsx", PrettierOptions::default()).test(&tests);
+
+    let tests = [
+        ("const f = (x) => x;\n", "const f = x => x;\n"),
+        ("const f = async (x) => x;\n", "const f = async x => x;\n"),
+        ("const f = (x, y) => x;\n", "const f = (x, y) => x;\n"),
+        ("const f = ({ x }) => x;\n", "const f = ({ x }) => x;\n"),
+        ("const f = (x = 1) => x;\n", "const f = (x = 1) => x;\n"),
+    ];
+    let options =
+        PrettierOptions { arrow_parens: ArrowParens::Avoid, ..PrettierOptions::default() };
+    Tester::new("test.js", options).test(&tests);
+}
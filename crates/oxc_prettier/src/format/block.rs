@@ -1,6 +1,7 @@
 use oxc_allocator::Vec;
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_span::Span;
 
 use crate::{doc::Doc, hardline, indent, ss, Prettier};
 
@@ -8,6 +9,7 @@ use super::statement;
 
 pub(super) fn print_block<'a>(
     p: &mut Prettier<'a>,
+    span: Span,
     stmts: &Vec<'a, Statement<'a>>,
     directives: Option<&Vec<'a, Directive>>,
     is_static_block: bool,
@@ -20,6 +22,9 @@ pub(super) fn print_block<'a>(
     if let Some(doc) = print_block_body(p, stmts, directives, true, false) {
         parts.push(indent![p, hardline!(), doc]);
         parts.push(hardline!());
+    } else if let Some(dangling) = p.print_dangling_comments(span, true) {
+        parts.push(dangling);
+        parts.push(hardline!());
     }
     parts.push(ss!("}"));
     Doc::Array(parts)
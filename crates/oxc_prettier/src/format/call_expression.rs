@@ -1,11 +1,13 @@
 use oxc_allocator::{Box, Vec};
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_span::Span;
 
 use crate::{doc::Doc, ss, Format, Prettier};
 
 pub(super) fn print_call_expression<'a>(
     p: &mut Prettier<'a>,
+    span: Span,
     callee: &Expression<'a>,
     arguments: &Vec<'a, Argument<'a>>,
     optional: bool, // for optional chaining
@@ -17,8 +19,21 @@ pub(super) fn print_call_expression<'a>(
         parts.push(ss!("new "));
     }
     parts.push(callee.format(p));
+    if let Some(type_parameters) = type_parameters {
+        parts.push(type_parameters.format(p));
+    }
     parts.push(ss!("("));
-    parts.extend(arguments.iter().map(|arg| arg.format(p)));
+    if arguments.is_empty() {
+        if let Some(dangling) = p.print_dangling_comments(span, false) {
+            parts.push(dangling);
+        }
+    }
+    for (i, arg) in arguments.iter().enumerate() {
+        if i != 0 {
+            parts.push(ss!(", "));
+        }
+        parts.push(arg.format(p));
+    }
     parts.push(ss!(")"));
     Doc::Array(parts)
 }
@@ -1,15 +1,188 @@
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
 
-use crate::{doc::Doc, ss, Format, Prettier};
+use crate::{
+    array,
+    doc::{Doc, Group},
+    group, hardline, indent, line, ss, Format, Prettier,
+};
+
+use super::{statement, typescript};
 
 pub(super) fn print_class<'a>(p: &mut Prettier<'a>, class: &Class<'a>) -> Doc<'a> {
     let mut parts = p.vec();
-    parts.push(ss!("class "));
+    if class.modifiers.contains(ModifierKind::Declare) {
+        parts.push(ss!("declare "));
+    }
+    if class.modifiers.contains(ModifierKind::Abstract) {
+        parts.push(ss!("abstract "));
+    }
+    parts.push(ss!("class"));
+
+    let mut parts_group = p.vec();
     if let Some(id) = &class.id {
-        parts.push(id.format(p));
+        parts_group.push(ss!(" "));
+        parts_group.push(id.format(p));
+    }
+    if let Some(type_parameters) = &class.type_parameters {
+        parts_group.push(type_parameters.format(p));
     }
+
+    let mut heritage_clauses = p.vec();
+    if let Some(super_class) = &class.super_class {
+        let mut clause = p.vec();
+        clause.push(ss!("extends "));
+        clause.push(super_class.format(p));
+        if let Some(super_type_parameters) = &class.super_type_parameters {
+            clause.push(super_type_parameters.format(p));
+        }
+        heritage_clauses.push(Doc::Array(clause));
+    }
+    if let Some(implements) = class.implements.as_ref().filter(|implements| !implements.is_empty())
+    {
+        let mut types = p.vec();
+        for (i, implement) in implements.iter().enumerate() {
+            if i > 0 {
+                types.push(ss!(","));
+                types.push(line!());
+            }
+            types.push(implement.format(p));
+        }
+        heritage_clauses.push(array!(p, ss!("implements "), indent!(p, Doc::Array(types))));
+    }
+
+    // Break before the heritage clauses together if they don't fit
+    let group_mode = heritage_clauses.len() > 1
+        || (class.type_parameters.is_some() && !heritage_clauses.is_empty());
+    if group_mode {
+        for clause in heritage_clauses {
+            parts_group.push(line!());
+            parts_group.push(clause);
+        }
+        parts.push(group!(p, Doc::Indent(parts_group)));
+    } else {
+        for clause in heritage_clauses {
+            parts_group.push(ss!(" "));
+            parts_group.push(clause);
+        }
+        parts.push(Doc::Array(parts_group));
+    }
+
     parts.push(ss!(" "));
     parts.push(class.body.format(p));
     Doc::Array(parts)
 }
+
+pub(super) fn print_class_body<'a>(p: &mut Prettier<'a>, body: &ClassBody<'a>) -> Doc<'a> {
+    if body.body.is_empty() {
+        return p.print_dangling_comments(body.span, true).map_or_else(
+            || ss!("{}"),
+            |dangling| array!(p, ss!("{"), dangling, hardline!(), ss!("}")),
+        );
+    }
+
+    let elements = statement::print_statement_sequence(p, &body.body, false);
+    array!(p, ss!("{"), indent!(p, hardline!(), Doc::Array(elements)), hardline!(), ss!("}"))
+}
+
+pub(super) fn print_property<'a>(
+    p: &mut Prettier<'a>,
+    property: &PropertyDefinition<'a>,
+    is_abstract: bool,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    if property.declare {
+        parts.push(ss!("declare "));
+    }
+    parts.push(typescript::print_accessibility(property.accessibility));
+    if property.r#static {
+        parts.push(ss!("static "));
+    }
+    if is_abstract {
+        parts.push(ss!("abstract "));
+    }
+    if property.r#override {
+        parts.push(ss!("override "));
+    }
+    if property.readonly {
+        parts.push(ss!("readonly "));
+    }
+    if let Some(variance) = property.variance {
+        parts.push(variance.format(p));
+    }
+    parts.push(print_property_key(p, &property.key, property.computed));
+    if property.optional {
+        parts.push(ss!("?"));
+    }
+    if property.definite {
+        parts.push(ss!("!"));
+    }
+    parts.push(typescript::print_type_annotation(p, property.type_annotation.as_deref()));
+    if let Some(value) = &property.value {
+        parts.push(ss!(" = "));
+        parts.push(value.format(p));
+    }
+    if p.options.semi {
+        parts.push(ss!(";"));
+    }
+    Doc::Group(Group::new(parts))
+}
+
+pub(super) fn print_method<'a>(
+    p: &mut Prettier<'a>,
+    method: &MethodDefinition<'a>,
+    is_abstract: bool,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    parts.push(typescript::print_accessibility(method.accessibility));
+    if method.r#static {
+        parts.push(ss!("static "));
+    }
+    if is_abstract {
+        parts.push(ss!("abstract "));
+    }
+    if method.r#override {
+        parts.push(ss!("override "));
+    }
+    match method.kind {
+        MethodDefinitionKind::Get => parts.push(ss!("get ")),
+        MethodDefinitionKind::Set => parts.push(ss!("set ")),
+        MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => {}
+    }
+    let func = &method.value;
+    if func.r#async {
+        parts.push(ss!("async "));
+    }
+    if func.generator {
+        parts.push(ss!("*"));
+    }
+    parts.push(print_property_key(p, &method.key, method.computed));
+    if method.optional {
+        parts.push(ss!("?"));
+    }
+    parts.push(typescript::print_signature(
+        p,
+        func.type_parameters.as_deref(),
+        &func.params,
+        func.return_type.as_deref(),
+    ));
+    if let Some(body) = &func.body {
+        parts.push(ss!(" "));
+        parts.push(body.format(p));
+    } else if p.options.semi {
+        parts.push(ss!(";"));
+    }
+    Doc::Array(parts)
+}
+
+pub(super) fn print_property_key<'a>(
+    p: &mut Prettier<'a>,
+    key: &PropertyKey<'a>,
+    computed: bool,
+) -> Doc<'a> {
+    if computed {
+        array!(p, ss!("["), key.format(p), ss!("]"))
+    } else {
+        key.format(p)
+    }
+}
@@ -3,10 +3,12 @@ use oxc_ast::ast::*;
 
 use crate::{doc::Doc, group, if_break, indent, softline, ss, Format, Prettier};
 
+use super::typescript;
+
 pub(super) fn print_function<'a>(p: &mut Prettier<'a>, func: &Function<'a>) -> Doc<'a> {
     let mut parts = p.vec();
-    if let Some(comments) = p.print_leading_comments(func.span) {
-        parts.push(comments);
+    if func.modifiers.contains(ModifierKind::Declare) {
+        parts.push(ss!("declare "));
     }
     if func.r#async {
         parts.push(ss!("async "));
@@ -16,17 +18,21 @@ pub(super) fn print_function<'a>(p: &mut Prettier<'a>, func: &Function<'a>) -> D
     } else {
         parts.push(ss!("function "));
     }
-    if let Some(type_params) = &func.type_parameters {
-        parts.push(type_params.format(p));
-    }
     if let Some(id) = &func.id {
         parts.push(p.str(id.name.as_str()));
     }
+    if let Some(type_params) = &func.type_parameters {
+        parts.push(type_params.format(p));
+    }
     if should_group_function_parameters(func) {
         parts.push(group!(p, func.params.format(p)));
     } else {
         parts.push(func.params.format(p));
     }
+    parts.push(typescript::print_type_annotation(p, func.return_type.as_deref()));
+    if let Some(predicate) = &func.predicate {
+        parts.push(typescript::print_flow_predicate(p, predicate, func.return_type.is_some()));
+    }
     if let Some(body) = &func.body {
         parts.push(ss!(" "));
         parts.push(body.format(p));
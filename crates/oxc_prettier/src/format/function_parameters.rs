@@ -10,6 +10,12 @@ pub(super) fn print_function_parameters<'a>(
     let mut parts = p.vec();
     parts.push(ss!("("));
 
+    if params.parameters_count() == 0 {
+        if let Some(dangling) = p.print_dangling_comments(params.span, false) {
+            parts.push(dangling);
+        }
+    }
+
     for (i, param) in params.items.iter().enumerate() {
         parts.push(param.format(p));
         if i < params.items.len() - 1 {
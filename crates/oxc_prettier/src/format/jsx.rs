@@ -0,0 +1,683 @@
+//! Printing of JSX
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/print/jsx.js>
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+
+use crate::{
+    array,
+    comments::CommentFlags,
+    conditional_group,
+    doc::{Doc, Group, IfBreak},
+    group, hardline, if_break, indent, line, softline, ss, wrap, Prettier,
+};
+
+use super::Format;
+
+/// A part of the printed children of a JSX element.
+///
+/// Prettier compares the separators between the children by identity to clean them up,
+/// so they are kept apart from the other docs until the children are printed.
+enum JsxPart<'a> {
+    /// An empty content or separator of the fill, i.e. `""`
+    Empty,
+    /// A meaningful space, which is printed as `{" "}` at the end of a broken line
+    JsxWhitespace,
+    /// `{" "}`
+    RawJsxWhitespace,
+    /// `{" "}` followed by a hard line break
+    RawJsxWhitespaceLine,
+    Line,
+    Softline,
+    Hardline,
+    Doc(Doc<'a>),
+}
+
+impl<'a> JsxPart<'a> {
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    fn is_line(&self) -> bool {
+        matches!(self, Self::Line | Self::Softline | Self::Hardline)
+    }
+
+    fn is_hard_or_soft_line(&self) -> bool {
+        matches!(self, Self::Softline | Self::Hardline)
+    }
+
+    fn to_doc(&self, p: &Prettier<'a>) -> Doc<'a> {
+        match self {
+            Self::Empty => ss!(""),
+            Self::JsxWhitespace => Doc::IfBreak(IfBreak {
+                break_contents: p.alloc(array!(p, ss!(raw_jsx_whitespace(p)), softline!())),
+                flat_contents: p.alloc(ss!(" ")),
+                group_id: None,
+            }),
+            Self::RawJsxWhitespace => ss!(raw_jsx_whitespace(p)),
+            Self::RawJsxWhitespaceLine => array!(p, ss!(raw_jsx_whitespace(p)), hardline!()),
+            Self::Line => line!(),
+            Self::Softline => softline!(),
+            Self::Hardline => hardline!(),
+            Self::Doc(doc) => p.clone_doc(doc),
+        }
+    }
+
+    fn will_break(&self) -> bool {
+        match self {
+            Self::RawJsxWhitespaceLine | Self::Hardline => true,
+            Self::Doc(doc) => doc.will_break(),
+            _ => false,
+        }
+    }
+}
+
+fn raw_jsx_whitespace(p: &Prettier) -> &'static str {
+    if p.options.single_quote {
+        "{' '}"
+    } else {
+        "{\" \"}"
+    }
+}
+
+/// Wrap a JSX element which breaks in parentheses, e.g. the initializer of `const a = <div />`.
+pub(super) fn maybe_wrap_jsx_element_in_parens<'a>(
+    p: &mut Prettier<'a>,
+    expr: &Expression<'a>,
+) -> Doc<'a> {
+    // Source parentheses around the element are replaced by the ones printed here
+    let mut inner = expr;
+    while let Expression::ParenthesizedExpression(paren) = inner {
+        inner = &paren.expression;
+    }
+    if !matches!(inner, Expression::JSXElement(_) | Expression::JSXFragment(_)) {
+        return expr.format(p);
+    }
+    let doc = inner.format(p);
+    group![p, if_break!(p, "("), indent!(p, softline!(), doc), softline!(), if_break!(p, ")")]
+}
+
+pub(super) fn print_jsx_element<'a>(p: &mut Prettier<'a>, element: &JSXElement<'a>) -> Doc<'a> {
+    let opening = element.opening_element.format(p);
+    let closing = element.closing_element.as_ref().map_or(ss!(""), |closing| closing.format(p));
+
+    // Keep `<div></div>` on one line
+    if is_empty_jsx_element(p, &element.children) {
+        return array!(p, opening, closing);
+    }
+
+    let is_facebook_translation_tag = matches!(
+        &element.opening_element.name,
+        JSXElementName::Identifier(ident) if ident.name == "fbt"
+    );
+    print_jsx_element_internal(
+        p,
+        opening,
+        closing,
+        &element.children,
+        element.opening_element.attributes.len() > 1,
+        is_facebook_translation_tag,
+    )
+}
+
+pub(super) fn print_jsx_fragment<'a>(p: &mut Prettier<'a>, fragment: &JSXFragment<'a>) -> Doc<'a> {
+    let opening = fragment.opening_fragment.format(p);
+    let closing = fragment.closing_fragment.format(p);
+
+    if fragment.children.is_empty() {
+        return array!(p, opening, closing);
+    }
+
+    print_jsx_element_internal(p, opening, closing, &fragment.children, false, false)
+}
+
+fn print_jsx_element_internal<'a>(
+    p: &mut Prettier<'a>,
+    opening: Doc<'a>,
+    closing: Doc<'a>,
+    children: &Vec<'a, JSXChild<'a>>,
+    contains_multiple_attributes: bool,
+    is_facebook_translation_tag: bool,
+) -> Doc<'a> {
+    // Don't break `<div>{`template`}</div>`
+    if let [JSXChild::ExpressionContainer(container)] = children.as_slice() {
+        if matches!(
+            container.expression,
+            JSXExpression::Expression(
+                Expression::TemplateLiteral(_) | Expression::TaggedTemplateExpression(_)
+            )
+        ) {
+            let child = print_jsx_expression_container(p, container, true);
+            return array!(p, opening, child, closing);
+        }
+    }
+
+    let contains_tag =
+        children.iter().any(|child| matches!(child, JSXChild::Element(_) | JSXChild::Fragment(_)));
+    let contains_multiple_expressions =
+        children.iter().filter(|child| matches!(child, JSXChild::ExpressionContainer(_))).count()
+            > 1;
+    let mut forced_break = opening.will_break()
+        || contains_tag
+        || contains_multiple_attributes
+        || contains_multiple_expressions;
+
+    let contains_text = children
+        .iter()
+        .any(|child| jsx_text(p.source_text, child).is_some_and(is_meaningful_jsx_text));
+
+    let mut parts = print_jsx_children(p, children, is_facebook_translation_tag);
+
+    remove_redundant_separators(&mut parts, contains_text);
+
+    // A meaningful space at the start or the end of a line is printed as `{" "}`.
+    let mut multiline_children = p.vec();
+    for (i, part) in parts.iter().enumerate() {
+        if matches!(part, JsxPart::JsxWhitespace) {
+            if i == 1 && parts[0].is_empty() {
+                if parts.len() == 2 {
+                    // Solitary whitespace
+                    multiline_children.push(JsxPart::RawJsxWhitespace.to_doc(p));
+                } else {
+                    // Leading whitespace
+                    multiline_children.push(JsxPart::RawJsxWhitespaceLine.to_doc(p));
+                }
+                continue;
+            } else if i == parts.len() - 1
+                || (i >= 2 && parts[i - 1].is_empty() && matches!(parts[i - 2], JsxPart::Hardline))
+            {
+                // Trailing whitespace, or whitespace after a line break
+                multiline_children.push(JsxPart::RawJsxWhitespace.to_doc(p));
+                continue;
+            }
+        }
+
+        multiline_children.push(part.to_doc(p));
+
+        if part.will_break() {
+            forced_break = true;
+        }
+    }
+
+    let content = if contains_text {
+        Doc::Fill(multiline_children)
+    } else {
+        Doc::Group(Group::new(multiline_children).with_break(true))
+    };
+
+    if forced_break {
+        return group![p, opening, indent!(p, hardline!(), content), hardline!(), closing];
+    }
+
+    let mut flat_parts = p.vec();
+    flat_parts.push(p.clone_doc(&opening));
+    flat_parts.extend(parts.iter().map(|part| part.to_doc(p)));
+    flat_parts.push(p.clone_doc(&closing));
+    let multiline_element =
+        group![p, opening, indent!(p, hardline!(), content), hardline!(), closing];
+
+    conditional_group!(p, Doc::Group(Group::new(flat_parts)), multiline_element)
+}
+
+/// Remove the empty contents and the line breaks before and after the meaningful spaces,
+/// where more than one separator ends up next to each other, then trim the leading and trailing
+/// separators.
+fn remove_redundant_separators(parts: &mut std::vec::Vec<JsxPart<'_>>, contains_text: bool) {
+    for i in (0..parts.len().saturating_sub(1)).rev() {
+        let (Some(a), Some(b)) = (parts.get(i), parts.get(i + 1)) else { continue };
+        let c = parts.get(i + 2);
+        let is_pair_of_empty_strings = a.is_empty() && b.is_empty();
+        let is_pair_of_hardlines =
+            matches!(a, JsxPart::Hardline) && b.is_empty() && matches!(c, Some(JsxPart::Hardline));
+        let is_line_followed_by_jsx_whitespace =
+            a.is_hard_or_soft_line() && b.is_empty() && matches!(c, Some(JsxPart::JsxWhitespace));
+        let is_jsx_whitespace_followed_by_line = matches!(a, JsxPart::JsxWhitespace)
+            && b.is_empty()
+            && c.is_some_and(JsxPart::is_hard_or_soft_line);
+        let is_double_jsx_whitespace = matches!(a, JsxPart::JsxWhitespace)
+            && b.is_empty()
+            && matches!(c, Some(JsxPart::JsxWhitespace));
+        let is_pair_of_hard_or_soft_lines = (matches!(a, JsxPart::Softline)
+            && b.is_empty()
+            && matches!(c, Some(JsxPart::Hardline)))
+            || (matches!(a, JsxPart::Hardline)
+                && b.is_empty()
+                && matches!(c, Some(JsxPart::Softline)));
+
+        if (is_pair_of_hardlines && contains_text)
+            || is_pair_of_empty_strings
+            || is_line_followed_by_jsx_whitespace
+            || is_double_jsx_whitespace
+        {
+            parts.drain(i..i + 2);
+        } else if is_jsx_whitespace_followed_by_line || is_pair_of_hard_or_soft_lines {
+            parts.drain(i + 1..i + 3);
+        }
+    }
+
+    // Trim the trailing lines and empty strings
+    while parts.last().is_some_and(|part| part.is_line() || part.is_empty()) {
+        parts.pop();
+    }
+    // Trim the leading lines and empty strings
+    while parts.len() > 1
+        && (parts[0].is_line() || parts[0].is_empty())
+        && (parts[1].is_line() || parts[1].is_empty())
+    {
+        parts.drain(0..2);
+    }
+}
+
+/// Print the children as alternating contents and separators for [Doc::Fill],
+/// with the words of the texts as the contents.
+fn print_jsx_children<'a>(
+    p: &mut Prettier<'a>,
+    children: &Vec<'a, JSXChild<'a>>,
+    is_facebook_translation_tag: bool,
+) -> std::vec::Vec<JsxPart<'a>> {
+    let mut parts = vec![];
+
+    for (i, child) in children.iter().enumerate() {
+        let next_child = children.get(i + 1);
+
+        let Some(text) = jsx_text(p.source_text, child) else {
+            let printed = print_jsx_child(p, child);
+            parts.push(JsxPart::Doc(printed));
+
+            let following = next_child.and_then(|next| jsx_text(p.source_text, next));
+            if let Some(following) = following.filter(|text| is_meaningful_jsx_text(text)) {
+                let first_word = split_jsx_whitespace(trim_jsx_whitespace(following))[0];
+                parts.push(separator_no_whitespace(
+                    is_facebook_translation_tag,
+                    first_word,
+                    child,
+                    next_child,
+                ));
+            } else {
+                parts.push(JsxPart::Hardline);
+            }
+            continue;
+        };
+
+        if is_meaningful_jsx_text(text) {
+            let mut words = split_jsx_whitespace(text);
+
+            // Starts with whitespace
+            if words[0].is_empty() {
+                parts.push(JsxPart::Empty);
+                words.remove(0);
+                let whitespace = words.remove(0);
+                if whitespace.contains('\n') {
+                    parts.push(separator_with_whitespace(
+                        is_facebook_translation_tag,
+                        words.first().copied().unwrap_or_default(),
+                        child,
+                    ));
+                } else {
+                    parts.push(JsxPart::JsxWhitespace);
+                }
+            }
+
+            // Ends with whitespace
+            let end_whitespace = if words.last().is_some_and(|word| word.is_empty()) {
+                words.pop();
+                words.pop()
+            } else {
+                None
+            };
+
+            // Whitespace only, without a new line
+            if words.is_empty() {
+                continue;
+            }
+
+            for (i, word) in words.iter().enumerate() {
+                if i % 2 == 1 {
+                    parts.push(JsxPart::Line);
+                } else {
+                    parts.push(JsxPart::Doc(p.str(word)));
+                }
+            }
+
+            let last_word = words.last().copied().unwrap_or_default();
+            match end_whitespace {
+                Some(whitespace) if whitespace.contains('\n') => {
+                    parts.push(separator_with_whitespace(
+                        is_facebook_translation_tag,
+                        last_word,
+                        child,
+                    ));
+                }
+                Some(_) => parts.push(JsxPart::JsxWhitespace),
+                None => parts.push(separator_no_whitespace(
+                    is_facebook_translation_tag,
+                    last_word,
+                    child,
+                    next_child,
+                )),
+            }
+        } else if text.matches('\n').count() > 1 {
+            // Keep (up to one) blank line between tags and expressions
+            parts.push(JsxPart::Empty);
+            parts.push(JsxPart::Hardline);
+        }
+    }
+
+    parts
+}
+
+fn print_jsx_child<'a>(p: &mut Prettier<'a>, child: &JSXChild<'a>) -> Doc<'a> {
+    match child {
+        JSXChild::Element(element) => wrap!(p, element.span, { print_jsx_element(p, element) }),
+        JSXChild::Fragment(fragment) => {
+            wrap!(p, fragment.span, { print_jsx_fragment(p, fragment) })
+        }
+        JSXChild::ExpressionContainer(container) => {
+            wrap!(p, container.span, { print_jsx_expression_container(p, container, true) })
+        }
+        JSXChild::Spread(spread) => spread.format(p),
+        JSXChild::Text(text) => p.str(text.span.source_text(p.source_text)),
+    }
+}
+
+fn separator_no_whitespace<'a>(
+    is_facebook_translation_tag: bool,
+    word: &str,
+    child: &JSXChild<'a>,
+    next: Option<&JSXChild<'a>>,
+) -> JsxPart<'a> {
+    if is_facebook_translation_tag {
+        return JsxPart::Empty;
+    }
+    let is_self_closing = |child: &JSXChild| matches!(child, JSXChild::Element(element) if element.closing_element.is_none());
+    if is_self_closing(child) || next.is_some_and(is_self_closing) {
+        if word.chars().count() == 1 {
+            return JsxPart::Softline;
+        }
+        return JsxPart::Hardline;
+    }
+    JsxPart::Softline
+}
+
+fn separator_with_whitespace<'a>(
+    is_facebook_translation_tag: bool,
+    word: &str,
+    child: &JSXChild<'a>,
+) -> JsxPart<'a> {
+    if is_facebook_translation_tag {
+        return JsxPart::Hardline;
+    }
+    if word.chars().count() == 1 {
+        return match child {
+            JSXChild::Text(text) if !text.value.contains('\n') => JsxPart::Hardline,
+            _ => JsxPart::Softline,
+        };
+    }
+    JsxPart::Hardline
+}
+
+/// The raw text of a text child, or of `{" "}`, which is printed as a text.
+fn jsx_text<'b>(source_text: &'b str, child: &JSXChild) -> Option<&'b str> {
+    match child {
+        JSXChild::Text(text) => Some(text.span.source_text(source_text)),
+        JSXChild::ExpressionContainer(JSXExpressionContainer {
+            expression: JSXExpression::Expression(Expression::StringLiteral(literal)),
+            ..
+        }) if literal.value == " " => Some(" "),
+        _ => None,
+    }
+}
+
+fn is_jsx_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\n' | '\r' | '\t')
+}
+
+/// A text is meaningful if it has a non-whitespace character, or whitespace without a line break.
+fn is_meaningful_jsx_text(text: &str) -> bool {
+    text.contains(|c| !is_jsx_whitespace(c)) || !text.contains('\n')
+}
+
+fn is_empty_jsx_element(p: &Prettier, children: &Vec<'_, JSXChild<'_>>) -> bool {
+    match children.as_slice() {
+        [] => true,
+        [child @ JSXChild::Text(_)] => {
+            jsx_text(p.source_text, child).is_some_and(|text| !is_meaningful_jsx_text(text))
+        }
+        _ => false,
+    }
+}
+
+fn trim_jsx_whitespace(text: &str) -> &str {
+    text.trim_matches(is_jsx_whitespace)
+}
+
+/// Split the text into words and the whitespace between them, like
+/// `text.split(/([ \n\r\t]+)/)`, so the first and the last words are empty
+/// if the text starts or ends with whitespace.
+fn split_jsx_whitespace(text: &str) -> std::vec::Vec<&str> {
+    let mut words = vec![];
+    let mut word_start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !is_jsx_whitespace(c) {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = chars.peek() {
+            if !is_jsx_whitespace(c) {
+                break;
+            }
+            end = i + c.len_utf8();
+            chars.next();
+        }
+        words.push(&text[word_start..start]);
+        words.push(&text[start..end]);
+        word_start = end;
+    }
+    words.push(&text[word_start..]);
+    words
+}
+
+pub(super) fn print_jsx_opening_element<'a>(
+    p: &mut Prettier<'a>,
+    element: &JSXOpeningElement<'a>,
+) -> Doc<'a> {
+    let mut name = p.vec();
+    name.push(element.name.format(p));
+    if let Some(type_parameters) = &element.type_parameters {
+        name.push(type_parameters.format(p));
+    }
+    let name = Doc::Array(name);
+
+    // Don't break self-closing elements with no attributes
+    if element.self_closing && element.attributes.is_empty() {
+        return array!(p, ss!("<"), name, ss!(" />"));
+    }
+
+    // Don't break up opening elements with a single long text attribute
+    if let [JSXAttributeItem::Attribute(attribute)] = element.attributes.as_slice() {
+        if let Some(JSXAttributeValue::StringLiteral(literal)) = &attribute.value {
+            if !literal.value.contains('\n')
+                && !p.has_comment(attribute.span, CommentFlags::empty())
+            {
+                let attribute = attribute.format(p);
+                let end = if element.self_closing { " />" } else { ">" };
+                return group![p, ss!("<"), name, ss!(" "), attribute, ss!(end)];
+            }
+        }
+    }
+
+    // Print the opening element expanded if any prop value is a string literal with a newline
+    let should_break = element.attributes.iter().any(|attribute| {
+        matches!(
+            attribute,
+            JSXAttributeItem::Attribute(attribute)
+                if matches!(&attribute.value, Some(JSXAttributeValue::StringLiteral(literal)) if literal.value.contains('\n'))
+        )
+    });
+
+    let mut attributes = p.vec();
+    for attribute in &element.attributes {
+        attributes.push(line!());
+        attributes.push(attribute.format(p));
+    }
+
+    let mut parts = p.vec();
+    parts.push(ss!("<"));
+    parts.push(name);
+    parts.push(Doc::Indent(attributes));
+
+    if element.self_closing {
+        parts.push(line!());
+        parts.push(ss!("/>"));
+    } else if should_print_bracket_same_line(p, element) {
+        parts.push(ss!(">"));
+    } else {
+        parts.push(softline!());
+        parts.push(ss!(">"));
+    }
+
+    Doc::Group(Group::new(parts).with_break(should_break))
+}
+
+fn should_print_bracket_same_line(p: &Prettier, element: &JSXOpeningElement) -> bool {
+    let last_attribute_has_trailing_comments = element
+        .attributes
+        .last()
+        .is_some_and(|attribute| p.has_comment(attribute.span(), CommentFlags::Trailing));
+    element.attributes.is_empty()
+        || (p.options.bracket_same_line && !last_attribute_has_trailing_comments)
+}
+
+pub(super) fn print_jsx_attribute<'a>(
+    p: &mut Prettier<'a>,
+    attribute: &JSXAttribute<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    parts.push(attribute.name.format(p));
+
+    if let Some(value) = &attribute.value {
+        let value = match value {
+            JSXAttributeValue::StringLiteral(literal) => print_jsx_attribute_string(p, literal),
+            _ => value.format(p),
+        };
+        parts.push(ss!("="));
+        parts.push(value);
+    }
+
+    Doc::Array(parts)
+}
+
+/// Print the string with the preferred quote, using the HTML entities for the other quotes.
+fn print_jsx_attribute_string<'a>(p: &Prettier<'a>, literal: &StringLiteral) -> Doc<'a> {
+    let raw = literal.span.source_text(p.source_text);
+    let text = raw[1..raw.len() - 1].replace("&apos;", "'").replace("&quot;", "\"");
+
+    let double_quotes = text.matches('"').count();
+    let single_quotes = text.matches('\'').count();
+    let (quote, escaped) = if p.options.jsx_single_quote {
+        if single_quotes > double_quotes {
+            ('"', "&quot;")
+        } else {
+            ('\'', "&apos;")
+        }
+    } else if double_quotes > single_quotes {
+        ('\'', "&apos;")
+    } else {
+        ('"', "&quot;")
+    };
+    let text = format!("{quote}{}{quote}", text.replace(quote, escaped));
+
+    let mut parts = p.vec();
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            parts.push(Doc::LiteralLine);
+        }
+        parts.push(p.str(line));
+    }
+    Doc::Array(parts)
+}
+
+/// `in_element` is true for the children of elements, and false for the values of attributes.
+pub(super) fn print_jsx_expression_container<'a>(
+    p: &mut Prettier<'a>,
+    container: &JSXExpressionContainer<'a>,
+    in_element: bool,
+) -> Doc<'a> {
+    let expression = match &container.expression {
+        JSXExpression::Expression(expr) => expr,
+        JSXExpression::EmptyExpression(_) => {
+            let has_line_comment =
+                p.has_comment(container.span, CommentFlags::Dangling | CommentFlags::Line);
+            let dangling = p.print_dangling_comments(container.span, false);
+            return match dangling {
+                Some(dangling) if has_line_comment => {
+                    array!(p, ss!("{"), dangling, hardline!(), ss!("}"))
+                }
+                Some(dangling) => array!(p, ss!("{"), dangling, ss!("}")),
+                None => ss!("{}"),
+            };
+        }
+    };
+
+    let should_inline = !p.has_comment(expression.span(), CommentFlags::empty())
+        && (matches!(
+            expression,
+            Expression::ArrayExpression(_)
+                | Expression::ObjectExpression(_)
+                | Expression::ArrowExpression(_)
+                | Expression::CallExpression(_)
+                | Expression::FunctionExpression(_)
+                | Expression::TemplateLiteral(_)
+                | Expression::TaggedTemplateExpression(_)
+        ) || (in_element
+            && matches!(
+                expression,
+                Expression::ConditionalExpression(_)
+                    | Expression::BinaryExpression(_)
+                    | Expression::LogicalExpression(_)
+            )));
+
+    let expression = expression.format(p);
+    if should_inline {
+        return group![p, ss!("{"), expression, Doc::LineSuffixBoundary, ss!("}")];
+    }
+    group![
+        p,
+        ss!("{"),
+        indent!(p, softline!(), expression),
+        softline!(),
+        Doc::LineSuffixBoundary,
+        ss!("}")
+    ]
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, PrettierOptions};
+
+    let tests = [
+        (
+            "const a = <div className='a'>hello {name}</div>;\n",
+            "const a = <div className=\"a\">hello {name}</div>;\n",
+        ),
+        ("const a = <Foo bar={1} {...props} />;\n", "const a = <Foo bar={1} {...props} />;\n"),
+        ("const a = <a.b.c x:y=\"1\" />;\n", "const a = <a.b.c x:y=\"1\" />;\n"),
+        ("const a = cond ? <div /> : null;\n", "const a = cond ? <div /> : null;\n"),
+        ("const a = <><A /><B /></>;\n", "const a = (\n  <>\n    <A />\n    <B />\n  </>\n);\n"),
+        (
+            "const a = <div>\n<span>aaaaaaaaaaaaaaaaaaaaaaaa</span>\n<span>bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb</span>\n</div>;\n",
+            "const a = (\n  <div>\n    <span>aaaaaaaaaaaaaaaaaaaaaaaa</span>\n    <span>bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb</span>\n  </div>\n);\n",
+        ),
+        ("const a = <div>{/* dangling */}</div>;\n", "const a = <div>{/* dangling */}</div>;\n"),
+    ];
+    Tester::new("test.jsx", PrettierOptions::default()).test(&tests);
+
+    let options = PrettierOptions { jsx_single_quote: true, ..PrettierOptions::default() };
+    Tester::new("test.jsx", options)
+        .test(&[("const a = <div className=\"a\" />;\n", "const a = <div className='a' />;\n")]);
+}
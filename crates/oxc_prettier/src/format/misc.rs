@@ -0,0 +1,21 @@
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+use crate::{array, doc::Doc, indent, line, ss, Prettier};
+
+pub(super) fn adjust_clause<'a>(
+    p: &Prettier<'a>,
+    node: &Statement<'a>,
+    clause: Doc<'a>,
+    force_space: bool,
+) -> Doc<'a> {
+    if matches!(node, Statement::EmptyStatement(_)) {
+        return ss!(";");
+    }
+
+    if matches!(node, Statement::BlockStatement(_)) || force_space {
+        return array![p, ss!(" "), clause];
+    }
+
+    indent![p, line!(), clause]
+}
@@ -18,15 +18,19 @@ mod call_expression;
 mod class;
 mod function;
 mod function_parameters;
+mod jsx;
+mod misc;
 mod module;
 mod object;
 mod statement;
 mod ternary;
+mod typescript;
 
 use crate::{
     array,
-    doc::{Doc, Separator},
-    format, group, hardline, indent, softline, ss, string, Prettier,
+    comments::CommentFlags,
+    doc::{Doc, Group, Separator},
+    format, group, hardline, indent, softline, ss, string, wrap, Prettier,
 };
 
 use self::{
@@ -34,6 +38,8 @@ use self::{
     binaryish::{BinaryishLeft, BinaryishOperator},
 };
 
+pub use self::statement::print_statement_sequence;
+
 pub trait Format<'a> {
     #[must_use]
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a>;
@@ -51,40 +57,46 @@ where
 impl<'a> Format<'a> for Program<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         block::print_block_body(p, &self.body, Some(&self.directives), false, true)
+            .or_else(|| {
+                let dangling = p.print_dangling_comments(self.span, false)?;
+                Some(array!(p, dangling, hardline!()))
+            })
             .unwrap_or(ss!(""))
     }
 }
 
 impl<'a> Format<'a> for Directive {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { Doc::Line })
     }
 }
 
 impl<'a> Format<'a> for Statement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        match self {
-            Self::BlockStatement(stmt) => stmt.format(p),
-            Self::BreakStatement(stmt) => stmt.format(p),
-            Self::ContinueStatement(stmt) => stmt.format(p),
-            Self::DebuggerStatement(stmt) => stmt.format(p),
-            Self::DoWhileStatement(stmt) => stmt.format(p),
-            Self::EmptyStatement(stmt) => stmt.format(p),
-            Self::ExpressionStatement(stmt) => stmt.format(p),
-            Self::ForInStatement(stmt) => stmt.format(p),
-            Self::ForOfStatement(stmt) => stmt.format(p),
-            Self::ForStatement(stmt) => stmt.format(p),
-            Self::IfStatement(stmt) => stmt.format(p),
-            Self::LabeledStatement(stmt) => stmt.format(p),
-            Self::ModuleDeclaration(decl) => decl.format(p),
-            Self::ReturnStatement(stmt) => stmt.format(p),
-            Self::SwitchStatement(stmt) => stmt.format(p),
-            Self::ThrowStatement(stmt) => stmt.format(p),
-            Self::TryStatement(stmt) => stmt.format(p),
-            Self::WhileStatement(stmt) => stmt.format(p),
-            Self::WithStatement(stmt) => stmt.format(p),
-            Self::Declaration(decl) => decl.format(p),
-        }
+        wrap!(p, self.span(), {
+            match self {
+                Self::BlockStatement(stmt) => stmt.format(p),
+                Self::BreakStatement(stmt) => stmt.format(p),
+                Self::ContinueStatement(stmt) => stmt.format(p),
+                Self::DebuggerStatement(stmt) => stmt.format(p),
+                Self::DoWhileStatement(stmt) => stmt.format(p),
+                Self::EmptyStatement(stmt) => stmt.format(p),
+                Self::ExpressionStatement(stmt) => stmt.format(p),
+                Self::ForInStatement(stmt) => stmt.format(p),
+                Self::ForOfStatement(stmt) => stmt.format(p),
+                Self::ForStatement(stmt) => stmt.format(p),
+                Self::IfStatement(stmt) => stmt.format(p),
+                Self::LabeledStatement(stmt) => stmt.format(p),
+                Self::ModuleDeclaration(decl) => decl.format(p),
+                Self::ReturnStatement(stmt) => stmt.format(p),
+                Self::SwitchStatement(stmt) => stmt.format(p),
+                Self::ThrowStatement(stmt) => stmt.format(p),
+                Self::TryStatement(stmt) => stmt.format(p),
+                Self::WhileStatement(stmt) => stmt.format(p),
+                Self::WithStatement(stmt) => stmt.format(p),
+                Self::Declaration(decl) => decl.format(p),
+            }
+        })
     }
 }
 
@@ -109,27 +121,50 @@ impl<'a> Format<'a> for IfStatement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         let mut parts = p.vec();
 
+        let consequent = format!(p, self.consequent);
+        let consequent = misc::adjust_clause(p, &self.consequent, consequent, false);
         let opening = group![
             p,
             ss!("if ("),
-            group!(p, indent!(p, softline!(), format!(p, self.test), softline!())),
+            group!(p, indent!(p, softline!(), format!(p, self.test)), softline!()),
             ss!(")"),
-            format!(p, self.consequent)
+            group!(p, consequent)
         ];
         parts.push(opening);
 
         if let Some(alternate) = &self.alternate {
+            let comment_on_own_line = p
+                .has_comment(self.consequent.span(), CommentFlags::Trailing | CommentFlags::Line)
+                || p.get_comments(self.span, CommentFlags::Dangling)
+                    .last()
+                    .is_some_and(|comment| comment.comment.is_line());
+            let else_on_same_line =
+                matches!(self.consequent, Statement::BlockStatement(_)) && !comment_on_own_line;
+            parts.push(if else_on_same_line { ss!(" ") } else { hardline!() });
+
+            if let Some(dangling) = p.print_dangling_comments(self.span, false) {
+                parts.push(dangling);
+                parts.push(if comment_on_own_line { hardline!() } else { ss!(" ") });
+            }
+
             parts.push(ss!("else"));
-            parts.push(group!(p, format!(p, alternate)));
+            let alternate_doc = format!(p, alternate);
+            let alternate_doc = misc::adjust_clause(
+                p,
+                alternate,
+                alternate_doc,
+                matches!(alternate, Statement::IfStatement(_)),
+            );
+            parts.push(group!(p, alternate_doc));
         }
 
-        Doc::Array(parts)
+        group!(p, Doc::Array(parts))
     }
 }
 
 impl<'a> Format<'a> for BlockStatement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        block::print_block(p, &self.body, None, false)
+        block::print_block(p, self.span, &self.body, None, false)
     }
 }
 
@@ -220,12 +255,16 @@ impl<'a> Format<'a> for SwitchStatement<'a> {
 
         let mut cases_parts = p.vec();
 
-        for case in &self.cases {
-            cases_parts.push(hardline!());
+        for (i, case) in self.cases.iter().enumerate() {
+            if i > 0 {
+                cases_parts.push(hardline!());
+            }
             cases_parts.push(format!(p, case));
         }
 
-        parts.push(indent!(p, hardline!(), group!(p, Doc::Array(cases_parts))));
+        if !self.cases.is_empty() {
+            parts.push(indent!(p, hardline!(), group!(p, Doc::Array(cases_parts))));
+        }
 
         parts.push(hardline!());
         parts.push(ss!("}"));
@@ -236,25 +275,40 @@ impl<'a> Format<'a> for SwitchStatement<'a> {
 
 impl<'a> Format<'a> for SwitchCase<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        let mut parts = p.vec();
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
 
-        if let Some(test) = &self.test {
-            parts.push(ss!("case "));
-            parts.push(format!(p, test));
-            parts.push(ss!(":"));
-        } else {
-            parts.push(ss!("default:"));
-        }
+            if let Some(test) = &self.test {
+                parts.push(ss!("case "));
+                parts.push(format!(p, test));
+                parts.push(ss!(":"));
+            } else {
+                parts.push(ss!("default:"));
+            }
 
-        let mut consequent_parts = p.vec();
-        for stmt in &self.consequent {
-            consequent_parts.push(hardline!());
-            consequent_parts.push(format!(p, stmt));
-        }
+            if let Some(dangling) = p.print_dangling_comments(self.span, false) {
+                parts.push(ss!(" "));
+                parts.push(dangling);
+            }
 
-        parts.push(indent!(p, hardline!(), group!(p, Doc::Array(consequent_parts))));
+            let consequent = self
+                .consequent
+                .iter()
+                .filter(|stmt| !matches!(stmt, Statement::EmptyStatement(_)))
+                .collect::<std::vec::Vec<_>>();
+            if !consequent.is_empty() {
+                let mut consequent_parts = p.vec();
+                for (i, stmt) in consequent.iter().enumerate() {
+                    if i > 0 {
+                        consequent_parts.push(hardline!());
+                    }
+                    consequent_parts.push(format!(p, stmt));
+                }
+                parts.push(indent!(p, hardline!(), group!(p, Doc::Array(consequent_parts))));
+            }
 
-        Doc::Array(parts)
+            Doc::Array(parts)
+        })
     }
 }
 
@@ -293,17 +347,19 @@ impl<'a> Format<'a> for TryStatement<'a> {
 
 impl<'a> Format<'a> for CatchClause<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        let mut parts = p.vec();
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
 
-        parts.push(ss!("catch "));
-        if let Some(param) = &self.param {
-            parts.push(ss!("("));
-            parts.push(format!(p, param));
-            parts.push(ss!(") "));
-        }
-        parts.push(format!(p, self.body));
+            parts.push(ss!("catch "));
+            if let Some(param) = &self.param {
+                parts.push(ss!("("));
+                parts.push(format!(p, param));
+                parts.push(ss!(") "));
+            }
+            parts.push(format!(p, self.body));
 
-        Doc::Array(parts)
+            Doc::Array(parts)
+        })
     }
 }
 
@@ -347,46 +403,64 @@ impl<'a> Format<'a> for DebuggerStatement {
 
 impl<'a> Format<'a> for ModuleDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        if let ModuleDeclaration::ImportDeclaration(decl) = self {
-            decl.format(p)
-        } else {
-            module::print_export_declaration(p, self)
-        }
+        wrap!(p, self.span(), {
+            if let ModuleDeclaration::ImportDeclaration(decl) = self {
+                decl.format(p)
+            } else {
+                module::print_export_declaration(p, self)
+            }
+        })
     }
 }
 
 impl<'a> Format<'a> for Declaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        match self {
-            Self::VariableDeclaration(stmt) => stmt.format(p),
-            Self::FunctionDeclaration(stmt) => stmt.format(p),
-            Self::ClassDeclaration(decl) => decl.format(p),
-            Self::UsingDeclaration(decl) => decl.format(p),
-            Self::TSTypeAliasDeclaration(decl) => decl.format(p),
-            Self::TSInterfaceDeclaration(decl) => decl.format(p),
-            Self::TSEnumDeclaration(decl) => decl.format(p),
-            Self::TSModuleDeclaration(decl) => decl.format(p),
-            Self::TSImportEqualsDeclaration(decl) => decl.format(p),
-        }
+        wrap!(p, self.span(), {
+            match self {
+                Self::VariableDeclaration(stmt) => stmt.format(p),
+                Self::FunctionDeclaration(stmt) => stmt.format(p),
+                Self::ClassDeclaration(decl) => decl.format(p),
+                Self::UsingDeclaration(decl) => decl.format(p),
+                Self::TSTypeAliasDeclaration(decl) => decl.format(p),
+                Self::TSInterfaceDeclaration(decl) => decl.format(p),
+                Self::TSEnumDeclaration(decl) => decl.format(p),
+                Self::TSModuleDeclaration(decl) => decl.format(p),
+                Self::TSImportEqualsDeclaration(decl) => decl.format(p),
+                Self::FlowOpaqueTypeDeclaration(decl) => decl.format(p),
+                Self::FlowDeclareModuleExports(decl) => decl.format(p),
+            }
+        })
     }
 }
 
 impl<'a> Format<'a> for VariableDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         let kind = self.kind.as_str();
-        let mut decls = p.vec();
-        decls.extend(self.declarations.iter().map(|decl| decl.format(p)));
+        // The declarators after the first one are indented, on their own lines if any of them has an initializer.
+        let has_init = self.declarations.iter().any(|decl| decl.init.is_some());
+        let mut rest = p.vec();
+        for decl in self.declarations.iter().skip(1) {
+            rest.push(ss!(","));
+            rest.push(if has_init { hardline!() } else { Doc::Line });
+            rest.push(decl.format(p));
+        }
 
         let mut parts = p.vec();
+        if self.modifiers.contains(ModifierKind::Declare) {
+            parts.push(ss!("declare "));
+        }
         parts.push(ss!(kind));
         parts.push(ss!(" "));
-        parts.push(Doc::Array(decls));
+        if let Some(first) = self.declarations.first() {
+            parts.push(first.format(p));
+        }
+        parts.push(Doc::Indent(rest));
 
         if p.options.semi {
             parts.push(ss!(";"));
         }
 
-        Doc::Group(parts)
+        Doc::Group(Group::new(parts))
     }
 }
 
@@ -398,79 +472,132 @@ impl<'a> Format<'a> for UsingDeclaration<'a> {
 
 impl<'a> Format<'a> for TSTypeAliasDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { typescript::print_type_alias(p, self) })
+    }
+}
+
+impl<'a> Format<'a> for FlowOpaqueTypeDeclaration<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, { typescript::print_opaque_type(p, self) })
+    }
+}
+
+impl<'a> Format<'a> for FlowDeclareModuleExports<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            parts.push(ss!("declare module.exports"));
+            parts.push(typescript::print_type_annotation(p, Some(&self.type_annotation)));
+            if p.options.semi {
+                parts.push(ss!(";"));
+            }
+            Doc::Array(parts)
+        })
     }
 }
 
 impl<'a> Format<'a> for TSInterfaceDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { typescript::print_interface(p, self) })
     }
 }
 
 impl<'a> Format<'a> for TSEnumDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { typescript::print_enum(p, self) })
     }
 }
 
 impl<'a> Format<'a> for TSModuleDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { typescript::print_module(p, self) })
     }
 }
 
 impl<'a> Format<'a> for TSImportEqualsDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            if self.is_export {
+                parts.push(ss!("export "));
+            }
+            parts.push(ss!("import "));
+            if self.import_kind.is_type() {
+                parts.push(ss!("type "));
+            }
+            parts.push(self.id.format(p));
+            parts.push(ss!(" = "));
+            parts.push(self.module_reference.format(p));
+            if p.options.semi {
+                parts.push(ss!(";"));
+            }
+            Doc::Array(parts)
+        })
     }
 }
 
 impl<'a> Format<'a> for TSTypeParameterDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        typescript::print_type_parameter_declaration(p, self)
     }
 }
 
 impl<'a> Format<'a> for TSTupleElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::TSType(ty) => ty.format(p),
+            Self::TSOptionalType(ty) => array![p, format!(p, ty.type_annotation), ss!("?")],
+            Self::TSRestType(ty) => array![p, ss!("..."), format!(p, ty.type_annotation)],
+            Self::TSNamedTupleMember(member) => member.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for VariableDeclarator<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        let mut parts = p.vec();
-        parts.push(self.id.format(p));
-        if let Some(init) = &self.init {
-            parts.push(ss!(" = "));
-            parts.push(init.format(p));
-        }
-        Doc::Array(parts)
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            parts.push(self.id.format(p));
+            if let Some(init) = &self.init {
+                parts.push(ss!(" = "));
+                parts.push(jsx::maybe_wrap_jsx_element_in_parens(p, init));
+            }
+            Doc::Array(parts)
+        })
     }
 }
 
 impl<'a> Format<'a> for Function<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        function::print_function(p, self)
+        wrap!(p, self.span, { function::print_function(p, self) })
     }
 }
 
 impl<'a> Format<'a> for FunctionBody<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        block::print_block(p, &self.statements, Some(&self.directives), false)
+        wrap!(p, self.span, {
+            block::print_block(p, self.span, &self.statements, Some(&self.directives), false)
+        })
     }
 }
 
 impl<'a> Format<'a> for FormalParameters<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        function_parameters::print_function_parameters(p, self)
+        wrap!(p, self.span, { function_parameters::print_function_parameters(p, self) })
     }
 }
 
 impl<'a> Format<'a> for FormalParameter<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        self.pattern.format(p)
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            parts.push(typescript::print_accessibility(self.accessibility));
+            if self.readonly {
+                parts.push(ss!("readonly "));
+            }
+            parts.push(self.pattern.format(p));
+            Doc::Array(parts)
+        })
     }
 }
 
@@ -478,7 +605,9 @@ impl<'a> Format<'a> for ImportDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         let mut parts = p.vec();
         parts.push(ss!("import"));
-        if self.import_kind.is_type() {
+        if self.import_kind.is_typeof() {
+            parts.push(ss!(" typeof"));
+        } else if self.import_kind.is_type() {
             parts.push(ss!(" type"));
         }
         if let Some(specifiers) = &self.specifiers {
@@ -546,31 +675,57 @@ impl<'a> Format<'a> for ImportAttribute {
 
 impl<'a> Format<'a> for ExportNamedDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        module::print_export_named_declaration(p, self)
     }
 }
 
 impl<'a> Format<'a> for TSExportAssignment<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        let mut parts = p.vec();
+        parts.push(ss!("export = "));
+        parts.push(self.expression.format(p));
+        if p.options.semi {
+            parts.push(ss!(";"));
+        }
+        Doc::Array(parts)
     }
 }
 
 impl<'a> Format<'a> for TSNamespaceExportDeclaration {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        let mut parts = p.vec();
+        parts.push(ss!("export as namespace "));
+        parts.push(self.id.format(p));
+        if p.options.semi {
+            parts.push(ss!(";"));
+        }
+        Doc::Array(parts)
     }
 }
 
 impl<'a> Format<'a> for ExportSpecifier {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            if self.export_kind.is_type() {
+                parts.push(ss!("type "));
+            }
+            parts.push(self.local.format(p));
+            if self.exported.span() != self.local.span() {
+                parts.push(ss!(" as "));
+                parts.push(self.exported.format(p));
+            }
+            Doc::Array(parts)
+        })
     }
 }
 
 impl<'a> Format<'a> for ModuleExportName {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::StringLiteral(literal) => literal.format(p),
+        }
     }
 }
 
@@ -599,72 +754,75 @@ impl<'a> Format<'a> for ExportDefaultDeclarationKind<'a> {
 
 impl<'a> Format<'a> for Expression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        match self {
-            Self::BooleanLiteral(lit) => lit.format(p),
-            Self::NullLiteral(lit) => lit.format(p),
-            Self::NumberLiteral(lit) => lit.format(p),
-            Self::BigintLiteral(lit) => lit.format(p),
-            Self::RegExpLiteral(lit) => lit.format(p),
-            Self::StringLiteral(lit) => lit.format(p),
-            Self::Identifier(ident) => ident.format(p),
-            Self::ThisExpression(expr) => expr.format(p),
-            Self::MemberExpression(expr) => expr.format(p),
-            Self::CallExpression(expr) => expr.format(p),
-            Self::ArrayExpression(expr) => expr.format(p),
-            Self::ObjectExpression(expr) => expr.format(p),
-            Self::FunctionExpression(expr) => expr.format(p),
-            Self::ArrowExpression(expr) => expr.format(p),
-            Self::YieldExpression(expr) => expr.format(p),
-            Self::UpdateExpression(expr) => expr.format(p),
-            Self::UnaryExpression(expr) => expr.format(p),
-            Self::BinaryExpression(expr) => expr.format(p),
-            Self::PrivateInExpression(expr) => expr.format(p),
-            Self::LogicalExpression(expr) => expr.format(p),
-            Self::ConditionalExpression(expr) => expr.format(p),
-            Self::AssignmentExpression(expr) => expr.format(p),
-            Self::SequenceExpression(expr) => expr.format(p),
-            Self::ParenthesizedExpression(expr) => expr.format(p),
-            Self::ImportExpression(expr) => expr.format(p),
-            Self::TemplateLiteral(literal) => literal.format(p),
-            Self::TaggedTemplateExpression(expr) => expr.format(p),
-            Self::Super(sup) => sup.format(p),
-            Self::AwaitExpression(expr) => expr.format(p),
-            Self::ChainExpression(expr) => expr.format(p),
-            Self::NewExpression(expr) => expr.format(p),
-            Self::MetaProperty(expr) => expr.format(p),
-            Self::ClassExpression(expr) => expr.format(p),
-            Self::JSXElement(el) => el.format(p),
-            Self::JSXFragment(fragment) => fragment.format(p),
-            Self::TSAsExpression(expr) => expr.expression.format(p),
-            Self::TSSatisfiesExpression(expr) => expr.expression.format(p),
-            Self::TSTypeAssertion(expr) => expr.expression.format(p),
-            Self::TSNonNullExpression(expr) => expr.expression.format(p),
-            Self::TSInstantiationExpression(expr) => expr.expression.format(p),
-        }
+        wrap!(p, self.span(), {
+            match self {
+                Self::BooleanLiteral(lit) => lit.format(p),
+                Self::NullLiteral(lit) => lit.format(p),
+                Self::NumberLiteral(lit) => lit.format(p),
+                Self::BigintLiteral(lit) => lit.format(p),
+                Self::RegExpLiteral(lit) => lit.format(p),
+                Self::StringLiteral(lit) => lit.format(p),
+                Self::Identifier(ident) => ident.format(p),
+                Self::ThisExpression(expr) => expr.format(p),
+                Self::MemberExpression(expr) => expr.format(p),
+                Self::CallExpression(expr) => expr.format(p),
+                Self::ArrayExpression(expr) => expr.format(p),
+                Self::ObjectExpression(expr) => expr.format(p),
+                Self::FunctionExpression(expr) => expr.format(p),
+                Self::ArrowExpression(expr) => expr.format(p),
+                Self::YieldExpression(expr) => expr.format(p),
+                Self::UpdateExpression(expr) => expr.format(p),
+                Self::UnaryExpression(expr) => expr.format(p),
+                Self::BinaryExpression(expr) => expr.format(p),
+                Self::PrivateInExpression(expr) => expr.format(p),
+                Self::LogicalExpression(expr) => expr.format(p),
+                Self::ConditionalExpression(expr) => expr.format(p),
+                Self::AssignmentExpression(expr) => expr.format(p),
+                Self::SequenceExpression(expr) => expr.format(p),
+                Self::ParenthesizedExpression(expr) => expr.format(p),
+                Self::ImportExpression(expr) => expr.format(p),
+                Self::TemplateLiteral(literal) => literal.format(p),
+                Self::TaggedTemplateExpression(expr) => expr.format(p),
+                Self::Super(sup) => sup.format(p),
+                Self::AwaitExpression(expr) => expr.format(p),
+                Self::ChainExpression(expr) => expr.format(p),
+                Self::NewExpression(expr) => expr.format(p),
+                Self::MetaProperty(expr) => expr.format(p),
+                Self::ClassExpression(expr) => expr.format(p),
+                Self::JSXElement(el) => el.format(p),
+                Self::JSXFragment(fragment) => fragment.format(p),
+                Self::TSAsExpression(expr) => expr.format(p),
+                Self::TSSatisfiesExpression(expr) => expr.format(p),
+                Self::TSTypeAssertion(expr) => expr.format(p),
+                Self::TSNonNullExpression(expr) => expr.format(p),
+                Self::TSInstantiationExpression(expr) => expr.format(p),
+                Self::FlowTypeCastExpression(expr) => expr.format(p),
+            }
+        })
     }
 }
 
 impl<'a> Format<'a> for IdentifierReference {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        p.str(self.name.as_str())
+        wrap!(p, self.span, { p.str(self.name.as_str()) })
     }
 }
 
 impl<'a> Format<'a> for IdentifierName {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        p.str(self.name.as_str())
+        wrap!(p, self.span, { p.str(self.name.as_str()) })
     }
 }
 
 impl<'a> Format<'a> for BindingIdentifier {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        p.str(self.name.as_str())
+        wrap!(p, self.span, { p.str(self.name.as_str()) })
     }
 }
 
 impl<'a> Format<'a> for LabelIdentifier {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        p.str(self.name.as_str())
+        wrap!(p, self.span, { p.str(self.name.as_str()) })
     }
 }
 
@@ -784,6 +942,7 @@ impl<'a> Format<'a> for CallExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         call_expression::print_call_expression(
             p,
+            self.span,
             &self.callee,
             &self.arguments,
             self.optional,
@@ -814,7 +973,7 @@ impl<'a> Format<'a> for ArrayExpressionElement<'a> {
 
 impl<'a> Format<'a> for SpreadElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        array![p, ss!("..."), format!(p, self.argument)]
+        wrap!(p, self.span, { array![p, ss!("..."), format!(p, self.argument)] })
     }
 }
 
@@ -826,7 +985,7 @@ impl<'a> Format<'a> for ArrayExpression<'a> {
 
 impl<'a> Format<'a> for ObjectExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        object::print_object_properties(p, &self.properties)
+        object::print_object_properties(p, self.span, &self.properties)
     }
 }
 
@@ -841,12 +1000,15 @@ impl<'a> Format<'a> for ObjectPropertyKind<'a> {
 
 impl<'a> Format<'a> for ObjectProperty<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        // Perf: Use same print function with BindingProperty
-        if self.shorthand {
-            self.key.format(p)
-        } else {
-            group!(p, format!(p, self.key), ss!(": "), format!(p, self.value))
-        }
+        wrap!(p, self.span, {
+            // Perf: Use same print function with BindingProperty
+            if self.shorthand {
+                self.key.format(p)
+            } else {
+                let value = jsx::maybe_wrap_jsx_element_in_parens(p, &self.value);
+                group!(p, format!(p, self.key), ss!(": "), value)
+            }
+        })
     }
 }
 
@@ -956,7 +1118,7 @@ impl<'a> Format<'a> for AssignmentExpression<'a> {
             ss!(" "),
             string!(p, self.operator.as_str()),
             ss!(" "),
-            format!(p, self.right)
+            jsx::maybe_wrap_jsx_element_in_parens(p, &self.right)
         ]
     }
 }
@@ -975,10 +1137,10 @@ impl<'a> Format<'a> for SimpleAssignmentTarget<'a> {
         match self {
             Self::AssignmentTargetIdentifier(ident) => ident.format(p),
             Self::MemberAssignmentTarget(member_expr) => member_expr.format(p),
-            Self::TSAsExpression(expr) => expr.expression.format(p),
-            Self::TSSatisfiesExpression(expr) => expr.expression.format(p),
-            Self::TSNonNullExpression(expr) => expr.expression.format(p),
-            Self::TSTypeAssertion(expr) => expr.expression.format(p),
+            Self::TSAsExpression(expr) => expr.format(p),
+            Self::TSSatisfiesExpression(expr) => expr.format(p),
+            Self::TSNonNullExpression(expr) => expr.format(p),
+            Self::TSTypeAssertion(expr) => expr.format(p),
         }
     }
 }
@@ -1106,6 +1268,7 @@ impl<'a> Format<'a> for NewExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         call_expression::print_call_expression(
             p,
+            self.span,
             &self.callee,
             &self.arguments,
             false,
@@ -1123,15 +1286,13 @@ impl<'a> Format<'a> for MetaProperty {
 
 impl<'a> Format<'a> for Class<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        class::print_class(p, self)
+        wrap!(p, self.span, { class::print_class(p, self) })
     }
 }
 
 impl<'a> Format<'a> for ClassBody<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        let mut parts = p.vec();
-        parts.push(ss!("{}"));
-        Doc::Array(parts)
+        wrap!(p, self.span, { class::print_class_body(p, self) })
     }
 }
 
@@ -1144,158 +1305,192 @@ impl<'a> Format<'a> for ClassElement<'a> {
             ClassElement::AccessorProperty(c) => c.format(p),
             ClassElement::TSAbstractMethodDefinition(c) => c.format(p),
             ClassElement::TSAbstractPropertyDefinition(c) => c.format(p),
-            ClassElement::TSIndexSignature(c) => c.format(p),
+            ClassElement::TSIndexSignature(c) => {
+                let signature = c.format(p);
+                if p.options.semi {
+                    array![p, signature, ss!(";")]
+                } else {
+                    signature
+                }
+            }
         }
     }
 }
 
 impl<'a> Format<'a> for JSXIdentifier {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { p.str(self.name.as_str()) })
     }
 }
 
 impl<'a> Format<'a> for JSXMemberExpressionObject<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::MemberExpression(expr) => expr.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXMemberExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        array![p, format!(p, self.object), ss!("."), format!(p, self.property)]
     }
 }
 
 impl<'a> Format<'a> for JSXElementName<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::NamespacedName(name) => name.format(p),
+            Self::MemberExpression(expr) => expr.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXNamespacedName {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        array![p, format!(p, self.namespace), ss!(":"), format!(p, self.property)]
     }
 }
 
 impl<'a> Format<'a> for JSXAttributeName<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::NamespacedName(name) => name.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXAttribute<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { jsx::print_jsx_attribute(p, self) })
     }
 }
 
 impl<'a> Format<'a> for JSXEmptyExpression {
-    fn format(&self, _: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        ss!("")
     }
 }
 
 impl<'a> Format<'a> for JSXExpression<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::Expression(expr) => expr.format(p),
+            Self::EmptyExpression(expr) => expr.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXExpressionContainer<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { jsx::print_jsx_expression_container(p, self, false) })
     }
 }
 
 impl<'a> Format<'a> for JSXAttributeValue<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::StringLiteral(literal) => literal.format(p),
+            Self::ExpressionContainer(container) => container.format(p),
+            Self::Element(element) => element.format(p),
+            Self::Fragment(fragment) => fragment.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXSpreadAttribute<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { array![p, ss!("{..."), format!(p, self.argument), ss!("}")] })
     }
 }
 
 impl<'a> Format<'a> for JSXAttributeItem<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::Attribute(attribute) => attribute.format(p),
+            Self::SpreadAttribute(attribute) => attribute.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXOpeningElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        jsx::print_jsx_opening_element(p, self)
     }
 }
 
 impl<'a> Format<'a> for JSXClosingElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        array![p, ss!("</"), format!(p, self.name), ss!(">")]
     }
 }
 
 impl<'a> Format<'a> for JSXElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        jsx::print_jsx_element(p, self)
     }
 }
 
 impl<'a> Format<'a> for JSXOpeningFragment {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        ss!("<>")
     }
 }
 
 impl<'a> Format<'a> for JSXClosingFragment {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        ss!("</>")
     }
 }
 
 impl<'a> Format<'a> for JSXText {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        p.str(self.span.source_text(p.source_text))
     }
 }
 
 impl<'a> Format<'a> for JSXSpreadChild<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { array![p, ss!("{..."), format!(p, self.expression), ss!("}")] })
     }
 }
 
 impl<'a> Format<'a> for JSXChild<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        match self {
+            Self::Text(text) => text.format(p),
+            Self::Element(element) => element.format(p),
+            Self::Fragment(fragment) => fragment.format(p),
+            Self::ExpressionContainer(container) => container.format(p),
+            Self::Spread(spread) => spread.format(p),
+        }
     }
 }
 
 impl<'a> Format<'a> for JSXFragment<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        jsx::print_jsx_fragment(p, self)
     }
 }
 
 impl<'a> Format<'a> for StaticBlock<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        block::print_block(p, &self.body, None, false)
+        wrap!(p, self.span, { block::print_block(p, self.span, &self.body, None, false) })
     }
 }
 
 impl<'a> Format<'a> for MethodDefinition<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { class::print_method(p, self, false) })
     }
 }
 
 impl<'a> Format<'a> for PropertyDefinition<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { class::print_property(p, self, false) })
     }
 }
 
@@ -1307,57 +1502,69 @@ impl<'a> Format<'a> for AccessorProperty<'a> {
 
 impl<'a> Format<'a> for PrivateIdentifier {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        let mut parts = p.vec();
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
 
-        parts.push(ss!("#"));
-        parts.push(p.str(self.name.as_str()));
+            parts.push(ss!("#"));
+            parts.push(p.str(self.name.as_str()));
 
-        Doc::Array(parts)
+            Doc::Array(parts)
+        })
     }
 }
 
 impl<'a> Format<'a> for BindingPattern<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        match self.kind {
+        let mut parts = p.vec();
+        parts.push(match self.kind {
             BindingPatternKind::BindingIdentifier(ref ident) => ident.format(p),
             BindingPatternKind::ObjectPattern(ref pattern) => pattern.format(p),
             BindingPatternKind::ArrayPattern(ref pattern) => pattern.format(p),
             BindingPatternKind::AssignmentPattern(ref pattern) => pattern.format(p),
+        });
+        if self.optional {
+            parts.push(ss!("?"));
         }
+        parts.push(typescript::print_type_annotation(p, self.type_annotation.as_deref()));
+        Doc::Array(parts)
     }
 }
 
 impl<'a> Format<'a> for ObjectPattern<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        object::print_object_properties(p, &self.properties)
+        wrap!(p, self.span, { object::print_object_properties(p, self.span, &self.properties) })
     }
 }
 
 impl<'a> Format<'a> for BindingProperty<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        if self.shorthand {
-            self.key.format(p)
-        } else {
-            group!(p, format!(p, self.key), ss!(": "), format!(p, self.value))
-        }
+        wrap!(p, self.span, {
+            if self.shorthand {
+                self.key.format(p)
+            } else {
+                group!(p, format!(p, self.key), ss!(": "), format!(p, self.value))
+            }
+        })
     }
 }
 
 impl<'a> Format<'a> for RestElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        array!(p, ss!("..."), format!(p, self.argument))
+        wrap!(p, self.span, { array!(p, ss!("..."), format!(p, self.argument)) })
     }
 }
 
 impl<'a> Format<'a> for ArrayPattern<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, { Doc::Line })
     }
 }
 
 impl<'a> Format<'a> for AssignmentPattern<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        array![p, format!(p, self.left), ss!(" = "), format!(p, self.right)]
+        wrap!(p, self.span, {
+            array![p, format!(p, self.left), ss!(" = "), format!(p, self.right)]
+        })
     }
 }
 
@@ -1395,18 +1602,603 @@ impl<'a> Format<'a> for RegExpFlags {
 
 impl<'a> Format<'a> for TSAbstractMethodDefinition<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.method_definition.span, {
+            class::print_method(p, &self.method_definition, true)
+        })
     }
 }
 
 impl<'a> Format<'a> for TSAbstractPropertyDefinition<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.property_definition.span, {
+            class::print_property(p, &self.property_definition, true)
+        })
     }
 }
 
 impl<'a> Format<'a> for TSIndexSignature<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        Doc::Line
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            parts.push(ss!("["));
+            for (i, param) in self.parameters.iter().enumerate() {
+                if i > 0 {
+                    parts.push(ss!(", "));
+                }
+                parts.push(p.str(param.name.as_str()));
+                parts.push(ss!(": "));
+                parts.push(param.type_annotation.format(p));
+            }
+            parts.push(ss!("]: "));
+            parts.push(self.type_annotation.format(p));
+            Doc::Array(parts)
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSTypeAnnotation<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, { self.type_annotation.format(p) })
+    }
+}
+
+impl<'a> Format<'a> for TSType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span(), {
+            match self {
+                Self::TSAnyKeyword(_) => ss!("any"),
+                Self::TSBigIntKeyword(_) => ss!("bigint"),
+                Self::TSBooleanKeyword(_) => ss!("boolean"),
+                Self::TSNeverKeyword(_) => ss!("never"),
+                Self::TSNullKeyword(_) => ss!("null"),
+                Self::TSNumberKeyword(_) => ss!("number"),
+                Self::TSObjectKeyword(_) => ss!("object"),
+                Self::TSStringKeyword(_) => ss!("string"),
+                Self::TSSymbolKeyword(_) => ss!("symbol"),
+                Self::TSThisKeyword(_) => ss!("this"),
+                Self::TSUndefinedKeyword(_) => ss!("undefined"),
+                Self::TSUnknownKeyword(_) => ss!("unknown"),
+                Self::TSVoidKeyword(_) => ss!("void"),
+                Self::TSArrayType(ty) => ty.format(p),
+                Self::TSConditionalType(ty) => ty.format(p),
+                Self::TSConstructorType(ty) => ty.format(p),
+                Self::TSFunctionType(ty) => ty.format(p),
+                Self::TSImportType(ty) => ty.format(p),
+                Self::TSIndexedAccessType(ty) => ty.format(p),
+                Self::TSInferType(ty) => ty.format(p),
+                Self::TSIntersectionType(ty) => ty.format(p),
+                Self::TSLiteralType(ty) => ty.format(p),
+                Self::TSMappedType(ty) => ty.format(p),
+                Self::TSQualifiedName(ty) => ty.format(p),
+                Self::TSTemplateLiteralType(ty) => ty.format(p),
+                Self::TSTupleType(ty) => ty.format(p),
+                Self::TSTypeLiteral(ty) => ty.format(p),
+                Self::TSTypeOperatorType(ty) => ty.format(p),
+                Self::TSTypePredicate(ty) => ty.format(p),
+                Self::TSTypeQuery(ty) => ty.format(p),
+                Self::TSTypeReference(ty) => ty.format(p),
+                Self::TSUnionType(ty) => ty.format(p),
+                Self::JSDocNullableType(ty) => {
+                    let type_annotation = ty.type_annotation.format(p);
+                    if ty.postfix {
+                        array![p, type_annotation, ss!("?")]
+                    } else {
+                        array![p, ss!("?"), type_annotation]
+                    }
+                }
+                Self::JSDocUnknownType(_) => ss!("?"),
+                Self::FlowNullableType(ty) => ty.format(p),
+                Self::FlowExactObjectType(ty) => ty.format(p),
+                Self::FlowInexactObjectType(ty) => ty.format(p),
+                Self::FlowExistsType(ty) => ty.format(p),
+            }
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSArrayType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let element_type =
+            typescript::print_type_with_parens(p, &self.element_type, typescript::is_loose_type);
+        array![p, element_type, ss!("[]")]
+    }
+}
+
+impl<'a> Format<'a> for TSConditionalType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        typescript::print_conditional_type(p, self)
+    }
+}
+
+impl<'a> Format<'a> for TSConstructorType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        if self.r#abstract {
+            parts.push(ss!("abstract "));
+        }
+        parts.push(ss!("new "));
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        parts.push(group!(p, self.params.format(p)));
+        parts.push(ss!(" => "));
+        parts.push(self.return_type.format(p));
+        Doc::Group(Group::new(parts))
+    }
+}
+
+impl<'a> Format<'a> for TSFunctionType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        parts.push(group!(p, self.params.format(p)));
+        parts.push(ss!(" => "));
+        parts.push(self.return_type.format(p));
+        Doc::Group(Group::new(parts))
+    }
+}
+
+impl<'a> Format<'a> for TSImportType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        if self.is_type_of {
+            parts.push(ss!("typeof "));
+        }
+        parts.push(ss!("import("));
+        parts.push(self.parameter.format(p));
+        parts.push(ss!(")"));
+        if let Some(qualifier) = &self.qualifier {
+            parts.push(ss!("."));
+            parts.push(qualifier.format(p));
+        }
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSIndexedAccessType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let object_type =
+            typescript::print_type_with_parens(p, &self.object_type, typescript::is_loose_type);
+        array![p, object_type, ss!("["), format!(p, self.index_type), ss!("]")]
+    }
+}
+
+impl<'a> Format<'a> for TSInferType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array![p, ss!("infer "), format!(p, self.type_parameter)]
+    }
+}
+
+impl<'a> Format<'a> for TSIntersectionType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        typescript::print_intersection_type(p, self)
+    }
+}
+
+impl<'a> Format<'a> for TSLiteralType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match &self.literal {
+            TSLiteral::BooleanLiteral(literal) => literal.format(p),
+            TSLiteral::NullLiteral(literal) => literal.format(p),
+            TSLiteral::NumberLiteral(literal) => literal.format(p),
+            TSLiteral::BigintLiteral(literal) => literal.format(p),
+            TSLiteral::RegExpLiteral(literal) => literal.format(p),
+            TSLiteral::StringLiteral(literal) => literal.format(p),
+            TSLiteral::TemplateLiteral(literal) => literal.format(p),
+            TSLiteral::UnaryExpression(expr) => expr.format(p),
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSMappedType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        typescript::print_mapped_type(p, self)
+    }
+}
+
+impl<'a> Format<'a> for TSQualifiedName<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array![p, format!(p, self.left), ss!("."), format!(p, self.right)]
+    }
+}
+
+impl<'a> Format<'a> for TSTemplateLiteralType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        parts.push(ss!("`"));
+        for (i, quasi) in self.quasis.iter().enumerate() {
+            parts.push(p.str(quasi.value.raw.as_str()));
+            if let Some(ty) = self.types.get(i) {
+                parts.push(ss!("${"));
+                parts.push(ty.format(p));
+                parts.push(ss!("}"));
+            }
+        }
+        parts.push(ss!("`"));
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSTupleType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array::print_array(p, &Array::TSTupleType(self))
+    }
+}
+
+impl<'a> Format<'a> for TSNamedTupleMember<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        // The parser does not keep the rest marker of a named member, e.g. `...rest: T[]`
+        if p.source_text[self.span.start as usize..].starts_with("...") {
+            parts.push(ss!("..."));
+        }
+        parts.push(self.label.format(p));
+        if self.optional {
+            parts.push(ss!("?"));
+        }
+        parts.push(ss!(": "));
+        parts.push(self.element_type.format(p));
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSTypeLiteral<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let separator = if p.options.semi { ";" } else { "" };
+        typescript::print_object_type(p, self.span, &self.members, separator, true, false)
+    }
+}
+
+impl<'a> Format<'a> for FlowNullableType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let type_annotation =
+            typescript::print_type_with_parens(p, &self.type_annotation, typescript::is_loose_type);
+        array![p, ss!("?"), type_annotation]
+    }
+}
+
+impl<'a> Format<'a> for FlowExactObjectType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        typescript::print_object_type_with_braces(
+            p,
+            self.span,
+            &self.members,
+            ",",
+            true,
+            false,
+            ("{|", "|}"),
+            false,
+        )
+    }
+}
+
+impl<'a> Format<'a> for FlowInexactObjectType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        typescript::print_object_type_with_braces(
+            p,
+            self.span,
+            &self.members,
+            ",",
+            true,
+            false,
+            ("{", "}"),
+            true,
+        )
+    }
+}
+
+impl<'a> Format<'a> for FlowObjectTypeSpread<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, { array![p, ss!("..."), self.argument.format(p)] })
+    }
+}
+
+impl<'a> Format<'a> for FlowVariance {
+    fn format(&self, _p: &mut Prettier<'a>) -> Doc<'a> {
+        match self.kind {
+            FlowVarianceKind::Plus => ss!("+"),
+            FlowVarianceKind::Minus => ss!("-"),
+        }
+    }
+}
+
+impl<'a> Format<'a> for FlowExistsType {
+    fn format(&self, _p: &mut Prettier<'a>) -> Doc<'a> {
+        ss!("*")
+    }
+}
+
+impl<'a> Format<'a> for TSTypeOperatorType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let operator = match self.operator {
+            TSTypeOperator::Keyof => "keyof ",
+            TSTypeOperator::Unique => "unique ",
+            TSTypeOperator::Readonly => "readonly ",
+        };
+        let type_annotation = typescript::print_type_with_parens(p, &self.type_annotation, |ty| {
+            typescript::is_loose_type(ty) && !matches!(ty, TSType::TSTypeOperatorType(_))
+        });
+        array![p, ss!(operator), type_annotation]
+    }
+}
+
+impl<'a> Format<'a> for TSTypePredicate<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        if self.asserts {
+            parts.push(ss!("asserts "));
+        }
+        parts.push(match &self.parameter_name {
+            TSTypePredicateName::Identifier(ident) => ident.format(p),
+            TSTypePredicateName::This(_) => ss!("this"),
+        });
+        if let Some(type_annotation) = &self.type_annotation {
+            parts.push(ss!(" is "));
+            parts.push(type_annotation.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSTypeQuery<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        parts.push(ss!("typeof "));
+        parts.push(self.expr_name.format(p));
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSTypeReference<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        parts.push(self.type_name.format(p));
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSUnionType<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        typescript::print_union_type(p, self)
+    }
+}
+
+impl<'a> Format<'a> for TSTypeName<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match self {
+            Self::IdentifierReference(ident) => ident.format(p),
+            Self::QualifiedName(name) => name.format(p),
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSTypeParameterInstantiation<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        typescript::print_type_parameter_instantiation(p, self)
+    }
+}
+
+impl<'a> Format<'a> for TSTypeParameter<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        if self.r#in {
+            parts.push(ss!("in "));
+        }
+        if self.out {
+            parts.push(ss!("out "));
+        }
+        if self.r#const {
+            parts.push(ss!("const "));
+        }
+        parts.push(self.name.format(p));
+        if let Some(constraint) = &self.constraint {
+            parts.push(ss!(" extends "));
+            parts.push(constraint.format(p));
+        }
+        if let Some(default) = &self.default {
+            parts.push(ss!(" = "));
+            parts.push(default.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSSignature<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match self {
+            Self::TSIndexSignature(signature) => signature.format(p),
+            Self::TSPropertySignature(signature) => signature.format(p),
+            Self::TSCallSignatureDeclaration(signature) => signature.format(p),
+            Self::TSConstructSignatureDeclaration(signature) => signature.format(p),
+            Self::TSMethodSignature(signature) => signature.format(p),
+            Self::FlowObjectTypeSpread(spread) => spread.format(p),
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSPropertySignature<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            if let Some(variance) = self.variance {
+                parts.push(variance.format(p));
+            }
+            if self.readonly {
+                parts.push(ss!("readonly "));
+            }
+            parts.push(class::print_property_key(p, &self.key, self.computed));
+            if self.optional {
+                parts.push(ss!("?"));
+            }
+            parts.push(typescript::print_type_annotation(p, self.type_annotation.as_deref()));
+            Doc::Array(parts)
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSCallSignatureDeclaration<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, {
+            typescript::print_signature(
+                p,
+                self.type_parameters.as_deref(),
+                &self.params,
+                self.return_type.as_deref(),
+            )
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSConstructSignatureDeclaration<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, {
+            let signature = typescript::print_signature(
+                p,
+                self.type_parameters.as_deref(),
+                &self.params,
+                self.return_type.as_deref(),
+            );
+            array![p, ss!("new "), signature]
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSMethodSignature<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            match self.kind {
+                TSMethodSignatureKind::Method => {}
+                TSMethodSignatureKind::Get => parts.push(ss!("get ")),
+                TSMethodSignatureKind::Set => parts.push(ss!("set ")),
+            }
+            parts.push(class::print_property_key(p, &self.key, self.computed));
+            if self.optional {
+                parts.push(ss!("?"));
+            }
+            parts.push(typescript::print_signature(
+                p,
+                self.type_parameters.as_deref(),
+                &self.params,
+                self.return_type.as_deref(),
+            ));
+            Doc::Array(parts)
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSInterfaceHeritage<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        parts.push(self.expression.format(p));
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSClassImplements<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        parts.push(self.expression.format(p));
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSEnumMember<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self.span, {
+            let mut parts = p.vec();
+            parts.push(match &self.id {
+                TSEnumMemberName::Identifier(ident) => ident.format(p),
+                TSEnumMemberName::StringLiteral(literal) => literal.format(p),
+                TSEnumMemberName::ComputedPropertyName(expr) => {
+                    array![p, ss!("["), format!(p, expr), ss!("]")]
+                }
+                TSEnumMemberName::NumberLiteral(literal) => literal.format(p),
+            });
+            if let Some(initializer) = &self.initializer {
+                parts.push(ss!(" = "));
+                parts.push(initializer.format(p));
+            }
+            Doc::Array(parts)
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSModuleDeclarationName {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match self {
+            Self::Identifier(ident) => ident.format(p),
+            Self::StringLiteral(literal) => literal.format(p),
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSModuleReference<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match self {
+            Self::TypeName(name) => name.format(p),
+            Self::ExternalModuleReference(reference) => {
+                array![p, ss!("require("), format!(p, reference.expression), ss!(")")]
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSAsExpression<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array![p, format!(p, self.expression), ss!(" as "), format!(p, self.type_annotation)]
+    }
+}
+
+impl<'a> Format<'a> for FlowTypeCastExpression<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array![
+            p,
+            ss!("("),
+            format!(p, self.expression),
+            typescript::print_type_annotation(p, Some(&self.type_annotation)),
+            ss!(")")
+        ]
+    }
+}
+
+impl<'a> Format<'a> for TSSatisfiesExpression<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array![p, format!(p, self.expression), ss!(" satisfies "), format!(p, self.type_annotation)]
+    }
+}
+
+impl<'a> Format<'a> for TSTypeAssertion<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array![p, ss!("<"), format!(p, self.type_annotation), ss!(">"), format!(p, self.expression)]
+    }
+}
+
+impl<'a> Format<'a> for TSNonNullExpression<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array![p, format!(p, self.expression), ss!("!")]
+    }
+}
+
+impl<'a> Format<'a> for TSInstantiationExpression<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        array![p, format!(p, self.expression), format!(p, self.type_parameters)]
     }
 }
@@ -1,7 +1,10 @@
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
 
-use crate::{doc::Doc, ss, Format, Prettier};
+use crate::{
+    array, comments::CommentFlags, doc::Doc, group, if_break, line, softline, ss, Format, Prettier,
+    TrailingComma,
+};
 
 pub(super) fn print_export_declaration<'a>(
     p: &mut Prettier<'a>,
@@ -32,6 +35,64 @@ pub(super) fn print_export_declaration<'a>(
     Doc::Array(parts)
 }
 
+/// `export { a, b as c } from "d"` or `export const a = 1`, after the `export` keyword.
+pub(super) fn print_export_named_declaration<'a>(
+    p: &mut Prettier<'a>,
+    decl: &ExportNamedDeclaration<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    parts.push(ss!(" "));
+    if let Some(declaration) = &decl.declaration {
+        parts.push(declaration.format(p));
+        return Doc::Array(parts);
+    }
+
+    if decl.export_kind.is_type() {
+        parts.push(ss!("type "));
+    }
+    parts.push(print_module_specifiers(p, &decl.specifiers));
+    if let Some(source) = &decl.source {
+        parts.push(ss!(" from "));
+        parts.push(source.format(p));
+    }
+    Doc::Array(parts)
+}
+
+fn print_module_specifiers<'a>(
+    p: &mut Prettier<'a>,
+    specifiers: &oxc_allocator::Vec<'a, ExportSpecifier>,
+) -> Doc<'a> {
+    if specifiers.is_empty() {
+        return ss!("{}");
+    }
+
+    // A single specifier without comments is never broken.
+    let can_break = specifiers.len() > 1
+        || specifiers.iter().any(|specifier| p.has_comment(specifier.span, CommentFlags::empty()));
+    if !can_break {
+        let bracket_space = if p.options.bracket_spacing { " " } else { "" };
+        let specifier = specifiers[0].format(p);
+        return array!(p, ss!("{"), ss!(bracket_space), specifier, ss!(bracket_space), ss!("}"));
+    }
+
+    let mut indent_parts = p.vec();
+    indent_parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
+    for (i, specifier) in specifiers.iter().enumerate() {
+        if i != 0 {
+            indent_parts.push(ss!(","));
+            indent_parts.push(line!());
+        }
+        indent_parts.push(specifier.format(p));
+    }
+    let trailing_comma = if matches!(p.options.trailing_comma, TrailingComma::None) {
+        ss!("")
+    } else {
+        if_break!(p, ",")
+    };
+    let closing_line = if p.options.bracket_spacing { line!() } else { softline!() };
+    group!(p, ss!("{"), Doc::Indent(indent_parts), trailing_comma, closing_line, ss!("}"))
+}
+
 fn print_semicolon_after_export_declaration<'a>(
     p: &Prettier<'a>,
     decl: &ModuleDeclaration<'a>,
@@ -40,7 +101,13 @@ fn print_semicolon_after_export_declaration<'a>(
         return None;
     }
 
-    let ModuleDeclaration::ExportDefaultDeclaration(decl) = decl else { return None };
+    let decl = match decl {
+        ModuleDeclaration::ExportDefaultDeclaration(decl) => decl,
+        ModuleDeclaration::ExportNamedDeclaration(decl) if decl.declaration.is_none() => {
+            return Some(ss!(";"));
+        }
+        _ => return None,
+    };
 
     match decl.declaration {
         ExportDefaultDeclarationKind::Expression(_) => Some(ss!(";")),
@@ -50,3 +117,43 @@ fn print_semicolon_after_export_declaration<'a>(
         | ExportDefaultDeclarationKind::TSEnumDeclaration(_) => None,
     }
 }
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, PrettierOptions};
+
+    let tests = [
+        ("export const a = 1, b = 2;\n", "export const a = 1,\n  b = 2;\n"),
+        ("export function f() {}\n", "export function f() {}\n"),
+        ("export {};\n", "export {};\n"),
+        ("export {  a  } from 'x';\n", "export { a } from \"x\";\n"),
+        ("export { a, b as c, d as 'e' };\n", "export { a, b as c, d as \"e\" };\n"),
+        (
+            "export { aaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccccccccccc } from 'x';\n",
+            "export {\n  aaaaaaaaaaaaaaaaaaaa,\n  bbbbbbbbbbbbbbbbbbbbbbbbbbbb,\n  cccccccccccccccccccccccccccccc,\n} from \"x\";\n",
+        ),
+    ];
+    Tester::new("test.js", PrettierOptions::default()).test(&tests);
+
+    let tests = [
+        ("export type { A, B };\n", "export type { A, B };\n"),
+        ("export { type A, b };\n", "export { type A, b };\n"),
+        ("export interface A { a: string }\n", "export interface A {\n  a: string;\n}\n"),
+    ];
+    Tester::new("test.ts", PrettierOptions::default()).test(&tests);
+
+    let options = PrettierOptions {
+        semi: false,
+        bracket_spacing: false,
+        trailing_comma: TrailingComma::None,
+        ..PrettierOptions::default()
+    };
+    let tests = [
+        ("export { a, b };\n", "export {a, b}\n"),
+        (
+            "export { aaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccccccccccc };\n",
+            "export {\n  aaaaaaaaaaaaaaaaaaaa,\n  bbbbbbbbbbbbbbbbbbbbbbbbbbbb,\n  cccccccccccccccccccccccccccccc\n}\n",
+        ),
+    ];
+    Tester::new("test.js", options).test(&tests);
+}
@@ -1,13 +1,25 @@
 use oxc_allocator::Vec;
+use oxc_span::Span;
 
-use crate::{doc::Doc, group, if_break, ss, Prettier};
+use crate::{
+    doc::{Doc, Group},
+    group, if_break, softline, ss, Prettier,
+};
 
 use super::Format;
 
 pub(super) fn print_object_properties<'a, F: Format<'a>>(
     p: &mut Prettier<'a>,
+    span: Span,
     properties: &Vec<'a, F>,
 ) -> Doc<'a> {
+    if properties.is_empty() {
+        return p.print_dangling_comments(span, true).map_or_else(
+            || ss!("{}"),
+            |dangling| group![p, ss!("{"), dangling, softline!(), ss!("}")],
+        );
+    }
+
     let mut parts = p.vec();
     parts.push(ss!("{"));
 
@@ -39,5 +51,5 @@ pub(super) fn print_object_properties<'a, F: Format<'a>>(
 
     parts.push(ss!("}"));
 
-    Doc::Group(parts)
+    Doc::Group(Group::new(parts))
 }
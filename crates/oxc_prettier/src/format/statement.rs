@@ -1,14 +1,17 @@
 use oxc_allocator::Vec;
 
-use crate::{doc::Doc, hardline, Prettier};
+use crate::{
+    doc::{Doc, Group},
+    hardline, Prettier,
+};
 use oxc_span::GetSpan;
 
 use super::Format;
 use crate::util::is_next_line_empty;
 
-pub(super) fn print_statement_sequence<'a, F: Format<'a> + GetSpan>(
+pub fn print_statement_sequence<'a, F: Format<'a> + GetSpan>(
     p: &mut Prettier<'a>,
-    stmts: &Vec<'a, F>,
+    stmts: &[F],
     remove_last_statement_hardline: bool,
 ) -> Vec<'a, Doc<'a>> {
     let mut parts = p.vec();
@@ -18,7 +21,7 @@ pub(super) fn print_statement_sequence<'a, F: Format<'a> + GetSpan>(
 
         if remove_last_statement_hardline && i == stmts.len() - 1 {
             match docs {
-                Doc::Array(ref mut docs) | Doc::Group(ref mut docs) => {
+                Doc::Array(ref mut docs) | Doc::Group(Group { contents: ref mut docs, .. }) => {
                     if matches!(docs.last(), Some(Doc::Hardline)) {
                         docs.pop();
                     }
@@ -0,0 +1,692 @@
+//! Printing of TypeScript types and declarations
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/print/typescript.js>
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/print/type-annotation.js>
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/print/type-parameters.js>
+//! * <https://github.com/prettier/prettier/blob/main/src/language-js/print/interface.js>
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    array,
+    comments::CommentFlags,
+    doc::{Align, AlignKind, Doc, Group, IfBreak},
+    group, if_break, indent, line, softline, ss,
+    util::{has_newline_in_range, is_next_line_empty},
+    Prettier, TrailingComma,
+};
+
+use super::{block, Format};
+
+/// Print a type, wrapped in parentheses if it is ambiguous in the position.
+///
+/// e.g. `(A | B)[]`, `keyof (A & B)` or `(() => void) | null`
+pub(super) fn print_type_with_parens<'a>(
+    p: &mut Prettier<'a>,
+    ty: &TSType<'a>,
+    needs_parens: fn(&TSType) -> bool,
+) -> Doc<'a> {
+    let doc = ty.format(p);
+    if needs_parens(ty) {
+        array!(p, ss!("("), doc, ss!(")"))
+    } else {
+        doc
+    }
+}
+
+/// Types which need parentheses as the element of an array or an operand of `keyof`.
+pub(super) fn is_loose_type(ty: &TSType) -> bool {
+    matches!(
+        ty,
+        TSType::TSUnionType(_)
+            | TSType::TSIntersectionType(_)
+            | TSType::TSFunctionType(_)
+            | TSType::TSConstructorType(_)
+            | TSType::TSConditionalType(_)
+            | TSType::TSTypeOperatorType(_)
+            | TSType::TSInferType(_)
+    )
+}
+
+/// Types which need parentheses as a member of a union or an intersection.
+pub(super) fn is_function_like_type(ty: &TSType) -> bool {
+    matches!(
+        ty,
+        TSType::TSFunctionType(_) | TSType::TSConstructorType(_) | TSType::TSConditionalType(_)
+    )
+}
+
+fn is_object_type(ty: &TSType) -> bool {
+    matches!(ty, TSType::TSTypeLiteral(_) | TSType::TSMappedType(_))
+}
+
+fn is_simple_type(ty: &TSType) -> bool {
+    match ty {
+        TSType::TSAnyKeyword(_)
+        | TSType::TSBigIntKeyword(_)
+        | TSType::TSBooleanKeyword(_)
+        | TSType::TSNeverKeyword(_)
+        | TSType::TSNullKeyword(_)
+        | TSType::TSNumberKeyword(_)
+        | TSType::TSObjectKeyword(_)
+        | TSType::TSStringKeyword(_)
+        | TSType::TSSymbolKeyword(_)
+        | TSType::TSThisKeyword(_)
+        | TSType::TSUndefinedKeyword(_)
+        | TSType::TSUnknownKeyword(_)
+        | TSType::TSVoidKeyword(_) => true,
+        TSType::TSTypeReference(reference) => reference.type_parameters.is_none(),
+        _ => false,
+    }
+}
+
+/// Types which are printed inline, e.g. in `Foo<{ a: string }>`.
+fn should_hug_type(p: &Prettier, ty: &TSType) -> bool {
+    if is_simple_type(ty) || is_object_type(ty) {
+        return true;
+    }
+    matches!(ty, TSType::TSUnionType(union) if should_hug_union_type(p, union))
+}
+
+/// An object type with only `null` or `void`, e.g. `{ a: string } | null`
+fn should_hug_union_type(p: &Prettier, union: &TSUnionType) -> bool {
+    let void_count = union
+        .types
+        .iter()
+        .filter(|ty| matches!(ty, TSType::TSVoidKeyword(_) | TSType::TSNullKeyword(_)))
+        .count();
+    let has_object = union.types.iter().any(is_object_type);
+    let has_comments = union.types.iter().any(|ty| p.has_comment(ty.span(), CommentFlags::empty()));
+    union.types.len() - 1 == void_count && has_object && !has_comments
+}
+
+pub(super) fn print_union_type<'a>(p: &mut Prettier<'a>, union: &TSUnionType<'a>) -> Doc<'a> {
+    // {
+    //   a: string
+    // } | null | void
+    // should be inlined and not be printed in the multi-line variant
+    let should_hug = should_hug_union_type(p, union);
+
+    let mut printed = std::vec::Vec::with_capacity(union.types.len());
+    for ty in &union.types {
+        let doc = print_type_with_parens(p, ty, is_function_like_type);
+        printed.push(if should_hug { doc } else { align_2(p, doc) });
+    }
+
+    let mut parts = p.vec();
+    if should_hug {
+        for (i, doc) in printed.into_iter().enumerate() {
+            if i > 0 {
+                parts.push(ss!(" | "));
+            }
+            parts.push(doc);
+        }
+        return Doc::Array(parts);
+    }
+
+    // single-line variation
+    // A | B | C
+    // multi-line variation
+    // | A
+    // | B
+    // | C
+    parts.push(Doc::IfBreak(IfBreak {
+        break_contents: p.alloc(array!(p, line!(), ss!("| "))),
+        flat_contents: p.alloc(ss!("")),
+        group_id: None,
+    }));
+    for (i, doc) in printed.into_iter().enumerate() {
+        if i > 0 {
+            parts.push(line!());
+            parts.push(ss!("| "));
+        }
+        parts.push(doc);
+    }
+    group!(p, Doc::Indent(parts))
+}
+
+pub(super) fn print_intersection_type<'a>(
+    p: &mut Prettier<'a>,
+    intersection: &TSIntersectionType<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    let mut was_indented = false;
+    for (i, ty) in intersection.types.iter().enumerate() {
+        let doc = print_type_with_parens(p, ty, |ty| {
+            matches!(ty, TSType::TSUnionType(_)) || is_function_like_type(ty)
+        });
+        if i == 0 {
+            parts.push(doc);
+        } else if !is_object_type(&intersection.types[i - 1]) && !is_object_type(ty) {
+            // If no object is involved, go to the next line if it breaks
+            parts.push(indent!(p, ss!(" &"), line!(), doc));
+        } else if is_object_type(&intersection.types[i - 1]) && is_object_type(ty) {
+            // If both are objects, don't indent
+            parts.push(ss!(" & "));
+            parts.push(if was_indented { indent!(p, doc) } else { doc });
+        } else {
+            // If you go from object to non-object or vis-versa, then inline it
+            if i > 1 {
+                was_indented = true;
+            }
+            parts.push(ss!(" & "));
+            parts.push(if i > 1 { indent!(p, doc) } else { doc });
+        }
+    }
+    Doc::Group(Group::new(parts))
+}
+
+pub(super) fn print_conditional_type<'a>(
+    p: &mut Prettier<'a>,
+    ty: &TSConditionalType<'a>,
+) -> Doc<'a> {
+    let should_break = matches!(ty.true_type, TSType::TSConditionalType(_))
+        || matches!(ty.false_type, TSType::TSConditionalType(_));
+    let mut parts = p.vec();
+    parts.extend(print_conditional_type_parts(p, ty));
+    let test = parts.remove(0);
+    let mut contents = p.vec();
+    contents.push(test);
+    contents.push(Doc::Indent(parts));
+    Doc::Group(Group::new(contents).with_break(should_break))
+}
+
+/// The test and the branches of the conditional type, with the conditional types
+/// in the false branch flattened into a chain.
+fn print_conditional_type_parts<'a>(
+    p: &mut Prettier<'a>,
+    ty: &TSConditionalType<'a>,
+) -> Vec<'a, Doc<'a>> {
+    let mut parts = p.vec();
+    let check_type = print_type_with_parens(p, &ty.check_type, is_function_like_type);
+    let extends_type = print_type_with_parens(p, &ty.extends_type, |ty| {
+        matches!(ty, TSType::TSConditionalType(_))
+    });
+    parts.push(array!(p, check_type, ss!(" extends "), extends_type));
+    parts.push(line!());
+    parts.push(ss!("? "));
+    let true_type = ty.true_type.format(p);
+    parts.push(align_2(p, true_type));
+    parts.push(line!());
+    parts.push(ss!(": "));
+    if let TSType::TSConditionalType(false_type) = &ty.false_type {
+        parts.extend(print_conditional_type_parts(p, false_type));
+    } else {
+        let false_type = ty.false_type.format(p);
+        parts.push(align_2(p, false_type));
+    }
+    parts
+}
+
+fn align_2<'a>(p: &Prettier<'a>, doc: Doc<'a>) -> Doc<'a> {
+    let mut contents = p.vec();
+    contents.push(doc);
+    Doc::Align(Align { kind: AlignKind::Spaces(2), contents })
+}
+
+pub(super) fn print_mapped_type<'a>(p: &mut Prettier<'a>, ty: &TSMappedType<'a>) -> Doc<'a> {
+    let should_break = has_newline_in_range(p.source_text, ty.span.start, ty.span.end);
+
+    let mut parts = p.vec();
+    parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
+    if let Some(readonly) = print_mapped_type_modifier(&ty.readonly, "readonly") {
+        parts.push(readonly);
+        parts.push(ss!(" "));
+    }
+    parts.push(ss!("["));
+    parts.push(ty.type_parameter.name.format(p));
+    if let Some(constraint) = &ty.type_parameter.constraint {
+        parts.push(ss!(" in "));
+        parts.push(constraint.format(p));
+    }
+    if let Some(name_type) = &ty.name_type {
+        parts.push(ss!(" as "));
+        parts.push(name_type.format(p));
+    }
+    parts.push(ss!("]"));
+    if let Some(optional) = print_mapped_type_modifier(&ty.optional, "?") {
+        parts.push(optional);
+    }
+    if let Some(type_annotation) = &ty.type_annotation {
+        parts.push(ss!(": "));
+        parts.push(type_annotation.format(p));
+    }
+    if p.options.semi {
+        parts.push(if_break!(p, ";"));
+    }
+
+    let mut contents = p.vec();
+    contents.push(ss!("{"));
+    contents.push(Doc::Indent(parts));
+    if let Some(dangling) = p.print_dangling_comments(ty.span, true) {
+        contents.push(dangling);
+    }
+    contents.push(if p.options.bracket_spacing { line!() } else { softline!() });
+    contents.push(ss!("}"));
+    Doc::Group(Group::new(contents).with_break(should_break))
+}
+
+fn print_mapped_type_modifier<'a>(
+    operator: &TSMappedTypeModifierOperator,
+    keyword: &'static str,
+) -> Option<Doc<'a>> {
+    match operator {
+        TSMappedTypeModifierOperator::True => Some(ss!(keyword)),
+        TSMappedTypeModifierOperator::Plus => {
+            Some(ss!(if keyword == "?" { "+?" } else { "+readonly" }))
+        }
+        TSMappedTypeModifierOperator::Minus => {
+            Some(ss!(if keyword == "?" { "-?" } else { "-readonly" }))
+        }
+        TSMappedTypeModifierOperator::None => None,
+    }
+}
+
+pub(super) fn print_type_parameter_instantiation<'a>(
+    p: &mut Prettier<'a>,
+    instantiation: &TSTypeParameterInstantiation<'a>,
+) -> Doc<'a> {
+    let params = &instantiation.params;
+    let should_inline = params.is_empty() || (params.len() == 1 && should_hug_type(p, &params[0]));
+    if should_inline {
+        let mut parts = p.vec();
+        parts.push(ss!("<"));
+        for (i, param) in params.iter().enumerate() {
+            if i > 0 {
+                parts.push(ss!(", "));
+            }
+            parts.push(param.format(p));
+        }
+        parts.push(ss!(">"));
+        return Doc::Array(parts);
+    }
+
+    let mut docs = std::vec::Vec::with_capacity(params.len());
+    for param in params {
+        docs.push(param.format(p));
+    }
+    print_type_parameters(p, docs, /* trailing_comma */ false)
+}
+
+pub(super) fn print_type_parameter_declaration<'a>(
+    p: &mut Prettier<'a>,
+    declaration: &TSTypeParameterDeclaration<'a>,
+) -> Doc<'a> {
+    let mut docs = std::vec::Vec::with_capacity(declaration.params.len());
+    for param in &declaration.params {
+        docs.push(param.format(p));
+    }
+    let trailing_comma = matches!(p.options.trailing_comma, TrailingComma::All);
+    print_type_parameters(p, docs, trailing_comma)
+}
+
+/// The type parameters of an arrow function.
+/// A single `<T,>` keeps its trailing comma in .tsx files, where `<T>` would start a JSX element.
+pub(super) fn print_arrow_type_parameters<'a>(
+    p: &mut Prettier<'a>,
+    declaration: &TSTypeParameterDeclaration<'a>,
+) -> Doc<'a> {
+    let params = &declaration.params;
+    if p.source_type.is_typescript()
+        && p.source_type.is_jsx()
+        && params.len() == 1
+        && params[0].constraint.is_none()
+    {
+        return array!(p, ss!("<"), params[0].format(p), ss!(",>"));
+    }
+    declaration.format(p)
+}
+
+fn print_type_parameters<'a>(
+    p: &Prettier<'a>,
+    docs: std::vec::Vec<Doc<'a>>,
+    trailing_comma: bool,
+) -> Doc<'a> {
+    let mut params = p.vec();
+    params.push(softline!());
+    for (i, doc) in docs.into_iter().enumerate() {
+        if i > 0 {
+            params.push(ss!(","));
+            params.push(line!());
+        }
+        params.push(doc);
+    }
+    let trailing_comma = if trailing_comma { if_break!(p, ",") } else { ss!("") };
+    group![p, ss!("<"), Doc::Indent(params), trailing_comma, softline!(), ss!(">")]
+}
+
+/// Print the members of a type literal, an interface or an enum like the properties of an object.
+pub(super) fn print_object_type<'a, F: Format<'a> + GetSpan>(
+    p: &mut Prettier<'a>,
+    span: Span,
+    members: &Vec<'a, F>,
+    separator: &'static str,
+    trailing_separator: bool,
+    force_break: bool,
+) -> Doc<'a> {
+    print_object_type_with_braces(
+        p,
+        span,
+        members,
+        separator,
+        trailing_separator,
+        force_break,
+        ("{", "}"),
+        false,
+    )
+}
+
+/// [print_object_type] with other braces, e.g. `{|` and `|}` for Flow exact object types,
+/// and with `...` after the members of a Flow inexact object type
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+pub(super) fn print_object_type_with_braces<'a, F: Format<'a> + GetSpan>(
+    p: &mut Prettier<'a>,
+    span: Span,
+    members: &Vec<'a, F>,
+    separator: &'static str,
+    trailing_separator: bool,
+    force_break: bool,
+    (open, close): (&'static str, &'static str),
+    inexact: bool,
+) -> Doc<'a> {
+    if members.is_empty() && inexact {
+        return array!(p, ss!(open), ss!("..."), ss!(close));
+    }
+    if members.is_empty() {
+        return p.print_dangling_comments(span, true).map_or_else(
+            || array!(p, ss!(open), ss!(close)),
+            |dangling| array!(p, ss!(open), dangling, softline!(), ss!(close)),
+        );
+    }
+
+    let should_break =
+        force_break || has_newline_in_range(p.source_text, span.start, members[0].span().start);
+
+    let mut parts = p.vec();
+    parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
+    for (i, member) in members.iter().enumerate() {
+        parts.push(group!(p, member.format(p)));
+        if i < members.len() - 1 {
+            parts.push(ss!(separator));
+            parts.push(line!());
+            if is_next_line_empty(p.source_text, member.span()) {
+                parts.push(softline!());
+            }
+        }
+    }
+    if inexact {
+        parts.push(ss!(separator));
+        parts.push(line!());
+        parts.push(ss!("..."));
+    } else if trailing_separator {
+        parts.push(if_break!(p, separator));
+    }
+
+    let mut contents = p.vec();
+    contents.push(ss!(open));
+    contents.push(Doc::Indent(parts));
+    contents.push(if p.options.bracket_spacing { line!() } else { softline!() });
+    contents.push(ss!(close));
+    Doc::Group(Group::new(contents).with_break(should_break))
+}
+
+/// `opaque type A: Super = T`
+pub(super) fn print_opaque_type<'a>(
+    p: &mut Prettier<'a>,
+    decl: &FlowOpaqueTypeDeclaration<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    if decl.modifiers.contains(ModifierKind::Declare) {
+        parts.push(ss!("declare "));
+    }
+    parts.push(ss!("opaque type "));
+    parts.push(decl.id.format(p));
+    if let Some(type_parameters) = &decl.type_parameters {
+        parts.push(type_parameters.format(p));
+    }
+    if let Some(supertype) = &decl.supertype {
+        parts.push(ss!(": "));
+        parts.push(supertype.format(p));
+    }
+    if let Some(type_annotation) = &decl.type_annotation {
+        parts.push(ss!(" = "));
+        parts.push(type_annotation.format(p));
+    }
+    if p.options.semi {
+        parts.push(ss!(";"));
+    }
+    Doc::Group(Group::new(parts))
+}
+
+pub(super) fn print_interface<'a>(
+    p: &mut Prettier<'a>,
+    decl: &TSInterfaceDeclaration<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    if decl.modifiers.contains(ModifierKind::Declare) {
+        parts.push(ss!("declare "));
+    }
+    parts.push(ss!("interface"));
+
+    let mut parts_group = p.vec();
+    parts_group.push(ss!(" "));
+    parts_group.push(decl.id.format(p));
+    if let Some(type_parameters) = &decl.type_parameters {
+        parts_group.push(type_parameters.format(p));
+    }
+
+    match &decl.extends {
+        Some(extends) if !extends.is_empty() => {
+            let mut heritages = p.vec();
+            for (i, heritage) in extends.iter().enumerate() {
+                if i > 0 {
+                    heritages.push(ss!(","));
+                    heritages.push(line!());
+                }
+                heritages.push(heritage.format(p));
+            }
+            let heritages =
+                if extends.len() == 1 { Doc::Array(heritages) } else { Doc::Indent(heritages) };
+            let extends_parts = array!(p, line!(), ss!("extends "), heritages);
+
+            if decl.type_parameters.is_some() {
+                parts.push(group!(p, Doc::Array(parts_group), indent!(p, extends_parts)));
+            } else {
+                parts_group.push(extends_parts);
+                parts.push(group!(p, Doc::Indent(parts_group)));
+            }
+        }
+        _ => parts.push(Doc::Array(parts_group)),
+    }
+
+    parts.push(ss!(" "));
+    let separator = if p.options.semi { ";" } else { "" };
+    parts.push(print_object_type(p, decl.body.span, &decl.body.body, separator, true, true));
+    Doc::Group(Group::new(parts))
+}
+
+pub(super) fn print_enum<'a>(p: &mut Prettier<'a>, decl: &TSEnumDeclaration<'a>) -> Doc<'a> {
+    let mut parts = p.vec();
+    if decl.modifiers.contains(ModifierKind::Declare) {
+        parts.push(ss!("declare "));
+    }
+    if decl.modifiers.contains(ModifierKind::Const) {
+        parts.push(ss!("const "));
+    }
+    parts.push(ss!("enum "));
+    parts.push(decl.id.format(p));
+    parts.push(ss!(" "));
+    let trailing_comma = !matches!(p.options.trailing_comma, TrailingComma::None);
+    parts.push(print_object_type(p, decl.body.span, &decl.body.members, ",", trailing_comma, true));
+    Doc::Array(parts)
+}
+
+pub(super) fn print_module<'a>(p: &mut Prettier<'a>, decl: &TSModuleDeclaration<'a>) -> Doc<'a> {
+    let mut parts = p.vec();
+    if decl.modifiers.contains(ModifierKind::Declare) {
+        parts.push(ss!("declare "));
+    }
+
+    // The keyword is not kept in the AST, and there is none in `declare global {}`
+    let id_start = decl.id.span().start as usize;
+    match p.source_text[decl.span.start as usize..id_start].split_whitespace().last() {
+        Some("namespace") => parts.push(ss!("namespace ")),
+        Some("module") => parts.push(ss!("module ")),
+        _ => {}
+    }
+    parts.push(decl.id.format(p));
+
+    let mut body = &decl.body;
+    while let TSModuleDeclarationBody::TSModuleDeclaration(nested) = body {
+        parts.push(ss!("."));
+        parts.push(nested.id.format(p));
+        body = &nested.body;
+    }
+
+    if let TSModuleDeclarationBody::TSModuleBlock(block) = body {
+        parts.push(ss!(" "));
+        parts.push(block::print_block(p, block.span, &block.body, None, false));
+    }
+    Doc::Array(parts)
+}
+
+pub(super) fn print_type_alias<'a>(
+    p: &mut Prettier<'a>,
+    decl: &TSTypeAliasDeclaration<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    if decl.modifiers.contains(ModifierKind::Declare) {
+        parts.push(ss!("declare "));
+    }
+    parts.push(ss!("type "));
+    parts.push(decl.id.format(p));
+    if let Some(type_parameters) = &decl.type_parameters {
+        parts.push(type_parameters.format(p));
+    }
+    match &decl.type_annotation {
+        // The union type prints its own line break after `=`
+        TSType::TSUnionType(union) if !should_hug_union_type(p, union) => {
+            parts.push(ss!(" ="));
+            parts.push(group!(p, indent!(p, line!())));
+            parts.push(print_union_type(p, union));
+        }
+        type_annotation => {
+            parts.push(ss!(" = "));
+            parts.push(type_annotation.format(p));
+        }
+    }
+    if p.options.semi {
+        parts.push(ss!(";"));
+    }
+    Doc::Group(Group::new(parts))
+}
+
+/// `public`, `private` or `protected`, followed by a space.
+pub(super) fn print_accessibility<'a>(accessibility: Option<TSAccessibility>) -> Doc<'a> {
+    match accessibility {
+        Some(TSAccessibility::Public) => ss!("public "),
+        Some(TSAccessibility::Private) => ss!("private "),
+        Some(TSAccessibility::Protected) => ss!("protected "),
+        None => ss!(""),
+    }
+}
+
+/// `: T`, e.g. for a variable or a parameter.
+pub(super) fn print_type_annotation<'a>(
+    p: &mut Prettier<'a>,
+    type_annotation: Option<&TSTypeAnnotation<'a>>,
+) -> Doc<'a> {
+    type_annotation
+        .map_or_else(|| ss!(""), |type_annotation| array!(p, ss!(": "), type_annotation.format(p)))
+}
+
+/// Flow `%checks` after the return type of a function, or in its place
+pub(super) fn print_flow_predicate<'a>(
+    p: &mut Prettier<'a>,
+    predicate: &FlowPredicate<'a>,
+    has_return_type: bool,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    parts.push(ss!(if has_return_type { " %checks" } else { ": %checks" }));
+    if let Some(value) = &predicate.value {
+        parts.push(ss!("("));
+        parts.push(value.format(p));
+        parts.push(ss!(")"));
+    }
+    Doc::Array(parts)
+}
+
+/// The type parameters, the parameters and the return type of a function-like node.
+pub(super) fn print_signature<'a>(
+    p: &mut Prettier<'a>,
+    type_parameters: Option<&TSTypeParameterDeclaration<'a>>,
+    params: &FormalParameters<'a>,
+    return_type: Option<&TSTypeAnnotation<'a>>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    if let Some(type_parameters) = type_parameters {
+        parts.push(type_parameters.format(p));
+    }
+    parts.push(group!(p, params.format(p)));
+    parts.push(print_type_annotation(p, return_type));
+    Doc::Array(parts)
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, PrettierOptions};
+
+    let tests = [
+        ("type A = string | number;\n", "type A = string | number;\n"),
+        ("type A<T> = { a: T; b?: string };\n", "type A<T> = { a: T; b?: string };\n"),
+        (
+            "type A = T extends string ? 'a' : 'b';\n",
+            "type A = T extends string ? \"a\" : \"b\";\n",
+        ),
+        (
+            "type M = { readonly [K in keyof T]?: T[K] };\n",
+            "type M = { readonly [K in keyof T]?: T[K] };\n",
+        ),
+        (
+            "type F = (a: string) => void;\ntype C = new () => A;\n",
+            "type F = (a: string) => void;\ntype C = new () => A;\n",
+        ),
+        (
+            "let a: typeof b;\nlet c: keyof T;\nlet d: T[number];\n",
+            "let a: typeof b;\nlet c: keyof T;\nlet d: T[number];\n",
+        ),
+        (
+            "let a: Array<string> = [], b: [number, string?] = [1];\n",
+            "let a: Array<string> = [],\n  b: [number, string?] = [1];\n",
+        ),
+        ("let a = b as unknown as string;\n", "let a = b as unknown as string;\n"),
+        ("let a = b!;\nlet c = <T>d;\n", "let a = b!;\nlet c = <T>d;\n"),
+        (
+            "interface A extends B { a(): void; readonly b: number }\n",
+            "interface A extends B {\n  a(): void;\n  readonly b: number;\n}\n",
+        ),
+        ("enum E { A = 1, B }\n", "enum E {\n  A = 1,\n  B,\n}\n"),
+        (
+            "// c1\nconst a = 1;\nenum E { A } // c2\nlet b;\n",
+            "// c1\nconst a = 1;\nenum E {\n  A,\n} // c2\nlet b;\n",
+        ),
+        (
+            "declare module 'x' { export const a: number; }\n",
+            "declare module \"x\" {\n  export const a: number;\n}\n",
+        ),
+        ("namespace N { const a = 1; }\n", "namespace N {\n  const a = 1;\n}\n"),
+        (
+            "function f<T extends object = {}>(a: T): asserts a is T {}\n",
+            "function f<T extends object = {}>(a: T): asserts a is T {}\n",
+        ),
+        (
+            "class A<T> implements B { private a: T; constructor(public b: string) {} }\n",
+            "class A<T> implements B {\n  private a: T;\n  constructor(public b: string) {}\n}\n",
+        ),
+    ];
+    Tester::new("test.ts", PrettierOptions::default()).test(&tests);
+}
@@ -2,26 +2,32 @@
 //!
 //! A port of <https://github.com/prettier/prettier>
 
-mod comment;
+mod comments;
 mod doc;
 mod format;
 mod macros;
 mod options;
 mod printer;
+mod range;
+#[cfg(test)]
+mod tester;
 mod util;
 
-use std::{
-    iter::{Peekable, Rev},
-    vec,
-};
+use std::collections::BTreeMap;
 
 use doc::Doc;
 use oxc_allocator::Allocator;
-use oxc_ast::{ast::Program, CommentKind, Trivias};
+use oxc_ast::{ast::Program, Trivias};
+use oxc_span::{GetSpan, SourceType, Span};
 
 pub use crate::doc::DocPrinter;
 pub use crate::options::{ArrowParens, PrettierOptions, QuoteProps, TrailingComma};
-use crate::{format::Format, printer::Printer};
+pub use crate::range::TextEdit;
+use crate::{
+    comments::{attach_comments, AttachedComment},
+    format::{print_statement_sequence, Format},
+    printer::Printer,
+};
 
 pub struct Prettier<'a> {
     allocator: &'a Allocator,
@@ -30,8 +36,16 @@ pub struct Prettier<'a> {
 
     options: PrettierOptions,
 
-    /// A stack of comments that will be carefully placed in the right places.
-    trivias: Peekable<Rev<vec::IntoIter<(u32, u32, CommentKind)>>>,
+    /// The source type of the formatted program, e.g. `<T,>` is kept in .tsx files.
+    source_type: SourceType,
+
+    trivias: Trivias,
+
+    /// The comments attached to the nodes, keyed by the span of the nodes.
+    comments: BTreeMap<Span, Vec<AttachedComment>>,
+
+    /// The last id created by [Prettier::next_id].
+    group_id_counter: u32,
 }
 
 impl<'a> Prettier<'a> {
@@ -41,16 +55,61 @@ impl<'a> Prettier<'a> {
         trivias: Trivias,
         options: PrettierOptions,
     ) -> Self {
-        let trivias = trivias.into_iter().rev().peekable();
-        Self { allocator, source_text, options, trivias }
+        Self {
+            allocator,
+            source_text,
+            options,
+            source_type: SourceType::default(),
+            trivias,
+            comments: BTreeMap::new(),
+            group_id_counter: 0,
+        }
     }
 
     pub fn build(mut self, program: &Program<'a>) -> String {
-        let doc = program.format(&mut self);
+        let doc = self.doc(program);
         Printer::new(doc, self.source_text, self.options).build()
     }
 
-    pub fn doc(mut self, program: &Program<'a>) -> Doc<'a> {
-        program.format(&mut self)
+    /// Format the program, returning the edits to the source text instead of the whole text.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn build_edits(self, program: &Program<'a>) -> Vec<TextEdit> {
+        let source_text = self.source_text;
+        let formatted = self.build(program);
+        let span = Span::new(0, source_text.len() as u32);
+        TextEdit::diff(source_text, span, &formatted).into_iter().collect()
+    }
+
+    /// Format the statements within `range` of the source text,
+    /// like Prettier's `rangeStart` and `rangeEnd` options.
+    ///
+    /// The range is expanded to the enclosing statements, and only the edits to them are returned.
+    /// Nothing is formatted if the range doesn't contain a statement.
+    pub fn build_range(mut self, program: &Program<'a>, range: Span) -> Vec<TextEdit> {
+        let range = range::trim_range(self.source_text, range);
+        let Some(stmts) = range::find_statements(program, range) else {
+            return vec![];
+        };
+        let span = Span::new(stmts[0].span().start, stmts[stmts.len() - 1].span().end);
+
+        // The comments outside of the statements are not a part of the edits.
+        self.source_type = program.source_type;
+        self.comments = attach_comments(self.source_text, program, &self.trivias);
+        for comments in self.comments.values_mut() {
+            comments.retain(|attached| {
+                span.start <= attached.comment.span.start && attached.comment.span.end <= span.end
+            });
+        }
+
+        let doc = Doc::Array(print_statement_sequence(&mut self, stmts, false));
+        let doc = range::align_to_line_indentation(&self, span.start, doc);
+        let formatted = Printer::new(doc, self.source_text, self.options).build();
+        TextEdit::diff(self.source_text, span, formatted.trim_end()).into_iter().collect()
+    }
+
+    pub fn doc(&mut self, program: &Program<'a>) -> Doc<'a> {
+        self.source_type = program.source_type;
+        self.comments = attach_comments(self.source_text, program, &self.trivias);
+        program.format(self)
     }
 }
@@ -56,6 +56,19 @@ macro_rules! hardline {
     };
 }
 
+#[macro_export]
+macro_rules! line_suffix {
+    ($p:ident, $( $x:expr ),* ) => {
+        {
+            let mut temp_vec = $p.vec();
+            $(
+                temp_vec.push($x);
+            )*
+            Doc::LineSuffix(temp_vec)
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! array {
     ($p:ident, $( $x:expr ),* ) => {
@@ -77,14 +90,67 @@ macro_rules! group {
             $(
                 temp_vec.push($x);
             )*
-            Doc::Group(temp_vec)
+            Doc::Group($crate::doc::Group::new(temp_vec))
+        }
+    };
+}
+
+/// Print the comments attached to the node around the doc of `$block`,
+/// or the original text of the node if it has a `// prettier-ignore` comment.
+#[macro_export]
+macro_rules! wrap {
+    ($p:ident, $span:expr, $block:block) => {{
+        let span = $span;
+        let doc = if $p.has_comment(span, $crate::comments::CommentFlags::PrettierIgnore) {
+            $p.print_ignored(span)
+        } else {
+            $block
+        };
+        $p.print_comments(span, doc)
+    }};
+}
+
+#[macro_export]
+macro_rules! conditional_group {
+    ($p:ident, $c: expr, $( $x:expr ),* ) => {
+        {
+            let mut temp_vec = $p.vec();
+            temp_vec.push($c);
+            let mut expanded_states = $p.vec();
+            $(
+                expanded_states.push($x);
+            )*
+            Doc::Group($crate::doc::Group::new_conditional_group(temp_vec, expanded_states))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! fill {
+    ($p:ident, $( $x:expr ),* ) => {
+        {
+            let mut temp_vec = $p.vec();
+            $(
+                temp_vec.push($x);
+            )*
+            Doc::Fill(temp_vec)
         }
     };
 }
 
 #[macro_export]
 macro_rules! if_break {
+    ($p:ident, $s:expr, $flat:expr, $group_id:expr) => {{
+        Doc::IfBreak($crate::doc::IfBreak {
+            break_contents: $p.alloc(Doc::Str($s)),
+            flat_contents: $p.alloc(Doc::Str($flat)),
+            group_id: $group_id,
+        })
+    }};
+    ($p:ident, $s:expr, $flat:expr) => {{
+        $crate::if_break!($p, $s, $flat, None)
+    }};
     ($p:ident, $s:expr) => {{
-        Doc::IfBreak($p.alloc(Doc::Str($s)))
+        $crate::if_break!($p, $s, "", None)
     }};
 }
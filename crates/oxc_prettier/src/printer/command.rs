@@ -1,13 +1,18 @@
-use crate::doc::Doc;
+use std::rc::Rc;
+
+use crate::{
+    doc::{AlignKind, Doc},
+    PrettierOptions,
+};
 
 pub struct Command<'a> {
-    pub indent: Indent,
+    pub indent: Rc<Indent<'a>>,
     pub mode: Mode,
     pub doc: Doc<'a>,
 }
 
 impl<'a> Command<'a> {
-    pub fn new(indent: Indent, mode: Mode, doc: Doc<'a>) -> Self {
+    pub fn new(indent: Rc<Indent<'a>>, mode: Mode, doc: Doc<'a>) -> Self {
         Self { indent, mode, doc }
     }
 }
@@ -19,12 +24,116 @@ pub enum Mode {
 }
 
 #[derive(Clone, Copy)]
-pub struct Indent {
+enum IndentPart<'a> {
+    Indent,
+    StringAlign(&'a str),
+    NumberAlign(usize),
+}
+
+/// The indentation of a line, made of indents and aligns.
+///
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/document/printer.js>
+#[derive(Default)]
+pub struct Indent<'a> {
+    /// The string which is printed after a line break
+    pub value: String,
+    /// The width of `value`
     pub length: usize,
+    queue: Vec<IndentPart<'a>>,
+    /// The indentation marked as the root by [AlignKind::Root]
+    pub root: Option<Rc<Indent<'a>>>,
 }
 
-impl Indent {
-    pub fn root() -> Self {
-        Self { length: 0 }
+impl<'a> Indent<'a> {
+    pub fn root() -> Rc<Self> {
+        Rc::new(Self::default())
+    }
+
+    pub fn make_indent(self: &Rc<Self>, options: PrettierOptions) -> Rc<Self> {
+        self.generate(Some(IndentPart::Indent), options)
+    }
+
+    pub fn make_align(self: &Rc<Self>, kind: AlignKind<'a>, options: PrettierOptions) -> Rc<Self> {
+        match kind {
+            AlignKind::DedentToRoot => self.root.clone().unwrap_or_else(Self::root),
+            AlignKind::Dedent => self.generate(None, options),
+            AlignKind::Root => Rc::new(Self {
+                value: self.value.clone(),
+                length: self.length,
+                queue: self.queue.clone(),
+                root: Some(Rc::clone(self)),
+            }),
+            AlignKind::Spaces(0) | AlignKind::String("") => Rc::clone(self),
+            AlignKind::Spaces(n) => self.generate(Some(IndentPart::NumberAlign(n)), options),
+            AlignKind::String(s) => self.generate(Some(IndentPart::StringAlign(s)), options),
+        }
+    }
+
+    /// Add `part` to the indentation, or remove the last part if it is `None`.
+    fn generate(&self, part: Option<IndentPart<'a>>, options: PrettierOptions) -> Rc<Self> {
+        let mut queue = self.queue.clone();
+        match part {
+            Some(part) => queue.push(part),
+            None => {
+                queue.pop();
+            }
+        }
+
+        let mut value = String::new();
+        let mut length = 0;
+        let mut last_tabs = 0;
+        let mut last_spaces = 0;
+
+        let add_tabs = |value: &mut String, length: &mut usize, count: usize| {
+            value.push_str(&"\t".repeat(count));
+            *length += options.tab_width * count;
+        };
+        let add_spaces = |value: &mut String, length: &mut usize, count: usize| {
+            value.push_str(&" ".repeat(count));
+            *length += count;
+        };
+        // Aligns are kept as spaces, unless they are followed by an indent with tabs.
+        let flush = |value: &mut String,
+                     length: &mut usize,
+                     last_tabs: &mut usize,
+                     last_spaces: &mut usize| {
+            if options.use_tabs {
+                if *last_tabs > 0 {
+                    add_tabs(value, length, *last_tabs);
+                }
+            } else if *last_spaces > 0 {
+                add_spaces(value, length, *last_spaces);
+            }
+            *last_tabs = 0;
+            *last_spaces = 0;
+        };
+
+        for part in &queue {
+            match part {
+                IndentPart::Indent => {
+                    flush(&mut value, &mut length, &mut last_tabs, &mut last_spaces);
+                    if options.use_tabs {
+                        add_tabs(&mut value, &mut length, 1);
+                    } else {
+                        add_spaces(&mut value, &mut length, options.tab_width);
+                    }
+                }
+                IndentPart::StringAlign(s) => {
+                    flush(&mut value, &mut length, &mut last_tabs, &mut last_spaces);
+                    value.push_str(s);
+                    length += s.len();
+                }
+                IndentPart::NumberAlign(n) => {
+                    last_tabs += 1;
+                    last_spaces += n;
+                }
+            }
+        }
+        if last_spaces > 0 {
+            add_spaces(&mut value, &mut length, last_spaces);
+        }
+
+        Rc::new(Self { value, length, queue, root: self.root.clone() })
     }
 }
@@ -5,31 +5,51 @@
 
 mod command;
 
-use std::collections::VecDeque;
+use std::{collections::HashMap, rc::Rc};
 
-use crate::{doc::Doc, PrettierOptions};
+use oxc_allocator::Vec;
+
+use crate::{
+    doc::{Align, Doc, Group, GroupId, IfBreak, IndentIfBreak},
+    PrettierOptions,
+};
 
 use self::command::{Command, Indent, Mode};
 
 pub struct Printer<'a> {
     options: PrettierOptions,
     /// The final output string in bytes
-    out: Vec<u8>,
+    out: std::vec::Vec<u8>,
     /// The current position in the output
     pos: usize,
     /// cmds is basically a stack. We've turned a recursive call into a
     /// while loop which is much faster. The while loop below adds new
     /// cmds to the array instead of recursively calling `print`.
-    cmds: Vec<Command<'a>>,
+    cmds: std::vec::Vec<Command<'a>>,
+    /// The contents of [Doc::LineSuffix] which are printed before the next newline.
+    line_suffix: std::vec::Vec<Command<'a>>,
+    /// The modes of the printed groups with an id, for [Doc::IfBreak] and [Doc::IndentIfBreak].
+    group_mode_map: HashMap<GroupId, Mode>,
+    /// A hardline was printed in flat mode, so the next group has to be measured again.
+    should_remeasure: bool,
 }
 
 impl<'a> Printer<'a> {
-    pub fn new(doc: Doc<'a>, source_text: &str, options: PrettierOptions) -> Self {
+    pub fn new(mut doc: Doc<'a>, source_text: &str, options: PrettierOptions) -> Self {
+        propagate_breaks(&mut doc);
         // Preallocate for performance because the output will very likely
         // be the same size as the original text.
-        let out = Vec::with_capacity(source_text.len());
+        let out = std::vec::Vec::with_capacity(source_text.len());
         let cmds = vec![Command::new(Indent::root(), Mode::Break, doc)];
-        Self { options, out, pos: 0, cmds }
+        Self {
+            options,
+            out,
+            pos: 0,
+            cmds,
+            line_suffix: vec![],
+            group_mode_map: HashMap::new(),
+            should_remeasure: false,
+        }
     }
 
     pub fn build(mut self) -> String {
@@ -46,137 +66,543 @@ impl<'a> Printer<'a> {
         while let Some(Command { indent, doc, mode }) = self.cmds.pop() {
             match doc {
                 Doc::Str(s) => self.handle_str(s),
-                Doc::Array(docs) => self.handle_array(indent, mode, docs),
-                Doc::Indent(docs) => self.handle_indent(indent, mode, docs),
-                Doc::Group(docs) => self.handle_group(indent, mode, docs),
-                Doc::Line => self.handle_line(indent, mode),
-                Doc::Softline => self.handle_softline(indent, mode),
-                Doc::Hardline => self.handle_hardline(indent),
-                Doc::IfBreak(doc) => self.handle_if_break(doc.unbox(), indent, mode),
+                Doc::Array(docs) => self.handle_array(&indent, mode, docs),
+                Doc::Indent(docs) => self.handle_indent(&indent, mode, docs),
+                Doc::Align(align) => self.handle_align(&indent, mode, align),
+                Doc::Group(group) => self.handle_group(indent, mode, group),
+                Doc::Fill(parts) => self.handle_fill(indent, mode, parts),
+                Doc::Line | Doc::Softline | Doc::Hardline | Doc::LiteralLine => {
+                    self.handle_line(indent, mode, doc);
+                }
+                Doc::IfBreak(if_break) => self.handle_if_break(indent, mode, if_break),
+                Doc::IndentIfBreak(indent_if_break) => {
+                    self.handle_indent_if_break(&indent, mode, indent_if_break);
+                }
+                Doc::LineSuffix(docs) => self.handle_line_suffix(indent, mode, docs),
+                Doc::LineSuffixBoundary => {
+                    if !self.line_suffix.is_empty() {
+                        self.cmds.push(Command::new(indent, mode, Doc::Hardline));
+                    }
+                }
+                Doc::Label(label) => self.handle_array(&indent, mode, label.contents),
+                // Handled by `propagate_breaks`
+                Doc::BreakParent => {}
+            }
+            if self.cmds.is_empty() && !self.line_suffix.is_empty() {
+                self.cmds.extend(self.line_suffix.drain(..).rev());
             }
         }
     }
 
+    fn handle_line_suffix(&mut self, indent: Rc<Indent<'a>>, mode: Mode, docs: Vec<'a, Doc<'a>>) {
+        self.line_suffix.push(Command::new(indent, mode, Doc::Array(docs)));
+    }
+
     fn handle_str(&mut self, s: &str) {
         self.out.extend(s.as_bytes());
         self.pos += s.len();
     }
 
-    fn handle_array(&mut self, indent: Indent, mode: Mode, docs: oxc_allocator::Vec<'a, Doc<'a>>) {
-        self.cmds.extend(docs.into_iter().rev().map(|doc| Command::new(indent, mode, doc)));
+    fn handle_array(&mut self, indent: &Rc<Indent<'a>>, mode: Mode, docs: Vec<'a, Doc<'a>>) {
+        self.cmds
+            .extend(docs.into_iter().rev().map(|doc| Command::new(Rc::clone(indent), mode, doc)));
     }
 
-    fn handle_indent(&mut self, indent: Indent, mode: Mode, docs: oxc_allocator::Vec<'a, Doc<'a>>) {
-        self.cmds.extend(
-            docs.into_iter()
-                .rev()
-                .map(|doc| Command::new(Indent { length: indent.length + 1 }, mode, doc)),
-        );
+    fn handle_indent(&mut self, indent: &Rc<Indent<'a>>, mode: Mode, docs: Vec<'a, Doc<'a>>) {
+        let indent = indent.make_indent(self.options);
+        self.handle_array(&indent, mode, docs);
     }
 
-    fn handle_group(&mut self, indent: Indent, mode: Mode, docs: oxc_allocator::Vec<'a, Doc<'a>>) {
+    fn handle_align(&mut self, indent: &Rc<Indent<'a>>, mode: Mode, align: Align<'a>) {
+        let indent = indent.make_align(align.kind, self.options);
+        self.handle_array(&indent, mode, align.contents);
+    }
+
+    fn handle_group(&mut self, indent: Rc<Indent<'a>>, mode: Mode, group: Group<'a>) {
+        let Group { contents, should_break, expanded_states, id } = group;
         match mode {
-            Mode::Flat => {
-                // TODO: consider supporting `group mode` e.g. Break/Flat
-                self.cmds.extend(
-                    docs.into_iter().rev().map(|doc| Command::new(indent, Mode::Flat, doc)),
-                );
+            Mode::Flat if !self.should_remeasure => {
+                let mode = if should_break { Mode::Break } else { Mode::Flat };
+                self.cmds.push(Command::new(indent, mode, Doc::Array(contents)));
             }
-            Mode::Break => {
-                #[allow(clippy::cast_possible_wrap)]
-                let remaining_width = (self.options.print_width as isize) - (self.pos as isize);
-
-                if self.fits(&docs, indent, remaining_width) {
-                    self.cmds.extend(
-                        docs.into_iter().rev().map(|doc| Command::new(indent, Mode::Flat, doc)),
-                    );
+            _ => {
+                self.should_remeasure = false;
+                let remaining_width = self.remaining_width();
+                let has_line_suffix = !self.line_suffix.is_empty();
+                let contents = Doc::Array(contents);
+
+                if !should_break
+                    && self.fits(
+                        vec![(Mode::Flat, &contents)],
+                        &self.cmds,
+                        remaining_width,
+                        has_line_suffix,
+                        false,
+                    )
+                {
+                    self.cmds.push(Command::new(indent, Mode::Flat, contents));
+                } else if let Some(mut expanded_states) =
+                    expanded_states.filter(|states| !states.is_empty())
+                {
+                    // Break the most expanded state if the group has to break,
+                    // otherwise print the first state which fits in flat mode.
+                    let index = if should_break {
+                        None
+                    } else {
+                        expanded_states.iter().position(|state| {
+                            self.fits(
+                                vec![(Mode::Flat, state)],
+                                &self.cmds,
+                                remaining_width,
+                                has_line_suffix,
+                                false,
+                            )
+                        })
+                    };
+                    let cmd = match index {
+                        Some(index) => {
+                            Command::new(indent, Mode::Flat, expanded_states.swap_remove(index))
+                        }
+                        None => Command::new(indent, Mode::Break, expanded_states.pop().unwrap()),
+                    };
+                    self.cmds.push(cmd);
                 } else {
-                    self.cmds.extend(
-                        docs.into_iter().rev().map(|doc| Command::new(indent, Mode::Break, doc)),
-                    );
+                    self.cmds.push(Command::new(indent, Mode::Break, contents));
                 }
             }
         }
+
+        if let Some(id) = id {
+            let mode = self.cmds.last().map_or(Mode::Flat, |cmd| cmd.mode);
+            self.group_mode_map.insert(id, mode);
+        }
+    }
+
+    /// Fills work like this: fit as many contents on a line as possible, breaking
+    /// the whitespace in between as needed. The contents and whitespace are
+    /// expected to alternate, `[content, whitespace, content, whitespace, ...]`.
+    fn handle_fill(&mut self, indent: Rc<Indent<'a>>, mode: Mode, mut parts: Vec<'a, Doc<'a>>) {
+        if parts.is_empty() {
+            return;
+        }
+
+        let remaining_width = self.remaining_width();
+        let has_line_suffix = !self.line_suffix.is_empty();
+        let content_fits =
+            self.fits(vec![(Mode::Flat, &parts[0])], &[], remaining_width, has_line_suffix, true);
+        let content_mode = if content_fits { Mode::Flat } else { Mode::Break };
+
+        if parts.len() == 1 {
+            let content = parts.pop().unwrap();
+            self.cmds.push(Command::new(indent, content_mode, content));
+            return;
+        }
+
+        if parts.len() == 2 {
+            let whitespace = parts.pop().unwrap();
+            let content = parts.pop().unwrap();
+            self.cmds.push(Command::new(Rc::clone(&indent), content_mode, whitespace));
+            self.cmds.push(Command::new(indent, content_mode, content));
+            return;
+        }
+
+        // Check whether the whitespace can be flat, which is the case if
+        // the content after it fits on the same line too.
+        let first_and_second_content_fits = self.fits(
+            vec![(Mode::Flat, &parts[2]), (Mode::Flat, &parts[1]), (Mode::Flat, &parts[0])],
+            &[],
+            remaining_width,
+            has_line_suffix,
+            true,
+        );
+
+        let mut drained = parts.drain(..2);
+        let content = drained.next().unwrap();
+        let whitespace = drained.next().unwrap();
+        drop(drained);
+
+        let whitespace_mode = if first_and_second_content_fits { Mode::Flat } else { Mode::Break };
+        self.cmds.push(Command::new(Rc::clone(&indent), mode, Doc::Fill(parts)));
+        self.cmds.push(Command::new(Rc::clone(&indent), whitespace_mode, whitespace));
+        self.cmds.push(Command::new(indent, content_mode, content));
     }
 
-    fn handle_line(&mut self, indent: Indent, mode: Mode) {
-        if matches!(mode, Mode::Flat) {
-            self.out.push(b' ');
+    fn handle_line(&mut self, indent: Rc<Indent<'a>>, mode: Mode, doc: Doc<'a>) {
+        let is_hard = matches!(doc, Doc::Hardline | Doc::LiteralLine);
+        if mode == Mode::Flat {
+            if !is_hard {
+                if matches!(doc, Doc::Line) {
+                    self.out.push(b' ');
+                    self.pos += 1;
+                }
+                return;
+            }
+            // This line was forced into the output even if we were in flattened mode,
+            // so we need to tell the next group that no matter what,
+            // it needs to remeasure.
+            self.should_remeasure = true;
+        }
+
+        if !self.line_suffix.is_empty() {
+            self.cmds.push(Command::new(indent, mode, doc));
+            self.cmds.extend(self.line_suffix.drain(..).rev());
+            return;
+        }
+
+        if matches!(doc, Doc::LiteralLine) {
+            self.out.push(b'\n');
+            if let Some(root) = &indent.root {
+                self.out.extend(root.value.as_bytes());
+                self.pos = root.length;
+            } else {
+                self.pos = 0;
+            }
         } else {
+            self.trim();
             self.out.push(b'\n');
-            self.pos = self.indent(indent.length);
+            self.out.extend(indent.value.as_bytes());
+            self.pos = indent.length;
         }
     }
 
-    fn handle_softline(&mut self, indent: Indent, mode: Mode) {
-        if !matches!(mode, Mode::Flat) {
-            self.out.push(b'\n');
-            self.pos = self.indent(indent.length);
+    fn handle_if_break(&mut self, indent: Rc<Indent<'a>>, mode: Mode, if_break: IfBreak<'a>) {
+        let group_mode = if_break.group_id.map_or(Some(mode), |id| self.group_mode(id));
+        match group_mode {
+            Some(Mode::Break) => {
+                self.cmds.push(Command::new(indent, mode, if_break.break_contents.unbox()));
+            }
+            Some(Mode::Flat) => {
+                self.cmds.push(Command::new(indent, mode, if_break.flat_contents.unbox()));
+            }
+            None => {}
         }
     }
 
-    fn handle_hardline(&mut self, indent: Indent) {
-        self.out.push(b'\n');
-        self.pos = self.indent(indent.length);
+    fn handle_indent_if_break(
+        &mut self,
+        indent: &Rc<Indent<'a>>,
+        mode: Mode,
+        indent_if_break: IndentIfBreak<'a>,
+    ) {
+        let IndentIfBreak { contents, group_id, negate } = indent_if_break;
+        let Some(group_mode) = self.group_mode(group_id) else { return };
+        if (group_mode == Mode::Break) == negate {
+            self.handle_array(indent, mode, contents);
+        } else {
+            self.handle_indent(indent, mode, contents);
+        }
+    }
+
+    /// The mode of a printed group, `None` if the group is not printed yet.
+    fn group_mode(&self, id: GroupId) -> Option<Mode> {
+        self.group_mode_map.get(&id).copied()
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn remaining_width(&self) -> isize {
+        (self.options.print_width as isize) - (self.pos as isize)
     }
 
-    fn handle_if_break(&mut self, doc: Doc<'a>, indent: Indent, mode: Mode) {
-        if mode == Mode::Break {
-            self.cmds.push(Command::new(indent, Mode::Break, doc));
+    /// Remove the trailing spaces and tabs of the output.
+    fn trim(&mut self) {
+        while let Some(b' ' | b'\t') = self.out.last() {
+            self.out.pop();
+            self.pos -= 1;
         }
     }
 
+    /// Whether `next` fits on the rest of the line, followed by `rest_commands` up to the first line break.
+    ///
+    /// `next` is a stack, its last element is checked first.
     #[allow(clippy::cast_possible_wrap)]
     fn fits(
         &self,
-        doc: &oxc_allocator::Vec<'a, Doc<'a>>,
-        indent: Indent,
-        remaining_width: isize,
+        next: std::vec::Vec<(Mode, &Doc<'a>)>,
+        rest_commands: &[Command<'a>],
+        width: isize,
+        has_line_suffix: bool,
+        must_be_flat: bool,
     ) -> bool {
-        let mut remaining_width = remaining_width;
+        let mut remaining_width = width;
+        let mut has_line_suffix = has_line_suffix;
+        let mut rest_idx = rest_commands.len();
+        let mut cmds = next;
 
-        // TODO: these should be commands
-        let mut queue: VecDeque<&Doc<'a>> = doc.iter().collect();
+        while remaining_width >= 0 {
+            let Some((mode, doc)) = cmds.pop() else {
+                if rest_idx == 0 {
+                    return true;
+                }
+                rest_idx -= 1;
+                let cmd = &rest_commands[rest_idx];
+                cmds.push((cmd.mode, &cmd.doc));
+                continue;
+            };
 
-        while let Some(next) = queue.pop_front() {
-            match next {
+            match doc {
                 Doc::Str(string) => {
                     remaining_width -= string.len() as isize;
                 }
-                Doc::Array(docs) | Doc::Indent(docs) | Doc::Group(docs) => {
-                    // Prepend docs to the queue
-                    for d in docs.iter().rev() {
-                        queue.push_front(d);
+                Doc::Array(docs) | Doc::Indent(docs) | Doc::Fill(docs) => {
+                    cmds.extend(docs.iter().rev().map(|doc| (mode, doc)));
+                }
+                Doc::Align(Align { contents, .. })
+                | Doc::IndentIfBreak(IndentIfBreak { contents, .. })
+                | Doc::Label(crate::doc::Label { contents, .. }) => {
+                    cmds.extend(contents.iter().rev().map(|doc| (mode, doc)));
+                }
+                Doc::Group(group) => {
+                    if must_be_flat && group.should_break {
+                        return false;
                     }
-
-                    if matches!(next, Doc::Indent(_)) {
-                        remaining_width -= (self.options.tab_width * indent.length) as isize;
+                    let group_mode = if group.should_break { Mode::Break } else { mode };
+                    match group.expanded_states.as_ref().and_then(|states| states.last()) {
+                        Some(most_expanded) if group_mode == Mode::Break => {
+                            cmds.push((group_mode, most_expanded));
+                        }
+                        _ => cmds.extend(group.contents.iter().rev().map(|doc| (group_mode, doc))),
+                    }
+                }
+                Doc::IfBreak(if_break) => {
+                    let group_mode = if_break
+                        .group_id
+                        .map_or(mode, |id| self.group_mode(id).unwrap_or(Mode::Flat));
+                    let contents = if group_mode == Mode::Break {
+                        &if_break.break_contents
+                    } else {
+                        &if_break.flat_contents
+                    };
+                    cmds.push((mode, contents));
+                }
+                Doc::Line => {
+                    if mode == Mode::Break {
+                        return true;
                     }
+                    remaining_width -= 1;
+                }
+                Doc::Softline => {
+                    if mode == Mode::Break {
+                        return true;
+                    }
+                }
+                Doc::Hardline | Doc::LiteralLine => {
+                    return true;
                 }
-                // trying to fit on a single line, so we don't need to consider line breaks
-                Doc::IfBreak { .. } | Doc::Softline => {}
-                Doc::Line => remaining_width -= 1,
-                Doc::Hardline => {
-                    return false;
+                Doc::LineSuffix(_) => {
+                    has_line_suffix = true;
                 }
+                Doc::LineSuffixBoundary => {
+                    if has_line_suffix {
+                        return true;
+                    }
+                }
+                Doc::BreakParent => {}
             }
+        }
+
+        false
+    }
+}
 
-            if remaining_width < 0 {
-                return false;
+/// Break the groups which contain a hard break, i.e. [Doc::Hardline], [Doc::LiteralLine]
+/// or [Doc::BreakParent], and the groups which contain them.
+///
+/// Conditional groups are not broken, so the printer can still try their expanded states.
+///
+/// Returns whether the doc contains a hard break.
+fn propagate_breaks(doc: &mut Doc<'_>) -> bool {
+    let propagate_all = |docs: &mut Vec<'_, Doc<'_>>| {
+        docs.iter_mut().fold(false, |should_break, doc| propagate_breaks(doc) || should_break)
+    };
+    match doc {
+        Doc::Hardline | Doc::LiteralLine | Doc::BreakParent => true,
+        Doc::Group(group) => {
+            let contents_break = propagate_all(&mut group.contents);
+            if let Some(expanded_states) = &mut group.expanded_states {
+                propagate_all(expanded_states);
+            } else if contents_break {
+                group.should_break = true;
             }
+            group.should_break
         }
+        Doc::Array(docs) | Doc::Indent(docs) | Doc::Fill(docs) | Doc::LineSuffix(docs) => {
+            propagate_all(docs)
+        }
+        Doc::Align(Align { contents, .. })
+        | Doc::IndentIfBreak(IndentIfBreak { contents, .. })
+        | Doc::Label(crate::doc::Label { contents, .. }) => propagate_all(contents),
+        Doc::IfBreak(if_break) => {
+            let break_contents = propagate_breaks(&mut if_break.break_contents);
+            propagate_breaks(&mut if_break.flat_contents) || break_contents
+        }
+        Doc::Str(_) | Doc::Line | Doc::Softline | Doc::LineSuffixBoundary => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::{Allocator, Vec};
+
+    use super::Printer;
+    use crate::{
+        array, conditional_group,
+        doc::{Align, AlignKind, Doc, Group, GroupId, IfBreak, IndentIfBreak, Label},
+        fill, group, hardline, if_break, indent, line, softline, ss, Prettier, PrettierOptions,
+    };
 
-        true
+    /// Print the doc built by `$doc` with `$p` at each of the print widths,
+    /// and compare with the expected output.
+    macro_rules! test {
+        (|$p:ident| $doc:expr, [$(($width:expr, $expected:expr)),* $(,)?]) => {
+            $({
+                let allocator = Allocator::default();
+                let options = PrettierOptions { print_width: $width, ..PrettierOptions::default() };
+                let $p = &mut Prettier::new(&allocator, "", vec![], options);
+                let printed = Printer::new($doc, "", options).build();
+                assert_eq!(printed, $expected, "print width {}", $width);
+            })*
+        };
     }
 
-    fn indent(&mut self, size: usize) -> usize {
-        if self.options.use_tabs {
-            self.out.extend("\t".repeat(size).as_bytes());
-            size
-        } else {
-            let count = self.options.tab_width * size;
-            self.out.extend(" ".repeat(count).as_bytes());
-            count
+    fn docs<'a, const N: usize>(p: &Prettier<'a>, docs: [Doc<'a>; N]) -> Vec<'a, Doc<'a>> {
+        let mut parts = p.vec();
+        parts.extend(docs);
+        parts
+    }
+
+    #[test]
+    fn group() {
+        test!(
+            |p| array!(p, group!(p, ss!("aaa"), line!(), ss!("bbb")), ss!(";")),
+            [(9, "aaa bbb;"), (8, "aaa bbb;"), (7, "aaa\nbbb;")]
+        );
+        // The outer group breaks first.
+        test!(
+            |p| {
+                let inner = group!(p, ss!("bbb"), line!(), ss!("ccc"));
+                group!(p, ss!("aaa"), indent!(p, line!(), inner))
+            },
+            [(11, "aaa bbb ccc"), (10, "aaa\n  bbb ccc"), (8, "aaa\n  bbb\n  ccc")]
+        );
+    }
+
+    #[test]
+    fn fill() {
+        test!(
+            |p| fill!(p, ss!("aa"), line!(), ss!("bb"), line!(), ss!("cc")),
+            [(8, "aa bb cc"), (7, "aa bb\ncc"), (5, "aa bb\ncc"), (4, "aa\nbb\ncc")]
+        );
+        // A content which does not fit is printed in break mode.
+        test!(
+            |p| {
+                let content = group!(p, ss!("bb"), line!(), ss!("cc"));
+                fill!(p, ss!("aa"), line!(), content)
+            },
+            [(8, "aa bb cc"), (7, "aa\nbb cc"), (4, "aa\nbb\ncc")]
+        );
+    }
+
+    fn aligned<'a>(p: &Prettier<'a>, kind: AlignKind<'a>) -> Doc<'a> {
+        let contents = docs(p, [hardline!(), group!(p, ss!("aaa"), line!(), ss!("bbb"))]);
+        array!(p, ss!("x"), indent!(p, Doc::Align(Align { kind, contents })))
+    }
+
+    #[test]
+    fn align() {
+        test!(
+            |p| aligned(p, AlignKind::Spaces(2)),
+            [(11, "x\n    aaa bbb"), (10, "x\n    aaa\n    bbb")]
+        );
+        test!(
+            |p| aligned(p, AlignKind::String("> ")),
+            [(11, "x\n  > aaa bbb"), (10, "x\n  > aaa\n  > bbb")]
+        );
+        test!(|p| aligned(p, AlignKind::Dedent), [(7, "x\naaa bbb"), (6, "x\naaa\nbbb")]);
+    }
+
+    #[test]
+    fn conditional_group() {
+        test!(
+            |p| {
+                let contents = array!(p, ss!("aaaaaaaa"), line!(), ss!("bbbb"));
+                let flat = array!(p, ss!("aaaaaaaa"), softline!(), ss!("bbbb"));
+                let expanded = array!(p, ss!("aaaaaaaa"), indent!(p, line!(), ss!("bbbb")));
+                conditional_group!(p, contents, flat, expanded)
+            },
+            [(13, "aaaaaaaa bbbb"), (12, "aaaaaaaabbbb"), (11, "aaaaaaaa\n  bbbb")]
+        );
+        // The most expanded state is printed in break mode if the group has to break.
+        test!(
+            |p| {
+                let contents = array!(p, ss!("aa"), line!(), ss!("bb"));
+                let expanded = array!(p, ss!("aa"), indent!(p, line!(), ss!("bb")));
+                let Doc::Group(group) = conditional_group!(p, contents, expanded) else {
+                    unreachable!()
+                };
+                Doc::Group(group.with_break(true))
+            },
+            [(80, "aa\n  bb")]
+        );
+    }
+
+    fn brackets<'a>(p: &mut Prettier<'a>) -> (GroupId, Doc<'a>) {
+        let id = p.next_id();
+        let contents = indent!(p, softline!(), ss!("aaaa"));
+        let group = Group::new(docs(p, [ss!("["), contents, softline!(), ss!("]")]));
+        (id, Doc::Group(group.with_id(id)))
+    }
+
+    #[test]
+    fn group_id() {
+        test!(
+            |p| {
+                let (id, group) = brackets(p);
+                array!(p, group, if_break!(p, ",", ";", Some(id)))
+            },
+            [(7, "[aaaa];"), (6, "[\n  aaaa\n],")]
+        );
+        // The mode of the group is looked up after it is printed, on the next lines too.
+        test!(
+            |p| {
+                let (id, group) = brackets(p);
+                let break_contents = p.alloc(ss!("broken"));
+                let flat_contents = p.alloc(ss!("flat"));
+                let if_break =
+                    Doc::IfBreak(IfBreak { break_contents, flat_contents, group_id: Some(id) });
+                array!(p, group, hardline!(), if_break)
+            },
+            [(6, "[aaaa]\nflat"), (5, "[\n  aaaa\n]\nbroken")]
+        );
+        for (negate, flat, broken) in
+            [(false, "[aaaa]\nx", "[\n  aaaa\n]\n  x"), (true, "[aaaa]\n  x", "[\n  aaaa\n]\nx")]
+        {
+            test!(
+                |p| {
+                    let (group_id, group) = brackets(p);
+                    let contents = docs(p, [hardline!(), ss!("x")]);
+                    array!(
+                        p,
+                        group,
+                        Doc::IndentIfBreak(IndentIfBreak { contents, group_id, negate })
+                    )
+                },
+                [(7, flat), (5, broken)]
+            );
         }
     }
+
+    fn labelled<'a>(p: &Prettier<'a>) -> Doc<'a> {
+        let contents = docs(p, [group!(p, ss!("aaa"), line!(), ss!("bbb"))]);
+        Doc::Label(Label { label: "member-chain", contents })
+    }
+
+    #[test]
+    fn label() {
+        test!(|p| labelled(p), [(7, "aaa bbb"), (6, "aaa\nbbb")]);
+
+        let allocator = Allocator::default();
+        let p = &mut Prettier::new(&allocator, "", vec![], PrettierOptions::default());
+        assert_eq!(labelled(p).label(), Some("member-chain"));
+        assert_eq!(ss!("aaa").label(), None);
+    }
 }
@@ -0,0 +1,121 @@
+//! Range formatting
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/blob/main/src/main/range-util.js>
+//! * <https://github.com/prettier/prettier/blob/main/src/main/core.js>
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Visit};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    doc::{Align, AlignKind, Doc},
+    Prettier,
+};
+
+/// A replacement of a part of the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub content: String,
+}
+
+impl TextEdit {
+    /// The edit replacing `span` of the source text with `formatted`,
+    /// narrowed down to the part which changed.
+    /// Returns `None` if the text is already formatted.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn diff(source_text: &str, span: Span, formatted: &str) -> Option<Self> {
+        let original = span.source_text(source_text);
+        if original == formatted {
+            return None;
+        }
+        let prefix = common_len(original.chars(), formatted.chars());
+        let suffix =
+            common_len(original[prefix..].chars().rev(), formatted[prefix..].chars().rev());
+        Some(Self {
+            span: Span::new(span.start + prefix as u32, span.end - suffix as u32),
+            content: formatted[prefix..formatted.len() - suffix].to_string(),
+        })
+    }
+}
+
+/// The length in bytes of the common part of two texts.
+fn common_len(a: impl Iterator<Item = char>, b: impl Iterator<Item = char>) -> usize {
+    a.zip(b).take_while(|(a, b)| a == b).map(|(a, _)| a.len_utf8()).sum()
+}
+
+/// Exclude the whitespace at both ends of the range.
+#[allow(clippy::cast_possible_truncation)]
+pub fn trim_range(source_text: &str, range: Span) -> Span {
+    let end = (range.end as usize).min(source_text.len());
+    let start = (range.start as usize).min(end);
+    let text = &source_text[start..end];
+    let trimmed_start = text.trim_start();
+    let start = end - trimmed_start.len();
+    Span::new(start as u32, (start + trimmed_start.trim_end().len()) as u32)
+}
+
+/// Find the statements covering the range in the innermost list of statements.
+///
+/// A range starting or ending in the middle of a statement is expanded to the whole statement,
+/// and a range spanning statements at different depths is expanded to their siblings, e.g.
+/// a range from a statement in a function body to the statement after the function
+/// is expanded to the function declaration and the statement after it.
+pub fn find_statements<'a>(program: &Program<'a>, range: Span) -> Option<&'a [Statement<'a>]> {
+    if range.start == range.end {
+        return None;
+    }
+    let mut finder = StatementsFinder { range, statements: None };
+    finder.visit_program(program);
+    finder.statements
+}
+
+struct StatementsFinder<'a> {
+    range: Span,
+    statements: Option<&'a [Statement<'a>]>,
+}
+
+impl<'a> Visit<'a> for StatementsFinder<'a> {
+    fn visit_statements(&mut self, stmts: &oxc_allocator::Vec<'a, Statement<'a>>) {
+        let first = stmts.iter().position(|stmt| stmt.span().end > self.range.start);
+        let last = stmts.iter().rposition(|stmt| stmt.span().start < self.range.end);
+        if let (Some(first), Some(last)) = (first, last) {
+            // The nested lists are visited later, so the innermost list wins.
+            if first <= last
+                && stmts[first].span().start <= self.range.start
+                && stmts[last].span().end >= self.range.end
+            {
+                let stmts = self.alloc(stmts);
+                self.statements = stmts.get(first..=last);
+            }
+        }
+        for stmt in stmts {
+            self.visit_statement(stmt);
+        }
+    }
+}
+
+/// Indent the doc to the indentation of the line where the range starts.
+/// The first line is not indented, because the text before the range is kept.
+pub fn align_to_line_indentation<'a>(p: &Prettier<'a>, offset: u32, doc: Doc<'a>) -> Doc<'a> {
+    let line_start = p.source_text[..offset as usize].rfind('\n').map_or(0, |i| i + 1);
+    let tab_width = p.options.tab_width;
+    let size = p.source_text[line_start..offset as usize]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .fold(0, |size, c| if c == '\t' { size + tab_width - size % tab_width } else { size + 1 });
+
+    let mut aligned = doc;
+    for _ in 0..size / tab_width {
+        let mut contents = p.vec();
+        contents.push(aligned);
+        aligned = Doc::Indent(contents);
+    }
+    if size % tab_width > 0 {
+        let mut contents = p.vec();
+        contents.push(aligned);
+        aligned = Doc::Align(Align { kind: AlignKind::Spaces(size % tab_width), contents });
+    }
+    aligned
+}
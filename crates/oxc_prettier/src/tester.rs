@@ -0,0 +1,33 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::{Prettier, PrettierOptions};
+
+pub struct Tester {
+    source_type: SourceType,
+
+    options: PrettierOptions,
+}
+
+impl Tester {
+    pub fn new(filename: &str, options: PrettierOptions) -> Self {
+        let source_type = SourceType::from_path(filename).unwrap();
+        Self { source_type, options }
+    }
+
+    /// Format each source text and compare it with the expected output.
+    pub fn test(&self, tests: &[(&str, &str)]) {
+        for (source_text, expected) in tests {
+            let formatted = self.format(source_text);
+            assert_eq!(formatted, *expected, "{source_text}");
+        }
+    }
+
+    fn format(&self, source_text: &str) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, self.source_type).parse();
+        assert!(ret.errors.is_empty(), "{source_text}");
+        Prettier::new(&allocator, source_text, ret.trivias, self.options).build(&ret.program)
+    }
+}
@@ -1,5 +1,122 @@
+//! Utilities for looking at the source text
+//!
+//! References:
+//! * <https://github.com/prettier/prettier/tree/main/src/utils>
+
 use oxc_span::Span;
 
-pub fn is_next_line_empty(source_text: &str, span: Span) -> bool {
-    source_text.chars().nth((span.end as usize) + 1).is_some_and(|c| c == '\n')
+pub fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+/// Skip spaces and tabs.
+pub fn skip_spaces(text: &str, index: usize, backwards: bool) -> usize {
+    if backwards {
+        text[..index].trim_end_matches([' ', '\t']).len()
+    } else {
+        text.len() - text[index..].trim_start_matches([' ', '\t']).len()
+    }
+}
+
+/// Skip a single newline, `\r\n` counts as one.
+pub fn skip_newline(text: &str, index: usize, backwards: bool) -> usize {
+    if backwards {
+        if text[..index].ends_with("\r\n") {
+            return index - 2;
+        }
+        match text[..index].chars().next_back() {
+            Some(c) if is_line_terminator(c) => index - c.len_utf8(),
+            _ => index,
+        }
+    } else {
+        if text[index..].starts_with("\r\n") {
+            return index + 2;
+        }
+        match text[index..].chars().next() {
+            Some(c) if is_line_terminator(c) => index + c.len_utf8(),
+            _ => index,
+        }
+    }
+}
+
+/// Skip a `/* */` comment starting at `index`.
+fn skip_inline_comment(text: &str, index: usize) -> usize {
+    if text[index..].starts_with("/*") {
+        if let Some(end) = text[index + 2..].find("*/") {
+            return index + 2 + end + 2;
+        }
+    }
+    index
+}
+
+/// Skip a `//` comment starting at `index`, up to but not including the newline.
+fn skip_trailing_comment(text: &str, index: usize) -> usize {
+    if text[index..].starts_with("//") {
+        return text[index..].find(is_line_terminator).map_or(text.len(), |end| index + end);
+    }
+    index
+}
+
+/// Whether there is a newline right before (or after) `index`, ignoring spaces and tabs.
+pub fn has_newline(text: &str, index: u32, backwards: bool) -> bool {
+    let index = index as usize;
+    if backwards {
+        let index = skip_spaces(text, index, true);
+        skip_newline(text, index, true) != index
+    } else {
+        let index = skip_spaces(text, index, false);
+        skip_newline(text, index, false) != index
+    }
+}
+
+pub fn has_newline_in_range(text: &str, start: u32, end: u32) -> bool {
+    text[start as usize..end as usize].contains(is_line_terminator)
+}
+
+pub fn is_previous_line_empty(text: &str, start: u32) -> bool {
+    let index = skip_spaces(text, start as usize, true);
+    let index = skip_newline(text, index, true);
+    let index = skip_spaces(text, index, true);
+    skip_newline(text, index, true) != index
+}
+
+/// Whether the line after the one containing `span.end` is empty,
+/// skipping over any comments which trail the node on the same line.
+#[allow(clippy::cast_possible_truncation)]
+pub fn is_next_line_empty(text: &str, span: Span) -> bool {
+    let mut index = span.end as usize;
+    loop {
+        let old_index = index;
+        index = text.len() - text[index..].trim_start_matches([',', ';', ' ', '\t']).len();
+        index = skip_inline_comment(text, index);
+        index = skip_spaces(text, index, false);
+        if index == old_index {
+            break;
+        }
+    }
+    index = skip_trailing_comment(text, index);
+    index = skip_newline(text, index, false);
+    has_newline(text, index as u32, false)
+}
+
+/// The index of the next character which is not a space, a newline or part of a comment.
+#[allow(clippy::cast_possible_truncation)]
+pub fn get_next_non_space_non_comment_character_index(text: &str, index: u32) -> Option<u32> {
+    let mut index = index as usize;
+    loop {
+        let old_index = index;
+        index = skip_spaces(text, index, false);
+        index = skip_inline_comment(text, index);
+        index = skip_trailing_comment(text, index);
+        index = skip_newline(text, index, false);
+        if index == old_index {
+            break;
+        }
+    }
+    (index < text.len()).then_some(index as u32)
+}
+
+pub fn get_next_non_space_non_comment_character(text: &str, index: u32) -> Option<char> {
+    get_next_non_space_non_comment_character_index(text, index)
+        .and_then(|index| text[index as usize..].chars().next())
 }
@@ -56,7 +56,7 @@ pub use crate::{
         Alias, AliasValue, EnforceExtension, ResolveOptions, Restriction, TsconfigOptions,
         TsconfigReferences,
     },
-    package_json::PackageJson,
+    package_json::{PackageJson, PackageType},
     resolution::Resolution,
 };
 
@@ -160,6 +160,18 @@ impl<Fs: FileSystem + Default> ResolverGeneric<Fs> {
         self.cache.clear();
     }
 
+    /// Find the nearest package.json of `path`, which is a file or a directory
+    ///
+    /// # Errors
+    ///
+    /// * [ResolveError::JSON]
+    pub fn find_package_json<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<Option<Arc<PackageJson>>, ResolveError> {
+        self.cache.value(path.as_ref()).find_package_json(&self.cache.fs, &self.options)
+    }
+
     /// Resolve `specifier` at `path`
     ///
     /// # Errors
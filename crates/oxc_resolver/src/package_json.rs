@@ -58,6 +58,20 @@ pub struct PackageJson {
     /// <https://github.com/defunctzombie/package-browser-field-spec>
     #[serde(skip)]
     pub browser_fields: Vec<BrowserField>,
+
+    /// The "type" field defines the module format that Node.js uses for all `.js` files that have that package.json file as their nearest parent.
+    /// Values other than `"commonjs"` and `"module"` are ignored.
+    ///
+    /// <https://nodejs.org/api/packages.html#type>
+    #[serde(skip)]
+    pub r#type: Option<PackageType>,
+}
+
+/// The "type" field of package.json
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageType {
+    CommonJs,
+    Module,
 }
 
 /// `matchObj` defined in `PACKAGE_IMPORTS_EXPORTS_RESOLVE`
@@ -127,6 +141,16 @@ impl PackageJson {
         package_json.exports.reserve_exact(options.exports_fields.len());
 
         if let Some(package_json_value) = package_json_value.as_object_mut() {
+            package_json.r#type = match package_json_value.get("type") {
+                Some(serde_json::Value::String(value)) if value == "commonjs" => {
+                    Some(PackageType::CommonJs)
+                }
+                Some(serde_json::Value::String(value)) if value == "module" => {
+                    Some(PackageType::Module)
+                }
+                _ => None,
+            };
+
             // Dynamically create `main_fields`.
             for main_field_key in &options.main_fields {
                 // Using `get` + `clone` instead of remove here
@@ -10,6 +10,7 @@ mod imports_field;
 mod incorrect_description_file;
 mod main_field;
 mod memory_fs;
+mod package_type;
 mod resolve;
 mod restrictions;
 mod roots;
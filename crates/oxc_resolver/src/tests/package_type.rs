@@ -0,0 +1,32 @@
+//! The "type" field of the nearest package.json
+
+use std::path::Path;
+
+use super::memory_fs::MemoryFS;
+use crate::{PackageType, ResolveOptions, ResolverGeneric};
+
+#[test]
+fn package_type() {
+    let file_system = MemoryFS::new(&[
+        ("/package.json", r#"{ "type": "module" }"#),
+        ("/cjs/package.json", r#"{ "type": "commonjs" }"#),
+        ("/cjs/lib/index.js", ""),
+        ("/none/package.json", "{}"),
+        ("/invalid/package.json", r#"{ "type": 1 }"#),
+    ]);
+    let resolver =
+        ResolverGeneric::<MemoryFS>::new_with_file_system(file_system, ResolveOptions::default());
+
+    let data = [
+        ("/index.js", Some(PackageType::Module)),
+        ("/cjs/lib/index.js", Some(PackageType::CommonJs)),
+        ("/cjs/lib", Some(PackageType::CommonJs)),
+        ("/none/index.js", None),
+        ("/invalid/index.js", None),
+    ];
+
+    for (path, expected) in data {
+        let package_json = resolver.find_package_json(Path::new(path)).unwrap().unwrap();
+        assert_eq!(package_json.r#type, expected, "{path}");
+    }
+}
@@ -249,6 +249,18 @@ impl<'a> Binder for TSTypeAliasDeclaration<'a> {
     }
 }
 
+impl<'a> Binder for FlowOpaqueTypeDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        let symbol_id = builder.declare_symbol(
+            self.id.span,
+            &self.id.name,
+            SymbolFlags::TypeAlias,
+            SymbolFlags::TypeAliasExcludes,
+        );
+        self.id.symbol_id.set(Some(symbol_id));
+    }
+}
+
 impl<'a> Binder for TSInterfaceDeclaration<'a> {
     fn bind(&self, builder: &mut SemanticBuilder) {
         let symbol_id = builder.declare_symbol(
@@ -151,6 +151,10 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     pub fn build(mut self, program: &Program<'a>) -> SemanticBuilderReturn<'a> {
+        // The parser resolves an unambiguous source type to a script or a module
+        if self.source_type.is_unambiguous() {
+            self.source_type = program.source_type;
+        }
         if self.source_type.is_typescript_definition() {
             self.scope.add_scope(None, ScopeFlags::Top);
         } else {
@@ -407,16 +411,51 @@ impl<'a> SemanticBuilder<'a> {
                 decl.bind(self);
                 self.make_all_namespaces_valuelike();
             }
+            AstKind::Program(program) => {
+                program.scope_id.set(Some(self.current_scope_id));
+            }
+            AstKind::BlockStatement(stmt) => {
+                stmt.scope_id.set(Some(self.current_scope_id));
+            }
+            AstKind::ForStatement(stmt) => {
+                if stmt.init.as_ref().is_some_and(ForStatementInit::is_lexical_declaration) {
+                    stmt.scope_id.set(Some(self.current_scope_id));
+                }
+            }
+            AstKind::ForInStatement(stmt) => {
+                if stmt.left.is_lexical_declaration() {
+                    stmt.scope_id.set(Some(self.current_scope_id));
+                }
+            }
+            AstKind::ForOfStatement(stmt) => {
+                if stmt.left.is_lexical_declaration() {
+                    stmt.scope_id.set(Some(self.current_scope_id));
+                }
+            }
+            AstKind::SwitchStatement(stmt) => {
+                stmt.scope_id.set(Some(self.current_scope_id));
+            }
             AstKind::Function(func) => {
+                func.scope_id.set(Some(self.current_scope_id));
                 self.function_stack.push(self.current_node_id);
                 func.bind(self);
                 self.make_all_namespaces_valuelike();
             }
-            AstKind::ArrowExpression(_) => {
+            AstKind::ArrowExpression(expr) => {
+                expr.scope_id.set(Some(self.current_scope_id));
                 self.function_stack.push(self.current_node_id);
                 self.make_all_namespaces_valuelike();
             }
+            AstKind::FunctionBody(body) => {
+                body.scope_id.set(Some(self.current_scope_id));
+            }
+            AstKind::StaticBlock(block) => {
+                block.scope_id.set(Some(self.current_scope_id));
+            }
             AstKind::Class(class) => {
+                if class.is_expression() {
+                    class.scope_id.set(Some(self.current_scope_id));
+                }
                 self.current_node_flags |= NodeFlags::Class;
                 class.bind(self);
                 self.make_all_namespaces_valuelike();
@@ -425,6 +464,7 @@ impl<'a> SemanticBuilder<'a> {
                 params.bind(self);
             }
             AstKind::CatchClause(clause) => {
+                clause.scope_id.set(Some(self.current_scope_id));
                 clause.bind(self);
             }
             AstKind::TSModuleDeclaration(module_declaration) => {
@@ -438,6 +478,9 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::TSTypeAliasDeclaration(type_alias_declaration) => {
                 type_alias_declaration.bind(self);
             }
+            AstKind::FlowOpaqueTypeDeclaration(opaque_type_declaration) => {
+                opaque_type_declaration.bind(self);
+            }
             AstKind::TSInterfaceDeclaration(interface_declaration) => {
                 interface_declaration.bind(self);
             }
@@ -466,7 +466,7 @@ fn check_module_declaration<'a>(
         ModuleKind::Script => {
             ctx.error(ModuleCode(text, span));
         }
-        ModuleKind::Module => {
+        ModuleKind::Module | ModuleKind::Unambiguous => {
             if matches!(ctx.nodes.parent_kind(node.id()), Some(AstKind::Program(_))) {
                 return;
             }
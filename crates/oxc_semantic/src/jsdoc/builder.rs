@@ -28,22 +28,24 @@ impl<'a> JSDocBuilder<'a> {
             return false;
         }
         let span = kind.span();
-        let comment_text = self.find_jsdoc_comment(span);
-        if let Some(comment_text) = comment_text {
-            self.docs.insert(span, JSDocComment::new(comment_text));
+        let comment_span = self.find_jsdoc_comment(span);
+        if let Some(comment_span) = comment_span {
+            let comment_text = comment_span.source_text(self.source_text);
+            self.docs.insert(span, JSDocComment::new(comment_text, comment_span));
         }
-        comment_text.is_some()
+        comment_span.is_some()
     }
 
     /// Find the jsdoc doc in front of this span, a.k.a leading comment
-    fn find_jsdoc_comment(&self, span: Span) -> Option<&'a str> {
+    fn find_jsdoc_comment(&self, span: Span) -> Option<Span> {
         let (start, comment) = self.trivias.comments().range(..span.start).next()?;
 
         if comment.kind().is_single_line() {
             return None;
         }
 
-        let comment_text = Span::new(*start, comment.end()).source_text(self.source_text);
+        let comment_span = Span::new(*start, comment.end());
+        let comment_text = comment_span.source_text(self.source_text);
 
         // Comments beginning with /*, /***, or more than 3 stars will be ignored.
         let mut chars = comment_text.chars();
@@ -61,7 +63,7 @@ impl<'a> JSDocBuilder<'a> {
             return None;
         }
 
-        Some(comment_text)
+        Some(comment_span)
     }
 }
 
@@ -71,7 +73,10 @@ mod test {
     use oxc_parser::Parser;
     use oxc_span::{SourceType, Span};
 
-    use crate::{jsdoc::JSDocComment, SemanticBuilder};
+    use crate::{
+        jsdoc::{JSDocComment, JSDocTagKind},
+        SemanticBuilder,
+    };
 
     #[allow(clippy::cast_possible_truncation)]
     fn get_jsdoc<'a>(
@@ -142,4 +147,21 @@ mod test {
             test_jsdoc(source_text, "function foo() {}");
         }
     }
+
+    #[test]
+    fn tags() {
+        let allocator = Allocator::default();
+        let source_text = "
+            /**
+             * @param {string} a - the a
+             */
+            function foo(a) {}";
+        let jsdoc = get_jsdoc(&allocator, source_text, "function foo(a) {}").unwrap();
+        let tags = jsdoc.tags();
+        assert_eq!(tags.len(), 1);
+        let JSDocTagKind::Param(param) = &tags[0].kind else { panic!() };
+        assert_eq!(param.name.unwrap().span.source_text(source_text), "a");
+        assert_eq!(param.r#type.as_ref().unwrap().span.source_text(source_text), "{string}");
+        assert_eq!(tags[0].span.source_text(source_text), "@param {string} a - the a");
+    }
 }
@@ -6,10 +6,16 @@ pub use builder::JSDocBuilder;
 use oxc_span::{GetSpan, Span};
 
 use self::parser::JSDocParser;
-pub use self::parser::JSDocTag;
+pub use self::{
+    tag::{JSDocParam, JSDocTag, JSDocTagKind, JSDocTypeExpression},
+    types::{JSDocName, JSDocRecordField, JSDocType},
+};
 use crate::AstNode;
 
 mod parser;
+mod tag;
+mod type_parser;
+mod types;
 
 #[derive(Debug)]
 pub struct JSDoc<'a> {
@@ -20,6 +26,8 @@ pub struct JSDoc<'a> {
 #[derive(Debug, Clone)]
 pub struct JSDocComment<'a> {
     comment: &'a str,
+    /// Span of the comment text, which excludes `/*` and `*/`
+    span: Span,
     /// Cached JSDocTags
     tags: OnceCell<Vec<JSDocTag<'a>>>,
 }
@@ -43,11 +51,15 @@ impl<'a> JSDoc<'a> {
 }
 
 impl<'a> JSDocComment<'a> {
-    pub fn new(comment: &'a str) -> JSDocComment<'a> {
-        Self { comment, tags: OnceCell::new() }
+    pub fn new(comment: &'a str, span: Span) -> JSDocComment<'a> {
+        Self { comment, span, tags: OnceCell::new() }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
     }
 
     pub fn tags<'b>(&'b self) -> &'b Vec<JSDocTag<'a>> {
-        self.tags.get_or_init(|| JSDocParser::new(self.comment).parse())
+        self.tags.get_or_init(|| JSDocParser::new(self.comment, self.span.start).parse())
     }
 }
@@ -1,306 +1,496 @@
-use std::str::FromStr;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ParamTypeKind {
-    Any,
-    Repeated,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ParamType<'a> {
-    value: &'a str,
+use oxc_span::Span;
+
+use super::{
+    tag::{JSDocParam, JSDocTag, JSDocTagKind, JSDocTypeExpression},
+    type_parser::TypeParser,
+    types::JSDocName,
+};
+
+/// Parser of the block tags of a JSDoc comment.
+///
+/// The source text is the comment without `/*` and `*/`, starting with the second `*` of `/**`.
+/// A tag starts with `@` at the start of a line, after the leading `*`, and runs until the
+/// next tag or the end of the comment.
+#[derive(Debug)]
+pub struct JSDocParser<'a> {
+    source_text: &'a str,
+    /// Offset of `source_text` in the source, for the spans
+    offset: u32,
+    current: usize,
+    /// End of the tag being parsed
+    end: usize,
 }
 
-impl<'a> ParamType<'a> {
-    #[allow(unused)]
-    pub fn kind(&self) -> Option<ParamTypeKind> {
-        ParamTypeKind::from_str(self.value).map(Option::Some).unwrap_or_default()
+impl<'a> JSDocParser<'a> {
+    pub fn new(source_text: &'a str, offset: u32) -> Self {
+        Self { source_text, offset, current: 0, end: 0 }
     }
-}
 
-impl FromStr for ParamTypeKind {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // TODO: This might be inaccurate if the type is listed as {....string} or some variant
-        if s.len() > 3 && &s[0..3] == "..." {
-            return Ok(Self::Repeated);
-        }
+    pub fn parse(mut self) -> Vec<JSDocTag<'a>> {
+        let lines = self.content_lines();
+        let tag_starts = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, (start, end))| {
+                let content = &self.source_text[*start..*end];
+                content.strip_prefix('@').is_some_and(|rest| rest.starts_with(is_tag_name_part))
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
 
-        if s == "*" {
-            return Ok(Self::Any);
+        let mut tags = vec![];
+        for (i, &line) in tag_starts.iter().enumerate() {
+            let next_line = tag_starts.get(i + 1).copied().unwrap_or(lines.len());
+            let start = lines[line].0;
+            // The tag ends at the end of its last non-empty line
+            let end = lines[line..next_line]
+                .iter()
+                .rev()
+                .find(|(start, end)| start < end)
+                .map_or(lines[line].1, |(_, end)| *end);
+            tags.push(self.parse_tag(start, end));
         }
-
-        Err(())
+        tags
     }
-}
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Param<'a> {
-    name: &'a str,
-    r#type: Option<ParamType<'a>>,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum JSDocTagKind<'a> {
-    Deprecated,
-    Param(Param<'a>),
-}
-
-impl<'a> FromStr for JSDocTagKind<'a> {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "deprecated" => Ok(Self::Deprecated),
-            "param" => Ok(Self::Param(Param::default())),
-            _ => Err(()),
+    /// The ranges of the content of the lines, without the leading `*` and the surrounding
+    /// whitespace.
+    fn content_lines(&self) -> Vec<(usize, usize)> {
+        let mut lines = vec![];
+        let mut line_start = 0;
+        for line in self.source_text.split('\n') {
+            let leading = line.len() - line.trim_start().len();
+            let mut content = &line[leading..];
+            if let Some(rest) = content.strip_prefix('*') {
+                content = rest;
+            }
+            let content_start = line.len() - content.trim_start().len();
+            let content_end = line.trim_end().len().max(content_start);
+            lines.push((line_start + content_start, line_start + content_end));
+            line_start += line.len() + 1;
         }
+        lines
     }
-}
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct JSDocTag<'a> {
-    pub kind: JSDocTagKind<'a>,
-    pub description: &'a str,
-}
+    /* ----------  Cursor ---------- */
 
-impl<'a> JSDocTag<'a> {
-    pub fn is_deprecated(&self) -> bool {
-        matches!(self.kind, JSDocTagKind::Deprecated)
-    }
-}
-
-#[derive(Debug)]
-pub struct JSDocParser<'a> {
-    source_text: &'a str,
-    current: usize,
-}
-
-impl<'a> JSDocParser<'a> {
-    pub fn new(source_text: &'a str) -> Self {
-        Self { source_text, current: 0 }
-    }
-
-    pub fn parse(mut self) -> Vec<JSDocTag<'a>> {
-        self.parse_comment(self.source_text)
+    fn peek(&self) -> Option<char> {
+        self.source_text[self.current..self.end].chars().next()
     }
 
     fn advance(&mut self) {
-        if self.current < self.source_text.len() {
-            self.current += 1;
+        if let Some(c) = self.peek() {
+            self.current += c.len_utf8();
         }
     }
 
-    fn at(&mut self, c: char) -> bool {
-        let Some(ch) = self.source_text.chars().nth(self.current) else { return false };
-        if ch == c {
-            self.advance();
-            true
-        } else {
-            false
+    /// Skip whitespace, and the leading `*` of the next lines
+    fn skip_trivia(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                '\n' => {
+                    self.advance();
+                    while self.peek().is_some_and(|c| c.is_whitespace() && c != '\n') {
+                        self.advance();
+                    }
+                    if self.peek() == Some('*') {
+                        self.advance();
+                    }
+                }
+                c if c.is_whitespace() => self.advance(),
+                _ => break,
+            }
         }
     }
 
-    fn take_until(&mut self, s: &'a str, predicate: fn(char) -> bool) -> &'a str {
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
         let start = self.current;
-        while let Some(c) = s.chars().nth(self.current) {
-            if predicate(c) {
-                break;
-            }
-            self.current += 1;
+        while self.peek().is_some_and(&predicate) {
+            self.advance();
         }
-        &s[start..self.current]
+        &self.source_text[start..self.current]
     }
 
-    fn skip_whitespace(&mut self, s: &'a str) {
-        while let Some(c) = s.chars().nth(self.current) {
-            if c != ' ' {
-                break;
-            }
-            self.current += 1;
-        }
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span::new(self.offset + start as u32, self.offset + end as u32)
     }
 
-    fn parse_comment(&mut self, comment: &'a str) -> Vec<JSDocTag<'a>> {
-        let mut tags = vec![];
+    /* ----------  Tags ---------- */
+
+    /// Parse the tag from the `@` at `start` to `end`
+    fn parse_tag(&mut self, start: usize, end: usize) -> JSDocTag<'a> {
+        self.current = start + 1;
+        self.end = end;
+        let name = self.take_while(is_tag_name_part);
+        let tag_name = JSDocName { span: self.span(start + 1, self.current), name };
+
+        let kind = match JSDocTagKind::from_tag_name(name) {
+            JSDocTagKind::Param(_) => JSDocTagKind::Param(self.parse_param()),
+            JSDocTagKind::Property(_) => JSDocTagKind::Property(self.parse_param()),
+            JSDocTagKind::Returns(_) => JSDocTagKind::Returns(self.parse_type_expression()),
+            JSDocTagKind::Throws(_) => JSDocTagKind::Throws(self.parse_type_expression()),
+            JSDocTagKind::Type(_) => JSDocTagKind::Type(self.parse_type_expression()),
+            JSDocTagKind::Typedef { .. } => {
+                let r#type = self.parse_type_expression();
+                JSDocTagKind::Typedef { r#type, name: self.parse_name() }
+            }
+            JSDocTagKind::Template { .. } => {
+                let constraint = self.parse_type_expression();
+                JSDocTagKind::Template { constraint, names: self.parse_template_names() }
+            }
+            kind => kind,
+        };
+
+        let hyphen = matches!(kind, JSDocTagKind::Param(_) | JSDocTagKind::Property(_));
+        let description = self.parse_description(hyphen);
+        JSDocTag { span: self.span(start, end), tag_name, kind, description }
+    }
 
-        while let Some(c) = comment.chars().nth(self.current) {
+    /// `{type}`, the type runs until a whitespace if the brace is not closed
+    fn parse_type_expression(&mut self) -> Option<JSDocTypeExpression<'a>> {
+        self.skip_trivia();
+        if self.peek() != Some('{') {
+            return None;
+        }
+        let start = self.current;
+        self.advance();
+        let inner_start = self.current;
+        let mut depth = 0;
+        let mut inner_end = None;
+        while let Some(c) = self.peek() {
             match c {
-                '@' => {
-                    self.current += 1;
-                    let Some(tag) = self.parse_tag(comment) else { break };
-                    self.current += tag.description.len();
-                    tags.push(tag);
-                }
-                _ => {
-                    self.current += 1;
+                '{' => depth += 1,
+                '}' if depth == 0 => {
+                    inner_end = Some(self.current);
+                    self.advance();
+                    break;
                 }
+                '}' => depth -= 1,
+                _ => {}
             }
+            self.advance();
         }
-
-        tags
-    }
-
-    fn parse_tag(&mut self, comment: &'a str) -> Option<JSDocTag<'a>> {
-        let tag = self.take_until(comment, |c| c == ' ' || c == '\n');
-        JSDocTagKind::from_str(tag).map_or(None, |kind| match kind {
-            JSDocTagKind::Deprecated => Some(self.parse_deprecated_tag(comment)),
-            JSDocTagKind::Param { .. } => Some(self.parse_param_tag(comment)),
-        })
+        let inner_end = inner_end.unwrap_or_else(|| {
+            self.current = inner_start;
+            self.take_while(|c| !c.is_whitespace());
+            self.current
+        });
+
+        let raw = &self.source_text[inner_start..inner_end];
+        #[allow(clippy::cast_possible_truncation)]
+        let ty = TypeParser::new(raw, self.offset + inner_start as u32).parse();
+        Some(JSDocTypeExpression { span: self.span(start, self.current), raw, ty })
     }
 
-    fn parse_deprecated_tag(&mut self, comment: &'a str) -> JSDocTag<'a> {
-        self.skip_whitespace(comment);
-        let description = self.take_until(comment, |c| c == '\n' || c == '*');
-        JSDocTag { kind: JSDocTagKind::Deprecated, description }
+    /// A name until the next whitespace
+    fn parse_name(&mut self) -> Option<JSDocName<'a>> {
+        self.skip_trivia();
+        let start = self.current;
+        let name = self.take_while(|c| !c.is_whitespace());
+        (!name.is_empty()).then(|| JSDocName { span: self.span(start, self.current), name })
     }
 
-    fn parse_param_tag(&mut self, comment: &'a str) -> JSDocTag<'a> {
-        self.skip_whitespace(comment);
-
-        let mut r#type = None;
+    /// `{type} name` or `{type} [name=default]`
+    fn parse_param(&mut self) -> JSDocParam<'a> {
+        let r#type = self.parse_type_expression();
+        self.skip_trivia();
+        if self.peek() != Some('[') {
+            return JSDocParam { name: self.parse_name(), r#type, optional: false, default: None };
+        }
 
-        if self.at('{') {
-            // If we hit a space, then treat it as the end of the type annotation.
-            let type_annotation = self.take_until(comment, |c| c == '}' || c == ' ');
-            r#type = Some(ParamType { value: type_annotation });
-            if self.at('}') {
-                self.skip_whitespace(comment);
+        self.advance();
+        let start = self.current;
+        let mut depth = 0;
+        while let Some(c) = self.peek() {
+            match c {
+                '[' | '{' | '(' => depth += 1,
+                ']' if depth == 0 => break,
+                ']' | '}' | ')' => depth -= 1,
+                _ => {}
             }
-            self.skip_whitespace(comment);
+            self.advance();
         }
+        let inner = &self.source_text[start..self.current];
+        self.advance();
+
+        let (name, default) = match inner.split_once('=') {
+            Some((name, default)) => (name, Some(default.trim())),
+            None => (inner, None),
+        };
+        let name_start = start + (name.len() - name.trim_start().len());
+        let name = name.trim();
+        let name = (!name.is_empty())
+            .then(|| JSDocName { span: self.span(name_start, name_start + name.len()), name });
+        JSDocParam { name, r#type, optional: true, default }
+    }
 
-        let name = self.take_until(comment, |c| c == ' ' || c == '\n');
-
-        self.skip_whitespace(comment);
-        if self.at('-') {
-            self.skip_whitespace(comment);
+    /// `T, U` of `@template`
+    fn parse_template_names(&mut self) -> Vec<JSDocName<'a>> {
+        let mut names = vec![];
+        loop {
+            self.skip_trivia();
+            let start = self.current;
+            let name = self.take_while(is_tag_name_part);
+            if name.is_empty() {
+                break;
+            }
+            names.push(JSDocName { span: self.span(start, self.current), name });
+            self.skip_trivia();
+            if self.peek() != Some(',') {
+                break;
+            }
+            self.advance();
         }
+        names
+    }
 
-        let description = self.take_until(comment, |c| c == '\n' || c == '*');
-
-        JSDocTag { kind: JSDocTagKind::Param(Param { name, r#type }), description }
+    /// The rest of the tag, after an optional `-` separating it from the name
+    fn parse_description(&mut self, hyphen: bool) -> &'a str {
+        self.skip_trivia();
+        if hyphen && self.peek() == Some('-') {
+            self.advance();
+            self.skip_trivia();
+        }
+        &self.source_text[self.current..self.end]
     }
 }
 
+fn is_tag_name_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
 #[cfg(test)]
 mod test {
+    use oxc_span::Span;
+
     use super::JSDocParser;
-    use crate::jsdoc::parser::{JSDocTag, JSDocTagKind, Param, ParamType, ParamTypeKind};
+    use crate::jsdoc::{
+        tag::{JSDocParam, JSDocTag, JSDocTagKind},
+        types::{JSDocName, JSDocType},
+    };
 
-    #[test]
-    fn deduces_correct_param_kind() {
-        let param = Param { name: "a", r#type: Some(ParamType { value: "string" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), None);
+    fn parse(source: &str) -> Vec<JSDocTag> {
+        JSDocParser::new(source, 0).parse()
+    }
 
-        let param = Param { name: "a", r#type: Some(ParamType { value: "...string" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), Some(ParamTypeKind::Repeated));
+    fn kinds<'a>(tags: &'a [JSDocTag<'a>]) -> Vec<(&'a str, &'a str)> {
+        tags.iter().map(|tag| (tag.tag_name.name, tag.description)).collect()
+    }
+
+    fn param_name<'a>(tag: &'a JSDocTag<'a>) -> Option<&'a str> {
+        let JSDocTagKind::Param(param) = &tag.kind else { return None };
+        param.name.map(|name| name.name)
+    }
 
-        let param = Param { name: "a", r#type: Some(ParamType { value: "*" }) };
-        assert_eq!(param.r#type.and_then(|t| t.kind()), Some(ParamTypeKind::Any));
+    fn type_raw<'a>(tag: &'a JSDocTag<'a>) -> Option<&'a str> {
+        match &tag.kind {
+            JSDocTagKind::Param(JSDocParam { r#type, .. })
+            | JSDocTagKind::Property(JSDocParam { r#type, .. })
+            | JSDocTagKind::Returns(r#type)
+            | JSDocTagKind::Throws(r#type)
+            | JSDocTagKind::Type(r#type)
+            | JSDocTagKind::Typedef { r#type, .. } => r#type.as_ref().map(|ty| ty.raw),
+            _ => None,
+        }
     }
 
     #[test]
     fn parses_single_line_jsdoc() {
-        let source = "/** @deprecated */";
-
-        let tags = JSDocParser::new(source).parse();
+        let tags = parse("* @deprecated ");
         assert_eq!(tags.len(), 1);
-        assert_eq!(tags, vec![JSDocTag { kind: JSDocTagKind::Deprecated, description: "" }]);
+        assert!(tags[0].is_deprecated());
+        assert_eq!(tags[0].span, Span::new(2, 13));
+        assert_eq!(tags[0].tag_name, JSDocName { span: Span::new(3, 13), name: "deprecated" });
+        assert_eq!(tags[0].description, "");
     }
 
     #[test]
     fn parses_multi_line_disjoint_jsdoc() {
-        let source = r"/** @deprecated
-        */
-        ";
-
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 1);
-        assert_eq!(tags, vec![JSDocTag { kind: JSDocTagKind::Deprecated, description: "" }]);
+        let source = "* @deprecated\n        ";
+        let tags = parse(source);
+        assert_eq!(kinds(&tags), [("deprecated", "")]);
     }
 
     #[test]
-    fn parses_multiline_jsdoc() {
-        let source = r"/**
+    fn parses_multiline_jsdoc_with_descriptions() {
+        let source = "*
+        * Comment description, @param is not a tag here
         * @param a
-        * @deprecated
-        */
-       ";
-
-        let tags = JSDocParser::new(source).parse();
+        * @deprecated since version 1.0
+        *   and more
+        *
+        ";
+        let tags = parse(source);
         assert_eq!(tags.len(), 2);
+        assert_eq!(param_name(&tags[0]), Some("a"));
+        assert_eq!(tags[0].description, "");
+        assert_eq!(tags[1].description, "since version 1.0\n        *   and more");
         assert_eq!(
-            tags,
-            vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param { name: "a", r#type: None }),
-                    description: ""
-                },
-                JSDocTag { kind: JSDocTagKind::Deprecated, description: "" },
-            ]
+            tags[1].description_lines().collect::<Vec<_>>(),
+            ["since version 1.0", "  and more"]
+        );
+        assert_eq!(
+            &source[tags[1].span.start as usize..tags[1].span.end as usize],
+            "@deprecated since version 1.0\n        *   and more"
         );
     }
 
     #[test]
-    fn parses_multiline_jsdoc_with_descriptions() {
-        let source = r"/**
-        * @param a
-        * @deprecated since version 1.0
-        */
-       ";
-
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 2);
+    fn parses_param_tags() {
+        let source = "*
+        * @param {string} a
+        * @param {string b
+        * @param {string} c - description
+        * @arg {number} [d] optional
+        * @argument {number=} [e = 1] - with default
+        * @param {{a: string, b: number}} f
+        * @param {function(
+        *   string
+        * ): void} g
+        * @param h
+        * @param
+        ";
+        let tags = parse(source);
         assert_eq!(
-            tags,
-            vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param { name: "a", r#type: None }),
-                    description: ""
-                },
-                JSDocTag { kind: JSDocTagKind::Deprecated, description: "since version 1.0" },
+            tags.iter().map(param_name).collect::<Vec<_>>(),
+            [
+                Some("a"),
+                Some("b"),
+                Some("c"),
+                Some("d"),
+                Some("e"),
+                Some("f"),
+                Some("g"),
+                Some("h"),
+                None
             ]
         );
+        assert_eq!(
+            tags.iter().map(type_raw).collect::<Vec<_>>(),
+            [
+                Some("string"),
+                Some("string"),
+                Some("string"),
+                Some("number"),
+                Some("number="),
+                Some("{a: string, b: number}"),
+                Some("function(\n        *   string\n        * ): void"),
+                None,
+                None
+            ]
+        );
+        assert_eq!(tags[2].description, "description");
+        assert_eq!(tags[3].description, "optional");
+        assert_eq!(tags[4].description, "with default");
+
+        let JSDocTagKind::Param(param) = &tags[4].kind else { panic!() };
+        assert!(param.optional);
+        assert_eq!(param.default, Some("1"));
+        let name = param.name.unwrap();
+        assert_eq!(&source[name.span.start as usize..name.span.end as usize], "e");
+        let ty = param.r#type.as_ref().unwrap();
+        assert_eq!(&source[ty.span.start as usize..ty.span.end as usize], "{number=}");
+        assert!(matches!(ty.ty, Some(JSDocType::Optional { .. })));
+
+        let JSDocTagKind::Param(param) = &tags[6].kind else { panic!() };
+        assert!(matches!(param.r#type.as_ref().unwrap().ty, Some(JSDocType::Function { .. })));
     }
 
     #[test]
-    fn parses_param_type_annotation() {
-        let source = r"/**
-        * @param {string} a
-        * @param {string b
-        * @param {string} c - description
-        */
-       ";
+    fn parses_typed_tags() {
+        let source = "*
+        * @returns {Promise<string>} the result
+        * @return nothing
+        * @type {?number}
+        * @throws {TypeError} when invalid
+        * @exception oops
+        * @typedef {Object} Foo
+        * @property {string} foo - the foo
+        * @prop {number=} [bar]
+        * @template {string} K, V description
+        * @template T
+        ";
+        let tags = parse(source);
+        assert_eq!(
+            kinds(&tags),
+            [
+                ("returns", "the result"),
+                ("return", "nothing"),
+                ("type", ""),
+                ("throws", "when invalid"),
+                ("exception", "oops"),
+                ("typedef", ""),
+                ("property", "the foo"),
+                ("prop", ""),
+                ("template", "description"),
+                ("template", "")
+            ]
+        );
+        assert_eq!(
+            tags.iter().map(type_raw).collect::<Vec<_>>(),
+            [
+                Some("Promise<string>"),
+                None,
+                Some("?number"),
+                Some("TypeError"),
+                None,
+                Some("Object"),
+                Some("string"),
+                Some("number="),
+                None,
+                None
+            ]
+        );
+
+        let JSDocTagKind::Typedef { name, .. } = &tags[5].kind else { panic!() };
+        assert_eq!(name.map(|name| name.name), Some("Foo"));
+        let JSDocTagKind::Template { constraint, names } = &tags[8].kind else { panic!() };
+        assert_eq!(constraint.as_ref().map(|ty| ty.raw), Some("string"));
+        assert_eq!(names.iter().map(|name| name.name).collect::<Vec<_>>(), ["K", "V"]);
+        let JSDocTagKind::Template { names, .. } = &tags[9].kind else { panic!() };
+        assert_eq!(names.iter().map(|name| name.name).collect::<Vec<_>>(), ["T"]);
+    }
 
-        let tags = JSDocParser::new(source).parse();
-        assert_eq!(tags.len(), 3);
+    #[test]
+    fn parses_description_tags() {
+        let source = "*
+        * @example
+        * function foo() {
+        *   return 1;
+        * }
+        * @see {@link foo} for more
+        * @since 1.0.0
+        * @customTag custom
+        ";
+        let tags = parse(source);
         assert_eq!(
-            tags,
-            vec![
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "a",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: ""
-                },
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "b",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: ""
-                },
-                JSDocTag {
-                    kind: JSDocTagKind::Param(Param {
-                        name: "c",
-                        r#type: Some(ParamType { value: "string" })
-                    }),
-                    description: "description"
-                },
+            tags.iter().map(|tag| &tag.kind).collect::<Vec<_>>(),
+            [
+                &JSDocTagKind::Example,
+                &JSDocTagKind::See,
+                &JSDocTagKind::Since,
+                &JSDocTagKind::Unknown
             ]
         );
+        assert_eq!(
+            tags[0].description_lines().collect::<Vec<_>>(),
+            ["function foo() {", "  return 1;", "}"]
+        );
+        assert_eq!(tags[1].description, "{@link foo} for more");
+        assert_eq!(tags[2].description, "1.0.0");
+        assert_eq!(tags[3].description, "custom");
+    }
+
+    #[test]
+    fn offsets_spans() {
+        let tags = JSDocParser::new("* @type {string}", 10).parse();
+        assert_eq!(tags[0].span, Span::new(12, 26));
+        let JSDocTagKind::Type(Some(ty)) = &tags[0].kind else { panic!() };
+        assert_eq!(ty.span, Span::new(18, 26));
+        assert_eq!(
+            ty.ty,
+            Some(JSDocType::Name(JSDocName { span: Span::new(19, 25), name: "string" }))
+        );
     }
 }
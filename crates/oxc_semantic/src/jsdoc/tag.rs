@@ -0,0 +1,102 @@
+use oxc_span::Span;
+
+use super::types::{JSDocName, JSDocType};
+
+/// A block tag of a JSDoc comment, e.g. `@param {string} a - description`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocTag<'a> {
+    /// From the `@` to the end of the description
+    pub span: Span,
+    /// The name without the `@`, e.g. `param`
+    pub tag_name: JSDocName<'a>,
+    pub kind: JSDocTagKind<'a>,
+    /// The text after the type and the name of the tag, as written in the source.
+    /// Use [JSDocTag::description_lines] for the text without the leading `*` of the lines.
+    pub description: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JSDocTagKind<'a> {
+    /// `@deprecated`
+    Deprecated,
+    /// `@example`
+    Example,
+    /// `@param`, `@arg` or `@argument`
+    Param(JSDocParam<'a>),
+    /// `@property` or `@prop`
+    Property(JSDocParam<'a>),
+    /// `@returns` or `@return`
+    Returns(Option<JSDocTypeExpression<'a>>),
+    /// `@see`
+    See,
+    /// `@since`
+    Since,
+    /// `@template {constraint} T, U`
+    Template { constraint: Option<JSDocTypeExpression<'a>>, names: Vec<JSDocName<'a>> },
+    /// `@throws` or `@exception`
+    Throws(Option<JSDocTypeExpression<'a>>),
+    /// `@type`
+    Type(Option<JSDocTypeExpression<'a>>),
+    /// `@typedef {type} Name`
+    Typedef { r#type: Option<JSDocTypeExpression<'a>>, name: Option<JSDocName<'a>> },
+    /// Any other tag, the whole text after the tag name is the description
+    Unknown,
+}
+
+impl<'a> JSDocTagKind<'a> {
+    pub fn from_tag_name(name: &str) -> Self {
+        match name {
+            "deprecated" => Self::Deprecated,
+            "example" => Self::Example,
+            "param" | "arg" | "argument" => Self::Param(JSDocParam::default()),
+            "property" | "prop" => Self::Property(JSDocParam::default()),
+            "returns" | "return" => Self::Returns(None),
+            "see" => Self::See,
+            "since" => Self::Since,
+            "template" => Self::Template { constraint: None, names: vec![] },
+            "throws" | "exception" => Self::Throws(None),
+            "type" => Self::Type(None),
+            "typedef" => Self::Typedef { r#type: None, name: None },
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// `{type} name` or `{type} [name=default]` of `@param` and `@property`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JSDocParam<'a> {
+    pub name: Option<JSDocName<'a>>,
+    pub r#type: Option<JSDocTypeExpression<'a>>,
+    /// Whether the name is in brackets, `[name]`
+    pub optional: bool,
+    /// `default` in `[name=default]`
+    pub default: Option<&'a str>,
+}
+
+/// The type in braces of a tag, `{string}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocTypeExpression<'a> {
+    /// Including the braces
+    pub span: Span,
+    /// The text in the braces
+    pub raw: &'a str,
+    /// `None` if the type is invalid
+    pub ty: Option<JSDocType<'a>>,
+}
+
+impl<'a> JSDocTag<'a> {
+    pub fn is_deprecated(&self) -> bool {
+        matches!(self.kind, JSDocTagKind::Deprecated)
+    }
+
+    /// The lines of the description, without the leading `*` and whitespace.
+    /// The indentation after the `*` is kept, for `@example`.
+    pub fn description_lines(&self) -> impl Iterator<Item = &'a str> {
+        self.description.lines().map(|line| {
+            let line = line.trim_start();
+            let line =
+                line.strip_prefix('*').map_or(line, |line| line.strip_prefix(' ').unwrap_or(line));
+            line.trim_end()
+        })
+    }
+}
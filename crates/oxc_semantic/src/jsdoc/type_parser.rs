@@ -0,0 +1,645 @@
+use oxc_span::Span;
+
+use super::types::{JSDocName, JSDocRecordField, JSDocType};
+
+/// Parser of the type expression in the braces of a tag, e.g. `string|number` in
+/// `@param {string|number} a`.
+///
+/// Type expressions can span multiple lines of a comment, the leading `*` of the lines are
+/// skipped as whitespace.
+pub struct TypeParser<'a> {
+    source_text: &'a str,
+    /// Offset of `source_text` in the source, for the spans
+    offset: u32,
+    current: usize,
+}
+
+impl<'a> TypeParser<'a> {
+    pub fn new(source_text: &'a str, offset: u32) -> Self {
+        Self { source_text, offset, current: 0 }
+    }
+
+    /// Returns `None` if the type expression is invalid.
+    pub fn parse(mut self) -> Option<JSDocType<'a>> {
+        let ty = self.parse_type()?;
+        self.skip_trivia();
+        (self.current == self.source_text.len()).then_some(ty)
+    }
+
+    /* ----------  Cursor ---------- */
+
+    fn peek(&self) -> Option<char> {
+        self.source_text[self.current..].chars().next()
+    }
+
+    fn advance(&mut self) {
+        if let Some(c) = self.peek() {
+            self.current += c.len_utf8();
+        }
+    }
+
+    /// Skip whitespace, and the leading `*` of the next lines
+    fn skip_trivia(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                '\n' => {
+                    self.advance();
+                    while self.peek().is_some_and(|c| c.is_whitespace() && c != '\n') {
+                        self.advance();
+                    }
+                    if self.peek() == Some('*') {
+                        self.advance();
+                    }
+                }
+                c if c.is_whitespace() => self.advance(),
+                _ => break,
+            }
+        }
+    }
+
+    fn at(&mut self, c: char) -> bool {
+        self.skip_trivia();
+        self.peek() == Some(c)
+    }
+
+    fn at_str(&mut self, s: &str) -> bool {
+        self.skip_trivia();
+        self.source_text[self.current..].starts_with(s)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let at = self.at(c);
+        if at {
+            self.advance();
+        }
+        at
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let at = self.at_str(s);
+        if at {
+            self.current += s.len();
+        }
+        at
+    }
+
+    fn expect(&mut self, c: char) -> Option<()> {
+        self.eat(c).then_some(())
+    }
+
+    /// The identifier at the cursor, without consuming it
+    fn peek_word(&mut self) -> &'a str {
+        self.skip_trivia();
+        let rest = &self.source_text[self.current..];
+        let len = rest.find(|c| !is_identifier_part(c)).unwrap_or(rest.len());
+        &rest[..len]
+    }
+
+    /// Whether the type after the `word` at the cursor starts with `c`, e.g. `(` after
+    /// `function`.
+    fn is_followed_by(&mut self, word: &str, c: char) -> bool {
+        let current = self.current;
+        self.current += word.len();
+        let followed = self.at(c);
+        self.current = current;
+        followed
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize) -> Span {
+        Span::new(self.offset + start as u32, self.offset + self.current as u32)
+    }
+
+    /// The text from `start` to the cursor
+    fn text(&self, start: usize) -> &'a str {
+        &self.source_text[start..self.current]
+    }
+
+    /* ----------  Types ---------- */
+
+    /// `A|B`
+    fn parse_type(&mut self) -> Option<JSDocType<'a>> {
+        self.skip_trivia();
+        let start = self.current;
+        let first = self.parse_intersection_type()?;
+        if !self.at('|') {
+            return Some(first);
+        }
+        let mut types = vec![first];
+        while self.eat('|') {
+            types.push(self.parse_intersection_type()?);
+        }
+        Some(JSDocType::Union { span: self.span(start), types })
+    }
+
+    /// `A&B`, which binds tighter than `|`
+    fn parse_intersection_type(&mut self) -> Option<JSDocType<'a>> {
+        self.skip_trivia();
+        let start = self.current;
+        let first = self.parse_prefix_type()?;
+        if !self.at('&') {
+            return Some(first);
+        }
+        let mut types = vec![first];
+        while self.eat('&') {
+            types.push(self.parse_prefix_type()?);
+        }
+        Some(JSDocType::Intersection { span: self.span(start), types })
+    }
+
+    /// `?T`, `!T`, `...T` and `?`
+    fn parse_prefix_type(&mut self) -> Option<JSDocType<'a>> {
+        self.skip_trivia();
+        let start = self.current;
+        if self.eat_str("...") {
+            let ty = Box::new(self.parse_prefix_type()?);
+            return Some(JSDocType::Variadic { span: self.span(start), ty });
+        }
+        if self.eat('?') {
+            if !self.at_type_start() {
+                return Some(JSDocType::Unknown(self.span(start)));
+            }
+            let ty = Box::new(self.parse_prefix_type()?);
+            return Some(JSDocType::Nullable { span: self.span(start), ty, postfix: false });
+        }
+        if self.eat('!') {
+            let ty = Box::new(self.parse_prefix_type()?);
+            return Some(JSDocType::NonNullable { span: self.span(start), ty, postfix: false });
+        }
+        self.parse_postfix_type()
+    }
+
+    fn at_type_start(&mut self) -> bool {
+        self.skip_trivia();
+        self.peek()
+            .is_some_and(|c| !matches!(c, '|' | '&' | ',' | ')' | '>' | ']' | '}' | '=' | ':'))
+    }
+
+    /// `T[]`, `T=`, `T?` and `T!`
+    fn parse_postfix_type(&mut self) -> Option<JSDocType<'a>> {
+        self.skip_trivia();
+        let start = self.current;
+        let mut ty = self.parse_primary_type()?;
+        loop {
+            ty = if self.eat_str("[]") {
+                JSDocType::Array { span: self.span(start), element: Box::new(ty) }
+            } else if self.at('=') && !self.at_str("=>") {
+                self.advance();
+                JSDocType::Optional { span: self.span(start), ty: Box::new(ty) }
+            } else if self.eat('?') {
+                JSDocType::Nullable { span: self.span(start), ty: Box::new(ty), postfix: true }
+            } else if self.eat('!') {
+                JSDocType::NonNullable { span: self.span(start), ty: Box::new(ty), postfix: true }
+            } else {
+                return Some(ty);
+            };
+        }
+    }
+
+    fn parse_primary_type(&mut self) -> Option<JSDocType<'a>> {
+        self.skip_trivia();
+        let start = self.current;
+        match self.peek()? {
+            '*' => {
+                self.advance();
+                Some(JSDocType::All(self.span(start)))
+            }
+            '(' if self.is_arrow_function() => self.parse_arrow_function(),
+            '(' => {
+                self.advance();
+                let ty = self.parse_type()?;
+                self.expect(')')?;
+                Some(ty)
+            }
+            '{' => self.parse_record(),
+            '[' => {
+                self.advance();
+                let elements = self.parse_list(']')?;
+                Some(JSDocType::Tuple { span: self.span(start), elements })
+            }
+            '\'' | '"' => {
+                let value = self.parse_string()?;
+                Some(JSDocType::StringLiteral { span: self.span(start), value })
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                self.advance();
+                while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '.') {
+                    self.advance();
+                }
+                Some(JSDocType::NumberLiteral { span: self.span(start), raw: self.text(start) })
+            }
+            c if is_identifier_start(c) => match self.peek_word() {
+                "function" if self.is_followed_by("function", '(') => self.parse_function(),
+                "import" if self.is_followed_by("import", '(') => self.parse_import(),
+                "typeof" if self.is_followed_by("typeof", ' ') || self.word_follows("typeof") => {
+                    self.current += "typeof".len();
+                    let name = self.parse_name()?;
+                    Some(JSDocType::TypeOf { span: self.span(start), name })
+                }
+                "keyof" if self.word_follows("keyof") => {
+                    self.current += "keyof".len();
+                    let ty = Box::new(self.parse_prefix_type()?);
+                    Some(JSDocType::KeyOf { span: self.span(start), ty })
+                }
+                _ => {
+                    let name = JSDocType::Name(self.parse_name()?);
+                    self.parse_generic(start, name)
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether another word follows `word` at the cursor, e.g. `keyof T`
+    fn word_follows(&mut self, word: &str) -> bool {
+        let rest = &self.source_text[self.current + word.len()..];
+        rest.starts_with(char::is_whitespace)
+            && rest.trim_start().starts_with(|c| is_identifier_start(c) || c == '(')
+    }
+
+    /// `Array.<T>` or `Array<T>`
+    fn parse_generic(&mut self, start: usize, base: JSDocType<'a>) -> Option<JSDocType<'a>> {
+        if !self.eat_str(".<") && !self.eat('<') {
+            return Some(base);
+        }
+        let arguments = self.parse_list('>')?;
+        Some(JSDocType::Generic { span: self.span(start), base: Box::new(base), arguments })
+    }
+
+    /// Types separated by commas, until `end`
+    fn parse_list(&mut self, end: char) -> Option<Vec<JSDocType<'a>>> {
+        let mut types = vec![];
+        while !self.eat(end) {
+            if !types.is_empty() {
+                self.expect(',')?;
+                if self.eat(end) {
+                    break;
+                }
+            }
+            types.push(self.parse_type()?);
+        }
+        Some(types)
+    }
+
+    /// A name path: `Foo`, `Foo.bar`, `Foo#bar`, `Foo~bar` or `module:foo/bar`
+    fn parse_name(&mut self) -> Option<JSDocName<'a>> {
+        self.skip_trivia();
+        let start = self.current;
+        if self.peek_word().is_empty() {
+            return None;
+        }
+        loop {
+            let word = self.peek_word();
+            self.current += word.len();
+            let rest = &self.source_text[self.current..];
+            let mut chars = rest.chars();
+            let separator = chars.next();
+            let next = chars.next();
+            let continues = match separator {
+                Some('.' | '#' | '~') => next.is_some_and(is_identifier_start),
+                Some(':') => {
+                    matches!(word, "module" | "external" | "event")
+                        && next.is_some_and(is_identifier_start)
+                }
+                Some('/' | '-') => {
+                    self.text(start).starts_with("module:") && next.is_some_and(is_identifier_part)
+                }
+                _ => false,
+            };
+            if !continues {
+                break;
+            }
+            self.advance();
+        }
+        Some(JSDocName { span: self.span(start), name: self.text(start) })
+    }
+
+    /// The value of a string literal at the cursor, without the quotes
+    fn parse_string(&mut self) -> Option<&'a str> {
+        let quote = self.peek()?;
+        self.advance();
+        let start = self.current;
+        loop {
+            match self.peek()? {
+                '\\' => {
+                    self.advance();
+                    self.advance();
+                }
+                c if c == quote => break,
+                _ => self.advance(),
+            }
+        }
+        let value = self.text(start);
+        self.advance();
+        Some(value)
+    }
+
+    /// `{a: A, b?: B, c, [key: string]: D}`
+    fn parse_record(&mut self) -> Option<JSDocType<'a>> {
+        let start = self.current;
+        self.expect('{')?;
+        let mut fields = vec![];
+        while !self.eat('}') {
+            if !fields.is_empty() {
+                if !self.eat(',') {
+                    self.expect(';')?;
+                }
+                if self.eat('}') {
+                    break;
+                }
+            }
+            self.skip_trivia();
+            let field_start = self.current;
+            let mut index_type = None;
+            let key = match self.peek()? {
+                '[' => {
+                    self.advance();
+                    let key = self.parse_record_key()?;
+                    self.expect(':')?;
+                    index_type = Some(self.parse_type()?);
+                    self.expect(']')?;
+                    key
+                }
+                '\'' | '"' => {
+                    let value = self.parse_string()?;
+                    JSDocName { span: self.span(field_start), name: value }
+                }
+                _ => self.parse_record_key()?,
+            };
+            let optional = self.eat('?');
+            let ty = if self.eat(':') { Some(self.parse_type()?) } else { None };
+            fields.push(JSDocRecordField {
+                span: self.span(field_start),
+                key,
+                index_type,
+                optional,
+                ty,
+            });
+        }
+        Some(JSDocType::Record { span: self.span(start), fields })
+    }
+
+    /// The identifier key of a record field
+    fn parse_record_key(&mut self) -> Option<JSDocName<'a>> {
+        let word = self.peek_word();
+        if word.is_empty() {
+            return None;
+        }
+        let start = self.current;
+        self.current += word.len();
+        Some(JSDocName { span: self.span(start), name: word })
+    }
+
+    /// `function(this:T, new:T, A, ...B): R`
+    fn parse_function(&mut self) -> Option<JSDocType<'a>> {
+        let start = self.current;
+        self.current += "function".len();
+        self.expect('(')?;
+        let mut this_type = None;
+        let mut new_type = None;
+        let mut params = vec![];
+        let mut first = true;
+        while !self.eat(')') {
+            if !first {
+                self.expect(',')?;
+            }
+            first = false;
+            match self.peek_word() {
+                word @ ("this" | "new") if self.is_followed_by(word, ':') => {
+                    self.current += word.len();
+                    self.expect(':')?;
+                    let ty = Some(Box::new(self.parse_type()?));
+                    if word == "this" {
+                        this_type = ty;
+                    } else {
+                        new_type = ty;
+                    }
+                }
+                _ => params.push(self.parse_type()?),
+            }
+        }
+        let returns = if self.eat(':') { Some(Box::new(self.parse_prefix_type()?)) } else { None };
+        Some(JSDocType::Function { span: self.span(start), this_type, new_type, params, returns })
+    }
+
+    /// Whether the parenthesis at the cursor starts the parameters of `(a: A) => R`
+    fn is_arrow_function(&mut self) -> bool {
+        let rest = &self.source_text[self.current..];
+        let mut depth = 0;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return rest[i + 1..]
+                            .trim_start_matches(|c: char| c.is_whitespace() || c == '*')
+                            .starts_with("=>");
+                    }
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// `(a: A, b?: B, ...c: C) => R`
+    fn parse_arrow_function(&mut self) -> Option<JSDocType<'a>> {
+        let start = self.current;
+        self.expect('(')?;
+        let mut params = vec![];
+        while !self.eat(')') {
+            if !params.is_empty() {
+                self.expect(',')?;
+            }
+            self.skip_trivia();
+            let param_start = self.current;
+            let rest = self.eat_str("...");
+            let name = self.parse_name()?;
+            let optional = self.eat('?');
+            let mut ty = if self.eat(':') { self.parse_type()? } else { JSDocType::Name(name) };
+            if optional {
+                ty = JSDocType::Optional { span: self.span(param_start), ty: Box::new(ty) };
+            }
+            if rest {
+                ty = JSDocType::Variadic { span: self.span(param_start), ty: Box::new(ty) };
+            }
+            params.push(ty);
+        }
+        if !self.eat_str("=>") {
+            return None;
+        }
+        let returns = Some(Box::new(self.parse_type()?));
+        Some(JSDocType::Function {
+            span: self.span(start),
+            this_type: None,
+            new_type: None,
+            params,
+            returns,
+        })
+    }
+
+    /// `import('./a').B`
+    fn parse_import(&mut self) -> Option<JSDocType<'a>> {
+        let start = self.current;
+        self.current += "import".len();
+        self.expect('(')?;
+        self.skip_trivia();
+        if !matches!(self.peek()?, '\'' | '"') {
+            return None;
+        }
+        let path = self.parse_string()?;
+        self.expect(')')?;
+        let member = if self.eat('.') { Some(self.parse_name()?) } else { None };
+        Some(JSDocType::Import { span: self.span(start), path, member })
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::{GetSpan, Span};
+
+    use super::TypeParser;
+    use crate::jsdoc::types::{JSDocName, JSDocType};
+
+    fn parse(source: &str) -> Option<JSDocType> {
+        TypeParser::new(source, 0).parse()
+    }
+
+    fn name(name: &str, start: u32) -> JSDocType {
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(start, start + name.len() as u32);
+        JSDocType::Name(JSDocName { span, name })
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(parse("string"), Some(name("string", 0)));
+        for source in ["Foo.Bar", "Foo#bar", "Foo~bar", "module:foo/bar-baz", "$_a1"] {
+            assert_eq!(parse(source), Some(name(source, 0)), "{source}");
+        }
+        assert_eq!(parse(" string "), Some(name("string", 1)));
+    }
+
+    #[test]
+    fn modifiers() {
+        let nullable = |start, postfix| JSDocType::Nullable {
+            span: Span::new(0, 7),
+            ty: Box::new(name("number", start)),
+            postfix,
+        };
+        assert_eq!(parse("?number"), Some(nullable(1, false)));
+        assert_eq!(parse("number?"), Some(nullable(0, true)));
+        assert_eq!(parse("?"), Some(JSDocType::Unknown(Span::new(0, 1))));
+        assert_eq!(parse("*"), Some(JSDocType::All(Span::new(0, 1))));
+        assert_eq!(
+            parse("...number="),
+            Some(JSDocType::Variadic {
+                span: Span::new(0, 10),
+                ty: Box::new(JSDocType::Optional {
+                    span: Span::new(3, 10),
+                    ty: Box::new(name("number", 3))
+                })
+            })
+        );
+    }
+
+    #[test]
+    fn compound_types() {
+        let ty = parse("Array.<string|!Foo>").unwrap();
+        let names = ty.names().into_iter().map(|name| name.name).collect::<Vec<_>>();
+        assert_eq!(names, ["Array", "string", "Foo"]);
+        let JSDocType::Generic { arguments, .. } = ty else { panic!() };
+        assert!(matches!(arguments[0], JSDocType::Union { span: Span { start: 7, end: 18 }, .. }));
+
+        for source in [
+            "Object<string, number>",
+            "(string|number)[]",
+            "{a: number, 'b'?: string, c}",
+            "{a: string; b: number;}",
+            "[string, number]",
+            "function(this:Foo, new:Bar, string, ...number=): boolean",
+            "function()",
+            "(a: string, b?: number, ...c: Foo[]) => void",
+            "typeof foo.bar",
+            "keyof Foo",
+            "import('./a').B.C",
+            "'a'|\"b\"|1|-1.5",
+            "{a: {b: Array<{c: string}>}}",
+            "{[key: string]: number, a: string}",
+            "A & B | C",
+        ] {
+            assert!(parse(source).is_some(), "{source}");
+        }
+        assert!(matches!(parse("(string)"), Some(JSDocType::Name(_))));
+        assert!(matches!(parse("function(): string"), Some(JSDocType::Function { .. })));
+        assert!(matches!(parse("() => string|number"), Some(JSDocType::Function { .. })));
+        assert!(matches!(parse("typeof"), Some(JSDocType::Name(_))));
+    }
+
+    #[test]
+    fn intersections() {
+        let ty = parse("A&B|C&D").unwrap();
+        let JSDocType::Union { types, .. } = &ty else { panic!() };
+        assert_eq!(
+            types[0],
+            JSDocType::Intersection {
+                span: Span::new(0, 3),
+                types: vec![name("A", 0), name("B", 2)]
+            }
+        );
+        assert!(matches!(
+            types[1],
+            JSDocType::Intersection { span: Span { start: 4, end: 7 }, .. }
+        ));
+        assert!(matches!(parse("(A|B)&C"), Some(JSDocType::Intersection { .. })));
+        let names = ty.names().into_iter().map(|name| name.name).collect::<Vec<_>>();
+        assert_eq!(names, ["A", "B", "C", "D"]);
+    }
+
+    #[test]
+    fn index_signatures() {
+        // `@type {{[key: string]: Foo}}`
+        let ty = parse("{[key: string]: Foo}").unwrap();
+        let JSDocType::Record { fields, .. } = &ty else { panic!() };
+        assert_eq!(fields[0].key, JSDocName { span: Span::new(2, 5), name: "key" });
+        assert_eq!(fields[0].index_type, Some(name("string", 7)));
+        assert_eq!(fields[0].ty, Some(name("Foo", 16)));
+        let names = ty.names().into_iter().map(|name| name.name).collect::<Vec<_>>();
+        assert_eq!(names, ["string", "Foo"]);
+    }
+
+    #[test]
+    fn multiline() {
+        let ty = parse("{\n *   a: string,\n *   b: number\n * }").unwrap();
+        let JSDocType::Record { fields, .. } = &ty else { panic!() };
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[1].ty.as_ref().map(GetSpan::span), Some(Span::new(26, 32)));
+    }
+
+    #[test]
+    fn invalid() {
+        for source in [
+            "",
+            "string number",
+            "Array<string",
+            "{a: }",
+            "function(",
+            "|",
+            "(a) =>",
+            "A&",
+            "{[key]: A}",
+            "{[key: string: A}",
+        ] {
+            assert!(parse(source).is_none(), "{source}");
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use oxc_span::{GetSpan, Span};
+
+/// A name in a JSDoc comment, e.g. a tag name, a parameter name or a type name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JSDocName<'a> {
+    pub span: Span,
+    pub name: &'a str,
+}
+
+/// A JSDoc type, the `type` in `@param {type} name`
+///
+/// Covers the [Closure Compiler type grammar](https://github.com/google/closure-compiler/wiki/Types-in-the-Closure-Type-System)
+/// and the TypeScript types used in JSDoc.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JSDocType<'a> {
+    /// `*`
+    All(Span),
+    /// `?`
+    Unknown(Span),
+    /// `string`, `Foo.Bar`, `Foo#bar`, `module:foo/bar`
+    Name(JSDocName<'a>),
+    /// `'a'`, the value is without the quotes
+    StringLiteral { span: Span, value: &'a str },
+    /// `1`
+    NumberLiteral { span: Span, raw: &'a str },
+    /// `?T` or `T?`
+    Nullable { span: Span, ty: Box<JSDocType<'a>>, postfix: bool },
+    /// `!T` or `T!`
+    NonNullable { span: Span, ty: Box<JSDocType<'a>>, postfix: bool },
+    /// `T=`
+    Optional { span: Span, ty: Box<JSDocType<'a>> },
+    /// `...T`
+    Variadic { span: Span, ty: Box<JSDocType<'a>> },
+    /// `T[]`
+    Array { span: Span, element: Box<JSDocType<'a>> },
+    /// `Array.<T>` or `Array<T>`
+    Generic { span: Span, base: Box<JSDocType<'a>>, arguments: Vec<JSDocType<'a>> },
+    /// `A|B`
+    Union { span: Span, types: Vec<JSDocType<'a>> },
+    /// `A&B`
+    Intersection { span: Span, types: Vec<JSDocType<'a>> },
+    /// `{a: A, b, [key: string]: C}`
+    Record { span: Span, fields: Vec<JSDocRecordField<'a>> },
+    /// `[A, B]`
+    Tuple { span: Span, elements: Vec<JSDocType<'a>> },
+    /// `function(this:T, A): R` or `(a: A) => R`.
+    /// The names of the parameters of arrow functions are not kept.
+    Function {
+        span: Span,
+        this_type: Option<Box<JSDocType<'a>>>,
+        new_type: Option<Box<JSDocType<'a>>>,
+        params: Vec<JSDocType<'a>>,
+        returns: Option<Box<JSDocType<'a>>>,
+    },
+    /// `typeof foo`
+    TypeOf { span: Span, name: JSDocName<'a> },
+    /// `keyof T`
+    KeyOf { span: Span, ty: Box<JSDocType<'a>> },
+    /// `import('./a').B`, the path is without the quotes
+    Import { span: Span, path: &'a str, member: Option<JSDocName<'a>> },
+}
+
+/// A field of a [JSDocType::Record], `a?: A` or the index signature `[key: string]: A`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocRecordField<'a> {
+    pub span: Span,
+    /// `a`, or `key` of an index signature
+    pub key: JSDocName<'a>,
+    /// `string` of the index signature `[key: string]`
+    pub index_type: Option<JSDocType<'a>>,
+    pub optional: bool,
+    pub ty: Option<JSDocType<'a>>,
+}
+
+impl<'a> GetSpan for JSDocType<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Name(name) => name.span,
+            Self::All(span)
+            | Self::Unknown(span)
+            | Self::StringLiteral { span, .. }
+            | Self::NumberLiteral { span, .. }
+            | Self::Nullable { span, .. }
+            | Self::NonNullable { span, .. }
+            | Self::Optional { span, .. }
+            | Self::Variadic { span, .. }
+            | Self::Array { span, .. }
+            | Self::Generic { span, .. }
+            | Self::Union { span, .. }
+            | Self::Intersection { span, .. }
+            | Self::Record { span, .. }
+            | Self::Tuple { span, .. }
+            | Self::Function { span, .. }
+            | Self::TypeOf { span, .. }
+            | Self::KeyOf { span, .. }
+            | Self::Import { span, .. } => *span,
+        }
+    }
+}
+
+impl<'a> JSDocType<'a> {
+    /// The type names in this type, e.g. `Array` and `string` in `Array<string>`,
+    /// in source order.
+    pub fn names(&self) -> Vec<JSDocName<'a>> {
+        let mut names = vec![];
+        self.collect_names(&mut names);
+        names
+    }
+
+    fn collect_names(&self, names: &mut Vec<JSDocName<'a>>) {
+        match self {
+            Self::All(_)
+            | Self::Unknown(_)
+            | Self::StringLiteral { .. }
+            | Self::NumberLiteral { .. }
+            | Self::TypeOf { .. }
+            | Self::Import { .. } => {}
+            Self::Name(name) => names.push(*name),
+            Self::Nullable { ty, .. }
+            | Self::NonNullable { ty, .. }
+            | Self::Optional { ty, .. }
+            | Self::Variadic { ty, .. }
+            | Self::KeyOf { ty, .. }
+            | Self::Array { element: ty, .. } => ty.collect_names(names),
+            Self::Generic { base, arguments, .. } => {
+                base.collect_names(names);
+                arguments.iter().for_each(|ty| ty.collect_names(names));
+            }
+            Self::Union { types, .. }
+            | Self::Intersection { types, .. }
+            | Self::Tuple { elements: types, .. } => {
+                types.iter().for_each(|ty| ty.collect_names(names));
+            }
+            Self::Record { fields, .. } => {
+                fields
+                    .iter()
+                    .flat_map(|field| field.index_type.iter().chain(&field.ty))
+                    .for_each(|ty| ty.collect_names(names));
+            }
+            Self::Function { this_type, new_type, params, returns, .. } => {
+                this_type.iter().chain(new_type).for_each(|ty| ty.collect_names(names));
+                params.iter().for_each(|ty| ty.collect_names(names));
+                returns.iter().for_each(|ty| ty.collect_names(names));
+            }
+        }
+    }
+}
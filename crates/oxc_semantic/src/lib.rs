@@ -12,7 +12,10 @@ mod symbol;
 use std::{rc::Rc, sync::Arc};
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
-pub use jsdoc::{JSDoc, JSDocComment, JSDocTag};
+pub use jsdoc::{
+    JSDoc, JSDocComment, JSDocName, JSDocParam, JSDocRecordField, JSDocTag, JSDocTagKind,
+    JSDocType, JSDocTypeExpression,
+};
 use oxc_ast::{ast::IdentifierReference, AstKind, TriviasMap};
 use oxc_span::SourceType;
 pub use oxc_syntax::{
@@ -23,6 +26,7 @@ pub use oxc_syntax::{
 
 pub use crate::{
     builder::VariableInfo,
+    module_record::ModuleRecordBuilder,
     node::{AstNode, AstNodeId, AstNodes, NodeFlags},
     reference::{Reference, ReferenceFlag, ReferenceId},
     scope::ScopeTree,
@@ -92,6 +96,10 @@ impl<'a> Semantic<'a> {
         &self.symbols
     }
 
+    pub fn symbols_mut(&mut self) -> &mut SymbolTable {
+        &mut self.symbols
+    }
+
     pub fn unused_labels(&self) -> &Vec<AstNodeId> {
         &self.unused_labels
     }
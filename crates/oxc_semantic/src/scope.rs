@@ -130,16 +130,25 @@ impl ScopeTree {
         &mut self.unresolved_references[scope_id]
     }
 
-    // TODO:
-    // <https://github.com/babel/babel/blob/419644f27c5c59deb19e71aaabd417a3bc5483ca/packages/babel-traverse/src/scope/index.ts#L543>
+    /// Generate a unique identifier based on the parts of `expr`, e.g. `_a$b` for `a.b`.
+    ///
+    /// <https://github.com/babel/babel/blob/419644f27c5c59deb19e71aaabd417a3bc5483ca/packages/babel-traverse/src/scope/index.ts#L543>
     pub fn generate_uid_based_on_node(&self, expr: &Expression) -> Atom {
         let mut parts = std::vec::Vec::with_capacity(1);
         expr.gather(&mut |part| parts.push(part));
-        let name = parts.join("$");
+        self.generate_uid_based_on_name(&parts.join("$"))
+    }
+
+    /// Generate a unique identifier based on `name`, e.g. `_this`, `_this2`.
+    ///
+    /// The identifier does not collide with any binding in the program or any global reference.
+    pub fn generate_uid_based_on_name(&self, name: &str) -> Atom {
         let name = name.trim_start_matches('_');
         for i in 0.. {
             let name = Self::generate_uid(name, i);
-            if !self.has_binding(ScopeId::new(0), &name) {
+            if !self.bindings.iter().any(|bindings| bindings.contains_key(&name))
+                && !self.root_unresolved_references().contains_key(&name)
+            {
                 return name;
             }
         }
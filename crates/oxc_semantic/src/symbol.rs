@@ -20,8 +20,9 @@ pub struct SymbolTable {
     pub names: IndexVec<SymbolId, Atom>,
     pub flags: IndexVec<SymbolId, SymbolFlags>,
     pub scope_ids: IndexVec<SymbolId, ScopeId>,
-    /// Pointer to the AST Node where this symbol is declared
-    pub declarations: IndexVec<SymbolId, AstNodeId>,
+    /// Pointer to the AST Node where this symbol is declared,
+    /// `None` for symbols created after the semantic analysis, see [`SymbolTable::create_synthetic_symbol`].
+    pub declarations: IndexVec<SymbolId, Option<AstNodeId>>,
     pub resolved_references: IndexVec<SymbolId, Vec<ReferenceId>>,
     pub references: IndexVec<ReferenceId, Reference>,
 }
@@ -63,8 +64,11 @@ impl SymbolTable {
         self.scope_ids[symbol_id]
     }
 
+    /// # Panics
+    ///
+    /// When the symbol is not declared by an AST node, see [`SymbolTable::create_synthetic_symbol`].
     pub fn get_declaration(&self, symbol_id: SymbolId) -> AstNodeId {
-        self.declarations[symbol_id]
+        self.declarations[symbol_id].expect("synthetic symbols have no declaration")
     }
 
     pub fn create_symbol(
@@ -82,7 +86,20 @@ impl SymbolTable {
     }
 
     pub fn add_declaration(&mut self, node_id: AstNodeId) {
-        self.declarations.push(node_id);
+        self.declarations.push(Some(node_id));
+    }
+
+    /// Create a symbol which is not declared by a node of the analyzed AST,
+    /// e.g. a temporary variable added by a transform.
+    pub fn create_synthetic_symbol(
+        &mut self,
+        name: Atom,
+        flag: SymbolFlags,
+        scope_id: ScopeId,
+    ) -> SymbolId {
+        let symbol_id = self.create_symbol(Span::default(), name, flag, scope_id);
+        self.declarations.push(None);
+        symbol_id
     }
 
     pub fn create_reference(&mut self, reference: Reference) -> ReferenceId {
@@ -0,0 +1,122 @@
+use std::cell::Cell;
+
+use oxc_allocator::{Allocator, Box, CloneIn, Vec};
+
+use crate::{Atom, SourceType, Span};
+
+/// Structural equality of AST nodes, ignoring their positions.
+///
+/// `a + b` at the start of a file is content equal to `a + b` at its end, but their
+/// [Span]s differ. Semantic ids in cells are ignored as well.
+///
+/// Derive it for AST nodes with `#[derive(ContentEq)]` from `oxc_macros`.
+pub trait ContentEq {
+    fn content_eq(&self, other: &Self) -> bool;
+
+    #[inline]
+    fn content_ne(&self, other: &Self) -> bool {
+        !self.content_eq(other)
+    }
+}
+
+impl ContentEq for Span {
+    #[inline]
+    fn content_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: ContentEq> ContentEq for Option<T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.content_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<'alloc, T: ContentEq> ContentEq for Box<'alloc, T> {
+    #[inline]
+    fn content_eq(&self, other: &Self) -> bool {
+        self.0.content_eq(other.0)
+    }
+}
+
+impl<'alloc, T: ContentEq> ContentEq for Vec<'alloc, T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.content_eq(b))
+    }
+}
+
+impl<T: Copy> ContentEq for Cell<Option<T>> {
+    #[inline]
+    fn content_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<'a> ContentEq for &'a str {
+    #[inline]
+    fn content_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+/// Implement [ContentEq] with [PartialEq], [CloneIn] with [Copy] or [Clone] and a no-op
+/// [ShiftSpan](crate::ShiftSpan) for types without positions or allocations.
+#[macro_export]
+macro_rules! impl_ast_leaf_traits {
+    ($($ty:ty),*) => {
+        $(
+            impl $crate::ContentEq for $ty {
+                #[inline]
+                fn content_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+
+            impl<'alloc> ::oxc_allocator::CloneIn<'alloc> for $ty {
+                type Cloned = Self;
+
+                #[inline]
+                fn clone_in(&self, _: &'alloc ::oxc_allocator::Allocator) -> Self {
+                    self.clone()
+                }
+            }
+
+            impl $crate::ShiftSpan for $ty {
+                #[inline]
+                fn shift_span(&mut self, _: u32, _: u32) {}
+            }
+        )*
+    };
+}
+
+impl_ast_leaf_traits!(Atom, SourceType);
+
+macro_rules! impl_content_eq_for_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl ContentEq for $ty {
+                #[inline]
+                fn content_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_content_eq_for_primitive!(
+    bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, char
+);
+
+impl<'alloc> CloneIn<'alloc> for Span {
+    type Cloned = Self;
+
+    #[inline]
+    fn clone_in(&self, _: &'alloc Allocator) -> Self {
+        *self
+    }
+}
@@ -3,11 +3,17 @@
 //! <https://doc.rust-lang.org/beta/nightly-rustc/rustc_span>
 
 mod atom;
+mod cmp;
+mod shift;
 mod source_type;
 mod span;
 
 pub use crate::{
     atom::Atom,
-    source_type::{Language, LanguageVariant, ModuleKind, SourceType, VALID_EXTENSIONS},
+    cmp::ContentEq,
+    shift::ShiftSpan,
+    source_type::{
+        Language, LanguageVariant, ModuleKind, SourceType, UnknownExtension, VALID_EXTENSIONS,
+    },
     span::{GetSpan, Span, SPAN},
 };
@@ -0,0 +1,74 @@
+use std::cell::Cell;
+
+use oxc_allocator::{Box, Vec};
+
+use crate::Span;
+
+/// Moving AST nodes to another position in the source text.
+///
+/// `node.shift_span(from, to)` moves every span of `node`, which must start at or after
+/// `from`, by `to - from`. Nodes after an edit are reused this way when reparsing.
+///
+/// Derive it for AST nodes with `#[derive(ShiftSpan)]` from `oxc_macros`.
+pub trait ShiftSpan {
+    fn shift_span(&mut self, from: u32, to: u32);
+}
+
+impl ShiftSpan for Span {
+    #[inline]
+    fn shift_span(&mut self, from: u32, to: u32) {
+        debug_assert!(from <= self.start && from <= self.end);
+        self.start = self.start - from + to;
+        self.end = self.end - from + to;
+    }
+}
+
+impl<T: ShiftSpan> ShiftSpan for Option<T> {
+    #[inline]
+    fn shift_span(&mut self, from: u32, to: u32) {
+        if let Some(node) = self {
+            node.shift_span(from, to);
+        }
+    }
+}
+
+impl<'alloc, T: ShiftSpan> ShiftSpan for Box<'alloc, T> {
+    #[inline]
+    fn shift_span(&mut self, from: u32, to: u32) {
+        (**self).shift_span(from, to);
+    }
+}
+
+impl<'alloc, T: ShiftSpan> ShiftSpan for Vec<'alloc, T> {
+    fn shift_span(&mut self, from: u32, to: u32) {
+        for node in self.iter_mut() {
+            node.shift_span(from, to);
+        }
+    }
+}
+
+/// Semantic ids are kept, they are replaced by the next semantic analysis.
+impl<T: Copy> ShiftSpan for Cell<Option<T>> {
+    #[inline]
+    fn shift_span(&mut self, _: u32, _: u32) {}
+}
+
+impl<'a> ShiftSpan for &'a str {
+    #[inline]
+    fn shift_span(&mut self, _: u32, _: u32) {}
+}
+
+macro_rules! impl_shift_span_for_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl ShiftSpan for $ty {
+                #[inline]
+                fn shift_span(&mut self, _: u32, _: u32) {}
+            }
+        )*
+    };
+}
+
+impl_shift_span_for_primitive!(
+    bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, char
+);
@@ -7,7 +7,7 @@ use serde::Serialize;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub struct SourceType {
-    /// JavaScript or TypeScript, default JavaScript
+    /// JavaScript, TypeScript or Flow, default JavaScript
     language: Language,
 
     /// Script or Module, default Module
@@ -21,7 +21,7 @@ pub struct SourceType {
     always_strict: bool,
 }
 
-/// JavaScript or TypeScript
+/// JavaScript, TypeScript or Flow
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub enum Language {
@@ -30,6 +30,9 @@ pub enum Language {
     TypeScript {
         is_definition_file: bool,
     },
+    /// JavaScript with [Flow](https://flow.org) type annotations.
+    /// Flow files have a `.js` extension, so this is never inferred from a path.
+    Flow,
 }
 
 /// Script or Module
@@ -38,6 +41,10 @@ pub enum Language {
 pub enum ModuleKind {
     Script,
     Module,
+    /// Detected from the source: a module when it contains `import` / `export` declarations,
+    /// `import.meta` or top-level `await`, otherwise a script.
+    /// The parser resolves it, so a parsed `Program` is always a script or a module.
+    Unambiguous,
 }
 
 /// JSX for JavaScript and TypeScript
@@ -74,6 +81,10 @@ impl SourceType {
         self.module_kind == ModuleKind::Module
     }
 
+    pub fn is_unambiguous(self) -> bool {
+        self.module_kind == ModuleKind::Unambiguous
+    }
+
     pub fn module_kind(self) -> ModuleKind {
         self.module_kind
     }
@@ -90,6 +101,10 @@ impl SourceType {
         matches!(self.language, Language::TypeScript { is_definition_file: true })
     }
 
+    pub fn is_flow(self) -> bool {
+        self.language == Language::Flow
+    }
+
     pub fn is_jsx(self) -> bool {
         self.variant == LanguageVariant::Jsx
     }
@@ -116,6 +131,14 @@ impl SourceType {
         self
     }
 
+    #[must_use]
+    pub fn with_unambiguous(mut self, yes: bool) -> Self {
+        if yes {
+            self.module_kind = ModuleKind::Unambiguous;
+        }
+        self
+    }
+
     #[must_use]
     pub fn with_typescript(mut self, yes: bool) -> Self {
         if yes {
@@ -132,6 +155,14 @@ impl SourceType {
         self
     }
 
+    #[must_use]
+    pub fn with_flow(mut self, yes: bool) -> Self {
+        if yes {
+            self.language = Language::Flow;
+        }
+        self
+    }
+
     #[must_use]
     pub fn with_jsx(mut self, yes: bool) -> Self {
         if yes {
@@ -8,8 +8,59 @@ use serde::Deserialize;
 #[cfg_attr(feature = "serde", derive(Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct CompilerAssumptions {
+    /// When compiling parameters, assume that the `length` property of functions does not need to be preserved.
+    /// See <https://babeljs.io/docs/assumptions#ignorefunctionlength>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ignore_function_length: bool,
+
+    /// When spreading or destructuring an iterable, assume that it is an array, and thus it's safe to
+    /// use `.concat` and index access instead of converting it with a helper.
+    /// See <https://babeljs.io/docs/assumptions#iterableisarray>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub iterable_is_array: bool,
+
     /// When using operators that check for null or undefined, assume that they are never used with the special value document.all.
     /// See <https://babeljs.io/docs/assumptions#nodocumentall>.
     #[cfg_attr(feature = "serde", serde(default))]
     pub no_document_all: bool,
+
+    /// When using object rest, assume that destructured objects don't have symbol keys or that it's not a problem if they are not copied.
+    /// See <https://babeljs.io/docs/assumptions#objectrestnosymbols>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub object_rest_no_symbols: bool,
+
+    /// Assume that "soft privacy" is enough for private fields, and thus they can be stored as
+    /// public non-enumerable properties with a unique name (rather than using an external `WeakMap`).
+    /// See <https://babeljs.io/docs/assumptions#privatefieldsasproperties>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub private_fields_as_properties: bool,
+
+    /// When declaring classes, assume that methods don't shadow getters on the superclass
+    /// and that the program doesn't depend on methods being non-enumerable.
+    /// See <https://babeljs.io/docs/assumptions#setclassmethods>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_class_methods: bool,
+
+    /// When using computed object properties, assume that the object doesn't contain properties
+    /// that overwrite setter defined in the same object, and thus it's safe to assign them.
+    /// See <https://babeljs.io/docs/assumptions#setcomputedproperties>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_computed_properties: bool,
+
+    /// When using public class fields, assume that they don't shadow any getter in the current class,
+    /// in its subclasses or in its superclass. Thus, it's safe to assign them rather than using `Object.defineProperty`.
+    /// See <https://babeljs.io/docs/assumptions#setpublicclassfields>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_public_class_fields: bool,
+
+    /// When using object spread, assume that spread properties don't trigger getters on the target object and thus it's safe to assign them.
+    /// See <https://babeljs.io/docs/assumptions#setspreadproperties>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub set_spread_properties: bool,
+
+    /// When using `for-of` with an iterator, it should always be closed with `.return()` and with `.throw()` in case of an error.
+    /// When this option is called Babel assumes that those methods are not defined or empty, and it avoids calling them.
+    /// See <https://babeljs.io/docs/assumptions#skipforofiteratorclosing>.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skip_for_of_iterator_closing: bool,
 }
@@ -26,3 +26,5 @@ impl NumberBase {
         matches!(self, Self::Float | Self::Decimal)
     }
 }
+
+oxc_span::impl_ast_leaf_traits!(NumberBase);
@@ -77,6 +77,35 @@ impl AssignmentOperator {
             Self::Exponential => "**=",
         }
     }
+
+    /// The binary operator of a compound assignment, e.g. `+` for `+=`.
+    pub fn to_binary_operator(self) -> Option<BinaryOperator> {
+        match self {
+            Self::Addition => Some(BinaryOperator::Addition),
+            Self::Subtraction => Some(BinaryOperator::Subtraction),
+            Self::Multiplication => Some(BinaryOperator::Multiplication),
+            Self::Division => Some(BinaryOperator::Division),
+            Self::Remainder => Some(BinaryOperator::Remainder),
+            Self::ShiftLeft => Some(BinaryOperator::ShiftLeft),
+            Self::ShiftRight => Some(BinaryOperator::ShiftRight),
+            Self::ShiftRightZeroFill => Some(BinaryOperator::ShiftRightZeroFill),
+            Self::BitwiseOR => Some(BinaryOperator::BitwiseOR),
+            Self::BitwiseXOR => Some(BinaryOperator::BitwiseXOR),
+            Self::BitwiseAnd => Some(BinaryOperator::BitwiseAnd),
+            Self::Exponential => Some(BinaryOperator::Exponential),
+            _ => None,
+        }
+    }
+
+    /// The logical operator of a logical assignment, e.g. `&&` for `&&=`.
+    pub fn to_logical_operator(self) -> Option<LogicalOperator> {
+        match self {
+            Self::LogicalAnd => Some(LogicalOperator::And),
+            Self::LogicalOr => Some(LogicalOperator::Or),
+            Self::LogicalNullish => Some(LogicalOperator::Coalesce),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -310,3 +339,11 @@ impl UpdateOperator {
         }
     }
 }
+
+oxc_span::impl_ast_leaf_traits!(
+    AssignmentOperator,
+    BinaryOperator,
+    LogicalOperator,
+    UnaryOperator,
+    UpdateOperator
+);
@@ -53,3 +53,5 @@ impl ReferenceFlag {
         self.contains(Self::ReadWrite)
     }
 }
+
+oxc_span::impl_ast_leaf_traits!(ReferenceFlag);
@@ -1,24 +1,41 @@
 use std::{
-    cell::{Ref, RefCell, RefMut},
+    cell::{Ref, RefCell},
     mem,
     rc::Rc,
 };
 
-use oxc_ast::AstBuilder;
+use oxc_ast::{ast::Program, AstBuilder};
 use oxc_diagnostics::Error;
-use oxc_semantic::{ScopeId, ScopeTree, Semantic, SymbolId, SymbolTable};
+use oxc_semantic::{ScopeId, ScopeTree, Semantic, SymbolFlags, SymbolId, SymbolTable};
 use oxc_span::{Atom, SourceType};
+use oxc_traverse::{traverse_mut, Traverse};
+
+use crate::helpers::{HelperLoader, HelperLoaderOptions};
 
 #[derive(Clone)]
 pub struct TransformerCtx<'a> {
     pub ast: Rc<AstBuilder<'a>>,
     semantic: Rc<RefCell<Semantic<'a>>>,
     errors: Rc<RefCell<Vec<Error>>>,
+    pub helpers: HelperLoader<'a>,
+    /// The function, static block and program scopes being transformed.
+    var_scope_stack: Rc<RefCell<Vec<ScopeId>>>,
 }
 
 impl<'a> TransformerCtx<'a> {
-    pub fn new(ast: Rc<AstBuilder<'a>>, semantic: Rc<RefCell<Semantic<'a>>>) -> Self {
-        Self { ast, semantic, errors: Rc::new(RefCell::new(vec![])) }
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        semantic: Rc<RefCell<Semantic<'a>>>,
+        helper_loader: HelperLoaderOptions,
+    ) -> Self {
+        let helpers = HelperLoader::new(Rc::clone(&ast), Rc::clone(&semantic), helper_loader);
+        Self {
+            ast,
+            semantic,
+            errors: Rc::new(RefCell::new(vec![])),
+            helpers,
+            var_scope_stack: Rc::default(),
+        }
     }
 
     pub fn semantic(&self) -> Ref<'_, Semantic<'a>> {
@@ -33,13 +50,49 @@ impl<'a> TransformerCtx<'a> {
         Ref::map(self.semantic.borrow(), |semantic| semantic.scopes())
     }
 
-    pub fn scopes_mut(&self) -> RefMut<'_, ScopeTree> {
-        RefMut::map(self.semantic.borrow_mut(), |semantic| semantic.scopes_mut())
+    /// Bindings are added to the function, static block or program scope `scope_id` until [`Self::exit_var_scope`].
+    /// Bodies created by the transforms have no scope, their bindings are added to the enclosing scope.
+    pub fn enter_var_scope(&self, scope_id: Option<ScopeId>) {
+        let scope_id = scope_id.unwrap_or_else(|| self.var_scope_id());
+        self.var_scope_stack.borrow_mut().push(scope_id);
+    }
+
+    pub fn exit_var_scope(&self) {
+        self.var_scope_stack.borrow_mut().pop();
+    }
+
+    /// The nearest function, static block or program scope.
+    pub fn var_scope_id(&self) -> ScopeId {
+        self.var_scope_stack
+            .borrow()
+            .last()
+            .copied()
+            .unwrap_or_else(|| self.scopes().root_scope_id())
+    }
+
+    /// Add a `var` binding to the nearest function, static block or program scope.
+    pub fn add_binding(&self, name: Atom) -> SymbolId {
+        let scope_id = self.var_scope_id();
+        let mut semantic = self.semantic.borrow_mut();
+        let flag = SymbolFlags::FunctionScopedVariable;
+        // The declaration is not in the original program
+        let symbol_id =
+            semantic.symbols_mut().create_synthetic_symbol(name.clone(), flag, scope_id);
+        semantic.scopes_mut().add_binding(scope_id, name, symbol_id);
+        symbol_id
     }
 
-    pub fn add_binding(&self, name: Atom) {
-        // TODO: use the correct scope and symbol id
-        self.scopes_mut().add_binding(ScopeId::new(0), name, SymbolId::new(0));
+    /// Traverse `program` with the hooks of `traverse`, which update the symbols and scopes.
+    pub fn traverse<T: Traverse<'a>>(&self, traverse: &mut T, program: &mut Program<'a>) {
+        let (symbols, scopes) = {
+            let mut semantic = self.semantic.borrow_mut();
+            (mem::take(semantic.symbols_mut()), mem::take(semantic.scopes_mut()))
+        };
+        let (symbols, scopes) =
+            traverse_mut(traverse, program, self.ast.allocator, symbols, scopes);
+        let mut semantic = self.semantic.borrow_mut();
+        *semantic.symbols_mut() = symbols;
+        *semantic.scopes_mut() = scopes;
     }
 
     pub fn source_type(&self) -> Ref<'_, SourceType> {
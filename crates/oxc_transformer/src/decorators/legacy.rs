@@ -0,0 +1,694 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    NumberBase,
+};
+
+use crate::{
+    context::TransformerCtx,
+    decorators::{DecoratorsOptions, DecoratorsVersion},
+    helpers::Helper,
+    options::TransformOptions,
+    utils::CreateVars,
+};
+
+/// TypeScript `experimentalDecorators`
+///
+/// ```typescript
+/// @dec
+/// class A {
+///   @prop x: string;
+///   method(@inject() a: number) {}
+/// }
+/// // is transformed to
+/// let A = class A {
+///   x;
+///   method(a) {}
+/// };
+/// _tsDecorate([prop], A.prototype, "x", void 0);
+/// _tsDecorate([_tsParam(0, inject())], A.prototype, "method", null);
+/// A = _tsDecorate([dec], A);
+/// ```
+///
+/// With `emitDecoratorMetadata`, the serialized types are added with `_tsMetadata("design:type", String)`.
+///
+/// References:
+/// * <https://www.typescriptlang.org/docs/handbook/decorators.html>
+/// * <https://www.typescriptlang.org/tsconfig#emitDecoratorMetadata>
+/// * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformers/legacyDecorators.ts>
+pub struct LegacyDecorators<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    emit_decorator_metadata: bool,
+
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+}
+
+impl<'a> CreateVars<'a> for LegacyDecorators<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
+}
+
+/// The `_tsDecorate` calls of a class.
+struct ClassDecorations<'a> {
+    /// `_tsDecorate([...], A.prototype, "x", void 0)`, instance members before static members.
+    members: std::vec::Vec<Expression<'a>>,
+    /// The class decorators, `_tsParam` of the constructor parameters and the metadata.
+    class: std::vec::Vec<Expression<'a>>,
+}
+
+impl<'a> LegacyDecorators<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let DecoratorsOptions { version, emit_decorator_metadata } = options.decorators?;
+        (version == DecoratorsVersion::Legacy).then(|| {
+            let vars = ast.new_vec();
+            Self { ast, ctx, emit_decorator_metadata, vars, vars_stack: vec![] }
+        })
+    }
+
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            let Some(stmt) = stmts.get_mut(i) else { break };
+            let class = match stmt {
+                Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(class),
+                Statement::ModuleDeclaration(decl) => match &mut **decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                        match &mut decl.declaration {
+                            Some(Declaration::ClassDeclaration(class)) => Some(class),
+                            _ => None,
+                        }
+                    }
+                    ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                        match &mut decl.declaration {
+                            ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            let Some(class) = class.filter(|class| has_decorators(class)) else {
+                i += 1;
+                continue;
+            };
+
+            let id = class
+                .id
+                .get_or_insert_with(|| BindingIdentifier::new(SPAN, self.generate_uid("default")));
+            let name = id.name.clone();
+            let decorations = self.transform_class(class, &name);
+
+            let mut after = self.ast.new_vec();
+            for member in decorations.members {
+                after.push(self.ast.expression_statement(SPAN, member));
+            }
+            if !decorations.class.is_empty() {
+                // `let A = class A {}`, the binding is replaced by the decorated class.
+                let stmt = mem::replace(stmt, self.ast.empty_statement(SPAN));
+                *stmts.get_mut(i).unwrap() = self.class_to_let(stmt, &name, &mut after);
+                let decorate = self.decorate(decorations.class, self.reference(&name), None);
+                let assign = self.assign(&name, decorate);
+                let index = after.len() - usize::from(is_export_default(&after));
+                after.insert(index, self.ast.expression_statement(SPAN, assign));
+            }
+
+            let after_len = after.len();
+            stmts.splice(i + 1..=i, after);
+            i += after_len + 1;
+        }
+    }
+
+    /// `(_a = class {}, _tsDecorate([...], _a.prototype, "x", void 0), _a = _tsDecorate([dec], _a))`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !has_decorators(class) {
+            return;
+        }
+        let name = self.create_new_var(expr).name;
+        let Expression::ClassExpression(class) = expr else { return };
+        let decorations = self.transform_class(class, &name);
+
+        let class = self.ast.move_expression(expr);
+        let mut expressions = self.ast.new_vec();
+        expressions.push(self.assign(&name, class));
+        expressions.extend(decorations.members);
+        if decorations.class.is_empty() {
+            expressions.push(self.reference(&name));
+        } else {
+            let decorate = self.decorate(decorations.class, self.reference(&name), None);
+            expressions.push(self.assign(&name, decorate));
+        }
+        *expr = self.ast.sequence_expression(SPAN, expressions);
+    }
+
+    /// Move the decorators out of the class.
+    fn transform_class(&mut self, class: &mut Class<'a>, name: &Atom) -> ClassDecorations<'a> {
+        let mut instance = vec![];
+        let mut statics = vec![];
+        let mut class_decorators = self.take_decorators(&mut class.decorators);
+        let mut constructor = None;
+
+        for element in class.body.body.iter_mut() {
+            let (r#static, key, computed, mut decorators, metadata, descriptor) = match element {
+                ClassElement::MethodDefinition(method) => {
+                    let method = &mut **method;
+                    if method.kind == MethodDefinitionKind::Constructor {
+                        class_decorators.extend(self.take_param_decorators(&mut method.value));
+                        constructor = Some(&method.value.params);
+                        continue;
+                    }
+                    let mut decorators = self.take_decorators(&mut method.decorators);
+                    decorators.extend(self.take_param_decorators(&mut method.value));
+                    let metadata = self.method_metadata(method);
+                    (method.r#static, &mut method.key, method.computed, decorators, metadata, true)
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    let prop = &mut **prop;
+                    let decorators = self.take_decorators(&mut prop.decorators);
+                    let annotation = prop.type_annotation.as_ref().map(|a| &a.type_annotation);
+                    let metadata = vec![("design:type", self.serialize_type(annotation))];
+                    (prop.r#static, &mut prop.key, prop.computed, decorators, metadata, false)
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    let prop = &mut **prop;
+                    let decorators = self.take_decorators(&mut prop.decorators);
+                    let metadata = vec![("design:type", self.serialize_type(None))];
+                    (prop.r#static, &mut prop.key, prop.computed, decorators, metadata, true)
+                }
+                _ => continue,
+            };
+            if decorators.is_empty() {
+                continue;
+            }
+            let Some(key) = self.member_key(key, computed) else { continue };
+            if self.emit_decorator_metadata {
+                decorators
+                    .extend(metadata.into_iter().map(|(key, value)| self.metadata(key, value)));
+            }
+            let target = if r#static {
+                self.reference(name)
+            } else {
+                let property = IdentifierName::new(SPAN, Atom::from("prototype"));
+                self.ast.static_member_expression(SPAN, self.reference(name), property, false)
+            };
+            // Methods and accessors are decorated with their property descriptor, fields with `void 0`.
+            let descriptor = if descriptor {
+                self.ast.literal_null_expression(NullLiteral::new(SPAN))
+            } else {
+                self.ast.void_0()
+            };
+            let decorate = self.decorate(decorators, target, Some((key, descriptor)));
+            if r#static { &mut statics } else { &mut instance }.push(decorate);
+        }
+
+        if self.emit_decorator_metadata && !class_decorators.is_empty() {
+            if let Some(params) = constructor {
+                let types = self.serialize_parameters(params);
+                class_decorators.push(self.metadata("design:paramtypes", types));
+            }
+        }
+        instance.extend(statics);
+        ClassDecorations { members: instance, class: class_decorators }
+    }
+
+    /// `design:type`, `design:paramtypes` and `design:returntype` of a method or accessor.
+    fn method_metadata(
+        &self,
+        method: &MethodDefinition<'a>,
+    ) -> std::vec::Vec<(&'static str, Expression<'a>)> {
+        if !self.emit_decorator_metadata {
+            return vec![];
+        }
+        let function = &method.value;
+        let return_type = function.return_type.as_ref().map(|a| &a.type_annotation);
+        let params = &function.params;
+        match method.kind {
+            MethodDefinitionKind::Get => vec![
+                ("design:type", self.serialize_type(return_type)),
+                ("design:paramtypes", self.ast.array_expression(SPAN, self.ast.new_vec(), None)),
+            ],
+            MethodDefinitionKind::Set => {
+                let param = params.items.iter().find(|param| !is_this_param(param));
+                let annotation = param
+                    .and_then(|p| p.pattern.type_annotation.as_ref())
+                    .map(|a| &a.type_annotation);
+                vec![
+                    ("design:type", self.serialize_type(annotation)),
+                    ("design:paramtypes", self.serialize_parameters(params)),
+                ]
+            }
+            MethodDefinitionKind::Method | MethodDefinitionKind::Constructor => vec![
+                ("design:type", self.global("Function")),
+                ("design:paramtypes", self.serialize_parameters(params)),
+                (
+                    "design:returntype",
+                    return_type
+                        .map_or_else(|| self.ast.void_0(), |ty| self.serialize_type(Some(ty))),
+                ),
+            ],
+        }
+    }
+
+    /// `[String, Number]`
+    fn serialize_parameters(&self, params: &FormalParameters<'a>) -> Expression<'a> {
+        let mut elements = self.ast.new_vec();
+        for param in params.items.iter().filter(|param| !is_this_param(param)) {
+            let annotation = param.pattern.type_annotation.as_ref().map(|a| &a.type_annotation);
+            elements.push(ArrayExpressionElement::Expression(self.serialize_type(annotation)));
+        }
+        if let Some(rest) = &params.rest {
+            let annotation = rest.argument.type_annotation.as_ref().map(|a| &a.type_annotation);
+            elements.push(ArrayExpressionElement::Expression(self.serialize_type(annotation)));
+        }
+        self.ast.array_expression(SPAN, elements, None)
+    }
+
+    /// The runtime value of a type, as serialized by `tsc` for `reflect-metadata`.
+    fn serialize_type(&self, ty: Option<&TSType<'a>>) -> Expression<'a> {
+        match ty.map_or(SerializedType::Object, SerializedType::from) {
+            SerializedType::Void => self.ast.void_0(),
+            SerializedType::Object => self.global("Object"),
+            SerializedType::Global(name) => self.global(name),
+            // `typeof Symbol === "function" ? Symbol : Object`
+            SerializedType::MaybeGlobal(name) => {
+                let test = self.ast.binary_expression(
+                    SPAN,
+                    self.type_of(self.global(name)),
+                    BinaryOperator::StrictEquality,
+                    self.string("function"),
+                );
+                self.ast.conditional_expression(
+                    SPAN,
+                    test,
+                    self.global(name),
+                    self.global("Object"),
+                )
+            }
+            // `typeof Foo === "undefined" ? Object : Foo`
+            SerializedType::Reference(type_name) => {
+                let mut test = None;
+                let mut prefix = None;
+                for name in type_name_parts(type_name) {
+                    let expr = prefix.take().map_or_else(
+                        || self.global(name.as_str()),
+                        |object| {
+                            let property = IdentifierName::new(SPAN, name.clone());
+                            self.ast.static_member_expression(SPAN, object, property, false)
+                        },
+                    );
+                    let check = self.ast.binary_expression(
+                        SPAN,
+                        self.type_of(self.ast.copy(&expr)),
+                        BinaryOperator::StrictEquality,
+                        self.string("undefined"),
+                    );
+                    test = Some(match test {
+                        None => check,
+                        Some(test) => {
+                            self.ast.logical_expression(SPAN, test, LogicalOperator::Or, check)
+                        }
+                    });
+                    prefix = Some(expr);
+                }
+                let (Some(test), Some(value)) = (test, prefix) else {
+                    return self.global("Object");
+                };
+                self.ast.conditional_expression(SPAN, test, self.global("Object"), value)
+            }
+        }
+    }
+
+    /// The key passed to `_tsDecorate`, computed keys are memoised as `[_a = key()]`.
+    fn member_key(&mut self, key: &mut PropertyKey<'a>, computed: bool) -> Option<Expression<'a>> {
+        match key {
+            PropertyKey::Identifier(ident) => Some(self.string(ident.name.as_str())),
+            PropertyKey::PrivateIdentifier(_) => None,
+            PropertyKey::Expression(
+                expr @ (Expression::StringLiteral(_) | Expression::NumberLiteral(_)),
+            ) => Some(self.ast.copy(expr)),
+            PropertyKey::Expression(expr) if computed => {
+                let temp = self.create_new_var(expr).name;
+                let value = self.ast.move_expression(expr);
+                *expr = self.assign(&temp, value);
+                Some(self.reference(&temp))
+            }
+            PropertyKey::Expression(expr) => Some(self.ast.copy(expr)),
+        }
+    }
+
+    fn take_decorators(
+        &self,
+        decorators: &mut Vec<'a, Decorator<'a>>,
+    ) -> std::vec::Vec<Expression<'a>> {
+        mem::replace(decorators, self.ast.new_vec()).into_iter().map(|d| d.expression).collect()
+    }
+
+    /// `_tsParam(0, dec)`
+    fn take_param_decorators(&self, function: &mut Function<'a>) -> std::vec::Vec<Expression<'a>> {
+        let mut decorators = vec![];
+        let params = function.params.items.iter_mut().filter(|param| !is_this_param(param));
+        for (index, param) in params.enumerate() {
+            for decorator in self.take_decorators(&mut param.decorators) {
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(self.number(index)));
+                arguments.push(Argument::Expression(decorator));
+                decorators.push(self.ctx.helpers.call(Helper::TsParam, arguments));
+            }
+        }
+        decorators
+    }
+
+    /// `_tsDecorate([decorators], target, key, descriptor)`
+    fn decorate(
+        &self,
+        decorators: std::vec::Vec<Expression<'a>>,
+        target: Expression<'a>,
+        member: Option<(Expression<'a>, Expression<'a>)>,
+    ) -> Expression<'a> {
+        let mut elements = self.ast.new_vec_with_capacity(decorators.len());
+        elements.extend(decorators.into_iter().map(ArrayExpressionElement::Expression));
+        let mut arguments = self.ast.new_vec_with_capacity(4);
+        arguments.push(Argument::Expression(self.ast.array_expression(SPAN, elements, None)));
+        arguments.push(Argument::Expression(target));
+        if let Some((key, descriptor)) = member {
+            arguments.push(Argument::Expression(key));
+            arguments.push(Argument::Expression(descriptor));
+        }
+        self.ctx.helpers.call(Helper::TsDecorate, arguments)
+    }
+
+    /// `_tsMetadata("design:type", String)`
+    fn metadata(&self, key: &str, value: Expression<'a>) -> Expression<'a> {
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.string(key)));
+        arguments.push(Argument::Expression(value));
+        self.ctx.helpers.call(Helper::TsMetadata, arguments)
+    }
+
+    /// `class A {}` -> `let A = class A {}`, `export default A` is added to `after`.
+    fn class_to_let(
+        &self,
+        stmt: Statement<'a>,
+        name: &Atom,
+        after: &mut Vec<'a, Statement<'a>>,
+    ) -> Statement<'a> {
+        match stmt {
+            Statement::Declaration(Declaration::ClassDeclaration(class)) => {
+                Statement::Declaration(self.let_declaration(name, class))
+            }
+            Statement::ModuleDeclaration(decl) => match decl.unbox() {
+                ModuleDeclaration::ExportNamedDeclaration(mut decl) => {
+                    if let Some(Declaration::ClassDeclaration(class)) = decl.declaration.take() {
+                        decl.declaration = Some(self.let_declaration(name, class));
+                    }
+                    self.ast.module_declaration(ModuleDeclaration::ExportNamedDeclaration(decl))
+                }
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                    let decl = decl.unbox();
+                    let ExportDefaultDeclarationKind::ClassDeclaration(class) = decl.declaration
+                    else {
+                        unreachable!()
+                    };
+                    let export = self.ast.export_default_declaration(
+                        SPAN,
+                        ExportDefaultDeclarationKind::Expression(self.reference(name)),
+                        decl.exported,
+                    );
+                    after.push(
+                        self.ast.module_declaration(ModuleDeclaration::ExportDefaultDeclaration(
+                            export,
+                        )),
+                    );
+                    Statement::Declaration(self.let_declaration(name, class))
+                }
+                decl => self.ast.module_declaration(decl),
+            },
+            stmt => stmt,
+        }
+    }
+
+    fn let_declaration(&self, name: &Atom, mut class: Box<'a, Class<'a>>) -> Declaration<'a> {
+        class.r#type = ClassType::ClassExpression;
+        let kind = VariableDeclarationKind::Let;
+        let id = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name.clone())),
+            None,
+            false,
+        );
+        let init = self.ast.class_expression(class);
+        let declarator = self.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Declaration::VariableDeclaration(decl)
+    }
+
+    fn assign(&self, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let left = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast
+                .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone())),
+        );
+        self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, value)
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn global(&self, name: &str) -> Expression<'a> {
+        self.reference(&Atom::from(name))
+    }
+
+    fn type_of(&self, expr: Expression<'a>) -> Expression<'a> {
+        self.ast.unary_expression(SPAN, UnaryOperator::Typeof, expr)
+    }
+
+    fn string(&self, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(SPAN, Atom::from(value)))
+    }
+
+    fn number(&self, value: usize) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        #[allow(clippy::cast_precision_loss)]
+        let literal = self.ast.number_literal(SPAN, value as f64, raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+}
+
+/// Classes with decorators on the class, its members or the parameters of its methods.
+fn has_decorators(class: &Class) -> bool {
+    let has_param_decorators = |function: &Function| {
+        function.params.items.iter().any(|param| !param.decorators.is_empty())
+    };
+    !class.decorators.is_empty()
+        || class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => {
+                !method.decorators.is_empty() || has_param_decorators(&method.value)
+            }
+            ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+            ClassElement::AccessorProperty(prop) => !prop.decorators.is_empty(),
+            _ => false,
+        })
+}
+
+fn is_this_param(param: &FormalParameter) -> bool {
+    matches!(&param.pattern.kind, BindingPatternKind::BindingIdentifier(ident) if ident.name == "this")
+}
+
+fn is_export_default(stmts: &[Statement]) -> bool {
+    matches!(stmts.last(), Some(Statement::ModuleDeclaration(decl)) if decl.is_default_export())
+}
+
+/// `A.B.C` -> `["A", "B", "C"]`
+fn type_name_parts(name: &TSTypeName) -> std::vec::Vec<Atom> {
+    match name {
+        TSTypeName::IdentifierReference(ident) => vec![ident.name.clone()],
+        TSTypeName::QualifiedName(name) => {
+            let mut parts = type_name_parts(&name.left);
+            parts.push(name.right.name.clone());
+            parts
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SerializedType<'a, 'b> {
+    Void,
+    Object,
+    Global(&'static str),
+    /// Globals which may not exist at runtime, `BigInt` and `Symbol`.
+    MaybeGlobal(&'static str),
+    Reference(&'b TSTypeName<'a>),
+}
+
+impl<'a, 'b> From<&'b TSType<'a>> for SerializedType<'a, 'b> {
+    fn from(ty: &'b TSType<'a>) -> Self {
+        match ty {
+            TSType::TSVoidKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSNullKeyword(_)
+            | TSType::TSNeverKeyword(_) => Self::Void,
+            TSType::TSBooleanKeyword(_) | TSType::TSTypePredicate(_) => Self::Global("Boolean"),
+            TSType::TSNumberKeyword(_) => Self::Global("Number"),
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => Self::Global("String"),
+            TSType::TSBigIntKeyword(_) => Self::MaybeGlobal("BigInt"),
+            TSType::TSSymbolKeyword(_) => Self::MaybeGlobal("Symbol"),
+            TSType::TSArrayType(_) | TSType::TSTupleType(_) => Self::Global("Array"),
+            TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => Self::Global("Function"),
+            TSType::TSLiteralType(literal) => match &literal.literal {
+                TSLiteral::BooleanLiteral(_) => Self::Global("Boolean"),
+                TSLiteral::NumberLiteral(_) | TSLiteral::UnaryExpression(_) => {
+                    Self::Global("Number")
+                }
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+                    Self::Global("String")
+                }
+                TSLiteral::BigintLiteral(_) => Self::MaybeGlobal("BigInt"),
+                TSLiteral::NullLiteral(_) => Self::Void,
+                TSLiteral::RegExpLiteral(_) => Self::Object,
+            },
+            TSType::TSTypeReference(reference) => Self::Reference(&reference.type_name),
+            // `string | null` is serialized as `String`, unions of different types as `Object`.
+            TSType::TSUnionType(union) => {
+                let mut serialized = None;
+                for ty in &union.types {
+                    let ty = Self::from(ty);
+                    if matches!(ty, Self::Void) {
+                        continue;
+                    }
+                    match serialized {
+                        None => serialized = Some(ty),
+                        Some(other) if other.same_as(ty) => {}
+                        Some(_) => return Self::Object,
+                    }
+                }
+                serialized.unwrap_or(Self::Void)
+            }
+            _ => Self::Object,
+        }
+    }
+}
+
+impl<'a, 'b> SerializedType<'a, 'b> {
+    fn same_as(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Void, Self::Void) | (Self::Object, Self::Object) => true,
+            (Self::Global(a), Self::Global(b)) | (Self::MaybeGlobal(a), Self::MaybeGlobal(b)) => {
+                a == b
+            }
+            (Self::Reference(a), Self::Reference(b)) => type_name_parts(a) == type_name_parts(b),
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::TransformOptions,
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        decorators: Some(DecoratorsOptions {
+            version: DecoratorsVersion::Legacy,
+            emit_decorator_metadata: false,
+        }),
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            module_name: "@swc/helpers".into(),
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "class A { @dec x; @dec static m() {} @dec get y() {} method(a, @inject() b) {} }",
+            "import { _ as _tsDecorate } from '@swc/helpers/_/_ts_decorate';
+            import { _ as _tsParam } from '@swc/helpers/_/_ts_param';
+            class A { x; static m() {} get y() {} method(a, b) {} }
+            _tsDecorate([dec], A.prototype, 'x', void 0);
+            _tsDecorate([dec], A.prototype, 'y', null);
+            _tsDecorate([_tsParam(1, inject())], A.prototype, 'method', null);
+            _tsDecorate([dec], A, 'm', null);",
+        ),
+        (
+            "@dec export class A { constructor(@inject() a) {} [foo()]() {} @dec [bar()]() {} }",
+            "import { _ as _tsParam } from '@swc/helpers/_/_ts_param';
+            import { _ as _tsDecorate } from '@swc/helpers/_/_ts_decorate';
+            var _ref;
+            export let A = class A { constructor(a) {} [foo()]() {} [_ref = bar()]() {} };
+            _tsDecorate([dec], A.prototype, _ref, null);
+            A = _tsDecorate([dec, _tsParam(0, inject())], A);",
+        ),
+        (
+            "@dec export default class {}",
+            "import { _ as _tsDecorate } from '@swc/helpers/_/_ts_decorate';
+            let _default = class _default {};
+            _default = _tsDecorate([dec], _default);
+            export default _default;",
+        ),
+    ];
+    Tester::new("test.mjs", options.clone()).test(tests);
+
+    let mut options = options;
+    options.decorators.as_mut().unwrap().emit_decorator_metadata = true;
+    let tests = &[(
+        "@Injectable() class A {
+            constructor(a: Service, b: string | null, c: ns.Token) {}
+            @Input() x: number[];
+            @Get() find(id: string): Promise<User> {}
+        }",
+        "import { _ as _tsMetadata } from '@swc/helpers/_/_ts_metadata';
+        import { _ as _tsDecorate } from '@swc/helpers/_/_ts_decorate';
+        let A = class A { constructor(a, b, c) {} x; find(id) {} };
+        _tsDecorate([Input(), _tsMetadata('design:type', Array)], A.prototype, 'x', void 0);
+        _tsDecorate([
+            Get(),
+            _tsMetadata('design:type', Function),
+            _tsMetadata('design:paramtypes', [String]),
+            _tsMetadata('design:returntype', typeof Promise === 'undefined' ? Object : Promise)
+        ], A.prototype, 'find', null);
+        A = _tsDecorate([
+            Injectable(),
+            _tsMetadata('design:paramtypes', [
+                typeof Service === 'undefined' ? Object : Service,
+                String,
+                typeof ns === 'undefined' || typeof ns.Token === 'undefined' ? Object : ns.Token
+            ])
+        ], A);",
+    )];
+    Tester::new("test.ts", options).test(tests);
+}
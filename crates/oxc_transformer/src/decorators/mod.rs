@@ -0,0 +1,28 @@
+mod legacy;
+mod version_2023_05;
+
+use serde::Deserialize;
+
+pub use self::{legacy::LegacyDecorators, version_2023_05::Decorators};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecoratorsOptions {
+    #[serde(default)]
+    pub version: DecoratorsVersion,
+    /// TypeScript `emitDecoratorMetadata`, emits `design:type`, `design:paramtypes` and `design:returntype`
+    /// metadata for `reflect-metadata`. Only used by the `legacy` version.
+    #[serde(default)]
+    pub emit_decorator_metadata: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DecoratorsVersion {
+    /// The TC39 proposal as of the 2023-05 meeting, with `accessor` fields and `context.metadata`.
+    #[default]
+    #[serde(rename = "2023-05")]
+    V2023_05,
+    /// TypeScript `experimentalDecorators`
+    #[serde(rename = "legacy")]
+    Legacy,
+}
@@ -0,0 +1,1136 @@
+use std::{mem, rc::Rc};
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator},
+    NumberBase,
+};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    context::TransformerCtx,
+    decorators::{DecoratorsOptions, DecoratorsVersion},
+    helpers::Helper,
+    options::TransformOptions,
+    utils::CreateVars,
+};
+
+/// Decorators: 2023-05
+///
+/// ```javascript
+/// @dec
+/// class A {
+///   @log method() {}
+///   @reactive accessor x = 1;
+/// }
+/// // is transformed to
+/// var _initProto, _init_x, _A, _initClass;
+/// class A {
+///   static {
+///     ({ e: [_init_x, _initProto], c: [_A, _initClass] } = _applyDecs2305(this, [[log, 2, "method"], [reactive, 1, "x"]], [dec]));
+///   }
+///   constructor() { _initProto(this); }
+///   method() {}
+///   #A = _init_x(this, 1);
+///   get x() { return this.#A; }
+///   set x(v) { this.#A = v; }
+///   static { _initClass(); }
+/// }
+/// A = _A;
+/// ```
+///
+/// `accessor` fields are lowered to private storage even when they are not decorated.
+/// References to the class binding inside the class body keep the undecorated class.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-proposal-decorators>
+/// * <https://github.com/tc39/proposal-decorators>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-helpers/src/helpers/applyDecs2305.js>
+pub struct Decorators<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+}
+
+impl<'a> CreateVars<'a> for Decorators<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
+}
+
+/// `kind` of the decorator context, encoded in the flags of the `_applyDecs2305` member entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Field = 0,
+    Accessor = 1,
+    Method = 2,
+    Getter = 3,
+    Setter = 4,
+}
+
+const STATIC: u8 = 8;
+const DECORATORS_HAVE_THIS: u8 = 16;
+
+/// Evaluated decorators, with the receiver of member expression decorators such as `@obj.dec`.
+struct DecoratorList<'a> {
+    decorators: std::vec::Vec<(Option<Expression<'a>>, Expression<'a>)>,
+}
+
+impl<'a> DecoratorList<'a> {
+    fn have_this(&self) -> bool {
+        self.decorators.iter().any(|(this, _)| this.is_some())
+    }
+}
+
+/// State of the class being transformed.
+struct ClassState<'a> {
+    /// Decorators and computed keys, evaluated in order before the class.
+    before: std::vec::Vec<Expression<'a>>,
+    /// `[dec, flags, "name", ...]` entries of `_applyDecs2305`.
+    members: std::vec::Vec<Expression<'a>>,
+    /// Variables destructured from the `e` result of `_applyDecs2305`.
+    outputs: std::vec::Vec<Atom>,
+    /// A private instance member, used for the `#x in obj` brand check.
+    brand: Option<Atom>,
+    proto_initializers: bool,
+    static_initializers: bool,
+    /// Private names in use, accessor storage names are picked from the rest.
+    private_names: FxHashSet<Atom>,
+}
+
+impl<'a> Decorators<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let DecoratorsOptions { version, .. } = options.decorators?;
+        (version == DecoratorsVersion::V2023_05).then(|| {
+            let vars = ast.new_vec();
+            Self { ast, ctx, vars, vars_stack: vec![] }
+        })
+    }
+
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            let Some(stmt) = stmts.get_mut(i) else { break };
+            let class = match stmt {
+                Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(class),
+                Statement::ModuleDeclaration(decl) => match &mut **decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                        match &mut decl.declaration {
+                            Some(Declaration::ClassDeclaration(class)) => Some(class),
+                            _ => None,
+                        }
+                    }
+                    ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                        match &mut decl.declaration {
+                            ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            let Some(class) = class.filter(|class| needs_transform(class)) else {
+                i += 1;
+                continue;
+            };
+
+            // `export default @dec class {}` -> `export default class _default {}`
+            let has_class_decorators = !class.decorators.is_empty();
+            if has_class_decorators && class.id.is_none() {
+                class.id = Some(BindingIdentifier::new(SPAN, self.generate_uid("default")));
+            }
+            let name = class.id.as_ref().map(|id| id.name.clone());
+            let (before, class_ref) = self.transform_class(class, name.as_ref());
+
+            if !before.is_empty() {
+                let expr = self.sequence(before);
+                stmts.insert(i, self.ast.expression_statement(SPAN, expr));
+                i += 1;
+            }
+            i += 1;
+            // `A = _A;`
+            if let (Some(name), Some(class_ref)) = (name, class_ref) {
+                let assign = self.assign(&name, self.reference(&class_ref));
+                stmts.insert(i, self.ast.expression_statement(SPAN, assign));
+                i += 1;
+            }
+        }
+    }
+
+    /// `(_dec = dec(), class { ... }, _A)`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !needs_transform(class) {
+            return;
+        }
+        let (before, class_ref) = self.transform_class(class, None);
+        if before.is_empty() && class_ref.is_none() {
+            return;
+        }
+        let class = self.ast.move_expression(expr);
+        let mut expressions = before;
+        expressions.push(class);
+        if let Some(class_ref) = class_ref {
+            expressions.push(self.reference(&class_ref));
+        }
+        *expr = self.sequence(expressions);
+    }
+
+    /// Returns the expressions to evaluate before the class,
+    /// and the variable holding the decorated class when there are class decorators.
+    fn transform_class(
+        &mut self,
+        class: &mut Class<'a>,
+        name: Option<&Atom>,
+    ) -> (std::vec::Vec<Expression<'a>>, Option<Atom>) {
+        let mut state = ClassState {
+            before: vec![],
+            members: vec![],
+            outputs: vec![],
+            brand: None,
+            proto_initializers: false,
+            static_initializers: false,
+            private_names: collect_private_names(class),
+        };
+
+        let class_decorators = mem::replace(&mut class.decorators, self.ast.new_vec());
+        let class_decorators = self.evaluate_decorators(class_decorators, &mut state.before);
+
+        let elements = mem::replace(&mut class.body.body, self.ast.new_vec());
+        for element in elements {
+            match element {
+                ClassElement::MethodDefinition(method)
+                    if !method.decorators.is_empty()
+                        && method.kind != MethodDefinitionKind::Constructor =>
+                {
+                    self.transform_method(method, &mut class.body.body, &mut state);
+                }
+                ClassElement::PropertyDefinition(prop)
+                    if !prop.decorators.is_empty() && !prop.declare =>
+                {
+                    self.transform_field(prop, &mut class.body.body, &mut state);
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    self.transform_accessor(prop, &mut class.body.body, &mut state);
+                }
+                element => class.body.body.push(element),
+            }
+        }
+
+        if state.members.is_empty() && class_decorators.decorators.is_empty() {
+            return (state.before, None);
+        }
+
+        let init_proto =
+            state.proto_initializers.then(|| self.create_new_named_var("initProto").name);
+        let init_static =
+            state.static_initializers.then(|| self.create_new_named_var("initStatic").name);
+        state.outputs.extend(init_proto.clone());
+        state.outputs.extend(init_static.clone());
+
+        let class_ref = (!class_decorators.decorators.is_empty()).then(|| {
+            let name = name.map_or("Class", Atom::as_str);
+            (self.create_new_named_var(name).name, self.create_new_named_var("initClass").name)
+        });
+
+        let parent =
+            class.super_class.as_mut().map(|super_class| self.memoise_super_class(super_class));
+        let call = self.apply_decs(&mut state, class_decorators, parent);
+
+        // `static { ({ e: [...], c: [_A, _initClass] } = _applyDecs2305(...)); _initStatic(this); }`
+        let mut body = self.ast.new_vec();
+        let target = self.destructure(&state.outputs, class_ref.as_ref());
+        let value = match (&class_ref, state.outputs.is_empty()) {
+            (Some(_), false) => call,
+            (Some(_), true) => self.member(call, "c"),
+            (None, _) => self.member(call, "e"),
+        };
+        let assign =
+            self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value);
+        body.push(self.ast.expression_statement(SPAN, assign));
+        if let Some(init_static) = &init_static {
+            body.push(self.ast.expression_statement(
+                SPAN,
+                self.call(init_static, self.ast.this_expression(SPAN), None),
+            ));
+        }
+        class.body.body.insert(0, self.ast.static_block(SPAN, body));
+
+        if let Some(init_proto) = &init_proto {
+            self.insert_init_proto(class, init_proto);
+        }
+
+        // `static { _initClass(); }`
+        let class_ref = class_ref.map(|(class_ref, init_class)| {
+            let call = self.ast.call_expression(
+                SPAN,
+                self.reference(&init_class),
+                self.ast.new_vec(),
+                false,
+                None,
+            );
+            let body = self.ast.new_vec_single(self.ast.expression_statement(SPAN, call));
+            class.body.body.push(self.ast.static_block(SPAN, body));
+            class_ref
+        });
+        (state.before, class_ref)
+    }
+
+    /// `@dec method() {}` is kept in the class, `@dec #m() {}` is replaced by `get #m() { return _call_m; }`.
+    fn transform_method(
+        &mut self,
+        mut method: Box<'a, MethodDefinition<'a>>,
+        body: &mut Vec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+    ) {
+        let decorators = mem::replace(&mut method.decorators, self.ast.new_vec());
+        let decorators = self.evaluate_decorators(decorators, &mut state.before);
+        let kind = match method.kind {
+            MethodDefinitionKind::Get => Kind::Getter,
+            MethodDefinitionKind::Set => Kind::Setter,
+            _ => Kind::Method,
+        };
+        let r#static = method.r#static;
+        if r#static {
+            state.static_initializers = true;
+        } else {
+            state.proto_initializers = true;
+        }
+        let computed = method.computed;
+        let name = self.member_name(&mut method.key, computed, &mut state.before);
+
+        let Some(private) = method.key.private_name() else {
+            state.members.push(self.member_entry(decorators, kind, r#static, name, vec![]));
+            body.push(ClassElement::MethodDefinition(method));
+            return;
+        };
+        if !r#static {
+            state.brand.get_or_insert_with(|| private.clone());
+        }
+        let output = self.create_new_named_var(&format!("call_{private}")).name;
+        state.outputs.push(output.clone());
+
+        let mut method = method.unbox();
+        let stub = match kind {
+            // `get #m() { return _call_m; }`
+            Kind::Method => {
+                let stmt = self.ast.return_statement(SPAN, Some(self.reference(&output)));
+                self.method(MethodDefinitionKind::Get, &private, r#static, None, stmt)
+            }
+            // `get #x() { return _call_x(this); }`
+            Kind::Getter => {
+                let call = self.call(&output, self.ast.this_expression(SPAN), None);
+                let stmt = self.ast.return_statement(SPAN, Some(call));
+                self.method(MethodDefinitionKind::Get, &private, r#static, None, stmt)
+            }
+            // `set #x(v) { _call_x(this, v); }`
+            _ => {
+                let value = Atom::from("v");
+                let call = self.call(
+                    &output,
+                    self.ast.this_expression(SPAN),
+                    Some(self.reference(&value)),
+                );
+                let stmt = self.ast.expression_statement(SPAN, call);
+                self.method(MethodDefinitionKind::Set, &private, r#static, Some(value), stmt)
+            }
+        };
+        method.value.r#type = FunctionType::FunctionExpression;
+        let function = self.ast.function_expression(method.value);
+        state.members.push(self.member_entry(decorators, kind, r#static, name, vec![function]));
+        body.push(stub);
+    }
+
+    /// `@dec x = 1` -> `x = _init_x(this, 1)`
+    fn transform_field(
+        &mut self,
+        mut prop: Box<'a, PropertyDefinition<'a>>,
+        body: &mut Vec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+    ) {
+        let decorators = mem::replace(&mut prop.decorators, self.ast.new_vec());
+        let decorators = self.evaluate_decorators(decorators, &mut state.before);
+        let computed = prop.computed;
+        let name = self.member_name(&mut prop.key, computed, &mut state.before);
+
+        let init = self.create_new_named_var(&format!("init_{}", var_name(&prop.key))).name;
+        state.outputs.push(init.clone());
+        prop.value = Some(self.call(&init, self.ast.this_expression(SPAN), prop.value.take()));
+
+        let accessors = match prop.key.private_name() {
+            Some(private) => {
+                if !prop.r#static {
+                    state.brand.get_or_insert_with(|| private.clone());
+                }
+                self.private_accessors(&private)
+            }
+            None => vec![],
+        };
+        state.members.push(self.member_entry(
+            decorators,
+            Kind::Field,
+            prop.r#static,
+            name,
+            accessors,
+        ));
+        body.push(ClassElement::PropertyDefinition(prop));
+    }
+
+    /// `accessor x = 1` -> `#A = 1; get x() { return this.#A; } set x(v) { this.#A = v; }`
+    fn transform_accessor(
+        &mut self,
+        prop: Box<'a, AccessorProperty<'a>>,
+        body: &mut Vec<'a, ClassElement<'a>>,
+        state: &mut ClassState<'a>,
+    ) {
+        let mut prop = prop.unbox();
+        let r#static = prop.r#static;
+        let storage = storage_name(&mut state.private_names);
+        let decorators = self.evaluate_decorators(prop.decorators, &mut state.before);
+        let decorated = !decorators.decorators.is_empty();
+
+        // Computed keys are used by both the getter and the setter.
+        let name = if decorated || (prop.computed && !is_literal_key(&prop.key)) {
+            Some(self.member_name(&mut prop.key, prop.computed, &mut state.before))
+        } else {
+            None
+        };
+        let mut value = prop.value.take();
+        if decorated {
+            let init = self.create_new_named_var(&format!("init_{}", var_name(&prop.key))).name;
+            state.outputs.push(init.clone());
+            value = Some(self.call(&init, self.ast.this_expression(SPAN), value));
+            if r#static {
+                state.static_initializers = true;
+            } else {
+                state.proto_initializers = true;
+            }
+        }
+        let storage_key = self.private_key(&storage);
+        body.push(self.ast.class_property(
+            SPAN,
+            storage_key,
+            value,
+            false,
+            r#static,
+            self.ast.new_vec(),
+        ));
+
+        let private = prop.key.private_name();
+        if let (true, Some(private)) = (decorated, &private) {
+            // `get #x() { return _get_x(this); }` and `set #x(v) { _set_x(this, v); }`
+            if !r#static {
+                state.brand.get_or_insert_with(|| private.clone());
+            }
+            let get = self.create_new_named_var(&format!("get_{private}")).name;
+            let set = self.create_new_named_var(&format!("set_{private}")).name;
+            state.outputs.push(get.clone());
+            state.outputs.push(set.clone());
+            let call = self.call(&get, self.ast.this_expression(SPAN), None);
+            let stmt = self.ast.return_statement(SPAN, Some(call));
+            body.push(self.method(MethodDefinitionKind::Get, private, r#static, None, stmt));
+            let value = Atom::from("v");
+            let call =
+                self.call(&set, self.ast.this_expression(SPAN), Some(self.reference(&value)));
+            let stmt = self.ast.expression_statement(SPAN, call);
+            body.push(self.method(MethodDefinitionKind::Set, private, r#static, Some(value), stmt));
+        } else {
+            // `get x() { return this.#A; }` and `set x(v) { this.#A = v; }`
+            let key = self.ast.copy(&prop.key);
+            let field = self.private_field(self.ast.this_expression(SPAN), &storage);
+            let stmt = self.ast.return_statement(SPAN, Some(field));
+            body.push(self.accessor_method(
+                MethodDefinitionKind::Get,
+                key,
+                prop.computed,
+                r#static,
+                None,
+                stmt,
+            ));
+            let value = Atom::from("v");
+            let field = self.private_field_target(self.ast.this_expression(SPAN), &storage);
+            let assign = self.ast.assignment_expression(
+                SPAN,
+                AssignmentOperator::Assign,
+                field,
+                self.reference(&value),
+            );
+            let stmt = self.ast.expression_statement(SPAN, assign);
+            body.push(self.accessor_method(
+                MethodDefinitionKind::Set,
+                prop.key,
+                prop.computed,
+                r#static,
+                Some(value),
+                stmt,
+            ));
+        }
+
+        if let (true, Some(name)) = (decorated, name) {
+            let accessors =
+                if private.is_some() { self.private_accessors(&storage) } else { vec![] };
+            state.members.push(self.member_entry(
+                decorators,
+                Kind::Accessor,
+                r#static,
+                name,
+                accessors,
+            ));
+        }
+    }
+
+    /// Evaluate decorators before the class, `@dec()` -> `_dec = dec()`.
+    ///
+    /// `@obj.dec` keeps `obj` as the receiver: `_obj = obj, _dec = _obj.dec`.
+    fn evaluate_decorators(
+        &mut self,
+        decorators: Vec<'a, Decorator<'a>>,
+        before: &mut std::vec::Vec<Expression<'a>>,
+    ) -> DecoratorList<'a> {
+        let mut list = DecoratorList { decorators: vec![] };
+        for decorator in decorators {
+            let mut expr = decorator.expression;
+            if matches!(expr, Expression::Identifier(_) | Expression::ThisExpression(_)) {
+                list.decorators.push((None, expr));
+                continue;
+            }
+            let mut this = None;
+            if let Expression::MemberExpression(member) = &mut expr {
+                if let MemberExpression::StaticMemberExpression(member) = &mut **member {
+                    this = Some(match &member.object {
+                        Expression::Identifier(_) | Expression::ThisExpression(_) => {
+                            self.ast.copy(&member.object)
+                        }
+                        object => {
+                            let name = self.create_new_var(object).name;
+                            let object = self.ast.move_expression(&mut member.object);
+                            before.push(self.assign(&name, object));
+                            member.object = self.reference(&name);
+                            self.reference(&name)
+                        }
+                    });
+                }
+            }
+            let name = self.create_new_named_var("dec").name;
+            before.push(self.assign(&name, expr));
+            list.decorators.push((this, self.reference(&name)));
+        }
+        list
+    }
+
+    /// The name passed to `_applyDecs2305`, computed keys are memoised as `[_computedKey = key()]`.
+    fn member_name(
+        &mut self,
+        key: &mut PropertyKey<'a>,
+        computed: bool,
+        before: &mut std::vec::Vec<Expression<'a>>,
+    ) -> Expression<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => self.string(&ident.name),
+            PropertyKey::PrivateIdentifier(ident) => self.string(&ident.name),
+            PropertyKey::Expression(expr) if computed && !is_literal(expr) => {
+                let name = self.create_new_named_var("computedKey").name;
+                let value = self.ast.move_expression(expr);
+                before.push(self.assign(&name, value));
+                *expr = self.reference(&name);
+                self.reference(&name)
+            }
+            PropertyKey::Expression(expr) => self.ast.copy(expr),
+        }
+    }
+
+    /// `[dec, flags, "name", ...accessors]`
+    fn member_entry(
+        &self,
+        decorators: DecoratorList<'a>,
+        kind: Kind,
+        r#static: bool,
+        name: Expression<'a>,
+        accessors: std::vec::Vec<Expression<'a>>,
+    ) -> Expression<'a> {
+        let have_this = decorators.have_this();
+        let mut flags = kind as u8;
+        if r#static {
+            flags |= STATIC;
+        }
+        if have_this {
+            flags |= DECORATORS_HAVE_THIS;
+        }
+        let decorators = if !have_this && decorators.decorators.len() == 1 {
+            decorators.decorators.into_iter().next().unwrap().1
+        } else {
+            self.decorators_array(decorators)
+        };
+        let mut elements = self.ast.new_vec();
+        elements.push(ArrayExpressionElement::Expression(decorators));
+        elements.push(ArrayExpressionElement::Expression(self.number(flags.into())));
+        elements.push(ArrayExpressionElement::Expression(name));
+        elements.extend(accessors.into_iter().map(ArrayExpressionElement::Expression));
+        self.ast.array_expression(SPAN, elements, None)
+    }
+
+    /// `[dec1, dec2]`, or `[this1, dec1, this2, dec2]` when a decorator has a receiver.
+    fn decorators_array(&self, decorators: DecoratorList<'a>) -> Expression<'a> {
+        let have_this = decorators.have_this();
+        let mut elements = self.ast.new_vec();
+        for (this, decorator) in decorators.decorators {
+            if have_this {
+                let this = this.unwrap_or_else(|| self.ast.void_0());
+                elements.push(ArrayExpressionElement::Expression(this));
+            }
+            elements.push(ArrayExpressionElement::Expression(decorator));
+        }
+        self.ast.array_expression(SPAN, elements, None)
+    }
+
+    /// `_applyDecs2305(this, [members], [classDecs], classDecsHaveThis, instanceBrand, parentClass)`
+    fn apply_decs(
+        &self,
+        state: &mut ClassState<'a>,
+        class_decorators: DecoratorList<'a>,
+        parent: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        let class_have_this = class_decorators.have_this();
+        let mut members = self.ast.new_vec();
+        members.extend(state.members.drain(..).map(ArrayExpressionElement::Expression));
+
+        let mut arguments = self.ast.new_vec();
+        arguments.push(Argument::Expression(self.ast.this_expression(SPAN)));
+        arguments.push(Argument::Expression(self.ast.array_expression(SPAN, members, None)));
+        arguments.push(Argument::Expression(self.decorators_array(class_decorators)));
+
+        let brand = state.brand.as_ref().map(|brand| {
+            // `_ => #x in _`
+            let param = Atom::from("_");
+            let check = Expression::PrivateInExpression(self.ast.alloc(PrivateInExpression {
+                span: SPAN,
+                left: PrivateIdentifier { span: SPAN, name: brand.clone() },
+                operator: BinaryOperator::In,
+                right: self.reference(&param),
+            }));
+            self.arrow(vec![param], check)
+        });
+        if class_have_this || brand.is_some() || parent.is_some() {
+            arguments.push(Argument::Expression(self.number(class_have_this.into())));
+        }
+        if brand.is_some() || parent.is_some() {
+            let brand = brand.unwrap_or_else(|| self.ast.void_0());
+            arguments.push(Argument::Expression(brand));
+        }
+        if let Some(parent) = parent {
+            arguments.push(Argument::Expression(parent));
+        }
+        self.ctx.helpers.call(Helper::ApplyDecs2305, arguments)
+    }
+
+    /// `class A extends (_B = B())` so the parent class can be passed to `_applyDecs2305`.
+    fn memoise_super_class(&mut self, super_class: &mut Expression<'a>) -> Expression<'a> {
+        if matches!(super_class, Expression::Identifier(_)) {
+            return self.ast.copy(super_class);
+        }
+        let name = self.create_new_var(super_class).name;
+        let value = self.ast.move_expression(super_class);
+        let assign = self.assign(&name, value);
+        *super_class = self.ast.parenthesized_expression(SPAN, assign);
+        self.reference(&name)
+    }
+
+    /// `{ e: [_init_x, _initProto], c: [_A, _initClass] }`, `[_init_x, _initProto]` or `[_A, _initClass]`
+    fn destructure(
+        &self,
+        outputs: &[Atom],
+        class_ref: Option<&(Atom, Atom)>,
+    ) -> AssignmentTarget<'a> {
+        let Some((class_ref, init_class)) = class_ref else {
+            return self.array_target(outputs);
+        };
+        let c = self.array_target(&[class_ref.clone(), init_class.clone()]);
+        if outputs.is_empty() {
+            return c;
+        }
+        let mut properties = self.ast.new_vec_with_capacity(2);
+        for (name, target) in [("e", self.array_target(outputs)), ("c", c)] {
+            let name =
+                self.ast.property_key_identifier(IdentifierName::new(SPAN, Atom::from(name)));
+            let binding = AssignmentTargetMaybeDefault::AssignmentTarget(target);
+            let property = AssignmentTargetPropertyProperty { span: SPAN, name, binding };
+            properties.push(AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+                self.ast.alloc(property),
+            ));
+        }
+        let object = ObjectAssignmentTarget { span: SPAN, properties, rest: None };
+        AssignmentTarget::AssignmentTargetPattern(AssignmentTargetPattern::ObjectAssignmentTarget(
+            self.ast.alloc(object),
+        ))
+    }
+
+    fn array_target(&self, names: &[Atom]) -> AssignmentTarget<'a> {
+        let mut elements = self.ast.new_vec_with_capacity(names.len());
+        for name in names {
+            let target = AssignmentTarget::SimpleAssignmentTarget(
+                self.ast.simple_assignment_target_identifier(IdentifierReference::new(
+                    SPAN,
+                    name.clone(),
+                )),
+            );
+            elements.push(Some(AssignmentTargetMaybeDefault::AssignmentTarget(target)));
+        }
+        let array =
+            ArrayAssignmentTarget { span: SPAN, elements, rest: None, trailing_comma: None };
+        AssignmentTarget::AssignmentTargetPattern(AssignmentTargetPattern::ArrayAssignmentTarget(
+            self.ast.alloc(array),
+        ))
+    }
+
+    /// Run `_initProto(this)` before the first instance field initializer,
+    /// or at the start of the constructor when there are no instance fields.
+    fn insert_init_proto(&mut self, class: &mut Class<'a>, init_proto: &Atom) {
+        let field = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::PropertyDefinition(prop) if !prop.r#static && !prop.declare => Some(prop),
+            _ => None,
+        });
+        if let Some(prop) = field {
+            let call = self.call(init_proto, self.ast.this_expression(SPAN), None);
+            let value = prop.value.take().unwrap_or_else(|| self.ast.void_0());
+            let mut expressions = self.ast.new_vec_with_capacity(2);
+            expressions.push(call);
+            expressions.push(value);
+            prop.value = Some(self.ast.sequence_expression(SPAN, expressions));
+            return;
+        }
+
+        let derived = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                method.value.body.as_mut()
+            }
+            _ => None,
+        });
+        if let Some(body) = constructor {
+            if derived {
+                // `_initProto(super())`
+                SuperCallWrapper { ast: &self.ast, name: init_proto }.visit_function_body(body);
+            } else {
+                let call = self.call(init_proto, self.ast.this_expression(SPAN), None);
+                body.statements.insert(0, self.ast.expression_statement(SPAN, call));
+            }
+            return;
+        }
+
+        // `constructor() { _initProto(this); }` or `constructor(...args) { _initProto(super(...args)); }`
+        let mut rest = None;
+        let this = if derived {
+            let args = self.generate_uid("args");
+            let binding = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, args.clone())),
+                None,
+                false,
+            );
+            rest = Some(self.ast.rest_element(SPAN, binding));
+            let spread = self.ast.spread_element(SPAN, self.reference(&args));
+            self.ast.call_expression(
+                SPAN,
+                self.ast.super_(SPAN),
+                self.ast.new_vec_single(Argument::SpreadElement(spread)),
+                false,
+                None,
+            )
+        } else {
+            self.ast.this_expression(SPAN)
+        };
+        let stmt = self.ast.expression_statement(SPAN, self.call(init_proto, this, None));
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            self.ast.new_vec(),
+            rest,
+        );
+        let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, "constructor".into()));
+        let function = self.function(params, stmt);
+        let constructor = self.ast.alloc(MethodDefinition {
+            span: SPAN,
+            key,
+            value: function,
+            kind: MethodDefinitionKind::Constructor,
+            computed: false,
+            r#static: false,
+            r#override: false,
+            optional: false,
+            accessibility: None,
+            decorators: self.ast.new_vec(),
+        });
+        // After the static block of `_applyDecs2305`.
+        class.body.body.insert(1, ClassElement::MethodDefinition(constructor));
+    }
+
+    /// `o => o.#x` and `(o, v) => o.#x = v`
+    fn private_accessors(&self, name: &Atom) -> std::vec::Vec<Expression<'a>> {
+        let (object, value) = (Atom::from("o"), Atom::from("v"));
+        let get = self.private_field(self.reference(&object), name);
+        let target = self.private_field_target(self.reference(&object), name);
+        let set = self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            self.reference(&value),
+        );
+        vec![self.arrow(vec![object.clone()], get), self.arrow(vec![object, value], set)]
+    }
+
+    fn private_key(&self, name: &Atom) -> PropertyKey<'a> {
+        PropertyKey::PrivateIdentifier(
+            self.ast.alloc(PrivateIdentifier { span: SPAN, name: name.clone() }),
+        )
+    }
+
+    fn private_field(&self, object: Expression<'a>, name: &Atom) -> Expression<'a> {
+        let field = PrivateIdentifier { span: SPAN, name: name.clone() };
+        self.ast.private_field_expression(SPAN, object, field, false)
+    }
+
+    fn private_field_target(&self, object: Expression<'a>, name: &Atom) -> AssignmentTarget<'a> {
+        let field = PrivateIdentifier { span: SPAN, name: name.clone() };
+        let member = MemberExpression::PrivateFieldExpression(PrivateFieldExpression {
+            span: SPAN,
+            object,
+            field,
+            optional: false,
+        });
+        AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_member_expression(member),
+        )
+    }
+
+    /// `get #x() { ... }` or `set #x(v) { ... }`
+    fn method(
+        &self,
+        kind: MethodDefinitionKind,
+        private: &Atom,
+        r#static: bool,
+        param: Option<Atom>,
+        stmt: Statement<'a>,
+    ) -> ClassElement<'a> {
+        self.accessor_method(kind, self.private_key(private), false, r#static, param, stmt)
+    }
+
+    fn accessor_method(
+        &self,
+        kind: MethodDefinitionKind,
+        key: PropertyKey<'a>,
+        computed: bool,
+        r#static: bool,
+        param: Option<Atom>,
+        stmt: Statement<'a>,
+    ) -> ClassElement<'a> {
+        let params =
+            self.params(FormalParameterKind::UniqueFormalParameters, param.into_iter().collect());
+        let method = self.ast.alloc(MethodDefinition {
+            span: SPAN,
+            key,
+            value: self.function(params, stmt),
+            kind,
+            computed,
+            r#static,
+            r#override: false,
+            optional: false,
+            accessibility: None,
+            decorators: self.ast.new_vec(),
+        });
+        ClassElement::MethodDefinition(method)
+    }
+
+    fn function(
+        &self,
+        params: Box<'a, FormalParameters<'a>>,
+        stmt: Statement<'a>,
+    ) -> Box<'a, Function<'a>> {
+        let body = self.ast.function_body(SPAN, self.ast.new_vec(), self.ast.new_vec_single(stmt));
+        self.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            None,
+            Modifiers::empty(),
+        )
+    }
+
+    fn arrow(&self, params: std::vec::Vec<Atom>, expr: Expression<'a>) -> Expression<'a> {
+        let params = self.params(FormalParameterKind::ArrowFormalParameters, params);
+        let stmt = self.ast.expression_statement(SPAN, expr);
+        let body = self.ast.function_body(SPAN, self.ast.new_vec(), self.ast.new_vec_single(stmt));
+        self.ast.arrow_expression(SPAN, true, false, false, params, body, None, None, None)
+    }
+
+    fn params(
+        &self,
+        kind: FormalParameterKind,
+        names: std::vec::Vec<Atom>,
+    ) -> Box<'a, FormalParameters<'a>> {
+        let mut items = self.ast.new_vec_with_capacity(names.len());
+        for name in names {
+            let pattern = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name)),
+                None,
+                false,
+            );
+            items.push(self.ast.formal_parameter(SPAN, pattern, None, false, self.ast.new_vec()));
+        }
+        self.ast.formal_parameters(SPAN, kind, items, None)
+    }
+
+    /// `name(this, value)`
+    fn call(
+        &self,
+        name: &Atom,
+        this: Expression<'a>,
+        value: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        let mut arguments = self.ast.new_vec_single(Argument::Expression(this));
+        arguments.extend(value.map(Argument::Expression));
+        self.ast.call_expression(SPAN, self.reference(name), arguments, false, None)
+    }
+
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, object, property, false)
+    }
+
+    fn sequence(&self, expressions: std::vec::Vec<Expression<'a>>) -> Expression<'a> {
+        let mut sequence = self.ast.new_vec_with_capacity(expressions.len());
+        sequence.extend(expressions);
+        self.ast.sequence_expression(SPAN, sequence)
+    }
+
+    fn assign(&self, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let left = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast
+                .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone())),
+        );
+        self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, value)
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn string(&self, value: &Atom) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(SPAN, value.clone()))
+    }
+
+    fn number(&self, value: u32) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        let literal = self.ast.number_literal(SPAN, f64::from(value), raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+}
+
+/// Classes with decorators or `accessor` fields.
+fn needs_transform(class: &Class) -> bool {
+    !class.decorators.is_empty()
+        || class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+            ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+            ClassElement::AccessorProperty(_) => true,
+            _ => false,
+        })
+}
+
+fn collect_private_names(class: &Class) -> FxHashSet<Atom> {
+    class
+        .body
+        .body
+        .iter()
+        .filter_map(|element| match element {
+            ClassElement::MethodDefinition(method) => method.key.private_name(),
+            ClassElement::PropertyDefinition(prop) => prop.key.private_name(),
+            ClassElement::AccessorProperty(prop) => prop.key.private_name(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `#A`, `#B`, ..., `#Z`, `#AA`, skipping the private names of the class.
+fn storage_name(names: &mut FxHashSet<Atom>) -> Atom {
+    let mut index = names.len();
+    loop {
+        let mut name = String::new();
+        let mut n = index;
+        loop {
+            name.insert(0, char::from(b'A' + u8::try_from(n % 26).unwrap_or(0)));
+            if n < 26 {
+                break;
+            }
+            n = n / 26 - 1;
+        }
+        let name = Atom::from(name);
+        if names.insert(name.clone()) {
+            return name;
+        }
+        index += 1;
+    }
+}
+
+/// Name of the variables of a member, `_init_x` for `x` and `#x`.
+fn var_name(key: &PropertyKey) -> String {
+    match key {
+        PropertyKey::Identifier(ident) => ident.name.to_string(),
+        PropertyKey::PrivateIdentifier(ident) => ident.name.to_string(),
+        PropertyKey::Expression(_) => "computedKey".to_string(),
+    }
+}
+
+fn is_literal(expr: &Expression) -> bool {
+    matches!(expr, Expression::StringLiteral(_) | Expression::NumberLiteral(_))
+}
+
+fn is_literal_key(key: &PropertyKey) -> bool {
+    !matches!(key, PropertyKey::Expression(expr) if !is_literal(expr))
+}
+
+/// Replaces `super()` with `_initProto(super())` in a derived constructor.
+struct SuperCallWrapper<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    name: &'b Atom,
+}
+
+impl<'a, 'b> VisitMut<'a> for SuperCallWrapper<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.visit_expression_match(expr);
+        if matches!(expr, Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_)))
+        {
+            let callee = self
+                .ast
+                .identifier_reference_expression(IdentifierReference::new(SPAN, self.name.clone()));
+            let call = self.ast.move_expression(expr);
+            let arguments = self.ast.new_vec_single(Argument::Expression(call));
+            *expr = self.ast.call_expression(SPAN, callee, arguments, false, None);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        decorators: Some(DecoratorsOptions::default()),
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "class A { accessor x = 1; static accessor [y] }",
+            "var _computedKey;
+            _computedKey = y;
+            class A {
+                #A = 1;
+                get x() { return this.#A; }
+                set x(v) { this.#A = v; }
+                static #B;
+                static get [_computedKey]() { return this.#B; }
+                static set [_computedKey](v) { this.#B = v; }
+            }",
+        ),
+        (
+            "@dec class A { @log method() {} @reactive accessor x = 1; }",
+            "import _applyDecs2305 from '@babel/runtime/helpers/applyDecs2305';
+            var _init_x, _initProto, _A, _initClass;
+            class A {
+                static {
+                    ({ e: [_init_x, _initProto], c: [_A, _initClass] } = _applyDecs2305(this, [[log, 2, 'method'], [reactive, 1, 'x']], [dec]));
+                }
+                method() {}
+                #A = (_initProto(this), _init_x(this, 1));
+                get x() { return this.#A; }
+                set x(v) { this.#A = v; }
+                static { _initClass(); }
+            }
+            A = _A;",
+        ),
+        (
+            "class A extends B { @a.b @c #m() {} @dec static x; }",
+            "import _applyDecs2305 from '@babel/runtime/helpers/applyDecs2305';
+            var _dec, _call_m, _init_x, _initProto;
+            _dec = a.b;
+            class A extends B {
+                static {
+                    [_call_m, _init_x, _initProto] = _applyDecs2305(this, [
+                        [[a, _dec, void 0, c], 18, 'm', function () {}],
+                        [dec, 8, 'x']
+                    ], [], 0, (_) => #m in _, B).e;
+                }
+                constructor(..._args) { _initProto(super(..._args)); }
+                get #m() { return _call_m; }
+                static x = _init_x(this);
+            }",
+        ),
+        (
+            "export default @dec class {}",
+            "import _applyDecs2305 from '@babel/runtime/helpers/applyDecs2305';
+            var _default2, _initClass;
+            export default class _default {
+                static { [_default2, _initClass] = _applyDecs2305(this, [], [dec]).c; }
+                static { _initClass(); }
+            }
+            _default = _default2;",
+        ),
+        (
+            "const A = @dec class { @dec get #x() { return 1; } }",
+            "import _applyDecs2305 from '@babel/runtime/helpers/applyDecs2305';
+            var _call_x, _initProto, _Class, _initClass;
+            const A = (class {
+                static {
+                    ({ e: [_call_x, _initProto], c: [_Class, _initClass] } = _applyDecs2305(this, [[dec, 3, 'x', function () { return 1; }]], [dec], 0, (_) => #x in _));
+                }
+                constructor() { _initProto(this); }
+                get #x() { return _call_x(this); }
+                static { _initClass(); }
+            }, _Class);",
+        ),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+}
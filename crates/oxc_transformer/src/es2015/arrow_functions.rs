@@ -0,0 +1,304 @@
+use std::rc::Rc;
+
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_span::{Atom, SPAN};
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+};
+
+/// ES2015: Arrow Functions
+///
+/// `() => this` is transformed to `var _this = this; (function () { return _this; })`.
+/// `this`, `arguments` and `new.target` are captured from the enclosing function,
+/// arrows inside class field initializers are bound with `.bind(this)` instead.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-arrow-functions>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-arrow-functions>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-traverse/src/path/conversion.ts>
+pub struct ArrowFunctions<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    /// Stack of the scopes which provide `this` to arrow functions.
+    scopes: std::vec::Vec<ThisScope>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ThisScopeKind {
+    Program,
+    Function,
+    ClassField,
+}
+
+struct ThisScope {
+    kind: ThisScopeKind,
+    this: Option<Atom>,
+    arguments: Option<Atom>,
+    new_target: Option<Atom>,
+}
+
+impl ThisScope {
+    fn new(kind: ThisScopeKind) -> Self {
+        Self { kind, this: None, arguments: None, new_target: None }
+    }
+}
+
+impl<'a> ArrowFunctions<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.arrow_functions).then(|| Self {
+            ast,
+            ctx,
+            scopes: vec![ThisScope::new(ThisScopeKind::Program)],
+        })
+    }
+
+    pub fn exit_program(&mut self, program: &mut Program<'a>) {
+        if let Some(scope) = self.scopes.pop() {
+            if let Some(stmt) = self.create_captured_declaration(scope) {
+                program.body.insert(0, stmt);
+            }
+        }
+    }
+
+    pub fn enter_function(&mut self) {
+        self.scopes.push(ThisScope::new(ThisScopeKind::Function));
+    }
+
+    /// Returns `true` if a declaration of the captured variables was inserted into the function body.
+    pub fn exit_function(&mut self, func: &mut Function<'a>) -> bool {
+        let Some(scope) = self.scopes.pop() else { return false };
+        let Some(body) = &mut func.body else { return false };
+        if let Some(stmt) = self.create_captured_declaration(scope) {
+            body.statements.insert(0, stmt);
+            return true;
+        }
+        false
+    }
+
+    pub fn enter_class_field(&mut self) {
+        self.scopes.push(ThisScope::new(ThisScopeKind::ClassField));
+    }
+
+    pub fn exit_class_field(&mut self) {
+        self.scopes.pop();
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ArrowExpression(arrow) = expr else { return };
+        let Some(scope) = self.scopes.last_mut() else { return };
+
+        let bind_this = scope.kind == ThisScopeKind::ClassField;
+        if !bind_this {
+            let mut replacer = ThisReplacer { ast: &self.ast, ctx: &self.ctx, scope };
+            replacer.visit_formal_parameters(&mut arrow.params);
+            replacer.visit_function_body(&mut arrow.body);
+        }
+
+        let Expression::ArrowExpression(arrow) = self.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let arrow = arrow.unbox();
+        let mut body = arrow.body;
+        if arrow.expression {
+            // `() => x` -> `function () { return x; }`
+            if let Some(Statement::ExpressionStatement(stmt)) = body.statements.pop() {
+                let argument = stmt.unbox().expression;
+                body.statements.push(self.ast.return_statement(SPAN, Some(argument)));
+            }
+        }
+        let mut params = arrow.params;
+        params.kind = FormalParameterKind::FormalParameter;
+        let func = self.ast.function(
+            FunctionType::FunctionExpression,
+            arrow.span,
+            None,
+            false,
+            false,
+            arrow.r#async,
+            params,
+            Some(body),
+            None,
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let func = self.ast.function_expression(func);
+
+        *expr = if bind_this {
+            // `function () {}.bind(this)`
+            let property = IdentifierName::new(SPAN, "bind".into());
+            let callee = self.ast.static_member_expression(SPAN, func, property, false);
+            let this = self.ast.this_expression(SPAN);
+            let arguments = self.ast.new_vec_single(Argument::Expression(this));
+            self.ast.call_expression(SPAN, callee, arguments, false, None)
+        } else {
+            func
+        };
+    }
+
+    /// `var _this = this, _arguments = arguments, _newtarget = new.target;`
+    fn create_captured_declaration(&self, scope: ThisScope) -> Option<Statement<'a>> {
+        let mut declarators = self.ast.new_vec();
+        if let Some(name) = scope.this {
+            let init = self.ast.this_expression(SPAN);
+            declarators.push(self.create_declarator(name, init));
+        }
+        if let Some(name) = scope.arguments {
+            let ident = IdentifierReference::new(SPAN, "arguments".into());
+            let init = self.ast.identifier_reference_expression(ident);
+            declarators.push(self.create_declarator(name, init));
+        }
+        if let Some(name) = scope.new_target {
+            let meta = IdentifierName::new(SPAN, "new".into());
+            let property = IdentifierName::new(SPAN, "target".into());
+            let init = self.ast.meta_property(SPAN, meta, property);
+            declarators.push(self.create_declarator(name, init));
+        }
+        if declarators.is_empty() {
+            return None;
+        }
+        let kind = VariableDeclarationKind::Var;
+        let decl = self.ast.variable_declaration(SPAN, kind, declarators, Modifiers::empty());
+        Some(Statement::Declaration(Declaration::VariableDeclaration(decl)))
+    }
+
+    fn create_declarator(&self, name: Atom, init: Expression<'a>) -> VariableDeclarator<'a> {
+        let ident = BindingIdentifier::new(SPAN, name);
+        let id = self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false);
+        self.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, Some(init), false)
+    }
+}
+
+/// Replaces `this`, `arguments` and `new.target` inside an arrow function,
+/// including nested arrow functions which share the same `this`.
+struct ThisReplacer<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    ctx: &'b TransformerCtx<'a>,
+    scope: &'b mut ThisScope,
+}
+
+impl<'a, 'b> ThisReplacer<'a, 'b> {
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        self.ctx.scopes().generate_uid_based_on_name(name)
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for ThisReplacer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(_) => {
+                let name = match &self.scope.this {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = self.generate_uid("this");
+                        self.scope.this.replace(name.clone());
+                        name
+                    }
+                };
+                *expr = self.reference(&name);
+                return;
+            }
+            // `arguments` at the top level is a regular identifier.
+            Expression::Identifier(ident)
+                if ident.name == "arguments"
+                    && self.scope.kind == ThisScopeKind::Function
+                    && !ident
+                        .reference_id
+                        .get()
+                        .is_some_and(|id| self.ctx.symbols().has_binding(id)) =>
+            {
+                let name = match &self.scope.arguments {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = self.generate_uid("arguments");
+                        self.scope.arguments.replace(name.clone());
+                        name
+                    }
+                };
+                *expr = self.reference(&name);
+                return;
+            }
+            Expression::MetaProperty(meta)
+                if meta.meta.name == "new"
+                    && meta.property.name == "target"
+                    && self.scope.kind == ThisScopeKind::Function =>
+            {
+                let name = match &self.scope.new_target {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = self.generate_uid("newtarget");
+                        self.scope.new_target.replace(name.clone());
+                        name
+                    }
+                };
+                *expr = self.reference(&name);
+                return;
+            }
+            _ => {}
+        }
+        self.visit_expression_match(expr);
+    }
+
+    /// `this` is rebound in non-arrow functions.
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    /// Only the heritage and computed keys of a class are evaluated in the outer `this` context.
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in class.body.body.iter_mut() {
+            match element {
+                ClassElement::MethodDefinition(def) if def.computed => {
+                    self.visit_property_key(&mut def.key);
+                }
+                ClassElement::PropertyDefinition(def) if def.computed => {
+                    self.visit_property_key(&mut def.key);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2022,
+        arrow_functions: true,
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        ("var a = () => 1;", "var a = function() { return 1; };"),
+        (
+            "function foo() { return () => this.x + arguments[0]; }",
+            "function foo() { var _this = this, _arguments = arguments; return function() { return _this.x + _arguments[0]; }; }",
+        ),
+        (
+            "function foo() { return () => () => this; }",
+            "function foo() { var _this = this; return function() { return function() { return _this; }; }; }",
+        ),
+        (
+            "class A { foo = () => this; }",
+            "class A { foo = function() { return this; }.bind(this); }",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+}
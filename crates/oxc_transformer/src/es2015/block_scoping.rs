@@ -0,0 +1,842 @@
+use std::rc::Rc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, AstKind, Visit, VisitMut};
+use oxc_semantic::{ReferenceId, ScopeFlags, SymbolFlags, SymbolId, SymbolTable};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+};
+
+/// ES2015: Block Scoping
+///
+/// `let` and `const` declarations are converted to `var`.
+/// Bindings which would conflict with another binding once hoisted to the function scope are renamed,
+/// and loops whose bindings are captured by closures are moved into a function so each iteration gets a fresh binding:
+///
+/// ```javascript
+/// for (let i = 0; i < 3; i++) { fns.push(() => i); }
+/// // is transformed to
+/// var _loop = (i) => { fns.push(() => i); };
+/// for (var i = 0; i < 3; i++) { _loop(i); }
+/// ```
+///
+/// When the body of a `for` loop writes to its bindings, the closure gets its own copy which is written back:
+///
+/// ```javascript
+/// for (let i = 0; i < 3; i++) { fns.push(() => i); i++; }
+/// // is transformed to
+/// var _loop = (_i) => { fns.push(() => _i); _i++; i = _i; };
+/// for (var i = 0; i < 3; i++) { _loop(i); }
+/// ```
+///
+/// Writes to `const` bindings throw with the `_readOnlyError` helper, `a = 1` -> `1, _readOnlyError("a")`.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-block-scoping>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-block-scoping>
+pub struct BlockScoping<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    renames: FxHashMap<SymbolId, Atom>,
+    /// References to loop bindings which are copied into the `_loop` closure.
+    reference_renames: FxHashMap<ReferenceId, Atom>,
+    /// Number of loops entered in each function.
+    loop_depths: std::vec::Vec<usize>,
+}
+
+impl<'a> BlockScoping<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.block_scoping).then(|| Self {
+            ast,
+            ctx,
+            renames: FxHashMap::default(),
+            reference_renames: FxHashMap::default(),
+            loop_depths: vec![0],
+        })
+    }
+
+    /// Collect the block scoped bindings which need to be renamed after they are hoisted.
+    pub fn enter_program(&mut self) {
+        let renames = {
+            let symbols = self.ctx.symbols();
+            let scopes = self.ctx.scopes();
+            let mut counts: FxHashMap<&Atom, usize> = FxHashMap::default();
+            for name in &symbols.names {
+                *counts.entry(name).or_default() += 1;
+            }
+            symbols
+                .iter()
+                .filter(|&symbol_id| {
+                    let flags = symbols.get_flag(symbol_id);
+                    flags.contains(SymbolFlags::BlockScopedVariable)
+                        && !flags.intersects(SymbolFlags::Class | SymbolFlags::Function)
+                        && !scopes
+                            .get_flags(symbols.get_scope_id(symbol_id))
+                            .intersects(ScopeFlags::Var)
+                })
+                .filter(|&symbol_id| {
+                    let name = symbols.get_name(symbol_id);
+                    counts.get(name).is_some_and(|&count| count > 1)
+                        || scopes.root_unresolved_references().contains_key(name)
+                })
+                .map(|symbol_id| (symbol_id, symbols.get_name(symbol_id).clone()))
+                .collect::<std::vec::Vec<_>>()
+        };
+        for (symbol_id, name) in renames {
+            let new_name = self.ctx.scopes().generate_uid_based_on_name(&name);
+            self.ctx.add_binding(new_name.clone());
+            self.renames.insert(symbol_id, new_name);
+        }
+    }
+
+    pub fn enter_function(&mut self) {
+        self.loop_depths.push(0);
+    }
+
+    pub fn exit_function(&mut self) {
+        self.loop_depths.pop();
+    }
+
+    /// The loop head is evaluated once, so its declarations are transformed before entering the loop.
+    pub fn enter_loop(&mut self, stmt: &mut Statement<'a>) {
+        let head = match stmt {
+            Statement::ForStatement(stmt) => match &mut stmt.init {
+                Some(ForStatementInit::VariableDeclaration(decl)) => Some(decl),
+                _ => None,
+            },
+            Statement::ForInStatement(stmt) => match &mut stmt.left {
+                ForStatementLeft::VariableDeclaration(decl) => Some(decl),
+                _ => None,
+            },
+            Statement::ForOfStatement(stmt) => match &mut stmt.left {
+                ForStatementLeft::VariableDeclaration(decl) => Some(decl),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(decl) = head {
+            self.transform_variable_declaration(decl);
+        }
+        if let Some(depth) = self.loop_depths.last_mut() {
+            *depth += 1;
+        }
+    }
+
+    pub fn exit_loop(&mut self) {
+        if let Some(depth) = self.loop_depths.last_mut() {
+            *depth -= 1;
+        }
+    }
+
+    pub fn transform_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
+        if let Some(name) = ident.symbol_id.get().and_then(|id| self.renames.get(&id)) {
+            ident.name = name.clone();
+        }
+    }
+
+    pub fn transform_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        if let Some(name) = self.reference_renames.get(&reference_id) {
+            ident.name = name.clone();
+            return;
+        }
+        let Some(symbol_id) = self.ctx.symbols().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        if let Some(name) = self.renames.get(&symbol_id) {
+            ident.name = name.clone();
+        }
+    }
+
+    /// Replace the writes to `const` bindings, the value is still evaluated before throwing.
+    ///
+    /// * `a = b` -> `b, _readOnlyError("a")`
+    /// * `a += b` -> `a + b, _readOnlyError("a")`
+    /// * `a ||= b` -> `a || (b, _readOnlyError("a"))`
+    /// * `a++` -> `+a, _readOnlyError("a")`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let name = {
+            let symbols = self.ctx.symbols();
+            let mut finder = ConstViolationFinder { symbols: &symbols, name: None };
+            match expr {
+                Expression::AssignmentExpression(assign) => {
+                    finder.visit_assignment_target(&assign.left);
+                }
+                Expression::UpdateExpression(update) => {
+                    finder.visit_simple_assignment_target(&update.argument);
+                }
+                _ => return,
+            }
+            let Some(name) = finder.name else { return };
+            name
+        };
+        let name = self.ast.literal_string_expression(StringLiteral::new(SPAN, name));
+        let throw = self
+            .ctx
+            .helpers
+            .call(Helper::ReadOnlyError, self.ast.new_vec_single(Argument::Expression(name)));
+
+        let value = match self.ast.move_expression(expr) {
+            Expression::AssignmentExpression(assign) => {
+                let assign = assign.unbox();
+                let left = match assign.left {
+                    AssignmentTarget::SimpleAssignmentTarget(
+                        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+                    ) => Some(self.ast.identifier_reference_expression(ident.unbox())),
+                    _ => None,
+                };
+                match (left, assign.operator.to_logical_operator()) {
+                    (Some(left), Some(operator)) => {
+                        let mut expressions = self.ast.new_vec_with_capacity(2);
+                        expressions.push(assign.right);
+                        expressions.push(throw);
+                        let right = self.ast.sequence_expression(SPAN, expressions);
+                        *expr = self.ast.logical_expression(SPAN, left, operator, right);
+                        return;
+                    }
+                    (Some(left), None) => match assign.operator.to_binary_operator() {
+                        Some(operator) => {
+                            self.ast.binary_expression(SPAN, left, operator, assign.right)
+                        }
+                        None => assign.right,
+                    },
+                    (None, _) => assign.right,
+                }
+            }
+            Expression::UpdateExpression(update) => match update.unbox().argument {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                    let argument = self.ast.identifier_reference_expression(ident.unbox());
+                    self.ast.unary_expression(SPAN, UnaryOperator::UnaryPlus, argument)
+                }
+                _ => unreachable!("only identifiers are `const` bindings"),
+            },
+            _ => unreachable!(),
+        };
+        let mut expressions = self.ast.new_vec_with_capacity(2);
+        expressions.push(value);
+        expressions.push(throw);
+        *expr = self.ast.sequence_expression(SPAN, expressions);
+    }
+
+    /// `let a = 1` -> `var a = 1`
+    pub fn transform_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if !decl.kind.is_lexical() {
+            return;
+        }
+        decl.kind = VariableDeclarationKind::Var;
+        let in_loop = self.loop_depths.last().is_some_and(|depth| *depth > 0);
+        for declarator in decl.declarations.iter_mut() {
+            declarator.kind = VariableDeclarationKind::Var;
+            // `let a;` is reset to `undefined` in each iteration of a loop.
+            if in_loop
+                && declarator.init.is_none()
+                && matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_))
+            {
+                declarator.init = Some(self.ast.void_0());
+            }
+        }
+    }
+
+    /// Move the bodies of loops whose block scoped bindings are captured by closures into a `_loop` function.
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            if let Some(stmt) = self.transform_loop(stmts.get_mut(i).unwrap()) {
+                stmts.insert(i, stmt);
+                i += 1;
+            }
+            i += 1;
+        }
+    }
+
+    /// Returns the `var _loop = () => {}` declaration to insert before the loop.
+    fn transform_loop(&mut self, stmt: &mut Statement<'a>) -> Option<Statement<'a>> {
+        let stmt = match stmt {
+            Statement::LabeledStatement(labeled) => &mut labeled.body,
+            stmt => stmt,
+        };
+        let is_for = matches!(stmt, Statement::ForStatement(_));
+        let (head, body) = match stmt {
+            Statement::ForStatement(stmt) => {
+                let stmt = &mut **stmt;
+                let head = match &stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) if decl.kind.is_lexical() => {
+                        Some(&**decl)
+                    }
+                    _ => None,
+                };
+                (head, &mut stmt.body)
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = &mut **stmt;
+                (lexical_left(&stmt.left), &mut stmt.body)
+            }
+            Statement::ForOfStatement(stmt) => {
+                let stmt = &mut **stmt;
+                (lexical_left(&stmt.left), &mut stmt.body)
+            }
+            Statement::WhileStatement(stmt) => (None, &mut stmt.body),
+            Statement::DoWhileStatement(stmt) => (None, &mut stmt.body),
+            _ => return None,
+        };
+
+        let mut params = vec![];
+        let (body_references, written) = {
+            let symbols = self.ctx.symbols();
+            let mut analyzer = LoopAnalyzer::new(&symbols);
+            analyzer.visit_statement(body);
+            let body_references = std::mem::take(&mut analyzer.references);
+            let written = std::mem::take(&mut analyzer.written);
+            if let Some(head) = head {
+                analyzer.visit_variable_declaration(head);
+                for declarator in &head.declarations {
+                    collect_binding_identifiers(&declarator.id, &mut params);
+                }
+            }
+            if !analyzer.needs_closure() {
+                return None;
+            }
+            (body_references, written)
+        };
+
+        // The loop bindings passed to the closure, with the name of the copy in the closure.
+        let mut bindings = vec![];
+        // `i = _i;` for the bindings written by the body of a `for` loop.
+        let mut write_back = vec![];
+        for ident in params {
+            let symbol_id = ident.symbol_id.get();
+            let name = symbol_id
+                .and_then(|id| self.renames.get(&id))
+                .map_or_else(|| ident.name.clone(), Clone::clone);
+            match symbol_id {
+                Some(symbol_id) if is_for && written.contains(&symbol_id) => {
+                    let copy = self.generate_uid(&name);
+                    for (reference_id, _) in
+                        body_references.iter().filter(|(_, id)| *id == symbol_id)
+                    {
+                        self.reference_renames.insert(*reference_id, copy.clone());
+                    }
+                    write_back.push((name.clone(), copy.clone()));
+                    bindings.push((name, copy));
+                }
+                _ => bindings.push((name.clone(), name)),
+            }
+        }
+
+        let mut replacer = JumpReplacer::new(&self.ast, &write_back);
+        replacer.visit_statement(body);
+        let loop_name = self.generate_uid("loop");
+
+        // `var _loop = (i) => { body };`
+        let mut statements = match self.ast.move_statement(body) {
+            Statement::BlockStatement(block) => block.unbox().body,
+            stmt => self.ast.new_vec_single(stmt),
+        };
+        statements.extend(write_back_statements(&self.ast, &write_back));
+        let items = Vec::from_iter_in(
+            bindings.iter().map(|(_, copy)| {
+                let pattern = self.ast.binding_pattern(
+                    self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, copy.clone())),
+                    None,
+                    false,
+                );
+                self.ast.formal_parameter(SPAN, pattern, None, false, self.ast.new_vec())
+            }),
+            self.ast.allocator,
+        );
+        let formal_parameters = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            None,
+        );
+        let function_body = self.ast.function_body(SPAN, self.ast.new_vec(), statements);
+        let closure = self.ast.arrow_expression(
+            SPAN,
+            false,
+            false,
+            false,
+            formal_parameters,
+            function_body,
+            None,
+            None,
+            None,
+        );
+        let declaration = self.var_declaration(loop_name.clone(), closure);
+
+        // `_loop(i);` or `var _ret = _loop(i); if (_ret === "break") break;`
+        let arguments = Vec::from_iter_in(
+            bindings.iter().map(|(name, _)| Argument::Expression(self.reference(name))),
+            self.ast.allocator,
+        );
+        let call =
+            self.ast.call_expression(SPAN, self.reference(&loop_name), arguments, false, None);
+        let mut statements = self.ast.new_vec();
+        if replacer.jumps.is_empty() && !replacer.has_return {
+            statements.push(self.ast.expression_statement(SPAN, call));
+        } else {
+            let ret = self.generate_uid("ret");
+            statements.push(self.var_declaration(ret.clone(), call));
+            for jump in &replacer.jumps {
+                let value = self
+                    .ast
+                    .literal_string_expression(StringLiteral::new(SPAN, Atom::from(jump.as_str())));
+                let test = self.ast.binary_expression(
+                    SPAN,
+                    self.reference(&ret),
+                    BinaryOperator::StrictEquality,
+                    value,
+                );
+                let consequent = match jump.split_once('|') {
+                    Some(("break", label)) => {
+                        self.ast.break_statement(SPAN, Some(label_identifier(label)))
+                    }
+                    Some((_, label)) => {
+                        self.ast.continue_statement(SPAN, Some(label_identifier(label)))
+                    }
+                    None => self.ast.break_statement(SPAN, None),
+                };
+                statements.push(self.ast.if_statement(SPAN, test, consequent, None));
+            }
+            if replacer.has_return {
+                // `if (typeof _ret === "object") return _ret.v;`
+                let type_of =
+                    self.ast.unary_expression(SPAN, UnaryOperator::Typeof, self.reference(&ret));
+                let object =
+                    self.ast.literal_string_expression(StringLiteral::new(SPAN, "object".into()));
+                let test = self.ast.binary_expression(
+                    SPAN,
+                    type_of,
+                    BinaryOperator::StrictEquality,
+                    object,
+                );
+                let property = IdentifierName::new(SPAN, "v".into());
+                let value =
+                    self.ast.static_member_expression(SPAN, self.reference(&ret), property, false);
+                let consequent = self.ast.return_statement(SPAN, Some(value));
+                statements.push(self.ast.if_statement(SPAN, test, consequent, None));
+            }
+        }
+        *body = self.ast.block_statement(self.ast.block(SPAN, statements));
+
+        Some(declaration)
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn var_declaration(&self, name: Atom, init: Expression<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let id = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name)),
+            None,
+            false,
+        );
+        let declarator = self.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+}
+
+fn lexical_left<'b, 'a>(left: &'b ForStatementLeft<'a>) -> Option<&'b VariableDeclaration<'a>> {
+    match left {
+        ForStatementLeft::VariableDeclaration(decl) if decl.kind.is_lexical() => Some(decl),
+        _ => None,
+    }
+}
+
+/// `i = _i;`
+fn write_back_statements<'a>(
+    ast: &AstBuilder<'a>,
+    write_back: &[(Atom, Atom)],
+) -> std::vec::Vec<Statement<'a>> {
+    write_back
+        .iter()
+        .map(|(name, copy)| {
+            let target = ast
+                .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone()));
+            let value =
+                ast.identifier_reference_expression(IdentifierReference::new(SPAN, copy.clone()));
+            let assignment = ast.assignment_expression(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::SimpleAssignmentTarget(target),
+                value,
+            );
+            ast.expression_statement(SPAN, assignment)
+        })
+        .collect()
+}
+
+fn label_identifier(name: &str) -> LabelIdentifier {
+    LabelIdentifier { span: SPAN, name: Atom::from(name) }
+}
+
+fn collect_binding_identifiers(
+    pattern: &BindingPattern,
+    out: &mut std::vec::Vec<BindingIdentifier>,
+) {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => out.push((**ident).clone()),
+        BindingPatternKind::ObjectPattern(obj) => {
+            for property in &obj.properties {
+                collect_binding_identifiers(&property.value, out);
+            }
+            if let Some(rest) = &obj.rest {
+                collect_binding_identifiers(&rest.argument, out);
+            }
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            for element in array.elements.iter().flatten() {
+                collect_binding_identifiers(element, out);
+            }
+            if let Some(rest) = &array.rest {
+                collect_binding_identifiers(&rest.argument, out);
+            }
+        }
+        BindingPatternKind::AssignmentPattern(assign) => {
+            collect_binding_identifiers(&assign.left, out);
+        }
+    }
+}
+
+/// Finds the first `const` binding written by an assignment target.
+struct ConstViolationFinder<'b> {
+    symbols: &'b SymbolTable,
+    name: Option<Atom>,
+}
+
+impl<'a, 'b> Visit<'a> for ConstViolationFinder<'b> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference) {
+        if self.name.is_some() {
+            return;
+        }
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let reference = self.symbols.get_reference(reference_id);
+        if !reference.is_write() {
+            return;
+        }
+        if let Some(symbol_id) = reference.symbol_id() {
+            if self.symbols.get_flag(symbol_id).is_const_variable() {
+                self.name = Some(ident.name.clone());
+            }
+        }
+    }
+
+    // Default values, computed keys and member expressions are not written to.
+    fn visit_expression(&mut self, _expr: &Expression<'a>) {}
+
+    fn visit_member_expression(&mut self, _expr: &MemberExpression<'a>) {}
+}
+
+/// Finds block scoped bindings of a loop which are referenced from closures.
+struct LoopAnalyzer<'b> {
+    symbols: &'b SymbolTable,
+    /// Whether each entered scope is a function scope.
+    scopes: std::vec::Vec<bool>,
+    function_depth: usize,
+    lexical_declaration_depth: usize,
+    declared: FxHashSet<SymbolId>,
+    captured: FxHashSet<SymbolId>,
+    /// Symbols written to.
+    written: FxHashSet<SymbolId>,
+    /// All the resolved references.
+    references: std::vec::Vec<(ReferenceId, SymbolId)>,
+    /// `var` declarations, `yield` and `await` can not be moved into a closure.
+    unsupported: bool,
+}
+
+impl<'b> LoopAnalyzer<'b> {
+    fn new(symbols: &'b SymbolTable) -> Self {
+        Self {
+            symbols,
+            scopes: vec![],
+            function_depth: 0,
+            lexical_declaration_depth: 0,
+            declared: FxHashSet::default(),
+            captured: FxHashSet::default(),
+            written: FxHashSet::default(),
+            references: vec![],
+            unsupported: false,
+        }
+    }
+
+    fn needs_closure(&self) -> bool {
+        !self.unsupported && self.declared.iter().any(|symbol_id| self.captured.contains(symbol_id))
+    }
+}
+
+impl<'a, 'b> Visit<'a> for LoopAnalyzer<'b> {
+    fn enter_scope(&mut self, flags: ScopeFlags) {
+        let is_function = flags.intersects(ScopeFlags::Function | ScopeFlags::ClassStaticBlock);
+        if is_function {
+            self.function_depth += 1;
+        }
+        self.scopes.push(is_function);
+    }
+
+    fn leave_scope(&mut self) {
+        if self.scopes.pop() == Some(true) {
+            self.function_depth -= 1;
+        }
+    }
+
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if self.function_depth > 0 {
+            return;
+        }
+        match kind {
+            AstKind::VariableDeclaration(decl) if decl.kind.is_lexical() => {
+                self.lexical_declaration_depth += 1;
+            }
+            AstKind::VariableDeclaration(_)
+            | AstKind::YieldExpression(_)
+            | AstKind::AwaitExpression(_) => {
+                self.unsupported = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if self.function_depth > 0 {
+            return;
+        }
+        if matches!(kind, AstKind::VariableDeclaration(decl) if decl.kind.is_lexical()) {
+            self.lexical_declaration_depth -= 1;
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier) {
+        if self.function_depth == 0 && self.lexical_declaration_depth > 0 {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                self.declared.insert(symbol_id);
+            }
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let reference = self.symbols.get_reference(reference_id);
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        self.references.push((reference_id, symbol_id));
+        if reference.is_write() {
+            self.written.insert(symbol_id);
+        }
+        if self.function_depth > 0 {
+            self.captured.insert(symbol_id);
+        }
+    }
+}
+
+/// Replaces `break`, `continue` and `return` in a loop body which is moved into a closure.
+///
+/// * `continue` -> `return;`
+/// * `break` -> `return "break";`
+/// * `break label` / `continue label` -> `return "break|label";` / `return "continue|label";`
+/// * `return x` -> `return { v: x };`
+///
+/// The written bindings of a `for` loop are copied back before `continue` and `break`.
+struct JumpReplacer<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    write_back: &'b [(Atom, Atom)],
+    loop_depth: usize,
+    switch_depth: usize,
+    labels: std::vec::Vec<Atom>,
+    /// Completion values which need to be handled after calling the closure.
+    jumps: std::vec::Vec<String>,
+    has_return: bool,
+}
+
+impl<'a, 'b> JumpReplacer<'a, 'b> {
+    fn new(ast: &'b AstBuilder<'a>, write_back: &'b [(Atom, Atom)]) -> Self {
+        Self {
+            ast,
+            write_back,
+            loop_depth: 0,
+            switch_depth: 0,
+            labels: vec![],
+            jumps: vec![],
+            has_return: false,
+        }
+    }
+
+    fn jump(&mut self, jump: String) -> Statement<'a> {
+        let value =
+            self.ast.literal_string_expression(StringLiteral::new(SPAN, Atom::from(jump.as_str())));
+        if !self.jumps.contains(&jump) {
+            self.jumps.push(jump);
+        }
+        self.jump_with_write_back(Some(value))
+    }
+
+    /// `return value;` or `{ i = _i; return value; }`
+    fn jump_with_write_back(&self, value: Option<Expression<'a>>) -> Statement<'a> {
+        let ret = self.ast.return_statement(SPAN, value);
+        if self.write_back.is_empty() {
+            return ret;
+        }
+        let mut statements = self.ast.new_vec();
+        statements.extend(write_back_statements(self.ast, self.write_back));
+        statements.push(ret);
+        self.ast.block_statement(self.ast.block(SPAN, statements))
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for JumpReplacer<'a, 'b> {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::BreakStatement(break_stmt) => match &break_stmt.label {
+                Some(label) if !self.labels.contains(&label.name) => {
+                    *stmt = self.jump(format!("break|{}", label.name));
+                }
+                None if self.loop_depth == 0 && self.switch_depth == 0 => {
+                    *stmt = self.jump("break".to_string());
+                }
+                _ => {}
+            },
+            Statement::ContinueStatement(continue_stmt) => match &continue_stmt.label {
+                Some(label) if !self.labels.contains(&label.name) => {
+                    *stmt = self.jump(format!("continue|{}", label.name));
+                }
+                None if self.loop_depth == 0 => {
+                    *stmt = self.jump_with_write_back(None);
+                }
+                _ => {}
+            },
+            Statement::ReturnStatement(return_stmt) => {
+                // `return x` -> `return { v: x }`
+                self.has_return = true;
+                let value = return_stmt.argument.take().unwrap_or_else(|| self.ast.void_0());
+                let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, "v".into()));
+                let property = self.ast.object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    key,
+                    value,
+                    None,
+                    false,
+                    false,
+                    false,
+                );
+                let object = self.ast.object_expression(
+                    SPAN,
+                    self.ast.new_vec_single(ObjectPropertyKind::ObjectProperty(property)),
+                    None,
+                );
+                return_stmt.argument = Some(object);
+            }
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => {
+                self.loop_depth += 1;
+                self.visit_statement_match(stmt);
+                self.loop_depth -= 1;
+            }
+            Statement::SwitchStatement(_) => {
+                self.switch_depth += 1;
+                self.visit_statement_match(stmt);
+                self.switch_depth -= 1;
+            }
+            Statement::LabeledStatement(labeled) => {
+                self.labels.push(labeled.label.name.clone());
+                self.visit_statement(&mut labeled.body);
+                self.labels.pop();
+            }
+            _ => self.visit_statement_match(stmt),
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &mut ArrowExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2015,
+        block_scoping: true,
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        ("let a = 1; const b = 2;", "var a = 1; var b = 2;"),
+        ("let a = 1; { let a = 2; a; } a;", "var a = 1; { var _a = 2; _a; } a;"),
+        ("for (;;) { let a; }", "for (;;) { var a = void 0; }"),
+        (
+            "for (let i = 0; i < 3; i++) { fns.push(() => i); }",
+            "var _loop = (i) => { fns.push(() => i); };\nfor (var i = 0; i < 3; i++) { _loop(i); }",
+        ),
+        (
+            "function f() { for (const x of xs) { if (x) break; if (!x) return x; fns.push(() => x); } }",
+            "function f() { var _loop = (x) => { if (x) return 'break'; if (!x) return { v: x }; fns.push(() => x); }; for (var x of xs) { var _ret = _loop(x); if (_ret === 'break') break; if (typeof _ret === 'object') return _ret.v; } }",
+        ),
+        (
+            "for (let i = 0; i < 3; i++) { fns.push(() => i); i++; }",
+            "var _loop = (_i) => { fns.push(() => _i); _i++; i = _i; };\nfor (var i = 0; i < 3; i++) { _loop(i); }",
+        ),
+        (
+            "for (let i = 0, j = 0; i < 3; i++) { fns.push(() => i + j); if (j) { i += 2; continue; } }",
+            "var _loop = (_i, j) => { fns.push(() => _i + j); if (j) { _i += 2; { i = _i; return; } } i = _i; };\nfor (var i = 0, j = 0; i < 3; i++) { _loop(i, j); }",
+        ),
+        (
+            "for (const x of xs) { fns.push(() => x); }",
+            "var _loop = (x) => { fns.push(() => x); };\nfor (var x of xs) { _loop(x); }",
+        ),
+    ];
+
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let mut options = options;
+    options.helper_loader =
+        HelperLoaderOptions { mode: HelperLoaderMode::Runtime, ..HelperLoaderOptions::default() };
+    let read_only_error = "import _readOnlyError from '@babel/runtime/helpers/readOnlyError';\n";
+    let tests = [
+        ("const a = 1; a = 2;", "var a = 1; 2, _readOnlyError('a');"),
+        ("const a = 1; a += 2;", "var a = 1; a + 2, _readOnlyError('a');"),
+        ("const a = 1; a ||= 2;", "var a = 1; a || (2, _readOnlyError('a'));"),
+        ("const a = 1; a++;", "var a = 1; +a, _readOnlyError('a');"),
+        ("const a = 1; let b; [b, a] = [2, 3];", "var a = 1; var b; [2, 3], _readOnlyError('a');"),
+        (
+            "const a = 1; { const a = 2; a = 3; }",
+            "var a = 1; { var _a = 2; 3, _readOnlyError('a'); }",
+        ),
+    ]
+    .map(|(source, expected)| (source, read_only_error.to_string() + expected));
+    let tests = tests.iter().map(|(a, b)| (*a, b.as_str())).collect::<std::vec::Vec<_>>();
+    Tester::new("test.mjs", options).test(&tests);
+}
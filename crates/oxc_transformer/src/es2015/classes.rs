@@ -0,0 +1,843 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_span::{Atom, GetSpan, Span, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Private class members can not be transformed to ES5 classes.")]
+#[diagnostic(severity(warning), help("Enable the class properties transform for private members."))]
+struct PrivateMember(#[label] Span);
+
+/// ES2015: Classes
+///
+/// ```javascript
+/// class A extends B {
+///   constructor() { super(); this.x = 1; }
+///   foo() { return super.foo(); }
+/// }
+/// // is transformed to
+/// var A = ((_B) => {
+///   function A() { var _this; _this = _B.call(this) || this; _this.x = 1; return _this; }
+///   A.prototype = Object.create(_B.prototype, { constructor: { value: A, writable: true, configurable: true } });
+///   _setPrototypeOf(A, _B);
+///   Object.defineProperty(A.prototype, "foo", { value: function() { return _B.prototype.foo.call(this); }, writable: true, configurable: true });
+///   return A;
+/// })(B);
+/// ```
+///
+/// The class body is wrapped in an arrow function so `this` and `arguments` of computed keys
+/// are preserved, the arrow functions transform lowers it further.
+/// Class fields are assigned in the constructor, i.e. as with the `setPublicClassFields` assumption.
+/// With the `setClassMethods` assumption, methods are assigned with `A.prototype.foo = function() {}`.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-classes>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-classes>
+pub struct Classes<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    set_class_methods: bool,
+}
+
+impl<'a> Classes<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.classes).then(|| Self {
+            ast,
+            ctx,
+            set_class_methods: options.assumptions.set_class_methods,
+        })
+    }
+
+    /// Class declarations are replaced with `var A = ...`,
+    /// `export default class A {}` is split into `var A = ...; export default A;`.
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            let Some(stmt) = stmts.get_mut(i) else { break };
+            match stmt {
+                Statement::Declaration(Declaration::ClassDeclaration(class)) => {
+                    if let Some(decl) = self.transform_class_declaration(class) {
+                        *stmt = Statement::Declaration(decl);
+                    }
+                }
+                Statement::ModuleDeclaration(decl) => match &mut **decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                        if let Some(Declaration::ClassDeclaration(class)) = &mut decl.declaration {
+                            if let Some(new_decl) = self.transform_class_declaration(class) {
+                                decl.declaration = Some(new_decl);
+                            }
+                        }
+                    }
+                    ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                        let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                            &mut decl.declaration
+                        else {
+                            i += 1;
+                            continue;
+                        };
+                        let Some(id) = class.id.clone() else {
+                            // `export default class {}` -> `export default (() => { ... })()`
+                            if let Some(expr) = self.transform_class(class) {
+                                decl.declaration = ExportDefaultDeclarationKind::Expression(expr);
+                            }
+                            i += 1;
+                            continue;
+                        };
+                        if let Some(new_decl) = self.transform_class_declaration(class) {
+                            let ident = IdentifierReference::new(SPAN, id.name);
+                            let expr = self.ast.identifier_reference_expression(ident);
+                            decl.declaration = ExportDefaultDeclarationKind::Expression(expr);
+                            stmts.insert(i, Statement::Declaration(new_decl));
+                            i += 1;
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if let Some(new_expr) = self.transform_class(class) {
+            *expr = new_expr;
+        }
+    }
+
+    /// `class A {}` -> `var A = (() => { ... })();`
+    fn transform_class_declaration(&mut self, class: &mut Class<'a>) -> Option<Declaration<'a>> {
+        let id = class.id.clone()?;
+        let init = self.transform_class(class)?;
+        let kind = VariableDeclarationKind::Var;
+        let pattern =
+            self.ast.binding_pattern(self.ast.binding_pattern_identifier(id), None, false);
+        let declarator = self.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        let decl = self.ast.variable_declaration(
+            class.span,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Some(Declaration::VariableDeclaration(decl))
+    }
+
+    fn transform_class(&mut self, class: &mut Class<'a>) -> Option<Expression<'a>> {
+        if let Some(span) = class.body.body.iter().find_map(|element| {
+            element
+                .property_key()
+                .filter(|key| key.is_private_identifier())
+                .map(GetSpan::span)
+                .or_else(|| match element {
+                    ClassElement::AccessorProperty(property) => Some(property.span),
+                    _ => None,
+                })
+        }) {
+            self.ctx.error(PrivateMember(span));
+            return None;
+        }
+
+        let name =
+            class.id.as_ref().map_or_else(|| self.generate_uid("class"), |id| id.name.clone());
+        let super_name = class.super_class.as_ref().map(|super_class| {
+            let name = match super_class {
+                Expression::Identifier(ident) => ident.name.to_string(),
+                _ => "super".to_string(),
+            };
+            self.generate_uid(&name)
+        });
+        let builder = ClassBuilder {
+            ast: &self.ast,
+            name: name.clone(),
+            super_name: super_name.clone(),
+            set_class_methods: self.set_class_methods,
+        };
+
+        let mut constructor = None;
+        let mut instance_fields = vec![];
+        let mut body = self.ast.new_vec();
+        let mut static_statements = vec![];
+        let elements = std::mem::replace(&mut class.body.body, self.ast.new_vec());
+        let mut elements = elements.into_iter().peekable();
+        while let Some(element) = elements.next() {
+            match element {
+                ClassElement::MethodDefinition(method)
+                    if method.kind == MethodDefinitionKind::Constructor =>
+                {
+                    constructor = Some(method.unbox().value);
+                }
+                ClassElement::MethodDefinition(method) if method.value.body.is_some() => {
+                    let method = method.unbox();
+                    let key = self.property_key(method.key, method.computed, &mut body);
+                    let mut func = method.value;
+                    builder.replace_super(&mut func, method.r#static);
+                    let func = self.ast.function_expression(func);
+                    match method.kind {
+                        MethodDefinitionKind::Get | MethodDefinitionKind::Set => {
+                            // Merge `get foo() {}` with a following `set foo() {}`.
+                            let mut pair = None;
+                            if let Some(ClassElement::MethodDefinition(next)) = elements.peek() {
+                                let is_pair = next.r#static == method.r#static
+                                    && !next.computed
+                                    && !method.computed
+                                    && next.value.body.is_some()
+                                    && matches!(
+                                        (method.kind, next.kind),
+                                        (MethodDefinitionKind::Get, MethodDefinitionKind::Set)
+                                            | (
+                                                MethodDefinitionKind::Set,
+                                                MethodDefinitionKind::Get
+                                            )
+                                    )
+                                    && next.key.static_name().is_some()
+                                    && next.key.static_name() == key_static_name(&key);
+                                if is_pair {
+                                    let Some(ClassElement::MethodDefinition(next)) =
+                                        elements.next()
+                                    else {
+                                        unreachable!()
+                                    };
+                                    let next = next.unbox();
+                                    let mut next_func = next.value;
+                                    builder.replace_super(&mut next_func, next.r#static);
+                                    pair =
+                                        Some((next.kind, self.ast.function_expression(next_func)));
+                                }
+                            }
+                            let mut accessors = vec![(method.kind, func)];
+                            accessors.extend(pair);
+                            body.push(builder.define_accessors(key, method.r#static, accessors));
+                        }
+                        _ => body.push(builder.define_method(key, method.r#static, func)),
+                    }
+                }
+                ClassElement::PropertyDefinition(property) if !property.declare => {
+                    let property = property.unbox();
+                    let key = self.property_key(property.key, property.computed, &mut body);
+                    let mut value = property.value.unwrap_or_else(|| self.ast.void_0());
+                    if property.r#static {
+                        // `this` in static initializers is the class.
+                        builder.replace_this(&mut value, &name);
+                        static_statements.push(builder.assign_member(
+                            self.reference(&name),
+                            key,
+                            value,
+                        ));
+                    } else {
+                        instance_fields.push((key, value));
+                    }
+                }
+                ClassElement::StaticBlock(block) => {
+                    // `(function () { ... }).call(A)`
+                    let block = block.unbox();
+                    let body = self.ast.function_body(SPAN, self.ast.new_vec(), block.body);
+                    let params = self.ast.formal_parameters(
+                        SPAN,
+                        FormalParameterKind::FormalParameter,
+                        self.ast.new_vec(),
+                        None,
+                    );
+                    let func = self.ast.function(
+                        FunctionType::FunctionExpression,
+                        SPAN,
+                        None,
+                        false,
+                        false,
+                        false,
+                        params,
+                        Some(body),
+                        None,
+                        None,
+                        None,
+                        Modifiers::empty(),
+                    );
+                    let func =
+                        self.ast.parenthesized_expression(SPAN, self.ast.function_expression(func));
+                    let callee = builder.member(func, "call");
+                    let arguments =
+                        self.ast.new_vec_single(Argument::Expression(self.reference(&name)));
+                    let call = self.ast.call_expression(SPAN, callee, arguments, false, None);
+                    static_statements.push(self.ast.expression_statement(SPAN, call));
+                }
+                // Type-only members and method overloads.
+                _ => {}
+            }
+        }
+
+        let constructor = builder.build_constructor(constructor, instance_fields);
+        let mut statements = self.ast.new_vec();
+        statements.push(self.ast.function_declaration(constructor));
+        if super_name.is_some() {
+            let set_prototype_of = self.ctx.helpers.helper(Helper::SetPrototypeOf);
+            statements.extend(builder.inherits(set_prototype_of));
+        }
+        statements.extend(body);
+        statements.extend(static_statements);
+        statements.push(self.ast.return_statement(SPAN, Some(self.reference(&name))));
+
+        // `((_B) => { ... })(B)`
+        let mut params = self.ast.new_vec();
+        let mut arguments = self.ast.new_vec();
+        if let (Some(super_name), Some(super_class)) = (super_name, class.super_class.take()) {
+            let pattern = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, super_name)),
+                None,
+                false,
+            );
+            params.push(self.ast.formal_parameter(SPAN, pattern, None, false, self.ast.new_vec()));
+            arguments.push(Argument::Expression(super_class));
+        }
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            params,
+            None,
+        );
+        let body = self.ast.function_body(SPAN, self.ast.new_vec(), statements);
+        let arrow =
+            self.ast.arrow_expression(SPAN, false, false, false, params, body, None, None, None);
+        let callee = self.ast.parenthesized_expression(SPAN, arrow);
+        Some(self.ast.call_expression(class.span, callee, arguments, false, None))
+    }
+
+    /// Computed keys are evaluated once in class definition order, `var _key = expr;`
+    fn property_key(
+        &self,
+        key: PropertyKey<'a>,
+        computed: bool,
+        body: &mut Vec<'a, Statement<'a>>,
+    ) -> Expression<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => {
+                self.ast.literal_string_expression(StringLiteral::new(SPAN, ident.unbox().name))
+            }
+            PropertyKey::Expression(expr) if computed && !is_literal(&expr) => {
+                let name = self.generate_uid("key");
+                let kind = VariableDeclarationKind::Var;
+                let pattern = self.ast.binding_pattern(
+                    self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name.clone())),
+                    None,
+                    false,
+                );
+                let declarator =
+                    self.ast.variable_declarator(SPAN, kind, pattern, Some(expr), false);
+                let decl = self.ast.variable_declaration(
+                    SPAN,
+                    kind,
+                    self.ast.new_vec_single(declarator),
+                    Modifiers::empty(),
+                );
+                body.push(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+                self.reference(&name)
+            }
+            PropertyKey::Expression(expr) => expr,
+            PropertyKey::PrivateIdentifier(_) => unreachable!("private members are rejected"),
+        }
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+}
+
+fn is_literal(expr: &Expression) -> bool {
+    matches!(expr, Expression::StringLiteral(_) | Expression::NumberLiteral(_))
+}
+
+fn key_static_name(key: &Expression) -> Option<Atom> {
+    match key {
+        Expression::StringLiteral(lit) => Some(lit.value.clone()),
+        _ => None,
+    }
+}
+
+struct ClassBuilder<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    name: Atom,
+    super_name: Option<Atom>,
+    set_class_methods: bool,
+}
+
+impl<'a, 'b> ClassBuilder<'a, 'b> {
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, object, property, false)
+    }
+
+    fn boolean(&self, value: bool) -> Expression<'a> {
+        self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, value))
+    }
+
+    /// `A.prototype` or `A`
+    fn target(&self, r#static: bool) -> Expression<'a> {
+        let class = self.reference(&self.name);
+        if r#static {
+            class
+        } else {
+            self.member(class, "prototype")
+        }
+    }
+
+    fn object(&self, properties: std::vec::Vec<(&str, Expression<'a>)>) -> Expression<'a> {
+        let mut props = self.ast.new_vec_with_capacity(properties.len());
+        for (name, value) in properties {
+            let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, Atom::from(name)));
+            let prop = self.ast.object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            props.push(ObjectPropertyKind::ObjectProperty(prop));
+        }
+        self.ast.object_expression(SPAN, props, None)
+    }
+
+    /// `Object.defineProperty(target, key, descriptor)`
+    fn define_property(
+        &self,
+        target: Expression<'a>,
+        key: Expression<'a>,
+        descriptor: Expression<'a>,
+    ) -> Statement<'a> {
+        let callee = self.member(self.reference(&Atom::from("Object")), "defineProperty");
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(target));
+        arguments.push(Argument::Expression(key));
+        arguments.push(Argument::Expression(descriptor));
+        let call = self.ast.call_expression(SPAN, callee, arguments, false, None);
+        self.ast.expression_statement(SPAN, call)
+    }
+
+    /// `object[key] = value;`
+    fn assign_member(
+        &self,
+        object: Expression<'a>,
+        key: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Statement<'a> {
+        let target = match key {
+            Expression::StringLiteral(lit) if is_identifier_name(&lit.value) => self
+                .ast
+                .static_member(SPAN, object, IdentifierName::new(SPAN, lit.value.clone()), false),
+            key => self.ast.computed_member(SPAN, object, key, false),
+        };
+        let target = self.ast.simple_assignment_target_member_expression(target);
+        let assignment = self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        );
+        self.ast.expression_statement(SPAN, assignment)
+    }
+
+    fn define_method(
+        &self,
+        key: Expression<'a>,
+        r#static: bool,
+        func: Expression<'a>,
+    ) -> Statement<'a> {
+        if self.set_class_methods {
+            return self.assign_member(self.target(r#static), key, func);
+        }
+        let descriptor = self.object(vec![
+            ("value", func),
+            ("writable", self.boolean(true)),
+            ("configurable", self.boolean(true)),
+        ]);
+        self.define_property(self.target(r#static), key, descriptor)
+    }
+
+    fn define_accessors(
+        &self,
+        key: Expression<'a>,
+        r#static: bool,
+        accessors: std::vec::Vec<(MethodDefinitionKind, Expression<'a>)>,
+    ) -> Statement<'a> {
+        let mut properties = accessors
+            .into_iter()
+            .map(|(kind, func)| {
+                (if kind == MethodDefinitionKind::Get { "get" } else { "set" }, func)
+            })
+            .collect::<std::vec::Vec<_>>();
+        properties.push(("configurable", self.boolean(true)));
+        self.define_property(self.target(r#static), key, self.object(properties))
+    }
+
+    /// `A.prototype = Object.create(_B.prototype, { constructor: { value: A, writable: true, configurable: true } }); _setPrototypeOf(A, _B);`
+    fn inherits(&self, set_prototype_of: Expression<'a>) -> [Statement<'a>; 2] {
+        let super_name = self.super_name.as_ref().expect("derived class");
+        let descriptor = self.object(vec![
+            ("value", self.reference(&self.name)),
+            ("writable", self.boolean(true)),
+            ("configurable", self.boolean(true)),
+        ]);
+        let properties = self.object(vec![("constructor", descriptor)]);
+        let create = self.member(self.reference(&Atom::from("Object")), "create");
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.member(self.reference(super_name), "prototype")));
+        arguments.push(Argument::Expression(properties));
+        let prototype = self.ast.call_expression(SPAN, create, arguments, false, None);
+        let prototype =
+            self.assign_member(self.reference(&self.name), self.string("prototype"), prototype);
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.reference(&self.name)));
+        arguments.push(Argument::Expression(self.reference(super_name)));
+        let proto = self.ast.call_expression(SPAN, set_prototype_of, arguments, false, None);
+        [prototype, self.ast.expression_statement(SPAN, proto)]
+    }
+
+    fn string(&self, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(SPAN, Atom::from(value)))
+    }
+
+    fn build_constructor(
+        &self,
+        constructor: Option<oxc_allocator::Box<'a, Function<'a>>>,
+        fields: std::vec::Vec<(Expression<'a>, Expression<'a>)>,
+    ) -> oxc_allocator::Box<'a, Function<'a>> {
+        let derived = self.super_name.is_some();
+        let mut constructor = constructor.unwrap_or_else(|| {
+            let params = self.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                self.ast.new_vec(),
+                None,
+            );
+            let mut statements = self.ast.new_vec();
+            if derived {
+                // `super(...arguments)`
+                let arguments = self.reference(&Atom::from("arguments"));
+                let spread = self.ast.spread_element(SPAN, arguments);
+                let call = self.ast.call_expression(
+                    SPAN,
+                    self.ast.super_(SPAN),
+                    self.ast.new_vec_single(Argument::SpreadElement(spread)),
+                    false,
+                    None,
+                );
+                statements.push(self.ast.expression_statement(SPAN, call));
+            }
+            let body = self.ast.function_body(SPAN, self.ast.new_vec(), statements);
+            self.ast.function(
+                FunctionType::FunctionDeclaration,
+                SPAN,
+                None,
+                false,
+                false,
+                false,
+                params,
+                Some(body),
+                None,
+                None,
+                None,
+                Modifiers::empty(),
+            )
+        });
+        constructor.r#type = FunctionType::FunctionDeclaration;
+        constructor.id = Some(BindingIdentifier::new(SPAN, self.name.clone()));
+        let Some(body) = &mut constructor.body else { return constructor };
+
+        if !derived {
+            // Fields are initialized before the constructor body.
+            let statements = std::mem::replace(&mut body.statements, self.ast.new_vec());
+            for (key, value) in fields {
+                body.statements.push(self.assign_member(
+                    self.ast.this_expression(SPAN),
+                    key,
+                    value,
+                ));
+            }
+            body.statements.extend(statements);
+            let mut replacer = SuperReplacer::new(self, false);
+            replacer.visit_function_body(body);
+            return constructor;
+        }
+
+        // `var _this; _this = _B.call(this) || this; ...; return _this;`
+        let this_name = Atom::from("_this");
+        let mut replacer = SuperReplacer::new(self, false);
+        replacer.this_name = Some(this_name.clone());
+        replacer.visit_function_body(body);
+
+        let mut statements = self.ast.new_vec();
+        let id = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, this_name.clone())),
+            None,
+            false,
+        );
+        let kind = VariableDeclarationKind::Var;
+        let declarator = self.ast.variable_declarator(SPAN, kind, id, None, false);
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        statements.push(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+        let mut fields = Some(fields);
+        for stmt in std::mem::replace(&mut body.statements, self.ast.new_vec()) {
+            let is_super_call = matches!(&stmt, Statement::ExpressionStatement(expr) if is_this_assignment(&expr.expression, &this_name));
+            statements.push(stmt);
+            if is_super_call {
+                for (key, value) in fields.take().into_iter().flatten() {
+                    statements.push(self.assign_member(self.reference(&this_name), key, value));
+                }
+            }
+        }
+        statements.push(self.ast.return_statement(SPAN, Some(self.reference(&this_name))));
+        body.statements = statements;
+        constructor
+    }
+
+    /// Replace `super` in a method.
+    fn replace_super(&self, func: &mut Function<'a>, r#static: bool) {
+        if let Some(body) = &mut func.body {
+            SuperReplacer::new(self, r#static).visit_function_body(body);
+        }
+    }
+
+    /// Replace `this` with the class name in static field initializers.
+    fn replace_this(&self, expr: &mut Expression<'a>, name: &Atom) {
+        let mut replacer = SuperReplacer::new(self, true);
+        replacer.this_name = Some(name.clone());
+        replacer.visit_expression(expr);
+    }
+}
+
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(oxc_syntax::identifier::is_identifier_start_all)
+        && chars.all(oxc_syntax::identifier::is_identifier_part)
+}
+
+/// `_this = ...`
+fn is_this_assignment(expr: &Expression, this_name: &Atom) -> bool {
+    matches!(expr, Expression::AssignmentExpression(assign)
+        if matches!(&assign.left, AssignmentTarget::SimpleAssignmentTarget(SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)) if ident.name == *this_name))
+}
+
+/// Replaces `super(...)`, `super.foo` and `this` (in derived constructors) inside a class member.
+struct SuperReplacer<'a, 'b, 'c> {
+    builder: &'c ClassBuilder<'a, 'b>,
+    r#static: bool,
+    this_name: Option<Atom>,
+}
+
+impl<'a, 'b, 'c> SuperReplacer<'a, 'b, 'c> {
+    fn new(builder: &'c ClassBuilder<'a, 'b>, r#static: bool) -> Self {
+        Self { builder, r#static, this_name: None }
+    }
+
+    fn ast(&self) -> &'b AstBuilder<'a> {
+        self.builder.ast
+    }
+
+    fn this(&self) -> Expression<'a> {
+        self.this_name
+            .as_ref()
+            .map_or_else(|| self.ast().this_expression(SPAN), |name| self.builder.reference(name))
+    }
+
+    /// `_B.prototype` or `_B`
+    fn super_object(&self) -> Expression<'a> {
+        let super_name = self.builder.super_name.clone().unwrap_or_else(|| Atom::from("Object"));
+        let object = self.builder.reference(&super_name);
+        if self.r#static {
+            object
+        } else {
+            self.builder.member(object, "prototype")
+        }
+    }
+
+    /// `super.foo` -> `_B.prototype.foo`
+    fn replace_super_member(&self, member: &mut MemberExpression<'a>) -> bool {
+        let object = member.object_mut();
+        if !matches!(object, Expression::Super(_)) {
+            return false;
+        }
+        *object = self.super_object();
+        true
+    }
+}
+
+impl<'a, 'b, 'c> VisitMut<'a> for SuperReplacer<'a, 'b, 'c> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(_) if self.this_name.is_some() => {
+                *expr = self.this();
+                return;
+            }
+            Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_)) => {
+                // `super(a)` -> `_this = _B.call(this, a) || this`
+                for argument in call.arguments.iter_mut() {
+                    self.visit_argument(argument);
+                }
+                let Some(super_name) = self.builder.super_name.clone() else { return };
+                let mut arguments = self
+                    .ast()
+                    .new_vec_single(Argument::Expression(self.ast().this_expression(SPAN)));
+                arguments.extend(std::mem::replace(&mut call.arguments, self.ast().new_vec()));
+                let callee = self.builder.member(self.builder.reference(&super_name), "call");
+                let call = self.ast().call_expression(SPAN, callee, arguments, false, None);
+                let value = self.ast().logical_expression(
+                    SPAN,
+                    call,
+                    LogicalOperator::Or,
+                    self.ast().this_expression(SPAN),
+                );
+                let this_name = self.this_name.clone().unwrap_or_else(|| Atom::from("_this"));
+                let target = self
+                    .ast()
+                    .simple_assignment_target_identifier(IdentifierReference::new(SPAN, this_name));
+                *expr = self.ast().assignment_expression(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::SimpleAssignmentTarget(target),
+                    value,
+                );
+                return;
+            }
+            Expression::CallExpression(call) if matches!(&call.callee, Expression::MemberExpression(member) if matches!(member.object(), Expression::Super(_))) =>
+            {
+                // `super.foo(a)` -> `_B.prototype.foo.call(this, a)`
+                for argument in call.arguments.iter_mut() {
+                    self.visit_argument(argument);
+                }
+                let Expression::MemberExpression(member) = &mut call.callee else { unreachable!() };
+                if let MemberExpression::ComputedMemberExpression(computed) = &mut **member {
+                    self.visit_expression(&mut computed.expression);
+                }
+                self.replace_super_member(member);
+                let callee = self.ast().move_expression(&mut call.callee);
+                call.callee = self.builder.member(callee, "call");
+                call.arguments.insert(0, Argument::Expression(self.this()));
+                return;
+            }
+            Expression::MemberExpression(member)
+                if matches!(member.object(), Expression::Super(_)) =>
+            {
+                self.replace_super_member(member);
+                if let MemberExpression::ComputedMemberExpression(computed) = &mut **member {
+                    self.visit_expression(&mut computed.expression);
+                }
+                return;
+            }
+            _ => {}
+        }
+        self.visit_expression_match(expr);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        // `return;` in a derived constructor returns the instance.
+        if let (Statement::ReturnStatement(ret), Some(name)) = (&mut *stmt, &self.this_name) {
+            if ret.argument.is_none() && !self.r#static {
+                ret.argument = Some(self.builder.reference(name));
+                return;
+            }
+        }
+        self.visit_statement_match(stmt);
+    }
+
+    /// `this` and `super` are rebound in non-arrow functions.
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    /// Nested classes are transformed separately.
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2022,
+        classes: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "class A { foo() {} static bar() {} }",
+            "var A = (() => {
+                function A() {}
+                Object.defineProperty(A.prototype, 'foo', { value: function() {}, writable: true, configurable: true });
+                Object.defineProperty(A, 'bar', { value: function() {}, writable: true, configurable: true });
+                return A;
+            })();",
+        ),
+        (
+            "class A extends B { constructor() { super(1); this.x = 1; } foo() { return super.foo(); } }",
+            "import _setPrototypeOf from '@babel/runtime/helpers/setPrototypeOf';
+            var A = ((_B) => {
+                function A() { var _this; _this = _B.call(this, 1) || this; _this.x = 1; return _this; }
+                A.prototype = Object.create(_B.prototype, { constructor: { value: A, writable: true, configurable: true } });
+                _setPrototypeOf(A, _B);
+                Object.defineProperty(A.prototype, 'foo', { value: function() { return _B.prototype.foo.call(this); }, writable: true, configurable: true });
+                return A;
+            })(B);",
+        ),
+        (
+            "class A { x = 1; static y = this; get z() {} set z(v) {} }",
+            "var A = (() => {
+                function A() { this.x = 1; }
+                Object.defineProperty(A.prototype, 'z', { get: function() {}, set: function(v) {}, configurable: true });
+                A.y = A;
+                return A;
+            })();",
+        ),
+        (
+            "export default class A {}",
+            "var A = (() => { function A() {} return A; })();\nexport default A;",
+        ),
+    ];
+    Tester::new("test.mjs", options.clone()).test(tests);
+
+    let mut options = options;
+    options.assumptions.set_class_methods = true;
+    let tests = &[(
+        "var A = class { foo() {} };",
+        "var A = (() => { function _class() {} _class.prototype.foo = function() {}; return _class; })();",
+    )];
+    Tester::new("test.js", options).test(tests);
+}
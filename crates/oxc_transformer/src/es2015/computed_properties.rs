@@ -0,0 +1,220 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    utils::CreateVars,
+};
+
+/// ES2015: Computed Properties
+///
+/// ```javascript
+/// var obj = { a: 1, [b]: 2, c: 3 };
+/// // is transformed to
+/// var _obj;
+/// var obj = (_obj = { a: 1 }, Object.defineProperty(_obj, b, { value: 2, enumerable: true, configurable: true, writable: true }), ..., _obj);
+/// ```
+///
+/// With the `setComputedProperties` assumption, properties are assigned with `_obj[b] = 2` instead.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-computed-properties>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-computed-properties>
+pub struct ComputedProperties<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+    set_computed_properties: bool,
+}
+
+impl<'a> CreateVars<'a> for ComputedProperties<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
+}
+
+impl<'a> ComputedProperties<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.computed_properties).then(|| {
+            let vars = ast.new_vec();
+            Self {
+                ast,
+                ctx,
+                vars,
+                vars_stack: vec![],
+                set_computed_properties: options.assumptions.set_computed_properties,
+            }
+        })
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ObjectExpression(obj) = expr else { return };
+        let Some(first_computed) = obj.properties.iter().position(
+            |prop| matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.computed),
+        ) else {
+            return;
+        };
+        // Object spread is handled by the ES2018 object rest spread transform.
+        if obj.properties.iter().any(|prop| matches!(prop, ObjectPropertyKind::SpreadProperty(_))) {
+            return;
+        }
+
+        let ident = self.create_new_named_var("obj");
+        let mut properties = std::mem::replace(&mut obj.properties, self.ast.new_vec());
+        let rest = properties.split_off(first_computed);
+
+        let mut expressions = self.ast.new_vec();
+        // `_obj = { a: 1 }`
+        let initial = self.ast.object_expression(SPAN, properties, None);
+        expressions.push(self.assign(&ident, initial));
+
+        for prop in rest {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { unreachable!() };
+            let ObjectProperty { kind, key, value, computed, .. } = prop.unbox();
+            let key = self.property_key_to_expression(key, computed);
+            let object = self.ast.identifier_reference_expression(ident.clone());
+            let expression = match kind {
+                PropertyKind::Init if self.set_computed_properties => {
+                    // `_obj[b] = 2`
+                    let target = self.ast.computed_member(SPAN, object, key, false);
+                    let target = self.ast.simple_assignment_target_member_expression(target);
+                    self.ast.assignment_expression(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        AssignmentTarget::SimpleAssignmentTarget(target),
+                        value,
+                    )
+                }
+                PropertyKind::Init => self.define_property(object, key, ("value", value), true),
+                PropertyKind::Get => self.define_property(object, key, ("get", value), false),
+                PropertyKind::Set => self.define_property(object, key, ("set", value), false),
+            };
+            expressions.push(expression);
+        }
+
+        expressions.push(self.ast.identifier_reference_expression(ident));
+        let sequence = self.ast.sequence_expression(SPAN, expressions);
+        *expr = self.ast.parenthesized_expression(SPAN, sequence);
+    }
+
+    fn property_key_to_expression(&self, key: PropertyKey<'a>, computed: bool) -> Expression<'a> {
+        match key {
+            PropertyKey::Expression(expr) if computed => expr,
+            PropertyKey::Identifier(ident) => {
+                self.ast.literal_string_expression(StringLiteral::new(SPAN, ident.unbox().name))
+            }
+            PropertyKey::Expression(expr) => expr,
+            PropertyKey::PrivateIdentifier(_) => {
+                unreachable!("private names are not valid in object literals")
+            }
+        }
+    }
+
+    fn assign(&self, ident: &IdentifierReference, value: Expression<'a>) -> Expression<'a> {
+        let target = self.ast.simple_assignment_target_identifier(ident.clone());
+        self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+
+    /// `Object.defineProperty(object, key, { value, enumerable: true, configurable: true, writable: true })`
+    fn define_property(
+        &self,
+        object: Expression<'a>,
+        key: Expression<'a>,
+        (name, value): (&str, Expression<'a>),
+        writable: bool,
+    ) -> Expression<'a> {
+        let mut properties = self.ast.new_vec();
+        properties.push(self.descriptor_property(name, value));
+        for name in ["enumerable", "configurable"] {
+            properties.push(self.descriptor_property(name, self.true_literal()));
+        }
+        if writable {
+            properties.push(self.descriptor_property("writable", self.true_literal()));
+        }
+        let descriptor = self.ast.object_expression(SPAN, properties, None);
+
+        let callee = self.ast.static_member_expression(
+            SPAN,
+            self.ast
+                .identifier_reference_expression(IdentifierReference::new(SPAN, "Object".into())),
+            IdentifierName::new(SPAN, "defineProperty".into()),
+            false,
+        );
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(object));
+        arguments.push(Argument::Expression(key));
+        arguments.push(Argument::Expression(descriptor));
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    fn descriptor_property(&self, name: &str, value: Expression<'a>) -> ObjectPropertyKind<'a> {
+        let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, Atom::from(name)));
+        let prop = self.ast.object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            None,
+            false,
+            false,
+            false,
+        );
+        ObjectPropertyKind::ObjectProperty(prop)
+    }
+
+    fn true_literal(&self) -> Expression<'a> {
+        self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, true))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2015,
+        computed_properties: true,
+        ..TransformOptions::default()
+    };
+
+    let tests = &[(
+        "var obj = { a: 1, [b]: 2, c: 3 };",
+        "var _obj;\nvar obj = (_obj = { a: 1 }, Object.defineProperty(_obj, b, { value: 2, enumerable: true, configurable: true, writable: true }), Object.defineProperty(_obj, 'c', { value: 3, enumerable: true, configurable: true, writable: true }), _obj);",
+    )];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let mut options = options;
+    options.assumptions.set_computed_properties = true;
+    let tests = &[(
+        "var obj = { [a]: 1, get [b]() { return 2; } };",
+        "var _obj;\nvar obj = (_obj = {}, _obj[a] = 1, Object.defineProperty(_obj, b, { get: function() { return 2; }, enumerable: true, configurable: true }), _obj);",
+    )];
+    Tester::new("test.js", options).test(tests);
+}
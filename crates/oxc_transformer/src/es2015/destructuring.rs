@@ -0,0 +1,611 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator},
+    NumberBase,
+};
+
+use super::spread::{to_array, ToArray};
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    utils::CreateVars,
+};
+
+/// ES2015: Destructuring
+///
+/// ```javascript
+/// var { a, b: [c, d = 1] } = obj;
+/// // is transformed to
+/// var a = obj.a, _ref = obj.b, c = _ref[0], _ref2 = _ref[1], d = _ref2 === void 0 ? 1 : _ref2;
+/// ```
+///
+/// Iterables destructured by array patterns are converted to arrays with `_slicedToArray`,
+/// or `_toArray` with a rest element, unless the `iterableIsArray` assumption is set.
+/// Object rest elements are left for the ES2018 object rest spread transform.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-destructuring>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-destructuring>
+pub struct Destructuring<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+    iterable_is_array: bool,
+}
+
+impl<'a> CreateVars<'a> for Destructuring<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
+}
+
+impl<'a> Destructuring<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.destructuring).then(|| {
+            let vars = ast.new_vec();
+            let iterable_is_array = options.assumptions.iterable_is_array;
+            Self { ast, ctx, vars, vars_stack: vec![], iterable_is_array }
+        })
+    }
+
+    /// `var { a } = obj` -> `var a = obj.a`
+    pub fn transform_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if !decl.declarations.iter().any(|d| d.init.is_some() && is_destructuring(&d.id)) {
+            return;
+        }
+        let kind = decl.kind;
+        let declarations = std::mem::replace(&mut decl.declarations, self.ast.new_vec());
+        for declarator in declarations {
+            match declarator.init {
+                Some(init) if is_destructuring(&declarator.id) => {
+                    self.flatten_binding(kind, declarator.id, init, &mut decl.declarations);
+                }
+                _ => decl.declarations.push(declarator),
+            }
+        }
+    }
+
+    /// `[a, b] = [b, a]` -> `(_ref = [b, a], a = _ref[0], b = _ref[1], _ref)`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr else { return };
+        if assign.operator != AssignmentOperator::Assign
+            || !matches!(&assign.left, AssignmentTarget::AssignmentTargetPattern(pattern) if can_flatten_target_pattern(pattern))
+        {
+            return;
+        }
+        let target = self.ast.move_assignment_target(&mut assign.left);
+        let value = self.ast.move_expression(&mut assign.right);
+        let is_array = matches!(value, Expression::ArrayExpression(_));
+
+        let mut expressions = self.ast.new_vec();
+        let ident = self.create_new_named_var("ref");
+        expressions.push(self.assign(&ident.name, value));
+        self.flatten_target(target, &ident.name, is_array, &mut expressions);
+        expressions.push(self.ast.identifier_reference_expression(ident));
+        let sequence = self.ast.sequence_expression(SPAN, expressions);
+        *expr = self.ast.parenthesized_expression(SPAN, sequence);
+    }
+
+    /// `catch ({ message }) {}` -> `catch (_ref) { let { message } = _ref; }`
+    pub fn transform_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        if !is_destructuring(param) {
+            return;
+        }
+        let name = self.generate_uid("ref");
+        let pattern = std::mem::replace(param, self.binding(name.clone()));
+        let stmt = self.declaration(VariableDeclarationKind::Let, pattern, self.reference(&name));
+        clause.body.body.insert(0, stmt);
+    }
+
+    /// `for (const [a, b] of xs) {}` -> `for (const _ref of xs) { const [a, b] = _ref; }`
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        let (left, body) = match stmt {
+            Statement::ForOfStatement(stmt) => {
+                let stmt = &mut **stmt;
+                (&mut stmt.left, &mut stmt.body)
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = &mut **stmt;
+                (&mut stmt.left, &mut stmt.body)
+            }
+            _ => return,
+        };
+        let head = match left {
+            ForStatementLeft::VariableDeclaration(decl)
+                if decl.declarations.len() == 1 && is_destructuring(&decl.declarations[0].id) =>
+            {
+                let name = self.generate_uid("ref");
+                let kind = decl.kind;
+                let pattern = std::mem::replace(
+                    &mut decl.declarations.first_mut().unwrap().id,
+                    self.binding(name.clone()),
+                );
+                self.declaration(kind, pattern, self.reference(&name))
+            }
+            ForStatementLeft::AssignmentTarget(AssignmentTarget::AssignmentTargetPattern(_)) => {
+                let name = self.generate_uid("ref");
+                let kind = VariableDeclarationKind::Var;
+                let declarator = self.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    self.binding(name.clone()),
+                    None,
+                    false,
+                );
+                let decl = self.ast.variable_declaration(
+                    SPAN,
+                    kind,
+                    self.ast.new_vec_single(declarator),
+                    Modifiers::empty(),
+                );
+                let ForStatementLeft::AssignmentTarget(target) =
+                    std::mem::replace(left, ForStatementLeft::VariableDeclaration(decl))
+                else {
+                    unreachable!()
+                };
+                let expr = self.ast.assignment_expression(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    self.reference(&name),
+                );
+                self.ast.expression_statement(SPAN, expr)
+            }
+            _ => return,
+        };
+        if let Statement::BlockStatement(block) = body {
+            block.body.insert(0, head);
+        } else {
+            let mut statements = self.ast.new_vec_with_capacity(2);
+            statements.push(head);
+            statements.push(self.ast.move_statement(body));
+            *body = self.ast.block_statement(self.ast.block(SPAN, statements));
+        }
+    }
+
+    fn flatten_binding(
+        &mut self,
+        kind: VariableDeclarationKind,
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        out: &mut Vec<'a, VariableDeclarator<'a>>,
+    ) {
+        if matches!(pattern.kind, BindingPatternKind::BindingIdentifier(_)) {
+            out.push(self.ast.variable_declarator(SPAN, kind, pattern, Some(init), false));
+            return;
+        }
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(_) => unreachable!(),
+            BindingPatternKind::AssignmentPattern(assign) => {
+                // `_tmp === void 0 ? default : _tmp`
+                let assign = assign.unbox();
+                let name = self.memoise_binding(kind, init, out);
+                let value = self.default_value(&name, assign.right);
+                self.flatten_binding(kind, assign.left, value, out);
+            }
+            BindingPatternKind::ObjectPattern(obj) if obj.rest.is_some() => {
+                let pattern = self.ast.binding_pattern(
+                    BindingPatternKind::ObjectPattern(obj),
+                    pattern.type_annotation,
+                    pattern.optional,
+                );
+                out.push(self.ast.variable_declarator(SPAN, kind, pattern, Some(init), false));
+            }
+            BindingPatternKind::ObjectPattern(obj) => {
+                let obj = obj.unbox();
+                let mut source = Source::new(init, obj.properties.len());
+                for property in obj.properties {
+                    let object = self.source_expression(kind, &mut source, out);
+                    let value = self.member(object, property.key);
+                    self.flatten_binding(kind, property.value, value, out);
+                }
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                let array = array.unbox();
+                let len = array.elements.len();
+                // `var _arr = _slicedToArray(arr, 2)`
+                let init =
+                    if self.iterable_is_array || matches!(init, Expression::ArrayExpression(_)) {
+                        init
+                    } else {
+                        let name = self.ctx.scopes().generate_uid_based_on_node(&init);
+                        self.ctx.add_binding(name.clone());
+                        let to =
+                            if array.rest.is_some() { ToArray::Rest } else { ToArray::Length(len) };
+                        let value = to_array(&self.ctx, init, to);
+                        let id = self.binding(name.clone());
+                        out.push(self.ast.variable_declarator(SPAN, kind, id, Some(value), false));
+                        self.reference(&name)
+                    };
+                let count =
+                    array.elements.iter().flatten().count() + usize::from(array.rest.is_some());
+                let mut source = Source::new(init, count);
+                for (i, element) in array.elements.into_iter().enumerate() {
+                    let Some(element) = element else { continue };
+                    let object = self.source_expression(kind, &mut source, out);
+                    let value = self.index(object, i);
+                    self.flatten_binding(kind, element, value, out);
+                }
+                if let Some(rest) = array.rest {
+                    let object = self.source_expression(kind, &mut source, out);
+                    let value = self.slice(object, len);
+                    self.flatten_binding(kind, rest.unbox().argument, value, out);
+                }
+            }
+        }
+    }
+
+    /// `is_array` is set when the source is an array literal, which is not converted to an array.
+    fn flatten_target(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        source: &Atom,
+        is_array: bool,
+        out: &mut Vec<'a, Expression<'a>>,
+    ) {
+        let AssignmentTarget::AssignmentTargetPattern(pattern) = target else { unreachable!() };
+        match pattern {
+            AssignmentTargetPattern::ObjectAssignmentTarget(obj) => {
+                for property in obj.unbox().properties {
+                    match property {
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                            let ident = ident.unbox();
+                            let property = IdentifierName::new(SPAN, ident.binding.name.clone());
+                            let mut value = self.ast.static_member_expression(
+                                SPAN,
+                                self.reference(source),
+                                property,
+                                false,
+                            );
+                            if let Some(init) = ident.init {
+                                value = self.default_value_expression(value, init);
+                            }
+                            let target = AssignmentTarget::SimpleAssignmentTarget(
+                                self.ast.simple_assignment_target_identifier(ident.binding),
+                            );
+                            self.flatten_target_value(target, value, out);
+                        }
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let property = property.unbox();
+                            let value = self.member(self.reference(source), property.name);
+                            self.flatten_target_maybe_default(property.binding, value, out);
+                        }
+                    }
+                }
+            }
+            AssignmentTargetPattern::ArrayAssignmentTarget(array) => {
+                let array = array.unbox();
+                let len = array.elements.len();
+                let converted;
+                let source = if self.iterable_is_array || is_array {
+                    source
+                } else {
+                    // `_ref2 = _slicedToArray(_ref, 2)`
+                    let to =
+                        if array.rest.is_some() { ToArray::Rest } else { ToArray::Length(len) };
+                    let value = to_array(&self.ctx, self.reference(source), to);
+                    converted = self.create_new_named_var(source).name;
+                    out.push(self.assign(&converted, value));
+                    &converted
+                };
+                for (i, element) in array.elements.into_iter().enumerate() {
+                    let Some(element) = element else { continue };
+                    let value = self.index(self.reference(source), i);
+                    self.flatten_target_maybe_default(element, value, out);
+                }
+                if let Some(rest) = array.rest {
+                    let value = self.slice(self.reference(source), len);
+                    self.flatten_target_value(rest, value, out);
+                }
+            }
+        }
+    }
+
+    fn flatten_target_maybe_default(
+        &mut self,
+        target: AssignmentTargetMaybeDefault<'a>,
+        value: Expression<'a>,
+        out: &mut Vec<'a, Expression<'a>>,
+    ) {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTarget(target) => {
+                self.flatten_target_value(target, value, out);
+            }
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let target = target.unbox();
+                let value = self.default_value_expression(value, target.init);
+                self.flatten_target_value(target.binding, value, out);
+            }
+        }
+    }
+
+    fn flatten_target_value(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        out: &mut Vec<'a, Expression<'a>>,
+    ) {
+        match &target {
+            AssignmentTarget::AssignmentTargetPattern(pattern)
+                if can_flatten_target_pattern(pattern) =>
+            {
+                let is_array = matches!(value, Expression::ArrayExpression(_));
+                let ident = self.create_new_named_var("ref");
+                out.push(self.assign(&ident.name, value));
+                self.flatten_target(target, &ident.name, is_array, out);
+            }
+            _ => {
+                let expr =
+                    self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value);
+                out.push(expr);
+            }
+        }
+    }
+
+    /// Returns the expression of the destructured value, a temporary variable is created when it is used more than once.
+    fn source_expression(
+        &mut self,
+        kind: VariableDeclarationKind,
+        source: &mut Source<'a>,
+        out: &mut Vec<'a, VariableDeclarator<'a>>,
+    ) -> Expression<'a> {
+        if let Some(name) = &source.name {
+            return self.reference(name);
+        }
+        let init = source.init.take().expect("source expression is used once");
+        if source.uses <= 1 {
+            return init;
+        }
+        let name = self.memoise_binding(kind, init, out);
+        source.name = Some(name.clone());
+        self.reference(&name)
+    }
+
+    /// `var _ref = init`, identifiers are reused.
+    fn memoise_binding(
+        &mut self,
+        kind: VariableDeclarationKind,
+        init: Expression<'a>,
+        out: &mut Vec<'a, VariableDeclarator<'a>>,
+    ) -> Atom {
+        if let Expression::Identifier(ident) = &init {
+            return ident.name.clone();
+        }
+        let name = self.generate_uid("ref");
+        out.push(self.ast.variable_declarator(
+            SPAN,
+            kind,
+            self.binding(name.clone()),
+            Some(init),
+            false,
+        ));
+        name
+    }
+
+    /// `name === void 0 ? default : name`
+    fn default_value(&self, name: &Atom, default: Expression<'a>) -> Expression<'a> {
+        let test = self.ast.binary_expression(
+            SPAN,
+            self.reference(name),
+            BinaryOperator::StrictEquality,
+            self.ast.void_0(),
+        );
+        self.ast.conditional_expression(SPAN, test, default, self.reference(name))
+    }
+
+    /// `(_ref = value) === void 0 ? default : _ref`
+    fn default_value_expression(
+        &mut self,
+        value: Expression<'a>,
+        default: Expression<'a>,
+    ) -> Expression<'a> {
+        let ident = self.create_new_named_var("ref");
+        let assignment = self.assign(&ident.name, value);
+        let test = self.ast.binary_expression(
+            SPAN,
+            self.ast.parenthesized_expression(SPAN, assignment),
+            BinaryOperator::StrictEquality,
+            self.ast.void_0(),
+        );
+        let alternate = self.ast.identifier_reference_expression(ident);
+        self.ast.conditional_expression(SPAN, test, default, alternate)
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn binding(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = BindingIdentifier::new(SPAN, name);
+        self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false)
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn declaration(
+        &self,
+        kind: VariableDeclarationKind,
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+    ) -> Statement<'a> {
+        let declarator = self.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    fn assign(&self, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let target = self
+            .ast
+            .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone()));
+        self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+
+    /// `object.key` or `object[key]`
+    fn member(&self, object: Expression<'a>, key: PropertyKey<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => {
+                self.ast.static_member_expression(SPAN, object, ident.unbox(), false)
+            }
+            PropertyKey::Expression(expr) => {
+                self.ast.computed_member_expression(SPAN, object, expr, false)
+            }
+            PropertyKey::PrivateIdentifier(_) => {
+                unreachable!("private names are not valid in patterns")
+            }
+        }
+    }
+
+    /// `object[i]`
+    fn index(&self, object: Expression<'a>, i: usize) -> Expression<'a> {
+        self.ast.computed_member_expression(SPAN, object, self.number(i), false)
+    }
+
+    /// `object.slice(i)`
+    fn slice(&self, object: Expression<'a>, i: usize) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, "slice".into());
+        let callee = self.ast.static_member_expression(SPAN, object, property, false);
+        let arguments = self.ast.new_vec_single(Argument::Expression(self.number(i)));
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    fn number(&self, value: usize) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        #[allow(clippy::cast_precision_loss)]
+        let literal = self.ast.number_literal(SPAN, value as f64, raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+}
+
+/// The value being destructured, which is memoised when it is accessed more than once.
+struct Source<'a> {
+    init: Option<Expression<'a>>,
+    name: Option<Atom>,
+    uses: usize,
+}
+
+impl<'a> Source<'a> {
+    fn new(init: Expression<'a>, uses: usize) -> Self {
+        let name = match &init {
+            Expression::Identifier(ident) => Some(ident.name.clone()),
+            _ => None,
+        };
+        Self { init: Some(init), name, uses }
+    }
+}
+
+fn is_destructuring(pattern: &BindingPattern) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(obj) => obj.rest.is_none(),
+        BindingPatternKind::ArrayPattern(_) => true,
+        BindingPatternKind::AssignmentPattern(assign) => is_destructuring(&assign.left),
+    }
+}
+
+/// Object rest elements are transformed by the ES2018 object rest spread transform.
+fn can_flatten_target_pattern(pattern: &AssignmentTargetPattern) -> bool {
+    match pattern {
+        AssignmentTargetPattern::ObjectAssignmentTarget(obj) => obj.rest.is_none(),
+        AssignmentTargetPattern::ArrayAssignmentTarget(_) => true,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let mut options = TransformOptions {
+        target: TransformTarget::ES2015,
+        destructuring: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::External,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        ("var { a, b } = obj;", "var a = obj.a, b = obj.b;"),
+        ("var { a } = foo();", "var a = foo().a;"),
+        ("var { a, b } = foo();", "var _ref = foo(), a = _ref.a, b = _ref.b;"),
+        (
+            "var [a, , b] = arr;",
+            "var _arr = babelHelpers.slicedToArray(arr, 3), a = _arr[0], b = _arr[2];",
+        ),
+        (
+            "var [a, ...b] = arr;",
+            "var _arr = babelHelpers.toArray(arr), a = _arr[0], b = _arr.slice(1);",
+        ),
+        ("var [a, b] = [1, 2];", "var _ref = [1, 2], a = _ref[0], b = _ref[1];"),
+        (
+            "var [a] = arguments;",
+            "var _arguments = Array.prototype.slice.call(arguments), a = _arguments[0];",
+        ),
+        ("var { a = 1 } = obj;", "var _ref = obj.a, a = _ref === void 0 ? 1 : _ref;"),
+        (
+            "let { a: [b] } = obj;",
+            "let _obj$a = babelHelpers.slicedToArray(obj.a, 1), b = _obj$a[0];",
+        ),
+        ("[a, b] = [b, a];", "var _ref;\n(_ref = [b, a], a = _ref[0], b = _ref[1], _ref);"),
+        (
+            "[a, b] = arr;",
+            "var _ref, _ref2;\n(_ref = arr, _ref2 = babelHelpers.slicedToArray(_ref, 2), a = _ref2[0], b = _ref2[1], _ref);",
+        ),
+        ("try {} catch ({ message }) {}", "try {} catch (_ref) { let message = _ref.message; }"),
+        (
+            "for (const [k, v] of entries) {}",
+            "for (const _ref of entries) { const _ref2 = babelHelpers.slicedToArray(_ref, 2), k = _ref2[0], v = _ref2[1]; }",
+        ),
+    ];
+
+    Tester::new("test.js", options.clone()).test(tests);
+
+    options.assumptions.iterable_is_array = true;
+    let tests = &[
+        ("var [a, , b, ...c] = arr;", "var a = arr[0], b = arr[2], c = arr.slice(3);"),
+        ("let { a: [b] } = obj;", "let b = obj.a[0];"),
+        (
+            "for (const [k, v] of entries) {}",
+            "for (const _ref of entries) { const k = _ref[0], v = _ref[1]; }",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+}
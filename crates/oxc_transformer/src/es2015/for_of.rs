@@ -0,0 +1,365 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator, UpdateOperator},
+    NumberBase,
+};
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+};
+
+/// ES2015: For Of
+///
+/// ```javascript
+/// for (const x of xs) { body }
+/// // is transformed to
+/// var _iterator = _createForOfIteratorHelper(xs), _step;
+/// try {
+///   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+///     const x = _step.value;
+///     body
+///   }
+/// } catch (err) { _iterator.e(err); } finally { _iterator.f(); }
+/// ```
+///
+/// The helper falls back to iterating arrays and array-likes when `Symbol.iterator` is missing,
+/// and calls `iterator.return()` on abrupt completions.
+/// With the `skipForOfIteratorClosing` assumption, `_createForOfIteratorHelperLoose` is used without `try`.
+/// Array literals are iterated by index, `for (var _i = 0, _arr = [a, b]; _i < _arr.length; _i++)`.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-for-of>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-for-of>
+pub struct ForOf<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    skip_for_of_iterator_closing: bool,
+}
+
+impl<'a> ForOf<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.for_of).then(|| Self {
+            ast,
+            ctx,
+            skip_for_of_iterator_closing: options.assumptions.skip_for_of_iterator_closing,
+        })
+    }
+
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::ForOfStatement(for_of) if !for_of.r#await => {
+                let Statement::ForOfStatement(for_of) = self.ast.move_statement(stmt) else {
+                    unreachable!()
+                };
+                *stmt = self.build(for_of.unbox(), None);
+            }
+            // The label is moved to the generated `for` statement so `continue label` keeps working.
+            Statement::LabeledStatement(labeled) if matches!(&labeled.body, Statement::ForOfStatement(for_of) if !for_of.r#await) =>
+            {
+                let Statement::LabeledStatement(labeled) = self.ast.move_statement(stmt) else {
+                    unreachable!()
+                };
+                let LabeledStatement { label, body, .. } = labeled.unbox();
+                let Statement::ForOfStatement(for_of) = body else { unreachable!() };
+                *stmt = self.build(for_of.unbox(), Some(label));
+            }
+            _ => {}
+        }
+    }
+
+    fn build(&self, for_of: ForOfStatement<'a>, label: Option<LabelIdentifier>) -> Statement<'a> {
+        let ForOfStatement { left, right, body, .. } = for_of;
+        if let Expression::ArrayExpression(_) = right {
+            return self.build_array_loop(left, right, body, label);
+        }
+        let iterator = self.generate_uid("iterator");
+        let step = self.generate_uid("step");
+
+        // `const x = _step.value;` or `x = _step.value;`
+        let value = self.member(self.reference(&step), "value");
+        let body = self.build_body(left, value, body);
+
+        // `var _iterator = _createForOfIteratorHelper(xs), _step`
+        let helper = if self.skip_for_of_iterator_closing {
+            Helper::CreateForOfIteratorHelperLoose
+        } else {
+            Helper::CreateForOfIteratorHelper
+        };
+        let get_iterator =
+            self.ctx.helpers.call(helper, self.ast.new_vec_single(Argument::Expression(right)));
+        let mut declarations = self.ast.new_vec_with_capacity(2);
+        declarations.push(self.declarator(iterator.clone(), Some(get_iterator)));
+        declarations.push(self.declarator(step.clone(), None));
+        let init = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+
+        if self.skip_for_of_iterator_closing {
+            // `!(_step = _iterator()).done`
+            let next = self.call(self.reference(&iterator), self.ast.new_vec());
+            let done = self
+                .member(self.ast.parenthesized_expression(SPAN, self.assign(&step, next)), "done");
+            let stmt = self.ast.for_statement(
+                SPAN,
+                Some(ForStatementInit::VariableDeclaration(init)),
+                Some(self.not(done)),
+                None,
+                body,
+            );
+            return self.with_label(stmt, label);
+        }
+
+        // `for (_iterator.s(); !(_step = _iterator.n()).done;)`
+        let start = self.call(self.member(self.reference(&iterator), "s"), self.ast.new_vec());
+        let next = self.call(self.member(self.reference(&iterator), "n"), self.ast.new_vec());
+        let done =
+            self.member(self.ast.parenthesized_expression(SPAN, self.assign(&step, next)), "done");
+        let stmt = self.ast.for_statement(
+            SPAN,
+            Some(ForStatementInit::Expression(start)),
+            Some(self.not(done)),
+            None,
+            body,
+        );
+        let stmt = self.with_label(stmt, label);
+
+        // `catch (err) { _iterator.e(err); } finally { _iterator.f(); }`
+        let err = self.generate_uid("err");
+        let error = self.call(
+            self.member(self.reference(&iterator), "e"),
+            self.ast.new_vec_single(Argument::Expression(self.reference(&err))),
+        );
+        let handler = self.ast.catch_clause(
+            SPAN,
+            Some(self.binding(err)),
+            self.ast
+                .block(SPAN, self.ast.new_vec_single(self.ast.expression_statement(SPAN, error))),
+        );
+        let finish = self.call(self.member(self.reference(&iterator), "f"), self.ast.new_vec());
+        let finalizer = self
+            .ast
+            .block(SPAN, self.ast.new_vec_single(self.ast.expression_statement(SPAN, finish)));
+        let try_stmt = self.ast.try_statement(
+            SPAN,
+            self.ast.block(SPAN, self.ast.new_vec_single(stmt)),
+            Some(handler),
+            Some(finalizer),
+        );
+
+        let mut statements = self.ast.new_vec_with_capacity(2);
+        statements.push(Statement::Declaration(Declaration::VariableDeclaration(init)));
+        statements.push(try_stmt);
+        self.ast.block_statement(self.ast.block(SPAN, statements))
+    }
+
+    /// `for (const x of [a, b])` -> `for (var _i = 0, _arr = [a, b]; _i < _arr.length; _i++) { const x = _arr[_i]; }`
+    fn build_array_loop(
+        &self,
+        left: ForStatementLeft<'a>,
+        right: Expression<'a>,
+        body: Statement<'a>,
+        label: Option<LabelIdentifier>,
+    ) -> Statement<'a> {
+        let index = self.generate_uid("i");
+        let array = self.generate_uid("arr");
+
+        let value = self.ast.computed_member_expression(
+            SPAN,
+            self.reference(&array),
+            self.reference(&index),
+            false,
+        );
+        let body = self.build_body(left, value, body);
+
+        let mut declarations = self.ast.new_vec_with_capacity(2);
+        declarations.push(self.declarator(index.clone(), Some(self.number(0.0, "0"))));
+        declarations.push(self.declarator(array.clone(), Some(right)));
+        let init = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+        let test = self.ast.binary_expression(
+            SPAN,
+            self.reference(&index),
+            BinaryOperator::LessThan,
+            self.member(self.reference(&array), "length"),
+        );
+        let target = self
+            .ast
+            .simple_assignment_target_identifier(IdentifierReference::new(SPAN, index.clone()));
+        let update = self.ast.update_expression(SPAN, UpdateOperator::Increment, false, target);
+        let stmt = self.ast.for_statement(
+            SPAN,
+            Some(ForStatementInit::VariableDeclaration(init)),
+            Some(test),
+            Some(update),
+            body,
+        );
+        self.with_label(stmt, label)
+    }
+
+    /// `{ const x = value; body }`
+    fn build_body(
+        &self,
+        left: ForStatementLeft<'a>,
+        value: Expression<'a>,
+        body: Statement<'a>,
+    ) -> Statement<'a> {
+        let assignment = match left {
+            ForStatementLeft::VariableDeclaration(mut decl) => {
+                if let Some(declarator) = decl.declarations.first_mut() {
+                    declarator.init = Some(value);
+                }
+                Statement::Declaration(Declaration::VariableDeclaration(decl))
+            }
+            ForStatementLeft::AssignmentTarget(target) => {
+                let expr =
+                    self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value);
+                self.ast.expression_statement(SPAN, expr)
+            }
+            ForStatementLeft::UsingDeclaration(decl) => {
+                Statement::Declaration(Declaration::UsingDeclaration(decl))
+            }
+        };
+        let mut statements = self.ast.new_vec_single(assignment);
+        match body {
+            Statement::BlockStatement(block) => statements.extend(block.unbox().body),
+            body => statements.push(body),
+        }
+        self.ast.block_statement(self.ast.block(SPAN, statements))
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn with_label(&self, stmt: Statement<'a>, label: Option<LabelIdentifier>) -> Statement<'a> {
+        match label {
+            Some(label) => self.ast.labeled_statement(SPAN, label, stmt),
+            None => stmt,
+        }
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn binding(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = BindingIdentifier::new(SPAN, name);
+        self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false)
+    }
+
+    fn declarator(&self, name: Atom, init: Option<Expression<'a>>) -> VariableDeclarator<'a> {
+        let kind = VariableDeclarationKind::Var;
+        self.ast.variable_declarator(SPAN, kind, self.binding(name), init, false)
+    }
+
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, object, property, false)
+    }
+
+    fn call(&self, callee: Expression<'a>, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    fn assign(&self, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let target = self
+            .ast
+            .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone()));
+        self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+
+    fn not(&self, expr: Expression<'a>) -> Expression<'a> {
+        self.ast.unary_expression(SPAN, UnaryOperator::LogicalNot, expr)
+    }
+
+    fn number(&self, value: f64, raw: &'a str) -> Expression<'a> {
+        self.ast.literal_number_expression(self.ast.number_literal(
+            SPAN,
+            value,
+            raw,
+            NumberBase::Decimal,
+        ))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let mut options = TransformOptions {
+        target: TransformTarget::ES2015,
+        for_of: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    options.assumptions.skip_for_of_iterator_closing = true;
+
+    let loose = "import _createForOfIteratorHelperLoose from '@babel/runtime/helpers/createForOfIteratorHelperLoose';\n";
+    let tests = [
+        (
+            "for (const x of xs) { foo(x); }",
+            "for (var _iterator = _createForOfIteratorHelperLoose(xs), _step; !(_step = _iterator()).done;) { const x = _step.value; foo(x); }",
+        ),
+        (
+            "a: for (x of xs) continue a;",
+            "a: for (var _iterator = _createForOfIteratorHelperLoose(xs), _step; !(_step = _iterator()).done;) { x = _step.value; continue a; }",
+        ),
+    ]
+    .map(|(source, expected)| (source, loose.to_string() + expected));
+    let tests = tests.iter().map(|(a, b)| (*a, b.as_str())).collect::<std::vec::Vec<_>>();
+    Tester::new("test.mjs", options.clone()).test(&tests);
+
+    options.assumptions.skip_for_of_iterator_closing = false;
+    let tests = &[
+        (
+            "for (const x of xs);",
+            "import _createForOfIteratorHelper from '@babel/runtime/helpers/createForOfIteratorHelper';
+            {
+                var _iterator = _createForOfIteratorHelper(xs), _step;
+                try {
+                    for (_iterator.s(); !(_step = _iterator.n()).done;) { const x = _step.value; ; }
+                } catch (_err) {
+                    _iterator.e(_err);
+                } finally {
+                    _iterator.f();
+                }
+            }",
+        ),
+        (
+            "for (const x of [a, b]) foo(x);",
+            "for (var _i = 0, _arr = [a, b]; _i < _arr.length; _i++) { const x = _arr[_i]; foo(x); }",
+        ),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+}
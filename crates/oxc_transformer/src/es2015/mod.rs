@@ -1,5 +1,25 @@
+mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod computed_properties;
+mod destructuring;
+mod for_of;
+mod new_target;
+mod parameters;
+mod regenerator;
 mod shorthand_properties;
+mod spread;
 mod template_literals;
 
+pub use arrow_functions::ArrowFunctions;
+pub use block_scoping::BlockScoping;
+pub use classes::Classes;
+pub use computed_properties::ComputedProperties;
+pub use destructuring::Destructuring;
+pub use for_of::ForOf;
+pub use new_target::NewTarget;
+pub use parameters::Parameters;
+pub use regenerator::Regenerator;
 pub use shorthand_properties::ShorthandProperties;
+pub use spread::Spread;
 pub use template_literals::TemplateLiterals;
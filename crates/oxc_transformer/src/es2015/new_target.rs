@@ -0,0 +1,153 @@
+use oxc_ast::ast::*;
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::BinaryOperator;
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+use rustc_hash::FxHashMap;
+
+use crate::options::{TransformOptions, TransformTarget};
+
+/// ES2015: New Target
+///
+/// ```javascript
+/// function Foo() { new.target }          // this instanceof Foo ? this.constructor : void 0
+/// class Foo { constructor() { new.target } } // this.constructor
+/// class Foo { foo() { new.target } }         // void 0
+/// ```
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-new-target>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-new-target>
+pub struct NewTarget {
+    /// Names generated for the anonymous functions using `new.target`, by their scope.
+    names: FxHashMap<ScopeId, Atom>,
+}
+
+impl NewTarget {
+    pub fn new(options: &TransformOptions) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.new_target)
+            .then(|| Self { names: FxHashMap::default() })
+    }
+}
+
+/// What `new.target` is replaced with
+enum Target {
+    /// `this.constructor`
+    Constructor,
+    /// `void 0`
+    Method,
+    /// `this instanceof Foo ? this.constructor : void 0`, in the function `Foo` with the scope
+    Function(Option<Atom>, Option<ScopeId>),
+}
+
+impl Target {
+    /// The target of the nearest function, arrow functions have the `new.target` of their parent
+    fn find(ctx: &TraverseCtx) -> Option<Self> {
+        let mut ancestors = ctx.ancestors();
+        let (id, scope_id) = loop {
+            match ancestors.next()? {
+                Ancestor::FunctionParams(func) => break (func.id(), func.scope_id()),
+                Ancestor::FunctionBody(func) => break (func.id(), func.scope_id()),
+                Ancestor::PropertyDefinitionValue(_) => return Some(Self::Method),
+                _ => {}
+            }
+        };
+        Some(match ancestors.next() {
+            Some(Ancestor::MethodDefinitionValue(def))
+                if *def.kind() == MethodDefinitionKind::Constructor =>
+            {
+                Self::Constructor
+            }
+            Some(Ancestor::MethodDefinitionValue(_)) => Self::Method,
+            _ => Self::Function(id.as_ref().map(|id| id.name.clone()), scope_id.get()),
+        })
+    }
+}
+
+impl<'a> Traverse<'a> for NewTarget {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::MetaProperty(meta) = expr else { return };
+        if meta.meta.name != "new" || meta.property.name != "target" {
+            return;
+        }
+        let (name, scope_id) = match Target::find(ctx) {
+            Some(Target::Constructor) => {
+                *expr = this_constructor(ctx);
+                return;
+            }
+            Some(Target::Method) => {
+                *expr = ctx.ast.void_0();
+                return;
+            }
+            Some(Target::Function(name, Some(scope_id))) => (name, scope_id),
+            Some(Target::Function(_, None)) | None => return,
+        };
+        let name = name.or_else(|| self.names.get(&scope_id).cloned()).unwrap_or_else(|| {
+            let name = ctx.scopes().generate_uid_based_on_name("target");
+            let flag = SymbolFlags::FunctionScopedVariable;
+            // The declaration is not in the original program
+            let symbol_id = ctx.symbols_mut().create_synthetic_symbol(name.clone(), flag, scope_id);
+            ctx.scopes_mut().add_binding(scope_id, name.clone(), symbol_id);
+            self.names.insert(scope_id, name.clone());
+            name
+        });
+        let ident = IdentifierReference::new(SPAN, name);
+        let test = ctx.ast.binary_expression(
+            SPAN,
+            ctx.ast.this_expression(SPAN),
+            BinaryOperator::Instanceof,
+            ctx.ast.identifier_reference_expression(ident),
+        );
+        *expr = ctx.ast.conditional_expression(SPAN, test, this_constructor(ctx), ctx.ast.void_0());
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if let Some(name) = func.scope_id.get().and_then(|scope_id| self.names.remove(&scope_id)) {
+            func.id = Some(BindingIdentifier::new(SPAN, name));
+        }
+    }
+}
+
+fn this_constructor<'a>(ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    let property = IdentifierName::new(SPAN, "constructor".into());
+    ctx.ast.static_member_expression(SPAN, ctx.ast.this_expression(SPAN), property, false)
+}
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2015,
+        new_target: true,
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "function Foo() { new.target; }",
+            "function Foo() { this instanceof Foo ? this.constructor : void 0; }",
+        ),
+        (
+            "var Foo = function() { new.target; };",
+            "var Foo = function _target() { this instanceof _target ? this.constructor : void 0; };",
+        ),
+        (
+            "class Foo { constructor() { new.target; } foo() { new.target; } }",
+            "class Foo { constructor() { this.constructor; } foo() { void 0; } }",
+        ),
+        (
+            "class Foo { foo() { () => new.target; } }",
+            "class Foo { foo() { () => void 0; } }",
+        ),
+        (
+            "function Foo() { () => new.target; }",
+            "function Foo() { () => this instanceof Foo ? this.constructor : void 0; }",
+        ),
+    ];
+
+    Tester::new("test.js", options).test(tests);
+}
@@ -0,0 +1,304 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    NumberBase,
+};
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+};
+
+/// ES2015: Parameters
+///
+/// Default, destructured and rest parameters are moved into the function body.
+///
+/// ```javascript
+/// function foo(a, b = 1, ...c) {}
+/// // is transformed to
+/// function foo(a) {
+///   var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;
+///   var c = Array.prototype.slice.call(arguments, 2);
+/// }
+/// ```
+///
+/// With the `ignoreFunctionLength` assumption, or for arrow functions which do not have their own `arguments`,
+/// parameters are kept and defaults are assigned with `if (b === void 0) { b = 1; }`.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-parameters>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-parameters>
+pub struct Parameters<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    ignore_function_length: bool,
+}
+
+impl<'a> Parameters<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.parameters).then(|| Self {
+            ast,
+            ctx,
+            ignore_function_length: options.assumptions.ignore_function_length,
+        })
+    }
+
+    pub fn transform_function(&mut self, func: &mut Function<'a>) {
+        let Some(body) = &mut func.body else { return };
+        let use_arguments = !self.ignore_function_length;
+        self.transform_parameters(&mut func.params, &mut body.statements, use_arguments);
+    }
+
+    pub fn transform_arrow_expression(&mut self, arrow: &mut ArrowExpression<'a>) {
+        // Arrow functions do not have `arguments`, so the rest element is kept.
+        if !arrow.params.items.iter().any(|param| !is_simple_parameter(param)) {
+            return;
+        }
+        if arrow.expression {
+            // `(a = 1) => a` -> `(a) => { if (a === void 0) { a = 1; } return a; }`
+            arrow.expression = false;
+            if let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() {
+                let argument = stmt.unbox().expression;
+                arrow.body.statements.push(self.ast.return_statement(SPAN, Some(argument)));
+            }
+        }
+        let rest = arrow.params.rest.take();
+        self.transform_parameters(&mut arrow.params, &mut arrow.body.statements, false);
+        arrow.params.rest = rest;
+    }
+
+    fn transform_parameters(
+        &mut self,
+        params: &mut FormalParameters<'a>,
+        statements: &mut Vec<'a, Statement<'a>>,
+        use_arguments: bool,
+    ) {
+        let first_default = params.items.iter().position(|param| {
+            matches!(param.pattern.kind, BindingPatternKind::AssignmentPattern(_))
+        });
+        if first_default.is_none()
+            && params.rest.is_none()
+            && params.items.iter().all(is_simple_parameter)
+        {
+            return;
+        }
+
+        let len = params.items.len();
+        let mut declarations = self.ast.new_vec();
+        let items = std::mem::replace(&mut params.items, self.ast.new_vec());
+        for (i, param) in items.into_iter().enumerate() {
+            let FormalParameter { span, pattern, accessibility, readonly, decorators } = param;
+            let removed = use_arguments && first_default.is_some_and(|first| i >= first);
+            if removed {
+                // `var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;`
+                let (pattern, init) = match pattern.kind {
+                    BindingPatternKind::AssignmentPattern(assign) => {
+                        let assign = assign.unbox();
+                        (assign.left, self.arguments_with_default(i, assign.right))
+                    }
+                    kind => (self.ast.binding_pattern(kind, None, false), self.arguments_at(i)),
+                };
+                declarations.push(self.var_statement(pattern, init));
+                continue;
+            }
+            let pattern = match pattern.kind {
+                BindingPatternKind::BindingIdentifier(_) => pattern,
+                BindingPatternKind::AssignmentPattern(assign) => {
+                    let assign = assign.unbox();
+                    if let BindingPatternKind::BindingIdentifier(ident) = &assign.left.kind {
+                        // `if (b === void 0) { b = 1; }`
+                        let name = ident.name.clone();
+                        declarations.push(self.assign_if_undefined(&name, assign.right));
+                        assign.left
+                    } else {
+                        // `var { x } = _ref === void 0 ? {} : _ref;`
+                        let name = self.generate_uid("ref");
+                        let test = self.is_undefined(self.reference(&name));
+                        let init = self.ast.conditional_expression(
+                            SPAN,
+                            test,
+                            assign.right,
+                            self.reference(&name),
+                        );
+                        declarations.push(self.var_statement(assign.left, init));
+                        self.binding(name)
+                    }
+                }
+                kind => {
+                    // `function foo({ x }) {}` -> `function foo(_ref) { var { x } = _ref; }`
+                    let name = self.generate_uid("ref");
+                    let pattern = self.ast.binding_pattern(kind, None, false);
+                    declarations.push(self.var_statement(pattern, self.reference(&name)));
+                    self.binding(name)
+                }
+            };
+            params.items.push(self.ast.formal_parameter(
+                span,
+                pattern,
+                accessibility,
+                readonly,
+                decorators,
+            ));
+        }
+
+        if let Some(rest) = params.rest.take() {
+            // `var c = Array.prototype.slice.call(arguments, 2);`
+            let init = self.slice_arguments(len);
+            declarations.push(self.var_statement(rest.unbox().argument, init));
+        }
+
+        declarations.extend(std::mem::replace(statements, self.ast.new_vec()));
+        *statements = declarations;
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn binding(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = BindingIdentifier::new(SPAN, name);
+        self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false)
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn number(&self, value: usize) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        #[allow(clippy::cast_precision_loss)]
+        let literal = self.ast.number_literal(SPAN, value as f64, raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+
+    fn arguments(&self) -> Expression<'a> {
+        self.reference(&Atom::from("arguments"))
+    }
+
+    /// `arguments[i]`
+    fn arguments_index(&self, i: usize) -> Expression<'a> {
+        self.ast.computed_member_expression(SPAN, self.arguments(), self.number(i), false)
+    }
+
+    /// `arguments.length > i`
+    fn arguments_length_greater_than(&self, i: usize) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, "length".into());
+        let length = self.ast.static_member_expression(SPAN, self.arguments(), property, false);
+        self.ast.binary_expression(SPAN, length, BinaryOperator::GreaterThan, self.number(i))
+    }
+
+    /// `arguments.length > i ? arguments[i] : undefined`
+    fn arguments_at(&self, i: usize) -> Expression<'a> {
+        let test = self.arguments_length_greater_than(i);
+        let undefined = self.reference(&Atom::from("undefined"));
+        self.ast.conditional_expression(SPAN, test, self.arguments_index(i), undefined)
+    }
+
+    /// `arguments.length > i && arguments[i] !== undefined ? arguments[i] : default`
+    fn arguments_with_default(&self, i: usize, default: Expression<'a>) -> Expression<'a> {
+        let left = self.arguments_length_greater_than(i);
+        let undefined = self.reference(&Atom::from("undefined"));
+        let right = self.ast.binary_expression(
+            SPAN,
+            self.arguments_index(i),
+            BinaryOperator::StrictInequality,
+            undefined,
+        );
+        let test = self.ast.logical_expression(SPAN, left, LogicalOperator::And, right);
+        self.ast.conditional_expression(SPAN, test, self.arguments_index(i), default)
+    }
+
+    /// `Array.prototype.slice.call(arguments, i)`
+    fn slice_arguments(&self, i: usize) -> Expression<'a> {
+        let array = self.reference(&Atom::from("Array"));
+        let mut callee = array;
+        for name in ["prototype", "slice", "call"] {
+            let property = IdentifierName::new(SPAN, name.into());
+            callee = self.ast.static_member_expression(SPAN, callee, property, false);
+        }
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.arguments()));
+        if i > 0 {
+            arguments.push(Argument::Expression(self.number(i)));
+        }
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `expr === void 0`
+    fn is_undefined(&self, expr: Expression<'a>) -> Expression<'a> {
+        self.ast.binary_expression(SPAN, expr, BinaryOperator::StrictEquality, self.ast.void_0())
+    }
+
+    /// `if (name === void 0) { name = value; }`
+    fn assign_if_undefined(&self, name: &Atom, value: Expression<'a>) -> Statement<'a> {
+        let test = self.is_undefined(self.reference(name));
+        let target = self
+            .ast
+            .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone()));
+        let assignment = self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        );
+        let consequent = self.ast.expression_statement(SPAN, assignment);
+        let consequent =
+            self.ast.block_statement(self.ast.block(SPAN, self.ast.new_vec_single(consequent)));
+        self.ast.if_statement(SPAN, test, consequent, None)
+    }
+
+    /// `var pattern = init;`
+    fn var_statement(&self, pattern: BindingPattern<'a>, init: Expression<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = self.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        let declarations = self.ast.new_vec_single(declarator);
+        let decl = self.ast.variable_declaration(SPAN, kind, declarations, Modifiers::empty());
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+}
+
+fn is_simple_parameter(param: &FormalParameter) -> bool {
+    matches!(param.pattern.kind, BindingPatternKind::BindingIdentifier(_))
+}
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2015,
+        parameters: true,
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "function foo(a, b = 1) {}",
+            "function foo(a) { var b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1; }",
+        ),
+        (
+            "function foo(a, ...b) {}",
+            "function foo(a) { var b = Array.prototype.slice.call(arguments, 1); }",
+        ),
+        ("function foo({ a }) {}", "function foo(_ref) { var { a } = _ref; }"),
+    ];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let mut options = options;
+    options.assumptions.ignore_function_length = true;
+    let tests = &[("function foo(a = 1) {}", "function foo(a) { if (a === void 0) { a = 1; } }")];
+    Tester::new("test.js", options).test(tests);
+}
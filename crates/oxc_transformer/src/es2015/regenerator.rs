@@ -0,0 +1,1942 @@
+use std::rc::Rc;
+
+use oxc_allocator::{CloneIn, Vec};
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstBuilder, Visit, VisitMut};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_semantic::SymbolId;
+use oxc_span::{Atom, GetSpan, Span, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    scope::ScopeFlags,
+    NumberBase,
+};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`yield` can not be transformed to ES5 in this position.")]
+#[diagnostic(severity(warning), help("Move the `yield` expression into a variable."))]
+struct UnsupportedYield(#[label] Span);
+
+/// The raw value of the numbers standing for a location until the listing is complete.
+const LOC: &str = "%loc";
+
+/// ES2015: Regenerator
+///
+/// ```javascript
+/// function* gen() { var x = yield 1; }
+/// // is transformed to
+/// var _marked = _regeneratorRuntime().mark(gen);
+/// function gen() {
+///   var x;
+///   return _regeneratorRuntime().wrap(function gen$(_context) {
+///     while (1) switch (_context.prev = _context.next) {
+///       case 0:
+///         _context.next = 2;
+///         return 1;
+///       case 2:
+///         x = _context.sent;
+///       case 3:
+///       case "end":
+///         return _context.stop();
+///     }
+///   }, _marked);
+/// }
+/// ```
+///
+/// The body is split into the cases of a state machine at each `yield`,
+/// the variables are hoisted to the outer function so they keep their values between the calls of `gen$`.
+/// Statements containing `yield`, `return`, `break` or `continue` are lowered to jumps between the cases,
+/// the locations of try statements are passed to the runtime as `[tryLoc, catchLoc, finallyLoc, afterLoc]`.
+///
+/// Runs after the other transforms so the generators created by the async transforms are lowered too.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-regenerator>
+/// * <https://github.com/facebook/regenerator/tree/main/packages/transform>
+pub struct Regenerator<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    /// `_marked = _regeneratorRuntime().mark(gen)` of the generator declarations in each statement list.
+    marked: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+}
+
+impl<'a> Regenerator<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.regenerator).then(|| Self {
+            ast,
+            ctx,
+            marked: vec![],
+        })
+    }
+
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        self.visit_statements(&mut program.body);
+    }
+
+    /// `function* gen() {}` -> `function gen() {}` with `var _marked = _regeneratorRuntime().mark(gen);`
+    fn transform_declaration(&mut self, func: &mut Function<'a>) {
+        let name = self.function_name(func);
+        let marked = self.generate_uid("marked");
+        let mark = self.mark(self.reference(&name));
+        let declarator = self.declarator(marked.clone(), Some(mark));
+        if let Some(declarations) = self.marked.last_mut() {
+            declarations.push(declarator);
+        }
+        self.transform_function(func, &name, Some(self.reference(&marked)));
+    }
+
+    /// `function* () {}` -> `_regeneratorRuntime().mark(function _callee() {})`
+    fn transform_expression(
+        &mut self,
+        mut func: oxc_allocator::Box<'a, Function<'a>>,
+    ) -> Expression<'a> {
+        let name = self.function_name(&mut func);
+        self.transform_function(&mut func, &name, Some(self.reference(&name)));
+        self.mark(self.ast.function_expression(func))
+    }
+
+    /// The name of `func`, anonymous functions are named `_callee`.
+    fn function_name(&self, func: &mut Function<'a>) -> Atom {
+        func.id
+            .get_or_insert_with(|| BindingIdentifier::new(SPAN, self.generate_uid("callee")))
+            .name
+            .clone()
+    }
+
+    /// Replace the body of `func` with `return _regeneratorRuntime().wrap(function name$(_context) {}, outer)`,
+    /// methods can not be marked and pass `null` as the outer function.
+    fn transform_function(
+        &mut self,
+        func: &mut Function<'a>,
+        name: &Atom,
+        outer: Option<Expression<'a>>,
+    ) {
+        let Some(body) = &mut func.body else { return };
+        func.generator = false;
+        let mut statements = self.ast.move_statement_vec(&mut body.statements);
+
+        let mut hoister = Hoister::new(&self.ast, &self.ctx);
+        for stmt in statements.iter_mut() {
+            hoister.visit_statement(stmt);
+        }
+
+        let context = self.generate_uid("context");
+        let mut emitter = Emitter::new(&self.ast, self.ctx.clone(), context.clone());
+        emitter.vars = hoister.vars;
+        for stmt in statements {
+            emitter.explode_statement(stmt, None);
+        }
+        let (cases, try_locs) = emitter.finish_listing();
+        let Emitter { vars, functions, .. } = emitter;
+
+        // `function gen$(_context) { while (1) switch (_context.prev = _context.next) {} }`
+        let discriminant = self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.context_target(&context, "prev"),
+            self.context_property(&context, "next"),
+        );
+        let dispatch = self.ast.while_statement(
+            SPAN,
+            self.ast.literal_number_expression(self.ast.number_literal(
+                SPAN,
+                1.0,
+                "1",
+                NumberBase::Decimal,
+            )),
+            self.ast.switch_statement(SPAN, discriminant, cases),
+        );
+        let param =
+            self.ast.formal_parameter(SPAN, self.binding(context), None, false, self.ast.new_vec());
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec_single(param),
+            None,
+        );
+        let inner = self.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            Some(BindingIdentifier::new(SPAN, Atom::from(format!("{name}$")))),
+            false,
+            false,
+            false,
+            params,
+            Some(self.ast.function_body(
+                SPAN,
+                self.ast.new_vec(),
+                self.ast.new_vec_single(dispatch),
+            )),
+            None,
+            None,
+            None,
+            Modifiers::empty(),
+        );
+
+        // `_regeneratorRuntime().wrap(gen$, _marked, this, [[0, 5]])`
+        let mut arguments = self.ast.new_vec_with_capacity(4);
+        arguments.push(Argument::Expression(self.ast.function_expression(inner)));
+        arguments.push(Argument::Expression(outer.unwrap_or_else(|| self.null())));
+        if hoister.uses_this {
+            arguments.push(Argument::Expression(self.ast.this_expression(SPAN)));
+        } else if try_locs.is_some() {
+            arguments.push(Argument::Expression(self.null()));
+        }
+        arguments.extend(try_locs.map(Argument::Expression));
+        let wrap = self.runtime_call("wrap", arguments);
+
+        // `var x, _args = arguments;`
+        let mut declarations = self.ast.new_vec_with_capacity(vars.len() + 1);
+        for var in vars {
+            declarations.push(self.declarator(var, None));
+        }
+        if let Some(args) = hoister.arguments {
+            declarations.push(self.declarator(args, Some(self.reference(&"arguments".into()))));
+        }
+        let Some(body) = &mut func.body else { return };
+        if !declarations.is_empty() {
+            let decl = self.ast.variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarations,
+                Modifiers::empty(),
+            );
+            body.statements.push(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+        }
+        body.statements.extend(functions);
+        body.statements.push(self.ast.return_statement(SPAN, Some(wrap)));
+    }
+
+    /// `_regeneratorRuntime().mark(gen)`
+    fn mark(&self, func: Expression<'a>) -> Expression<'a> {
+        self.runtime_call("mark", self.ast.new_vec_single(Argument::Expression(func)))
+    }
+
+    /// `_regeneratorRuntime().method(arguments)`
+    fn runtime_call(&self, method: &str, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        runtime_call(&self.ast, &self.ctx, method, arguments)
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn binding(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name));
+        self.ast.binding_pattern(ident, None, false)
+    }
+
+    fn declarator(&self, name: Atom, init: Option<Expression<'a>>) -> VariableDeclarator<'a> {
+        let kind = VariableDeclarationKind::Var;
+        self.ast.variable_declarator(SPAN, kind, self.binding(name), init, false)
+    }
+
+    fn null(&self) -> Expression<'a> {
+        self.ast.literal_null_expression(NullLiteral::new(SPAN))
+    }
+
+    fn context_property(&self, context: &Atom, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, self.reference(context), property, false)
+    }
+
+    fn context_target(&self, context: &Atom, name: &str) -> AssignmentTarget<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        let member = self.ast.static_member(SPAN, self.reference(context), property, false);
+        AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_member_expression(member),
+        )
+    }
+}
+
+impl<'a> VisitMut<'a> for Regenerator<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        self.marked.push(self.ast.new_vec());
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+        let Some(declarations) = self.marked.pop() else { return };
+        if !declarations.is_empty() {
+            let decl = self.ast.variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarations,
+                Modifiers::empty(),
+            );
+            stmts.insert(0, Statement::Declaration(Declaration::VariableDeclaration(decl)));
+        }
+    }
+
+    /// Nested generators are transformed first, the generator expressions in [`Self::visit_expression`].
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.visit_function_body(body);
+        }
+        if func.generator && func.r#type == FunctionType::FunctionDeclaration {
+            self.transform_declaration(func);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.visit_expression_match(expr);
+        if matches!(expr, Expression::FunctionExpression(func) if func.generator) {
+            let Expression::FunctionExpression(func) = self.ast.move_expression(expr) else {
+                unreachable!()
+            };
+            *expr = self.transform_expression(func);
+        }
+    }
+
+    /// `{ *foo() {} }` -> `{ foo: _regeneratorRuntime().mark(function _callee() {}) }`
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if prop.method
+            && matches!(&prop.value, Expression::FunctionExpression(func) if func.generator)
+        {
+            prop.method = false;
+        }
+        self.visit_property_key(&mut prop.key);
+        self.visit_expression(&mut prop.value);
+    }
+
+    /// `*foo() {}` -> `foo() { return _regeneratorRuntime().wrap(function foo$(_context) {}, null, this); }`
+    fn visit_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
+        self.visit_property_key(&mut def.key);
+        self.visit_function(&mut def.value);
+        if def.value.generator {
+            let name = def.key.static_name().unwrap_or_else(|| "_callee".into());
+            self.transform_function(&mut def.value, &name, None);
+        }
+    }
+}
+
+/// `_regeneratorRuntime().method(arguments)`
+fn runtime_call<'a>(
+    ast: &AstBuilder<'a>,
+    ctx: &TransformerCtx<'a>,
+    method: &str,
+    arguments: Vec<'a, Argument<'a>>,
+) -> Expression<'a> {
+    let runtime = ctx.helpers.call(Helper::RegeneratorRuntime, ast.new_vec());
+    let property = IdentifierName::new(SPAN, Atom::from(method));
+    let callee = ast.static_member_expression(SPAN, runtime, property, false);
+    ast.call_expression(SPAN, callee, arguments, false, None)
+}
+
+/// An index into the locations of [`Emitter`].
+type Loc = usize;
+
+/// The targets of `break` and `continue`.
+enum LeapEntry {
+    Loop { label: Option<Atom>, break_loc: Loc, continue_loc: Loc },
+    Switch { break_loc: Loc },
+    Labeled { label: Atom, break_loc: Loc },
+}
+
+struct TryEntry {
+    try_loc: Loc,
+    catch_loc: Option<Loc>,
+    finally_loc: Option<Loc>,
+    after_loc: Loc,
+}
+
+/// Lowers the statements of a generator to a listing of statements,
+/// which is split into the cases of the state machine at the marked locations.
+struct Emitter<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    ctx: TransformerCtx<'a>,
+    /// `_context`
+    context: Atom,
+    listing: Vec<'a, Statement<'a>>,
+    /// The index in the listing of each location, `None` until it is marked.
+    locs: std::vec::Vec<Option<usize>>,
+    /// The indices in the listing starting a case.
+    marked: FxHashSet<usize>,
+    /// The number of `_context.t0` temporaries.
+    temps: usize,
+    try_entries: std::vec::Vec<TryEntry>,
+    leaps: std::vec::Vec<LeapEntry>,
+    /// Variables hoisted to the outer function.
+    vars: std::vec::Vec<Atom>,
+    /// Function declarations hoisted to the outer function.
+    functions: Vec<'a, Statement<'a>>,
+}
+
+impl<'a, 'b> Emitter<'a, 'b> {
+    fn new(ast: &'b AstBuilder<'a>, ctx: TransformerCtx<'a>, context: Atom) -> Self {
+        let mut marked = FxHashSet::default();
+        marked.insert(0);
+        Self {
+            ast,
+            ctx,
+            context,
+            listing: ast.new_vec(),
+            locs: vec![],
+            marked,
+            temps: 0,
+            try_entries: vec![],
+            leaps: vec![],
+            vars: vec![],
+            functions: ast.new_vec(),
+        }
+    }
+
+    /// The cases of the switch statement and the locations of the try statements.
+    fn finish_listing(&mut self) -> (Vec<'a, SwitchCase<'a>>, Option<Expression<'a>>) {
+        let end = self.loc();
+        self.mark(end);
+
+        let mut resolver = LocResolver { ast: self.ast, locs: &self.locs };
+        let listing = self.ast.move_statement_vec(&mut self.listing);
+        let mut cases = self.ast.new_vec();
+        let mut current: Option<(usize, Vec<'a, Statement<'a>>)> = None;
+        let mut ended = false;
+        for (index, mut stmt) in listing.into_iter().enumerate() {
+            if self.marked.contains(&index) {
+                if let Some((index, consequent)) = current.take() {
+                    cases.push(self.switch_case(index, consequent));
+                }
+                current = Some((index, self.ast.new_vec()));
+                ended = false;
+            }
+            // Statements after a completion can not be reached
+            if let Some((_, consequent)) = &mut current {
+                if !ended {
+                    ended = is_completion(&stmt);
+                    resolver.visit_statement(&mut stmt);
+                    consequent.push(stmt);
+                }
+            }
+        }
+        if let Some((index, consequent)) = current.take() {
+            cases.push(self.switch_case(index, consequent));
+        }
+        let end = self.locs[end].unwrap_or_default();
+        cases.push(self.switch_case(end, self.ast.new_vec()));
+        // `case "end": return _context.stop();`
+        let stop = self.call(self.context_property("stop"), self.ast.new_vec());
+        let test = self.string(LOC_END);
+        let stop = self.ast.new_vec_single(self.ast.return_statement(SPAN, Some(stop)));
+        cases.push(self.ast.switch_case(SPAN, Some(test), stop));
+
+        (cases, self.try_locs())
+    }
+
+    /// `[[tryLoc, catchLoc, finallyLoc, afterLoc]]`, the catch location is a hole without a catch clause.
+    fn try_locs(&self) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let mut entries = self.ast.new_vec_with_capacity(self.try_entries.len());
+        for entry in &self.try_entries {
+            let mut locs = self.ast.new_vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::Expression(self.resolved(entry.try_loc)));
+            if let Some(catch_loc) = entry.catch_loc {
+                locs.push(ArrayExpressionElement::Expression(self.resolved(catch_loc)));
+            }
+            if let Some(finally_loc) = entry.finally_loc {
+                if entry.catch_loc.is_none() {
+                    locs.push(ArrayExpressionElement::Elision(SPAN));
+                }
+                locs.push(ArrayExpressionElement::Expression(self.resolved(finally_loc)));
+                locs.push(ArrayExpressionElement::Expression(self.resolved(entry.after_loc)));
+            }
+            let locs = self.ast.array_expression(SPAN, locs, None);
+            entries.push(ArrayExpressionElement::Expression(locs));
+        }
+        Some(self.ast.array_expression(SPAN, entries, None))
+    }
+
+    fn switch_case(&self, index: usize, consequent: Vec<'a, Statement<'a>>) -> SwitchCase<'a> {
+        self.ast.switch_case(SPAN, Some(self.number(index)), consequent)
+    }
+
+    /* ---------- Locations ---------- */
+
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        self.locs.len() - 1
+    }
+
+    /// The current location without starting a case, for the start of try statements.
+    fn current_loc(&mut self) -> Loc {
+        self.locs.push(Some(self.listing.len()));
+        self.locs.len() - 1
+    }
+
+    /// Start a case at `loc`.
+    fn mark(&mut self, loc: Loc) {
+        let index = self.listing.len();
+        self.locs[loc] = Some(index);
+        self.marked.insert(index);
+    }
+
+    /// A placeholder for `loc`, replaced with its index in [`Self::finish_listing`].
+    #[allow(clippy::cast_precision_loss)]
+    fn loc_expr(&self, loc: Loc) -> Expression<'a> {
+        let literal = self.ast.number_literal(SPAN, loc as f64, LOC, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+
+    fn resolved(&self, loc: Loc) -> Expression<'a> {
+        self.number(self.locs[loc].unwrap_or_default())
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn number(&self, value: usize) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        let literal = self.ast.number_literal(SPAN, value as f64, raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+
+    /* ---------- Emitting ---------- */
+
+    fn emit(&mut self, stmt: Statement<'a>) {
+        self.listing.push(stmt);
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>) {
+        self.emit(self.ast.expression_statement(SPAN, expr));
+    }
+
+    /// Emit `left = right` and return `left`.
+    fn emit_assign(&mut self, left: Expression<'a>, right: Expression<'a>) -> Expression<'a> {
+        let result = left.clone_in(self.ast.allocator);
+        self.emit_expression(self.assign(left, right));
+        result
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Loc) {
+        self.jump_to(self.loc_expr(loc));
+    }
+
+    fn jump_to(&mut self, target: Expression<'a>) {
+        self.emit_expression(self.assign(self.context_property("next"), target));
+        self.emit(self.ast.break_statement(SPAN, None));
+    }
+
+    /// `if (test) { _context.next = loc; break; }`
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        let mut body = self.ast.new_vec_with_capacity(2);
+        body.push(self.ast.expression_statement(
+            SPAN,
+            self.assign(self.context_property("next"), self.loc_expr(loc)),
+        ));
+        body.push(self.ast.break_statement(SPAN, None));
+        let consequent = self.ast.block_statement(self.ast.block(SPAN, body));
+        self.emit(self.ast.if_statement(SPAN, test, consequent, None));
+    }
+
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc) {
+        let test = match test {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => self.ast.unary_expression(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.jump_if(test, loc);
+    }
+
+    /// `return _context.abrupt("break", loc);`
+    fn abrupt(&mut self, kind: &str, argument: Option<Expression<'a>>) {
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.string(kind)));
+        arguments.extend(argument.map(Argument::Expression));
+        let abrupt = self.call(self.context_property("abrupt"), arguments);
+        self.emit(self.ast.return_statement(SPAN, Some(abrupt)));
+    }
+
+    /// `_context.prev = loc;`
+    fn update_prev(&mut self, loc: Loc) {
+        self.emit_expression(self.assign(self.context_property("prev"), self.loc_expr(loc)));
+    }
+
+    /* ---------- Expressions ---------- */
+
+    fn context_property(&self, name: &str) -> Expression<'a> {
+        let object = self.reference(&self.context);
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, object, property, false)
+    }
+
+    /// A new temporary `_context.t0`, by its property name.
+    fn temp(&mut self) -> Atom {
+        let name = Atom::from(format!("t{}", self.temps));
+        self.temps += 1;
+        name
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn string(&self, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(SPAN, Atom::from(value)))
+    }
+
+    fn undefined(&self) -> Expression<'a> {
+        let zero = self.ast.number_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+        let zero = self.ast.literal_number_expression(zero);
+        self.ast.unary_expression(SPAN, UnaryOperator::Void, zero)
+    }
+
+    fn call(&self, callee: Expression<'a>, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, object, property, false)
+    }
+
+    /// `left = right` for an identifier or member expression `left`.
+    fn assign(&self, left: Expression<'a>, right: Expression<'a>) -> Expression<'a> {
+        let target = match left {
+            Expression::Identifier(ident) => {
+                self.ast.simple_assignment_target_identifier(ident.unbox())
+            }
+            Expression::MemberExpression(member) => {
+                self.ast.simple_assignment_target_member_expression(member.unbox())
+            }
+            _ => unreachable!("only identifiers and members are assigned"),
+        };
+        let target = AssignmentTarget::SimpleAssignmentTarget(target);
+        self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, right)
+    }
+
+    fn take(&self, expr: &mut Expression<'a>) -> Expression<'a> {
+        self.ast.move_expression(expr)
+    }
+
+    /* ---------- Statements ---------- */
+
+    fn explode_statement(&mut self, stmt: Statement<'a>, label: Option<Atom>) {
+        match stmt {
+            Statement::EmptyStatement(_) => return,
+            Statement::Declaration(Declaration::FunctionDeclaration(_)) => {
+                self.functions.push(stmt);
+                return;
+            }
+            Statement::Declaration(Declaration::VariableDeclaration(decl)) => {
+                self.explode_variable_declaration(decl.unbox());
+                return;
+            }
+            // `class A {}` -> `A = class A {}`
+            Statement::Declaration(Declaration::ClassDeclaration(mut class)) => {
+                if let Some(id) = &class.id {
+                    let name = id.name.clone();
+                    class.r#type = ClassType::ClassExpression;
+                    let class = self.ast.class_expression(class);
+                    self.emit_expression(self.assign(self.reference(&name), class));
+                    self.vars.push(name);
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        if !contains_leap(&stmt) {
+            self.emit(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression(stmt.unbox().expression, true);
+            }
+            Statement::BlockStatement(block) => {
+                for stmt in block.unbox().body {
+                    self.explode_statement(stmt, None);
+                }
+            }
+            Statement::LabeledStatement(stmt) => {
+                let LabeledStatement { label, body, .. } = stmt.unbox();
+                let after = self.loc();
+                self.leaps.push(LeapEntry::Labeled { label: label.name.clone(), break_loc: after });
+                self.explode_statement(body, Some(label.name));
+                self.leaps.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let WhileStatement { test, body, .. } = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode(test);
+                self.jump_if_not(test, after);
+                self.explode_loop_body(body, label, after, before);
+                self.jump(before);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let DoWhileStatement { body, test, .. } = stmt.unbox();
+                let first = self.loc();
+                let test_loc = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.explode_loop_body(body, label, after, test_loc);
+                self.mark(test_loc);
+                let test = self.explode(test);
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                let ForStatement { init, test, update, body, .. } = stmt.unbox();
+                let head = self.loc();
+                let update_loc = self.loc();
+                let after = self.loc();
+                match init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.explode_variable_declaration(decl.unbox());
+                    }
+                    Some(ForStatementInit::Expression(init)) => {
+                        self.explode_expression(init, true);
+                    }
+                    Some(ForStatementInit::UsingDeclaration(decl)) => {
+                        self.ctx.error(UnsupportedYield(decl.span));
+                    }
+                    None => {}
+                }
+                self.mark(head);
+                if let Some(test) = test {
+                    let test = self.explode(test);
+                    self.jump_if_not(test, after);
+                }
+                self.explode_loop_body(body, label, after, update_loc);
+                self.mark(update_loc);
+                if let Some(update) = update {
+                    self.explode_expression(update, true);
+                }
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => {
+                let ForInStatement { left, right, body, .. } = stmt.unbox();
+                self.explode_for_each("keys", left, right, body, label);
+            }
+            Statement::ForOfStatement(stmt) if !stmt.r#await => {
+                let ForOfStatement { left, right, body, .. } = stmt.unbox();
+                self.explode_for_each("values", left, right, body, label);
+            }
+            Statement::IfStatement(stmt) => {
+                let IfStatement { test, consequent, alternate, .. } = stmt.unbox();
+                let else_loc = alternate.as_ref().map(|_| self.loc());
+                let after = self.loc();
+                let test = self.explode(test);
+                self.jump_if_not(test, else_loc.unwrap_or(after));
+                self.explode_statement(consequent, None);
+                if let (Some(else_loc), Some(alternate)) = (else_loc, alternate) {
+                    self.jump(after);
+                    self.mark(else_loc);
+                    self.explode_statement(alternate, None);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let argument = stmt.unbox().argument.map(|argument| self.explode(argument));
+                self.abrupt("return", argument);
+            }
+            Statement::BreakStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| &label.name);
+                match self.break_loc(label) {
+                    Some(loc) => self.abrupt("break", Some(self.loc_expr(loc))),
+                    None => self.emit(Statement::BreakStatement(stmt)),
+                }
+            }
+            Statement::ContinueStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| &label.name);
+                match self.continue_loc(label) {
+                    Some(loc) => self.abrupt("continue", Some(self.loc_expr(loc))),
+                    None => self.emit(Statement::ContinueStatement(stmt)),
+                }
+            }
+            Statement::ThrowStatement(stmt) => {
+                let ThrowStatement { span, argument } = stmt.unbox();
+                let argument = self.explode(argument);
+                self.emit(self.ast.throw_statement(span, argument));
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch(stmt.unbox()),
+            Statement::TryStatement(stmt) => self.explode_try(stmt.unbox()),
+            stmt => {
+                self.ctx.error(UnsupportedYield(stmt.span()));
+                self.emit(stmt);
+            }
+        }
+    }
+
+    fn explode_loop_body(
+        &mut self,
+        body: Statement<'a>,
+        label: Option<Atom>,
+        break_loc: Loc,
+        continue_loc: Loc,
+    ) {
+        self.leaps.push(LeapEntry::Loop { label, break_loc, continue_loc });
+        self.explode_statement(body, None);
+        self.leaps.pop();
+    }
+
+    /// `let x = yield 1;` -> `x = yield 1;` with `x` hoisted, `let x;` is reset with `x = void 0;`
+    fn explode_variable_declaration(&mut self, decl: VariableDeclaration<'a>) {
+        let is_var = decl.kind.is_var();
+        for declarator in decl.declarations {
+            let VariableDeclarator { id, init, .. } = declarator;
+            let init = match init {
+                Some(init) => init,
+                None if is_var => {
+                    collect_names(&id, &mut self.vars);
+                    continue;
+                }
+                None => self.undefined(),
+            };
+            let target = binding_to_target(self.ast, id, &mut self.vars);
+            let assign =
+                self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, init);
+            self.explode_expression(assign, true);
+        }
+    }
+
+    /// `for (x in o)` with `_context.t0 = _regeneratorRuntime().keys(o)`,
+    /// and `for (x of xs)` with `_context.t0 = _regeneratorRuntime().values(xs)`.
+    fn explode_for_each(
+        &mut self,
+        method: &str,
+        left: ForStatementLeft<'a>,
+        right: Expression<'a>,
+        body: Statement<'a>,
+        label: Option<Atom>,
+    ) {
+        let head = self.loc();
+        let after = self.loc();
+        let right = self.explode(right);
+        let iterator = self.temp();
+        let init = runtime_call(
+            self.ast,
+            &self.ctx,
+            method,
+            self.ast.new_vec_single(Argument::Expression(right)),
+        );
+        self.emit_assign(self.context_property(&iterator), init);
+        self.mark(head);
+        // `(_context.t1 = _context.t0()).done` or `(_context.t1 = _context.t0.next()).done`
+        let step = self.temp();
+        let next = if method == "keys" {
+            self.context_property(&iterator)
+        } else {
+            self.member(self.context_property(&iterator), "next")
+        };
+        let next = self.call(next, self.ast.new_vec());
+        let next = self.assign(self.context_property(&step), next);
+        let done = self.member(self.ast.parenthesized_expression(SPAN, next), "done");
+        self.jump_if(done, after);
+        let target = match left {
+            ForStatementLeft::AssignmentTarget(target) => target,
+            ForStatementLeft::VariableDeclaration(mut decl) => {
+                let declarator = decl.declarations.remove(0);
+                binding_to_target(self.ast, declarator.id, &mut self.vars)
+            }
+            ForStatementLeft::UsingDeclaration(decl) => {
+                self.ctx.error(UnsupportedYield(decl.span));
+                return;
+            }
+        };
+        let value = self.member(self.context_property(&step), "value");
+        let assign =
+            self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assign);
+        self.explode_loop_body(body, label, after, head);
+        self.jump(head);
+        self.mark(after);
+    }
+
+    /// The cases are selected with `_context.next = _context.t0 === a ? 3 : _context.t0 === b ? 5 : 7;`
+    fn explode_switch(&mut self, stmt: SwitchStatement<'a>) {
+        let SwitchStatement { discriminant, mut cases, .. } = stmt;
+        let discriminant = self.explode(discriminant);
+        let temp = self.temp();
+        let discriminant = self.emit_assign(self.context_property(&temp), discriminant);
+        let after = self.loc();
+        let default_loc = self.loc();
+        let locs = cases
+            .iter()
+            .map(|case| if case.test.is_some() { self.loc() } else { default_loc })
+            .collect::<std::vec::Vec<_>>();
+        let mut condition = self.loc_expr(default_loc);
+        for (case, loc) in cases.iter_mut().zip(&locs).rev() {
+            if let Some(test) = case.test.take() {
+                let test = self.ast.binary_expression(
+                    SPAN,
+                    discriminant.clone_in(self.ast.allocator),
+                    BinaryOperator::StrictEquality,
+                    test,
+                );
+                condition =
+                    self.ast.conditional_expression(SPAN, test, self.loc_expr(*loc), condition);
+            }
+        }
+        let target = self.explode(condition);
+        self.jump_to(target);
+
+        self.leaps.push(LeapEntry::Switch { break_loc: after });
+        for (case, loc) in cases.into_iter().zip(locs) {
+            self.mark(loc);
+            for stmt in case.consequent {
+                self.explode_statement(stmt, None);
+            }
+        }
+        self.leaps.pop();
+        self.mark(after);
+        if self.locs[default_loc].is_none() {
+            self.mark(default_loc);
+        }
+    }
+
+    /// The runtime dispatches exceptions to the catch location with `_context.prev` in the try block,
+    /// the caught exception is `_context.t0 = _context["catch"](tryLoc)`.
+    fn explode_try(&mut self, stmt: TryStatement<'a>) {
+        let TryStatement { block, handler, finalizer, .. } = stmt;
+        let after = self.loc();
+        let catch_loc = handler.as_ref().map(|_| self.loc());
+        let finally_loc = finalizer.as_ref().map(|_| self.loc());
+        let try_loc = self.current_loc();
+        self.try_entries.push(TryEntry { try_loc, catch_loc, finally_loc, after_loc: after });
+        self.update_prev(try_loc);
+        for stmt in block.unbox().body {
+            self.explode_statement(stmt, None);
+        }
+
+        if let (Some(handler), Some(catch_loc)) = (handler, catch_loc) {
+            self.jump(finally_loc.unwrap_or(after));
+            self.mark(catch_loc);
+            self.update_prev(catch_loc);
+            let temp = self.temp();
+            let catch = self.ast.computed_member_expression(
+                SPAN,
+                self.reference(&self.context),
+                self.string("catch"),
+                false,
+            );
+            let caught = self
+                .call(catch, self.ast.new_vec_single(Argument::Expression(self.loc_expr(try_loc))));
+            self.emit_assign(self.context_property(&temp), caught);
+            let CatchClause { param, body, .. } = handler.unbox();
+            let mut body = body.unbox().body;
+            match param {
+                Some(BindingPattern {
+                    kind: BindingPatternKind::BindingIdentifier(ident), ..
+                }) => {
+                    let mut replacer = CatchParamReplacer {
+                        ast: self.ast,
+                        ctx: &self.ctx,
+                        name: ident.name.clone(),
+                        symbol_id: ident.symbol_id.get(),
+                        replacement: self.context_property(&temp),
+                    };
+                    replacer.visit_statements(&mut body);
+                }
+                Some(param) => {
+                    let target = binding_to_target(self.ast, param, &mut self.vars);
+                    let value = self.context_property(&temp);
+                    let assign = self.ast.assignment_expression(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        target,
+                        value,
+                    );
+                    self.emit_expression(assign);
+                }
+                None => {}
+            }
+            for stmt in body {
+                self.explode_statement(stmt, None);
+            }
+        }
+
+        if let (Some(finalizer), Some(finally_loc)) = (finalizer, finally_loc) {
+            self.mark(finally_loc);
+            self.update_prev(finally_loc);
+            for stmt in finalizer.unbox().body {
+                self.explode_statement(stmt, None);
+            }
+            // `return _context.finish(finallyLoc);`
+            let finish = self.call(
+                self.context_property("finish"),
+                self.ast.new_vec_single(Argument::Expression(self.loc_expr(finally_loc))),
+            );
+            self.emit(self.ast.return_statement(SPAN, Some(finish)));
+        }
+        self.mark(after);
+    }
+
+    fn break_loc(&self, label: Option<&Atom>) -> Option<Loc> {
+        self.leaps.iter().rev().find_map(|entry| match (entry, label) {
+            (LeapEntry::Loop { break_loc, .. } | LeapEntry::Switch { break_loc }, None) => {
+                Some(*break_loc)
+            }
+            (
+                LeapEntry::Loop { label: Some(name), break_loc, .. }
+                | LeapEntry::Labeled { label: name, break_loc },
+                Some(label),
+            ) if name == label => Some(*break_loc),
+            _ => None,
+        })
+    }
+
+    fn continue_loc(&self, label: Option<&Atom>) -> Option<Loc> {
+        self.leaps.iter().rev().find_map(|entry| match entry {
+            LeapEntry::Loop { label: name, continue_loc, .. }
+                if label.is_none() || name.as_ref() == label =>
+            {
+                Some(*continue_loc)
+            }
+            _ => None,
+        })
+    }
+
+    /* ---------- Expression explosion ---------- */
+
+    /// Explode `expr` for its value.
+    fn explode(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        self.explode_expression(expr, false).unwrap_or_else(|| self.undefined())
+    }
+
+    /// Explode `expr` for its value, stored in a temporary so it is evaluated before the following `yield`.
+    fn explode_temp(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        self.explode_via_temp(None, expr, false).unwrap_or_else(|| self.undefined())
+    }
+
+    fn explode_via_temp(
+        &mut self,
+        temp: Option<Atom>,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        let result = self.explode_expression(expr, ignore_result)?;
+        Some(match temp {
+            Some(temp) => self.emit_assign(self.context_property(&temp), result),
+            None if is_literal(&result) => result,
+            None => {
+                let temp = self.temp();
+                self.emit_assign(self.context_property(&temp), result)
+            }
+        })
+    }
+
+    /// Emit `expr` when its result is ignored, otherwise return it.
+    fn finish(&mut self, expr: Expression<'a>, ignore_result: bool) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Emit the parts of `expr` before each `yield` and return the rest,
+    /// the values evaluated before a `yield` are kept in `_context.t0` temporaries.
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if !contains_yield(&expr) {
+            return self.finish(expr, ignore_result);
+        }
+        match expr {
+            Expression::ParenthesizedExpression(expr) => {
+                self.explode_expression(expr.unbox().expression, ignore_result)
+            }
+            Expression::MemberExpression(mut member) => {
+                self.explode_member(&mut member);
+                self.finish(Expression::MemberExpression(member), ignore_result)
+            }
+            Expression::CallExpression(call) => {
+                let call = self.explode_call(call);
+                self.finish(call, ignore_result)
+            }
+            Expression::NewExpression(mut new) => {
+                let callee = self.take(&mut new.callee);
+                new.callee = self.explode_temp(callee);
+                self.explode_arguments(&mut new.arguments);
+                self.finish(Expression::NewExpression(new), ignore_result)
+            }
+            Expression::ObjectExpression(mut object) => {
+                for property in object.properties.iter_mut() {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(property) => {
+                            if let PropertyKey::Expression(key) = &mut property.key {
+                                let key = self.take(key);
+                                property.key = PropertyKey::Expression(self.explode_temp(key));
+                            }
+                            let value = self.take(&mut property.value);
+                            property.value = self.explode_temp(value);
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            let argument = self.take(&mut spread.argument);
+                            spread.argument = self.explode_temp(argument);
+                        }
+                    }
+                }
+                self.finish(Expression::ObjectExpression(object), ignore_result)
+            }
+            Expression::ArrayExpression(mut array) => {
+                for element in array.elements.iter_mut() {
+                    match element {
+                        ArrayExpressionElement::Expression(expr) => {
+                            let value = self.take(expr);
+                            *expr = self.explode_temp(value);
+                        }
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            let argument = self.take(&mut spread.argument);
+                            spread.argument = self.explode_temp(argument);
+                        }
+                        ArrayExpressionElement::Elision(_) => {}
+                    }
+                }
+                self.finish(Expression::ArrayExpression(array), ignore_result)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let mut expressions = sequence.unbox().expressions;
+                let last = expressions.pop();
+                for expr in expressions {
+                    self.explode_expression(expr, true);
+                }
+                last.and_then(|last| self.explode_expression(last, ignore_result))
+            }
+            Expression::LogicalExpression(expr) => {
+                self.explode_logical(expr.unbox(), ignore_result)
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.explode_conditional(expr.unbox(), ignore_result)
+            }
+            Expression::UnaryExpression(mut unary) => {
+                let argument = self.take(&mut unary.argument);
+                unary.argument = self.explode(argument);
+                self.finish(Expression::UnaryExpression(unary), ignore_result)
+            }
+            Expression::BinaryExpression(mut binary) => {
+                let left = self.take(&mut binary.left);
+                binary.left = self.explode_temp(left);
+                let right = self.take(&mut binary.right);
+                binary.right = self.explode_temp(right);
+                self.finish(Expression::BinaryExpression(binary), ignore_result)
+            }
+            Expression::AssignmentExpression(assign) => {
+                self.explode_assignment(assign, ignore_result)
+            }
+            Expression::UpdateExpression(mut update) => {
+                self.explode_simple_target(&mut update.argument);
+                self.finish(Expression::UpdateExpression(update), ignore_result)
+            }
+            Expression::YieldExpression(expr) => self.explode_yield(expr.unbox(), ignore_result),
+            Expression::TemplateLiteral(mut template) => {
+                for expr in template.expressions.iter_mut() {
+                    let value = self.take(expr);
+                    *expr = self.explode_temp(value);
+                }
+                self.finish(Expression::TemplateLiteral(template), ignore_result)
+            }
+            expr => {
+                self.ctx.error(UnsupportedYield(expr.span()));
+                self.finish(expr, ignore_result)
+            }
+        }
+    }
+
+    fn explode_member(&mut self, member: &mut MemberExpression<'a>) {
+        let object = self.take(member.object_mut());
+        *member.object_mut() = self.explode_temp(object);
+        if let MemberExpression::ComputedMemberExpression(member) = member {
+            let property = self.take(&mut member.expression);
+            member.expression = self.explode_temp(property);
+        }
+    }
+
+    /// `a.b(yield x)` -> `_context.t0 = a; ... _context.t0.b.call(_context.t0, _context.t1)`,
+    /// other callees are called unqualified, `(0, _context.t0)(_context.t1)`.
+    fn explode_call(
+        &mut self,
+        mut call: oxc_allocator::Box<'a, CallExpression<'a>>,
+    ) -> Expression<'a> {
+        let has_leaping_arguments = call.arguments.iter().any(|argument| match argument {
+            Argument::Expression(expr) => contains_yield(expr),
+            Argument::SpreadElement(spread) => contains_yield(&spread.argument),
+        });
+        let callee = self.take(&mut call.callee);
+        let mut this_argument = None;
+        call.callee = match callee {
+            Expression::MemberExpression(mut member) if has_leaping_arguments => {
+                let object = self.take(member.object_mut());
+                let temp = self.temp();
+                let object = self.explode_via_temp(Some(temp), object, false);
+                let object = object.unwrap_or_else(|| self.undefined());
+                if let MemberExpression::ComputedMemberExpression(member) = &mut *member {
+                    let property = self.take(&mut member.expression);
+                    member.expression = self.explode_temp(property);
+                }
+                this_argument = Some(object.clone_in(self.ast.allocator));
+                *member.object_mut() = object;
+                self.member(Expression::MemberExpression(member), "call")
+            }
+            callee @ Expression::MemberExpression(_) => self.explode(callee),
+            callee => match self.explode_temp(callee) {
+                callee @ Expression::MemberExpression(_) => {
+                    let mut expressions = self.ast.new_vec_with_capacity(2);
+                    expressions.push(self.number(0));
+                    expressions.push(callee);
+                    self.ast.sequence_expression(SPAN, expressions)
+                }
+                callee => callee,
+            },
+        };
+        if has_leaping_arguments {
+            self.explode_arguments(&mut call.arguments);
+            if let Some(this_argument) = this_argument {
+                call.arguments.insert(0, Argument::Expression(this_argument));
+            }
+        }
+        Expression::CallExpression(call)
+    }
+
+    fn explode_arguments(&mut self, arguments: &mut Vec<'a, Argument<'a>>) {
+        for argument in arguments.iter_mut() {
+            match argument {
+                Argument::Expression(expr) => {
+                    let value = self.take(expr);
+                    *expr = self.explode_temp(value);
+                }
+                Argument::SpreadElement(spread) => {
+                    let value = self.take(&mut spread.argument);
+                    spread.argument = self.explode_temp(value);
+                }
+            }
+        }
+    }
+
+    /// `a && (yield b)` -> `_context.t0 = a; if (!_context.t0) { _context.next = 5; break; } ...`
+    fn explode_logical(
+        &mut self,
+        expr: LogicalExpression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        let LogicalExpression { left, operator, right, .. } = expr;
+        let after = self.loc();
+        let result = (!ignore_result).then(|| self.temp());
+        let left = self.explode_via_temp(result.clone(), left, false);
+        let left = left.unwrap_or_else(|| self.undefined());
+        match operator {
+            LogicalOperator::And => self.jump_if_not(left, after),
+            LogicalOperator::Or => self.jump_if(left, after),
+            LogicalOperator::Coalesce => {
+                let null = self.ast.literal_null_expression(NullLiteral::new(SPAN));
+                let test = self.ast.binary_expression(SPAN, left, BinaryOperator::Inequality, null);
+                self.jump_if(test, after);
+            }
+        }
+        self.explode_via_temp(result.clone(), right, ignore_result);
+        self.mark(after);
+        result.map(|result| self.context_property(&result))
+    }
+
+    fn explode_conditional(
+        &mut self,
+        expr: ConditionalExpression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        let ConditionalExpression { test, consequent, alternate, .. } = expr;
+        let else_loc = self.loc();
+        let after = self.loc();
+        let test = self.explode(test);
+        let result = (!ignore_result).then(|| self.temp());
+        self.jump_if_not(test, else_loc);
+        self.explode_via_temp(result.clone(), consequent, ignore_result);
+        self.jump(after);
+        self.mark(else_loc);
+        self.explode_via_temp(result.clone(), alternate, ignore_result);
+        self.mark(after);
+        result.map(|result| self.context_property(&result))
+    }
+
+    /// `a += yield b` -> `_context.t0 = a; ... a = _context.t0 + _context.sent`
+    fn explode_assignment(
+        &mut self,
+        mut assign: oxc_allocator::Box<'a, AssignmentExpression<'a>>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if assign.operator == AssignmentOperator::Assign {
+            match &mut assign.left {
+                AssignmentTarget::SimpleAssignmentTarget(target) => {
+                    self.explode_simple_target(target);
+                }
+                AssignmentTarget::AssignmentTargetPattern(pattern) => {
+                    if contains_yield_in_target(pattern) {
+                        self.ctx.error(UnsupportedYield(assign.span));
+                    }
+                }
+            }
+            let right = self.take(&mut assign.right);
+            assign.right = self.explode(right);
+            return self.finish(Expression::AssignmentExpression(assign), ignore_result);
+        }
+        let operator = assign.operator.to_binary_operator();
+        let lhs = match (&mut assign.left, operator) {
+            (AssignmentTarget::SimpleAssignmentTarget(target), Some(operator)) => {
+                self.explode_simple_target(target);
+                simple_target_expression(self.ast, target).map(|lhs| (lhs, operator))
+            }
+            _ => None,
+        };
+        let Some((lhs, operator)) = lhs else {
+            self.ctx.error(UnsupportedYield(assign.span));
+            return self.finish(Expression::AssignmentExpression(assign), ignore_result);
+        };
+        let temp = self.temp();
+        let temp = self.emit_assign(self.context_property(&temp), lhs);
+        let right = self.take(&mut assign.right);
+        let right = self.explode(right);
+        assign.operator = AssignmentOperator::Assign;
+        assign.right = self.ast.binary_expression(SPAN, temp, operator, right);
+        self.finish(Expression::AssignmentExpression(assign), ignore_result)
+    }
+
+    fn explode_simple_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::MemberAssignmentTarget(member) = target {
+            let mut finder = LeapFinder { yield_only: true, found: false };
+            finder.visit_member_expression(member);
+            if finder.found {
+                self.explode_member(member);
+            }
+        }
+    }
+
+    /// `yield x` -> `_context.next = 2; return x; case 2: _context.sent`,
+    /// `yield* xs` -> `return _context.delegateYield(xs, "t0", 2); case 2: _context.t0`
+    fn explode_yield(
+        &mut self,
+        expr: YieldExpression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        let after = self.loc();
+        let argument = expr.argument.map(|argument| self.explode(argument));
+        if expr.delegate {
+            let result = self.temp();
+            let mut arguments = self.ast.new_vec_with_capacity(3);
+            arguments.push(Argument::Expression(argument.unwrap_or_else(|| self.undefined())));
+            arguments.push(Argument::Expression(self.string(&result)));
+            arguments.push(Argument::Expression(self.loc_expr(after)));
+            let delegate = self.call(self.context_property("delegateYield"), arguments);
+            self.emit(self.ast.return_statement(SPAN, Some(delegate)));
+            self.mark(after);
+            return (!ignore_result).then(|| self.context_property(&result));
+        }
+        self.emit_expression(self.assign(self.context_property("next"), self.loc_expr(after)));
+        self.emit(self.ast.return_statement(SPAN, argument));
+        self.mark(after);
+        (!ignore_result).then(|| self.context_property("sent"))
+    }
+}
+
+/// The value of `case "end":`
+const LOC_END: &str = "end";
+
+fn is_completion(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::ReturnStatement(_)
+            | Statement::ThrowStatement(_)
+            | Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_)
+    )
+}
+
+fn is_literal(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumberLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::StringLiteral(_)
+    )
+}
+
+fn simple_target_expression<'a>(
+    ast: &AstBuilder<'a>,
+    target: &SimpleAssignmentTarget<'a>,
+) -> Option<Expression<'a>> {
+    match target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => Some(
+            ast.identifier_reference_expression(IdentifierReference::new(SPAN, ident.name.clone())),
+        ),
+        SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+            Some(ast.member_expression(member.clone_in(ast.allocator).unbox()))
+        }
+        _ => None,
+    }
+}
+
+/// Whether there is a `yield`, or a `return`, `break` or `continue` with `yield_only: false`,
+/// outside of nested functions.
+struct LeapFinder {
+    yield_only: bool,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if !self.yield_only {
+            self.found = true;
+        } else if let Some(argument) = &stmt.argument {
+            self.visit_expression(argument);
+        }
+    }
+
+    fn visit_break_statement(&mut self, _stmt: &BreakStatement) {
+        self.found |= !self.yield_only;
+    }
+
+    fn visit_continue_statement(&mut self, _stmt: &ContinueStatement) {
+        self.found |= !self.yield_only;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowExpression<'a>) {}
+}
+
+fn contains_leap(stmt: &Statement) -> bool {
+    let mut finder = LeapFinder { yield_only: false, found: false };
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+fn contains_yield(expr: &Expression) -> bool {
+    let mut finder = LeapFinder { yield_only: true, found: false };
+    finder.visit_expression(expr);
+    finder.found
+}
+
+fn contains_yield_in_target(pattern: &AssignmentTargetPattern) -> bool {
+    let mut finder = LeapFinder { yield_only: true, found: false };
+    finder.visit_assignment_target_pattern(pattern);
+    finder.found
+}
+
+fn collect_names(pattern: &BindingPattern, names: &mut std::vec::Vec<Atom>) {
+    pattern.bound_names(&mut |ident| {
+        if !names.contains(&ident.name) {
+            names.push(ident.name.clone());
+        }
+    });
+}
+
+/// `{ a, b: [c = 1] }` as an assignment target, the bound names are collected into `names`.
+fn binding_to_target<'a>(
+    ast: &AstBuilder<'a>,
+    pattern: BindingPattern<'a>,
+    names: &mut std::vec::Vec<Atom>,
+) -> AssignmentTarget<'a> {
+    collect_names(&pattern, names);
+    pattern_to_target(ast, pattern)
+}
+
+fn pattern_to_target<'a>(
+    ast: &AstBuilder<'a>,
+    pattern: BindingPattern<'a>,
+) -> AssignmentTarget<'a> {
+    match pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            let ident = IdentifierReference::new(ident.span, ident.name.clone());
+            AssignmentTarget::SimpleAssignmentTarget(ast.simple_assignment_target_identifier(ident))
+        }
+        BindingPatternKind::ObjectPattern(pattern) => {
+            let ObjectPattern { span, properties, rest } = pattern.unbox();
+            let properties = Vec::from_iter_in(
+                properties.into_iter().map(|property| {
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(ast.alloc(
+                        AssignmentTargetPropertyProperty {
+                            span: property.span,
+                            name: property.key,
+                            binding: pattern_to_maybe_default(ast, property.value),
+                        },
+                    ))
+                }),
+                ast.allocator,
+            );
+            let rest = rest.map(|rest| pattern_to_target(ast, rest.unbox().argument));
+            AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ObjectAssignmentTarget(
+                    ast.alloc(ObjectAssignmentTarget { span, properties, rest }),
+                ),
+            )
+        }
+        BindingPatternKind::ArrayPattern(pattern) => {
+            let ArrayPattern { span, elements, rest } = pattern.unbox();
+            let elements = Vec::from_iter_in(
+                elements
+                    .into_iter()
+                    .map(|element| element.map(|element| pattern_to_maybe_default(ast, element))),
+                ast.allocator,
+            );
+            let rest = rest.map(|rest| pattern_to_target(ast, rest.unbox().argument));
+            AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ArrayAssignmentTarget(ast.alloc(ArrayAssignmentTarget {
+                    span,
+                    elements,
+                    rest,
+                    trailing_comma: None,
+                })),
+            )
+        }
+        BindingPatternKind::AssignmentPattern(pattern) => {
+            pattern_to_target(ast, pattern.unbox().left)
+        }
+    }
+}
+
+fn pattern_to_maybe_default<'a>(
+    ast: &AstBuilder<'a>,
+    pattern: BindingPattern<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    match pattern.kind {
+        BindingPatternKind::AssignmentPattern(pattern) => {
+            let AssignmentPattern { span, left, right } = pattern.unbox();
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(ast.alloc(
+                AssignmentTargetWithDefault {
+                    span,
+                    binding: pattern_to_target(ast, left),
+                    init: right,
+                },
+            ))
+        }
+        kind => AssignmentTargetMaybeDefault::AssignmentTarget(pattern_to_target(
+            ast,
+            BindingPattern { kind, ..pattern },
+        )),
+    }
+}
+
+/// Hoists the `var` declarations of the generator to the outer function,
+/// and finds the uses of `this` and `arguments` which are bound by `gen$`.
+struct Hoister<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    ctx: &'b TransformerCtx<'a>,
+    vars: std::vec::Vec<Atom>,
+    uses_this: bool,
+    /// `_args` replacing `arguments`
+    arguments: Option<Atom>,
+    /// The `var`s of arrow functions are not hoisted.
+    arrow_depth: usize,
+}
+
+impl<'a, 'b> Hoister<'a, 'b> {
+    fn new(ast: &'b AstBuilder<'a>, ctx: &'b TransformerCtx<'a>) -> Self {
+        Self { ast, ctx, vars: vec![], uses_this: false, arguments: None, arrow_depth: 0 }
+    }
+
+    /// `var a = 1, b;` -> `a = 1`
+    fn declarations_to_expression(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ast.new_vec();
+        for declarator in decl.declarations.iter_mut() {
+            let Some(init) = declarator.init.take() else {
+                collect_names(&declarator.id, &mut self.vars);
+                continue;
+            };
+            let id = std::mem::replace(
+                &mut declarator.id,
+                self.ast.binding_pattern(
+                    self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, "".into())),
+                    None,
+                    false,
+                ),
+            );
+            let target = binding_to_target(self.ast, id, &mut self.vars);
+            expressions.push(self.ast.assignment_expression(
+                SPAN,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ast.sequence_expression(SPAN, expressions)),
+        }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for Hoister<'a, 'b> {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.visit_statement_match(stmt);
+        if self.arrow_depth > 0 {
+            return;
+        }
+        if let Statement::Declaration(Declaration::VariableDeclaration(decl)) = stmt {
+            if decl.kind.is_var() {
+                *stmt = match self.declarations_to_expression(decl) {
+                    Some(expr) => self.ast.expression_statement(SPAN, expr),
+                    None => self.ast.empty_statement(SPAN),
+                };
+            }
+        }
+    }
+
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+        stmts.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+    }
+
+    fn visit_for_statement_init(&mut self, init: &mut ForStatementInit<'a>) {
+        match init {
+            ForStatementInit::VariableDeclaration(decl) => self.visit_variable_declaration(decl),
+            ForStatementInit::Expression(expr) => self.visit_expression(expr),
+            ForStatementInit::UsingDeclaration(decl) => self.visit_using_declaration(decl),
+        }
+        if self.arrow_depth > 0 {
+            return;
+        }
+        if let ForStatementInit::VariableDeclaration(decl) = init {
+            if decl.kind.is_var() {
+                let expr = self.declarations_to_expression(decl);
+                // `for (var i; ;)` -> `for (void 0; ;)`
+                *init = ForStatementInit::Expression(expr.unwrap_or_else(|| {
+                    let zero = self.ast.number_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+                    let zero = self.ast.literal_number_expression(zero);
+                    self.ast.unary_expression(SPAN, UnaryOperator::Void, zero)
+                }));
+            }
+        }
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if self.arrow_depth == 0 {
+            if let ForStatementLeft::VariableDeclaration(decl) = left {
+                if decl.kind.is_var() && !decl.declarations.is_empty() {
+                    let declarator = decl.declarations.remove(0);
+                    let target = binding_to_target(self.ast, declarator.id, &mut self.vars);
+                    *left = ForStatementLeft::AssignmentTarget(target);
+                }
+            }
+        }
+        match left {
+            ForStatementLeft::VariableDeclaration(decl) => self.visit_variable_declaration(decl),
+            ForStatementLeft::AssignmentTarget(target) => self.visit_assignment_target(target),
+            ForStatementLeft::UsingDeclaration(decl) => self.visit_using_declaration(decl),
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(_) => self.uses_this = true,
+            // `arguments` -> `_args`
+            Expression::Identifier(ident) if ident.name == "arguments" => {
+                let name = self.arguments.get_or_insert_with(|| {
+                    let name = self.ctx.scopes().generate_uid_based_on_name("args");
+                    self.ctx.add_binding(name.clone());
+                    name
+                });
+                ident.name = name.clone();
+            }
+            _ => self.visit_expression_match(expr),
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        self.arrow_depth += 1;
+        self.visit_formal_parameters(&mut expr.params);
+        self.visit_function_body(&mut expr.body);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+}
+
+/// Replaces the references to the catch parameter with `_context.t0`.
+struct CatchParamReplacer<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    ctx: &'b TransformerCtx<'a>,
+    name: Atom,
+    symbol_id: Option<SymbolId>,
+    replacement: Expression<'a>,
+}
+
+impl<'a, 'b> CatchParamReplacer<'a, 'b> {
+    fn is_param(&self, ident: &IdentifierReference) -> bool {
+        if ident.name != self.name {
+            return false;
+        }
+        match (self.symbol_id, ident.reference_id.get()) {
+            (Some(symbol_id), Some(reference_id)) => {
+                self.ctx.symbols().get_reference(reference_id).symbol_id() == Some(symbol_id)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for CatchParamReplacer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) if self.is_param(ident) => {
+                *expr = self.replacement.clone_in(self.ast.allocator);
+            }
+            _ => self.visit_expression_match(expr),
+        }
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) if self.is_param(ident) => {
+                if let Expression::MemberExpression(member) =
+                    self.replacement.clone_in(self.ast.allocator)
+                {
+                    *target = self.ast.simple_assignment_target_member_expression(member.unbox());
+                }
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+                self.visit_member_expression(member);
+            }
+            _ => {}
+        }
+    }
+
+    /// `{ e }` -> `{ e: _context.t0 }`
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        self.visit_property_key(&mut prop.key);
+        self.visit_expression(&mut prop.value);
+        if prop.shorthand && !matches!(prop.value, Expression::Identifier(_)) {
+            prop.shorthand = false;
+        }
+    }
+}
+
+/// Replaces the location placeholders with their indices in the listing.
+struct LocResolver<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    locs: &'b [Option<usize>],
+}
+
+impl<'a, 'b> VisitMut<'a> for LocResolver<'a, 'b> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    fn visit_number_literal(&mut self, lit: &mut NumberLiteral<'a>) {
+        if lit.raw == LOC {
+            let index = self.locs[lit.value as usize].unwrap_or_default();
+            lit.value = index as f64;
+            lit.raw = self.ast.new_str(&index.to_string());
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2015,
+        regenerator: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let import = "import _regeneratorRuntime from '@babel/runtime/helpers/regeneratorRuntime';\n";
+    let tests = [
+        (
+            "function* gen() { var x = yield 1; }",
+            "var _marked = _regeneratorRuntime().mark(gen);
+            function gen() {
+              var x;
+              return _regeneratorRuntime().wrap(function gen$(_context) {
+                while (1) switch (_context.prev = _context.next) {
+                  case 0:
+                    _context.next = 2;
+                    return 1;
+                  case 2:
+                    x = _context.sent;
+                  case 3:
+                  case 'end':
+                    return _context.stop();
+                }
+              }, _marked);
+            }",
+        ),
+        (
+            "var gen = function* () { yield* this.xs; return arguments[0]; };",
+            "var gen = _regeneratorRuntime().mark(function _callee() {
+              var _args = arguments;
+              return _regeneratorRuntime().wrap(function _callee$(_context) {
+                while (1) switch (_context.prev = _context.next) {
+                  case 0:
+                    return _context.delegateYield(this.xs, 't0', 1);
+                  case 1:
+                    return _context.abrupt('return', _args[0]);
+                  case 2:
+                  case 'end':
+                    return _context.stop();
+                }
+              }, _callee, this);
+            });",
+        ),
+        (
+            "function* gen() { while (a) { if (yield b) break; } }",
+            "var _marked = _regeneratorRuntime().mark(gen);
+            function gen() {
+              return _regeneratorRuntime().wrap(function gen$(_context) {
+                while (1) switch (_context.prev = _context.next) {
+                  case 0:
+                    if (!a) {
+                      _context.next = 7;
+                      break;
+                    }
+                    _context.next = 3;
+                    return b;
+                  case 3:
+                    if (!_context.sent) {
+                      _context.next = 5;
+                      break;
+                    }
+                    return _context.abrupt('break', 7);
+                  case 5:
+                    _context.next = 0;
+                    break;
+                  case 7:
+                  case 'end':
+                    return _context.stop();
+                }
+              }, _marked);
+            }",
+        ),
+        (
+            "function* gen() { try { yield a; } catch (e) { log(e); } finally { done(); } }",
+            "var _marked = _regeneratorRuntime().mark(gen);
+            function gen() {
+              return _regeneratorRuntime().wrap(function gen$(_context) {
+                while (1) switch (_context.prev = _context.next) {
+                  case 0:
+                    _context.prev = 0;
+                    _context.next = 3;
+                    return a;
+                  case 3:
+                    _context.next = 8;
+                    break;
+                  case 5:
+                    _context.prev = 5;
+                    _context.t0 = _context['catch'](0);
+                    log(_context.t0);
+                  case 8:
+                    _context.prev = 8;
+                    done();
+                    return _context.finish(8);
+                  case 11:
+                  case 'end':
+                    return _context.stop();
+                }
+              }, _marked, null, [[0, 5, 8, 11]]);
+            }",
+        ),
+        (
+            "function* gen() { for (var key in obj) { yield key; } }",
+            "var _marked = _regeneratorRuntime().mark(gen);
+            function gen() {
+              var key;
+              return _regeneratorRuntime().wrap(function gen$(_context) {
+                while (1) switch (_context.prev = _context.next) {
+                  case 0:
+                    _context.t0 = _regeneratorRuntime().keys(obj);
+                  case 1:
+                    if ((_context.t1 = _context.t0()).done) {
+                      _context.next = 7;
+                      break;
+                    }
+                    key = _context.t1.value;
+                    _context.next = 5;
+                    return key;
+                  case 5:
+                    _context.next = 1;
+                    break;
+                  case 7:
+                  case 'end':
+                    return _context.stop();
+                }
+              }, _marked);
+            }",
+        ),
+        (
+            "function* gen() { foo(a, yield b); }",
+            "var _marked = _regeneratorRuntime().mark(gen);
+            function gen() {
+              return _regeneratorRuntime().wrap(function gen$(_context) {
+                while (1) switch (_context.prev = _context.next) {
+                  case 0:
+                    _context.t0 = foo;
+                    _context.t1 = a;
+                    _context.next = 4;
+                    return b;
+                  case 4:
+                    _context.t2 = _context.sent;
+                    (0, _context.t0)(_context.t1, _context.t2);
+                  case 6:
+                  case 'end':
+                    return _context.stop();
+                }
+              }, _marked);
+            }",
+        ),
+        (
+            "class A { *foo() { yield 1; } }",
+            "class A {
+              foo() {
+                return _regeneratorRuntime().wrap(function foo$(_context) {
+                  while (1) switch (_context.prev = _context.next) {
+                    case 0:
+                      _context.next = 2;
+                      return 1;
+                    case 2:
+                    case 'end':
+                      return _context.stop();
+                  }
+                }, null);
+              }
+            }",
+        ),
+    ];
+    let tests = tests.map(|(source, expected)| (source, format!("{import}{expected}")));
+    let tests = tests.iter().map(|(a, b)| (*a, b.as_str())).collect::<std::vec::Vec<_>>();
+    Tester::new("test.mjs", options).test(&tests);
+}
@@ -0,0 +1,377 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::NumberBase;
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+    utils::CreateVars,
+};
+
+/// ES2015: Spread
+///
+/// ```javascript
+/// [a, ...b];       // [a].concat(_toConsumableArray(b))
+/// foo(...a);       // foo.apply(void 0, _toConsumableArray(a))
+/// obj.foo(a, ...b) // obj.foo.apply(obj, [a].concat(_toConsumableArray(b)))
+/// new Foo(...a);   // new (Function.prototype.bind.apply(Foo, [null].concat(_toConsumableArray(a))))()
+/// ```
+///
+/// With the `iterableIsArray` assumption, spread elements are used as is, e.g. `[a].concat(b)`.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-spread>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-spread>
+pub struct Spread<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+    iterable_is_array: bool,
+}
+
+impl<'a> CreateVars<'a> for Spread<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
+}
+
+impl<'a> Spread<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2015 || options.spread).then(|| {
+            let vars = ast.new_vec();
+            let iterable_is_array = options.assumptions.iterable_is_array;
+            Self { ast, ctx, vars, vars_stack: vec![], iterable_is_array }
+        })
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ArrayExpression(array) if has_spread_element(array) => {
+                let elements = std::mem::replace(&mut array.elements, self.ast.new_vec());
+                *expr = self.build_array(elements);
+            }
+            Expression::CallExpression(call)
+                if !call.optional
+                    && !matches!(call.callee, Expression::Super(_))
+                    && call
+                        .arguments
+                        .iter()
+                        .any(|arg| matches!(arg, Argument::SpreadElement(_))) =>
+            {
+                let arguments = std::mem::replace(&mut call.arguments, self.ast.new_vec());
+                let args = self.build_arguments(arguments);
+                let callee = self.ast.move_expression(&mut call.callee);
+                let (callee, this) = self.split_callee(callee);
+                let callee = self.member(callee, "apply");
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(this));
+                arguments.push(Argument::Expression(args));
+                *expr = self.ast.call_expression(SPAN, callee, arguments, false, None);
+            }
+            Expression::NewExpression(new_expr)
+                if new_expr
+                    .arguments
+                    .iter()
+                    .any(|arg| matches!(arg, Argument::SpreadElement(_))) =>
+            {
+                // `new (Function.prototype.bind.apply(Foo, [null].concat(a)))()`
+                let arguments = std::mem::replace(&mut new_expr.arguments, self.ast.new_vec());
+                let mut elements = self.ast.new_vec_with_capacity(arguments.len() + 1);
+                elements.push(ArrayExpressionElement::Expression(
+                    self.ast.literal_null_expression(NullLiteral { span: SPAN }),
+                ));
+                elements.extend(arguments.into_iter().map(|arg| match arg {
+                    Argument::SpreadElement(spread) => {
+                        ArrayExpressionElement::SpreadElement(spread)
+                    }
+                    Argument::Expression(expr) => ArrayExpressionElement::Expression(expr),
+                }));
+                let args = self.build_concat(self.build_parts(elements));
+                let callee = self.ast.move_expression(&mut new_expr.callee);
+                let mut bind = self.ast.identifier_reference_expression(IdentifierReference::new(
+                    SPAN,
+                    "Function".into(),
+                ));
+                for name in ["prototype", "bind", "apply"] {
+                    bind = self.member(bind, name);
+                }
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(callee));
+                arguments.push(Argument::Expression(args));
+                let bound = self.ast.call_expression(SPAN, bind, arguments, false, None);
+                let bound = self.ast.parenthesized_expression(SPAN, bound);
+                *expr = self.ast.new_expression(SPAN, bound, self.ast.new_vec(), None);
+            }
+            _ => {}
+        }
+    }
+
+    /// Split a callee into the function and its `this` value,
+    /// `obj.foo` -> (`(_obj = obj).foo`, `_obj`) or `foo` -> (`foo`, `void 0`).
+    fn split_callee(&mut self, callee: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        let Expression::MemberExpression(mut member) = callee else {
+            return (callee, self.ast.void_0());
+        };
+        let object = member.object_mut();
+        if matches!(object, Expression::Super(_)) {
+            let this = self.ast.this_expression(SPAN);
+            return (self.ast.member_expression(member.unbox()), this);
+        }
+        let this = if let Some(ident) = self.maybe_generate_memoised(object) {
+            let target = self.ast.simple_assignment_target_identifier(ident.clone());
+            let value = self.ast.move_expression(object);
+            let assignment = self.ast.assignment_expression(
+                SPAN,
+                oxc_syntax::operator::AssignmentOperator::Assign,
+                AssignmentTarget::SimpleAssignmentTarget(target),
+                value,
+            );
+            *object = self.ast.parenthesized_expression(SPAN, assignment);
+            self.ast.identifier_reference_expression(ident)
+        } else {
+            clone_static_expression(&self.ast, object)
+        };
+        (self.ast.member_expression(member.unbox()), this)
+    }
+
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, object, property, false)
+    }
+
+    fn build_arguments(&self, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        // `foo(...arguments)` -> `foo.apply(void 0, arguments)`
+        if let [Argument::SpreadElement(spread)] = arguments.as_slice() {
+            if is_arguments(&spread.argument) {
+                let Some(Argument::SpreadElement(spread)) = arguments.into_iter().next() else {
+                    unreachable!()
+                };
+                return spread.unbox().argument;
+            }
+        }
+        let elements = Vec::from_iter_in(
+            arguments.into_iter().map(|arg| match arg {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                Argument::Expression(expr) => ArrayExpressionElement::Expression(expr),
+            }),
+            self.ast.allocator,
+        );
+        self.build_concat(self.build_parts(elements))
+    }
+
+    /// `[a, ...b, c]` -> `[a].concat(_toConsumableArray(b), [c])`
+    fn build_array(&self, elements: Vec<'a, ArrayExpressionElement<'a>>) -> Expression<'a> {
+        // `[...b]` -> `_toConsumableArray(b)`, which is a copy already
+        let is_copied = match elements.as_slice() {
+            [ArrayExpressionElement::SpreadElement(spread)] => self.is_converted(&spread.argument),
+            _ => false,
+        };
+        let mut parts = self.build_parts(elements);
+        if is_copied {
+            return parts.remove(0);
+        }
+        if !matches!(parts.first(), Some(Expression::ArrayExpression(_))) {
+            parts.insert(0, self.ast.array_expression(SPAN, self.ast.new_vec(), None));
+        }
+        self.build_concat(parts)
+    }
+
+    /// `[a, ...b, c]` -> `[[a], _toConsumableArray(b), [c]]`
+    fn build_parts(
+        &self,
+        elements: Vec<'a, ArrayExpressionElement<'a>>,
+    ) -> std::vec::Vec<Expression<'a>> {
+        let mut parts = vec![];
+        let mut current = self.ast.new_vec();
+        for element in elements {
+            match element {
+                ArrayExpressionElement::SpreadElement(spread) => {
+                    if !current.is_empty() {
+                        let elements = std::mem::replace(&mut current, self.ast.new_vec());
+                        parts.push(self.ast.array_expression(SPAN, elements, None));
+                    }
+                    parts.push(self.spread_literal(spread.unbox().argument));
+                }
+                element => current.push(element),
+            }
+        }
+        if !current.is_empty() {
+            parts.push(self.ast.array_expression(SPAN, current, None));
+        }
+        parts
+    }
+
+    /// `first.concat(...rest)`, or `first` when it is the only part.
+    fn build_concat(&self, parts: std::vec::Vec<Expression<'a>>) -> Expression<'a> {
+        let mut parts = parts.into_iter();
+        let Some(first) = parts.next() else {
+            return self.ast.array_expression(SPAN, self.ast.new_vec(), None);
+        };
+        let arguments = Vec::from_iter_in(parts.map(Argument::Expression), self.ast.allocator);
+        if arguments.is_empty() {
+            return first;
+        }
+        let callee = self.member(first, "concat");
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    fn spread_literal(&self, argument: Expression<'a>) -> Expression<'a> {
+        if self.is_converted(&argument) {
+            to_array(&self.ctx, argument, ToArray::Spread)
+        } else {
+            argument
+        }
+    }
+
+    /// Whether a spread element is converted to an array.
+    fn is_converted(&self, argument: &Expression<'a>) -> bool {
+        !matches!(argument, Expression::ArrayExpression(_))
+            && (!self.iterable_is_array || is_arguments(argument))
+    }
+}
+
+fn has_spread_element(array: &ArrayExpression) -> bool {
+    array.elements.iter().any(|element| matches!(element, ArrayExpressionElement::SpreadElement(_)))
+}
+
+fn is_arguments(expr: &Expression) -> bool {
+    matches!(expr, Expression::Identifier(ident) if ident.name == "arguments")
+}
+
+/// The use of an iterable converted by [to_array].
+#[derive(Clone, Copy)]
+pub enum ToArray {
+    /// `_toConsumableArray(a)` for spread elements
+    Spread,
+    /// `_slicedToArray(a, length)` for array patterns without a rest element
+    Length(usize),
+    /// `_toArray(a)` for array patterns with a rest element
+    Rest,
+}
+
+/// Convert an iterable to an array, as `scope.toArray` in Babel.
+/// Array literals are returned as is, and `arguments` is copied with
+/// `Array.prototype.slice.call(arguments)`.
+pub fn to_array<'a>(ctx: &TransformerCtx<'a>, expr: Expression<'a>, to: ToArray) -> Expression<'a> {
+    let ast = &ctx.ast;
+    if matches!(expr, Expression::ArrayExpression(_)) {
+        return expr;
+    }
+    if is_arguments(&expr) {
+        let mut callee =
+            ast.identifier_reference_expression(IdentifierReference::new(SPAN, "Array".into()));
+        for name in ["prototype", "slice", "call"] {
+            let property = IdentifierName::new(SPAN, Atom::from(name));
+            callee = ast.static_member_expression(SPAN, callee, property, false);
+        }
+        let arguments = ast.new_vec_single(Argument::Expression(expr));
+        return ast.call_expression(SPAN, callee, arguments, false, None);
+    }
+    let mut arguments = ast.new_vec_single(Argument::Expression(expr));
+    let helper = match to {
+        ToArray::Spread => Helper::ToConsumableArray,
+        ToArray::Rest => Helper::ToArray,
+        ToArray::Length(length) => {
+            let raw = ast.new_str(&length.to_string());
+            #[allow(clippy::cast_precision_loss)]
+            let length = ast.number_literal(SPAN, length as f64, raw, NumberBase::Decimal);
+            arguments.push(Argument::Expression(ast.literal_number_expression(length)));
+            Helper::SlicedToArray
+        }
+    };
+    ctx.helpers.call(helper, arguments)
+}
+
+/// Clone an expression which has been checked with `SymbolTable::is_static`.
+pub fn clone_static_expression<'a>(ast: &AstBuilder<'a>, expr: &Expression<'a>) -> Expression<'a> {
+    match expr {
+        Expression::Identifier(ident) => ast.identifier_reference_expression((**ident).clone()),
+        Expression::ThisExpression(_) => ast.this_expression(SPAN),
+        _ => unreachable!("only `this` and identifiers are static"),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let mut options = TransformOptions {
+        target: TransformTarget::ES2015,
+        spread: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::External,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        ("[a, ...b, c];", "[a].concat(babelHelpers.toConsumableArray(b), [c]);"),
+        ("[...a];", "babelHelpers.toConsumableArray(a);"),
+        ("[...a, b];", "[].concat(babelHelpers.toConsumableArray(a), [b]);"),
+        ("[...[1, 2]];", "[1, 2];"),
+        ("[...arguments];", "Array.prototype.slice.call(arguments);"),
+        ("foo(...a);", "foo.apply(void 0, babelHelpers.toConsumableArray(a));"),
+        ("foo(...arguments);", "foo.apply(void 0, arguments);"),
+        ("foo(a, ...b);", "foo.apply(void 0, [a].concat(babelHelpers.toConsumableArray(b)));"),
+        ("foo(...a, b);", "foo.apply(void 0, babelHelpers.toConsumableArray(a).concat([b]));"),
+        (
+            "obj.foo(...a);",
+            "var _obj;\n(_obj = obj).foo.apply(_obj, babelHelpers.toConsumableArray(a));",
+        ),
+    ];
+
+    Tester::new("test.js", options.clone()).test(tests);
+
+    // The callee of `new` is parenthesized by the codegen, the expected code must not be reprinted.
+    let tests = &[(
+        "new Foo(...a);",
+        "new (Function.prototype.bind.apply(Foo, [null].concat(babelHelpers.toConsumableArray(a))))();\n",
+    )];
+
+    Tester::new("test.js", options.clone()).test_exact(tests);
+
+    options.assumptions.iterable_is_array = true;
+    let tests = &[
+        ("[a, ...b, c];", "[a].concat(b, [c]);"),
+        ("[...a];", "[].concat(a);"),
+        ("[...arguments];", "Array.prototype.slice.call(arguments);"),
+        ("foo(...a);", "foo.apply(void 0, a);"),
+        ("foo(a, ...b);", "foo.apply(void 0, [a].concat(b));"),
+        ("obj.foo(...a);", "var _obj;\n(_obj = obj).foo.apply(_obj, a);"),
+        ("function f(obj) { obj.foo(...a); }", "function f(obj) { obj.foo.apply(obj, a); }"),
+        ("a.b.foo(...c);", "var _a$b;\n(_a$b = a.b).foo.apply(_a$b, c);"),
+    ];
+
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let tests = &[
+        ("new Foo(...a);", "new (Function.prototype.bind.apply(Foo, [null].concat(a)))();\n"),
+        ("new F(...args);", "new (Function.prototype.bind.apply(F, [null].concat(args)))();\n"),
+        ("new a.B(x, ...y);", "new (Function.prototype.bind.apply(a.B, [null, x].concat(y)))();\n"),
+    ];
+
+    Tester::new("test.js", options).test_exact(tests);
+}
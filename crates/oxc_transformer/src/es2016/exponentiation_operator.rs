@@ -21,6 +21,8 @@ pub struct ExponentiationOperator<'a> {
     ast: Rc<AstBuilder<'a>>,
     ctx: TransformerCtx<'a>,
     vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
 }
 
 struct Exploded<'a> {
@@ -36,6 +38,10 @@ impl<'a> CreateVars<'a> for ExponentiationOperator<'a> {
     fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
         &mut self.vars
     }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
 }
 
 impl<'a> ExponentiationOperator<'a> {
@@ -46,7 +52,7 @@ impl<'a> ExponentiationOperator<'a> {
     ) -> Option<Self> {
         (options.target < TransformTarget::ES2016 || options.exponentiation_operator).then(|| {
             let vars = ast.new_vec();
-            Self { ast, ctx, vars }
+            Self { ast, ctx, vars, vars_stack: vec![] }
         })
     }
 
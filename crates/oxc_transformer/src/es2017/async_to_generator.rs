@@ -0,0 +1,567 @@
+use std::rc::Rc;
+
+use oxc_allocator::Box;
+use oxc_ast::{ast::*, syntax_directed_operations::IsSimpleParameterList, AstBuilder, VisitMut};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+};
+
+/// ES2017: Async To Generator
+///
+/// ```javascript
+/// async function foo(a) { await a; }
+/// // is transformed to
+/// function foo(a) { return _asyncToGenerator(function* () { yield a; }).apply(this, arguments); }
+///
+/// async (a) => await a;
+/// // is transformed to
+/// (a) => _asyncToGenerator(function* () { return yield a; }).call(this);
+/// ```
+///
+/// `super` can not be used in the generator, property accesses are moved into arrow functions,
+/// `super.foo()` -> `_superprop_getFoo().call(this)` with `var _superprop_getFoo = () => super.foo;`.
+/// The generator of an arrow function has its own `arguments`, they are captured with `var _arguments = arguments;`.
+///
+/// Parameters which are not simple are evaluated in the generator so their errors reject the promise,
+/// `async function foo(a = b) {}` -> `function foo() { return _asyncToGenerator(function* (a = b) {}).apply(this, arguments); }`.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-async-to-generator>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-async-to-generator>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-helper-remap-async-to-generator>
+pub struct AsyncToGenerator<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    /// Whether each enclosing function is an async (non generator) function.
+    functions: Vec<bool>,
+}
+
+impl<'a> AsyncToGenerator<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2017 || options.async_to_generator).then(|| Self {
+            ast,
+            ctx,
+            functions: vec![],
+        })
+    }
+
+    pub fn enter_function(&mut self, func: &Function<'a>) {
+        self.functions.push(func.r#async && !func.generator);
+    }
+
+    pub fn exit_function(&mut self, func: &mut Function<'a>) {
+        if !self.functions.pop().unwrap_or_default() {
+            return;
+        }
+        let Some(body) = &mut func.body else { return };
+        func.r#async = false;
+        let params = self.move_params(&mut func.params, None);
+        let generator = self.move_to_generator(body, params, false);
+        // `.apply(this, arguments)`
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.ast.this_expression(SPAN)));
+        arguments.push(Argument::Expression(self.reference(&Atom::from("arguments"))));
+        let call = self.call_member(generator, "apply", arguments);
+        body.statements.push(self.ast.return_statement(SPAN, Some(call)));
+    }
+
+    pub fn enter_arrow_expression(&mut self, arrow: &ArrowExpression<'a>) {
+        self.functions.push(arrow.r#async);
+    }
+
+    pub fn exit_arrow_expression(&mut self, arrow: &mut ArrowExpression<'a>) {
+        if !self.functions.pop().unwrap_or_default() {
+            return;
+        }
+        arrow.r#async = false;
+        if arrow.expression {
+            // `async () => x` -> `async () => { return x; }`
+            arrow.expression = false;
+            if let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() {
+                let argument = stmt.unbox().expression;
+                arrow.body.statements.push(self.ast.return_statement(SPAN, Some(argument)));
+            }
+        }
+        let rest = (!arrow.params.is_simple_parameter_list()).then(|| self.generate_uid("args"));
+        let params = self.move_params(&mut arrow.params, rest.clone());
+        let generator = self.move_to_generator(&mut arrow.body, params, true);
+        // `.call(this)`, the generator shares the lexical `this` of the arrow function,
+        // or `.apply(this, _args)` when the parameters are moved to the generator.
+        let call = if let Some(rest) = rest {
+            let mut arguments = self.ast.new_vec_with_capacity(2);
+            arguments.push(Argument::Expression(self.ast.this_expression(SPAN)));
+            arguments.push(Argument::Expression(self.reference(&rest)));
+            self.call_member(generator, "apply", arguments)
+        } else {
+            let arguments =
+                self.ast.new_vec_single(Argument::Expression(self.ast.this_expression(SPAN)));
+            self.call_member(generator, "call", arguments)
+        };
+        if arrow.body.statements.is_empty() {
+            arrow.expression = true;
+            arrow.body.statements.push(self.ast.expression_statement(SPAN, call));
+        } else {
+            arrow.body.statements.push(self.ast.return_statement(SPAN, Some(call)));
+        }
+    }
+
+    /// `await x` -> `yield x`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AwaitExpression(await_expr) = expr else { return };
+        if !self.functions.last().copied().unwrap_or_default() {
+            return;
+        }
+        let argument = self.ast.move_expression(&mut await_expr.argument);
+        *expr = self.ast.yield_expression(await_expr.span, false, Some(argument));
+    }
+
+    /// Move the statements of `body` into `_asyncToGenerator(function* () { body })`,
+    /// the `super` accessors and captured `arguments` are declared in `body`.
+    fn move_to_generator(
+        &self,
+        body: &mut FunctionBody<'a>,
+        params: Option<Box<'a, FormalParameters<'a>>>,
+        is_arrow: bool,
+    ) -> Expression<'a> {
+        let mut statements = self.ast.move_statement_vec(&mut body.statements);
+        let mut replacer = GeneratorBodyReplacer::new(&self.ast, &self.ctx, is_arrow);
+        for stmt in statements.iter_mut() {
+            replacer.visit_statement(stmt);
+        }
+        if let Some(decl) = replacer.into_declaration() {
+            body.statements.push(decl);
+        }
+        wrap_generator(&self.ast, &self.ctx, Helper::AsyncToGenerator, params, statements)
+    }
+
+    /// Move parameters which are not simple to the generator.
+    ///
+    /// Functions keep placeholders `_x` for their `length`, arrow functions take `..._args`.
+    fn move_params(
+        &self,
+        params: &mut Box<'a, FormalParameters<'a>>,
+        rest: Option<Atom>,
+    ) -> Option<Box<'a, FormalParameters<'a>>> {
+        if params.is_simple_parameter_list() {
+            return None;
+        }
+        let kind = params.kind;
+        let (items, rest) = if let Some(name) = rest {
+            let pattern = self.binding_pattern(name);
+            let rest = self.ast.rest_element(SPAN, pattern);
+            (self.ast.new_vec(), Some(rest))
+        } else {
+            let length = params
+                .items
+                .iter()
+                .take_while(|param| {
+                    !matches!(param.pattern.kind, BindingPatternKind::AssignmentPattern(_))
+                })
+                .count();
+            let items = oxc_allocator::Vec::from_iter_in(
+                (0..length).map(|_| {
+                    let pattern = self.binding_pattern(self.generate_uid("x"));
+                    self.ast.formal_parameter(SPAN, pattern, None, false, self.ast.new_vec())
+                }),
+                self.ast.allocator,
+            );
+            (items, None)
+        };
+        let placeholders = self.ast.formal_parameters(SPAN, kind, items, rest);
+        let mut moved = std::mem::replace(params, placeholders);
+        moved.kind = FormalParameterKind::FormalParameter;
+        Some(moved)
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn binding_pattern(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name));
+        self.ast.binding_pattern(ident, None, false)
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn call_member(
+        &self,
+        object: Expression<'a>,
+        name: &str,
+        arguments: oxc_allocator::Vec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        let callee = self.ast.static_member_expression(SPAN, object, property, false);
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+}
+
+/// `helper(function* (params) { statements })`
+pub fn wrap_generator<'a>(
+    ast: &AstBuilder<'a>,
+    ctx: &TransformerCtx<'a>,
+    helper: Helper,
+    params: Option<Box<'a, FormalParameters<'a>>>,
+    statements: oxc_allocator::Vec<'a, Statement<'a>>,
+) -> Expression<'a> {
+    let params = params.unwrap_or_else(|| {
+        ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, ast.new_vec(), None)
+    });
+    let body = ast.function_body(SPAN, ast.new_vec(), statements);
+    let generator = ast.function(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        false,
+        true,
+        false,
+        params,
+        Some(body),
+        None,
+        None,
+        None,
+        Modifiers::empty(),
+    );
+    let arguments = ast.new_vec_single(Argument::Expression(ast.function_expression(generator)));
+    ctx.helpers.call(helper, arguments)
+}
+
+/// Replaces `super` property accesses and the `arguments` of arrow functions in the body of a generator.
+struct GeneratorBodyReplacer<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    ctx: &'b TransformerCtx<'a>,
+    /// `arguments` are only replaced for arrow functions, functions pass them to the generator.
+    is_arrow: bool,
+    arguments: Option<Atom>,
+    /// `_superprop_getFoo` by property name, computed properties use `None`.
+    getters: std::vec::Vec<(Option<Atom>, Atom)>,
+    setters: std::vec::Vec<(Option<Atom>, Atom)>,
+    declarations: oxc_allocator::Vec<'a, VariableDeclarator<'a>>,
+}
+
+impl<'a, 'b> GeneratorBodyReplacer<'a, 'b> {
+    fn new(ast: &'b AstBuilder<'a>, ctx: &'b TransformerCtx<'a>, is_arrow: bool) -> Self {
+        Self {
+            ast,
+            ctx,
+            is_arrow,
+            arguments: None,
+            getters: vec![],
+            setters: vec![],
+            declarations: ast.new_vec(),
+        }
+    }
+
+    /// `var _superprop_getFoo = () => super.foo, _arguments = arguments;`
+    fn into_declaration(self) -> Option<Statement<'a>> {
+        if self.declarations.is_empty() {
+            return None;
+        }
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            self.declarations,
+            Modifiers::empty(),
+        );
+        Some(Statement::Declaration(Declaration::VariableDeclaration(decl)))
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn declare(&mut self, name: Atom, init: Expression<'a>) {
+        let ident = self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name));
+        let pattern = self.ast.binding_pattern(ident, None, false);
+        let kind = VariableDeclarationKind::Var;
+        self.declarations.push(self.ast.variable_declarator(
+            SPAN,
+            kind,
+            pattern,
+            Some(init),
+            false,
+        ));
+    }
+
+    /// `(_prop) => super[_prop]`
+    fn arrow(&self, params: &[Atom], body: Expression<'a>) -> Expression<'a> {
+        let items = oxc_allocator::Vec::from_iter_in(
+            params.iter().map(|name| {
+                let ident =
+                    self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name.clone()));
+                let pattern = self.ast.binding_pattern(ident, None, false);
+                self.ast.formal_parameter(SPAN, pattern, None, false, self.ast.new_vec())
+            }),
+            self.ast.allocator,
+        );
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            None,
+        );
+        let body = self.ast.function_body(
+            SPAN,
+            self.ast.new_vec(),
+            self.ast.new_vec_single(self.ast.expression_statement(SPAN, body)),
+        );
+        self.ast.arrow_expression(SPAN, true, false, false, params, body, None, None, None)
+    }
+
+    /// `super.foo` or `super[_prop]`
+    fn super_member(&self, property: Option<&Atom>, prop: Option<&Atom>) -> Expression<'a> {
+        match (property, prop) {
+            (Some(property), _) => self.ast.static_member_expression(
+                SPAN,
+                self.ast.super_(SPAN),
+                IdentifierName::new(SPAN, property.clone()),
+                false,
+            ),
+            (None, Some(prop)) => self.ast.computed_member_expression(
+                SPAN,
+                self.ast.super_(SPAN),
+                self.reference(prop),
+                false,
+            ),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    /// `_superprop_getFoo` for `() => super.foo` or `_superprop_get` for `(_prop) => super[_prop]`
+    fn getter(&mut self, property: Option<Atom>) -> Atom {
+        if let Some((_, name)) = self.getters.iter().find(|(key, _)| *key == property) {
+            return name.clone();
+        }
+        let name = self.generate_uid(&accessor_name("superprop_get", property.as_ref()));
+        let arrow = if property.is_some() {
+            self.arrow(&[], self.super_member(property.as_ref(), None))
+        } else {
+            let prop = self.generate_uid("prop");
+            self.arrow(&[prop.clone()], self.super_member(None, Some(&prop)))
+        };
+        self.declare(name.clone(), arrow);
+        self.getters.push((property, name.clone()));
+        name
+    }
+
+    /// `_superprop_setFoo` for `(_value) => super.foo = _value`
+    /// or `_superprop_set` for `(_prop, _value) => super[_prop] = _value`
+    fn setter(&mut self, property: Option<Atom>) -> Atom {
+        if let Some((_, name)) = self.setters.iter().find(|(key, _)| *key == property) {
+            return name.clone();
+        }
+        let name = self.generate_uid(&accessor_name("superprop_set", property.as_ref()));
+        let prop = property.is_none().then(|| self.generate_uid("prop"));
+        let value = self.generate_uid("value");
+        let target = match self.super_member(property.as_ref(), prop.as_ref()) {
+            Expression::MemberExpression(member) => {
+                self.ast.simple_assignment_target_member_expression(member.unbox())
+            }
+            _ => unreachable!(),
+        };
+        let assignment = self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            self.reference(&value),
+        );
+        let params = prop.into_iter().chain([value]).collect::<std::vec::Vec<_>>();
+        let arrow = self.arrow(&params, assignment);
+        self.declare(name.clone(), arrow);
+        self.setters.push((property, name.clone()));
+        name
+    }
+
+    /// `super.foo` -> `_superprop_getFoo()`, `super[x]` -> `_superprop_get(x)`
+    fn replace_super_get(&mut self, member: &mut MemberExpression<'a>) -> Expression<'a> {
+        let (property, key) = self.super_key(member);
+        let callee = self.getter(property);
+        let arguments = oxc_allocator::Vec::from_iter_in(
+            key.into_iter().map(Argument::Expression),
+            self.ast.allocator,
+        );
+        self.ast.call_expression(SPAN, self.reference(&callee), arguments, false, None)
+    }
+
+    /// The property name of `super.foo`, or the visited key of `super[x]`.
+    fn super_key(
+        &mut self,
+        member: &mut MemberExpression<'a>,
+    ) -> (Option<Atom>, Option<Expression<'a>>) {
+        match member {
+            MemberExpression::StaticMemberExpression(member) => {
+                (Some(member.property.name.clone()), None)
+            }
+            MemberExpression::ComputedMemberExpression(member) => {
+                self.visit_expression(&mut member.expression);
+                (None, Some(self.ast.move_expression(&mut member.expression)))
+            }
+            MemberExpression::PrivateFieldExpression(_) => unreachable!("`super.#a` is an error"),
+        }
+    }
+}
+
+fn is_super_member(expr: &Expression) -> bool {
+    matches!(expr, Expression::MemberExpression(member) if matches!(member.object(), Expression::Super(_)))
+}
+
+/// `superprop_getFoo`
+fn accessor_name(prefix: &str, property: Option<&Atom>) -> String {
+    let mut name = prefix.to_string();
+    if let Some(property) = property {
+        let mut chars = property.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    name
+}
+
+impl<'a, 'b> VisitMut<'a> for GeneratorBodyReplacer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `super.foo(a)` -> `_superprop_getFoo().call(this, a)`
+            Expression::CallExpression(call) if is_super_member(&call.callee) => {
+                for argument in call.arguments.iter_mut() {
+                    self.visit_argument(argument);
+                }
+                let Expression::MemberExpression(member) = &mut call.callee else { unreachable!() };
+                let getter = self.replace_super_get(member);
+                let property = IdentifierName::new(SPAN, "call".into());
+                call.callee = self.ast.static_member_expression(SPAN, getter, property, false);
+                call.arguments.insert(0, Argument::Expression(self.ast.this_expression(SPAN)));
+            }
+            // `super.foo = a` -> `_superprop_setFoo(a)`
+            Expression::AssignmentExpression(assign)
+                if assign.operator == AssignmentOperator::Assign
+                    && matches!(&assign.left, AssignmentTarget::SimpleAssignmentTarget(SimpleAssignmentTarget::MemberAssignmentTarget(member)) if matches!(member.object(), Expression::Super(_))) =>
+            {
+                self.visit_expression(&mut assign.right);
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &mut assign.left
+                else {
+                    unreachable!()
+                };
+                let (property, key) = self.super_key(member);
+                let setter = self.setter(property);
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.extend(key.map(Argument::Expression));
+                arguments.push(Argument::Expression(self.ast.move_expression(&mut assign.right)));
+                *expr =
+                    self.ast.call_expression(SPAN, self.reference(&setter), arguments, false, None);
+            }
+            // `super.foo` -> `_superprop_getFoo()`
+            Expression::MemberExpression(member)
+                if matches!(member.object(), Expression::Super(_)) =>
+            {
+                *expr = self.replace_super_get(member);
+            }
+            // `arguments` -> `_arguments`
+            Expression::Identifier(ident) if self.is_arrow && ident.name == "arguments" => {
+                let name = if let Some(name) = &self.arguments {
+                    name.clone()
+                } else {
+                    let name = self.generate_uid("arguments");
+                    self.declare(name.clone(), self.reference(&Atom::from("arguments")));
+                    self.arguments = Some(name.clone());
+                    name
+                };
+                ident.name = name;
+            }
+            _ => self.visit_expression_match(expr),
+        }
+    }
+
+    /// `super` and `arguments` are rebound in non-arrow functions.
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2017,
+        async_to_generator: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "async function foo(a) { await a; }",
+            "import _asyncToGenerator from '@babel/runtime/helpers/asyncToGenerator';
+            function foo(a) { return _asyncToGenerator(function* () { yield a; }).apply(this, arguments); }",
+        ),
+        (
+            "const foo = async (a) => await a;",
+            "import _asyncToGenerator from '@babel/runtime/helpers/asyncToGenerator';
+            const foo = (a) => _asyncToGenerator(function* () { return yield a; }).call(this);",
+        ),
+        (
+            "class A { async foo() { await 1; } }",
+            "import _asyncToGenerator from '@babel/runtime/helpers/asyncToGenerator';
+            class A { foo() { return _asyncToGenerator(function* () { yield 1; }).apply(this, arguments); } }",
+        ),
+        (
+            "class A extends B { async foo() { super.x = await super.bar(1); return super[y]; } }",
+            "import _asyncToGenerator from '@babel/runtime/helpers/asyncToGenerator';
+            class A extends B { foo() {
+                var _superprop_getBar = () => super.bar, _superprop_setX = (_value) => super.x = _value, _superprop_get = (_prop) => super[_prop];
+                return _asyncToGenerator(function* () {
+                    _superprop_setX(yield _superprop_getBar().call(this, 1));
+                    return _superprop_get(y);
+                }).apply(this, arguments);
+            } }",
+        ),
+        (
+            "function f() { return async () => arguments[0] + (function () { return arguments; })(); }",
+            "import _asyncToGenerator from '@babel/runtime/helpers/asyncToGenerator';
+            function f() { return () => {
+                var _arguments = arguments;
+                return _asyncToGenerator(function* () { return _arguments[0] + (function () { return arguments; })(); }).call(this);
+            }; }",
+        ),
+        (
+            "async function foo(a, { b } = c, ...d) { await a; }",
+            "import _asyncToGenerator from '@babel/runtime/helpers/asyncToGenerator';
+            function foo(_x) { return _asyncToGenerator(function* (a, { b } = c, ...d) { yield a; }).apply(this, arguments); }",
+        ),
+        (
+            "const foo = async (a = b) => await a;",
+            "import _asyncToGenerator from '@babel/runtime/helpers/asyncToGenerator';
+            const foo = (..._args) => _asyncToGenerator(function* (a = b) { return yield a; }).apply(this, _args);",
+        ),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+}
@@ -0,0 +1,390 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{
+    context::TransformerCtx,
+    es2017::wrap_generator,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+};
+
+/// ES2018: Async Generator Functions
+///
+/// ```javascript
+/// async function* foo() { await 1; yield* bar; }
+/// // is transformed to
+/// function foo() {
+///   return _wrapAsyncGenerator(function* () {
+///     yield _awaitAsyncGenerator(1);
+///     yield* _asyncGeneratorDelegate(_asyncIterator(bar));
+///   }).apply(this, arguments);
+/// }
+/// ```
+///
+/// `for await (const x of xs) {}` is lowered to a `for` loop over `_asyncIterator(xs)`,
+/// which awaits each step and closes the iterator with `await _iterator.return()` on abrupt completions.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-async-generator-functions>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-async-generator-functions>
+pub struct AsyncGeneratorFunctions<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    /// Whether each enclosing function is an async generator function.
+    functions: std::vec::Vec<bool>,
+}
+
+impl<'a> AsyncGeneratorFunctions<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2018 || options.async_generator_functions)
+            .then(|| Self { ast, ctx, functions: vec![] })
+    }
+
+    pub fn enter_function(&mut self, func: &Function<'a>) {
+        self.functions.push(func.r#async && func.generator);
+    }
+
+    pub fn exit_function(&mut self, func: &mut Function<'a>) {
+        if !self.functions.pop().unwrap_or_default() {
+            return;
+        }
+        let Some(body) = &mut func.body else { return };
+        func.r#async = false;
+        func.generator = false;
+        // `return _wrapAsyncGenerator(function* () { body }).apply(this, arguments);`
+        let statements = self.ast.move_statement_vec(&mut body.statements);
+        let generator =
+            wrap_generator(&self.ast, &self.ctx, Helper::WrapAsyncGenerator, None, statements);
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(self.ast.this_expression(SPAN)));
+        arguments.push(Argument::Expression(self.reference(&Atom::from("arguments"))));
+        let call = self.call(self.member(generator, "apply"), arguments);
+        body.statements.push(self.ast.return_statement(SPAN, Some(call)));
+    }
+
+    pub fn enter_arrow_expression(&mut self) {
+        self.functions.push(false);
+    }
+
+    pub fn exit_arrow_expression(&mut self) {
+        self.functions.pop();
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        if !self.functions.last().copied().unwrap_or_default() {
+            return;
+        }
+        match expr {
+            // `await x` -> `yield _awaitAsyncGenerator(x)`
+            Expression::AwaitExpression(await_expr) => {
+                let argument = self.ast.move_expression(&mut await_expr.argument);
+                let argument = self.helper_call(Helper::AwaitAsyncGenerator, argument);
+                *expr = self.ast.yield_expression(await_expr.span, false, Some(argument));
+            }
+            // `yield* x` -> `yield* _asyncGeneratorDelegate(_asyncIterator(x))`
+            Expression::YieldExpression(yield_expr) if yield_expr.delegate => {
+                let Some(argument) = &mut yield_expr.argument else { return };
+                let iterator =
+                    self.helper_call(Helper::AsyncIterator, self.ast.move_expression(argument));
+                *argument = self.helper_call(Helper::AsyncGeneratorDelegate, iterator);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::ForOfStatement(for_of) if for_of.r#await => {
+                let Statement::ForOfStatement(for_of) = self.ast.move_statement(stmt) else {
+                    unreachable!()
+                };
+                *stmt = self.build(for_of.unbox(), None);
+            }
+            Statement::LabeledStatement(labeled) if matches!(&labeled.body, Statement::ForOfStatement(for_of) if for_of.r#await) =>
+            {
+                let Statement::LabeledStatement(labeled) = self.ast.move_statement(stmt) else {
+                    unreachable!()
+                };
+                let LabeledStatement { label, body, .. } = labeled.unbox();
+                let Statement::ForOfStatement(for_of) = body else { unreachable!() };
+                *stmt = self.build(for_of.unbox(), Some(label));
+            }
+            _ => {}
+        }
+    }
+
+    /// ```javascript
+    /// var _iteratorAbruptCompletion = false, _didIteratorError = false, _iteratorError;
+    /// try {
+    ///   for (var _iterator = _asyncIterator(xs), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) {
+    ///     const x = _step.value;
+    ///   }
+    /// } catch (err) {
+    ///   _didIteratorError = true;
+    ///   _iteratorError = err;
+    /// } finally {
+    ///   try {
+    ///     if (_iteratorAbruptCompletion && _iterator.return != null) { await _iterator.return(); }
+    ///   } finally {
+    ///     if (_didIteratorError) { throw _iteratorError; }
+    ///   }
+    /// }
+    /// ```
+    fn build(&self, for_of: ForOfStatement<'a>, label: Option<LabelIdentifier>) -> Statement<'a> {
+        let ForOfStatement { left, right, body, .. } = for_of;
+        let abrupt_completion = self.generate_uid("iteratorAbruptCompletion");
+        let did_iterator_error = self.generate_uid("didIteratorError");
+        let iterator_error = self.generate_uid("iteratorError");
+        let iterator = self.generate_uid("iterator");
+        let step = self.generate_uid("step");
+
+        // `const x = _step.value;`
+        let value = self.member(self.reference(&step), "value");
+        let assignment = match left {
+            ForStatementLeft::VariableDeclaration(mut decl) => {
+                if let Some(declarator) = decl.declarations.first_mut() {
+                    declarator.init = Some(value);
+                }
+                Statement::Declaration(Declaration::VariableDeclaration(decl))
+            }
+            ForStatementLeft::AssignmentTarget(target) => {
+                let expr =
+                    self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value);
+                self.ast.expression_statement(SPAN, expr)
+            }
+            ForStatementLeft::UsingDeclaration(decl) => {
+                Statement::Declaration(Declaration::UsingDeclaration(decl))
+            }
+        };
+        let mut statements = self.ast.new_vec_single(assignment);
+        match body {
+            Statement::BlockStatement(block) => statements.extend(block.unbox().body),
+            body => statements.push(body),
+        }
+        let body = self.ast.block_statement(self.ast.block(SPAN, statements));
+
+        // `var _iterator = _asyncIterator(xs), _step`
+        let mut declarations = self.ast.new_vec_with_capacity(2);
+        declarations.push(
+            self.declarator(iterator.clone(), Some(self.helper_call(Helper::AsyncIterator, right))),
+        );
+        declarations.push(self.declarator(step.clone(), None));
+        let init = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+
+        // `_iteratorAbruptCompletion = !(_step = await _iterator.next()).done`
+        let next = self.call(self.member(self.reference(&iterator), "next"), self.ast.new_vec());
+        let next = self.ast.await_expression(SPAN, next);
+        let done =
+            self.member(self.ast.parenthesized_expression(SPAN, self.assign(&step, next)), "done");
+        let test = self.assign(&abrupt_completion, self.not(done));
+        let update = self.assign(&abrupt_completion, self.boolean(false));
+        let stmt = self.ast.for_statement(
+            SPAN,
+            Some(ForStatementInit::VariableDeclaration(init)),
+            Some(test),
+            Some(update),
+            body,
+        );
+        let stmt = match label {
+            Some(label) => self.ast.labeled_statement(SPAN, label, stmt),
+            None => stmt,
+        };
+
+        // `catch (err) { _didIteratorError = true; _iteratorError = err; }`
+        let err = self.generate_uid("err");
+        let mut catch_body = self.ast.new_vec_with_capacity(2);
+        catch_body.push(
+            self.ast
+                .expression_statement(SPAN, self.assign(&did_iterator_error, self.boolean(true))),
+        );
+        catch_body.push(
+            self.ast.expression_statement(SPAN, self.assign(&iterator_error, self.reference(&err))),
+        );
+        let handler =
+            self.ast.catch_clause(SPAN, Some(self.binding(err)), self.ast.block(SPAN, catch_body));
+
+        // `if (_iteratorAbruptCompletion && _iterator.return != null) { await _iterator.return(); }`
+        let right = self.ast.binary_expression(
+            SPAN,
+            self.member(self.reference(&iterator), "return"),
+            BinaryOperator::Inequality,
+            self.ast.literal_null_expression(NullLiteral { span: SPAN }),
+        );
+        let test = self.ast.logical_expression(
+            SPAN,
+            self.reference(&abrupt_completion),
+            LogicalOperator::And,
+            right,
+        );
+        let close = self.call(self.member(self.reference(&iterator), "return"), self.ast.new_vec());
+        let close = self.ast.expression_statement(SPAN, self.ast.await_expression(SPAN, close));
+        let close = self.ast.if_statement(SPAN, test, self.block_statement(close), None);
+        // `if (_didIteratorError) { throw _iteratorError; }`
+        let rethrow = self.ast.if_statement(
+            SPAN,
+            self.reference(&did_iterator_error),
+            self.block_statement(self.ast.throw_statement(SPAN, self.reference(&iterator_error))),
+            None,
+        );
+        let finalizer = self.ast.try_statement(
+            SPAN,
+            self.ast.block(SPAN, self.ast.new_vec_single(close)),
+            None,
+            Some(self.ast.block(SPAN, self.ast.new_vec_single(rethrow))),
+        );
+        let try_stmt = self.ast.try_statement(
+            SPAN,
+            self.ast.block(SPAN, self.ast.new_vec_single(stmt)),
+            Some(handler),
+            Some(self.ast.block(SPAN, self.ast.new_vec_single(finalizer))),
+        );
+
+        // `var _iteratorAbruptCompletion = false, _didIteratorError = false, _iteratorError;`
+        let mut declarations = self.ast.new_vec_with_capacity(3);
+        declarations.push(self.declarator(abrupt_completion, Some(self.boolean(false))));
+        declarations.push(self.declarator(did_iterator_error, Some(self.boolean(false))));
+        declarations.push(self.declarator(iterator_error, None));
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+
+        let mut statements = self.ast.new_vec_with_capacity(2);
+        statements.push(Statement::Declaration(Declaration::VariableDeclaration(decl)));
+        statements.push(try_stmt);
+        self.ast.block_statement(self.ast.block(SPAN, statements))
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn helper_call(&self, helper: Helper, argument: Expression<'a>) -> Expression<'a> {
+        self.ctx.helpers.call(helper, self.ast.new_vec_single(Argument::Expression(argument)))
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn binding(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = BindingIdentifier::new(SPAN, name);
+        self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false)
+    }
+
+    fn declarator(&self, name: Atom, init: Option<Expression<'a>>) -> VariableDeclarator<'a> {
+        let kind = VariableDeclarationKind::Var;
+        self.ast.variable_declarator(SPAN, kind, self.binding(name), init, false)
+    }
+
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, object, property, false)
+    }
+
+    fn call(&self, callee: Expression<'a>, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    fn assign(&self, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let target = self
+            .ast
+            .simple_assignment_target_identifier(IdentifierReference::new(SPAN, name.clone()));
+        self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+
+    fn not(&self, expr: Expression<'a>) -> Expression<'a> {
+        self.ast.unary_expression(SPAN, UnaryOperator::LogicalNot, expr)
+    }
+
+    fn boolean(&self, value: bool) -> Expression<'a> {
+        self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, value))
+    }
+
+    fn block_statement(&self, stmt: Statement<'a>) -> Statement<'a> {
+        self.ast.block_statement(self.ast.block(SPAN, self.ast.new_vec_single(stmt)))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2018,
+        async_generator_functions: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "async function* foo() { await 1; yield 2; yield* bar; }",
+            "import _awaitAsyncGenerator from '@babel/runtime/helpers/awaitAsyncGenerator';
+            import _asyncIterator from '@babel/runtime/helpers/asyncIterator';
+            import _asyncGeneratorDelegate from '@babel/runtime/helpers/asyncGeneratorDelegate';
+            import _wrapAsyncGenerator from '@babel/runtime/helpers/wrapAsyncGenerator';
+            function foo() {
+                return _wrapAsyncGenerator(function* () {
+                    yield _awaitAsyncGenerator(1);
+                    yield 2;
+                    yield* _asyncGeneratorDelegate(_asyncIterator(bar));
+                }).apply(this, arguments);
+            }",
+        ),
+        (
+            "async function foo() { for await (const x of xs) { bar(x); } }",
+            "import _asyncIterator from '@babel/runtime/helpers/asyncIterator';
+            async function foo() {
+                {
+                    var _iteratorAbruptCompletion = false, _didIteratorError = false, _iteratorError;
+                    try {
+                        for (var _iterator = _asyncIterator(xs), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) {
+                            const x = _step.value;
+                            bar(x);
+                        }
+                    } catch (_err) {
+                        _didIteratorError = true;
+                        _iteratorError = _err;
+                    } finally {
+                        try {
+                            if (_iteratorAbruptCompletion && _iterator.return != null) { await _iterator.return(); }
+                        } finally {
+                            if (_didIteratorError) { throw _iteratorError; }
+                        }
+                    }
+                }
+            }",
+        ),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+}
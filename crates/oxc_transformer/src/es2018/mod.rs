@@ -0,0 +1,5 @@
+mod async_generator_functions;
+mod object_rest_spread;
+
+pub use async_generator_functions::AsyncGeneratorFunctions;
+pub use object_rest_spread::ObjectRestSpread;
@@ -0,0 +1,406 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+};
+
+/// ES2018: Object Rest Spread
+///
+/// ```javascript
+/// ({ a, ...b, c });
+/// // is transformed to
+/// _objectSpread2(_objectSpread2({ a }, b), {}, { c });
+///
+/// const { a, ...b } = obj;
+/// // is transformed to
+/// const { a } = obj, b = _objectWithoutProperties(obj, ["a"]);
+/// ```
+///
+/// Rest elements are transformed in variable declarations, function parameters, catch clauses and `for-in`/`for-of` heads.
+/// Only the top level rest element of a pattern is lowered, and patterns with computed keys are left untouched.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-object-rest-spread>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-object-rest-spread>
+pub struct ObjectRestSpread<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    set_spread_properties: bool,
+    object_rest_no_symbols: bool,
+}
+
+impl<'a> ObjectRestSpread<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2018 || options.object_rest_spread).then(|| Self {
+            ast,
+            ctx,
+            set_spread_properties: options.assumptions.set_spread_properties,
+            object_rest_no_symbols: options.assumptions.object_rest_no_symbols,
+        })
+    }
+
+    /// `{ a, ...b, c }` -> `_objectSpread2(_objectSpread2({ a }, b), {}, { c })`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ObjectExpression(object) = expr else { return };
+        if !object
+            .properties
+            .iter()
+            .any(|prop| matches!(prop, ObjectPropertyKind::SpreadProperty(_)))
+        {
+            return;
+        }
+
+        let helper =
+            if self.set_spread_properties { Helper::Extends } else { Helper::ObjectSpread2 };
+        let properties = std::mem::replace(&mut object.properties, self.ast.new_vec());
+        let mut call: Option<Vec<'a, Argument<'a>>> = None;
+        let mut props = self.ast.new_vec();
+        for prop in properties {
+            match prop {
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    let props = std::mem::replace(&mut props, self.ast.new_vec());
+                    let arguments = self.make(helper, call.take(), props);
+                    call = Some(arguments);
+                    if let Some(arguments) = &mut call {
+                        arguments.push(Argument::Expression(spread.unbox().argument));
+                    }
+                }
+                prop @ ObjectPropertyKind::ObjectProperty(_) => props.push(prop),
+            }
+        }
+        let arguments = if props.is_empty() {
+            call.unwrap_or_else(|| self.ast.new_vec())
+        } else {
+            self.make(helper, call, props)
+        };
+        *expr = self.ctx.helpers.call(helper, arguments);
+    }
+
+    /// Arguments of the next helper call, the properties before the spread are merged into the previous call.
+    fn make(
+        &self,
+        helper: Helper,
+        call: Option<Vec<'a, Argument<'a>>>,
+        props: Vec<'a, ObjectPropertyKind<'a>>,
+    ) -> Vec<'a, Argument<'a>> {
+        let had_props = !props.is_empty();
+        let object = self.ast.object_expression(SPAN, props, None);
+        let Some(arguments) = call else {
+            return self.ast.new_vec_single(Argument::Expression(object));
+        };
+        let previous = self.ctx.helpers.call(helper, arguments);
+        let mut arguments = self.ast.new_vec_single(Argument::Expression(previous));
+        if had_props {
+            if !self.set_spread_properties {
+                // Properties after a spread are defined, not assigned.
+                arguments.push(Argument::Expression(self.ast.object_expression(
+                    SPAN,
+                    self.ast.new_vec(),
+                    None,
+                )));
+            }
+            arguments.push(Argument::Expression(object));
+        }
+        arguments
+    }
+
+    /// `const { a, ...b } = obj` -> `const { a } = obj, b = _objectWithoutProperties(obj, ["a"])`
+    pub fn transform_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if !decl.declarations.iter().any(|d| d.init.is_some() && has_rest(&d.id)) {
+            return;
+        }
+        let kind = decl.kind;
+        let declarations = std::mem::replace(&mut decl.declarations, self.ast.new_vec());
+        for declarator in declarations {
+            match declarator.init {
+                Some(init) if has_rest(&declarator.id) => {
+                    self.flatten(kind, declarator.id, init, &mut decl.declarations);
+                }
+                _ => decl.declarations.push(declarator),
+            }
+        }
+    }
+
+    fn flatten(
+        &self,
+        kind: VariableDeclarationKind,
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        declarations: &mut Vec<'a, VariableDeclarator<'a>>,
+    ) {
+        let BindingPatternKind::ObjectPattern(pattern) = pattern.kind else { unreachable!() };
+        let ObjectPattern { properties, rest, .. } = pattern.unbox();
+        let Some(rest) = rest else { unreachable!() };
+
+        // `_ref = init`, unless `init` is a reference which can be read more than once.
+        let source = if self.is_static(&init) {
+            init
+        } else {
+            let name = self.generate_uid("ref");
+            declarations.push(self.declarator(kind, self.binding(name.clone()), Some(init)));
+            self.reference(&name)
+        };
+
+        let mut excluded = self.ast.new_vec_with_capacity(properties.len());
+        for prop in &properties {
+            let name = prop.key.static_name().expect("computed keys are not transformed");
+            let lit = self.ast.literal_string_expression(StringLiteral::new(SPAN, name));
+            excluded.push(ArrayExpressionElement::Expression(lit));
+        }
+        if !properties.is_empty() {
+            let pattern = self.ast.object_pattern(SPAN, properties, None);
+            let pattern = self.ast.binding_pattern(pattern, None, false);
+            declarations.push(self.declarator(kind, pattern, Some(self.clone_reference(&source))));
+        }
+
+        // `b = _objectWithoutProperties(_ref, ["a"])`
+        let helper = if self.object_rest_no_symbols {
+            Helper::ObjectWithoutPropertiesLoose
+        } else {
+            Helper::ObjectWithoutProperties
+        };
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(source));
+        arguments.push(Argument::Expression(self.ast.array_expression(SPAN, excluded, None)));
+        let value = self.ctx.helpers.call(helper, arguments);
+        declarations.push(self.declarator(kind, rest.unbox().argument, Some(value)));
+    }
+
+    /// `function f({ a, ...b }) {}` -> `function f(_ref) { let { a, ...b } = _ref; }`
+    pub fn transform_function(&mut self, func: &mut Function<'a>) {
+        let Some(body) = &mut func.body else { return };
+        let declarations = self.transform_parameters(&mut func.params);
+        for decl in declarations.into_iter().rev() {
+            body.statements.insert(0, decl);
+        }
+    }
+
+    pub fn transform_arrow_expression(&mut self, arrow: &mut ArrowExpression<'a>) {
+        let declarations = self.transform_parameters(&mut arrow.params);
+        if declarations.is_empty() {
+            return;
+        }
+        if arrow.expression {
+            // `({ ...a }) => a` -> `(_ref) => { let { ...a } = _ref; return a; }`
+            arrow.expression = false;
+            if let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() {
+                let argument = stmt.unbox().expression;
+                arrow.body.statements.push(self.ast.return_statement(SPAN, Some(argument)));
+            }
+        }
+        for decl in declarations.into_iter().rev() {
+            arrow.body.statements.insert(0, decl);
+        }
+    }
+
+    fn transform_parameters(
+        &self,
+        params: &mut FormalParameters<'a>,
+    ) -> std::vec::Vec<Statement<'a>> {
+        let mut declarations = vec![];
+        for param in params.items.iter_mut() {
+            let pattern = match &mut param.pattern.kind {
+                BindingPatternKind::AssignmentPattern(assign) => &mut assign.left,
+                _ => &mut param.pattern,
+            };
+            if has_rest(pattern) {
+                let name = self.generate_uid("ref");
+                let pattern = std::mem::replace(pattern, self.binding(name.clone()));
+                declarations.push(self.declaration(pattern, self.reference(&name)));
+            }
+        }
+        declarations
+    }
+
+    /// `catch ({ ...a }) {}` -> `catch (_ref) { let { ...a } = _ref; }`
+    pub fn transform_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        if !has_rest(param) {
+            return;
+        }
+        let name = self.generate_uid("ref");
+        let pattern = std::mem::replace(param, self.binding(name.clone()));
+        clause.body.body.insert(0, self.declaration(pattern, self.reference(&name)));
+    }
+
+    /// `for (const { ...a } of xs) {}` -> `for (const _ref of xs) { const { ...a } = _ref; }`
+    pub fn transform_statement(&mut self, stmt: &mut Statement<'a>) {
+        let (left, body) = match stmt {
+            Statement::ForOfStatement(stmt) => {
+                let stmt = &mut **stmt;
+                (&mut stmt.left, &mut stmt.body)
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = &mut **stmt;
+                (&mut stmt.left, &mut stmt.body)
+            }
+            _ => return,
+        };
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        let kind = decl.kind;
+        let [declarator] = decl.declarations.as_mut_slice() else { return };
+        if !has_rest(&declarator.id) {
+            return;
+        }
+        let name = self.generate_uid("ref");
+        let pattern = std::mem::replace(&mut declarator.id, self.binding(name.clone()));
+        let declarator = self.declarator(kind, pattern, Some(self.reference(&name)));
+        let head = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        let head = Statement::Declaration(Declaration::VariableDeclaration(head));
+        if let Statement::BlockStatement(block) = body {
+            block.body.insert(0, head);
+        } else {
+            let mut statements = self.ast.new_vec_with_capacity(2);
+            statements.push(head);
+            statements.push(self.ast.move_statement(body));
+            *body = self.ast.block_statement(self.ast.block(SPAN, statements));
+        }
+    }
+
+    /// Identifiers generated by the transformer and constant references are not memoised.
+    fn is_static(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) if ident.reference_id.get().is_none() => true,
+            _ => self.ctx.symbols().is_static(expr),
+        }
+    }
+
+    fn clone_reference(&self, expr: &Expression<'a>) -> Expression<'a> {
+        match expr {
+            Expression::Identifier(ident) => {
+                self.ast.identifier_reference_expression((**ident).clone())
+            }
+            Expression::ThisExpression(_) => self.ast.this_expression(SPAN),
+            _ => unreachable!("only static expressions are reused"),
+        }
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn binding(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = BindingIdentifier::new(SPAN, name);
+        self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false)
+    }
+
+    fn declarator(
+        &self,
+        kind: VariableDeclarationKind,
+        pattern: BindingPattern<'a>,
+        init: Option<Expression<'a>>,
+    ) -> VariableDeclarator<'a> {
+        self.ast.variable_declarator(SPAN, kind, pattern, init, false)
+    }
+
+    /// `let pattern = init;`
+    fn declaration(&self, pattern: BindingPattern<'a>, init: Expression<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Let;
+        let declarator = self.declarator(kind, pattern, Some(init));
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+}
+
+/// The pattern is an object pattern with a rest element and no computed keys.
+fn has_rest(pattern: &BindingPattern) -> bool {
+    matches!(&pattern.kind, BindingPatternKind::ObjectPattern(object)
+        if object.rest.is_some() && object.properties.iter().all(|prop| prop.key.static_name().is_some()))
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2018,
+        object_rest_spread: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let spread = "import _objectSpread2 from '@babel/runtime/helpers/objectSpread2';\n";
+    let rest =
+        "import _objectWithoutProperties from '@babel/runtime/helpers/objectWithoutProperties';\n";
+    let tests = [
+        ("({ ...a });", spread.to_string() + "_objectSpread2({}, a);"),
+        ("({ a, ...b, c });", spread.to_string() + "_objectSpread2(_objectSpread2({ a }, b), {}, { c });"),
+        ("({ ...a, ...b });", spread.to_string() + "_objectSpread2(_objectSpread2({}, a), b);"),
+        (
+            "function f(obj) { const { a, ...b } = obj; }",
+            rest.to_string()
+                + "function f(obj) { const { a } = obj, b = _objectWithoutProperties(obj, ['a']); }",
+        ),
+        (
+            "let { 'a': x, 1: y, ...z } = foo();",
+            rest.to_string()
+                + "let _ref = foo(), { 'a': x, 1: y } = _ref, z = _objectWithoutProperties(_ref, ['a', '1']);",
+        ),
+        (
+            "function f({ a, ...b }) {}",
+            rest.to_string()
+                + "function f(_ref) { let { a } = _ref, b = _objectWithoutProperties(_ref, ['a']); }",
+        ),
+        (
+            "const f = ({ ...a } = {}) => a;",
+            rest.to_string()
+                + "const f = (_ref = {}) => { let a = _objectWithoutProperties(_ref, []); return a; };",
+        ),
+        (
+            "for (const { a, ...b } of xs);",
+            rest.to_string()
+                + "for (const _ref of xs) { const { a } = _ref, b = _objectWithoutProperties(_ref, ['a']); ; }",
+        ),
+        ("const { [a]: b, ...c } = obj;", "const { [a]: b, ...c } = obj;".to_string()),
+    ];
+    let tests = tests.iter().map(|(a, b)| (*a, b.as_str())).collect::<std::vec::Vec<_>>();
+    Tester::new("test.mjs", options.clone()).test(&tests);
+
+    let mut options = options;
+    options.assumptions.set_spread_properties = true;
+    options.assumptions.object_rest_no_symbols = true;
+    let tests = &[
+        (
+            "({ a, ...b, c });",
+            "import _extends from '@babel/runtime/helpers/extends';\n_extends(_extends({ a }, b), { c });",
+        ),
+        (
+            "function f(obj) { const { a, ...b } = obj; }",
+            "import _objectWithoutPropertiesLoose from '@babel/runtime/helpers/objectWithoutPropertiesLoose';\nfunction f(obj) { const { a } = obj, b = _objectWithoutPropertiesLoose(obj, ['a']); }",
+        ),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+}
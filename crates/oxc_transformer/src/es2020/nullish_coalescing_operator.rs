@@ -32,6 +32,8 @@ pub struct NullishCoalescingOperator<'a> {
     ctx: TransformerCtx<'a>,
 
     vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
 }
 
 impl<'a> CreateVars<'a> for NullishCoalescingOperator<'a> {
@@ -42,6 +44,10 @@ impl<'a> CreateVars<'a> for NullishCoalescingOperator<'a> {
     fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
         &mut self.vars
     }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
 }
 
 impl<'a> NullishCoalescingOperator<'a> {
@@ -55,7 +61,7 @@ impl<'a> NullishCoalescingOperator<'a> {
                 let no_document_all = options.assumptions.no_document_all
                     || options.nullish_coalescing_operator.is_some_and(|o| o.loose);
                 let vars = ast.new_vec();
-                Self { no_document_all, ast, ctx, vars }
+                Self { no_document_all, ast, ctx, vars, vars_stack: vec![] }
             })
     }
 
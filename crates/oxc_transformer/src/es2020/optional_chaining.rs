@@ -0,0 +1,288 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{
+    context::TransformerCtx,
+    options::{TransformOptions, TransformTarget},
+    utils::CreateVars,
+};
+
+/// ES2020: Optional Chaining
+///
+/// ```javascript
+/// a?.b.c;
+/// // is transformed to
+/// var _a;
+/// (_a = a) === null || _a === void 0 ? void 0 : _a.b.c;
+///
+/// a.b?.();
+/// // is transformed to
+/// var _a, _a$b;
+/// (_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a);
+///
+/// delete a?.b;
+/// // is transformed to
+/// var _a;
+/// (_a = a) === null || _a === void 0 ? true : delete _a.b;
+/// ```
+///
+/// With the `noDocumentAll` assumption, `_a == null` is used for the checks.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-optional-chaining>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-optional-chaining>
+pub struct OptionalChaining<'a> {
+    no_document_all: bool,
+
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+}
+
+impl<'a> CreateVars<'a> for OptionalChaining<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
+}
+
+impl<'a> OptionalChaining<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2020 || options.optional_chaining).then(|| {
+            let no_document_all = options.assumptions.no_document_all;
+            let vars = ast.new_vec();
+            Self { no_document_all, ast, ctx, vars, vars_stack: vec![] }
+        })
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let delete = match expr {
+            Expression::ChainExpression(_) => false,
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::Delete
+                    && matches!(unary.argument, Expression::ChainExpression(_)) =>
+            {
+                true
+            }
+            _ => return,
+        };
+
+        let chain = if let Expression::UnaryExpression(unary) = expr {
+            self.ast.move_expression(&mut unary.argument)
+        } else {
+            self.ast.move_expression(expr)
+        };
+        let Expression::ChainExpression(chain) = chain else { unreachable!() };
+        let element = match chain.unbox().expression {
+            ChainElement::CallExpression(call) => Expression::CallExpression(call),
+            ChainElement::MemberExpression(member) => Expression::MemberExpression(member),
+        };
+
+        // Collect the links of the chain from the outermost one down to the object of the chain,
+        // `a?.b.c()` is split into `a`, `?.b`, `.c`, `()`.
+        let mut links = vec![];
+        let mut current = element;
+        loop {
+            let object = match &mut current {
+                Expression::MemberExpression(member) => {
+                    self.ast.move_expression(member.object_mut())
+                }
+                Expression::CallExpression(call) => self.ast.move_expression(&mut call.callee),
+                _ => break,
+            };
+            links.push(current);
+            current = object;
+        }
+        links.reverse();
+
+        *expr = self.build(current, &mut links.into_iter().peekable(), delete, None);
+    }
+
+    /// Apply `links` to `object`, each optional link becomes a conditional wrapping the rest of the chain.
+    /// `context` is the object of `object` when it is the callee of the next link.
+    fn build(
+        &mut self,
+        mut object: Expression<'a>,
+        links: &mut std::iter::Peekable<std::vec::IntoIter<Expression<'a>>>,
+        delete: bool,
+        mut context: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        while let Some(mut link) = links.next() {
+            if is_optional(&link) {
+                let (left, reference) = self.memoise(object);
+                let test = self.nullish_check(left, &reference);
+                let mut next_context = None;
+                match &mut link {
+                    Expression::MemberExpression(member) => {
+                        set_member_optional(member);
+                        // `a?.[x]?.()` calls `_a$x` with `this` as `_a`
+                        if links.peek().is_some_and(
+                            |next| matches!(next, Expression::CallExpression(call) if call.optional),
+                        ) {
+                            next_context = Some(self.ast.copy(&reference));
+                        }
+                        *member.object_mut() = reference;
+                    }
+                    Expression::CallExpression(call) => {
+                        call.optional = false;
+                        if let Some(context) = context.take() {
+                            // `_a$b.call(_a, ...arguments)`
+                            let property = IdentifierName::new(SPAN, Atom::from("call"));
+                            call.callee =
+                                self.ast.static_member_expression(SPAN, reference, property, false);
+                            call.arguments.insert(0, Argument::Expression(context));
+                        } else {
+                            call.callee = reference;
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+                let consequent = if delete {
+                    self.ast.literal_boolean_expression(BooleanLiteral::new(SPAN, true))
+                } else {
+                    self.ast.void_0()
+                };
+                let alternate = self.build(link, links, delete, next_context);
+                return self.ast.conditional_expression(SPAN, test, consequent, alternate);
+            }
+
+            context = None;
+            match &mut link {
+                Expression::MemberExpression(member) => {
+                    if links.peek().is_some_and(
+                        |next| matches!(next, Expression::CallExpression(call) if call.optional),
+                    ) {
+                        if matches!(object, Expression::Super(_)) {
+                            context = Some(self.ast.this_expression(SPAN));
+                        } else {
+                            let (left, reference) = self.memoise(object);
+                            object = left;
+                            context = Some(reference);
+                        }
+                    }
+                    *member.object_mut() = object;
+                }
+                Expression::CallExpression(call) => call.callee = object,
+                _ => unreachable!(),
+            }
+            object = link;
+        }
+
+        if delete {
+            object = self.ast.unary_expression(SPAN, UnaryOperator::Delete, object);
+        }
+        object
+    }
+
+    /// Returns `(_a = a, _a)`, or `(a, a)` when `a` is static.
+    fn memoise(&mut self, expr: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if self.ctx.symbols().is_static(&expr) {
+            let reference = self.ast.copy(&expr);
+            return (expr, reference);
+        }
+        let ident = self.create_new_var(&expr);
+        let reference = self.ast.identifier_reference_expression(ident.clone());
+        let left = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_identifier(ident),
+        );
+        let assignment =
+            self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, expr);
+        (assignment, reference)
+    }
+
+    /// `left === null || reference === void 0` or `left == null`
+    fn nullish_check(&self, left: Expression<'a>, reference: &Expression<'a>) -> Expression<'a> {
+        let null = self.ast.literal_null_expression(NullLiteral::new(SPAN));
+        if self.no_document_all {
+            return self.ast.binary_expression(SPAN, left, BinaryOperator::Equality, null);
+        }
+        let op = BinaryOperator::StrictEquality;
+        let left = self.ast.binary_expression(SPAN, left, op, null);
+        let right =
+            self.ast.binary_expression(SPAN, self.ast.copy(reference), op, self.ast.void_0());
+        self.ast.logical_expression(SPAN, left, LogicalOperator::Or, right)
+    }
+}
+
+fn is_optional(expr: &Expression) -> bool {
+    match expr {
+        Expression::MemberExpression(member) => member.optional(),
+        Expression::CallExpression(call) => call.optional,
+        _ => false,
+    }
+}
+
+fn set_member_optional(member: &mut MemberExpression) {
+    match member {
+        MemberExpression::ComputedMemberExpression(expr) => expr.optional = false,
+        MemberExpression::StaticMemberExpression(expr) => expr.optional = false,
+        MemberExpression::PrivateFieldExpression(expr) => expr.optional = false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2020,
+        optional_chaining: true,
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        ("a?.b.c;", "var _a; (_a = a) === null || _a === void 0 ? void 0 : _a.b.c;"),
+        (
+            "function f(a) { a?.[0]?.b; }",
+            "function f(a) { var _a$ref; a === null || a === void 0 ? void 0 : (_a$ref = a[0]) === null || _a$ref === void 0 ? void 0 : _a$ref.b; }",
+        ),
+        ("function f(a) { a?.(); }", "function f(a) { a === null || a === void 0 ? void 0 : a(); }"),
+        (
+            "function f(a) { a.b?.(1); }",
+            "function f(a) { var _a$b; (_a$b = a.b) === null || _a$b === void 0 ? void 0 : _a$b.call(a, 1); }",
+        ),
+        (
+            "a.b?.();",
+            "var _a, _a$b; (_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a);",
+        ),
+        (
+            "a?.[x]?.();",
+            "var _a, _a$x; (_a = a) === null || _a === void 0 ? void 0 : (_a$x = _a[x]) === null || _a$x === void 0 ? void 0 : _a$x.call(_a);",
+        ),
+        (
+            "function f(a) { delete a?.b; }",
+            "function f(a) { a === null || a === void 0 ? true : delete a.b; }",
+        ),
+        (
+            "function f(a) { (a?.b).c; }",
+            "function f(a) { (a === null || a === void 0 ? void 0 : a.b).c; }",
+        ),
+    ];
+    Tester::new("test.js", options.clone()).test(tests);
+
+    let mut options = options;
+    options.assumptions.no_document_all = true;
+    let tests = &[("function f(a) { a?.b; }", "function f(a) { a == null ? void 0 : a.b; }")];
+    Tester::new("test.js", options).test(tests);
+}
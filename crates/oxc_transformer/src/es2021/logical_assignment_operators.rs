@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use oxc_allocator::Vec;
+use oxc_allocator::{CloneIn, Vec};
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::SPAN;
 use oxc_syntax::operator::{AssignmentOperator, LogicalOperator};
@@ -21,6 +21,8 @@ pub struct LogicalAssignmentOperators<'a> {
     ctx: TransformerCtx<'a>,
 
     vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
 }
 
 impl<'a> CreateVars<'a> for LogicalAssignmentOperators<'a> {
@@ -31,6 +33,10 @@ impl<'a> CreateVars<'a> for LogicalAssignmentOperators<'a> {
     fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
         &mut self.vars
     }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
 }
 
 impl<'a> LogicalAssignmentOperators<'a> {
@@ -42,7 +48,7 @@ impl<'a> LogicalAssignmentOperators<'a> {
         (options.target < TransformTarget::ES2021 || options.logical_assignment_operators).then(
             || {
                 let vars = ast.new_vec();
-                Self { ast, ctx, vars }
+                Self { ast, ctx, vars, vars_stack: vec![] }
             },
         )
     }
@@ -79,8 +85,8 @@ impl<'a> LogicalAssignmentOperators<'a> {
                     match &**member_expr {
                         MemberExpression::StaticMemberExpression(static_expr) => {
                             if let Some(ident) = self.maybe_generate_memoised(&static_expr.object) {
-                                let right = self.ast.copy(&static_expr.object);
-                                let mut expr = self.ast.copy(static_expr);
+                                let right = static_expr.object.clone_in(self.ast.allocator);
+                                let mut expr = static_expr.clone_in(self.ast.allocator);
                                 let target = AssignmentTarget::SimpleAssignmentTarget(
                                     self.ast.simple_assignment_target_identifier(ident.clone()),
                                 );
@@ -90,7 +96,7 @@ impl<'a> LogicalAssignmentOperators<'a> {
                                     MemberExpression::StaticMemberExpression(expr),
                                 );
 
-                                let mut expr = self.ast.copy(static_expr);
+                                let mut expr = static_expr.clone_in(self.ast.allocator);
                                 expr.object = self.ast.identifier_reference_expression(ident);
                                 assign_target =
                                     self.ast.simple_assignment_target_member_expression(
@@ -99,11 +105,11 @@ impl<'a> LogicalAssignmentOperators<'a> {
                             } else {
                                 left_expr = self.ast.member_expression(
                                     MemberExpression::StaticMemberExpression(
-                                        self.ast.copy(static_expr),
+                                        static_expr.clone_in(self.ast.allocator),
                                     ),
                                 );
                                 assign_target = SimpleAssignmentTarget::MemberAssignmentTarget(
-                                    self.ast.copy(member_expr),
+                                    member_expr.clone_in(self.ast.allocator),
                                 );
                             };
                         }
@@ -115,8 +121,8 @@ impl<'a> LogicalAssignmentOperators<'a> {
                                 let property_ident =
                                     self.maybe_generate_memoised(&computed_expr.expression);
 
-                                let right = self.ast.copy(&computed_expr.object);
-                                let mut expr = self.ast.copy(computed_expr);
+                                let right = computed_expr.object.clone_in(self.ast.allocator);
+                                let mut expr = computed_expr.clone_in(self.ast.allocator);
                                 let target = AssignmentTarget::SimpleAssignmentTarget(
                                     self.ast.simple_assignment_target_identifier(ident.clone()),
                                 );
@@ -128,7 +134,8 @@ impl<'a> LogicalAssignmentOperators<'a> {
                                             property_ident.clone(),
                                         ),
                                     );
-                                    let right = self.ast.copy(&computed_expr.expression);
+                                    let right =
+                                        computed_expr.expression.clone_in(self.ast.allocator);
                                     expr.expression =
                                         self.ast.assignment_expression(SPAN, op, left, right);
                                 }
@@ -137,7 +144,7 @@ impl<'a> LogicalAssignmentOperators<'a> {
                                 );
 
                                 // `(_a[_b$y] = c)` part
-                                let mut expr = self.ast.copy(computed_expr);
+                                let mut expr = computed_expr.clone_in(self.ast.allocator);
                                 expr.object = self.ast.identifier_reference_expression(ident);
                                 if let Some(property_ident) = property_ident {
                                     expr.expression =
@@ -152,7 +159,7 @@ impl<'a> LogicalAssignmentOperators<'a> {
                                     self.maybe_generate_memoised(&computed_expr.expression);
 
                                 // let right = self.ast.copy(&computed_expr.object);
-                                let mut expr = self.ast.copy(computed_expr);
+                                let mut expr = computed_expr.clone_in(self.ast.allocator);
                                 // let target = AssignmentTarget::SimpleAssignmentTarget(
                                 // self.ast.simple_assignment_target_identifier(ident.clone()),
                                 // );
@@ -164,7 +171,8 @@ impl<'a> LogicalAssignmentOperators<'a> {
                                             property_ident.clone(),
                                         ),
                                     );
-                                    let right = self.ast.copy(&computed_expr.expression);
+                                    let right =
+                                        computed_expr.expression.clone_in(self.ast.allocator);
                                     expr.expression =
                                         self.ast.assignment_expression(SPAN, op, left, right);
                                 }
@@ -172,7 +180,7 @@ impl<'a> LogicalAssignmentOperators<'a> {
                                     MemberExpression::ComputedMemberExpression(expr),
                                 );
 
-                                let mut expr = self.ast.copy(computed_expr);
+                                let mut expr = computed_expr.clone_in(self.ast.allocator);
                                 // expr.object = self.ast.identifier_reference_expression(ident);
                                 if let Some(property_ident) = property_ident {
                                     expr.expression =
@@ -0,0 +1,1339 @@
+use std::{hash::BuildHasherDefault, mem, rc::Rc};
+
+use indexmap::IndexMap;
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UpdateOperator};
+use rustc_hash::FxHasher;
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    options::{TransformOptions, TransformTarget},
+    utils::CreateVars,
+};
+
+/// ES2022: Class Properties
+///
+/// Lowers public and private class fields, private methods and accessors, and `#x in obj` brand checks.
+///
+/// ```javascript
+/// class A {
+///   #x = 1;
+///   static y = 2;
+///   #m() { return this.#x; }
+///   has(obj) { return #x in obj; }
+/// }
+/// // is transformed to
+/// var _x = new WeakMap();
+/// var _A_brand = new WeakSet();
+/// class A {
+///   constructor() {
+///     _classPrivateMethodInitSpec(this, _A_brand);
+///     _classPrivateFieldInitSpec(this, _x, 1);
+///   }
+///   has(obj) { return _x.has(_checkInRHS(obj)); }
+/// }
+/// function _m() { return _classPrivateFieldGet2(_x, this); }
+/// _defineProperty(A, "y", 2);
+/// ```
+///
+/// With the `setPublicClassFields` assumption, public fields are assigned with `this.y = 2`.
+/// With the `privateFieldsAsProperties` assumption, private members are stored as non-enumerable
+/// properties keyed by `_classPrivateFieldLooseKey("x")`.
+///
+/// Static blocks of transformed classes are lowered as well, so they keep their order with static fields.
+/// Private names inside optional chains (`a?.#x`) are not supported, private names in destructuring
+/// targets are assigned through `_toSetter(_classPrivateFieldSet2, [_x, obj])._`.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-class-properties>
+/// * <https://babel.dev/docs/babel-plugin-transform-private-methods>
+/// * <https://babel.dev/docs/babel-plugin-transform-private-property-in-object>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-helper-create-class-features-plugin>
+pub struct ClassProperties<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    set_public_class_fields: bool,
+    private_fields_as_properties: bool,
+
+    vars: Vec<'a, VariableDeclarator<'a>>,
+    /// Vars of the enclosing functions.
+    vars_stack: std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>,
+}
+
+impl<'a> CreateVars<'a> for ClassProperties<'a> {
+    fn ctx(&self) -> &TransformerCtx<'a> {
+        &self.ctx
+    }
+
+    fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>> {
+        &mut self.vars
+    }
+
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>> {
+        &mut self.vars_stack
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PrivateKind {
+    Field,
+    Method,
+    Accessor,
+}
+
+#[derive(Debug, Clone)]
+struct PrivateName {
+    kind: PrivateKind,
+    r#static: bool,
+    /// `_x`: the `WeakMap` of a field, the function of a method,
+    /// or the property key with `privateFieldsAsProperties`.
+    id: Atom,
+    /// The function of a method with `privateFieldsAsProperties`.
+    method: Option<Atom>,
+    getter: Option<Atom>,
+    setter: Option<Atom>,
+}
+
+type PrivateNames = IndexMap<Atom, PrivateName, BuildHasherDefault<FxHasher>>;
+
+/// Code moved out of a class, see [`ClassProperties::transform_class`].
+struct ClassOutput<'a> {
+    /// `var _x = new WeakMap();`, evaluated before the class.
+    before: std::vec::Vec<(Atom, Expression<'a>)>,
+    /// `function _m() {}`, private methods and accessors.
+    methods: std::vec::Vec<Box<'a, Function<'a>>>,
+    /// Static fields and blocks, evaluated in order after the class.
+    statics: std::vec::Vec<Static<'a>>,
+}
+
+enum Static<'a> {
+    /// `_defineProperty(A, "x", 1);`
+    Expression(Expression<'a>),
+    /// `var _x = { _: 1 };`
+    Var(Atom, Expression<'a>),
+}
+
+impl<'a> ClassProperties<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        (options.target < TransformTarget::ES2022 || options.class_properties).then(|| {
+            let vars = ast.new_vec();
+            Self {
+                ast,
+                ctx,
+                set_public_class_fields: options.assumptions.set_public_class_fields,
+                private_fields_as_properties: options.assumptions.private_fields_as_properties,
+                vars,
+                vars_stack: vec![],
+            }
+        })
+    }
+
+    /// Transform class declarations, the code moved out of the class is inserted around it.
+    pub fn transform_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            let Some(stmt) = stmts.get_mut(i) else { break };
+            let class = match stmt {
+                Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(class),
+                Statement::ModuleDeclaration(decl) => match &mut **decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                        match &mut decl.declaration {
+                            Some(Declaration::ClassDeclaration(class)) => Some(class),
+                            _ => None,
+                        }
+                    }
+                    ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                        match &mut decl.declaration {
+                            ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            let Some(class) = class.filter(|class| needs_transform(class)) else {
+                i += 1;
+                continue;
+            };
+
+            // `export default class {}` -> `export default class _default {}`
+            let id = class
+                .id
+                .get_or_insert_with(|| BindingIdentifier::new(SPAN, self.generate_uid("default")));
+            let name = id.name.clone();
+            let output = self.transform_class(class, &name);
+
+            let mut before = self.ast.new_vec();
+            for (name, init) in output.before {
+                before.push(self.var_declaration(name, init));
+            }
+            let mut after = self.ast.new_vec();
+            for func in output.methods {
+                after.push(self.ast.function_declaration(func));
+            }
+            for item in output.statics {
+                after.push(match item {
+                    Static::Expression(expr) => self.ast.expression_statement(SPAN, expr),
+                    Static::Var(name, init) => self.var_declaration(name, init),
+                });
+            }
+
+            let (before_len, after_len) = (before.len(), after.len());
+            stmts.splice(i..i, before);
+            i += before_len + 1;
+            stmts.splice(i..i, after);
+            i += after_len;
+        }
+    }
+
+    /// `class { #x = 1; static y = 2 }` ->
+    /// `(_x = new WeakMap(), _Class = class { ... }, _defineProperty(_Class, "y", 2), _Class)`
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !needs_transform(class) {
+            return;
+        }
+
+        let has_static = class.body.body.iter().any(|element| match element {
+            ClassElement::StaticBlock(_) => true,
+            ClassElement::PropertyDefinition(prop) => prop.r#static,
+            ClassElement::MethodDefinition(method) => {
+                method.r#static && method.key.is_private_identifier()
+            }
+            _ => false,
+        });
+        // The class reference is only used by static members.
+        let reference = has_static.then(|| {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            self.create_new_named_var(name).name
+        });
+        let class_ref = reference.clone().unwrap_or_else(|| Atom::from("Class"));
+        let output = self.transform_class(class, &class_ref);
+
+        let mut expressions = self.ast.new_vec();
+        for (name, init) in output.before {
+            expressions.push(self.assign_var(name, init));
+        }
+        for func in output.methods {
+            let Some(name) = func.id.as_ref().map(|id| id.name.clone()) else { continue };
+            expressions.push(self.assign_var(name, self.ast.function_expression(func)));
+        }
+        let class = self.ast.move_expression(expr);
+        let Some(reference) = reference else {
+            if expressions.is_empty() {
+                *expr = class;
+            } else {
+                expressions.push(class);
+                *expr = self.ast.sequence_expression(SPAN, expressions);
+            }
+            return;
+        };
+        expressions.push(self.assign(reference.clone(), class));
+        for item in output.statics {
+            expressions.push(match item {
+                Static::Expression(expr) => expr,
+                Static::Var(name, init) => self.assign_var(name, init),
+            });
+        }
+        expressions.push(self.reference(&reference));
+        *expr = self.ast.sequence_expression(SPAN, expressions);
+    }
+
+    /// Move fields into the constructor and private methods out of the class,
+    /// and replace the private names in the class body.
+    ///
+    /// `class_ref` references the class in static initializers.
+    fn transform_class(&mut self, class: &mut Class<'a>, class_ref: &Atom) -> ClassOutput<'a> {
+        let loose = self.private_fields_as_properties;
+        let mut output = ClassOutput { before: vec![], methods: vec![], statics: vec![] };
+        let mut instance_inits = vec![];
+
+        let (mut names, brand) =
+            self.collect_private_names(class, &mut output, &mut instance_inits);
+        if loose {
+            self.define_private_methods(&names, class_ref, &mut output, &mut instance_inits);
+        }
+
+        if !names.is_empty() {
+            let mut replacer =
+                PrivateReplacer { transform: self, names, class: class_ref.clone(), brand };
+            replacer.visit_class_body(&mut class.body);
+            names = replacer.names;
+        }
+
+        let elements = mem::replace(&mut class.body.body, self.ast.new_vec());
+        for element in elements {
+            match element {
+                ClassElement::PropertyDefinition(prop) if !prop.declare => {
+                    let prop = prop.unbox();
+                    let mut value = prop.value.unwrap_or_else(|| self.ast.void_0());
+                    if prop.r#static {
+                        self.replace_this(&mut value, class_ref);
+                    }
+                    let target = self.target(prop.r#static, class_ref);
+                    let init = if let PropertyKey::PrivateIdentifier(ident) = &prop.key {
+                        let id = self.reference(&names[&ident.name].id);
+                        if loose {
+                            // `Object.defineProperty(this, _x, { writable: true, value: 1 })`
+                            let writable = self
+                                .ast
+                                .literal_boolean_expression(BooleanLiteral::new(SPAN, true));
+                            let properties = vec![("writable", writable), ("value", value)];
+                            self.define_property(target, id, properties)
+                        } else if prop.r#static {
+                            // `var _x = { _: 1 }`
+                            let object = self.object(vec![("_", value)]);
+                            output.statics.push(Static::Var(names[&ident.name].id.clone(), object));
+                            continue;
+                        } else {
+                            self.helper_call(Helper::ClassPrivateFieldInitSpec, [target, id, value])
+                        }
+                    } else {
+                        let key = self.field_key(prop.key, prop.computed, &mut output.before);
+                        self.define_field(target, key, value)
+                    };
+                    if prop.r#static {
+                        output.statics.push(Static::Expression(init));
+                    } else {
+                        instance_inits.push(init);
+                    }
+                }
+                ClassElement::MethodDefinition(method) if method.key.is_private_identifier() => {
+                    let method = method.unbox();
+                    let Some(info) = method.key.private_name().and_then(|name| names.get(&name))
+                    else {
+                        continue;
+                    };
+                    let name = match method.kind {
+                        MethodDefinitionKind::Get => info.getter.clone(),
+                        MethodDefinitionKind::Set => info.setter.clone(),
+                        _ => Some(info.method.clone().unwrap_or_else(|| info.id.clone())),
+                    };
+                    let mut func = method.value;
+                    func.r#type = FunctionType::FunctionDeclaration;
+                    func.id = name.map(|name| BindingIdentifier::new(SPAN, name));
+                    output.methods.push(func);
+                }
+                ClassElement::StaticBlock(block) => {
+                    let mut block = block.unbox();
+                    let expr =
+                        if let [Statement::ExpressionStatement(stmt)] = block.body.as_mut_slice() {
+                            self.ast.move_expression(&mut stmt.expression)
+                        } else {
+                            // `(() => { ... })()`
+                            let params = self.ast.formal_parameters(
+                                SPAN,
+                                FormalParameterKind::ArrowFormalParameters,
+                                self.ast.new_vec(),
+                                None,
+                            );
+                            let body = self.ast.function_body(SPAN, self.ast.new_vec(), block.body);
+                            let arrow = self.ast.arrow_expression(
+                                SPAN, false, false, false, params, body, None, None, None,
+                            );
+                            let callee = self.ast.parenthesized_expression(SPAN, arrow);
+                            self.ast.call_expression(SPAN, callee, self.ast.new_vec(), false, None)
+                        };
+                    let mut expr = expr;
+                    self.replace_this(&mut expr, class_ref);
+                    output.statics.push(Static::Expression(expr));
+                }
+                element => class.body.body.push(element),
+            }
+        }
+
+        if !instance_inits.is_empty() {
+            self.insert_instance_inits(class, instance_inits);
+        }
+        output
+    }
+
+    /// Initialize instance fields at the start of the constructor, or after `super()` in derived classes.
+    fn insert_instance_inits(
+        &mut self,
+        class: &mut Class<'a>,
+        inits: std::vec::Vec<Expression<'a>>,
+    ) {
+        let derived = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                method.value.body.as_mut()
+            }
+            _ => None,
+        });
+
+        let Some(body) = constructor else {
+            // `constructor(...args) { super(...args); }`
+            let mut statements = self.ast.new_vec();
+            let mut rest = None;
+            if derived {
+                let args = self.generate_uid("args");
+                rest = Some(self.ast.rest_element(SPAN, self.binding(args.clone())));
+                let spread = self.ast.spread_element(SPAN, self.reference(&args));
+                let call = self.ast.call_expression(
+                    SPAN,
+                    self.ast.super_(SPAN),
+                    self.ast.new_vec_single(Argument::SpreadElement(spread)),
+                    false,
+                    None,
+                );
+                statements.push(self.ast.expression_statement(SPAN, call));
+            }
+            for init in inits {
+                statements.push(self.ast.expression_statement(SPAN, init));
+            }
+            let params = self.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::UniqueFormalParameters,
+                self.ast.new_vec(),
+                rest,
+            );
+            let body = self.ast.function_body(SPAN, self.ast.new_vec(), statements);
+            let func = self.ast.function(
+                FunctionType::FunctionExpression,
+                SPAN,
+                None,
+                false,
+                false,
+                false,
+                params,
+                Some(body),
+                None,
+                None,
+                None,
+                Modifiers::empty(),
+            );
+            let key = self
+                .ast
+                .property_key_identifier(IdentifierName::new(SPAN, Atom::from("constructor")));
+            let method = self.ast.alloc(MethodDefinition {
+                span: SPAN,
+                key,
+                value: func,
+                kind: MethodDefinitionKind::Constructor,
+                computed: false,
+                r#static: false,
+                r#override: false,
+                optional: false,
+                accessibility: None,
+                decorators: self.ast.new_vec(),
+            });
+            class.body.body.insert(0, ClassElement::MethodDefinition(method));
+            return;
+        };
+
+        if !derived {
+            let statements = mem::replace(&mut body.statements, self.ast.new_vec());
+            for init in inits {
+                body.statements.push(self.ast.expression_statement(SPAN, init));
+            }
+            body.statements.extend(statements);
+            return;
+        }
+
+        // After a top level `super()` call.
+        if let Some(index) = body.statements.iter().position(|stmt| {
+            matches!(stmt, Statement::ExpressionStatement(stmt)
+                if matches!(&stmt.expression, Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_))))
+        }) {
+            let statements = inits.into_iter().map(|init| self.ast.expression_statement(SPAN, init));
+            body.statements.splice(index + 1..=index, statements);
+            return;
+        }
+
+        // `var _super = (...args) => (super(...args), ..., this);` replaces the `super()` calls.
+        let name = self.generate_uid("super");
+        let args = self.generate_uid("args");
+        let spread = self.ast.spread_element(SPAN, self.reference(&args));
+        let call = self.ast.call_expression(
+            SPAN,
+            self.ast.super_(SPAN),
+            self.ast.new_vec_single(Argument::SpreadElement(spread)),
+            false,
+            None,
+        );
+        let mut expressions = self.ast.new_vec_single(call);
+        expressions.extend(inits);
+        expressions.push(self.ast.this_expression(SPAN));
+        let sequence = self.ast.sequence_expression(SPAN, expressions);
+        let params = self.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            self.ast.new_vec(),
+            Some(self.ast.rest_element(SPAN, self.binding(args))),
+        );
+        let arrow_body = self.ast.function_body(
+            SPAN,
+            self.ast.new_vec(),
+            self.ast.new_vec_single(self.ast.expression_statement(SPAN, sequence)),
+        );
+        let arrow = self
+            .ast
+            .arrow_expression(SPAN, true, false, false, params, arrow_body, None, None, None);
+        SuperCallReplacer { ast: &self.ast, name: &name }.visit_function_body(body);
+        body.statements.insert(0, self.var_declaration(name, arrow));
+    }
+
+    /// Private names of the class in declaration order, and the brand of private instance methods.
+    ///
+    /// The `WeakMap`s, `WeakSet` or loose keys holding them are added to `output.before`.
+    fn collect_private_names(
+        &self,
+        class: &Class<'a>,
+        output: &mut ClassOutput<'a>,
+        instance_inits: &mut std::vec::Vec<Expression<'a>>,
+    ) -> (PrivateNames, Option<Atom>) {
+        let loose = self.private_fields_as_properties;
+        let mut names = PrivateNames::default();
+        let mut brand = None;
+        for element in &class.body.body {
+            let (name, kind, r#static) = match element {
+                ClassElement::PropertyDefinition(prop) => match &prop.key {
+                    PropertyKey::PrivateIdentifier(ident) => {
+                        (ident.name.clone(), PrivateKind::Field, prop.r#static)
+                    }
+                    _ => continue,
+                },
+                ClassElement::MethodDefinition(method) => match &method.key {
+                    PropertyKey::PrivateIdentifier(ident) => {
+                        let kind = match method.kind {
+                            MethodDefinitionKind::Get | MethodDefinitionKind::Set => {
+                                PrivateKind::Accessor
+                            }
+                            _ => PrivateKind::Method,
+                        };
+                        (ident.name.clone(), kind, method.r#static)
+                    }
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if !names.contains_key(&name) {
+                let id = self.generate_uid(&name);
+                if loose {
+                    output.before.push((id.clone(), self.loose_key(&name)));
+                } else if kind == PrivateKind::Field && !r#static {
+                    output.before.push((id.clone(), self.new_instance("WeakMap")));
+                }
+                let method =
+                    (loose && kind == PrivateKind::Method).then(|| self.generate_uid(&name));
+                names.insert(
+                    name.clone(),
+                    PrivateName { kind, r#static, id, method, getter: None, setter: None },
+                );
+            }
+            if kind != PrivateKind::Field && !r#static && !loose && brand.is_none() {
+                let class_name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+                let name = self.generate_uid(&format!("{class_name}_brand"));
+                output.before.push((name.clone(), self.new_instance("WeakSet")));
+                instance_inits.push(self.helper_call(
+                    Helper::ClassPrivateMethodInitSpec,
+                    [self.ast.this_expression(SPAN), self.reference(&name)],
+                ));
+                brand = Some(name);
+            }
+            if let ClassElement::MethodDefinition(method) = element {
+                let accessor = match method.kind {
+                    MethodDefinitionKind::Get => "get",
+                    MethodDefinitionKind::Set => "set",
+                    _ => continue,
+                };
+                let function = self.generate_uid(&format!("{accessor}_{name}"));
+                if let Some(info) = names.get_mut(&name) {
+                    if method.kind == MethodDefinitionKind::Get {
+                        info.getter = Some(function);
+                    } else {
+                        info.setter = Some(function);
+                    }
+                }
+            }
+        }
+        (names, brand)
+    }
+
+    /// `Object.defineProperty(this, _m, { value: _m2 })`
+    fn define_private_methods(
+        &self,
+        names: &PrivateNames,
+        class_ref: &Atom,
+        output: &mut ClassOutput<'a>,
+        instance_inits: &mut std::vec::Vec<Expression<'a>>,
+    ) {
+        for info in names.values() {
+            let properties = match info.kind {
+                PrivateKind::Field => continue,
+                PrivateKind::Method => {
+                    vec![("value", self.reference(info.method.as_ref().unwrap_or(&info.id)))]
+                }
+                PrivateKind::Accessor => {
+                    let mut properties = vec![];
+                    if let Some(getter) = &info.getter {
+                        properties.push(("get", self.reference(getter)));
+                    }
+                    if let Some(setter) = &info.setter {
+                        properties.push(("set", self.reference(setter)));
+                    }
+                    properties
+                }
+            };
+            let target = self.target(info.r#static, class_ref);
+            let define = self.define_property(target, self.reference(&info.id), properties);
+            if info.r#static {
+                output.statics.push(Static::Expression(define));
+            } else {
+                instance_inits.push(define);
+            }
+        }
+    }
+
+    /// Computed keys are evaluated once before the class, `var _key = expr;`
+    fn field_key(
+        &mut self,
+        key: PropertyKey<'a>,
+        computed: bool,
+        before: &mut std::vec::Vec<(Atom, Expression<'a>)>,
+    ) -> Expression<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => {
+                self.ast.literal_string_expression(StringLiteral::new(SPAN, ident.unbox().name))
+            }
+            PropertyKey::Expression(expr)
+                if computed
+                    && !matches!(
+                        expr,
+                        Expression::StringLiteral(_) | Expression::NumberLiteral(_)
+                    ) =>
+            {
+                let name = self.generate_uid("key");
+                before.push((name.clone(), expr));
+                self.reference(&name)
+            }
+            PropertyKey::Expression(expr) => expr,
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+        }
+    }
+
+    /// `_defineProperty(target, key, value)` or `target.key = value`
+    fn define_field(
+        &self,
+        target: Expression<'a>,
+        key: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        if !self.set_public_class_fields {
+            return self.helper_call(Helper::DefineProperty, [target, key, value]);
+        }
+        let member = match key {
+            Expression::StringLiteral(lit) if is_identifier_name(&lit.value) => {
+                let property = IdentifierName::new(SPAN, lit.value.clone());
+                self.ast.static_member(SPAN, target, property, false)
+            }
+            key => self.ast.computed_member(SPAN, target, key, false),
+        };
+        let left = AssignmentTarget::SimpleAssignmentTarget(
+            SimpleAssignmentTarget::MemberAssignmentTarget(self.ast.alloc(member)),
+        );
+        self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, value)
+    }
+
+    /// `Object.defineProperty(target, key, { ... })`
+    fn define_property(
+        &self,
+        target: Expression<'a>,
+        key: Expression<'a>,
+        properties: std::vec::Vec<(&str, Expression<'a>)>,
+    ) -> Expression<'a> {
+        let callee = self.member(self.reference(&Atom::from("Object")), "defineProperty");
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(target));
+        arguments.push(Argument::Expression(key));
+        arguments.push(Argument::Expression(self.object(properties)));
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `this` for instance members, the class for static members.
+    fn target(&self, r#static: bool, class_ref: &Atom) -> Expression<'a> {
+        if r#static {
+            self.reference(class_ref)
+        } else {
+            self.ast.this_expression(SPAN)
+        }
+    }
+
+    /// `_classPrivateFieldLooseKey("x")`
+    fn loose_key(&self, name: &Atom) -> Expression<'a> {
+        let name = self.ast.literal_string_expression(StringLiteral::new(SPAN, name.clone()));
+        self.helper_call(Helper::ClassPrivateFieldLooseKey, [name])
+    }
+
+    /// `new WeakMap()`
+    fn new_instance(&self, name: &str) -> Expression<'a> {
+        let callee = self.reference(&Atom::from(name));
+        self.ast.new_expression(SPAN, callee, self.ast.new_vec(), None)
+    }
+
+    /// Replace `this` with the class in static initializers.
+    fn replace_this(&self, expr: &mut Expression<'a>, class_ref: &Atom) {
+        ThisReplacer { ast: &self.ast, name: class_ref }.visit_expression(expr);
+    }
+
+    fn helper_call<const N: usize>(
+        &self,
+        helper: Helper,
+        arguments: [Expression<'a>; N],
+    ) -> Expression<'a> {
+        let mut args = self.ast.new_vec_with_capacity(N);
+        args.extend(arguments.into_iter().map(Argument::Expression));
+        self.ctx.helpers.call(helper, args)
+    }
+
+    fn object(&self, properties: std::vec::Vec<(&str, Expression<'a>)>) -> Expression<'a> {
+        let mut props = self.ast.new_vec_with_capacity(properties.len());
+        for (name, value) in properties {
+            let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, Atom::from(name)));
+            let prop = self.ast.object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            props.push(ObjectPropertyKind::ObjectProperty(prop));
+        }
+        self.ast.object_expression(SPAN, props, None)
+    }
+
+    fn member(&self, object: Expression<'a>, name: &str) -> Expression<'a> {
+        let property = IdentifierName::new(SPAN, Atom::from(name));
+        self.ast.static_member_expression(SPAN, object, property, false)
+    }
+
+    fn var_declaration(&self, name: Atom, init: Expression<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator =
+            self.ast.variable_declarator(SPAN, kind, self.binding(name), Some(init), false);
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    /// `name = init`, with `var name` added to the enclosing statements.
+    fn assign_var(&mut self, name: Atom, init: Expression<'a>) -> Expression<'a> {
+        self.create_var(name.clone());
+        self.assign(name, init)
+    }
+
+    fn assign(&self, name: Atom, value: Expression<'a>) -> Expression<'a> {
+        let left = AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_identifier(IdentifierReference::new(SPAN, name)),
+        );
+        self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, left, value)
+    }
+
+    fn binding(&self, name: Atom) -> BindingPattern<'a> {
+        let ident = self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name));
+        self.ast.binding_pattern(ident, None, false)
+    }
+
+    fn reference(&self, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name.clone()))
+    }
+
+    fn generate_uid(&self, name: &str) -> Atom {
+        let name = self.ctx.scopes().generate_uid_based_on_name(name);
+        self.ctx.add_binding(name.clone());
+        name
+    }
+
+    /// Returns `(_obj = obj, _obj)`, or `(obj, obj)` when `obj` is static.
+    fn memoise(&mut self, expr: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if self.ctx.symbols().is_static(&expr) {
+            let reference = self.ast.copy(&expr);
+            return (expr, reference);
+        }
+        let ident = self.create_new_var(&expr);
+        (self.assign(ident.name.clone(), expr), self.reference(&ident.name))
+    }
+}
+
+/// Classes with fields, private methods or private accessors.
+fn needs_transform(class: &Class) -> bool {
+    class.body.body.iter().any(|element| match element {
+        ClassElement::PropertyDefinition(prop) => !prop.declare,
+        ClassElement::MethodDefinition(method) => method.key.is_private_identifier(),
+        _ => false,
+    })
+}
+
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(oxc_syntax::identifier::is_identifier_start_all)
+        && chars.all(oxc_syntax::identifier::is_identifier_part)
+}
+
+/// Replaces the private names of a class.
+struct PrivateReplacer<'a, 'b> {
+    transform: &'b mut ClassProperties<'a>,
+    names: PrivateNames,
+    /// Brand of static private members.
+    class: Atom,
+    /// `_A_brand`, brand of private methods and accessors.
+    brand: Option<Atom>,
+}
+
+impl<'a, 'b> PrivateReplacer<'a, 'b> {
+    fn ast(&self) -> Rc<AstBuilder<'a>> {
+        Rc::clone(&self.transform.ast)
+    }
+
+    fn lookup(&self, member: &MemberExpression<'a>) -> Option<PrivateName> {
+        match member {
+            MemberExpression::PrivateFieldExpression(expr) if !expr.optional => {
+                self.names.get(&expr.field.name).cloned()
+            }
+            _ => None,
+        }
+    }
+
+    /// The brand checked before accessing a private method or accessor.
+    fn brand(&self, info: &PrivateName) -> Atom {
+        if info.r#static {
+            self.class.clone()
+        } else {
+            self.brand.clone().unwrap_or_else(|| self.class.clone())
+        }
+    }
+
+    /// Read a private member.
+    fn get(&self, info: &PrivateName, object: Expression<'a>) -> Expression<'a> {
+        let t = &self.transform;
+        match (info.kind, info.r#static) {
+            // `_classPrivateFieldGet2(_x, obj)`
+            (PrivateKind::Field, false) => {
+                t.helper_call(Helper::ClassPrivateFieldGet2, [t.reference(&info.id), object])
+            }
+            // `_assertClassBrand(A, obj, _x)._`
+            (PrivateKind::Field, true) => {
+                let check = t.helper_call(
+                    Helper::AssertClassBrand,
+                    [t.reference(&self.class), object, t.reference(&info.id)],
+                );
+                t.member(check, "_")
+            }
+            // `_assertClassBrand(_A_brand, obj, _m)`
+            (PrivateKind::Method, _) => t.helper_call(
+                Helper::AssertClassBrand,
+                [t.reference(&self.brand(info)), object, t.reference(&info.id)],
+            ),
+            // `_classPrivateGetter(_A_brand, obj, _get_x)`
+            (PrivateKind::Accessor, _) => {
+                let getter =
+                    info.getter.as_ref().map_or_else(|| t.ast.void_0(), |g| t.reference(g));
+                t.helper_call(
+                    Helper::ClassPrivateGetter,
+                    [t.reference(&self.brand(info)), object, getter],
+                )
+            }
+        }
+    }
+
+    /// Write a private field or accessor.
+    fn set(
+        &self,
+        info: &PrivateName,
+        object: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let t = &self.transform;
+        if info.kind == PrivateKind::Accessor {
+            // `_classPrivateSetter(_A_brand, _set_x, obj, value)`
+            let setter = info.setter.as_ref().map_or_else(|| t.ast.void_0(), |s| t.reference(s));
+            return t.helper_call(
+                Helper::ClassPrivateSetter,
+                [t.reference(&self.brand(info)), setter, object, value],
+            );
+        }
+        // `_classPrivateFieldSet2(_x, obj, value)`
+        t.helper_call(Helper::ClassPrivateFieldSet2, [t.reference(&info.id), object, value])
+    }
+
+    /// A member setting a private name when assigned, for destructuring targets.
+    /// `[obj.#x] = arr` -> `[_toSetter(_classPrivateFieldSet2, [_x, obj])._] = arr`
+    fn destructure_set(
+        &self,
+        name: &Atom,
+        info: &PrivateName,
+        object: Expression<'a>,
+    ) -> MemberExpression<'a> {
+        let t = &self.transform;
+        let (setter, arguments) = match info.kind {
+            PrivateKind::Field => {
+                (Helper::ClassPrivateFieldSet2, vec![t.reference(&info.id), object])
+            }
+            // `_classPrivateSetter(_A_brand, _set_x, obj, value)`
+            PrivateKind::Accessor => {
+                let setter =
+                    info.setter.as_ref().map_or_else(|| t.ast.void_0(), |s| t.reference(s));
+                (Helper::ClassPrivateSetter, vec![t.reference(&self.brand(info)), setter, object])
+            }
+            // Private methods are read-only, `_readOnlyError("#m")`
+            PrivateKind::Method => {
+                let name = t.ast.new_str(&format!("#{name}"));
+                let name = t.ast.literal_string_expression(StringLiteral::new(SPAN, name.into()));
+                (Helper::ReadOnlyError, vec![name])
+            }
+        };
+        let mut elements = t.ast.new_vec_with_capacity(arguments.len());
+        elements.extend(arguments.into_iter().map(ArrayExpressionElement::Expression));
+        let arguments = t.ast.array_expression(SPAN, elements, None);
+        let setter = t.helper_call(Helper::ToSetter, [t.ctx.helpers.helper(setter), arguments]);
+        t.ast.static_member(SPAN, setter, IdentifierName::new(SPAN, Atom::from("_")), false)
+    }
+
+    fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let ast = self.ast();
+        match expr {
+            Expression::PrivateInExpression(private_in) => {
+                let Some(info) = self.names.get(&private_in.left.name).cloned() else { return };
+                let right = ast.move_expression(&mut private_in.right);
+                let t = &self.transform;
+                *expr = if t.private_fields_as_properties {
+                    // `Object.prototype.hasOwnProperty.call(obj, _x)`
+                    let object = t.member(t.reference(&Atom::from("Object")), "prototype");
+                    let callee = t.member(t.member(object, "hasOwnProperty"), "call");
+                    let mut arguments = ast.new_vec_with_capacity(2);
+                    arguments.push(Argument::Expression(right));
+                    arguments.push(Argument::Expression(t.reference(&info.id)));
+                    ast.call_expression(SPAN, callee, arguments, false, None)
+                } else {
+                    let right = t.helper_call(Helper::CheckInRHS, [right]);
+                    if info.r#static {
+                        // `_checkInRHS(obj) === A`
+                        let class = t.reference(&self.class);
+                        ast.binary_expression(SPAN, right, BinaryOperator::StrictEquality, class)
+                    } else {
+                        // `_x.has(_checkInRHS(obj))` or `_A_brand.has(_checkInRHS(obj))`
+                        let brand = if info.kind == PrivateKind::Field {
+                            info.id
+                        } else {
+                            self.brand(&info)
+                        };
+                        let callee = t.member(t.reference(&brand), "has");
+                        let arguments = ast.new_vec_single(Argument::Expression(right));
+                        ast.call_expression(SPAN, callee, arguments, false, None)
+                    }
+                };
+            }
+            // With `privateFieldsAsProperties` private names become regular member expressions.
+            _ if self.transform.private_fields_as_properties => {}
+            // `obj.#x`
+            Expression::MemberExpression(member) => {
+                let Some(info) = self.lookup(member) else { return };
+                if info.kind == PrivateKind::Field && info.r#static {
+                    return;
+                }
+                let object = ast.move_expression(member.object_mut());
+                *expr = self.get(&info, object);
+            }
+            // `obj.#m()` -> `_assertClassBrand(_A_brand, obj, _m).call(obj)`
+            Expression::CallExpression(call) => {
+                let Expression::MemberExpression(member) = &mut call.callee else { return };
+                let Some(info) = self.lookup(member) else { return };
+                let object = ast.move_expression(member.object_mut());
+                let (object, context) = self.transform.memoise(object);
+                let callee = self.get(&info, object);
+                call.callee = self.transform.member(callee, "call");
+                call.arguments.insert(0, Argument::Expression(context));
+            }
+            // `obj.#x = value`
+            Expression::AssignmentExpression(assign) => {
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &mut assign.left
+                else {
+                    return;
+                };
+                let Some(info) = self.lookup(member).filter(is_writable) else { return };
+                let object = ast.move_expression(member.object_mut());
+                let value = ast.move_expression(&mut assign.right);
+                *expr = if let Some(operator) = assign.operator.to_logical_operator() {
+                    // `get(_obj = obj) && set(_obj, value)`
+                    let (object, reference) = self.transform.memoise(object);
+                    let left = self.get(&info, object);
+                    let right = self.set(&info, reference, value);
+                    ast.logical_expression(SPAN, left, operator, right)
+                } else if let Some(operator) = assign.operator.to_binary_operator() {
+                    // `set(_obj = obj, get(_obj) + value)`
+                    let (object, reference) = self.transform.memoise(object);
+                    let left = self.get(&info, reference);
+                    let value = ast.binary_expression(SPAN, left, operator, value);
+                    self.set(&info, object, value)
+                } else {
+                    self.set(&info, object, value)
+                };
+            }
+            // `obj.#x++` -> `(set(_obj = obj, (_x2 = get(_obj), _x3 = _x2++, _x2)), _x3)`
+            Expression::UpdateExpression(update) => {
+                let SimpleAssignmentTarget::MemberAssignmentTarget(member) = &mut update.argument
+                else {
+                    return;
+                };
+                let Some(info) = self.lookup(member).filter(is_writable) else { return };
+                let name = match &**member {
+                    MemberExpression::PrivateFieldExpression(expr) => expr.field.name.clone(),
+                    _ => return,
+                };
+                let object = ast.move_expression(member.object_mut());
+                let (object, reference) = self.transform.memoise(object);
+                let old = self.transform.create_new_named_var(&name);
+                let mut expressions = ast.new_vec();
+                expressions
+                    .push(self.transform.assign(old.name.clone(), self.get(&info, reference)));
+                let argument =
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(ast.alloc(old.clone()));
+                let (operator, prefix) = (update.operator, update.prefix);
+                if prefix {
+                    // `(_x2 = get(_obj), ++_x2)`
+                    expressions.push(ast.update_expression(SPAN, operator, true, argument));
+                    let value = ast.sequence_expression(SPAN, expressions);
+                    *expr = self.set(&info, object, value);
+                } else {
+                    // `(_x2 = get(_obj), _x3 = _x2++, _x2)`
+                    let result = self.transform.create_new_named_var(&name);
+                    let update = ast.update_expression(SPAN, operator, false, argument);
+                    expressions.push(self.transform.assign(result.name.clone(), update));
+                    expressions.push(self.transform.reference(&old.name));
+                    let value = ast.sequence_expression(SPAN, expressions);
+                    let mut expressions = ast.new_vec_with_capacity(2);
+                    expressions.push(self.set(&info, object, value));
+                    expressions.push(self.transform.reference(&result.name));
+                    *expr = ast.sequence_expression(SPAN, expressions);
+                }
+                debug_assert!(matches!(
+                    operator,
+                    UpdateOperator::Increment | UpdateOperator::Decrement
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    /// `obj.#x` -> `_classPrivateFieldLooseBase(obj, _x)[_x]` or `_assertClassBrand(A, obj, _x)._`
+    fn transform_member_expression(&mut self, member: &mut MemberExpression<'a>) {
+        let Some(info) = self.lookup(member) else { return };
+        let ast = self.ast();
+        let t = &self.transform;
+        if t.private_fields_as_properties {
+            let object = ast.move_expression(member.object_mut());
+            let base =
+                t.helper_call(Helper::ClassPrivateFieldLooseBase, [object, t.reference(&info.id)]);
+            *member = ast.computed_member(SPAN, base, t.reference(&info.id), false);
+        } else if info.kind == PrivateKind::Field && info.r#static {
+            let object = ast.move_expression(member.object_mut());
+            let check = t.helper_call(
+                Helper::AssertClassBrand,
+                [t.reference(&self.class), object, t.reference(&info.id)],
+            );
+            *member =
+                ast.static_member(SPAN, check, IdentifierName::new(SPAN, Atom::from("_")), false);
+        }
+    }
+}
+
+/// Private fields and accessors can be written, static fields are written through their `._` member.
+fn is_writable(info: &PrivateName) -> bool {
+    match info.kind {
+        PrivateKind::Field => !info.r#static,
+        PrivateKind::Accessor => true,
+        PrivateKind::Method => false,
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for PrivateReplacer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.transform_expression(expr);
+        self.visit_expression_match(expr);
+    }
+
+    /// Temporary vars are declared in the methods they are used in, not around the class.
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        self.transform.ctx.enter_var_scope(body.scope_id.get());
+        self.transform.enter_var_scope();
+        for directive in body.directives.iter_mut() {
+            self.visit_directive(directive);
+        }
+        self.visit_statements(&mut body.statements);
+        self.transform.exit_var_scope(&mut body.statements);
+        self.transform.ctx.exit_var_scope();
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        self.transform.ctx.enter_var_scope(block.scope_id.get());
+        self.transform.enter_var_scope();
+        self.visit_statements(&mut block.body);
+        self.transform.exit_var_scope(&mut block.body);
+        self.transform.ctx.exit_var_scope();
+    }
+
+    /// `() => a.#x++` -> `() => { var _a; return ...; }`
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        self.visit_formal_parameters(&mut expr.params);
+        self.visit_function_body(&mut expr.body);
+        if expr.expression && expr.body.statements.len() > 1 {
+            expr.expression = false;
+            if let Some(Statement::ExpressionStatement(stmt)) = expr.body.statements.last_mut() {
+                let argument = self.transform.ast.move_expression(&mut stmt.expression);
+                if let Some(last) = expr.body.statements.last_mut() {
+                    *last = self.transform.ast.return_statement(SPAN, Some(argument));
+                }
+            }
+        }
+    }
+
+    /// Private names left in assignment targets are destructuring targets,
+    /// assignments and updates of private names are transformed in [`Self::visit_expression`].
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.visit_identifier_reference(ident);
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+                let name = match &**member {
+                    MemberExpression::PrivateFieldExpression(expr) => Some(expr.field.name.clone()),
+                    _ => None,
+                };
+                // Static fields are written through `_assertClassBrand(A, obj, _x)._`
+                let loose = self.transform.private_fields_as_properties;
+                let info = self
+                    .lookup(member)
+                    .filter(|info| !loose && (info.kind != PrivateKind::Field || !info.r#static));
+                if let (Some(name), Some(info)) = (name, info) {
+                    let mut object = self.ast().move_expression(member.object_mut());
+                    self.visit_expression(&mut object);
+                    **member = self.destructure_set(&name, &info, object);
+                } else {
+                    self.visit_member_expression(member);
+                }
+            }
+            SimpleAssignmentTarget::TSAsExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSSatisfiesExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSNonNullExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSTypeAssertion(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+        }
+    }
+
+    fn visit_member_expression(&mut self, member: &mut MemberExpression<'a>) {
+        self.transform_member_expression(member);
+        match member {
+            MemberExpression::ComputedMemberExpression(expr) => {
+                self.visit_computed_member_expression(expr);
+            }
+            MemberExpression::StaticMemberExpression(expr) => {
+                self.visit_static_member_expression(expr);
+            }
+            MemberExpression::PrivateFieldExpression(expr) => {
+                self.visit_private_field_expression(expr);
+            }
+        }
+    }
+
+    /// Private names declared by a nested class shadow ours.
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        let shadowed = class
+            .body
+            .body
+            .iter()
+            .filter_map(ClassElement::property_key)
+            .filter_map(PropertyKey::private_name)
+            .filter_map(|name| self.names.swap_remove(&name).map(|info| (name, info)))
+            .collect::<std::vec::Vec<_>>();
+        self.visit_class_body(&mut class.body);
+        self.names.extend(shadowed);
+    }
+}
+
+/// Replaces `this` outside of nested functions.
+struct ThisReplacer<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    name: &'b Atom,
+}
+
+impl<'a, 'b> VisitMut<'a> for ThisReplacer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(_) = expr {
+            *expr = self
+                .ast
+                .identifier_reference_expression(IdentifierReference::new(SPAN, self.name.clone()));
+            return;
+        }
+        self.visit_expression_match(expr);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Replaces `super()` with `_super()` in a derived constructor.
+struct SuperCallReplacer<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    name: &'b Atom,
+}
+
+impl<'a, 'b> VisitMut<'a> for SuperCallReplacer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::CallExpression(call) = expr {
+            if matches!(call.callee, Expression::Super(_)) {
+                call.callee = self.ast.identifier_reference_expression(IdentifierReference::new(
+                    SPAN,
+                    self.name.clone(),
+                ));
+            }
+        }
+        self.visit_expression_match(expr);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES2022,
+        class_properties: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        (
+            "class A { x = 1; static y = this; }",
+            "import _defineProperty from '@babel/runtime/helpers/defineProperty';
+            class A { constructor() { _defineProperty(this, 'x', 1); } }
+            _defineProperty(A, 'y', A);",
+        ),
+        (
+            "class A extends B { x; constructor() { foo(); super(); bar(); } }",
+            "import _defineProperty from '@babel/runtime/helpers/defineProperty';
+            class A extends B { constructor() { foo(); super(); _defineProperty(this, 'x', void 0); bar(); } }",
+        ),
+        (
+            "class A extends B { [foo()] = 1; }",
+            "import _defineProperty from '@babel/runtime/helpers/defineProperty';
+            var _key = foo();
+            class A extends B { constructor(..._args) { super(..._args); _defineProperty(this, _key, 1); } }",
+        ),
+        (
+            "class A { #x = 1; get() { return this.#x; } set(v) { this.#x = v; } }",
+            "import _classPrivateFieldGet2 from '@babel/runtime/helpers/classPrivateFieldGet2';
+            import _classPrivateFieldSet2 from '@babel/runtime/helpers/classPrivateFieldSet2';
+            import _classPrivateFieldInitSpec from '@babel/runtime/helpers/classPrivateFieldInitSpec';
+            var _x = new WeakMap();
+            class A {
+                constructor() { _classPrivateFieldInitSpec(this, _x, 1); }
+                get() { return _classPrivateFieldGet2(_x, this); }
+                set(v) { _classPrivateFieldSet2(_x, this, v); }
+            }",
+        ),
+        (
+            "class A { #m() {} static #s = 1; test(o) { this.#m(); A.#s++; return #m in o; } }",
+            "import _classPrivateMethodInitSpec from '@babel/runtime/helpers/classPrivateMethodInitSpec';
+            import _assertClassBrand from '@babel/runtime/helpers/assertClassBrand';
+            import _checkInRHS from '@babel/runtime/helpers/checkInRHS';
+            var _A_brand = new WeakSet();
+            class A {
+                constructor() { _classPrivateMethodInitSpec(this, _A_brand); }
+                test(o) { _assertClassBrand(_A_brand, this, _m).call(this); _assertClassBrand(A, A, _s)._++; return _A_brand.has(_checkInRHS(o)); }
+            }
+            function _m() {}
+            var _s = { _: 1 };",
+        ),
+        (
+            "const A = class { static x = 1; };",
+            "import _defineProperty from '@babel/runtime/helpers/defineProperty';
+            var _Class;
+            const A = (_Class = class {}, _defineProperty(_Class, 'x', 1), _Class);",
+        ),
+        (
+            "class A { #x; set #y(v) {} f(o) { [this.#x, { a: o.#y }] = o; } }",
+            "import _classPrivateMethodInitSpec from '@babel/runtime/helpers/classPrivateMethodInitSpec';
+            import _classPrivateFieldSet2 from '@babel/runtime/helpers/classPrivateFieldSet2';
+            import _toSetter from '@babel/runtime/helpers/toSetter';
+            import _classPrivateSetter from '@babel/runtime/helpers/classPrivateSetter';
+            import _classPrivateFieldInitSpec from '@babel/runtime/helpers/classPrivateFieldInitSpec';
+            var _x = new WeakMap();
+            var _A_brand = new WeakSet();
+            class A {
+                constructor() { _classPrivateMethodInitSpec(this, _A_brand); _classPrivateFieldInitSpec(this, _x, void 0); }
+                f(o) { [_toSetter(_classPrivateFieldSet2, [_x, this])._, { a: _toSetter(_classPrivateSetter, [_A_brand, _set_y, o])._ }] = o; }
+            }
+            function _set_y(v) {}",
+        ),
+        (
+            "class A { #x = 0; f() { return () => this.foo().#x++; } }",
+            "import _classPrivateFieldGet2 from '@babel/runtime/helpers/classPrivateFieldGet2';
+            import _classPrivateFieldSet2 from '@babel/runtime/helpers/classPrivateFieldSet2';
+            import _classPrivateFieldInitSpec from '@babel/runtime/helpers/classPrivateFieldInitSpec';
+            var _x = new WeakMap();
+            class A {
+                constructor() { _classPrivateFieldInitSpec(this, _x, 0); }
+                f() { return () => { var _ref, _x2, _x3; return _classPrivateFieldSet2(_x, _ref = this.foo(), (_x2 = _classPrivateFieldGet2(_x, _ref), _x3 = _x2++, _x2)), _x3; }; }
+            }",
+        ),
+    ];
+    Tester::new("test.mjs", options.clone()).test(tests);
+
+    let mut options = options;
+    options.assumptions.set_public_class_fields = true;
+    options.assumptions.private_fields_as_properties = true;
+    let tests = &[(
+        "class A { x = 1; #y = 2; foo(o) { return this.#y + (#y in o); } }",
+        "import _classPrivateFieldLooseKey from '@babel/runtime/helpers/classPrivateFieldLooseKey';
+        import _classPrivateFieldLooseBase from '@babel/runtime/helpers/classPrivateFieldLooseBase';
+        var _y = _classPrivateFieldLooseKey('y');
+        class A {
+            constructor() { this.x = 1; Object.defineProperty(this, _y, { writable: true, value: 2 }); }
+            foo(o) { return _classPrivateFieldLooseBase(this, _y)[_y] + Object.prototype.hasOwnProperty.call(o, _y); }
+        }",
+    )];
+    Tester::new("test.mjs", options).test(tests);
+}
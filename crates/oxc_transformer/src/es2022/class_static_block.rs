@@ -93,6 +93,7 @@ impl<'a> ClassStaticBlock<'a> {
                         function_body,
                         None,
                         None,
+                        None,
                     );
 
                     let callee = self.ast.parenthesized_expression(SPAN, callee);
@@ -0,0 +1,168 @@
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::SPAN;
+
+use std::rc::Rc;
+
+/// Strip Flow type annotations
+///
+/// Type annotations and type declarations are not printed by the codegen,
+/// only the type imports / exports, `this` parameters, type casts and the class fields which
+/// only declare a type need to be removed.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-flow-strip-types>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-flow-strip-types>
+pub struct Flow<'a> {
+    ast: Rc<AstBuilder<'a>>,
+}
+
+impl<'a> Flow<'a> {
+    pub fn new(ast: Rc<AstBuilder<'a>>) -> Self {
+        Self { ast }
+    }
+
+    /// `function f(this: T) {}` -> `function f() {}`
+    #[allow(clippy::unused_self)]
+    pub fn transform_formal_parameters(&self, params: &mut FormalParameters<'a>) {
+        let Some(param) = params.items.first() else { return };
+        if let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind {
+            if ident.name == "this" {
+                params.items.remove(0);
+            }
+        }
+    }
+
+    /// `class A { +p: T; declare q: U; r: V = 1 }` -> `class A { r = 1 }`
+    ///
+    /// Fields without a value, decorators or a private name only declare the type of the field.
+    #[allow(clippy::unused_self)]
+    pub fn transform_class_body(&self, body: &mut ClassBody<'a>) {
+        body.body.retain(|element| match element {
+            ClassElement::PropertyDefinition(prop) => {
+                !prop.declare
+                    && (prop.value.is_some()
+                        || !prop.decorators.is_empty()
+                        || matches!(prop.key, PropertyKey::PrivateIdentifier(_)))
+            }
+            _ => true,
+        });
+    }
+
+    /// `(x: T)` -> `x`
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        while let Expression::FlowTypeCastExpression(cast) = expr {
+            *expr = self.ast.move_expression(&mut cast.expression);
+        }
+    }
+
+    /// * Remove `import type`, `import typeof` and `export type` statements and specifiers
+    /// * Adds `export {}` if all import / export statements are removed, this is used to tell
+    /// downstream tools that this file is in ESM.
+    ///
+    /// Unlike TypeScript, imports are not elided when they are only used as types.
+    pub fn transform_program(&self, program: &mut Program<'a>) {
+        let mut needs_explicit_esm = false;
+
+        for stmt in program.body.iter_mut() {
+            if let Statement::ModuleDeclaration(module_decl) = stmt {
+                needs_explicit_esm = true;
+                match &mut **module_decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                        decl.specifiers.retain(|specifier| specifier.export_kind.is_value());
+                    }
+                    ModuleDeclaration::ImportDeclaration(decl) if decl.import_kind.is_value() => {
+                        if let Some(specifiers) = &mut decl.specifiers {
+                            let is_empty = specifiers.is_empty();
+                            specifiers.retain(|specifier| match specifier {
+                                ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                                    s.import_kind.is_value()
+                                }
+                                _ => true,
+                            });
+                            // `import { type A } from 'a'` is removed as `import type { A } from 'a'`
+                            if !is_empty && specifiers.is_empty() {
+                                decl.import_kind = ImportOrExportKind::Type;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        program.body.retain(|stmt| match stmt {
+            Statement::ModuleDeclaration(module_decl) => match &**module_decl {
+                // `import 'a'` and `import {} from 'a'` are kept for their side effects
+                ModuleDeclaration::ImportDeclaration(decl) => decl.import_kind.is_value(),
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        return false;
+                    }
+                    if let Some(declaration) = &decl.declaration {
+                        return !declaration.is_typescript_syntax();
+                    }
+                    decl.source.is_some() || !decl.specifiers.is_empty()
+                }
+                _ => true,
+            },
+            _ => true,
+        });
+
+        if needs_explicit_esm
+            && !program.body.iter().any(|s| matches!(s, Statement::ModuleDeclaration(_)))
+        {
+            let empty_export = self.ast.export_named_declaration(
+                SPAN,
+                None,
+                self.ast.new_vec(),
+                None,
+                ImportOrExportKind::Value,
+            );
+            let export_decl = ModuleDeclaration::ExportNamedDeclaration(empty_export);
+            program.body.push(self.ast.module_declaration(export_decl));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use oxc_span::SourceType;
+
+    use crate::{tester::Tester, TransformOptions};
+
+    let source_type = SourceType::default().with_module(true).with_flow(true);
+    let tests = &[
+        (
+            "import type { A } from 'a'; import typeof B from 'b'; import { typeof C, type D, E } from 'c'; E;",
+            "import { E } from 'c'; E;",
+        ),
+        ("import {} from './side'; import { type A } from 'a';", "import {} from './side';"),
+        // Imports are kept even if they are only used as types
+        ("import { A } from 'a'; let a: A;", "import { A } from 'a'; let a;"),
+        (
+            "export type T = ?string; export opaque type O: string = string; export const x: ?O = null;",
+            "export const x = null;",
+        ),
+        ("import type { A } from 'a'; type B = {| a: A |};", "export {};"),
+        (
+            "function f(this: Foo, x: ?number, y?: Array<?T>): {| a: string |} | null { return null; }",
+            "function f(x, y) { return null; }",
+        ),
+        ("class K<T> { prop: T; method(): void {} }", "class K { method() {} }"),
+        (
+            "class L { +p: T; -q: number; #r: R; s: S = 1; declare t: T; declare static u: U; }",
+            "class L { #r; s = 1; }",
+        ),
+        ("let a = (b: any); f((c: string), [(d: ?number)]);", "let a = b; f(c, [d]);"),
+        (
+            "function f(x: mixed): boolean %checks { return !!x; } const g = (x: mixed): %checks => !!x;",
+            "function f(x) { return !!x; } const g = (x) => !!x;",
+        ),
+        (
+            "type A = { +a: string, -b: number, ...B, ... }; class C { +a: string; static -b = 1; }",
+            "class C { static b = 1; }",
+        ),
+        ("declare module.exports: { a: string }; module.exports = { a: '' };", "module.exports = { a: '' };"),
+    ];
+    Tester::new_with_source_type(source_type, TransformOptions::default()).test(tests);
+}
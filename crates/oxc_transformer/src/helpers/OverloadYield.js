@@ -0,0 +1,4 @@
+function _OverloadYield(value, kind) {
+  this.v = value;
+  this.k = kind;
+}
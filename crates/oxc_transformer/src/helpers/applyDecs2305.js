@@ -0,0 +1,168 @@
+function _applyDecs2305(targetClass, memberDecs, classDecs, classDecsHaveThis, instanceBrand, parentClass) {
+  var symbolMetadata = Symbol.metadata || Symbol.for("Symbol.metadata");
+  var metadata = Object.create(parentClass == null ? null : parentClass[symbolMetadata] || null);
+  var kinds = ["field", "accessor", "method", "getter", "setter", "class"];
+  var ret = [];
+  var protoInitializers, staticInitializers;
+
+  function assertCallable(fn, hint) {
+    if (typeof fn !== "function") {
+      throw new TypeError(hint + " must be a function");
+    }
+    return fn;
+  }
+
+  function runInitializers(initializers, value) {
+    for (var i = 0; i < initializers.length; i++) {
+      initializers[i].call(value);
+    }
+    return value;
+  }
+
+  function addInitializer(initializers, finished, initializer) {
+    if (finished.v) {
+      throw new Error("attempted to call addInitializer after decoration was finished");
+    }
+    initializers.push(assertCallable(initializer, "An initializer"));
+  }
+
+  function decorate(decs, decsHaveThis, ctx, initializers, getValue, setValue) {
+    if (!decsHaveThis && !Array.isArray(decs)) decs = [decs];
+    for (var i = decs.length - 1; i >= 0; i -= decsHaveThis ? 2 : 1) {
+      var finished = { v: false };
+      ctx.addInitializer = addInitializer.bind(null, initializers, finished);
+      var result;
+      try {
+        result = decs[i].call(decsHaveThis ? decs[i - 1] : void 0, getValue(), ctx);
+      } finally {
+        finished.v = true;
+      }
+      if (result !== void 0) setValue(result);
+    }
+  }
+
+  function initField(inits, initializers) {
+    return function (instance, value) {
+      for (var i = inits.length - 1; i >= 0; i--) {
+        value = inits[i].call(instance, value);
+      }
+      runInitializers(initializers, instance);
+      return value;
+    };
+  }
+
+  function applyMemberDec(decInfo) {
+    var flags = decInfo[1];
+    var kind = flags & 7;
+    var isStatic = !!(flags & 8);
+    var decsHaveThis = !!(flags & 16);
+    var isPrivate = decInfo.length > 3;
+    var name = isPrivate ? "#" + decInfo[2] : decInfo[2];
+    var base = isStatic ? targetClass : targetClass.prototype;
+    var initializers = kind === 0
+      ? []
+      : isStatic
+        ? staticInitializers = staticInitializers || []
+        : protoInitializers = protoInitializers || [];
+
+    var desc;
+    if (isPrivate) {
+      desc = kind < 2
+        ? { get: function () { return decInfo[3](this); }, set: function (v) { decInfo[4](this, v); } }
+        : kind === 2 ? { value: decInfo[3] } : kind === 3 ? { get: decInfo[3] } : { set: decInfo[3] };
+    } else if (kind !== 0) {
+      desc = Object.getOwnPropertyDescriptor(base, name);
+    }
+
+    var access = {};
+    if (isPrivate) {
+      access.has = isStatic ? function (target) { return target === targetClass; } : instanceBrand;
+      if (kind === 2) {
+        access.get = function () { return desc.value; };
+      } else {
+        if (kind !== 4) access.get = function (target) { return desc.get.call(target); };
+        if (kind !== 3) access.set = function (target, value) { desc.set.call(target, value); };
+      }
+    } else {
+      access.has = function (target) { return name in target; };
+      if (kind !== 4) access.get = function (target) { return target[name]; };
+      if (kind < 2 || kind === 4) access.set = function (target, value) { target[name] = value; };
+    }
+    var ctx = {
+      kind: kinds[kind],
+      name: name,
+      static: isStatic,
+      private: isPrivate,
+      access: access,
+      metadata: metadata
+    };
+
+    if (kind === 0) {
+      var inits = [];
+      decorate(decInfo[0], decsHaveThis, ctx, initializers, function () {}, function (init) {
+        inits.push(assertCallable(init, "field decorators"));
+      });
+      ret.push(initField(inits, initializers));
+    } else if (kind === 1) {
+      var accessorInits = [];
+      decorate(decInfo[0], decsHaveThis, ctx, initializers, function () {
+        return { get: desc.get, set: desc.set };
+      }, function (result) {
+        if (typeof result !== "object" || result === null) {
+          throw new TypeError("accessor decorators must return an object with get, set, or init properties or void 0");
+        }
+        if (result.get !== void 0) desc.get = assertCallable(result.get, "accessor.get");
+        if (result.set !== void 0) desc.set = assertCallable(result.set, "accessor.set");
+        if (result.init !== void 0) accessorInits.push(assertCallable(result.init, "accessor.init"));
+      });
+      ret.push(initField(accessorInits, []));
+      if (isPrivate) {
+        ret.push(function (instance) { return desc.get.call(instance); });
+        ret.push(function (instance, value) { desc.set.call(instance, value); });
+      } else {
+        Object.defineProperty(base, name, desc);
+      }
+    } else {
+      var key = kind === 2 ? "value" : kind === 3 ? "get" : "set";
+      decorate(decInfo[0], decsHaveThis, ctx, initializers, function () {
+        return desc[key];
+      }, function (result) {
+        desc[key] = assertCallable(result, kinds[kind] + " decorators");
+      });
+      if (!isPrivate) {
+        Object.defineProperty(base, name, desc);
+      } else if (kind === 2) {
+        ret.push(desc.value);
+      } else if (kind === 3) {
+        ret.push(function (instance) { return desc.get.call(instance); });
+      } else {
+        ret.push(function (instance, value) { desc.set.call(instance, value); });
+      }
+    }
+  }
+
+  for (var i = 0; i < memberDecs.length; i++) {
+    applyMemberDec(memberDecs[i]);
+  }
+  if (protoInitializers) ret.push(runInitializers.bind(null, protoInitializers));
+  if (staticInitializers) ret.push(runInitializers.bind(null, staticInitializers));
+  Object.defineProperty(targetClass, symbolMetadata, { configurable: true, enumerable: true, value: metadata });
+
+  return {
+    e: ret,
+    get c() {
+      if (!classDecs.length) return [];
+      var newClass = targetClass;
+      var classInitializers = [];
+      var ctx = { kind: "class", name: targetClass.name, metadata: metadata };
+      decorate(classDecs, classDecsHaveThis, ctx, classInitializers, function () {
+        return newClass;
+      }, function (result) {
+        newClass = assertCallable(result, "class decorators");
+      });
+      return [newClass, function () {
+        runInitializers(classInitializers, newClass);
+      }];
+    }
+  };
+}
@@ -0,0 +1,3 @@
+function _arrayWithHoles(arr) {
+  if (Array.isArray(arr)) return arr;
+}
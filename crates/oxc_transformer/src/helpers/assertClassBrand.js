@@ -0,0 +1,6 @@
+function _assertClassBrand(brand, receiver, value) {
+  if (typeof brand === "function" ? brand === receiver : brand.has(receiver)) {
+    return arguments.length < 3 ? receiver : value;
+  }
+  throw new TypeError("Private element is not present on this object");
+}
@@ -0,0 +1,55 @@
+function _asyncIterator(iterable) {
+  var method,
+    async,
+    sync,
+    retry = 2;
+  if (typeof Symbol !== "undefined") {
+    async = Symbol.asyncIterator;
+    sync = Symbol.iterator;
+  }
+  while (retry--) {
+    if (async && (method = iterable[async]) != null) {
+      return method.call(iterable);
+    }
+    if (sync && (method = iterable[sync]) != null) {
+      return new _AsyncFromSyncIterator(method.call(iterable));
+    }
+    async = "@@asyncIterator";
+    sync = "@@iterator";
+  }
+  throw new TypeError("Object is not async iterable");
+}
+function _AsyncFromSyncIterator(s) {
+  this.s = s;
+  this.n = s.next;
+}
+function _AsyncFromSyncIteratorContinuation(r) {
+  if (Object(r) !== r) {
+    return Promise.reject(new TypeError(r + " is not an object."));
+  }
+  var done = r.done;
+  return Promise.resolve(r.value).then(function (value) {
+    return { value: value, done: done };
+  });
+}
+_AsyncFromSyncIterator.prototype = {
+  s: null,
+  n: null,
+  next: function () {
+    return _AsyncFromSyncIteratorContinuation(this.n.apply(this.s, arguments));
+  },
+  return: function (value) {
+    var ret = this.s.return;
+    if (ret === undefined) {
+      return Promise.resolve({ value: value, done: true });
+    }
+    return _AsyncFromSyncIteratorContinuation(ret.apply(this.s, arguments));
+  },
+  throw: function (value) {
+    var thr = this.s.return;
+    if (thr === undefined) {
+      return Promise.reject(value);
+    }
+    return _AsyncFromSyncIteratorContinuation(thr.apply(this.s, arguments));
+  },
+};
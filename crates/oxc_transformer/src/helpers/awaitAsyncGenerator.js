@@ -0,0 +1,3 @@
+function _awaitAsyncGenerator(value) {
+  return new _OverloadYield(value, 0);
+}
@@ -0,0 +1,3 @@
+function _classPrivateFieldGet2(privateMap, receiver) {
+  return privateMap.get(_assertClassBrand(privateMap, receiver));
+}
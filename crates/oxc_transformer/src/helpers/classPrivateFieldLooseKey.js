@@ -0,0 +1,4 @@
+var _classPrivateFieldLooseKeyId = 0;
+function _classPrivateFieldLooseKey(name) {
+  return "__private_" + _classPrivateFieldLooseKeyId++ + "_" + name;
+}
@@ -0,0 +1,4 @@
+function _classPrivateFieldSet2(privateMap, receiver, value) {
+  privateMap.set(_assertClassBrand(privateMap, receiver), value);
+  return value;
+}
@@ -0,0 +1,3 @@
+function _classPrivateGetter(brand, receiver, getter) {
+  return getter(_assertClassBrand(brand, receiver));
+}
@@ -0,0 +1,4 @@
+function _classPrivateSetter(brand, setter, receiver, value) {
+  setter(_assertClassBrand(brand, receiver), value);
+  return value;
+}
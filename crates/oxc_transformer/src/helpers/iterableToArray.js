@@ -0,0 +1,5 @@
+function _iterableToArray(iter) {
+  if ((typeof Symbol !== "undefined" && iter[Symbol.iterator] != null) || iter["@@iterator"] != null) {
+    return Array.from(iter);
+  }
+}
@@ -0,0 +1,33 @@
+function _iterableToArrayLimit(arr, i) {
+  var it = arr == null ? null : (typeof Symbol !== "undefined" && arr[Symbol.iterator]) || arr["@@iterator"];
+  if (it == null) return;
+  var result = [];
+  var done = true;
+  var didErr = false;
+  var err, step, ret;
+  try {
+    var next = (it = it.call(arr)).next;
+    if (i === 0) {
+      if (Object(it) !== it) return;
+      done = false;
+    } else {
+      for (; !(done = (step = next.call(it)).done); done = true) {
+        result.push(step.value);
+        if (result.length === i) break;
+      }
+    }
+  } catch (e) {
+    didErr = true;
+    err = e;
+  } finally {
+    try {
+      if (!done && it.return != null) {
+        ret = it.return();
+        if (Object(ret) !== ret) return;
+      }
+    } finally {
+      if (didErr) throw err;
+    }
+  }
+  return result;
+}
@@ -0,0 +1,699 @@
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SymbolFlags};
+use oxc_span::{Atom, SourceType, SPAN};
+use serde::Deserialize;
+
+/// How the runtime helpers (`_asyncToGenerator`, `_objectSpread2` ...) are provided.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HelperLoaderMode {
+    /// Helpers are defined at the top of each file.
+    #[default]
+    Inline,
+    /// Helpers are imported from `{module_name}/helpers/{name}`, e.g. `@babel/runtime/helpers/asyncToGenerator`.
+    ///
+    /// `@swc/helpers` is laid out differently, helpers are imported from `@swc/helpers/_/_async_to_generator`.
+    ///
+    /// The helpers which the runtime does not have are inlined.
+    Runtime,
+    /// Helpers are properties of a global object, e.g. `babelHelpers.asyncToGenerator`.
+    ///
+    /// The global object has the helpers of `@babel/runtime`, the other helpers are inlined.
+    External,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelperLoaderOptions {
+    #[serde(default)]
+    pub mode: HelperLoaderMode,
+    /// The runtime module to import helpers from. default to `@babel/runtime`
+    #[serde(default = "default_module_name")]
+    pub module_name: Cow<'static, str>,
+    /// The global object of the helpers in [`HelperLoaderMode::External`]. default to `babelHelpers`
+    #[serde(default = "default_global_name")]
+    pub global_name: Cow<'static, str>,
+}
+
+fn default_module_name() -> Cow<'static, str> {
+    Cow::Borrowed("@babel/runtime")
+}
+
+fn default_global_name() -> Cow<'static, str> {
+    Cow::Borrowed("babelHelpers")
+}
+
+impl Default for HelperLoaderOptions {
+    fn default() -> Self {
+        Self {
+            mode: HelperLoaderMode::default(),
+            module_name: default_module_name(),
+            global_name: default_global_name(),
+        }
+    }
+}
+
+/// The runtime modules the helpers are imported from, or the global object of
+/// [`HelperLoaderMode::External`] which has the helpers of `@babel/runtime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Runtime {
+    Babel,
+    Swc,
+}
+
+/// A runtime helper, named after the `@babel/runtime/helpers/*` module it is imported from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Helper {
+    ApplyDecs2305,
+    ArrayLikeToArray,
+    ArrayWithHoles,
+    ArrayWithoutHoles,
+    AssertClassBrand,
+    AsyncGeneratorDelegate,
+    AsyncIterator,
+    AsyncToGenerator,
+    AwaitAsyncGenerator,
+    CheckInRHS,
+    CheckPrivateRedeclaration,
+    ClassPrivateFieldGet2,
+    ClassPrivateFieldInitSpec,
+    ClassPrivateFieldLooseBase,
+    ClassPrivateFieldLooseKey,
+    ClassPrivateFieldSet2,
+    ClassPrivateGetter,
+    ClassPrivateMethodInitSpec,
+    ClassPrivateSetter,
+    CreateForOfIteratorHelper,
+    CreateForOfIteratorHelperLoose,
+    DefineProperty,
+    Extends,
+    Inherits,
+    InteropRequireDefault,
+    InteropRequireWildcard,
+    IterableToArray,
+    IterableToArrayLimit,
+    NonIterableRest,
+    NonIterableSpread,
+    ObjectSpread2,
+    ObjectWithoutProperties,
+    ObjectWithoutPropertiesLoose,
+    OverloadYield,
+    ReadOnlyError,
+    RegeneratorRuntime,
+    SetPrototypeOf,
+    SlicedToArray,
+    ToArray,
+    ToConsumableArray,
+    ToSetter,
+    TsDecorate,
+    TsMetadata,
+    TsParam,
+    UnsupportedIterableToArray,
+    WrapAsyncGenerator,
+    WrapRegExp,
+}
+
+impl Helper {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ApplyDecs2305 => "applyDecs2305",
+            Self::ArrayLikeToArray => "arrayLikeToArray",
+            Self::ArrayWithHoles => "arrayWithHoles",
+            Self::ArrayWithoutHoles => "arrayWithoutHoles",
+            Self::AssertClassBrand => "assertClassBrand",
+            Self::AsyncGeneratorDelegate => "asyncGeneratorDelegate",
+            Self::AsyncIterator => "asyncIterator",
+            Self::AsyncToGenerator => "asyncToGenerator",
+            Self::AwaitAsyncGenerator => "awaitAsyncGenerator",
+            Self::CheckInRHS => "checkInRHS",
+            Self::CheckPrivateRedeclaration => "checkPrivateRedeclaration",
+            Self::ClassPrivateFieldGet2 => "classPrivateFieldGet2",
+            Self::ClassPrivateFieldInitSpec => "classPrivateFieldInitSpec",
+            Self::ClassPrivateFieldLooseBase => "classPrivateFieldLooseBase",
+            Self::ClassPrivateFieldLooseKey => "classPrivateFieldLooseKey",
+            Self::ClassPrivateFieldSet2 => "classPrivateFieldSet2",
+            Self::ClassPrivateGetter => "classPrivateGetter",
+            Self::ClassPrivateMethodInitSpec => "classPrivateMethodInitSpec",
+            Self::ClassPrivateSetter => "classPrivateSetter",
+            Self::CreateForOfIteratorHelper => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorHelperLoose => "createForOfIteratorHelperLoose",
+            Self::DefineProperty => "defineProperty",
+            Self::Extends => "extends",
+            Self::Inherits => "inherits",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
+            Self::IterableToArray => "iterableToArray",
+            Self::IterableToArrayLimit => "iterableToArrayLimit",
+            Self::NonIterableRest => "nonIterableRest",
+            Self::NonIterableSpread => "nonIterableSpread",
+            Self::ObjectSpread2 => "objectSpread2",
+            Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::ObjectWithoutPropertiesLoose => "objectWithoutPropertiesLoose",
+            Self::OverloadYield => "OverloadYield",
+            Self::ReadOnlyError => "readOnlyError",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::SetPrototypeOf => "setPrototypeOf",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::ToSetter => "toSetter",
+            Self::TsDecorate => "tsDecorate",
+            Self::TsMetadata => "tsMetadata",
+            Self::TsParam => "tsParam",
+            Self::UnsupportedIterableToArray => "unsupportedIterableToArray",
+            Self::WrapAsyncGenerator => "wrapAsyncGenerator",
+            Self::WrapRegExp => "wrapRegExp",
+        }
+    }
+
+    /// The name of the helper module in `runtime`,
+    /// `None` when the runtime does not have the helper and it is inlined instead.
+    fn runtime_name(self, runtime: Runtime) -> Option<&'static str> {
+        match runtime {
+            Runtime::Babel => self.babel_name(),
+            Runtime::Swc => self.swc_name(),
+        }
+    }
+
+    /// The name of the helper in `@babel/runtime`, which has all helpers except the `ts*` ones.
+    fn babel_name(self) -> Option<&'static str> {
+        match self {
+            Self::TsDecorate | Self::TsMetadata | Self::TsParam => None,
+            _ => Some(self.name()),
+        }
+    }
+
+    /// The name of the helper in `@swc/helpers`, e.g. `_async_to_generator`.
+    ///
+    /// `@swc/helpers` does not have the helpers added in Babel 7.24 for private elements and
+    /// async generators, and its own helpers for them take different arguments.
+    fn swc_name(self) -> Option<&'static str> {
+        let name = match self {
+            Self::ArrayLikeToArray => "_array_like_to_array",
+            Self::ArrayWithHoles => "_array_with_holes",
+            Self::ArrayWithoutHoles => "_array_without_holes",
+            Self::AsyncIterator => "_async_iterator",
+            Self::AsyncToGenerator => "_async_to_generator",
+            Self::CheckInRHS => "_check_in_rhs",
+            Self::CheckPrivateRedeclaration => "_check_private_redeclaration",
+            Self::ClassPrivateFieldLooseBase => "_class_private_field_loose_base",
+            Self::ClassPrivateFieldLooseKey => "_class_private_field_loose_key",
+            Self::CreateForOfIteratorHelperLoose => "_create_for_of_iterator_helper_loose",
+            Self::DefineProperty => "_define_property",
+            Self::Extends => "_extends",
+            Self::Inherits => "_inherits",
+            Self::InteropRequireDefault => "_interop_require_default",
+            Self::InteropRequireWildcard => "_interop_require_wildcard",
+            Self::IterableToArray => "_iterable_to_array",
+            Self::IterableToArrayLimit => "_iterable_to_array_limit",
+            Self::NonIterableRest => "_non_iterable_rest",
+            Self::NonIterableSpread => "_non_iterable_spread",
+            Self::ObjectSpread2 => "_object_spread",
+            Self::ObjectWithoutProperties => "_object_without_properties",
+            Self::ObjectWithoutPropertiesLoose => "_object_without_properties_loose",
+            Self::ReadOnlyError => "_read_only_error",
+            Self::SetPrototypeOf => "_set_prototype_of",
+            Self::SlicedToArray => "_sliced_to_array",
+            Self::ToArray => "_to_array",
+            Self::ToConsumableArray => "_to_consumable_array",
+            Self::TsDecorate => "_ts_decorate",
+            Self::TsMetadata => "_ts_metadata",
+            Self::TsParam => "_ts_param",
+            Self::UnsupportedIterableToArray => "_unsupported_iterable_to_array",
+            Self::ApplyDecs2305
+            | Self::AssertClassBrand
+            | Self::AsyncGeneratorDelegate
+            | Self::AwaitAsyncGenerator
+            | Self::ClassPrivateFieldGet2
+            | Self::ClassPrivateFieldInitSpec
+            | Self::ClassPrivateFieldSet2
+            | Self::ClassPrivateGetter
+            | Self::ClassPrivateMethodInitSpec
+            | Self::ClassPrivateSetter
+            | Self::CreateForOfIteratorHelper
+            | Self::OverloadYield
+            | Self::RegeneratorRuntime
+            | Self::ToSetter
+            | Self::WrapAsyncGenerator
+            | Self::WrapRegExp => return None,
+        };
+        Some(name)
+    }
+
+    /// Helpers which must be defined along with this one when inlined.
+    fn dependencies(self) -> &'static [Self] {
+        match self {
+            Self::ArrayWithoutHoles | Self::UnsupportedIterableToArray => &[Self::ArrayLikeToArray],
+            Self::AsyncGeneratorDelegate | Self::AwaitAsyncGenerator | Self::WrapAsyncGenerator => {
+                &[Self::OverloadYield]
+            }
+            Self::ClassPrivateFieldGet2
+            | Self::ClassPrivateFieldSet2
+            | Self::ClassPrivateGetter
+            | Self::ClassPrivateSetter => &[Self::AssertClassBrand],
+            Self::ClassPrivateFieldInitSpec | Self::ClassPrivateMethodInitSpec => {
+                &[Self::CheckPrivateRedeclaration]
+            }
+            Self::CreateForOfIteratorHelper | Self::CreateForOfIteratorHelperLoose => {
+                &[Self::UnsupportedIterableToArray]
+            }
+            Self::Inherits => &[Self::SetPrototypeOf],
+            Self::ObjectSpread2 => &[Self::DefineProperty],
+            Self::ObjectWithoutProperties => &[Self::ObjectWithoutPropertiesLoose],
+            Self::SlicedToArray => &[
+                Self::ArrayWithHoles,
+                Self::IterableToArrayLimit,
+                Self::UnsupportedIterableToArray,
+                Self::NonIterableRest,
+            ],
+            Self::ToArray => &[
+                Self::ArrayWithHoles,
+                Self::IterableToArray,
+                Self::UnsupportedIterableToArray,
+                Self::NonIterableRest,
+            ],
+            Self::ToConsumableArray => &[
+                Self::ArrayWithoutHoles,
+                Self::IterableToArray,
+                Self::UnsupportedIterableToArray,
+                Self::NonIterableSpread,
+            ],
+            Self::WrapRegExp => &[Self::Inherits, Self::SetPrototypeOf],
+            _ => &[],
+        }
+    }
+
+    /// Source of the inlined helper, taken from `@babel/helpers`.
+    /// The `ts*` helpers are the `__decorate`, `__metadata` and `__param` helpers of `tslib`.
+    fn source(self) -> &'static str {
+        match self {
+            Self::ApplyDecs2305 => include_str!("applyDecs2305.js"),
+            Self::ArrayLikeToArray => include_str!("arrayLikeToArray.js"),
+            Self::ArrayWithHoles => include_str!("arrayWithHoles.js"),
+            Self::ArrayWithoutHoles => include_str!("arrayWithoutHoles.js"),
+            Self::AssertClassBrand => include_str!("assertClassBrand.js"),
+            Self::AsyncGeneratorDelegate => include_str!("asyncGeneratorDelegate.js"),
+            Self::AsyncIterator => include_str!("asyncIterator.js"),
+            Self::AsyncToGenerator => include_str!("asyncToGenerator.js"),
+            Self::AwaitAsyncGenerator => include_str!("awaitAsyncGenerator.js"),
+            Self::CheckInRHS => include_str!("checkInRHS.js"),
+            Self::CheckPrivateRedeclaration => include_str!("checkPrivateRedeclaration.js"),
+            Self::ClassPrivateFieldGet2 => include_str!("classPrivateFieldGet2.js"),
+            Self::ClassPrivateFieldInitSpec => include_str!("classPrivateFieldInitSpec.js"),
+            Self::ClassPrivateFieldLooseBase => include_str!("classPrivateFieldLooseBase.js"),
+            Self::ClassPrivateFieldLooseKey => include_str!("classPrivateFieldLooseKey.js"),
+            Self::ClassPrivateFieldSet2 => include_str!("classPrivateFieldSet2.js"),
+            Self::ClassPrivateGetter => include_str!("classPrivateGetter.js"),
+            Self::ClassPrivateMethodInitSpec => include_str!("classPrivateMethodInitSpec.js"),
+            Self::ClassPrivateSetter => include_str!("classPrivateSetter.js"),
+            Self::CreateForOfIteratorHelper => include_str!("createForOfIteratorHelper.js"),
+            Self::CreateForOfIteratorHelperLoose => {
+                include_str!("createForOfIteratorHelperLoose.js")
+            }
+            Self::DefineProperty => include_str!("defineProperty.js"),
+            Self::Extends => include_str!("extends.js"),
+            Self::Inherits => include_str!("inherits.js"),
+            Self::InteropRequireDefault => include_str!("interopRequireDefault.js"),
+            Self::InteropRequireWildcard => include_str!("interopRequireWildcard.js"),
+            Self::IterableToArray => include_str!("iterableToArray.js"),
+            Self::IterableToArrayLimit => include_str!("iterableToArrayLimit.js"),
+            Self::NonIterableRest => include_str!("nonIterableRest.js"),
+            Self::NonIterableSpread => include_str!("nonIterableSpread.js"),
+            Self::ObjectSpread2 => include_str!("objectSpread2.js"),
+            Self::ObjectWithoutProperties => include_str!("objectWithoutProperties.js"),
+            Self::ObjectWithoutPropertiesLoose => {
+                include_str!("objectWithoutPropertiesLoose.js")
+            }
+            Self::OverloadYield => include_str!("OverloadYield.js"),
+            Self::ReadOnlyError => include_str!("readOnlyError.js"),
+            Self::RegeneratorRuntime => include_str!("regeneratorRuntime.js"),
+            Self::SetPrototypeOf => include_str!("setPrototypeOf.js"),
+            Self::SlicedToArray => include_str!("slicedToArray.js"),
+            Self::ToArray => include_str!("toArray.js"),
+            Self::ToConsumableArray => include_str!("toConsumableArray.js"),
+            Self::ToSetter => include_str!("toSetter.js"),
+            Self::TsDecorate => include_str!("tsDecorate.js"),
+            Self::TsMetadata => include_str!("tsMetadata.js"),
+            Self::TsParam => include_str!("tsParam.js"),
+            Self::UnsupportedIterableToArray => include_str!("unsupportedIterableToArray.js"),
+            Self::WrapAsyncGenerator => include_str!("wrapAsyncGenerator.js"),
+            Self::WrapRegExp => include_str!("wrapRegExp.js"),
+        }
+    }
+}
+
+/// Collects the helpers used by the transforms and adds them to the program.
+#[derive(Clone)]
+pub struct HelperLoader<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    semantic: Rc<RefCell<Semantic<'a>>>,
+    options: HelperLoaderOptions,
+    /// The used helpers and their local names.
+    used: Rc<RefCell<std::vec::Vec<(Helper, Atom)>>>,
+}
+
+impl<'a> HelperLoader<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        semantic: Rc<RefCell<Semantic<'a>>>,
+        options: HelperLoaderOptions,
+    ) -> Self {
+        Self { ast, semantic, options, used: Rc::default() }
+    }
+
+    /// The runtime the helpers are taken from, `None` when they are inlined.
+    fn runtime(&self) -> Option<Runtime> {
+        match self.options.mode {
+            HelperLoaderMode::Inline => None,
+            HelperLoaderMode::Runtime if self.options.module_name.starts_with("@swc/helpers") => {
+                Some(Runtime::Swc)
+            }
+            HelperLoaderMode::Runtime | HelperLoaderMode::External => Some(Runtime::Babel),
+        }
+    }
+
+    /// Whether `helper` is defined in the program,
+    /// the helpers which are not in the runtime are inlined as well.
+    fn is_inlined(&self, helper: Helper) -> bool {
+        self.runtime().map_or(true, |runtime| helper.runtime_name(runtime).is_none())
+    }
+
+    /// `_asyncToGenerator`, or `babelHelpers.asyncToGenerator` for external helpers.
+    pub fn helper(&self, helper: Helper) -> Expression<'a> {
+        let name = self.local_name(helper);
+        if self.options.mode == HelperLoaderMode::External && !self.is_inlined(helper) {
+            let object = self.ast.identifier_reference_expression(IdentifierReference::new(
+                SPAN,
+                Atom::from(self.options.global_name.as_ref()),
+            ));
+            let property = IdentifierName::new(SPAN, helper.name().into());
+            return self.ast.static_member_expression(SPAN, object, property, false);
+        }
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name))
+    }
+
+    /// The local name of `helper`, `_asyncToGenerator2` when `_asyncToGenerator` is already bound.
+    fn local_name(&self, helper: Helper) -> Atom {
+        if let Some((_, name)) = self.used.borrow().iter().find(|(used, _)| *used == helper) {
+            return name.clone();
+        }
+        // Helpers are declared at the top level
+        let mut semantic = self.semantic.borrow_mut();
+        let name = semantic.scopes().generate_uid_based_on_name(helper.name());
+        let scope_id = semantic.scopes().root_scope_id();
+        let flag = SymbolFlags::FunctionScopedVariable;
+        let symbol_id =
+            semantic.symbols_mut().create_synthetic_symbol(name.clone(), flag, scope_id);
+        semantic.scopes_mut().add_binding(scope_id, name.clone(), symbol_id);
+        self.used.borrow_mut().push((helper, name.clone()));
+        name
+    }
+
+    /// `_asyncToGenerator(arguments)`
+    pub fn call(&self, helper: Helper, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        self.ast.call_expression(SPAN, self.helper(helper), arguments, false, None)
+    }
+
+    pub fn add_helpers(&self, program: &mut Program<'a>) {
+        self.add_dependencies();
+        let used = std::mem::take(&mut *self.used.borrow_mut());
+        let mut statements = match self.options.mode {
+            HelperLoaderMode::Runtime => self.import_helpers(&used, program.source_type),
+            // Provided by the environment
+            HelperLoaderMode::Inline | HelperLoaderMode::External => self.ast.new_vec(),
+        };
+        statements.extend(self.inline_helpers(&used));
+        if statements.is_empty() {
+            return;
+        }
+        let index = program
+            .body
+            .iter()
+            .rposition(|stmt| matches!(stmt, Statement::ModuleDeclaration(m) if m.is_import()))
+            .map_or(0, |i| i + 1);
+        program.body.splice(index..index, statements);
+    }
+
+    /// Inlined helpers are defined along with their dependencies.
+    fn add_dependencies(&self) {
+        let mut i = 0;
+        loop {
+            let Some(helper) = self.used.borrow().get(i).map(|(helper, _)| *helper) else { break };
+            if self.is_inlined(helper) {
+                for dependency in helper.dependencies() {
+                    self.local_name(*dependency);
+                }
+            }
+            i += 1;
+        }
+    }
+
+    /// The sources of the inlined helpers, with the helpers renamed to their local names.
+    fn inline_helpers(&self, used: &[(Helper, Atom)]) -> Vec<'a, Statement<'a>> {
+        let mut renamer = HelperRenamer {
+            names: used
+                .iter()
+                .map(|(helper, name)| (Atom::from(format!("_{}", helper.name())), name.clone()))
+                .collect(),
+        };
+        let mut helpers: std::vec::Vec<_> = used
+            .iter()
+            .map(|(helper, _)| *helper)
+            .filter(|helper| self.is_inlined(*helper))
+            .collect();
+        helpers.sort_by_key(|helper| helper.name());
+        let mut statements = self.ast.new_vec();
+        for helper in helpers {
+            let ret =
+                Parser::new(self.ast.allocator, helper.source(), SourceType::default()).parse();
+            debug_assert!(ret.errors.is_empty(), "failed to parse helper {}", helper.name());
+            let mut body = ret.program.body;
+            renamer.visit_statements(&mut body);
+            statements.extend(body);
+        }
+        statements
+    }
+
+    /// `import _asyncToGenerator from "@babel/runtime/helpers/asyncToGenerator";`
+    /// or `var _asyncToGenerator = require("@babel/runtime/helpers/asyncToGenerator");` for scripts.
+    ///
+    /// `@swc/helpers` exports the helpers as `_`,
+    /// `import { _ as _asyncToGenerator } from "@swc/helpers/_/_async_to_generator";`
+    /// or `var _asyncToGenerator = require("@swc/helpers/_/_async_to_generator")._;` for scripts.
+    fn import_helpers(
+        &self,
+        used: &[(Helper, Atom)],
+        source_type: SourceType,
+    ) -> Vec<'a, Statement<'a>> {
+        let module_name = &self.options.module_name;
+        let Some(runtime) = self.runtime() else { return self.ast.new_vec() };
+        let is_swc = runtime == Runtime::Swc;
+        let mut statements = self.ast.new_vec_with_capacity(used.len());
+        for (helper, local) in used {
+            let Some(name) = helper.runtime_name(runtime) else { continue };
+            let local = local.clone();
+            let source = if is_swc {
+                format!("{module_name}/_/{name}")
+            } else {
+                format!("{module_name}/helpers/{name}")
+            };
+            let source = StringLiteral::new(SPAN, Atom::from(source));
+            let stmt = if source_type.is_module() {
+                let local = BindingIdentifier::new(SPAN, local);
+                let specifier = if is_swc {
+                    ImportDeclarationSpecifier::ImportSpecifier(ImportSpecifier {
+                        span: SPAN,
+                        imported: ModuleExportName::Identifier(IdentifierName::new(
+                            SPAN,
+                            "_".into(),
+                        )),
+                        local,
+                        import_kind: ImportOrExportKind::Value,
+                    })
+                } else {
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(ImportDefaultSpecifier {
+                        span: SPAN,
+                        local,
+                    })
+                };
+                let decl = self.ast.import_declaration(
+                    SPAN,
+                    Some(self.ast.new_vec_single(specifier)),
+                    source,
+                    None,
+                    ImportOrExportKind::Value,
+                );
+                self.ast.module_declaration(ModuleDeclaration::ImportDeclaration(decl))
+            } else {
+                let callee = self.ast.identifier_reference_expression(IdentifierReference::new(
+                    SPAN,
+                    "require".into(),
+                ));
+                let arguments = self.ast.new_vec_single(Argument::Expression(
+                    self.ast.literal_string_expression(source),
+                ));
+                let mut init = self.ast.call_expression(SPAN, callee, arguments, false, None);
+                if is_swc {
+                    let property = IdentifierName::new(SPAN, "_".into());
+                    init = self.ast.static_member_expression(SPAN, init, property, false);
+                }
+                let id = self.ast.binding_pattern(
+                    self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, local)),
+                    None,
+                    false,
+                );
+                let kind = VariableDeclarationKind::Var;
+                let declarator = self.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+                let decl = self.ast.variable_declaration(
+                    SPAN,
+                    kind,
+                    self.ast.new_vec_single(declarator),
+                    Modifiers::empty(),
+                );
+                Statement::Declaration(Declaration::VariableDeclaration(decl))
+            };
+            statements.push(stmt);
+        }
+        statements
+    }
+}
+
+/// Renames the helpers defined and referenced by inlined helper sources, `_inherits` -> `_inherits2`.
+struct HelperRenamer {
+    names: std::vec::Vec<(Atom, Atom)>,
+}
+
+impl HelperRenamer {
+    fn rename(&self, name: &mut Atom) {
+        if let Some((_, local)) = self.names.iter().find(|(helper, _)| helper == name) {
+            *name = local.clone();
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for HelperRenamer {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
+        self.rename(&mut ident.name);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        self.rename(&mut ident.name);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        decorators::{DecoratorsOptions, DecoratorsVersion},
+        options::{TransformOptions, TransformTarget},
+        tester::Tester,
+    };
+
+    let options = |helper_loader| TransformOptions {
+        target: TransformTarget::ES2017,
+        async_to_generator: true,
+        helper_loader,
+        ..TransformOptions::default()
+    };
+
+    let external = options(HelperLoaderOptions {
+        mode: HelperLoaderMode::External,
+        ..HelperLoaderOptions::default()
+    });
+    Tester::new("test.mjs", external).test(&[(
+        "async function foo() { await 1; }",
+        "function foo() { return babelHelpers.asyncToGenerator(function* () { yield 1; }).apply(this, arguments); }",
+    )]);
+
+    let swc = options(HelperLoaderOptions {
+        mode: HelperLoaderMode::Runtime,
+        module_name: "@swc/helpers".into(),
+        ..HelperLoaderOptions::default()
+    });
+    Tester::new("test.mjs", swc).test(&[(
+        "async function foo() { await 1; }",
+        "import { _ as _asyncToGenerator } from '@swc/helpers/_/_async_to_generator';
+        function foo() { return _asyncToGenerator(function* () { yield 1; }).apply(this, arguments); }",
+    )]);
+
+    // `@swc/helpers` names are not derived from the Babel ones
+    let swc = TransformOptions {
+        target: TransformTarget::ES2017,
+        object_rest_spread: true,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            module_name: "@swc/helpers".into(),
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    Tester::new("test.mjs", swc).test(&[(
+        "const a = { ...b };",
+        "import { _ as _objectSpread2 } from '@swc/helpers/_/_object_spread';
+        const a = _objectSpread2({}, b);",
+    )]);
+
+    // Helpers which are not in `@babel/runtime` are inlined
+    let runtime = TransformOptions {
+        decorators: Some(DecoratorsOptions {
+            version: DecoratorsVersion::Legacy,
+            emit_decorator_metadata: false,
+        }),
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    Tester::new("test.mjs", runtime).test(&[(
+        "@dec class A { m(@inject() a) {} }",
+        "function _tsDecorate(decorators, target, key, desc) {
+            var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+            if (typeof Reflect === 'object' && typeof Reflect.decorate === 'function') r = Reflect.decorate(decorators, target, key, desc);
+            else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+            return c > 3 && r && Object.defineProperty(target, key, r), r;
+        }
+        function _tsParam(paramIndex, decorator) {
+            return function (target, key) {
+                decorator(target, key, paramIndex);
+            };
+        }
+        let A = class A { m(a) {} };
+        _tsDecorate([_tsParam(0, inject())], A.prototype, 'm', null);
+        A = _tsDecorate([dec], A);",
+    )]);
+
+    // Local names do not shadow the bindings of the program
+    let runtime = options(HelperLoaderOptions {
+        mode: HelperLoaderMode::Runtime,
+        ..HelperLoaderOptions::default()
+    });
+    Tester::new("test.mjs", runtime).test(&[(
+        "function _asyncToGenerator() {} async function foo() { await 1; }",
+        "import _asyncToGenerator2 from '@babel/runtime/helpers/asyncToGenerator';
+        function _asyncToGenerator() {}
+        function foo() { return _asyncToGenerator2(function* () { yield 1; }).apply(this, arguments); }",
+    )]);
+
+    let inline = TransformOptions {
+        target: TransformTarget::ES2022,
+        class_properties: true,
+        ..TransformOptions::default()
+    };
+    Tester::new("test.js", inline).test(&[(
+        "var _assertClassBrand; class A { static #x = 1; f() { return A.#x; } }",
+        "function _assertClassBrand2(brand, receiver, value) {
+            if (typeof brand === 'function' ? brand === receiver : brand.has(receiver)) {
+                return arguments.length < 3 ? receiver : value;
+            }
+            throw new TypeError('Private element is not present on this object');
+        }
+        var _assertClassBrand;
+        class A { f() { return _assertClassBrand2(A, A, _x)._; } }
+        var _x = { _: 1 };",
+    )]);
+}
@@ -0,0 +1,77 @@
+function _wrapAsyncGenerator(fn) {
+  return function () {
+    return new _AsyncGenerator(fn.apply(this, arguments));
+  };
+}
+function _AsyncGenerator(gen) {
+  var front, back;
+  function resume(key, arg) {
+    try {
+      var result = gen[key](arg),
+        value = result.value,
+        overloaded = value instanceof _OverloadYield;
+      Promise.resolve(overloaded ? value.v : value).then(
+        function (arg) {
+          if (overloaded) {
+            var nextKey = key === "return" ? "return" : "next";
+            if (!value.k || arg.done) {
+              return resume(nextKey, arg);
+            }
+            arg = gen[nextKey](arg).value;
+          }
+          settle(result.done ? "return" : "normal", arg);
+        },
+        function (err) {
+          resume("throw", err);
+        }
+      );
+    } catch (err) {
+      settle("throw", err);
+    }
+  }
+  function settle(type, value) {
+    switch (type) {
+      case "return":
+        front.resolve({ value: value, done: true });
+        break;
+      case "throw":
+        front.reject(value);
+        break;
+      default:
+        front.resolve({ value: value, done: false });
+        break;
+    }
+    front = front.next;
+    if (front) {
+      resume(front.key, front.arg);
+    } else {
+      back = null;
+    }
+  }
+  this._invoke = function (key, arg) {
+    return new Promise(function (resolve, reject) {
+      var request = { key: key, arg: arg, resolve: resolve, reject: reject, next: null };
+      if (back) {
+        back = back.next = request;
+      } else {
+        front = back = request;
+        resume(key, arg);
+      }
+    });
+  };
+  if (typeof gen.return !== "function") {
+    this.return = undefined;
+  }
+}
+_AsyncGenerator.prototype[(typeof Symbol === "function" && Symbol.asyncIterator) || "@@asyncIterator"] = function () {
+  return this;
+};
+_AsyncGenerator.prototype.next = function (arg) {
+  return this._invoke("next", arg);
+};
+_AsyncGenerator.prototype.throw = function (arg) {
+  return this._invoke("throw", arg);
+};
+_AsyncGenerator.prototype.return = function (arg) {
+  return this._invoke("return", arg);
+};
@@ -8,15 +8,22 @@
 //! * <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformer.ts>
 
 mod context;
+mod decorators;
 mod es2015;
 mod es2016;
+mod es2017;
+mod es2018;
 mod es2019;
 mod es2020;
 mod es2021;
 mod es2022;
+mod flow;
+mod helpers;
+mod modules;
 mod options;
 mod react_jsx;
 mod regexp;
+mod targets;
 #[cfg(test)]
 mod tester;
 mod typescript;
@@ -24,44 +31,85 @@ mod utils;
 
 use std::{cell::RefCell, rc::Rc, sync::Arc};
 
-use es2015::TemplateLiterals;
 use oxc_allocator::Allocator;
 use oxc_ast::{ast::*, AstBuilder, VisitMut};
 use oxc_diagnostics::Error;
-use oxc_semantic::Semantic;
-use oxc_span::SourceType;
+use oxc_semantic::{ScopeId, Semantic};
+use oxc_span::{SourceType, SPAN};
 
 use crate::{
-    context::TransformerCtx, es2015::ShorthandProperties, es2016::ExponentiationOperator,
-    es2019::OptionalCatchBinding, es2020::NullishCoalescingOperator,
-    es2021::LogicalAssignmentOperators, es2022::ClassStaticBlock, react_jsx::ReactJsx,
-    regexp::RegexpFlags, typescript::TypeScript, utils::CreateVars,
+    context::TransformerCtx,
+    decorators::{Decorators, LegacyDecorators},
+    es2015::{
+        ArrowFunctions, BlockScoping, Classes, ComputedProperties, Destructuring, ForOf, NewTarget,
+        Parameters, Regenerator, ShorthandProperties, Spread, TemplateLiterals,
+    },
+    es2016::ExponentiationOperator,
+    es2017::AsyncToGenerator,
+    es2018::{AsyncGeneratorFunctions, ObjectRestSpread},
+    es2019::OptionalCatchBinding,
+    es2020::{NullishCoalescingOperator, OptionalChaining},
+    es2021::LogicalAssignmentOperators,
+    es2022::{ClassProperties, ClassStaticBlock},
+    flow::Flow,
+    modules::CommonJs,
+    react_jsx::{ReactJsx, ReactRefresh},
+    regexp::{RegexpFlags, RegexpSyntax},
+    typescript::TypeScript,
+    utils::CreateVars,
 };
 
 pub use crate::{
+    decorators::{DecoratorsOptions, DecoratorsVersion},
     es2020::NullishCoalescingOperatorOptions,
+    helpers::{HelperLoaderMode, HelperLoaderOptions},
+    modules::{ImportInterop, ModuleFormat, ModulesOptions},
     options::{TransformOptions, TransformTarget},
-    react_jsx::{ReactJsxOptions, ReactJsxRuntime},
+    react_jsx::{ReactJsxOptions, ReactJsxRuntime, ReactRefreshOptions},
+    targets::{Engine, Targets, Version},
 };
 
 pub struct Transformer<'a> {
     ctx: TransformerCtx<'a>,
     #[allow(unused)]
     typescript: Option<TypeScript<'a>>,
+    flow: Option<Flow<'a>>,
     react_jsx: Option<ReactJsx<'a>>,
+    react_refresh: Option<ReactRefresh<'a>>,
+    regexp_syntax: Option<RegexpSyntax<'a>>,
     regexp_flags: Option<RegexpFlags<'a>>,
+    decorators: Option<Decorators<'a>>,
+    legacy_decorators: Option<LegacyDecorators<'a>>,
+    modules: Option<CommonJs<'a>>,
     // es2022
     es2022_class_static_block: Option<ClassStaticBlock<'a>>,
+    es2022_class_properties: Option<ClassProperties<'a>>,
     // es2021
     es2021_logical_assignment_operators: Option<LogicalAssignmentOperators<'a>>,
     // es2020
     es2020_nullish_coalescing_operators: Option<NullishCoalescingOperator<'a>>,
+    es2020_optional_chaining: Option<OptionalChaining<'a>>,
     // es2019
     es2019_optional_catch_binding: Option<OptionalCatchBinding<'a>>,
+    // es2018
+    es2018_async_generator_functions: Option<AsyncGeneratorFunctions<'a>>,
+    es2018_object_rest_spread: Option<ObjectRestSpread<'a>>,
+    // es2017
+    es2017_async_to_generator: Option<AsyncToGenerator<'a>>,
     // es2016
     es2016_exponentiation_operator: Option<ExponentiationOperator<'a>>,
     // es2015
+    es2015_arrow_functions: Option<ArrowFunctions<'a>>,
+    es2015_block_scoping: Option<BlockScoping<'a>>,
+    es2015_classes: Option<Classes<'a>>,
+    es2015_computed_properties: Option<ComputedProperties<'a>>,
+    es2015_destructuring: Option<Destructuring<'a>>,
+    es2015_for_of: Option<ForOf<'a>>,
+    es2015_new_target: Option<NewTarget>,
+    es2015_parameters: Option<Parameters<'a>>,
+    es2015_regenerator: Option<Regenerator<'a>>,
     es2015_shorthand_properties: Option<ShorthandProperties<'a>>,
+    es2015_spread: Option<Spread<'a>>,
     es2015_template_literals: Option<TemplateLiterals<'a>>,
 }
 
@@ -73,31 +121,57 @@ impl<'a> Transformer<'a> {
         semantic: Semantic<'a>,
         options: TransformOptions,
     ) -> Self {
+        let options = options.resolve_targets();
         let ast = Rc::new(AstBuilder::new(allocator));
         let ctx = TransformerCtx::new(
             Rc::clone(&ast),
             Rc::new(RefCell::new(semantic)),
+            options.helper_loader.clone(),
         );
 
         Self {
             ctx: ctx.clone(),
             // TODO: pass verbatim_module_syntax from user config
             typescript: source_type.is_typescript().then(|| TypeScript::new(Rc::clone(&ast), ctx.clone(), false)),
+            flow: source_type.is_flow().then(|| Flow::new(Rc::clone(&ast))),
+            react_refresh: ReactRefresh::new(Rc::clone(&ast), ctx.clone(), &options),
+            regexp_syntax: RegexpSyntax::new(Rc::clone(&ast), ctx.clone(), &options),
             regexp_flags: RegexpFlags::new(Rc::clone(&ast), &options),
+            decorators: Decorators::new(Rc::clone(&ast), ctx.clone(), &options),
+            legacy_decorators: LegacyDecorators::new(Rc::clone(&ast), ctx.clone(), &options),
+            modules: CommonJs::new(Rc::clone(&ast), ctx.clone(), &options),
             es2022_class_static_block: es2022::ClassStaticBlock::new(Rc::clone(&ast), &options),
+            es2022_class_properties: ClassProperties::new(Rc::clone(&ast), ctx.clone(), &options),
             es2021_logical_assignment_operators: LogicalAssignmentOperators::new(Rc::clone(&ast), ctx.clone(), &options),
             es2020_nullish_coalescing_operators: NullishCoalescingOperator::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2020_optional_chaining: OptionalChaining::new(Rc::clone(&ast), ctx.clone(), &options),
             es2019_optional_catch_binding: OptionalCatchBinding::new(Rc::clone(&ast), &options),
+            es2018_async_generator_functions: AsyncGeneratorFunctions::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2018_object_rest_spread: ObjectRestSpread::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2017_async_to_generator: AsyncToGenerator::new(Rc::clone(&ast), ctx.clone(), &options),
             es2016_exponentiation_operator: ExponentiationOperator::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_arrow_functions: ArrowFunctions::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_block_scoping: BlockScoping::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_classes: Classes::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_computed_properties: ComputedProperties::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_destructuring: Destructuring::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_for_of: ForOf::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_new_target: NewTarget::new(&options),
+            es2015_parameters: Parameters::new(Rc::clone(&ast), ctx.clone(), &options),
+            es2015_regenerator: Regenerator::new(Rc::clone(&ast), ctx.clone(), &options),
             es2015_shorthand_properties: ShorthandProperties::new(Rc::clone(&ast), &options),
+            es2015_spread: Spread::new(Rc::clone(&ast), ctx.clone(), &options),
             es2015_template_literals: TemplateLiterals::new(Rc::clone(&ast), &options),
-            react_jsx: options.react_jsx.map(|options| ReactJsx::new(Rc::clone(&ast), ctx.clone(), options)),
+            react_jsx: options.react_jsx.map(|react_jsx| ReactJsx::new(Rc::clone(&ast), ctx.clone(), react_jsx, options.filename.as_deref().unwrap_or_default())),
         }
     }
 
     /// # Errors
     /// Returns `Vec<Error>` if any errors were collected during the transformation.
     pub fn build(mut self, program: &mut Program<'a>) -> Result<(), Vec<Error>> {
+        if let Some(new_target) = self.es2015_new_target.as_mut() {
+            self.ctx.traverse(new_target, program);
+        }
         self.visit_program(program);
         let errors: Vec<_> = self
             .ctx
@@ -112,6 +186,36 @@ impl<'a> Transformer<'a> {
             Err(errors)
         }
     }
+
+    /// Temporary vars created until [`Self::exit_var_scope`] are declared in the
+    /// function body, static block or program scope `scope_id`.
+    fn enter_var_scope(&mut self, scope_id: Option<ScopeId>) {
+        self.ctx.enter_var_scope(scope_id);
+        self.decorators.as_mut().map(CreateVars::enter_var_scope);
+        self.legacy_decorators.as_mut().map(CreateVars::enter_var_scope);
+        self.es2022_class_properties.as_mut().map(CreateVars::enter_var_scope);
+        self.es2021_logical_assignment_operators.as_mut().map(CreateVars::enter_var_scope);
+        self.es2020_nullish_coalescing_operators.as_mut().map(CreateVars::enter_var_scope);
+        self.es2020_optional_chaining.as_mut().map(CreateVars::enter_var_scope);
+        self.es2016_exponentiation_operator.as_mut().map(CreateVars::enter_var_scope);
+        self.es2015_computed_properties.as_mut().map(CreateVars::enter_var_scope);
+        self.es2015_destructuring.as_mut().map(CreateVars::enter_var_scope);
+        self.es2015_spread.as_mut().map(CreateVars::enter_var_scope);
+    }
+
+    fn exit_var_scope(&mut self, stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        self.decorators.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.legacy_decorators.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.es2022_class_properties.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.es2021_logical_assignment_operators.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.es2020_nullish_coalescing_operators.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.es2020_optional_chaining.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.es2016_exponentiation_operator.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.es2015_computed_properties.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.es2015_destructuring.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.es2015_spread.as_mut().map(|t| t.exit_var_scope(stmts));
+        self.ctx.exit_var_scope();
+    }
 }
 
 impl<'a> VisitMut<'a> for Transformer<'a> {
@@ -121,36 +225,215 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
         }
 
         self.typescript.as_mut().map(|t| t.transform_program(program));
+        self.flow.as_mut().map(|t| t.transform_program(program));
+        self.react_refresh.as_mut().map(|t| t.transform_program(program));
+        self.es2015_block_scoping.as_mut().map(BlockScoping::enter_program);
+        let root_scope_id = self.ctx.scopes().root_scope_id();
+        self.enter_var_scope(Some(root_scope_id));
         self.visit_statements(&mut program.body);
+        self.exit_var_scope(&mut program.body);
 
+        self.es2015_arrow_functions.as_mut().map(|t| t.exit_program(program));
+        self.es2015_regenerator.as_mut().map(|t| t.transform_program(program));
         self.react_jsx.as_mut().map(|t| t.add_react_jsx_runtime_imports(program));
+        self.ctx.helpers.add_helpers(program);
+
+        if let Some(modules) = self.modules.as_mut() {
+            modules.transform_program(program);
+            // The interop helpers
+            self.ctx.helpers.add_helpers(program);
+            modules.wrap_program(program);
+        }
     }
 
     fn visit_statements(&mut self, stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        self.decorators.as_mut().map(|t| t.transform_statements(stmts));
+        self.legacy_decorators.as_mut().map(|t| t.transform_statements(stmts));
+        self.es2022_class_properties.as_mut().map(|t| t.transform_statements(stmts));
+        self.es2015_classes.as_mut().map(|t| t.transform_statements(stmts));
+        self.es2015_block_scoping.as_mut().map(|t| t.transform_statements(stmts));
+
         for stmt in stmts.iter_mut() {
             self.visit_statement(stmt);
         }
-        // TODO: we need scope id to insert the vars into the correct statements
-        self.es2021_logical_assignment_operators.as_mut().map(|t| t.add_vars_to_statements(stmts));
-        self.es2020_nullish_coalescing_operators.as_mut().map(|t| t.add_vars_to_statements(stmts));
-        self.es2016_exponentiation_operator.as_mut().map(|t| t.add_vars_to_statements(stmts));
+    }
+
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        for directive in body.directives.iter_mut() {
+            self.visit_directive(directive);
+        }
+        self.enter_var_scope(body.scope_id.get());
+        self.visit_statements(&mut body.statements);
+        self.exit_var_scope(&mut body.statements);
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        self.enter_var_scope(block.scope_id.get());
+        self.visit_statements(&mut block.body);
+        self.exit_var_scope(&mut block.body);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.es2018_async_generator_functions.as_mut().map(|t| t.transform_statement(stmt));
+        self.es2018_object_rest_spread.as_mut().map(|t| t.transform_statement(stmt));
+        self.es2015_for_of.as_mut().map(|t| t.transform_statement(stmt));
+        self.es2015_destructuring.as_mut().map(|t| t.transform_statement(stmt));
+
+        let is_loop = matches!(
+            stmt,
+            Statement::ForStatement(_)
+                | Statement::ForInStatement(_)
+                | Statement::ForOfStatement(_)
+                | Statement::WhileStatement(_)
+                | Statement::DoWhileStatement(_)
+        );
+        if is_loop {
+            self.es2015_block_scoping.as_mut().map(|t| t.enter_loop(stmt));
+        }
+        self.visit_statement_match(stmt);
+        if is_loop {
+            self.es2015_block_scoping.as_mut().map(BlockScoping::exit_loop);
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        self.es2018_object_rest_spread.as_mut().map(|t| t.transform_variable_declaration(decl));
+        self.es2015_destructuring.as_mut().map(|t| t.transform_variable_declaration(decl));
+        self.es2015_block_scoping.as_mut().map(|t| t.transform_variable_declaration(decl));
+
+        for declarator in decl.declarations.iter_mut() {
+            self.visit_variable_declarator(declarator);
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
+        self.es2015_block_scoping.as_mut().map(|t| t.transform_binding_identifier(ident));
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        self.es2015_block_scoping.as_mut().map(|t| t.transform_identifier_reference(ident));
     }
 
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
         // self.typescript.as_mut().map(|t| t.transform_expression(expr));
+        self.flow.as_mut().map(|t| t.transform_expression(expr));
         self.react_jsx.as_mut().map(|t| t.transform_expression(expr));
+        self.regexp_syntax.as_mut().map(|t| t.transform_expression(expr));
         self.regexp_flags.as_mut().map(|t| t.transform_expression(expr));
 
+        self.decorators.as_mut().map(|t| t.transform_expression(expr));
+        self.legacy_decorators.as_mut().map(|t| t.transform_expression(expr));
+        self.es2022_class_properties.as_mut().map(|t| t.transform_expression(expr));
         self.es2021_logical_assignment_operators.as_mut().map(|t| t.transform_expression(expr));
         self.es2020_nullish_coalescing_operators.as_mut().map(|t| t.transform_expression(expr));
+        self.es2020_optional_chaining.as_mut().map(|t| t.transform_expression(expr));
+        self.es2018_async_generator_functions.as_mut().map(|t| t.transform_expression(expr));
+        self.es2018_object_rest_spread.as_mut().map(|t| t.transform_expression(expr));
+        self.es2017_async_to_generator.as_mut().map(|t| t.transform_expression(expr));
         self.es2016_exponentiation_operator.as_mut().map(|t| t.transform_expression(expr));
         self.es2015_template_literals.as_mut().map(|t| t.transform_expression(expr));
+        self.es2015_classes.as_mut().map(|t| t.transform_expression(expr));
+        self.es2015_arrow_functions.as_mut().map(|t| t.transform_expression(expr));
+        self.es2015_destructuring.as_mut().map(|t| t.transform_expression(expr));
+        self.es2015_block_scoping.as_mut().map(|t| t.transform_expression(expr));
 
         self.visit_expression_match(expr);
+
+        self.es2015_spread.as_mut().map(|t| t.transform_expression(expr));
+        self.es2015_computed_properties.as_mut().map(|t| t.transform_expression(expr));
+    }
+
+    fn visit_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
+        self.react_jsx.as_mut().map(|t| t.enter_method_definition(def));
+
+        for decorator in def.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut def.key);
+        self.visit_function(&mut def.value);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        self.react_jsx.as_mut().map(ReactJsx::enter_function);
+        self.es2018_object_rest_spread.as_mut().map(|t| t.transform_function(func));
+        self.es2018_async_generator_functions.as_mut().map(|t| t.enter_function(func));
+        self.es2017_async_to_generator.as_mut().map(|t| t.enter_function(func));
+        self.es2015_parameters.as_mut().map(|t| t.transform_function(func));
+        self.es2015_arrow_functions.as_mut().map(ArrowFunctions::enter_function);
+        self.es2015_block_scoping.as_mut().map(BlockScoping::enter_function);
+
+        if let Some(ident) = &mut func.id {
+            self.visit_binding_identifier(ident);
+        }
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.visit_function_body(body);
+        }
+        if let Some(parameters) = &mut func.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(annotation) = &mut func.return_type {
+            self.visit_ts_type_annotation(annotation);
+        }
+
+        self.es2015_block_scoping.as_mut().map(BlockScoping::exit_function);
+        self.es2017_async_to_generator.as_mut().map(|t| t.exit_function(func));
+        self.es2018_async_generator_functions.as_mut().map(|t| t.exit_function(func));
+        self.es2015_arrow_functions.as_mut().map(|t| t.exit_function(func));
+        self.react_jsx.as_mut().map(ReactJsx::exit_function);
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        self.es2018_object_rest_spread.as_mut().map(|t| t.transform_arrow_expression(expr));
+        self.es2018_async_generator_functions
+            .as_mut()
+            .map(AsyncGeneratorFunctions::enter_arrow_expression);
+        self.es2017_async_to_generator.as_mut().map(|t| t.enter_arrow_expression(expr));
+        self.es2015_parameters.as_mut().map(|t| t.transform_arrow_expression(expr));
+        self.es2015_block_scoping.as_mut().map(BlockScoping::enter_function);
+
+        self.visit_formal_parameters(&mut expr.params);
+        self.visit_function_body(&mut expr.body);
+        // Vars were declared in the expression body, `() => a` -> `() => { var _a; return a; }`
+        if expr.expression && expr.body.statements.len() > 1 {
+            expr.expression = false;
+            if let Some(stmt) = expr.body.statements.last_mut() {
+                if let Statement::ExpressionStatement(expr_stmt) = stmt {
+                    let argument = self.ctx.ast.move_expression(&mut expr_stmt.expression);
+                    *stmt = self.ctx.ast.return_statement(SPAN, Some(argument));
+                }
+            }
+        }
+        if let Some(parameters) = &mut expr.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+
+        self.es2015_block_scoping.as_mut().map(BlockScoping::exit_function);
+        self.es2017_async_to_generator.as_mut().map(|t| t.exit_arrow_expression(expr));
+        self.es2018_async_generator_functions
+            .as_mut()
+            .map(AsyncGeneratorFunctions::exit_arrow_expression);
+    }
+
+    fn visit_property_definition(&mut self, def: &mut PropertyDefinition<'a>) {
+        for decorator in def.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut def.key);
+        self.es2015_arrow_functions.as_mut().map(ArrowFunctions::enter_class_field);
+        if let Some(value) = &mut def.value {
+            self.visit_expression(value);
+        }
+        self.es2015_arrow_functions.as_mut().map(ArrowFunctions::exit_class_field);
+        if let Some(annotation) = &mut def.type_annotation {
+            self.visit_ts_type_annotation(annotation);
+        }
     }
 
     fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
         self.es2019_optional_catch_binding.as_mut().map(|t| t.transform_catch_clause(clause));
+        self.es2018_object_rest_spread.as_mut().map(|t| t.transform_catch_clause(clause));
+        self.es2015_destructuring.as_mut().map(|t| t.transform_catch_clause(clause));
 
         if let Some(param) = &mut clause.param {
             self.visit_binding_pattern(param);
@@ -169,6 +452,7 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
     }
 
     fn visit_class_body(&mut self, class_body: &mut ClassBody<'a>) {
+        self.flow.as_mut().map(|t| t.transform_class_body(class_body));
         self.es2022_class_static_block.as_mut().map(|t| t.transform_class_body(class_body));
 
         class_body.body.iter_mut().for_each(|class_element| {
@@ -178,6 +462,7 @@ impl<'a> VisitMut<'a> for Transformer<'a> {
 
     fn visit_formal_parameters(&mut self, params: &mut FormalParameters<'a>) {
         self.typescript.as_mut().map(|t| t.transform_formal_parameters(params));
+        self.flow.as_mut().map(|t| t.transform_formal_parameters(params));
         for param in params.items.iter_mut() {
             self.visit_formal_parameter(param);
         }
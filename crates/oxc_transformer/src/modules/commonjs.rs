@@ -0,0 +1,1189 @@
+use std::{hash::BuildHasherDefault, mem, rc::Rc};
+
+use indexmap::IndexMap;
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstBuilder, VisitMut};
+use oxc_semantic::SymbolId;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    identifier::{is_identifier_part, is_identifier_start_all},
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    NumberBase,
+};
+use rustc_hash::{FxHashMap, FxHasher};
+
+use crate::{
+    context::TransformerCtx,
+    helpers::Helper,
+    modules::{ImportInterop, ModuleFormat, ModulesOptions},
+    options::TransformOptions,
+};
+
+/// ES Modules to CommonJS
+///
+/// ```javascript
+/// import foo, { bar } from "foo";
+/// export const x = bar(foo);
+/// export * from "baz";
+/// // is transformed to
+/// "use strict";
+/// Object.defineProperty(exports, "__esModule", { value: true });
+/// var _exportNames = { x: true };
+/// Object.defineProperty(exports, "x", { enumerable: true, get: function () { return x; } });
+/// var _foo = _interopRequireWildcard(require("foo"));
+/// var _baz = require("baz");
+/// Object.keys(_baz).forEach(function (key) { /* re-export `key` with a getter */ });
+/// const x = (0, _foo.bar)(_foo.default);
+/// ```
+///
+/// Exports are defined with getters so they stay live bindings, and references to imports are
+/// replaced with member expressions on the required module.
+/// Top level `this` is `undefined`, and `import("foo")` is lowered to
+/// `Promise.resolve().then(function () { return _interopRequireWildcard(require("foo")); })`.
+///
+/// With the `amd` and `umd` formats, the CommonJS output is wrapped in a factory receiving
+/// `require`, `exports` and `module`.
+///
+/// References:
+/// * <https://babel.dev/docs/babel-plugin-transform-modules-commonjs>
+/// * <https://babel.dev/docs/babel-plugin-transform-modules-amd>
+/// * <https://babel.dev/docs/babel-plugin-transform-modules-umd>
+/// * <https://github.com/babel/babel/tree/main/packages/babel-helper-module-transforms>
+pub struct CommonJs<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: ModulesOptions,
+
+    sources: IndexMap<Atom, ModuleSource, BuildHasherDefault<FxHasher>>,
+    imports: FxHashMap<SymbolId, Binding>,
+    /// Imports by name, for references created by other transforms which have no reference id.
+    import_names: FxHashMap<Atom, Binding>,
+    /// The exported names and the bindings returned by their getters.
+    exports: std::vec::Vec<(Atom, Binding)>,
+    has_exports: bool,
+}
+
+/// A module required by `import` or `export ... from`.
+#[derive(Default)]
+struct ModuleSource {
+    /// `_foo` in `var _foo = require("foo")`, `None` for side effect only imports.
+    name: Option<Atom>,
+    default: bool,
+    named: bool,
+    namespace: bool,
+    /// `export * from "foo"`
+    export_star: bool,
+}
+
+/// The replacement of a reference to an imported binding, or the value of an export.
+#[derive(Debug, Clone)]
+enum Binding {
+    /// `_foo`, or a local binding
+    Identifier(Atom),
+    /// `_foo.bar`
+    Member(Atom, Atom),
+}
+
+impl<'a> CommonJs<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        options.modules.clone().map(|options| Self {
+            ast,
+            ctx,
+            options,
+            sources: IndexMap::default(),
+            imports: FxHashMap::default(),
+            import_names: FxHashMap::default(),
+            exports: vec![],
+            has_exports: false,
+        })
+    }
+
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+
+        let body = mem::replace(&mut program.body, self.ast.new_vec());
+        for stmt in body {
+            match stmt {
+                Statement::ModuleDeclaration(decl) => {
+                    self.transform_module_declaration(decl.unbox(), &mut program.body);
+                }
+                stmt => program.body.push(stmt),
+            }
+        }
+
+        let header = self.build_header();
+        program.body.splice(0..0, header);
+
+        let mut references = ModuleReferences {
+            ast: &self.ast,
+            ctx: &self.ctx,
+            import_interop: self.options.import_interop,
+            imports: &self.imports,
+            import_names: &self.import_names,
+            this_depth: 0,
+        };
+        for stmt in program.body.iter_mut() {
+            references.visit_statement(stmt);
+        }
+
+        if !program.directives.iter().any(|d| d.directive == "use strict") {
+            let directive = self.ast.directive(
+                SPAN,
+                StringLiteral::new(SPAN, "use strict".into()),
+                "use strict".into(),
+            );
+            program.directives.insert(0, directive);
+        }
+        // Helpers added from now on are required instead of imported.
+        program.source_type = program.source_type.with_module(false);
+    }
+
+    /// Wrap the CommonJS output in an AMD or UMD factory.
+    pub fn wrap_program(&mut self, program: &mut Program<'a>) {
+        if self.options.format == ModuleFormat::CommonJs || program.source_type.is_module() {
+            return;
+        }
+
+        let mut dependencies: std::vec::Vec<Atom> =
+            vec!["require".into(), "exports".into(), "module".into()];
+        for stmt in &program.body {
+            if let Some(source) = Self::required_source(stmt) {
+                if !dependencies.contains(source) {
+                    dependencies.push(source.clone());
+                }
+            }
+        }
+        let mut elements = self.ast.new_vec_with_capacity(dependencies.len());
+        for dependency in dependencies {
+            elements.push(ArrayExpressionElement::Expression(self.string(dependency)));
+        }
+        let dependencies = self.ast.array_expression(SPAN, elements, None);
+        let module_id = self.options.module_id.as_deref().map(Atom::from);
+
+        // `function (require, exports, module) {}`
+        let body = self.ast.function_body(
+            SPAN,
+            mem::replace(&mut program.directives, self.ast.new_vec()),
+            mem::replace(&mut program.body, self.ast.new_vec()),
+        );
+        let factory = self.function(&["require", "exports", "module"], body);
+
+        let wrapper = if self.options.format == ModuleFormat::Amd {
+            // `define("id", ["require", "exports", "module"], factory)`
+            let mut arguments = self.ast.new_vec_with_capacity(3);
+            if let Some(module_id) = module_id {
+                arguments.push(Argument::Expression(self.string(module_id)));
+            }
+            arguments.push(Argument::Expression(dependencies));
+            arguments.push(Argument::Expression(factory));
+            self.ast.call_expression(SPAN, self.identifier("define"), arguments, false, None)
+        } else {
+            self.umd_wrapper(dependencies, module_id, factory)
+        };
+        program.body.push(self.ast.expression_statement(SPAN, wrapper));
+    }
+
+    /// ```javascript
+    /// (function (global, factory) {
+    ///   if (typeof define === "function" && define.amd) {
+    ///     define(["require", "exports", "module"], factory);
+    ///   } else if (typeof exports === "object" && typeof module !== "undefined") {
+    ///     factory(require, exports, module);
+    ///   } else {
+    ///     var mod = { exports: {} };
+    ///     factory(function (name) { return global[name]; }, mod.exports, mod);
+    ///     global["id"] = mod.exports;
+    ///   }
+    /// })(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, factory);
+    /// ```
+    fn umd_wrapper(
+        &self,
+        dependencies: Expression<'a>,
+        module_id: Option<Atom>,
+        factory: Expression<'a>,
+    ) -> Expression<'a> {
+        let call = |callee: &str, arguments: std::vec::Vec<Expression<'a>>| {
+            let arguments = Vec::from_iter_in(
+                arguments.into_iter().map(Argument::Expression),
+                self.ast.allocator,
+            );
+            let call =
+                self.ast.call_expression(SPAN, self.identifier(callee), arguments, false, None);
+            self.ast.expression_statement(SPAN, call)
+        };
+        let block = |statements: std::vec::Vec<Statement<'a>>| {
+            let block = self.ast.block(SPAN, Vec::from_iter_in(statements, self.ast.allocator));
+            self.ast.block_statement(block)
+        };
+
+        let is_amd = self.ast.logical_expression(
+            SPAN,
+            self.is_typeof("define", BinaryOperator::StrictEquality, "function"),
+            LogicalOperator::And,
+            self.member(self.identifier("define"), "amd"),
+        );
+        let amd = call("define", vec![dependencies, self.identifier("factory")]);
+
+        let is_commonjs = self.ast.logical_expression(
+            SPAN,
+            self.is_typeof("exports", BinaryOperator::StrictEquality, "object"),
+            LogicalOperator::And,
+            self.is_typeof("module", BinaryOperator::StrictInequality, "undefined"),
+        );
+        let commonjs = call(
+            "factory",
+            vec![self.identifier("require"), self.identifier("exports"), self.identifier("module")],
+        );
+
+        let mut globals = vec![];
+        let empty = self.ast.object_expression(SPAN, self.ast.new_vec(), None);
+        globals
+            .push(self.var_declaration("mod".into(), self.object(vec![("exports".into(), empty)])));
+        let global_name = self.ast.computed_member_expression(
+            SPAN,
+            self.identifier("global"),
+            self.identifier("name"),
+            false,
+        );
+        let global_require = self.function(
+            &["name"],
+            self.ast.function_body(
+                SPAN,
+                self.ast.new_vec(),
+                self.ast.new_vec_single(self.ast.return_statement(SPAN, Some(global_name))),
+            ),
+        );
+        globals.push(call(
+            "factory",
+            vec![
+                global_require,
+                self.member(self.identifier("mod"), "exports"),
+                self.identifier("mod"),
+            ],
+        ));
+        if let Some(module_id) = module_id {
+            let member = self.ast.computed_member(
+                SPAN,
+                self.identifier("global"),
+                self.string(module_id),
+                false,
+            );
+            let target = AssignmentTarget::SimpleAssignmentTarget(
+                self.ast.simple_assignment_target_member_expression(member),
+            );
+            let value = self.member(self.identifier("mod"), "exports");
+            let assign =
+                self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, value);
+            globals.push(self.ast.expression_statement(SPAN, assign));
+        }
+
+        let alternate =
+            self.ast.if_statement(SPAN, is_commonjs, block(vec![commonjs]), Some(block(globals)));
+        let stmt = self.ast.if_statement(SPAN, is_amd, block(vec![amd]), Some(alternate));
+        let wrapper = self.function(
+            &["global", "factory"],
+            self.ast.function_body(SPAN, self.ast.new_vec(), self.ast.new_vec_single(stmt)),
+        );
+
+        let global = self.ast.conditional_expression(
+            SPAN,
+            self.is_typeof("globalThis", BinaryOperator::StrictInequality, "undefined"),
+            self.identifier("globalThis"),
+            self.ast.conditional_expression(
+                SPAN,
+                self.is_typeof("self", BinaryOperator::StrictInequality, "undefined"),
+                self.identifier("self"),
+                self.ast.this_expression(SPAN),
+            ),
+        );
+        let mut arguments = self.ast.new_vec_with_capacity(2);
+        arguments.push(Argument::Expression(global));
+        arguments.push(Argument::Expression(factory));
+        let callee = self.ast.parenthesized_expression(SPAN, wrapper);
+        self.ast.call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `"foo"` of the top level `require("foo")`, `var _foo = require("foo")`
+    /// and `var _foo = _interopRequireDefault(require("foo"))`.
+    fn required_source<'b>(stmt: &'b Statement<'a>) -> Option<&'b Atom> {
+        fn require_call<'b>(expr: &'b Expression) -> Option<&'b Atom> {
+            let Expression::CallExpression(call) = expr else { return None };
+            match (&call.callee, call.arguments.first()) {
+                (
+                    Expression::Identifier(ident),
+                    Some(Argument::Expression(Expression::StringLiteral(source))),
+                ) if ident.name == "require" && call.arguments.len() == 1 => Some(&source.value),
+                (_, Some(Argument::Expression(arg))) => require_call(arg),
+                _ => None,
+            }
+        }
+        match stmt {
+            Statement::ExpressionStatement(stmt) => require_call(&stmt.expression),
+            Statement::Declaration(Declaration::VariableDeclaration(decl)) => {
+                decl.declarations.first().and_then(|d| d.init.as_ref()).and_then(require_call)
+            }
+            _ => None,
+        }
+    }
+
+    fn transform_module_declaration(
+        &mut self,
+        decl: ModuleDeclaration<'a>,
+        body: &mut Vec<'a, Statement<'a>>,
+    ) {
+        match decl {
+            ModuleDeclaration::ImportDeclaration(decl) => self.transform_import(&decl),
+            ModuleDeclaration::ExportAllDeclaration(decl) => {
+                if decl.export_kind.is_type() {
+                    return;
+                }
+                self.has_exports = true;
+                let name = self.source_name(&decl.source.value);
+                let source = self.add_source(&decl.source.value);
+                if let Some(exported) = &decl.exported {
+                    source.namespace = true;
+                    self.exports.push((exported.name().clone(), Binding::Identifier(name)));
+                } else {
+                    source.export_star = true;
+                }
+            }
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                self.has_exports = true;
+                self.transform_export_default(decl.unbox(), body);
+            }
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                self.transform_export_named(decl.unbox(), body);
+            }
+            ModuleDeclaration::TSExportAssignment(decl) => {
+                // `module.exports = expr`
+                let module = self.ast.identifier_reference_expression(IdentifierReference::new(
+                    SPAN,
+                    "module".into(),
+                ));
+                let target = self.member_target(module, "exports");
+                let expr = self.ast.assignment_expression(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    decl.unbox().expression,
+                );
+                body.push(self.ast.expression_statement(SPAN, expr));
+            }
+            ModuleDeclaration::TSNamespaceExportDeclaration(_) => {}
+        }
+    }
+
+    fn transform_import(&mut self, decl: &ImportDeclaration<'a>) {
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let source_value = &decl.source.value;
+        self.add_source(source_value);
+        let Some(specifiers) = &decl.specifiers else { return };
+        for specifier in specifiers {
+            let (local, binding) = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if specifier.import_kind.is_type() {
+                        continue;
+                    }
+                    let name = self.source_name(source_value);
+                    let imported = specifier.imported.name().clone();
+                    let source = self.add_source(source_value);
+                    if imported == "default" {
+                        source.default = true;
+                    } else {
+                        source.named = true;
+                    }
+                    (&specifier.local, self.import_binding(name, imported))
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    let name = self.source_name(source_value);
+                    self.add_source(source_value).default = true;
+                    (&specifier.local, self.import_binding(name, "default".into()))
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    let name = self.source_name(source_value);
+                    self.add_source(source_value).namespace = true;
+                    (&specifier.local, Binding::Identifier(name))
+                }
+            };
+            if let Some(symbol_id) = local.symbol_id.get() {
+                self.imports.insert(symbol_id, binding.clone());
+            }
+            self.import_names.insert(local.name.clone(), binding);
+        }
+    }
+
+    /// `_foo.bar`, or `_foo` for the default import in `node` interop.
+    fn import_binding(&self, name: Atom, imported: Atom) -> Binding {
+        if imported == "default" && self.options.import_interop == ImportInterop::Node {
+            Binding::Identifier(name)
+        } else {
+            Binding::Member(name, imported)
+        }
+    }
+
+    fn transform_export_default(
+        &mut self,
+        decl: ExportDefaultDeclaration<'a>,
+        body: &mut Vec<'a, Statement<'a>>,
+    ) {
+        match decl.declaration {
+            ExportDefaultDeclarationKind::Expression(expr) => {
+                // `exports.default = expr`
+                let target = self.member_target(self.exports_object(), "default");
+                let expr =
+                    self.ast.assignment_expression(SPAN, AssignmentOperator::Assign, target, expr);
+                body.push(self.ast.expression_statement(SPAN, expr));
+            }
+            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                let id = func.id.get_or_insert_with(|| self.default_binding());
+                self.exports.push(("default".into(), Binding::Identifier(id.name.clone())));
+                body.push(self.ast.function_declaration(func));
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                let id = class.id.get_or_insert_with(|| self.default_binding());
+                self.exports.push(("default".into(), Binding::Identifier(id.name.clone())));
+                body.push(self.ast.class_declaration(class));
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)
+            | ExportDefaultDeclarationKind::TSEnumDeclaration(_) => {}
+        }
+    }
+
+    fn default_binding(&self) -> BindingIdentifier {
+        let name = self.ctx.scopes().generate_uid_based_on_name("default");
+        self.ctx.add_binding(name.clone());
+        BindingIdentifier::new(SPAN, name)
+    }
+
+    fn transform_export_named(
+        &mut self,
+        decl: ExportNamedDeclaration<'a>,
+        body: &mut Vec<'a, Statement<'a>>,
+    ) {
+        if decl.export_kind.is_type() {
+            return;
+        }
+        if let Some(declaration) = decl.declaration {
+            if !declaration.is_typescript_syntax() {
+                self.has_exports = true;
+                declaration.bound_names(&mut |ident| {
+                    self.exports
+                        .push((ident.name.clone(), Binding::Identifier(ident.name.clone())));
+                });
+            }
+            body.push(Statement::Declaration(declaration));
+            return;
+        }
+
+        self.has_exports = true;
+        for specifier in &decl.specifiers {
+            if specifier.export_kind.is_type() {
+                continue;
+            }
+            let exported = specifier.exported.name().clone();
+            let local = specifier.local.name().clone();
+            let value = if let Some(source) = &decl.source {
+                let name = self.source_name(&source.value);
+                let source = self.add_source(&source.value);
+                if local == "default" {
+                    source.default = true;
+                } else {
+                    source.named = true;
+                }
+                self.import_binding(name, local)
+            } else {
+                Binding::Identifier(local)
+            };
+            self.exports.push((exported, value));
+        }
+    }
+
+    fn add_source(&mut self, source: &Atom) -> &mut ModuleSource {
+        self.sources.entry(source.clone()).or_default()
+    }
+
+    /// The name of the variable the module is required into, e.g. `_foo` for `./foo.js`.
+    fn source_name(&mut self, source: &Atom) -> Atom {
+        if let Some(name) = self.sources.get(source).and_then(|source| source.name.clone()) {
+            return name;
+        }
+        let name = self.ctx.scopes().generate_uid_based_on_name(&source_name_hint(source));
+        self.ctx.add_binding(name.clone());
+        self.add_source(source).name = Some(name.clone());
+        name
+    }
+
+    fn build_header(&mut self) -> Vec<'a, Statement<'a>> {
+        let mut statements = self.ast.new_vec();
+        if self.has_exports {
+            // `Object.defineProperty(exports, "__esModule", { value: true });`
+            let value = self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, true));
+            let descriptor = self.object(vec![("value".into(), value)]);
+            statements.push(self.define_property(self.string("__esModule".into()), descriptor));
+        }
+
+        // `export * from` skips the names exported explicitly.
+        let has_export_star = self.sources.values().any(|source| source.export_star);
+        let names = self
+            .exports
+            .iter()
+            .filter(|(exported, _)| exported.as_str() != "default")
+            .map(|(exported, _)| {
+                let value =
+                    self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, true));
+                (exported.clone(), value)
+            })
+            .collect::<std::vec::Vec<_>>();
+        let export_names = (has_export_star && !names.is_empty()).then(|| {
+            // `var _exportNames = { x: true };`
+            let name = self.ctx.scopes().generate_uid_based_on_name("exportNames");
+            self.ctx.add_binding(name.clone());
+            statements.push(self.var_declaration(name.clone(), self.object(names)));
+            name
+        });
+
+        for (exported, value) in &self.exports {
+            let value = binding_expression(&self.ast, value);
+            statements.push(self.getter(self.string(exported.clone()), value));
+        }
+
+        for (source, info) in &self.sources {
+            let require = self.require_module(source, info);
+            let Some(name) = &info.name else {
+                statements.push(self.ast.expression_statement(SPAN, require));
+                continue;
+            };
+            statements.push(self.var_declaration(name.clone(), require));
+
+            if info.export_star {
+                statements.push(self.export_star(name, export_names.as_ref()));
+            }
+        }
+        statements
+    }
+
+    /// ```javascript
+    /// Object.keys(_foo).forEach(function (key) {
+    ///   if (key === "default" || key === "__esModule") return;
+    ///   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    ///   if (key in exports && exports[key] === _foo[key]) return;
+    ///   Object.defineProperty(exports, key, { enumerable: true, get: function () { return _foo[key]; } });
+    /// });
+    /// ```
+    fn export_star(&self, name: &Atom, export_names: Option<&Atom>) -> Statement<'a> {
+        let key = || self.identifier("key");
+        let is_key = |value: &str| {
+            self.ast.binary_expression(
+                SPAN,
+                key(),
+                BinaryOperator::StrictEquality,
+                self.string(value.into()),
+            )
+        };
+        let return_if = |test: Expression<'a>| {
+            self.ast.if_statement(SPAN, test, self.ast.return_statement(SPAN, None), None)
+        };
+        let module_key =
+            || self.ast.computed_member_expression(SPAN, self.identifier(name), key(), false);
+
+        let mut statements = self.ast.new_vec();
+        statements.push(return_if(self.ast.logical_expression(
+            SPAN,
+            is_key("default"),
+            LogicalOperator::Or,
+            is_key("__esModule"),
+        )));
+        if let Some(export_names) = export_names {
+            let object_prototype = self.member(self.identifier("Object"), "prototype");
+            let has_own_property = self.member(object_prototype, "hasOwnProperty");
+            let mut arguments = self.ast.new_vec_with_capacity(2);
+            arguments.push(Argument::Expression(self.identifier(export_names)));
+            arguments.push(Argument::Expression(key()));
+            let callee = self.member(has_own_property, "call");
+            statements
+                .push(return_if(self.ast.call_expression(SPAN, callee, arguments, false, None)));
+        }
+        let exports_key =
+            self.ast.computed_member_expression(SPAN, self.exports_object(), key(), false);
+        statements.push(return_if(self.ast.logical_expression(
+            SPAN,
+            self.ast.binary_expression(SPAN, key(), BinaryOperator::In, self.exports_object()),
+            LogicalOperator::And,
+            self.ast.binary_expression(
+                SPAN,
+                exports_key,
+                BinaryOperator::StrictEquality,
+                module_key(),
+            ),
+        )));
+        statements.push(self.getter(key(), module_key()));
+
+        let callback =
+            self.function(&["key"], self.ast.function_body(SPAN, self.ast.new_vec(), statements));
+        let keys = self.ast.call_expression(
+            SPAN,
+            self.member(self.identifier("Object"), "keys"),
+            self.ast.new_vec_single(Argument::Expression(self.identifier(name))),
+            false,
+            None,
+        );
+        let for_each = self.ast.call_expression(
+            SPAN,
+            self.member(keys, "forEach"),
+            self.ast.new_vec_single(Argument::Expression(callback)),
+            false,
+            None,
+        );
+        self.ast.expression_statement(SPAN, for_each)
+    }
+
+    /// `require("foo")` wrapped with the interop helper needed by the imports of `foo`.
+    fn require_module(&self, source: &Atom, info: &ModuleSource) -> Expression<'a> {
+        let require = require_call(
+            &self.ast,
+            self.ast.literal_string_expression(StringLiteral::new(SPAN, source.clone())),
+        );
+        match self.options.import_interop {
+            ImportInterop::Babel
+                if info.namespace || (info.default && (info.named || info.export_star)) =>
+            {
+                let arguments = self.ast.new_vec_single(Argument::Expression(require));
+                self.ctx.helpers.call(Helper::InteropRequireWildcard, arguments)
+            }
+            ImportInterop::Babel if info.default => {
+                let arguments = self.ast.new_vec_single(Argument::Expression(require));
+                self.ctx.helpers.call(Helper::InteropRequireDefault, arguments)
+            }
+            ImportInterop::Node if info.namespace => {
+                let mut arguments = self.ast.new_vec_single(Argument::Expression(require));
+                arguments.push(Argument::Expression(
+                    self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, true)),
+                ));
+                self.ctx.helpers.call(Helper::InteropRequireWildcard, arguments)
+            }
+            _ => require,
+        }
+    }
+
+    fn exports_object(&self) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, "exports".into()))
+    }
+
+    fn member_target(&self, object: Expression<'a>, property: &str) -> AssignmentTarget<'a> {
+        let member =
+            self.ast.static_member(SPAN, object, IdentifierName::new(SPAN, property.into()), false);
+        AssignmentTarget::SimpleAssignmentTarget(
+            self.ast.simple_assignment_target_member_expression(member),
+        )
+    }
+
+    /// `Object.defineProperty(exports, key, descriptor);`
+    fn define_property(&self, key: Expression<'a>, descriptor: Expression<'a>) -> Statement<'a> {
+        let callee = self.member(self.identifier("Object"), "defineProperty");
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(self.exports_object()));
+        arguments.push(Argument::Expression(key));
+        arguments.push(Argument::Expression(descriptor));
+        let call = self.ast.call_expression(SPAN, callee, arguments, false, None);
+        self.ast.expression_statement(SPAN, call)
+    }
+
+    /// `Object.defineProperty(exports, key, { enumerable: true, get: function () { return value; } });`
+    fn getter(&self, key: Expression<'a>, value: Expression<'a>) -> Statement<'a> {
+        let enumerable = self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, true));
+        let get = self.function(
+            &[],
+            self.ast.function_body(
+                SPAN,
+                self.ast.new_vec(),
+                self.ast.new_vec_single(self.ast.return_statement(SPAN, Some(value))),
+            ),
+        );
+        let descriptor = self.object(vec![("enumerable".into(), enumerable), ("get".into(), get)]);
+        self.define_property(key, descriptor)
+    }
+
+    /// `function (a, b) {}`
+    fn function(&self, params: &[&str], body: Box<'a, FunctionBody<'a>>) -> Expression<'a> {
+        let mut items = self.ast.new_vec_with_capacity(params.len());
+        for &param in params {
+            let pattern = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, param.into())),
+                None,
+                false,
+            );
+            items.push(self.ast.formal_parameter(SPAN, pattern, None, false, self.ast.new_vec()));
+        }
+        let params =
+            self.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, None);
+        self.ast.function_expression(self.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            None,
+            Modifiers::empty(),
+        ))
+    }
+
+    /// `{ a: value, "b-c": value }`
+    fn object(&self, properties: std::vec::Vec<(Atom, Expression<'a>)>) -> Expression<'a> {
+        let mut props = self.ast.new_vec_with_capacity(properties.len());
+        for (name, value) in properties {
+            let key = if is_identifier_name(&name) {
+                self.ast.property_key_identifier(IdentifierName::new(SPAN, name))
+            } else {
+                self.ast.property_key_expression(self.string(name))
+            };
+            let property = self.ast.object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            props.push(ObjectPropertyKind::ObjectProperty(property));
+        }
+        self.ast.object_expression(SPAN, props, None)
+    }
+
+    /// `var name = init;`
+    fn var_declaration(&self, name: Atom, init: Expression<'a>) -> Statement<'a> {
+        let id = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name)),
+            None,
+            false,
+        );
+        let kind = VariableDeclarationKind::Var;
+        let declarator = self.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    /// `typeof name === value`
+    fn is_typeof(&self, name: &str, operator: BinaryOperator, value: &str) -> Expression<'a> {
+        let left = self.ast.unary_expression(SPAN, UnaryOperator::Typeof, self.identifier(name));
+        self.ast.binary_expression(SPAN, left, operator, self.string(value.into()))
+    }
+
+    fn member(&self, object: Expression<'a>, property: &str) -> Expression<'a> {
+        self.ast.static_member_expression(
+            SPAN,
+            object,
+            IdentifierName::new(SPAN, property.into()),
+            false,
+        )
+    }
+
+    fn identifier(&self, name: &str) -> Expression<'a> {
+        identifier(&self.ast, name.into())
+    }
+
+    fn string(&self, value: Atom) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(SPAN, value))
+    }
+}
+
+/// `require(source)`
+fn require_call<'a>(ast: &AstBuilder<'a>, source: Expression<'a>) -> Expression<'a> {
+    let callee =
+        ast.identifier_reference_expression(IdentifierReference::new(SPAN, "require".into()));
+    ast.call_expression(SPAN, callee, ast.new_vec_single(Argument::Expression(source)), false, None)
+}
+
+fn identifier<'a>(ast: &AstBuilder<'a>, name: Atom) -> Expression<'a> {
+    ast.identifier_reference_expression(IdentifierReference::new(SPAN, name))
+}
+
+/// `_foo`, `_foo.bar` or `_foo["bar-baz"]`
+fn binding_expression<'a>(ast: &AstBuilder<'a>, binding: &Binding) -> Expression<'a> {
+    match binding {
+        Binding::Identifier(name) => identifier(ast, name.clone()),
+        Binding::Member(name, property) => {
+            let object = identifier(ast, name.clone());
+            if is_identifier_name(property) {
+                let property = IdentifierName::new(SPAN, property.clone());
+                ast.static_member_expression(SPAN, object, property, false)
+            } else {
+                let property =
+                    ast.literal_string_expression(StringLiteral::new(SPAN, property.clone()));
+                ast.computed_member_expression(SPAN, object, property, false)
+            }
+        }
+    }
+}
+
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(is_identifier_start_all) && chars.all(is_identifier_part)
+}
+
+/// `fooBar` for `./foo-bar.js` and `@scope/foo-bar`.
+fn source_name_hint(source: &str) -> String {
+    let basename = source.trim_end_matches('/').rsplit('/').next().unwrap_or(source);
+    let basename = basename.split_once('.').map_or(basename, |(name, _)| name);
+    let mut name = String::with_capacity(basename.len());
+    let mut uppercase = false;
+    for c in basename.chars() {
+        if is_identifier_part(c) && c != '$' {
+            name.push(if uppercase { c.to_ascii_uppercase() } else { c });
+            uppercase = false;
+        } else {
+            uppercase = !name.is_empty();
+        }
+    }
+    if name.is_empty() {
+        name.push_str("module");
+    }
+    name
+}
+
+/// Rewrites references to imports, top level `this` and `import()`.
+struct ModuleReferences<'a, 'b> {
+    ast: &'b AstBuilder<'a>,
+    ctx: &'b TransformerCtx<'a>,
+    import_interop: ImportInterop,
+    imports: &'b FxHashMap<SymbolId, Binding>,
+    import_names: &'b FxHashMap<Atom, Binding>,
+    /// Number of functions and classes `this` is nested in.
+    this_depth: usize,
+}
+
+impl<'a, 'b> ModuleReferences<'a, 'b> {
+    fn import_binding(&self, ident: &IdentifierReference) -> Option<&'b Binding> {
+        match ident.reference_id.get() {
+            Some(reference_id) => {
+                let symbol_id = self.ctx.symbols().get_reference(reference_id).symbol_id()?;
+                self.imports.get(&symbol_id)
+            }
+            None => self.import_names.get(&ident.name),
+        }
+    }
+
+    fn binding_expression(&self, binding: &Binding) -> Expression<'a> {
+        binding_expression(self.ast, binding)
+    }
+
+    fn identifier(&self, name: Atom) -> Expression<'a> {
+        identifier(self.ast, name)
+    }
+
+    /// `(0, _foo.bar)` so the imported function is not called with the module as `this`.
+    fn unbound_callee(&self, callee: &mut Expression<'a>) {
+        let Expression::Identifier(ident) = callee else { return };
+        let Some(binding @ Binding::Member(..)) = self.import_binding(ident) else { return };
+        let zero = self.ast.number_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+        let mut expressions = self.ast.new_vec_with_capacity(2);
+        expressions.push(self.ast.literal_number_expression(zero));
+        expressions.push(self.binding_expression(binding));
+        *callee = self.ast.sequence_expression(SPAN, expressions);
+    }
+
+    /// `Promise.resolve().then(function () { return _interopRequireWildcard(require("foo")); })`
+    /// or `Promise.resolve(`${foo}`).then(function (s) { return _interopRequireWildcard(require(s)); })`
+    fn transform_import_expression(&self, expr: &mut Expression<'a>) {
+        let Expression::ImportExpression(import) = expr else { return };
+        let source = mem::replace(&mut import.source, self.ast.void_0());
+
+        let (resolved, params, source) = if matches!(source, Expression::StringLiteral(_)) {
+            (None, self.ast.new_vec(), source)
+        } else {
+            let quasis = {
+                let mut quasis = self.ast.new_vec_with_capacity(2);
+                for tail in [false, true] {
+                    let value =
+                        self.ast.template_element_value(Atom::from(""), Some(Atom::from("")));
+                    quasis.push(self.ast.template_element(SPAN, tail, value));
+                }
+                quasis
+            };
+            let template = self.ast.template_literal_expression(self.ast.template_literal(
+                SPAN,
+                quasis,
+                self.ast.new_vec_single(source),
+            ));
+            let param = self.ast.formal_parameter(
+                SPAN,
+                self.ast.binding_pattern(
+                    self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, "s".into())),
+                    None,
+                    false,
+                ),
+                None,
+                false,
+                self.ast.new_vec(),
+            );
+            (Some(template), self.ast.new_vec_single(param), self.identifier("s".into()))
+        };
+
+        let mut module = require_call(self.ast, source);
+        match self.import_interop {
+            ImportInterop::Babel => {
+                let arguments = self.ast.new_vec_single(Argument::Expression(module));
+                module = self.ctx.helpers.call(Helper::InteropRequireWildcard, arguments);
+            }
+            ImportInterop::Node => {
+                let mut arguments = self.ast.new_vec_single(Argument::Expression(module));
+                arguments.push(Argument::Expression(
+                    self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, true)),
+                ));
+                module = self.ctx.helpers.call(Helper::InteropRequireWildcard, arguments);
+            }
+            ImportInterop::None => {}
+        }
+
+        let body = self.ast.function_body(
+            SPAN,
+            self.ast.new_vec(),
+            self.ast.new_vec_single(self.ast.return_statement(SPAN, Some(module))),
+        );
+        let params =
+            self.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, None);
+        let callback = self.ast.function_expression(self.ast.function(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            None,
+            Modifiers::empty(),
+        ));
+
+        let promise = self.identifier("Promise".into());
+        let resolve = self.ast.static_member_expression(
+            SPAN,
+            promise,
+            IdentifierName::new(SPAN, "resolve".into()),
+            false,
+        );
+        let resolve_arguments = resolved.map_or_else(
+            || self.ast.new_vec(),
+            |resolved| self.ast.new_vec_single(Argument::Expression(resolved)),
+        );
+        let resolved = self.ast.call_expression(SPAN, resolve, resolve_arguments, false, None);
+        let then = self.ast.static_member_expression(
+            SPAN,
+            resolved,
+            IdentifierName::new(SPAN, "then".into()),
+            false,
+        );
+        let arguments = self.ast.new_vec_single(Argument::Expression(callback));
+        *expr = self.ast.call_expression(SPAN, then, arguments, false, None);
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for ModuleReferences<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if let Some(binding) = self.import_binding(ident) {
+                    *expr = self.binding_expression(binding);
+                }
+                return;
+            }
+            Expression::ThisExpression(_) if self.this_depth == 0 => {
+                *expr = self.ast.void_0();
+                return;
+            }
+            Expression::CallExpression(call) => self.unbound_callee(&mut call.callee),
+            Expression::TaggedTemplateExpression(tagged) => self.unbound_callee(&mut tagged.tag),
+            _ => {}
+        }
+        self.visit_expression_match(expr);
+        if matches!(expr, Expression::ImportExpression(_)) {
+            self.transform_import_expression(expr);
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if prop.shorthand {
+            if let Expression::Identifier(ident) = &prop.value {
+                prop.shorthand = self.import_binding(ident).is_none();
+            }
+        }
+        self.visit_property_key(&mut prop.key);
+        self.visit_expression(&mut prop.value);
+        if let Some(init) = &mut prop.init {
+            self.visit_expression(init);
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        self.this_depth += 1;
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.visit_function_body(body);
+        }
+        self.this_depth -= 1;
+    }
+
+    fn visit_class_body(&mut self, body: &mut ClassBody<'a>) {
+        self.this_depth += 1;
+        for elem in body.body.iter_mut() {
+            self.visit_class_element(elem);
+        }
+        self.this_depth -= 1;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        modules::{ModuleFormat, ModulesOptions},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        modules: Some(ModulesOptions::default()),
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        (
+            "import foo, { bar } from './foo-bar.js'; import * as ns from 'ns'; import 'side-effect';
+foo(bar, ns.x, { bar }); bar``; this.x;",
+            r#""use strict";
+var _interopRequireWildcard = require("@babel/runtime/helpers/interopRequireWildcard");
+var _fooBar = _interopRequireWildcard(require("./foo-bar.js"));
+var _ns = _interopRequireWildcard(require("ns"));
+require("side-effect");
+(0, _fooBar.default)(_fooBar.bar, _ns.x, { bar: _fooBar.bar });
+(0, _fooBar.bar)``;
+(void 0).x;"#,
+        ),
+        (
+            "import a from 'a'; import { b } from 'b'; export const x = a, y = b; export function f() { return this; }",
+            r#""use strict";
+var _interopRequireDefault = require("@babel/runtime/helpers/interopRequireDefault");
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "x", { enumerable: true, get: function() { return x; } });
+Object.defineProperty(exports, "y", { enumerable: true, get: function() { return y; } });
+Object.defineProperty(exports, "f", { enumerable: true, get: function() { return f; } });
+var _a = _interopRequireDefault(require("a"));
+var _b = require("b");
+const x = _a.default, y = _b.b;
+function f() { return this; }"#,
+        ),
+        (
+            "import { a } from 'a'; let b = 1; export { a, b as c }; export { d, default as e } from 'd'; export * as ns from 'ns'; export default b;",
+            r#""use strict";
+var _interopRequireWildcard = require("@babel/runtime/helpers/interopRequireWildcard");
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "a", { enumerable: true, get: function() { return _a.a; } });
+Object.defineProperty(exports, "c", { enumerable: true, get: function() { return b; } });
+Object.defineProperty(exports, "d", { enumerable: true, get: function() { return _d.d; } });
+Object.defineProperty(exports, "e", { enumerable: true, get: function() { return _d.default; } });
+Object.defineProperty(exports, "ns", { enumerable: true, get: function() { return _ns; } });
+var _a = require("a");
+var _d = _interopRequireWildcard(require("d"));
+var _ns = _interopRequireWildcard(require("ns"));
+let b = 1;
+exports.default = b;"#,
+        ),
+        (
+            "export * from 'a'; export default class {}",
+            r#""use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "default", { enumerable: true, get: function() { return _default; } });
+var _a = require("a");
+Object.keys(_a).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (key in exports && exports[key] === _a[key]) return;
+  Object.defineProperty(exports, key, { enumerable: true, get: function() { return _a[key]; } });
+});
+class _default {}"#,
+        ),
+        (
+            "export * from 'a'; export const x = 1;",
+            r#""use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+var _exportNames = { x: true };
+Object.defineProperty(exports, "x", { enumerable: true, get: function() { return x; } });
+var _a = require("a");
+Object.keys(_a).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+  if (key in exports && exports[key] === _a[key]) return;
+  Object.defineProperty(exports, key, { enumerable: true, get: function() { return _a[key]; } });
+});
+const x = 1;"#,
+        ),
+        (
+            "const a = import('a'); const b = import(name);",
+            r#""use strict";
+var _interopRequireWildcard = require("@babel/runtime/helpers/interopRequireWildcard");
+const a = Promise.resolve().then(function() { return _interopRequireWildcard(require("a")); });
+const b = Promise.resolve(`${name}`).then(function(s) { return _interopRequireWildcard(require(s)); });"#,
+        ),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+
+    let source = "import 'a'; export default 1;";
+    let wrapped = |format| TransformOptions {
+        modules: Some(ModulesOptions {
+            format,
+            module_id: Some("m".into()),
+            ..ModulesOptions::default()
+        }),
+        ..TransformOptions::default()
+    };
+    Tester::new("test.mjs", wrapped(ModuleFormat::Amd)).test(&[(
+        source,
+        r#"define("m", ["require", "exports", "module", "a"], function(require, exports, module) {
+  "use strict";
+  Object.defineProperty(exports, "__esModule", { value: true });
+  require("a");
+  exports.default = 1;
+});"#,
+    )]);
+    Tester::new("test.mjs", wrapped(ModuleFormat::Umd)).test(&[(
+        source,
+        r#"(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define(["require", "exports", "module", "a"], factory);
+  } else if (typeof exports === "object" && typeof module !== "undefined") {
+    factory(require, exports, module);
+  } else {
+    var mod = { exports: {} };
+    factory(function(name) { return global[name]; }, mod.exports, mod);
+    global["m"] = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(require, exports, module) {
+  "use strict";
+  Object.defineProperty(exports, "__esModule", { value: true });
+  require("a");
+  exports.default = 1;
+});"#,
+    )]);
+}
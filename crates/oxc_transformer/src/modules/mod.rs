@@ -0,0 +1,44 @@
+mod commonjs;
+
+use serde::Deserialize;
+
+pub use self::commonjs::CommonJs;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModulesOptions {
+    #[serde(default)]
+    pub format: ModuleFormat,
+    /// How the default and namespace imports of CommonJS modules are resolved.
+    #[serde(default)]
+    pub import_interop: ImportInterop,
+    /// The module name of `define("name", ...)` for AMD, and the global name for UMD.
+    #[serde(default)]
+    pub module_id: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ModuleFormat {
+    #[default]
+    #[serde(rename = "commonjs")]
+    CommonJs,
+    /// CommonJS wrapped in `define(["require", "exports", "module", ...], function (require, exports, module) {})`
+    #[serde(rename = "amd")]
+    Amd,
+    /// CommonJS wrapped in a factory which works with AMD, CommonJS and browser globals.
+    #[serde(rename = "umd")]
+    Umd,
+}
+
+/// See <https://babel.dev/docs/babel-plugin-transform-modules-commonjs#importinterop>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportInterop {
+    /// `module.exports` is the default export unless the module is marked with `__esModule`.
+    #[default]
+    Babel,
+    /// `module.exports` is always the default export, as in Node.js.
+    Node,
+    /// Imports are not wrapped with any interop helper.
+    None,
+}
@@ -1,28 +1,123 @@
 use oxc_syntax::assumptions::CompilerAssumptions;
 
-use crate::{es2020::NullishCoalescingOperatorOptions, react_jsx::ReactJsxOptions};
+use crate::{
+    decorators::DecoratorsOptions,
+    es2020::NullishCoalescingOperatorOptions,
+    helpers::HelperLoaderOptions,
+    modules::ModulesOptions,
+    react_jsx::{ReactJsxOptions, ReactRefreshOptions},
+    targets::Targets,
+};
 
 #[derive(Debug, Default, Clone)]
 pub struct TransformOptions {
     pub target: TransformTarget,
+    /// Engines to support, enables the plugins they need in addition to `target`.
+    pub targets: Option<Targets>,
     pub assumptions: CompilerAssumptions,
+    /// Path of the file being transformed, used for `__source` in React JSX development mode.
+    pub filename: Option<String>,
 
     pub react_jsx: Option<ReactJsxOptions>,
+    /// React Fast Refresh, registers components and hook signatures for `react-refresh/runtime`.
+    pub react_refresh: Option<ReactRefreshOptions>,
 
+    /// Decorators are not part of any target, they are only transformed when enabled.
+    pub decorators: Option<DecoratorsOptions>,
+
+    /// Transform ES modules to CommonJS, AMD or UMD. Runs after all other transforms.
+    pub modules: Option<ModulesOptions>,
+
+    /// How runtime helpers are provided, inlined or imported from `@babel/runtime`.
+    pub helper_loader: HelperLoaderOptions,
+
+    // es2024
+    pub unicode_sets_regex: bool,
     // es2022
     pub class_static_block: bool,
+    pub class_properties: bool,
     // es2021
     pub logical_assignment_operators: bool,
     // es2020
     pub nullish_coalescing_operator: Option<NullishCoalescingOperatorOptions>,
+    pub optional_chaining: bool,
     // es2019
     pub optional_catch_binding: bool,
+    // es2018
+    pub async_generator_functions: bool,
+    pub dotall_regex: bool,
+    pub named_capturing_groups_regex: bool,
+    pub object_rest_spread: bool,
+    pub unicode_property_regex: bool,
+    // es2017
+    pub async_to_generator: bool,
     // es2016
     pub exponentiation_operator: bool,
     // es2015
+    pub arrow_functions: bool,
+    pub block_scoping: bool,
+    pub classes: bool,
+    pub computed_properties: bool,
+    pub destructuring: bool,
+    pub for_of: bool,
+    pub new_target: bool,
+    pub parameters: bool,
+    pub regenerator: bool,
     pub shorthand_properties: bool,
+    pub spread: bool,
     pub sticky_regex: bool,
     pub template_literals: bool,
+    pub unicode_regex: bool,
+}
+
+impl TransformOptions {
+    /// Enable the plugins required by `targets`.
+    #[must_use]
+    pub fn resolve_targets(mut self) -> Self {
+        let Some(targets) = &self.targets else { return self };
+        for plugin in targets.required_plugins() {
+            match plugin {
+                "transform-unicode-sets-regex" => self.unicode_sets_regex = true,
+                "transform-class-static-block" => self.class_static_block = true,
+                "transform-class-properties"
+                | "transform-private-methods"
+                | "transform-private-property-in-object" => self.class_properties = true,
+                "transform-logical-assignment-operators" => {
+                    self.logical_assignment_operators = true;
+                }
+                "transform-nullish-coalescing-operator" => {
+                    self.nullish_coalescing_operator.get_or_insert_with(Default::default);
+                }
+                "transform-optional-chaining" => self.optional_chaining = true,
+                "transform-optional-catch-binding" => self.optional_catch_binding = true,
+                "transform-async-generator-functions" => self.async_generator_functions = true,
+                "transform-dotall-regex" => self.dotall_regex = true,
+                "transform-named-capturing-groups-regex" => {
+                    self.named_capturing_groups_regex = true;
+                }
+                "transform-object-rest-spread" => self.object_rest_spread = true,
+                "transform-unicode-property-regex" => self.unicode_property_regex = true,
+                "transform-async-to-generator" => self.async_to_generator = true,
+                "transform-exponentiation-operator" => self.exponentiation_operator = true,
+                "transform-arrow-functions" => self.arrow_functions = true,
+                "transform-block-scoping" => self.block_scoping = true,
+                "transform-classes" => self.classes = true,
+                "transform-computed-properties" => self.computed_properties = true,
+                "transform-destructuring" => self.destructuring = true,
+                "transform-for-of" => self.for_of = true,
+                "transform-new-target" => self.new_target = true,
+                "transform-parameters" => self.parameters = true,
+                "transform-regenerator" => self.regenerator = true,
+                "transform-shorthand-properties" => self.shorthand_properties = true,
+                "transform-spread" => self.spread = true,
+                "transform-sticky-regex" => self.sticky_regex = true,
+                "transform-template-literals" => self.template_literals = true,
+                "transform-unicode-regex" => self.unicode_regex = true,
+                _ => {}
+            }
+        }
+        self
+    }
 }
 
 /// See <https://www.typescriptlang.org/tsconfig#target>
@@ -31,6 +126,7 @@ pub enum TransformTarget {
     ES5,
     ES2015,
     ES2016,
+    ES2017,
     ES2018,
     ES2019,
     ES2020,
@@ -1,6 +1,7 @@
 mod options;
+mod refresh;
 
-use std::rc::Rc;
+use std::{mem, rc::Rc};
 
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, AstBuilder};
@@ -12,9 +13,13 @@ use oxc_span::{Atom, GetSpan, Span, SPAN};
 use oxc_syntax::{
     identifier::{is_irregular_whitespace, is_line_terminator},
     xml_entities::XML_ENTITIES,
+    NumberBase,
 };
 
-pub use self::options::{ReactJsxOptions, ReactJsxRuntime};
+pub use self::{
+    options::{ReactJsxOptions, ReactJsxRuntime},
+    refresh::{ReactRefresh, ReactRefreshOptions},
+};
 use crate::context::TransformerCtx;
 
 #[derive(Debug, Error, Diagnostic)]
@@ -44,13 +49,21 @@ struct SpreadChildrenAreNotSupported(#[label] Span);
 
 /// Transform React JSX
 ///
+/// In development mode, the automatic runtime calls
+/// `_jsxDEV(type, props, key, isStaticChildren, source, self)` from `react/jsx-dev-runtime`,
+/// and the classic runtime adds `__self` and `__source` props.
+/// The source is `{ fileName: _jsxFileName, lineNumber, columnNumber }` of the element.
+///
 /// References:
 /// * <https://babeljs.io/docs/babel-plugin-transform-react-jsx>
 /// * <https://github.com/babel/babel/tree/main/packages/babel-helper-builder-react-jsx>
+/// * <https://babeljs.io/docs/babel-plugin-transform-react-jsx-development>
 pub struct ReactJsx<'a> {
     ast: Rc<AstBuilder<'a>>,
     ctx: TransformerCtx<'a>,
     options: ReactJsxOptions,
+    /// `_jsxFileName` in development mode
+    filename: Atom,
 
     imports: Vec<'a, Statement<'a>>,
     import_jsx: bool,
@@ -59,6 +72,12 @@ pub struct ReactJsx<'a> {
     import_create_element: bool,
     require_jsx_runtime: bool,
     jsx_runtime_importer: Atom,
+    /// `var _jsxFileName = "..."` is added when `__source` is used.
+    add_jsx_file_name: bool,
+
+    /// Whether `this` can be passed as `__self`, for each enclosing function.
+    this_allowed: std::vec::Vec<bool>,
+    in_constructor: bool,
 }
 
 enum JSXElementOrFragment<'a, 'b> {
@@ -76,6 +95,13 @@ impl<'a, 'b> JSXElementOrFragment<'a, 'b> {
         }
     }
 
+    fn span(&self) -> Span {
+        match self {
+            Self::Element(e) => e.span,
+            Self::Fragment(e) => e.span,
+        }
+    }
+
     fn children(&self) -> &'b Vec<'a, JSXChild<'a>> {
         match self {
             Self::Element(e) => &e.children,
@@ -100,21 +126,28 @@ impl<'a, 'b> JSXElementOrFragment<'a, 'b> {
 }
 
 impl<'a> ReactJsx<'a> {
-    pub fn new(ast: Rc<AstBuilder<'a>>, ctx: TransformerCtx<'a>, options: ReactJsxOptions) -> Self {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: ReactJsxOptions,
+        filename: &str,
+    ) -> Self {
         let imports = ast.new_vec();
         let options = options.with_comments(&ctx.semantic());
 
+        let runtime = if options.development { "jsx-dev-runtime" } else { "jsx-runtime" };
         let jsx_runtime_importer =
             if options.import_source == "react" || options.runtime.is_classic() {
-                Atom::new_inline("react/jsx-runtime")
+                Atom::from(format!("react/{runtime}"))
             } else {
-                Atom::from(format!("{}/jsx-runtime", options.import_source))
+                Atom::from(format!("{}/{runtime}", options.import_source))
             };
 
         Self {
             ast,
             ctx,
             options,
+            filename: Atom::from(filename),
             imports,
             jsx_runtime_importer,
             require_jsx_runtime: false,
@@ -122,9 +155,26 @@ impl<'a> ReactJsx<'a> {
             import_jsxs: false,
             import_fragment: false,
             import_create_element: false,
+            add_jsx_file_name: false,
+            this_allowed: vec![],
+            in_constructor: false,
         }
     }
 
+    pub fn enter_method_definition(&mut self, def: &MethodDefinition<'a>) {
+        self.in_constructor = def.kind == MethodDefinitionKind::Constructor;
+    }
+
+    /// `this` may be used before `super()` in constructors, so it is not passed as `__self` there.
+    pub fn enter_function(&mut self) {
+        let in_constructor = mem::take(&mut self.in_constructor);
+        self.this_allowed.push(!in_constructor);
+    }
+
+    pub fn exit_function(&mut self) {
+        self.this_allowed.pop();
+    }
+
     pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
         match expr {
             Expression::JSXElement(e) => {
@@ -142,17 +192,17 @@ impl<'a> ReactJsx<'a> {
             if self.options.import_source != "react" {
                 self.ctx.error(ImportSourceCannotBeSet);
             }
-            return;
-        }
-
-        if self.options.pragma != "React.createElement"
+        } else if self.options.pragma != "React.createElement"
             || self.options.pragma_frag != "React.Fragment"
         {
             self.ctx.error(PragmaAndPragmaFragCannotBeSet);
             return;
         }
 
-        let imports = self.ast.move_statement_vec(&mut self.imports);
+        let mut imports = self.ast.move_statement_vec(&mut self.imports);
+        if self.add_jsx_file_name {
+            imports.push(self.jsx_file_name_declaration());
+        }
         let index = program
             .body
             .iter()
@@ -189,11 +239,41 @@ impl<'a> ReactJsx<'a> {
         }
     }
 
+    /// `var _jsxFileName = "/path/to/file.jsx";`
+    fn jsx_file_name_declaration(&self) -> Statement<'a> {
+        let id = self.ast.binding_pattern(
+            self.ast
+                .binding_pattern_identifier(BindingIdentifier::new(SPAN, "_jsxFileName".into())),
+            None,
+            false,
+        );
+        let init =
+            self.ast.literal_string_expression(StringLiteral::new(SPAN, self.filename.clone()));
+        let kind = VariableDeclarationKind::Var;
+        let declarator = self.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            kind,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    /// The variable `react/jsx-runtime` is required into in scripts.
+    fn jsx_runtime_variable(&self) -> &'static str {
+        if self.options.development {
+            "_reactJsxDevRuntime"
+        } else {
+            "_reactJsxRuntime"
+        }
+    }
+
     fn add_require_jsx_runtime(&mut self) {
         if !self.require_jsx_runtime {
             self.require_jsx_runtime = true;
             self.add_require_statement(
-                "_reactJsxRuntime",
+                self.jsx_runtime_variable(),
                 Self::new_string_literal(self.jsx_runtime_importer.as_str()),
                 false,
             );
@@ -205,16 +285,21 @@ impl<'a> ReactJsx<'a> {
             self.add_require_jsx_runtime();
         } else if !self.import_jsx {
             self.import_jsx = true;
+            let (imported, local) =
+                if self.options.development { ("jsxDEV", "_jsxDEV") } else { ("jsx", "_jsx") };
             self.add_import_statement(
-                "jsx",
-                "_jsx",
+                imported,
+                local,
                 Self::new_string_literal(self.jsx_runtime_importer.as_str()),
             );
         }
     }
 
     fn add_import_jsxs(&mut self) {
-        if self.ctx.source_type().is_script() {
+        if self.options.development {
+            // `jsxDEV` is used for static children as well
+            self.add_import_jsx();
+        } else if self.ctx.source_type().is_script() {
             self.add_require_jsx_runtime();
         } else if !self.import_jsxs {
             self.import_jsxs = true;
@@ -310,7 +395,9 @@ impl<'a> ReactJsx<'a> {
     fn transform_jsx<'b>(&mut self, e: &JSXElementOrFragment<'a, 'b>) -> Expression<'a> {
         let is_classic = self.options.runtime.is_classic();
         let is_automatic = self.options.runtime.is_automatic();
+        let is_development = self.options.development;
         let has_key_after_props_spread = e.has_key_after_props_spread();
+        let is_jsx_dev = is_automatic && is_development && !has_key_after_props_spread;
 
         // TODO: compute the correct capacity for both runtimes
         let mut arguments = self.ast.new_vec_with_capacity(1);
@@ -329,7 +416,7 @@ impl<'a> ReactJsx<'a> {
         let attributes_len = attributes.map_or(0, |attrs| attrs.len());
 
         // Add `null` to second argument in classic mode
-        if is_classic && attributes_len == 0 {
+        if is_classic && attributes_len == 0 && !is_development {
             let null_expr = self.ast.literal_null_expression(NullLiteral::new(SPAN));
             arguments.push(Argument::Expression(null_expr));
         }
@@ -342,7 +429,7 @@ impl<'a> ReactJsx<'a> {
 
             for attribute in attributes {
                 // optimize `{...prop}` to `prop` in static mode
-                if is_classic && attributes_len == 1 {
+                if is_classic && attributes_len == 1 && !is_development {
                     if let JSXAttributeItem::SpreadAttribute(spread) = attribute {
                         // deopt if spreading an object with `__proto__` key
                         if !matches!(&spread.argument, Expression::ObjectExpression(o) if o.has_proto())
@@ -406,12 +493,39 @@ impl<'a> ReactJsx<'a> {
 
         self.add_import(e, has_key_after_props_spread, need_jsxs);
 
+        // `createElement` receives `__self` and `__source` as props
+        if is_development && !is_jsx_dev {
+            for (name, value) in
+                [("__self", self.jsx_self()), ("__source", self.jsx_source(e.span()))]
+            {
+                let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, name.into()));
+                let kind = PropertyKind::Init;
+                let property =
+                    self.ast.object_property(SPAN, kind, key, value, None, false, false, false);
+                properties.push(ObjectPropertyKind::ObjectProperty(property));
+            }
+        }
+
         if !properties.is_empty() || is_automatic {
             let object_expression = self.ast.object_expression(SPAN, properties, None);
             arguments.push(Argument::Expression(object_expression));
         }
 
-        if is_automatic && key_prop.is_some() {
+        if is_jsx_dev {
+            // `key, isStaticChildren, source, self`
+            let key = if key_prop.is_some() {
+                self.transform_jsx_attribute_value(key_prop)
+            } else {
+                self.ast.void_0()
+            };
+            arguments.push(Argument::Expression(key));
+            let is_static_children = BooleanLiteral::new(SPAN, need_jsxs);
+            arguments.push(Argument::Expression(
+                self.ast.literal_boolean_expression(is_static_children),
+            ));
+            arguments.push(Argument::Expression(self.jsx_source(e.span())));
+            arguments.push(Argument::Expression(self.jsx_self()));
+        } else if is_automatic && key_prop.is_some() {
             arguments.push(Argument::Expression(self.transform_jsx_attribute_value(key_prop)));
         }
 
@@ -428,6 +542,59 @@ impl<'a> ReactJsx<'a> {
         self.ast.call_expression(SPAN, callee, arguments, false, None)
     }
 
+    /// `this`, or `void 0` where `this` can not be used.
+    fn jsx_self(&self) -> Expression<'a> {
+        if self.this_allowed.last().copied().unwrap_or(true) {
+            self.ast.this_expression(SPAN)
+        } else {
+            self.ast.void_0()
+        }
+    }
+
+    /// `{ fileName: _jsxFileName, lineNumber: 1, columnNumber: 1 }`
+    fn jsx_source(&mut self, span: Span) -> Expression<'a> {
+        self.add_jsx_file_name = true;
+        let (line, column) = {
+            let semantic = self.ctx.semantic();
+            let text = &semantic.source_text()[..span.start as usize];
+            let line = text.matches('\n').count() + 1;
+            let line_start = text.rfind('\n').map_or(0, |i| i + 1);
+            let column = text[line_start..].encode_utf16().count() + 1;
+            (line, column)
+        };
+
+        let file_name = self
+            .ast
+            .identifier_reference_expression(IdentifierReference::new(SPAN, "_jsxFileName".into()));
+        let line = self.number_expression(line);
+        let column = self.number_expression(column);
+        let mut properties = self.ast.new_vec_with_capacity(3);
+        for (name, value) in
+            [("fileName", file_name), ("lineNumber", line), ("columnNumber", column)]
+        {
+            let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, name.into()));
+            let property = self.ast.object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            properties.push(ObjectPropertyKind::ObjectProperty(property));
+        }
+        self.ast.object_expression(SPAN, properties, None)
+    }
+
+    fn number_expression(&self, value: usize) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        #[allow(clippy::cast_precision_loss)]
+        let literal = self.ast.number_literal(SPAN, value as f64, raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+
     fn get_react_references(&mut self) -> Expression<'a> {
         let ident = IdentifierReference::new(SPAN, "React".into());
         self.ast.identifier_reference_expression(ident)
@@ -475,9 +642,12 @@ impl<'a> ReactJsx<'a> {
             }
             ReactJsxRuntime::Automatic => {
                 let is_script = self.ctx.source_type().is_script();
+                let is_development = self.options.development;
                 let name = if is_script {
                     if has_key_after_props_spread {
                         "createElement"
+                    } else if is_development {
+                        "jsxDEV"
                     } else if jsxs {
                         "jsxs"
                     } else {
@@ -485,6 +655,8 @@ impl<'a> ReactJsx<'a> {
                     }
                 } else if has_key_after_props_spread {
                     "_createElement"
+                } else if is_development {
+                    "_jsxDEV"
                 } else if jsxs {
                     "_jsxs"
                 } else {
@@ -492,8 +664,11 @@ impl<'a> ReactJsx<'a> {
                 };
 
                 if is_script {
-                    let object_ident_name =
-                        if has_key_after_props_spread { "_react" } else { "_reactJsxRuntime" };
+                    let object_ident_name = if has_key_after_props_spread {
+                        "_react"
+                    } else {
+                        self.jsx_runtime_variable()
+                    };
                     self.get_static_member_expression(object_ident_name, name)
                 } else {
                     let ident = IdentifierReference::new(SPAN, name.into());
@@ -516,7 +691,7 @@ impl<'a> ReactJsx<'a> {
             }
             ReactJsxRuntime::Automatic => {
                 if self.ctx.source_type().is_script() {
-                    self.get_static_member_expression("_reactJsxRuntime", "Fragment")
+                    self.get_static_member_expression(self.jsx_runtime_variable(), "Fragment")
                 } else {
                     let ident = IdentifierReference::new(SPAN, "_Fragment".into());
                     self.ast.identifier_reference_expression(ident)
@@ -744,3 +919,38 @@ impl<'a> ReactJsx<'a> {
         unsafe { String::from_utf8_unchecked(buffer) }
     }
 }
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let options = |runtime| TransformOptions {
+        react_jsx: Some(ReactJsxOptions {
+            runtime,
+            development: true,
+            ..ReactJsxOptions::default()
+        }),
+        filename: Some("/app.jsx".into()),
+        ..TransformOptions::default()
+    };
+
+    let tests = &[(
+        r#"const a = <div key="k"><span /><span /></div>;
+class A { constructor() { <b /> } }"#,
+        r#"import { jsxDEV as _jsxDEV } from "react/jsx-dev-runtime";
+var _jsxFileName = "/app.jsx";
+const a = _jsxDEV("div", { children: [
+  _jsxDEV("span", {}, void 0, false, { fileName: _jsxFileName, lineNumber: 1, columnNumber: 24 }, this),
+  _jsxDEV("span", {}, void 0, false, { fileName: _jsxFileName, lineNumber: 1, columnNumber: 32 }, this)
+] }, "k", true, { fileName: _jsxFileName, lineNumber: 1, columnNumber: 11 }, this);
+class A { constructor() { _jsxDEV("b", {}, void 0, false, { fileName: _jsxFileName, lineNumber: 2, columnNumber: 27 }, void 0); } }"#,
+    )];
+    Tester::new("test.jsx", options(ReactJsxRuntime::Automatic)).test(tests);
+
+    let tests = &[(
+        "<div />",
+        r#"var _jsxFileName = "/app.jsx";
+React.createElement("div", { __self: this, __source: { fileName: _jsxFileName, lineNumber: 1, columnNumber: 1 } });"#,
+    )];
+    Tester::new("test.jsx", options(ReactJsxRuntime::Classic)).test(tests);
+}
@@ -25,6 +25,10 @@ pub struct ReactJsxOptions {
     /// Replace the component used when compiling JSX fragments. It should be a valid JSX tag name. default to `React.Fragment`
     #[serde(default = "default_pragma_frag")]
     pub pragma_frag: Cow<'static, str>,
+    /// Development mode, adds `__source` and `__self` to the elements for React DevTools and
+    /// error messages. The automatic runtime uses `jsxDEV` from `react/jsx-dev-runtime`.
+    #[serde(default)]
+    pub development: bool,
 }
 
 fn default_throw_if_namespace() -> bool {
@@ -51,6 +55,7 @@ impl Default for ReactJsxOptions {
             import_source: default_import_source(),
             pragma: default_pragma(),
             pragma_frag: default_pragma_frag(),
+            development: false,
         }
     }
 }
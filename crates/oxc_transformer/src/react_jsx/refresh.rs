@@ -0,0 +1,664 @@
+use std::{borrow::Cow, mem, rc::Rc};
+
+use oxc_allocator::{Allocator, CloneIn, Vec};
+use oxc_ast::{ast::*, AstBuilder, Visit};
+use oxc_semantic::ScopeFlags;
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::operator::AssignmentOperator;
+use serde::Deserialize;
+
+use crate::{context::TransformerCtx, options::TransformOptions};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactRefreshOptions {
+    /// The function components are registered with. default to `$RefreshReg$`
+    #[serde(default = "default_refresh_reg")]
+    pub refresh_reg: Cow<'static, str>,
+    /// The function creating hook signatures. default to `$RefreshSig$`
+    #[serde(default = "default_refresh_sig")]
+    pub refresh_sig: Cow<'static, str>,
+}
+
+fn default_refresh_reg() -> Cow<'static, str> {
+    Cow::Borrowed("$RefreshReg$")
+}
+
+fn default_refresh_sig() -> Cow<'static, str> {
+    Cow::Borrowed("$RefreshSig$")
+}
+
+impl Default for ReactRefreshOptions {
+    fn default() -> Self {
+        Self { refresh_reg: default_refresh_reg(), refresh_sig: default_refresh_sig() }
+    }
+}
+
+/// React Fast Refresh
+///
+/// Registers the components and the hook signatures of the top level functions, so
+/// `react-refresh/runtime` can keep the state of edited components.
+///
+/// ```javascript
+/// export function App() {
+///   const [count, setCount] = useState(0);
+///   return <div>{count}</div>;
+/// }
+/// // is transformed to
+/// var _s = $RefreshSig$();
+/// export function App() {
+///   _s();
+///   const [count, setCount] = useState(0);
+///   return <div>{count}</div>;
+/// }
+/// _s(App, "useState{[count, setCount](0)}");
+/// _c = App;
+/// var _c;
+/// $RefreshReg$(_c, "App");
+/// ```
+///
+/// Components wrapped in HOCs such as `memo()` are registered together with the components
+/// they wrap, e.g. `const A = memo(_c = () => {}); _c2 = A;` registers `A$memo` and `A`.
+///
+/// Custom hooks are passed to the signature so edits to them reset the state,
+/// and `// @refresh reset` resets the state of every component of the file.
+/// Signatures are not hashed.
+///
+/// References:
+/// * <https://github.com/facebook/react/blob/main/packages/react-refresh/src/ReactFreshBabelPlugin.js>
+pub struct ReactRefresh<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    options: ReactRefreshOptions,
+    /// `// @refresh reset`
+    force_reset: bool,
+
+    /// `_s` of `var _s = $RefreshSig$()`
+    signatures: std::vec::Vec<Atom>,
+    /// `_c` and the component name of `$RefreshReg$(_c, "App")`
+    registrations: std::vec::Vec<(Atom, Atom)>,
+}
+
+/// A hook called by a function, e.g. `const [count, setCount] = useState(0)`.
+struct HookCall<'a> {
+    /// `useState`
+    name: String,
+    /// `[count, setCount](0)`
+    key: String,
+    /// `useState`, or `React.useState` for member callees
+    callee: Expression<'a>,
+}
+
+impl<'a> ReactRefresh<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let options = options.react_refresh.clone()?;
+        let force_reset = {
+            let semantic = ctx.semantic();
+            let force_reset = semantic.trivias().comments_spans().any(|(_, span)| {
+                span.source_text(semantic.source_text()).trim_start().starts_with("@refresh reset")
+            });
+            force_reset
+        };
+        Some(Self { ast, ctx, options, force_reset, signatures: vec![], registrations: vec![] })
+    }
+
+    pub fn transform_program(&mut self, program: &mut Program<'a>) {
+        let body = mem::replace(&mut program.body, self.ast.new_vec());
+        for mut stmt in body {
+            let mut after = vec![];
+            match &mut stmt {
+                Statement::Declaration(decl) => self.transform_declaration(decl, &mut after),
+                Statement::ModuleDeclaration(decl) => match &mut **decl {
+                    ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                        if let Some(decl) = &mut decl.declaration {
+                            self.transform_declaration(decl, &mut after);
+                        }
+                    }
+                    ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                        match &mut decl.declaration {
+                            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                                self.transform_function_declaration(func, &mut after);
+                            }
+                            // `export default memo(() => {})`
+                            ExportDefaultDeclarationKind::Expression(
+                                expr @ Expression::CallExpression(_),
+                            ) => {
+                                self.find_inner_components("%default%", expr, true);
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+            program.body.push(stmt);
+            program.body.extend(after);
+        }
+
+        if !self.signatures.is_empty() {
+            // `var _s = $RefreshSig$(), _s2 = $RefreshSig$();`
+            let mut declarations = self.ast.new_vec_with_capacity(self.signatures.len());
+            for name in &self.signatures {
+                let init = self.call(self.options.refresh_sig.clone().into(), self.ast.new_vec());
+                declarations.push(self.var_declarator(name.clone(), Some(init)));
+            }
+            let index = program
+                .body
+                .iter()
+                .rposition(|stmt| matches!(stmt, Statement::ModuleDeclaration(m) if m.is_import()))
+                .map_or(0, |i| i + 1);
+            program.body.insert(index, self.var_declaration(declarations));
+        }
+
+        if !self.registrations.is_empty() {
+            // `var _c, _c2;`
+            let mut declarations = self.ast.new_vec_with_capacity(self.registrations.len());
+            for (handle, _) in &self.registrations {
+                declarations.push(self.var_declarator(handle.clone(), None));
+            }
+            program.body.push(self.var_declaration(declarations));
+
+            // `$RefreshReg$(_c, "App");`
+            for (handle, name) in &self.registrations {
+                let mut arguments = self.ast.new_vec_with_capacity(2);
+                arguments.push(Argument::Expression(self.identifier(handle.clone())));
+                arguments.push(Argument::Expression(self.string(name.clone())));
+                let call = self.call(self.options.refresh_reg.clone().into(), arguments);
+                program.body.push(self.ast.expression_statement(SPAN, call));
+            }
+        }
+    }
+
+    fn transform_declaration(
+        &mut self,
+        decl: &mut Declaration<'a>,
+        after: &mut std::vec::Vec<Statement<'a>>,
+    ) {
+        match decl {
+            Declaration::FunctionDeclaration(func) => {
+                self.transform_function_declaration(func, after);
+            }
+            Declaration::VariableDeclaration(decl) => {
+                for declarator in decl.declarations.iter_mut() {
+                    self.transform_variable_declarator(declarator, after);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `function App() {}`
+    fn transform_function_declaration(
+        &mut self,
+        func: &mut Function<'a>,
+        after: &mut std::vec::Vec<Statement<'a>>,
+    ) {
+        let Some(name) = func.id.as_ref().map(|id| id.name.clone()) else { return };
+        if let Some(body) = &mut func.body {
+            if let Some(signature) = self.create_signature(body) {
+                // `_s(App, "useState{[count, setCount](0)}")`
+                let target = self.identifier(name.clone());
+                let expr = self.call_signature(signature, target);
+                after.push(self.ast.expression_statement(SPAN, expr));
+            }
+        }
+        if is_componentish_name(&name) {
+            self.register_after(name, after);
+        }
+    }
+
+    /// `const App = () => {}`, `const App = function () {}` and `const App = memo(() => {})`
+    fn transform_variable_declarator(
+        &mut self,
+        declarator: &mut VariableDeclarator<'a>,
+        after: &mut std::vec::Vec<Statement<'a>>,
+    ) {
+        let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else { return };
+        let name = id.name.clone();
+        let Some(init) = &mut declarator.init else { return };
+
+        if !is_componentish_name(&name) {
+            // `const useCustom = () => {}` is signed but not registered
+            self.sign_function(init);
+            return;
+        }
+        if let Expression::CallExpression(call) = init {
+            // `const Foo = require("foo")` is not a HOC
+            if matches!(&call.callee, Expression::Identifier(ident)
+                if ident.name.starts_with("require") || ident.name.starts_with("import"))
+            {
+                return;
+            }
+        }
+        // The value is not wrapped in `_c = ` so the function keeps its inferred name
+        if self.find_inner_components(&name, init, false) {
+            self.register_after(name, after);
+        }
+    }
+
+    /// Registers the components in `expr`, which is the value of the component `name`.
+    /// For `memo(forwardRef(() => {}))`, the arrow function is registered as
+    /// `App$memo$forwardRef`, the `forwardRef()` call as `App$memo`,
+    /// and the `memo()` call as `App` when `wrap` is `true`.
+    ///
+    /// Returns `false` if `expr` does not look like a component.
+    fn find_inner_components(&mut self, name: &str, expr: &mut Expression<'a>, wrap: bool) -> bool {
+        match expr {
+            // `memo(Foo)`, where `Foo` is registered by its own declaration
+            Expression::Identifier(ident) => return is_componentish_name(&ident.name),
+            Expression::ArrowExpression(arrow) => {
+                // `() => () => {}` is a factory
+                let returns_arrow = arrow.expression
+                    && matches!(arrow.body.statements.first(), Some(Statement::ExpressionStatement(stmt))
+                        if matches!(stmt.expression, Expression::ArrowExpression(_)));
+                self.sign_function(expr);
+                if returns_arrow {
+                    return false;
+                }
+            }
+            Expression::FunctionExpression(_) => self.sign_function(expr),
+            Expression::CallExpression(call) => {
+                if !matches!(
+                    call.callee,
+                    Expression::Identifier(_) | Expression::MemberExpression(_)
+                ) {
+                    return false;
+                }
+                let callee =
+                    call.callee.span().source_text(self.ctx.semantic().source_text()).to_string();
+                let Some(Argument::Expression(arg)) = call.arguments.first_mut() else {
+                    return false;
+                };
+                if !self.find_inner_components(&format!("{name}${callee}"), arg, true) {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+        if wrap {
+            // `_c = () => {}`
+            let handle = self.create_registration(Atom::from(name));
+            let value = mem::replace(expr, self.ast.void_0());
+            *expr = self.assign(handle, value);
+        }
+        true
+    }
+
+    /// Wraps a function calling hooks in its signature, `_s(() => {}, "useState{}")`.
+    fn sign_function(&mut self, expr: &mut Expression<'a>) {
+        let signature = match expr {
+            Expression::ArrowExpression(arrow) => {
+                let signature = self.create_signature(&mut arrow.body);
+                if signature.is_some() && arrow.expression {
+                    self.arrow_expression_to_block(arrow);
+                }
+                signature
+            }
+            Expression::FunctionExpression(func) => {
+                func.body.as_mut().and_then(|body| self.create_signature(body))
+            }
+            _ => None,
+        };
+        if let Some(signature) = signature {
+            let target = mem::replace(expr, self.ast.void_0());
+            *expr = self.call_signature(signature, target);
+        }
+    }
+
+    /// `() => expr` to `() => { return expr; }`
+    fn arrow_expression_to_block(&self, arrow: &mut ArrowExpression<'a>) {
+        arrow.expression = false;
+        if let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() {
+            let expr = stmt.unbox().expression;
+            let span = expr.span();
+            arrow.body.statements.push(self.ast.return_statement(span, Some(expr)));
+        }
+    }
+
+    /// `_c = App;` after the declaration of a component.
+    fn register_after(&mut self, name: Atom, after: &mut std::vec::Vec<Statement<'a>>) {
+        let value = self.identifier(name.clone());
+        let handle = self.create_registration(name);
+        after.push(self.ast.expression_statement(SPAN, self.assign(handle, value)));
+    }
+
+    /// The `_c` handle of a component, registered at the end of the program.
+    fn create_registration(&mut self, name: Atom) -> Atom {
+        let handle = self.ctx.scopes().generate_uid_based_on_name("c");
+        self.ctx.add_binding(handle.clone());
+        self.registrations.push((handle.clone(), name));
+        handle
+    }
+
+    /// Inserts `_s();` into the function body and returns the arguments of the signature call
+    /// `_s(target, "key", forceReset, function () { return [useCustomHook]; })`.
+    fn create_signature(&mut self, body: &mut FunctionBody<'a>) -> Option<Signature<'a>> {
+        let hook_calls = {
+            let semantic = self.ctx.semantic();
+            let mut collector = HookCallCollector {
+                allocator: self.ast.allocator,
+                source_text: semantic.source_text(),
+                calls: vec![],
+            };
+            collector.visit_function_body(body);
+            collector.calls
+        };
+        if hook_calls.is_empty() {
+            return None;
+        }
+
+        let key = hook_calls
+            .iter()
+            .map(|call| format!("{}{{{}}}", call.name, call.key))
+            .collect::<std::vec::Vec<_>>()
+            .join("\n");
+        let mut force_reset = self.force_reset;
+        let mut custom_hooks = self.ast.new_vec();
+        for call in hook_calls.into_iter().filter(|call| !is_builtin_hook(&call.name)) {
+            let scopes = self.ctx.scopes();
+            let is_bound = root_name(&call.callee)
+                .is_some_and(|name| scopes.has_binding(scopes.root_scope_id(), name));
+            if is_bound {
+                custom_hooks.push(ArrayExpressionElement::Expression(call.callee));
+            } else {
+                // The hook may change without this file being edited
+                force_reset = true;
+            }
+        }
+
+        let name = self.ctx.scopes().generate_uid_based_on_name("s");
+        self.ctx.add_binding(name.clone());
+        let call = self.call(name.clone(), self.ast.new_vec());
+        body.statements.insert(0, self.ast.expression_statement(SPAN, call));
+        self.signatures.push(name.clone());
+
+        let mut arguments = self.ast.new_vec_with_capacity(3);
+        arguments.push(Argument::Expression(self.string(Atom::from(key))));
+        if force_reset || !custom_hooks.is_empty() {
+            let force_reset = self.ast.boolean_literal(SPAN, force_reset);
+            arguments.push(Argument::Expression(self.ast.literal_boolean_expression(force_reset)));
+        }
+        if !custom_hooks.is_empty() {
+            // `function () { return [useCustomHook]; }`
+            let hooks = self.ast.array_expression(SPAN, custom_hooks, None);
+            let body = self.ast.function_body(
+                SPAN,
+                self.ast.new_vec(),
+                self.ast.new_vec_single(self.ast.return_statement(SPAN, Some(hooks))),
+            );
+            let params = self.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                self.ast.new_vec(),
+                None,
+            );
+            arguments.push(Argument::Expression(self.ast.function_expression(self.ast.function(
+                FunctionType::FunctionExpression,
+                SPAN,
+                None,
+                false,
+                false,
+                false,
+                params,
+                Some(body),
+                None,
+                None,
+                None,
+                Modifiers::empty(),
+            ))));
+        }
+        Some(Signature { name, arguments })
+    }
+
+    /// `_s(target, "key")`
+    fn call_signature(&self, signature: Signature<'a>, target: Expression<'a>) -> Expression<'a> {
+        let Signature { name, mut arguments } = signature;
+        arguments.insert(0, Argument::Expression(target));
+        self.call(name, arguments)
+    }
+
+    fn identifier(&self, name: Atom) -> Expression<'a> {
+        self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name))
+    }
+
+    fn string(&self, value: Atom) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral::new(SPAN, value))
+    }
+
+    fn call(&self, name: Atom, arguments: Vec<'a, Argument<'a>>) -> Expression<'a> {
+        self.ast.call_expression(SPAN, self.identifier(name), arguments, false, None)
+    }
+
+    /// `name = value`
+    fn assign(&self, name: Atom, value: Expression<'a>) -> Expression<'a> {
+        let target =
+            self.ast.simple_assignment_target_identifier(IdentifierReference::new(SPAN, name));
+        self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+
+    fn var_declarator(&self, name: Atom, init: Option<Expression<'a>>) -> VariableDeclarator<'a> {
+        let id = self.ast.binding_pattern(
+            self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name)),
+            None,
+            false,
+        );
+        self.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, init, false)
+    }
+
+    fn var_declaration(&self, declarations: Vec<'a, VariableDeclarator<'a>>) -> Statement<'a> {
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+}
+
+/// The signature call of a function without its target.
+struct Signature<'a> {
+    /// `_s`
+    name: Atom,
+    /// `"key", forceReset, function () { return [useCustomHook]; }`
+    arguments: Vec<'a, Argument<'a>>,
+}
+
+/// `App`, components are named in PascalCase
+fn is_componentish_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+/// `React` of `React.useState`
+fn root_name<'e>(expr: &'e Expression) -> Option<&'e Atom> {
+    match expr {
+        Expression::Identifier(ident) => Some(&ident.name),
+        Expression::MemberExpression(member) => root_name(member.object()),
+        _ => None,
+    }
+}
+
+/// `useState`, `use`
+fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use").is_some_and(|rest| {
+        rest.is_empty() || rest.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+    })
+}
+
+fn is_builtin_hook(name: &str) -> bool {
+    matches!(
+        name,
+        "useState"
+            | "useReducer"
+            | "useEffect"
+            | "useLayoutEffect"
+            | "useInsertionEffect"
+            | "useMemo"
+            | "useCallback"
+            | "useRef"
+            | "useContext"
+            | "useImperativeHandle"
+            | "useDebugValue"
+            | "useId"
+            | "useDeferredValue"
+            | "useTransition"
+            | "useSyncExternalStore"
+            | "useOptimistic"
+            | "useActionState"
+            | "useFormStatus"
+            | "useFormState"
+    )
+}
+
+/// Collects the hooks called by a function, without the calls of nested functions.
+struct HookCallCollector<'a, 's> {
+    allocator: &'a Allocator,
+    source_text: &'s str,
+    calls: std::vec::Vec<HookCall<'a>>,
+}
+
+impl<'a, 's> HookCallCollector<'a, 's> {
+    /// Returns `true` if `call` is a hook call.
+    fn add_hook_call(&mut self, call: &CallExpression<'a>, id: Option<&BindingPattern>) -> bool {
+        let name = match &call.callee {
+            Expression::Identifier(ident) => ident.name.as_str(),
+            Expression::MemberExpression(member) => match member.static_property_name() {
+                Some(name) => name,
+                None => return false,
+            },
+            _ => return false,
+        };
+        if !is_hook_name(name) {
+            return false;
+        }
+
+        let mut key =
+            id.map_or_else(String::new, |id| id.span().source_text(self.source_text).to_string());
+        let initial_state = match name {
+            "useState" => call.arguments.first(),
+            "useReducer" => call.arguments.get(1),
+            _ => None,
+        };
+        if let Some(arg) = initial_state {
+            key.push_str(&format!("({})", arg.span().source_text(self.source_text)));
+        }
+        self.calls.push(HookCall {
+            name: name.to_string(),
+            key,
+            callee: call.callee.clone_in(self.allocator),
+        });
+        true
+    }
+
+    fn visit_call_arguments(&mut self, call: &CallExpression<'a>) {
+        for arg in &call.arguments {
+            self.visit_argument(arg);
+        }
+        self.visit_expression(&call.callee);
+    }
+}
+
+impl<'a, 's> Visit<'a> for HookCallCollector<'a, 's> {
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+
+    fn visit_variable_declarator(&mut self, declarator: &VariableDeclarator<'a>) {
+        let Some(init) = &declarator.init else { return };
+        if let Expression::CallExpression(call) = init {
+            if self.add_hook_call(call, Some(&declarator.id)) {
+                self.visit_call_arguments(call);
+                return;
+            }
+        }
+        self.visit_expression(init);
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        self.add_hook_call(call, None);
+        self.visit_call_arguments(call);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let options = TransformOptions {
+        react_refresh: Some(ReactRefreshOptions::default()),
+        ..TransformOptions::default()
+    };
+    let tests = &[
+        (
+            "import { useState } from 'react';
+import { useCustom } from './hooks';
+export function App() { const [count, setCount] = useState(0); useCustom(); return count; }
+const Button = () => useState(1);
+function helper() {}",
+            r#"import { useState } from 'react';
+import { useCustom } from './hooks';
+var _s = $RefreshSig$(), _s2 = $RefreshSig$();
+export function App() { _s(); const [count, setCount] = useState(0); useCustom(); return count; }
+_s(App, "useState{[count, setCount](0)}\nuseCustom{}", false, function () { return [useCustom]; });
+_c = App;
+const Button = _s2(() => { _s2(); return useState(1); }, "useState{(1)}");
+_c2 = Button;
+function helper() {}
+var _c, _c2;
+$RefreshReg$(_c, "App");
+$RefreshReg$(_c2, "Button");"#,
+        ),
+        (
+            "// @refresh reset
+export default function Page() { React.useEffect(() => { useNested(); }); useGlobal(); }",
+            r#"// @refresh reset
+var _s = $RefreshSig$();
+export default function Page() { _s(); React.useEffect(() => { useNested(); }); useGlobal(); }
+_s(Page, "useEffect{}\nuseGlobal{}", true);
+_c = Page;
+var _c;
+$RefreshReg$(_c, "Page");"#,
+        ),
+        (
+            "import { memo, forwardRef } from 'react';
+export const A = memo(forwardRef((props, ref) => { const [x] = useState(); return x; }));
+const B = React.memo(function () {});
+const C = memo(A);
+const D = memo(() => () => {});
+const E = require('e');
+export default memo(A);",
+            r#"import { memo, forwardRef } from 'react';
+var _s = $RefreshSig$();
+export const A = memo(_c2 = forwardRef(_c = _s((props, ref) => { _s(); const [x] = useState(); return x; }, "useState{[x]}")));
+_c3 = A;
+const B = React.memo(_c4 = function () {});
+_c5 = B;
+const C = memo(A);
+_c6 = C;
+const D = memo(() => () => {});
+const E = require('e');
+export default _c7 = memo(A);
+var _c, _c2, _c3, _c4, _c5, _c6, _c7;
+$RefreshReg$(_c, "A$memo$forwardRef");
+$RefreshReg$(_c2, "A$memo");
+$RefreshReg$(_c3, "A");
+$RefreshReg$(_c4, "B$React.memo");
+$RefreshReg$(_c5, "B");
+$RefreshReg$(_c6, "C");
+$RefreshReg$(_c7, "%default%");"#,
+        ),
+    ];
+    Tester::new("test.mjs", options).test(tests);
+}
@@ -0,0 +1,315 @@
+use std::fmt::Write;
+
+pub const MAX_CODE_POINT: u32 = 0x10_FFFF;
+pub const MAX_CODE_UNIT: u32 = 0xFFFF;
+
+const HIGH_SURROGATES: (u32, u32) = (0xD800, 0xDBFF);
+const LOW_SURROGATES: (u32, u32) = (0xDC00, 0xDFFF);
+
+/// A set of code points, stored as sorted and non-adjacent inclusive ranges.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CodePointSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CodePointSet {
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut set = Self::default();
+        for (start, end) in ranges {
+            set.add_range(start, end);
+        }
+        set
+    }
+
+    /// `\d`
+    pub fn digit() -> Self {
+        Self::from_ranges([('0' as u32, '9' as u32)])
+    }
+
+    /// `\w`
+    pub fn word() -> Self {
+        Self::from_ranges([
+            ('0' as u32, '9' as u32),
+            ('A' as u32, 'Z' as u32),
+            ('_' as u32, '_' as u32),
+            ('a' as u32, 'z' as u32),
+        ])
+    }
+
+    /// `\s`, WhiteSpace and LineTerminator
+    pub fn space() -> Self {
+        Self::from_ranges([
+            (0x09, 0x0D),
+            (0x20, 0x20),
+            (0xA0, 0xA0),
+            (0x1680, 0x1680),
+            (0x2000, 0x200A),
+            (0x2028, 0x2029),
+            (0x202F, 0x202F),
+            (0x205F, 0x205F),
+            (0x3000, 0x3000),
+            (0xFEFF, 0xFEFF),
+        ])
+    }
+
+    /// Everything matched by `.` without the `s` flag.
+    pub fn dot(max: u32) -> Self {
+        Self::from_ranges([(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)]).complement(max)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn add(&mut self, code_point: u32) {
+        self.add_range(code_point, code_point);
+    }
+
+    pub fn add_range(&mut self, start: u32, end: u32) {
+        let (start, end) = if start <= end { (start, end) } else { (end, start) };
+        let index = self.ranges.partition_point(|&(_, e)| e.saturating_add(1) < start);
+        let mut merged = (start, end);
+        let mut last = index;
+        while last < self.ranges.len() && self.ranges[last].0 <= end.saturating_add(1) {
+            merged.0 = merged.0.min(self.ranges[last].0);
+            merged.1 = merged.1.max(self.ranges[last].1);
+            last += 1;
+        }
+        self.ranges.splice(index..last, [merged]);
+    }
+
+    pub fn union(&mut self, other: &Self) {
+        for &(start, end) in &other.ranges {
+            self.add_range(start, end);
+        }
+    }
+
+    /// The code points in `0..=max` which are not in this set.
+    pub fn complement(&self, max: u32) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(start, end) in &self.ranges {
+            if start > max {
+                break;
+            }
+            if start > next {
+                ranges.push((next, start - 1));
+            }
+            next = end + 1;
+        }
+        if next <= max {
+            ranges.push((next, max));
+        }
+        Self { ranges }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = self.ranges[i];
+            let (b_start, b_end) = other.ranges[j];
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(MAX_CODE_POINT))
+    }
+
+    fn within(&self, start: u32, end: u32) -> Self {
+        self.intersection(&Self { ranges: vec![(start, end)] })
+    }
+
+    /// Encodes the set as a character class, or a single character,
+    /// with `\u{...}` escapes for astral code points when `unicode` is true.
+    pub fn to_class(&self, negated: bool, unicode: bool) -> String {
+        let mut out = String::new();
+        if !negated && self.ranges.len() == 1 && self.ranges[0].0 == self.ranges[0].1 {
+            match self.ranges[0].0 {
+                // The following character of the pattern is unknown
+                0 => out.push_str("\\x00"),
+                code_point => write_char(&mut out, code_point, false, unicode),
+            }
+            return out;
+        }
+        out.push('[');
+        if negated {
+            out.push('^');
+        }
+        for (i, &(start, end)) in self.ranges.iter().enumerate() {
+            // `\0` followed by a digit would be read as an octal escape
+            let next_is_digit =
+                |code_point: u32| char::from_u32(code_point).is_some_and(|c| c.is_ascii_digit());
+            let write = |out: &mut String, code_point: u32, next: Option<u32>| {
+                if code_point == 0 && next.is_some_and(next_is_digit) {
+                    out.push_str("\\x00");
+                } else {
+                    write_char(out, code_point, true, unicode);
+                }
+            };
+            let next = self.ranges.get(i + 1).map(|r| r.0);
+            if start == end {
+                write(&mut out, start, next);
+            } else if start + 1 == end {
+                write(&mut out, start, Some(end));
+                write(&mut out, end, next);
+            } else {
+                write(&mut out, start, None);
+                out.push('-');
+                write(&mut out, end, next);
+            }
+        }
+        out.push(']');
+        out
+    }
+
+    /// Encodes the set for a regex without the `u` flag,
+    /// where astral code points are matched as surrogate pairs.
+    ///
+    /// Returns the pattern and whether it is a single atom, i.e. it can be quantified as is.
+    pub fn to_surrogate_pattern(&self) -> (String, bool) {
+        let bmp = self.within(0, HIGH_SURROGATES.0 - 1).union_with(&self.within(0xE000, 0xFFFF));
+        let lone_high = self.within(HIGH_SURROGATES.0, HIGH_SURROGATES.1);
+        let lone_low = self.within(LOW_SURROGATES.0, LOW_SURROGATES.1);
+        let astral = self.within(0x1_0000, MAX_CODE_POINT);
+
+        let mut parts = vec![];
+        if !bmp.is_empty() {
+            parts.push(bmp.to_class(false, false));
+        }
+        parts.extend(astral.surrogate_pairs());
+        if !lone_high.is_empty() {
+            parts.push(format!("{}(?![\\uDC00-\\uDFFF])", lone_high.to_class(false, false)));
+        }
+        if !lone_low.is_empty() {
+            parts.push(format!("(?:[^\\uD800-\\uDBFF]|^){}", lone_low.to_class(false, false)));
+        }
+        match parts.len() {
+            0 => ("[]".into(), true),
+            // Only the BMP part is a character class, the others are sequences
+            1 => (parts.pop().unwrap(), !bmp.is_empty()),
+            _ => (format!("(?:{})", parts.join("|")), true),
+        }
+    }
+
+    fn union_with(mut self, other: &Self) -> Self {
+        self.union(other);
+        self
+    }
+
+    /// `\uD83D[\uDE00-\uDE4F]|[\uD83E-\uD83F][\uDC00-\uDFFF]`
+    fn surrogate_pairs(&self) -> Vec<String> {
+        // (high surrogates, low surrogates)
+        let mut pairs: Vec<((u32, u32), (u32, u32))> = vec![];
+        let mut push = |high: (u32, u32), low: (u32, u32)| {
+            if let Some(last) = pairs.last_mut() {
+                if last.1 == low && last.0 .1 + 1 == high.0 {
+                    last.0 .1 = high.1;
+                    return;
+                }
+            }
+            pairs.push((high, low));
+        };
+        for &(start, end) in &self.ranges {
+            let (start_high, start_low) = split_surrogates(start);
+            let (end_high, end_low) = split_surrogates(end);
+            if start_high == end_high {
+                push((start_high, start_high), (start_low, end_low));
+                continue;
+            }
+            let mut middle = (start_high + 1, end_high - 1);
+            if start_low == LOW_SURROGATES.0 {
+                middle.0 = start_high;
+            } else {
+                push((start_high, start_high), (start_low, LOW_SURROGATES.1));
+            }
+            if end_low == LOW_SURROGATES.1 {
+                middle.1 = end_high;
+            }
+            if middle.0 <= middle.1 {
+                push(middle, LOW_SURROGATES);
+            }
+            if end_low != LOW_SURROGATES.1 {
+                push((end_high, end_high), (LOW_SURROGATES.0, end_low));
+            }
+        }
+        pairs
+            .into_iter()
+            .map(|(high, low)| {
+                let high = Self { ranges: vec![high] }.to_class(false, false);
+                let low = Self { ranges: vec![low] }.to_class(false, false);
+                high + &low
+            })
+            .collect()
+    }
+}
+
+/// Splits an astral code point into its high and low surrogates.
+pub fn split_surrogates(code_point: u32) -> (u32, u32) {
+    let offset = code_point - 0x1_0000;
+    (HIGH_SURROGATES.0 + (offset >> 10), LOW_SURROGATES.0 + (offset & 0x3FF))
+}
+
+/// Writes a code point as it would be written by hand in a pattern,
+/// escaping syntax characters and non-printable characters.
+pub fn write_char(out: &mut String, code_point: u32, in_class: bool, unicode: bool) {
+    match code_point {
+        0x00 => out.push_str("\\0"),
+        0x09 => out.push_str("\\t"),
+        0x0A => out.push_str("\\n"),
+        0x0C => out.push_str("\\f"),
+        0x0D => out.push_str("\\r"),
+        0x20..=0x7E => {
+            let c = char::from_u32(code_point).unwrap();
+            let escape = if in_class {
+                matches!(c, '\\' | ']' | '[' | '-' | '^')
+            } else {
+                matches!(
+                    c,
+                    '\\' | '^'
+                        | '$'
+                        | '.'
+                        | '*'
+                        | '+'
+                        | '?'
+                        | '('
+                        | ')'
+                        | '['
+                        | ']'
+                        | '{'
+                        | '}'
+                        | '|'
+                        | '/'
+                )
+            };
+            if escape {
+                out.push('\\');
+            }
+            out.push(c);
+        }
+        0x01..=0xFF => {
+            write!(out, "\\x{code_point:02X}").unwrap();
+        }
+        0x100..=0xFFFF => {
+            write!(out, "\\u{code_point:04X}").unwrap();
+        }
+        _ if unicode => {
+            write!(out, "\\u{{{code_point:X}}}").unwrap();
+        }
+        _ => {
+            let (high, low) = split_surrogates(code_point);
+            write!(out, "\\u{high:04X}\\u{low:04X}").unwrap();
+        }
+    }
+}
@@ -1,3 +1,7 @@
+mod code_points;
+mod pattern;
 mod regexp_flags;
+mod regexp_syntax;
 
 pub use regexp_flags::RegexpFlags;
+pub use regexp_syntax::RegexpSyntax;
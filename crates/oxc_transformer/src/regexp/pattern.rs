@@ -0,0 +1,594 @@
+//! A parser for the regular expression patterns, just enough for rewriting them.
+//!
+//! Atoms keep their source text so that the parts which are not transformed are printed as written.
+//!
+//! See <https://tc39.es/ecma262/#sec-patterns>
+
+/// `a|b`
+#[derive(Debug)]
+pub struct Disjunction<'s> {
+    pub alternatives: Vec<Alternative<'s>>,
+}
+
+/// `ab`
+#[derive(Debug)]
+pub struct Alternative<'s> {
+    pub terms: Vec<Term<'s>>,
+}
+
+/// An atom and its quantifier, e.g. `a+?` or `(a){1,2}`
+#[derive(Debug)]
+pub struct Term<'s> {
+    pub atom: Atom<'s>,
+    pub quantifier: Option<&'s str>,
+}
+
+#[derive(Debug)]
+pub enum Atom<'s> {
+    /// `^`, `$`, `\b` and `\B`
+    Assertion(&'s str),
+    /// A literal or escaped character
+    Char {
+        value: u32,
+        raw: &'s str,
+    },
+    /// `.`
+    Dot,
+    /// `\d`, `\D`, `\s`, `\S`, `\w` and `\W`
+    Escape(CharacterClassEscape),
+    /// `\p{...}` and `\P{...}`
+    Property {
+        negated: bool,
+        name: &'s str,
+    },
+    Class(CharacterClass<'s>),
+    Group(Group<'s>),
+    /// `\1`
+    Backreference {
+        index: u32,
+        raw: &'s str,
+    },
+    /// `\k<name>`
+    NamedBackreference(&'s str),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CharacterClassEscape {
+    pub kind: char,
+    pub negated: bool,
+}
+
+#[derive(Debug)]
+pub struct Group<'s> {
+    /// `""` for capturing groups, otherwise `?:`, `?=`, `?!`, `?<=`, `?<!` or modifiers such as `?i:`
+    pub prefix: &'s str,
+    pub name: Option<&'s str>,
+    pub body: Disjunction<'s>,
+}
+
+#[derive(Debug)]
+pub struct CharacterClass<'s> {
+    pub negated: bool,
+    pub kind: ClassSetKind,
+    pub items: Vec<ClassItem<'s>>,
+    pub raw: &'s str,
+}
+
+/// How the items of a character class are combined, only the `v` flag allows `&&` and `--`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassSetKind {
+    Union,
+    Intersection,
+    Subtraction,
+}
+
+#[derive(Debug)]
+pub enum ClassItem<'s> {
+    Char(u32),
+    Range(u32, u32),
+    Escape(CharacterClassEscape),
+    Property {
+        negated: bool,
+        name: &'s str,
+    },
+    /// Nested class with the `v` flag
+    Class(CharacterClass<'s>),
+    /// `\q{abc|def}` with the `v` flag
+    Strings(Vec<Vec<u32>>),
+}
+
+/// The pattern and its capturing groups.
+#[derive(Debug)]
+pub struct Pattern<'s> {
+    pub body: Disjunction<'s>,
+    /// Group names and their indexes, in the order of the groups.
+    pub group_names: Vec<(&'s str, u32)>,
+}
+
+impl<'s> Pattern<'s> {
+    /// Returns `None` for patterns which are not valid, or not supported.
+    pub fn parse(source: &'s str, unicode: bool, unicode_sets: bool) -> Option<Self> {
+        let mut parser = PatternParser {
+            source,
+            pos: 0,
+            unicode: unicode || unicode_sets,
+            unicode_sets,
+            named_groups: false,
+            group_count: 0,
+            total_groups: 0,
+            group_names: vec![],
+        };
+        parser.count_groups();
+        let body = parser.parse_disjunction()?;
+        if parser.pos != source.len() {
+            return None;
+        }
+        Some(Self { body, group_names: parser.group_names })
+    }
+}
+
+struct PatternParser<'s> {
+    source: &'s str,
+    pos: usize,
+    /// `u` or `v` flag
+    unicode: bool,
+    /// `v` flag
+    unicode_sets: bool,
+    /// `\k<name>` is only a backreference when the pattern has named groups, or with the `u` flag
+    named_groups: bool,
+    group_count: u32,
+    /// `\10` is a backreference only when there are at least 10 groups
+    total_groups: u32,
+    group_names: Vec<(&'s str, u32)>,
+}
+
+impl<'s> PatternParser<'s> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source[self.pos..].chars().nth(n)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if self.source[self.pos..].starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Counts the capturing groups ahead of time for backreferences to later groups.
+    fn count_groups(&mut self) {
+        let mut chars = self.source.char_indices();
+        let mut in_class = false;
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '(' if !in_class => {
+                    let rest = &self.source[i + 1..];
+                    if !rest.starts_with('?') {
+                        self.total_groups += 1;
+                    } else if rest.starts_with("?<")
+                        && !rest.starts_with("?<=")
+                        && !rest.starts_with("?<!")
+                    {
+                        self.total_groups += 1;
+                        self.named_groups = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_disjunction(&mut self) -> Option<Disjunction<'s>> {
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+        Some(Disjunction { alternatives })
+    }
+
+    fn parse_alternative(&mut self) -> Option<Alternative<'s>> {
+        let mut terms = vec![];
+        while let Some(c) = self.peek() {
+            if matches!(c, '|' | ')') {
+                break;
+            }
+            let atom = self.parse_atom()?;
+            let quantifier =
+                if matches!(atom, Atom::Assertion(_)) { None } else { self.parse_quantifier() };
+            terms.push(Term { atom, quantifier });
+        }
+        Some(Alternative { terms })
+    }
+
+    fn parse_quantifier(&mut self) -> Option<&'s str> {
+        let start = self.pos;
+        match self.peek()? {
+            '*' | '+' | '?' => {
+                self.next();
+            }
+            '{' => {
+                let rest = &self.source[self.pos + 1..];
+                let end = rest.find('}')?;
+                let inner = &rest[..end];
+                let mut parts = inner.splitn(2, ',');
+                let min = parts.next()?;
+                let valid = !min.is_empty()
+                    && min.bytes().all(|b| b.is_ascii_digit())
+                    && parts.next().map_or(true, |max| max.bytes().all(|b| b.is_ascii_digit()));
+                if !valid {
+                    return None;
+                }
+                self.pos += end + 2;
+            }
+            _ => return None,
+        }
+        self.eat('?');
+        Some(&self.source[start..self.pos])
+    }
+
+    fn parse_atom(&mut self) -> Option<Atom<'s>> {
+        let start = self.pos;
+        let c = self.next()?;
+        let atom = match c {
+            '^' | '$' => Atom::Assertion(&self.source[start..self.pos]),
+            '.' => Atom::Dot,
+            '(' => Atom::Group(self.parse_group()?),
+            '[' => Atom::Class(self.parse_class(start)?),
+            '\\' => self.parse_atom_escape(start)?,
+            '*' | '+' | '?' => return None,
+            '{' | '}' | ']' if self.unicode => return None,
+            _ => Atom::Char { value: c as u32, raw: &self.source[start..self.pos] },
+        };
+        Some(atom)
+    }
+
+    fn parse_group(&mut self) -> Option<Group<'s>> {
+        let start = self.pos;
+        let mut name = None;
+        if self.eat('?') {
+            let lookaround_or_non_capturing = self.eat_str("<=")
+                || self.eat_str("<!")
+                || self.eat(':')
+                || self.eat('=')
+                || self.eat('!');
+            if lookaround_or_non_capturing {
+                // `prefix` is printed as is
+            } else if self.eat('<') {
+                let name_start = self.pos;
+                let end = self.source[self.pos..].find('>')?;
+                self.pos += end + 1;
+                name = Some(&self.source[name_start..self.pos - 1]);
+            } else {
+                // Modifiers, `(?i:a)` or `(?-i:a)`
+                while self.peek().is_some_and(|c| matches!(c, 'i' | 'm' | 's' | '-')) {
+                    self.next();
+                }
+                if !self.eat(':') {
+                    return None;
+                }
+            }
+        }
+        let prefix = if name.is_some() { "" } else { &self.source[start..self.pos] };
+        if prefix.is_empty() {
+            self.group_count += 1;
+            if let Some(name) = name {
+                self.group_names.push((name, self.group_count));
+            }
+        }
+        let body = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return None;
+        }
+        Some(Group { prefix, name, body })
+    }
+
+    fn parse_atom_escape(&mut self, start: usize) -> Option<Atom<'s>> {
+        let c = self.peek()?;
+        let atom = match c {
+            'b' | 'B' => {
+                self.next();
+                Atom::Assertion(&self.source[start..self.pos])
+            }
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => {
+                self.next();
+                Atom::Escape(CharacterClassEscape { kind: c, negated: c.is_ascii_uppercase() })
+            }
+            'p' | 'P' if self.unicode => {
+                self.next();
+                Atom::Property { negated: c == 'P', name: self.parse_property_name()? }
+            }
+            'k' if self.unicode || self.named_groups => {
+                self.next();
+                if !self.eat('<') {
+                    return None;
+                }
+                let name_start = self.pos;
+                let end = self.source[self.pos..].find('>')?;
+                self.pos += end + 1;
+                Atom::NamedBackreference(&self.source[name_start..self.pos - 1])
+            }
+            '1'..='9' => {
+                let digits = self.source[self.pos..]
+                    .bytes()
+                    .take_while(u8::is_ascii_digit)
+                    .map(|b| u32::from(b - b'0'))
+                    .fold(0u32, |n, d| n.saturating_mul(10).saturating_add(d));
+                if self.unicode || digits <= self.total_groups {
+                    while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                        self.next();
+                    }
+                    Atom::Backreference { index: digits, raw: &self.source[start..self.pos] }
+                } else {
+                    let value = self.parse_character_escape(false)?;
+                    Atom::Char { value, raw: &self.source[start..self.pos] }
+                }
+            }
+            _ => {
+                let value = self.parse_character_escape(false)?;
+                Atom::Char { value, raw: &self.source[start..self.pos] }
+            }
+        };
+        Some(atom)
+    }
+
+    /// `{Script=Greek}` of `\p{Script=Greek}`
+    fn parse_property_name(&mut self) -> Option<&'s str> {
+        if !self.eat('{') {
+            return None;
+        }
+        let start = self.pos;
+        let end = self.source[self.pos..].find('}')?;
+        self.pos += end + 1;
+        Some(&self.source[start..self.pos - 1])
+    }
+
+    /// The character after `\`, returns its value.
+    fn parse_character_escape(&mut self, in_class: bool) -> Option<u32> {
+        let c = self.next()?;
+        let value = match c {
+            't' => 0x09,
+            'n' => 0x0A,
+            'v' => 0x0B,
+            'f' => 0x0C,
+            'r' => 0x0D,
+            'b' if in_class => 0x08,
+            '-' if in_class => '-' as u32,
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.next();
+                    letter as u32 % 32
+                }
+                _ if self.unicode => return None,
+                // `\c` is read as a backslash and `c`
+                _ => {
+                    self.pos -= 1;
+                    '\\' as u32
+                }
+            },
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            '0'..='7' if !self.unicode => {
+                // Legacy octal escapes, up to `\377`
+                let mut value = c.to_digit(8).unwrap();
+                while let Some(digit) = self.peek().and_then(|c| c.to_digit(8)) {
+                    if value * 8 + digit > 0o377 {
+                        break;
+                    }
+                    value = value * 8 + digit;
+                    self.next();
+                }
+                value
+            }
+            'x' => match self.parse_hex_digits(2) {
+                Some(value) => value,
+                None if self.unicode => return None,
+                None => 'x' as u32,
+            },
+            'u' => self.parse_unicode_escape()?,
+            _ if self.unicode => {
+                let allowed = "^$\\.*+?()[]{}|/".contains(c)
+                    || (self.unicode_sets && in_class && "&-!#%,:;<=>@`~".contains(c));
+                if !allowed {
+                    return None;
+                }
+                c as u32
+            }
+            _ => c as u32,
+        };
+        Some(value)
+    }
+
+    fn parse_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.source.get(self.pos..self.pos + count)?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += count;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    /// After `\u`, `\uXXXX`, `\u{X}` or a surrogate pair `\uXXXX\uXXXX` with the `u` flag.
+    fn parse_unicode_escape(&mut self) -> Option<u32> {
+        if self.unicode && self.eat('{') {
+            let end = self.source[self.pos..].find('}')?;
+            let value = u32::from_str_radix(&self.source[self.pos..self.pos + end], 16).ok()?;
+            self.pos += end + 1;
+            return (value <= 0x10_FFFF).then_some(value);
+        }
+        let Some(value) = self.parse_hex_digits(4) else {
+            return if self.unicode { None } else { Some('u' as u32) };
+        };
+        if self.unicode && (0xD800..=0xDBFF).contains(&value) {
+            let checkpoint = self.pos;
+            if self.eat_str("\\u") {
+                if let Some(low) = self.parse_hex_digits(4) {
+                    if (0xDC00..=0xDFFF).contains(&low) {
+                        return Some(0x1_0000 + ((value - 0xD800) << 10) + (low - 0xDC00));
+                    }
+                }
+            }
+            self.pos = checkpoint;
+        }
+        Some(value)
+    }
+
+    /// After `[`
+    fn parse_class(&mut self, start: usize) -> Option<CharacterClass<'s>> {
+        let negated = self.eat('^');
+        let (kind, items) = if self.unicode_sets {
+            self.parse_class_set_expression()?
+        } else {
+            (ClassSetKind::Union, self.parse_class_ranges()?)
+        };
+        if !self.eat(']') {
+            return None;
+        }
+        Some(CharacterClass { negated, kind, items, raw: &self.source[start..self.pos] })
+    }
+
+    fn parse_class_ranges(&mut self) -> Option<Vec<ClassItem<'s>>> {
+        let mut items = vec![];
+        while self.peek()? != ']' {
+            let item = self.parse_class_atom()?;
+            if self.peek() == Some('-') && self.peek_nth(1).is_some_and(|c| c != ']') {
+                if let ClassItem::Char(from) = item {
+                    self.next();
+                    match self.parse_class_atom()? {
+                        ClassItem::Char(to) if from <= to => items.push(ClassItem::Range(from, to)),
+                        ClassItem::Char(_) => return None,
+                        // `[a-\d]` is `a`, `-` and `\d` without the `u` flag
+                        to if !self.unicode => {
+                            items.extend([item, ClassItem::Char('-' as u32), to]);
+                        }
+                        _ => return None,
+                    }
+                    continue;
+                }
+                if self.unicode {
+                    return None;
+                }
+            }
+            items.push(item);
+        }
+        Some(items)
+    }
+
+    fn parse_class_atom(&mut self) -> Option<ClassItem<'s>> {
+        if !self.eat('\\') {
+            return self.next().map(|c| ClassItem::Char(c as u32));
+        }
+        let c = self.peek()?;
+        let item = match c {
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => {
+                self.next();
+                ClassItem::Escape(CharacterClassEscape { kind: c, negated: c.is_ascii_uppercase() })
+            }
+            'p' | 'P' if self.unicode => {
+                self.next();
+                ClassItem::Property { negated: c == 'P', name: self.parse_property_name()? }
+            }
+            'q' if self.unicode_sets => {
+                self.next();
+                ClassItem::Strings(self.parse_class_strings()?)
+            }
+            _ => ClassItem::Char(self.parse_character_escape(true)?),
+        };
+        Some(item)
+    }
+
+    /// The contents of a class with the `v` flag, e.g. `[\p{L}--[a-z]]`
+    fn parse_class_set_expression(&mut self) -> Option<(ClassSetKind, Vec<ClassItem<'s>>)> {
+        let mut items = vec![];
+        let mut kind = ClassSetKind::Union;
+        while self.peek()? != ']' {
+            if !items.is_empty() {
+                let operator = if self.eat_str("&&") {
+                    Some(ClassSetKind::Intersection)
+                } else if self.eat_str("--") {
+                    Some(ClassSetKind::Subtraction)
+                } else {
+                    None
+                };
+                match (kind, operator) {
+                    (ClassSetKind::Union, Some(operator)) if items.len() == 1 => kind = operator,
+                    (_, Some(operator)) if operator == kind => {}
+                    (ClassSetKind::Union, None) => {}
+                    // Operators can't be mixed without nesting
+                    _ => return None,
+                }
+            }
+            let item = self.parse_class_set_operand()?;
+            if kind == ClassSetKind::Union
+                && self.peek() == Some('-')
+                && self.peek_nth(1) != Some('-')
+            {
+                let ClassItem::Char(from) = item else { return None };
+                self.next();
+                let ClassItem::Char(to) = self.parse_class_set_operand()? else { return None };
+                if from > to {
+                    return None;
+                }
+                items.push(ClassItem::Range(from, to));
+                continue;
+            }
+            items.push(item);
+        }
+        Some((kind, items))
+    }
+
+    fn parse_class_set_operand(&mut self) -> Option<ClassItem<'s>> {
+        let start = self.pos;
+        if self.eat('[') {
+            return Some(ClassItem::Class(self.parse_class(start)?));
+        }
+        match self.peek()? {
+            // Syntax characters must be escaped with the `v` flag
+            '(' | ')' | '{' | '}' | '/' | '|' | '-' => None,
+            _ => self.parse_class_atom(),
+        }
+    }
+
+    /// After `\q`, `{abc|def}`
+    fn parse_class_strings(&mut self) -> Option<Vec<Vec<u32>>> {
+        if !self.eat('{') {
+            return None;
+        }
+        let mut strings = vec![vec![]];
+        loop {
+            match self.next()? {
+                '}' => break,
+                '|' => strings.push(vec![]),
+                '\\' => {
+                    let value = self.parse_character_escape(true)?;
+                    strings.last_mut().unwrap().push(value);
+                }
+                c => strings.last_mut().unwrap().push(c as u32),
+            }
+        }
+        Some(strings)
+    }
+}
@@ -31,16 +31,16 @@ impl<'a> RegexpFlags<'a> {
         if target < TransformTarget::ES2015 || options.sticky_regex {
             flag |= RegExpFlags::Y;
         }
-        if target < TransformTarget::ES2015 {
+        if target < TransformTarget::ES2015 || options.unicode_regex {
             flag |= RegExpFlags::U;
         }
-        if target < TransformTarget::ES2018 {
+        if target < TransformTarget::ES2018 || options.dotall_regex {
             flag |= RegExpFlags::S;
         }
         if target < TransformTarget::ES2022 {
             flag |= RegExpFlags::D;
         }
-        if target < TransformTarget::ES2024 {
+        if target < TransformTarget::ES2024 || options.unicode_sets_regex {
             flag |= RegExpFlags::V;
         }
         if target < TransformTarget::ESNext {
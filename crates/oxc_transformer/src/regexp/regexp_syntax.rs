@@ -0,0 +1,524 @@
+use std::{fmt::Write, rc::Rc};
+
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::NumberBase;
+
+use super::{
+    code_points::{write_char, CodePointSet, MAX_CODE_POINT, MAX_CODE_UNIT},
+    pattern::{
+        Alternative, Atom as PatternAtom, CharacterClass, CharacterClassEscape, ClassItem,
+        ClassSetKind, Disjunction, Pattern, Term,
+    },
+};
+use crate::{context::TransformerCtx, helpers::Helper, TransformOptions, TransformTarget};
+
+/// Rewrites the syntax of regex patterns which are not supported by the target,
+/// so the flags which enable them can be removed.
+///
+/// * ES2024 [Unicode Sets v](https://babel.dev/docs/babel-plugin-transform-unicode-sets-regex)
+///   `/[\p{L}--[a-z]]/v` -> `/[A-Z\xAA...]/u`
+/// * ES2018 [Named Capturing Groups](https://babel.dev/docs/babel-plugin-transform-named-capturing-groups-regex)
+///   `/(?<year>\d{4})/` -> `_wrapRegExp(/(\d{4})/, { year: 1 })`
+/// * ES2018 [Unicode Property Escapes](https://babel.dev/docs/babel-plugin-transform-unicode-property-regex)
+///   `/\p{Script=Greek}/u` -> `/[\u0370-\u0373...]/u`
+/// * ES2018 [Dotall s](https://babel.dev/docs/babel-plugin-transform-dotall-regex)
+///   `/./s` -> `/[\0-\uFFFF]/`
+/// * ES2015 [Unicode u](https://babel.dev/docs/babel-plugin-transform-unicode-regex)
+///   `/💩/u` -> `/\uD83D\uDCA9/`
+///
+/// Regexes which can't be rewritten are left to [super::RegexpFlags].
+///
+/// References:
+/// * <https://github.com/mathiasbynens/regexpu-core>
+pub struct RegexpSyntax<'a> {
+    ast: Rc<AstBuilder<'a>>,
+    ctx: TransformerCtx<'a>,
+    lowering: Lowering,
+}
+
+/// The syntax to rewrite, for the whole program or a single regex.
+#[derive(Debug, Default, Clone, Copy)]
+struct Lowering {
+    unicode_sets: bool,
+    named_groups: bool,
+    property_escapes: bool,
+    dot_all: bool,
+    unicode: bool,
+}
+
+impl<'a> RegexpSyntax<'a> {
+    pub fn new(
+        ast: Rc<AstBuilder<'a>>,
+        ctx: TransformerCtx<'a>,
+        options: &TransformOptions,
+    ) -> Option<Self> {
+        let target = options.target;
+        let lowering = Lowering {
+            unicode_sets: target < TransformTarget::ES2024 || options.unicode_sets_regex,
+            named_groups: target < TransformTarget::ES2018 || options.named_capturing_groups_regex,
+            property_escapes: target < TransformTarget::ES2018 || options.unicode_property_regex,
+            dot_all: target < TransformTarget::ES2018 || options.dotall_regex,
+            unicode: target < TransformTarget::ES2015 || options.unicode_regex,
+        };
+        let enabled = lowering.unicode_sets
+            || lowering.named_groups
+            || lowering.property_escapes
+            || lowering.dot_all
+            || lowering.unicode;
+        enabled.then_some(Self { ast, ctx, lowering })
+    }
+
+    pub fn transform_expression(&self, expr: &mut Expression<'a>) {
+        let Expression::RegExpLiteral(literal) = expr else { return };
+        let flags = literal.regex.flags;
+        let source = literal.regex.pattern.clone();
+        let source = source.as_str();
+
+        let unicode_sets = self.lowering.unicode_sets && flags.contains(RegExpFlags::V);
+        let lowering = Lowering {
+            unicode_sets,
+            named_groups: self.lowering.named_groups && source.contains("(?<"),
+            property_escapes: self.lowering.property_escapes
+                && flags.intersects(RegExpFlags::U | RegExpFlags::V)
+                && (source.contains("\\p") || source.contains("\\P")),
+            dot_all: self.lowering.dot_all && flags.contains(RegExpFlags::S),
+            unicode: self.lowering.unicode && (flags.contains(RegExpFlags::U) || unicode_sets),
+        };
+        if !(lowering.unicode_sets
+            || lowering.named_groups
+            || lowering.property_escapes
+            || lowering.dot_all
+            || lowering.unicode)
+        {
+            return;
+        }
+
+        let Some(pattern) =
+            Pattern::parse(source, flags.contains(RegExpFlags::U), flags.contains(RegExpFlags::V))
+        else {
+            return;
+        };
+        let printer = PatternPrinter {
+            unicode: flags.intersects(RegExpFlags::U | RegExpFlags::V),
+            lowering,
+            group_names: &pattern.group_names,
+        };
+        let Some(new_source) = printer.print(&pattern) else { return };
+
+        let mut new_flags = flags;
+        if lowering.dot_all {
+            new_flags.remove(RegExpFlags::S);
+        }
+        if lowering.unicode_sets {
+            new_flags.remove(RegExpFlags::V);
+            new_flags.insert(RegExpFlags::U);
+        }
+        if lowering.unicode {
+            new_flags.remove(RegExpFlags::U);
+        }
+        literal.regex.pattern = Atom::from(new_source);
+        literal.regex.flags = new_flags;
+
+        if lowering.named_groups && !pattern.group_names.is_empty() {
+            let groups = self.group_names_object(&pattern.group_names);
+            let regex = self.ast.move_expression(expr);
+            let mut arguments = self.ast.new_vec_with_capacity(2);
+            arguments.push(Argument::Expression(regex));
+            arguments.push(Argument::Expression(groups));
+            *expr = self.ctx.helpers.call(Helper::WrapRegExp, arguments);
+        }
+    }
+
+    /// `{ year: 1, month: 2 }`, or `{ year: [1, 3] }` for duplicate names
+    fn group_names_object(&self, group_names: &[(&str, u32)]) -> Expression<'a> {
+        let mut properties = self.ast.new_vec();
+        let mut seen = vec![];
+        for &(name, _) in group_names {
+            if seen.contains(&name) {
+                continue;
+            }
+            seen.push(name);
+            let indexes = group_names.iter().filter(|(n, _)| *n == name).map(|(_, i)| *i);
+            let value = if indexes.clone().count() == 1 {
+                self.number_expression(indexes.into_iter().next().unwrap())
+            } else {
+                let mut elements = self.ast.new_vec();
+                for index in indexes {
+                    elements
+                        .push(ArrayExpressionElement::Expression(self.number_expression(index)));
+                }
+                self.ast.array_expression(SPAN, elements, None)
+            };
+            let key = self.ast.property_key_identifier(IdentifierName::new(SPAN, name.into()));
+            let property = self.ast.object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            properties.push(ObjectPropertyKind::ObjectProperty(property));
+        }
+        self.ast.object_expression(SPAN, properties, None)
+    }
+
+    fn number_expression(&self, value: u32) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        let literal = self.ast.number_literal(SPAN, f64::from(value), raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+}
+
+/// Prints a parsed pattern with the syntax in [Lowering] rewritten.
+struct PatternPrinter<'p, 's> {
+    /// The `u` or `v` flag of the original regex
+    unicode: bool,
+    lowering: Lowering,
+    group_names: &'p [(&'s str, u32)],
+}
+
+impl<'p, 's> PatternPrinter<'p, 's> {
+    /// Returns `None` if the pattern uses unknown unicode properties.
+    fn print(&self, pattern: &Pattern<'s>) -> Option<String> {
+        let mut out = String::new();
+        self.print_disjunction(&pattern.body, &mut out)?;
+        Some(out)
+    }
+
+    /// Whether astral code points are written as `\u{...}` in the result.
+    fn unicode_output(&self) -> bool {
+        self.unicode && !self.lowering.unicode
+    }
+
+    fn max_code_point(&self) -> u32 {
+        if self.unicode {
+            MAX_CODE_POINT
+        } else {
+            MAX_CODE_UNIT
+        }
+    }
+
+    fn print_disjunction(&self, disjunction: &Disjunction<'s>, out: &mut String) -> Option<()> {
+        for (i, alternative) in disjunction.alternatives.iter().enumerate() {
+            if i > 0 {
+                out.push('|');
+            }
+            self.print_alternative(alternative, out)?;
+        }
+        Some(())
+    }
+
+    fn print_alternative(&self, alternative: &Alternative<'s>, out: &mut String) -> Option<()> {
+        for (i, term) in alternative.terms.iter().enumerate() {
+            self.print_term(term, alternative.terms.get(i + 1), out)?;
+        }
+        Some(())
+    }
+
+    fn print_term(&self, term: &Term<'s>, next: Option<&Term<'s>>, out: &mut String) -> Option<()> {
+        let (source, is_atom) = self.print_atom(&term.atom, next)?;
+        match term.quantifier {
+            Some(quantifier) if !is_atom => {
+                write!(out, "(?:{source}){quantifier}").unwrap();
+            }
+            Some(quantifier) => {
+                out.push_str(&source);
+                out.push_str(quantifier);
+            }
+            None => out.push_str(&source),
+        }
+        Some(())
+    }
+
+    /// Returns the printed atom and whether it can be quantified without a group.
+    fn print_atom(
+        &self,
+        atom: &PatternAtom<'s>,
+        next: Option<&Term<'s>>,
+    ) -> Option<(String, bool)> {
+        let printed = match atom {
+            PatternAtom::Assertion(raw) | PatternAtom::Backreference { raw, .. } => {
+                ((*raw).to_string(), true)
+            }
+            PatternAtom::Char { value, raw } => {
+                if self.lowering.unicode && (*value > 0xFFFF || raw.starts_with("\\u{")) {
+                    let mut out = String::new();
+                    write_char(&mut out, *value, false, false);
+                    (out, *value <= 0xFFFF)
+                } else {
+                    ((*raw).to_string(), true)
+                }
+            }
+            PatternAtom::Dot => {
+                if self.lowering.dot_all {
+                    self.encode(&CodePointSet::from_ranges([(0, self.max_code_point())]), false)
+                } else if self.lowering.unicode {
+                    self.encode(&CodePointSet::dot(MAX_CODE_POINT), false)
+                } else {
+                    (".".into(), true)
+                }
+            }
+            PatternAtom::Escape(escape) => {
+                if self.lowering.unicode && escape.negated {
+                    self.encode(&self.escape_set(*escape), false)
+                } else {
+                    (format!("\\{}", escape.kind), true)
+                }
+            }
+            PatternAtom::Property { negated, name } => {
+                if self.lowering.unicode || self.lowering.property_escapes {
+                    self.encode(&unicode_property(name)?, *negated)
+                } else {
+                    let p = if *negated { 'P' } else { 'p' };
+                    (format!("\\{p}{{{name}}}"), true)
+                }
+            }
+            PatternAtom::Class(class) => {
+                if self.lowering.unicode
+                    || self.lowering.unicode_sets
+                    || (self.lowering.property_escapes && has_property(class))
+                {
+                    self.print_class(class)?
+                } else {
+                    (class.raw.to_string(), true)
+                }
+            }
+            PatternAtom::Group(group) => {
+                let mut out = String::from("(");
+                match group.name {
+                    Some(name) if !self.lowering.named_groups => {
+                        write!(out, "?<{name}>").unwrap();
+                    }
+                    _ => out.push_str(group.prefix),
+                }
+                self.print_disjunction(&group.body, &mut out)?;
+                out.push(')');
+                (out, true)
+            }
+            PatternAtom::NamedBackreference(name) => {
+                if self.lowering.named_groups {
+                    // Only one of the groups with duplicate names can participate in a match,
+                    // the backreferences to the others match the empty string.
+                    let mut out = String::new();
+                    let mut count = 0;
+                    for (_, index) in self.group_names.iter().filter(|(n, _)| n == name) {
+                        write!(out, "\\{index}").unwrap();
+                        count += 1;
+                    }
+                    if count == 0 {
+                        return None;
+                    }
+                    // `\1` followed by `0` would be `\10`
+                    let followed_by_digit = next.is_some_and(|term| {
+                        matches!(term.atom, PatternAtom::Char { raw, .. } if raw.starts_with(|c: char| c.is_ascii_digit()))
+                    });
+                    if followed_by_digit {
+                        (format!("(?:{out})"), true)
+                    } else {
+                        (out, count == 1)
+                    }
+                } else {
+                    (format!("\\k<{name}>"), true)
+                }
+            }
+        };
+        Some(printed)
+    }
+
+    /// Encodes a set of code points, as a class with the `u` flag or with surrogate pairs without it.
+    fn encode(&self, set: &CodePointSet, negated: bool) -> (String, bool) {
+        if self.lowering.unicode {
+            if negated {
+                set.complement(MAX_CODE_POINT).to_surrogate_pattern()
+            } else {
+                set.to_surrogate_pattern()
+            }
+        } else {
+            (set.to_class(negated, self.unicode_output()), true)
+        }
+    }
+
+    fn print_class(&self, class: &CharacterClass<'s>) -> Option<(String, bool)> {
+        let (chars, mut strings) = self.class_contents(class)?;
+        let (chars, is_atom) = self.encode(&chars, class.negated);
+        if strings.is_empty() {
+            return Some((chars, is_atom));
+        }
+        // `[\q{abc|d}e]` -> `(?:abc|[de])`, longer strings first as alternatives are ordered
+        strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+        let mut out = String::from("(?:");
+        for string in &strings {
+            for &value in string {
+                write_char(&mut out, value, false, self.unicode_output());
+            }
+            out.push('|');
+        }
+        if chars == "[]" {
+            out.pop();
+        } else {
+            out.push_str(&chars);
+        }
+        out.push(')');
+        Some((out, true))
+    }
+
+    /// The code points and strings (`\q{...}`) matched by a class, ignoring its negation.
+    fn class_contents(&self, class: &CharacterClass<'s>) -> Option<(CodePointSet, Vec<Vec<u32>>)> {
+        let mut items = class.items.iter();
+        let Some(first) = items.next() else { return Some(Default::default()) };
+        let (mut chars, mut strings) = self.class_item(first)?;
+        for item in items {
+            let (other_chars, other_strings) = self.class_item(item)?;
+            match class.kind {
+                ClassSetKind::Union => {
+                    chars.union(&other_chars);
+                    for string in other_strings {
+                        if !strings.contains(&string) {
+                            strings.push(string);
+                        }
+                    }
+                }
+                ClassSetKind::Intersection => {
+                    chars = chars.intersection(&other_chars);
+                    strings.retain(|string| other_strings.contains(string));
+                }
+                ClassSetKind::Subtraction => {
+                    chars = chars.difference(&other_chars);
+                    strings.retain(|string| !other_strings.contains(string));
+                }
+            }
+        }
+        Some((chars, strings))
+    }
+
+    fn class_item(&self, item: &ClassItem<'s>) -> Option<(CodePointSet, Vec<Vec<u32>>)> {
+        let contents = match item {
+            ClassItem::Char(value) => (CodePointSet::from_ranges([(*value, *value)]), vec![]),
+            ClassItem::Range(start, end) => (CodePointSet::from_ranges([(*start, *end)]), vec![]),
+            ClassItem::Escape(escape) => (self.escape_set(*escape), vec![]),
+            ClassItem::Property { negated, name } => {
+                let set = unicode_property(name)?;
+                let set = if *negated { set.complement(self.max_code_point()) } else { set };
+                (set, vec![])
+            }
+            ClassItem::Class(class) => {
+                let (chars, strings) = self.class_contents(class)?;
+                if class.negated {
+                    (chars.complement(self.max_code_point()), vec![])
+                } else {
+                    (chars, strings)
+                }
+            }
+            ClassItem::Strings(strings) => {
+                let mut chars = CodePointSet::default();
+                let mut multiple = vec![];
+                for string in strings {
+                    if let [value] = string.as_slice() {
+                        chars.add(*value);
+                    } else if !multiple.contains(string) {
+                        multiple.push(string.clone());
+                    }
+                }
+                (chars, multiple)
+            }
+        };
+        Some(contents)
+    }
+
+    fn escape_set(&self, escape: CharacterClassEscape) -> CodePointSet {
+        let set = match escape.kind.to_ascii_lowercase() {
+            'd' => CodePointSet::digit(),
+            's' => CodePointSet::space(),
+            _ => CodePointSet::word(),
+        };
+        if escape.negated {
+            set.complement(self.max_code_point())
+        } else {
+            set
+        }
+    }
+}
+
+fn has_property(class: &CharacterClass) -> bool {
+    class.items.iter().any(|item| match item {
+        ClassItem::Property { .. } => true,
+        ClassItem::Class(class) => has_property(class),
+        _ => false,
+    })
+}
+
+/// The code points of a unicode property, e.g. `Script=Greek`, from the tables of `regex-syntax`.
+fn unicode_property(name: &str) -> Option<CodePointSet> {
+    use regex_syntax::hir::{Class, HirKind};
+    // `Any` includes the surrogates, which are not `char`s
+    if name == "Any" {
+        return Some(CodePointSet::from_ranges([(0, MAX_CODE_POINT)]));
+    }
+    let hir = regex_syntax::Parser::new().parse(&format!("\\p{{{name}}}")).ok()?;
+    match hir.kind() {
+        HirKind::Class(Class::Unicode(class)) => Some(CodePointSet::from_ranges(
+            class.iter().map(|range| (range.start() as u32, range.end() as u32)),
+        )),
+        HirKind::Literal(literal) => {
+            let c = std::str::from_utf8(&literal.0).ok()?.chars().next()?;
+            Some(CodePointSet::from_ranges([(c as u32, c as u32)]))
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{
+        helpers::{HelperLoaderMode, HelperLoaderOptions},
+        tester::Tester,
+    };
+
+    let options = TransformOptions {
+        target: TransformTarget::ES5,
+        helper_loader: HelperLoaderOptions {
+            mode: HelperLoaderMode::Runtime,
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::default()
+    };
+
+    let tests = &[
+        // dotAll
+        ("/a.b/s;", "/a[\\0-\\uFFFF]b/;"),
+        ("/a.b/;", "/a.b/;"),
+        // unicode
+        ("/💩/u;", "/\\uD83D\\uDCA9/;"),
+        ("/💩+/u;", "/(?:\\uD83D\\uDCA9)+/;"),
+        ("/\\u{61}/u;", "/a/;"),
+        ("/[💩-💫]/u;", "/\\uD83D[\\uDCA9-\\uDCAB]/;"),
+        ("/[^a]/u;", "/(?:[\\0-`b-\\uD7FF\\uE000-\\uFFFF]|[\\uD800-\\uDBFF][\\uDC00-\\uDFFF]|[\\uD800-\\uDBFF](?![\\uDC00-\\uDFFF])|(?:[^\\uD800-\\uDBFF]|^)[\\uDC00-\\uDFFF])/;"),
+        // unicode property escapes
+        ("/\\p{ASCII_Hex_Digit}/u;", "/[0-9A-Fa-f]/;"),
+        ("/[\\p{ASCII_Hex_Digit}_]+/u;", "/[0-9A-F_a-f]+/;"),
+        // unicode sets
+        ("/[\\p{ASCII_Hex_Digit}--[a-f]]/v;", "/[0-9A-F]/;"),
+        ("/[[a-z]&&[aeiou1]]/v;", "/[aeiou]/;"),
+        ("/[\\q{abc|d}e]/v;", "/(?:abc|[de])/;"),
+        // named capturing groups
+        (
+            "/(?<year>\\d{4})-(?<month>\\d{2})\\k<month>/;",
+            "import _wrapRegExp from '@babel/runtime/helpers/wrapRegExp';\n_wrapRegExp(/(\\d{4})-(\\d{2})\\2/, {year: 1, month: 2});",
+        ),
+        // unknown properties are left to `new RegExp`
+        ("/\\p{Unknown}/u;", "new RegExp('\\\\p{Unknown}', 'u');"),
+    ];
+
+    Tester::new("test.mjs", options).test(tests);
+
+    let options = TransformOptions { unicode_property_regex: true, ..TransformOptions::default() };
+    let tests = &[
+        ("/\\p{ASCII_Hex_Digit}/u;", "/[0-9A-Fa-f]/u;"),
+        ("/\\P{ASCII_Hex_Digit}/u;", "/[^0-9A-Fa-f]/u;"),
+    ];
+    Tester::new("test.js", options).test(tests);
+}
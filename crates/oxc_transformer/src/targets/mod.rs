@@ -0,0 +1,249 @@
+//! Browser and runtime targets
+//!
+//! Selects the transforms required by a set of engines, using the compat data of
+//! [@babel/compat-data](https://github.com/babel/babel/blob/main/packages/babel-compat-data/data/plugins.json)
+//! bundled into the binary.
+//!
+//! References:
+//! * <https://babel.dev/docs/options#targets>
+//! * <https://esbuild.github.io/api/#target>
+//! * <https://github.com/browserslist/browserslist#queries>
+
+use std::{fmt, str::FromStr};
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+/// Minimum engine versions supporting each plugin, in the format of `@babel/compat-data`.
+/// Engines missing from an entry do not support the feature.
+const COMPAT_DATA: &str = include_str!("compat_data.json");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Engine {
+    Chrome,
+    Deno,
+    Edge,
+    Electron,
+    Firefox,
+    Ie,
+    Ios,
+    Node,
+    Opera,
+    Safari,
+    Samsung,
+}
+
+impl FromStr for Engine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "chrome" | "and_chr" => Ok(Self::Chrome),
+            "deno" => Ok(Self::Deno),
+            "edge" => Ok(Self::Edge),
+            "electron" => Ok(Self::Electron),
+            "firefox" | "ff" | "and_ff" => Ok(Self::Firefox),
+            "ie" | "explorer" => Ok(Self::Ie),
+            "ios" | "ios_saf" => Ok(Self::Ios),
+            "node" => Ok(Self::Node),
+            "opera" => Ok(Self::Opera),
+            "safari" => Ok(Self::Safari),
+            "samsung" => Ok(Self::Samsung),
+            _ => Err(format!("Unknown target engine `{s}`")),
+        }
+    }
+}
+
+/// `major.minor.patch`, missing components are `0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Version(pub u32, pub u32, pub u32);
+
+impl FromStr for Version {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('.').map(str::parse::<u32>);
+        let mut next = || match parts.next() {
+            Some(Ok(n)) => Ok(n),
+            Some(Err(_)) => Err(format!("Invalid version `{s}`")),
+            None => Ok(0),
+        };
+        let version = Self(next()?, next()?, next()?);
+        if parts.next().is_some() {
+            return Err(format!("Invalid version `{s}`"));
+        }
+        Ok(version)
+    }
+}
+
+impl TryFrom<String> for Version {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+/// The lowest version of each engine to support.
+///
+/// Created from a browserslist-style query such as `chrome 80, safari 13, node 14`,
+/// or deserialized from an engine map such as `{ "chrome": "80", "safari": "13" }`.
+///
+/// Only queries naming engine versions are supported: usage based queries such as
+/// `> 0.5%` or `defaults` need the caniuse database, which is not bundled.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(try_from = "TargetsInput")]
+pub struct Targets(FxHashMap<Engine, Version>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TargetsInput {
+    Query(String),
+    Map(FxHashMap<String, String>),
+}
+
+impl TryFrom<TargetsInput> for Targets {
+    type Error = String;
+
+    fn try_from(input: TargetsInput) -> Result<Self, Self::Error> {
+        match input {
+            TargetsInput::Query(query) => Self::from_query(&query),
+            TargetsInput::Map(map) => Self::from_map(map),
+        }
+    }
+}
+
+impl Targets {
+    /// Parse a comma or `or` separated list of `engine version`, `engine >= version` or `engineversion` queries.
+    ///
+    /// # Errors
+    ///
+    /// * Unknown engines and invalid versions
+    /// * Queries that do not name an engine version, e.g. `last 2 versions`
+    pub fn from_query(query: &str) -> Result<Self, String> {
+        let mut targets = Self::default();
+        let query = query.to_ascii_lowercase();
+        for part in query.split(',').flat_map(|part| part.split(" or ")) {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            let (engine, version) = split_query(part)
+                .ok_or_else(|| format!("Unsupported browserslist query `{part}`"))?;
+            targets.add(engine.parse()?, version.parse()?);
+        }
+        if targets.0.is_empty() {
+            return Err(format!("Empty browserslist query `{query}`"));
+        }
+        Ok(targets)
+    }
+
+    /// Create targets from `(engine, version)` pairs.
+    ///
+    /// # Errors
+    ///
+    /// * Unknown engines and invalid versions
+    pub fn from_map<I, K, V>(map: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut targets = Self::default();
+        for (engine, version) in map {
+            let engine = engine.as_ref().to_ascii_lowercase();
+            targets.add(engine.parse()?, version.as_ref().parse()?);
+        }
+        Ok(targets)
+    }
+
+    /// Keep the lowest version when an engine is listed more than once.
+    fn add(&mut self, engine: Engine, version: Version) {
+        self.0.entry(engine).and_modify(|v| *v = (*v).min(version)).or_insert(version);
+    }
+
+    pub fn get(&self, engine: Engine) -> Option<Version> {
+        self.0.get(&engine).copied()
+    }
+
+    /// Returns the babel plugins (e.g. `transform-optional-chaining`) required by these targets.
+    pub fn required_plugins(&self) -> Vec<&'static str> {
+        let mut plugins = compat_data()
+            .into_iter()
+            .filter(|(_, supported)| self.needs_transform(supported))
+            .map(|(plugin, _)| plugin)
+            .collect::<Vec<_>>();
+        plugins.sort_unstable();
+        plugins
+    }
+
+    /// A transform is required when any target is older than its first supporting version,
+    /// or when the engine does not support the feature at all.
+    fn needs_transform(&self, supported: &FxHashMap<Engine, Version>) -> bool {
+        self.0.iter().any(|(engine, version)| {
+            supported.get(engine).map_or(true, |min_version| version < min_version)
+        })
+    }
+}
+
+/// `chrome 80`, `chrome >= 80`, `chrome>=80` or `chrome80`
+fn split_query(query: &str) -> Option<(&str, &str)> {
+    let (engine, version) = match query.find(|c: char| c.is_ascii_whitespace() || c == '>') {
+        Some(index) => query.split_at(index),
+        None => query.split_at(query.find(|c: char| c.is_ascii_digit())?),
+    };
+    let version = version.trim_start();
+    let version = version.strip_prefix(">=").unwrap_or(version).trim_start();
+    (!engine.is_empty() && version.starts_with(|c: char| c.is_ascii_digit()))
+        .then_some((engine, version))
+}
+
+fn compat_data() -> FxHashMap<&'static str, FxHashMap<Engine, Version>> {
+    serde_json::from_str(COMPAT_DATA).expect("invalid compat_data.json")
+}
+
+#[test]
+fn test() {
+    use crate::{options::TransformOptions, tester::Tester};
+
+    let targets = Targets::from_query("Chrome 80, safari >= 13.1 or node14, chrome 79").unwrap();
+    assert_eq!(targets.get(Engine::Chrome), Some(Version(79, 0, 0)));
+    assert_eq!(targets.get(Engine::Safari), Some(Version(13, 1, 0)));
+    assert_eq!(targets.get(Engine::Node), Some(Version(14, 0, 0)));
+
+    for query in ["", "last 2 versions", "> 0.5%", "defaults", "netscape 4", "chrome 8x"] {
+        assert!(Targets::from_query(query).is_err(), "{query}");
+    }
+
+    let targets = Targets::from_map([("chrome", "91")]).unwrap();
+    assert!(targets.required_plugins().contains(&"transform-class-static-block"));
+    assert!(!targets.required_plugins().contains(&"transform-optional-chaining"));
+
+    // Internet Explorer is missing from the compat data and supports nothing.
+    let targets = Targets::from_query("ie 11").unwrap();
+    assert_eq!(targets.required_plugins().len(), compat_data().len());
+
+    let targets: Targets = serde_json::from_str(r#"{ "node": "16.9" }"#).unwrap();
+    assert_eq!(targets.get(Engine::Node), Some(Version(16, 9, 0)));
+    let targets: Targets = serde_json::from_str(r#""node 16.9""#).unwrap();
+    assert_eq!(targets.get(Engine::Node), Some(Version(16, 9, 0)));
+
+    // Safari 13 supports arrow functions but not optional chaining.
+    let options = TransformOptions {
+        targets: Some(Targets::from_query("safari 13").unwrap()),
+        ..TransformOptions::default()
+    };
+    let tests = &[(
+        "function f(a) { return () => a?.b; }",
+        "function f(a) { return () => a === null || a === void 0 ? void 0 : a.b; }",
+    )];
+    Tester::new("test.js", options).test(tests);
+}
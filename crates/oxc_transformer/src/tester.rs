@@ -18,6 +18,10 @@ pub struct Tester {
 impl Tester {
     pub fn new(filename: &str, options: TransformOptions) -> Self {
         let source_type = SourceType::from_path(filename).unwrap();
+        Self::new_with_source_type(source_type, options)
+    }
+
+    pub fn new_with_source_type(source_type: SourceType, options: TransformOptions) -> Self {
         Self { source_type, options, allocator: Allocator::default() }
     }
 
@@ -29,6 +33,14 @@ impl Tester {
         }
     }
 
+    /// Compare with the expected code as is, without printing it with the codegen first.
+    pub fn test_exact(&self, tests: &[(&str, &str)]) {
+        for (source_text, expected) in tests {
+            let transformed = self.transform(source_text).unwrap();
+            assert_eq!(transformed, *expected, "{source_text}");
+        }
+    }
+
     fn transform(&self, source_text: &str) -> Result<std::string::String, std::vec::Vec<Error>> {
         let program = Parser::new(&self.allocator, source_text, self.source_type).parse().program;
         let semantic = SemanticBuilder::new(source_text, self.source_type).build(&program).semantic;
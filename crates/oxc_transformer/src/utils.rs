@@ -1,16 +1,35 @@
-use std::mem;
+use std::{cell::Cell, mem};
 
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
-use oxc_span::Span;
+use oxc_span::{Atom, Span};
 
 use crate::context::TransformerCtx;
 
+/// Temporary vars, declared with `var` at the top of the function or program they are created in.
 pub trait CreateVars<'a> {
     fn ctx(&self) -> &TransformerCtx<'a>;
 
     fn vars_mut(&mut self) -> &mut Vec<'a, VariableDeclarator<'a>>;
 
+    /// Vars of the enclosing functions, see [`CreateVars::enter_var_scope`].
+    fn vars_stack_mut(&mut self) -> &mut std::vec::Vec<Vec<'a, VariableDeclarator<'a>>>;
+
+    /// Vars created until [`CreateVars::exit_var_scope`] are declared in the function,
+    /// static block or program being entered.
+    fn enter_var_scope(&mut self) {
+        let new_vec = self.ctx().ast.new_vec();
+        let vars = mem::replace(self.vars_mut(), new_vec);
+        self.vars_stack_mut().push(vars);
+    }
+
+    fn exit_var_scope(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        self.add_vars_to_statements(stmts);
+        if let Some(vars) = self.vars_stack_mut().pop() {
+            *self.vars_mut() = vars;
+        }
+    }
+
     fn add_vars_to_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
         if self.vars_mut().is_empty() {
             return;
@@ -26,11 +45,21 @@ pub trait CreateVars<'a> {
 
     fn create_new_var(&mut self, expr: &Expression<'a>) -> IdentifierReference {
         let name = self.ctx().scopes().generate_uid_based_on_node(expr);
-        self.ctx().add_binding(name.clone());
+        self.create_var(name)
+    }
+
+    /// Create a new var based on a name, e.g. `_obj`, `_obj2`.
+    fn create_new_named_var(&mut self, name: &str) -> IdentifierReference {
+        let name = self.ctx().scopes().generate_uid_based_on_name(name);
+        self.create_var(name)
+    }
+
+    fn create_var(&mut self, name: Atom) -> IdentifierReference {
+        let symbol_id = self.ctx().add_binding(name.clone());
 
         // Add `var name` to scope
-        // TODO: hookup symbol id
-        let binding_identifier = BindingIdentifier::new(Span::default(), name.clone());
+        let mut binding_identifier = BindingIdentifier::new(Span::default(), name.clone());
+        binding_identifier.symbol_id = Cell::new(Some(symbol_id));
         let binding_pattern_kind = self.ctx().ast.binding_pattern_identifier(binding_identifier);
         let binding = self.ctx().ast.binding_pattern(binding_pattern_kind, None, false);
         let kind = VariableDeclarationKind::Var;
@@ -50,3 +79,34 @@ pub trait CreateVars<'a> {
         }
     }
 }
+
+#[test]
+fn test() {
+    use crate::{options::TransformTarget, tester::Tester, TransformOptions};
+
+    let options =
+        TransformOptions { target: TransformTarget::ES2020, ..TransformOptions::default() };
+    let tests = &[
+        // Declared in the nearest function, not the nearest statement list
+        (
+            "function f() { if (x) { c.d ||= 2; } }",
+            "function f() { var _c; if (x) { (_c = c).d || (_c.d = 2); } }",
+        ),
+        // Vars of the enclosing scope are not declared in nested functions
+        (
+            "a.b ??= 1; function f() { c.d ??= 2; }",
+            "var _a; (_a = a).b ?? (_a.b = 1); function f() { var _c; (_c = c).d ?? (_c.d = 2); }",
+        ),
+        // Names do not shadow bindings in nested scopes
+        (
+            "a.b ??= 1; function f() { var _a; return _a; }",
+            "var _a2; (_a2 = a).b ?? (_a2.b = 1); function f() { var _a; return _a; }",
+        ),
+        // Arrow functions with an expression body get a block body
+        (
+            "const g = () => e.f &&= 3;",
+            "const g = () => { var _e; return (_e = e).f && (_e.f = 3); };",
+        ),
+    ];
+    Tester::new("test.js", options).test(tests);
+}
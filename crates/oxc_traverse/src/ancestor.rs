@@ -0,0 +1,701 @@
+//! The ancestors of the node being traversed
+//!
+//! An [Ancestor] is a node together with the field containing the node being traversed, e.g.
+//! [Ancestor::CallExpressionArguments] while an argument of a call is traversed. Its view, e.g.
+//! [CallExpressionWithoutArguments], gives access to the other fields of the node. The field
+//! containing the node being traversed is borrowed mutably by the traversal, so it is not
+//! accessible.
+//!
+//! Only the nodes listed in [AstType] are ancestors. The nodes between them, e.g. the
+//! `SwitchCase`s of a `switch` statement, are part of the field of the nearest ancestor,
+//! e.g. [Ancestor::SwitchStatementCases].
+
+use std::{cell::Cell, marker::PhantomData};
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::ast::*;
+use oxc_semantic::ScopeId;
+use oxc_span::{SourceType, Span};
+use oxc_syntax::operator::{
+    AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+};
+
+/// The type of an [Ancestor]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AstType {
+    Program,
+
+    // Statements
+    BlockStatement,
+    DoWhileStatement,
+    ExpressionStatement,
+    ForInStatement,
+    ForOfStatement,
+    ForStatement,
+    IfStatement,
+    LabeledStatement,
+    ReturnStatement,
+    SwitchStatement,
+    ThrowStatement,
+    TryStatement,
+    WhileStatement,
+    WithStatement,
+    VariableDeclaration,
+    ExportDefaultDeclaration,
+    ExportNamedDeclaration,
+
+    // Expressions
+    ArrayExpression,
+    AssignmentExpression,
+    AwaitExpression,
+    BinaryExpression,
+    CallExpression,
+    ChainExpression,
+    ConditionalExpression,
+    ImportExpression,
+    LogicalExpression,
+    ComputedMemberExpression,
+    StaticMemberExpression,
+    PrivateFieldExpression,
+    NewExpression,
+    ObjectExpression,
+    ParenthesizedExpression,
+    SequenceExpression,
+    TaggedTemplateExpression,
+    TemplateLiteral,
+    UnaryExpression,
+    UpdateExpression,
+    YieldExpression,
+    PrivateInExpression,
+    TSAsExpression,
+    TSSatisfiesExpression,
+    TSTypeAssertion,
+    TSNonNullExpression,
+    TSInstantiationExpression,
+    FlowTypeCastExpression,
+
+    // Functions and classes, as declarations, expressions or methods
+    Function,
+    ArrowExpression,
+    Class,
+    MethodDefinition,
+    PropertyDefinition,
+
+    // Parts of statements and expressions
+    CatchClause,
+    VariableDeclarator,
+    ObjectProperty,
+}
+
+impl AstType {
+    pub fn is_function(self) -> bool {
+        matches!(self, Self::Function | Self::ArrowExpression)
+    }
+
+    /// Statements and declarations, except function and class declarations
+    pub fn is_statement(self) -> bool {
+        matches!(
+            self,
+            Self::BlockStatement
+                | Self::DoWhileStatement
+                | Self::ExpressionStatement
+                | Self::ForInStatement
+                | Self::ForOfStatement
+                | Self::ForStatement
+                | Self::IfStatement
+                | Self::LabeledStatement
+                | Self::ReturnStatement
+                | Self::SwitchStatement
+                | Self::ThrowStatement
+                | Self::TryStatement
+                | Self::WhileStatement
+                | Self::WithStatement
+                | Self::VariableDeclaration
+                | Self::ExportDefaultDeclaration
+                | Self::ExportNamedDeclaration
+        )
+    }
+
+    /// Expressions, except function, arrow function and class expressions
+    pub fn is_expression(self) -> bool {
+        matches!(
+            self,
+            Self::ArrayExpression
+                | Self::AssignmentExpression
+                | Self::AwaitExpression
+                | Self::BinaryExpression
+                | Self::CallExpression
+                | Self::ChainExpression
+                | Self::ConditionalExpression
+                | Self::ImportExpression
+                | Self::LogicalExpression
+                | Self::ComputedMemberExpression
+                | Self::StaticMemberExpression
+                | Self::PrivateFieldExpression
+                | Self::NewExpression
+                | Self::ObjectExpression
+                | Self::ParenthesizedExpression
+                | Self::SequenceExpression
+                | Self::TaggedTemplateExpression
+                | Self::TemplateLiteral
+                | Self::UnaryExpression
+                | Self::UpdateExpression
+                | Self::YieldExpression
+                | Self::PrivateInExpression
+                | Self::TSAsExpression
+                | Self::TSSatisfiesExpression
+                | Self::TSTypeAssertion
+                | Self::TSNonNullExpression
+                | Self::TSInstantiationExpression
+                | Self::FlowTypeCastExpression
+        )
+    }
+}
+
+/// Declares the [Ancestor] variants and their views.
+///
+/// `fields` are accessible in all the views of a node, each of `paths` is a field which contains
+/// nodes with hooks, and gets a variant whose view gives access to everything but that field.
+macro_rules! ancestors {
+    ($(
+        $ty:ident {
+            fields { $($field:ident: $field_ty:ty),* $(,)? }
+            paths { $($path:ident: $path_ty:ty => $variant:ident($view:ident)),* $(,)? }
+        }
+    )*) => {
+        /// An ancestor of the node being traversed, with the field containing the node
+        #[derive(Debug, Clone, Copy)]
+        pub enum Ancestor<'a, 't> {
+            $($($variant($view<'a, 't>),)*)*
+        }
+
+        impl<'a, 't> Ancestor<'a, 't> {
+            pub fn ty(&self) -> AstType {
+                match self {
+                    $($(Self::$variant(_) => AstType::$ty,)*)*
+                }
+            }
+        }
+
+        impl<'a: 't, 't> Ancestor<'a, 't> {
+            pub fn span(&self) -> Span {
+                match self {
+                    $($(Self::$variant(node) => *node.span(),)*)*
+                }
+            }
+        }
+
+        $(ancestors!(@views $ty [$($field: $field_ty),*] [] [$($path: $path_ty => $variant($view)),*]);)*
+    };
+
+    (@views $ty:ident [$($field:ident: $field_ty:ty),*] [$($done:ident: $done_ty:ty),*] []) => {};
+
+    (@views $ty:ident [$($field:ident: $field_ty:ty),*] [$($done:ident: $done_ty:ty),*] [
+        $path:ident: $path_ty:ty => $variant:ident($view:ident)
+        $(, $rest:ident: $rest_ty:ty => $rest_variant:ident($rest_view:ident))*
+    ]) => {
+        view!($ty $view [$($field: $field_ty,)* $($done: $done_ty,)* $($rest: $rest_ty,)*]);
+        ancestors!(@views $ty [$($field: $field_ty),*] [$($done: $done_ty,)* $path: $path_ty] [
+            $($rest: $rest_ty => $rest_variant($rest_view)),*
+        ]);
+    };
+}
+
+/// Declares the view of a node without the field containing the node being traversed
+macro_rules! view {
+    ($ty:ident $view:ident [$($field:ident: $field_ty:ty,)*]) => {
+        /// A view of an ancestor, see the [module docs](self)
+        #[derive(Debug, Clone, Copy)]
+        pub struct $view<'a, 't>(*const $ty<'a>, PhantomData<&'t ()>);
+
+        impl<'a, 't> $view<'a, 't> {
+            /// # Safety
+            /// `node` must point to a node which is not moved or dropped while the view is
+            /// accessible, and whose fields in the view are not borrowed mutably meanwhile.
+            pub(crate) unsafe fn new(node: *const $ty<'a>) -> Self {
+                Self(node, PhantomData)
+            }
+        }
+
+        impl<'a: 't, 't> $view<'a, 't> {
+            $(
+                pub fn $field(&self) -> &'t $field_ty {
+                    // SAFETY: The node is alive and its fields in the view are not borrowed
+                    // mutably while the view is accessible, see `new`.
+                    unsafe { &*std::ptr::addr_of!((*self.0).$field) }
+                }
+            )*
+        }
+    };
+}
+
+ancestors! {
+    Program {
+        fields {
+            span: Span,
+            source_type: SourceType,
+            directives: Vec<'a, Directive>,
+            hashbang: Option<Hashbang>,
+            scope_id: Cell<Option<ScopeId>>,
+        }
+        paths { body: Vec<'a, Statement<'a>> => ProgramBody(ProgramWithoutBody) }
+    }
+
+    BlockStatement {
+        fields { span: Span, scope_id: Cell<Option<ScopeId>> }
+        paths { body: Vec<'a, Statement<'a>> => BlockStatementBody(BlockStatementWithoutBody) }
+    }
+    DoWhileStatement {
+        fields { span: Span }
+        paths {
+            body: Statement<'a> => DoWhileStatementBody(DoWhileStatementWithoutBody),
+            test: Expression<'a> => DoWhileStatementTest(DoWhileStatementWithoutTest),
+        }
+    }
+    ExpressionStatement {
+        fields { span: Span }
+        paths {
+            expression: Expression<'a>
+                => ExpressionStatementExpression(ExpressionStatementWithoutExpression),
+        }
+    }
+    ForInStatement {
+        fields { span: Span, scope_id: Cell<Option<ScopeId>> }
+        paths {
+            left: ForStatementLeft<'a> => ForInStatementLeft(ForInStatementWithoutLeft),
+            right: Expression<'a> => ForInStatementRight(ForInStatementWithoutRight),
+            body: Statement<'a> => ForInStatementBody(ForInStatementWithoutBody),
+        }
+    }
+    ForOfStatement {
+        fields { span: Span, r#await: bool, scope_id: Cell<Option<ScopeId>> }
+        paths {
+            left: ForStatementLeft<'a> => ForOfStatementLeft(ForOfStatementWithoutLeft),
+            right: Expression<'a> => ForOfStatementRight(ForOfStatementWithoutRight),
+            body: Statement<'a> => ForOfStatementBody(ForOfStatementWithoutBody),
+        }
+    }
+    ForStatement {
+        fields { span: Span, scope_id: Cell<Option<ScopeId>> }
+        paths {
+            init: Option<ForStatementInit<'a>> => ForStatementInit(ForStatementWithoutInit),
+            test: Option<Expression<'a>> => ForStatementTest(ForStatementWithoutTest),
+            update: Option<Expression<'a>> => ForStatementUpdate(ForStatementWithoutUpdate),
+            body: Statement<'a> => ForStatementBody(ForStatementWithoutBody),
+        }
+    }
+    IfStatement {
+        fields { span: Span }
+        paths {
+            test: Expression<'a> => IfStatementTest(IfStatementWithoutTest),
+            consequent: Statement<'a> => IfStatementConsequent(IfStatementWithoutConsequent),
+            alternate: Option<Statement<'a>> => IfStatementAlternate(IfStatementWithoutAlternate),
+        }
+    }
+    LabeledStatement {
+        fields { span: Span, label: LabelIdentifier }
+        paths { body: Statement<'a> => LabeledStatementBody(LabeledStatementWithoutBody) }
+    }
+    ReturnStatement {
+        fields { span: Span }
+        paths {
+            argument: Option<Expression<'a>> => ReturnStatementArgument(ReturnStatementWithoutArgument),
+        }
+    }
+    SwitchStatement {
+        fields { span: Span, scope_id: Cell<Option<ScopeId>> }
+        paths {
+            discriminant: Expression<'a>
+                => SwitchStatementDiscriminant(SwitchStatementWithoutDiscriminant),
+            cases: Vec<'a, SwitchCase<'a>> => SwitchStatementCases(SwitchStatementWithoutCases),
+        }
+    }
+    ThrowStatement {
+        fields { span: Span }
+        paths { argument: Expression<'a> => ThrowStatementArgument(ThrowStatementWithoutArgument) }
+    }
+    TryStatement {
+        fields { span: Span }
+        paths {
+            block: Box<'a, BlockStatement<'a>> => TryStatementBlock(TryStatementWithoutBlock),
+            handler: Option<Box<'a, CatchClause<'a>>> => TryStatementHandler(TryStatementWithoutHandler),
+            finalizer: Option<Box<'a, BlockStatement<'a>>>
+                => TryStatementFinalizer(TryStatementWithoutFinalizer),
+        }
+    }
+    WhileStatement {
+        fields { span: Span }
+        paths {
+            test: Expression<'a> => WhileStatementTest(WhileStatementWithoutTest),
+            body: Statement<'a> => WhileStatementBody(WhileStatementWithoutBody),
+        }
+    }
+    WithStatement {
+        fields { span: Span }
+        paths {
+            object: Expression<'a> => WithStatementObject(WithStatementWithoutObject),
+            body: Statement<'a> => WithStatementBody(WithStatementWithoutBody),
+        }
+    }
+    VariableDeclaration {
+        fields { span: Span, kind: VariableDeclarationKind, modifiers: Modifiers<'a> }
+        paths {
+            declarations: Vec<'a, VariableDeclarator<'a>>
+                => VariableDeclarationDeclarations(VariableDeclarationWithoutDeclarations),
+        }
+    }
+    ExportDefaultDeclaration {
+        fields { span: Span, exported: ModuleExportName }
+        paths {
+            declaration: ExportDefaultDeclarationKind<'a>
+                => ExportDefaultDeclarationDeclaration(ExportDefaultDeclarationWithoutDeclaration),
+        }
+    }
+    ExportNamedDeclaration {
+        fields {
+            span: Span,
+            specifiers: Vec<'a, ExportSpecifier>,
+            source: Option<StringLiteral>,
+            export_kind: ImportOrExportKind,
+        }
+        paths {
+            declaration: Option<Declaration<'a>>
+                => ExportNamedDeclarationDeclaration(ExportNamedDeclarationWithoutDeclaration),
+        }
+    }
+
+    ArrayExpression {
+        fields { span: Span, trailing_comma: Option<Span> }
+        paths {
+            elements: Vec<'a, ArrayExpressionElement<'a>>
+                => ArrayExpressionElements(ArrayExpressionWithoutElements),
+        }
+    }
+    AssignmentExpression {
+        fields { span: Span, operator: AssignmentOperator }
+        paths {
+            left: AssignmentTarget<'a> => AssignmentExpressionLeft(AssignmentExpressionWithoutLeft),
+            right: Expression<'a> => AssignmentExpressionRight(AssignmentExpressionWithoutRight),
+        }
+    }
+    AwaitExpression {
+        fields { span: Span }
+        paths { argument: Expression<'a> => AwaitExpressionArgument(AwaitExpressionWithoutArgument) }
+    }
+    BinaryExpression {
+        fields { span: Span, operator: BinaryOperator }
+        paths {
+            left: Expression<'a> => BinaryExpressionLeft(BinaryExpressionWithoutLeft),
+            right: Expression<'a> => BinaryExpressionRight(BinaryExpressionWithoutRight),
+        }
+    }
+    CallExpression {
+        fields { span: Span, optional: bool }
+        paths {
+            callee: Expression<'a> => CallExpressionCallee(CallExpressionWithoutCallee),
+            arguments: Vec<'a, Argument<'a>> => CallExpressionArguments(CallExpressionWithoutArguments),
+            type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>
+                => CallExpressionTypeParameters(CallExpressionWithoutTypeParameters),
+        }
+    }
+    ChainExpression {
+        fields { span: Span }
+        paths {
+            expression: ChainElement<'a> => ChainExpressionExpression(ChainExpressionWithoutExpression),
+        }
+    }
+    ConditionalExpression {
+        fields { span: Span }
+        paths {
+            test: Expression<'a> => ConditionalExpressionTest(ConditionalExpressionWithoutTest),
+            consequent: Expression<'a>
+                => ConditionalExpressionConsequent(ConditionalExpressionWithoutConsequent),
+            alternate: Expression<'a>
+                => ConditionalExpressionAlternate(ConditionalExpressionWithoutAlternate),
+        }
+    }
+    ImportExpression {
+        fields { span: Span }
+        paths {
+            source: Expression<'a> => ImportExpressionSource(ImportExpressionWithoutSource),
+            arguments: Vec<'a, Expression<'a>>
+                => ImportExpressionArguments(ImportExpressionWithoutArguments),
+        }
+    }
+    LogicalExpression {
+        fields { span: Span, operator: LogicalOperator }
+        paths {
+            left: Expression<'a> => LogicalExpressionLeft(LogicalExpressionWithoutLeft),
+            right: Expression<'a> => LogicalExpressionRight(LogicalExpressionWithoutRight),
+        }
+    }
+    ComputedMemberExpression {
+        fields { span: Span, optional: bool }
+        paths {
+            object: Expression<'a>
+                => ComputedMemberExpressionObject(ComputedMemberExpressionWithoutObject),
+            expression: Expression<'a>
+                => ComputedMemberExpressionExpression(ComputedMemberExpressionWithoutExpression),
+        }
+    }
+    StaticMemberExpression {
+        fields { span: Span, property: IdentifierName, optional: bool }
+        paths {
+            object: Expression<'a> => StaticMemberExpressionObject(StaticMemberExpressionWithoutObject),
+        }
+    }
+    PrivateFieldExpression {
+        fields { span: Span, field: PrivateIdentifier, optional: bool }
+        paths {
+            object: Expression<'a> => PrivateFieldExpressionObject(PrivateFieldExpressionWithoutObject),
+        }
+    }
+    NewExpression {
+        fields { span: Span }
+        paths {
+            callee: Expression<'a> => NewExpressionCallee(NewExpressionWithoutCallee),
+            arguments: Vec<'a, Argument<'a>> => NewExpressionArguments(NewExpressionWithoutArguments),
+            type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>
+                => NewExpressionTypeParameters(NewExpressionWithoutTypeParameters),
+        }
+    }
+    ObjectExpression {
+        fields { span: Span, trailing_comma: Option<Span> }
+        paths {
+            properties: Vec<'a, ObjectPropertyKind<'a>>
+                => ObjectExpressionProperties(ObjectExpressionWithoutProperties),
+        }
+    }
+    ParenthesizedExpression {
+        fields { span: Span }
+        paths {
+            expression: Expression<'a>
+                => ParenthesizedExpressionExpression(ParenthesizedExpressionWithoutExpression),
+        }
+    }
+    SequenceExpression {
+        fields { span: Span }
+        paths {
+            expressions: Vec<'a, Expression<'a>>
+                => SequenceExpressionExpressions(SequenceExpressionWithoutExpressions),
+        }
+    }
+    TaggedTemplateExpression {
+        fields {
+            span: Span,
+            type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+        }
+        paths {
+            tag: Expression<'a> => TaggedTemplateExpressionTag(TaggedTemplateExpressionWithoutTag),
+            quasi: TemplateLiteral<'a>
+                => TaggedTemplateExpressionQuasi(TaggedTemplateExpressionWithoutQuasi),
+        }
+    }
+    TemplateLiteral {
+        fields { span: Span, quasis: Vec<'a, TemplateElement> }
+        paths {
+            expressions: Vec<'a, Expression<'a>>
+                => TemplateLiteralExpressions(TemplateLiteralWithoutExpressions),
+        }
+    }
+    UnaryExpression {
+        fields { span: Span, operator: UnaryOperator }
+        paths { argument: Expression<'a> => UnaryExpressionArgument(UnaryExpressionWithoutArgument) }
+    }
+    UpdateExpression {
+        fields { span: Span, operator: UpdateOperator, prefix: bool }
+        paths {
+            argument: SimpleAssignmentTarget<'a>
+                => UpdateExpressionArgument(UpdateExpressionWithoutArgument),
+        }
+    }
+    YieldExpression {
+        fields { span: Span, delegate: bool }
+        paths {
+            argument: Option<Expression<'a>> => YieldExpressionArgument(YieldExpressionWithoutArgument),
+        }
+    }
+    PrivateInExpression {
+        fields { span: Span, left: PrivateIdentifier, operator: BinaryOperator }
+        paths { right: Expression<'a> => PrivateInExpressionRight(PrivateInExpressionWithoutRight) }
+    }
+    TSAsExpression {
+        fields { span: Span }
+        paths {
+            expression: Expression<'a> => TSAsExpressionExpression(TSAsExpressionWithoutExpression),
+            type_annotation: TSType<'a>
+                => TSAsExpressionTypeAnnotation(TSAsExpressionWithoutTypeAnnotation),
+        }
+    }
+    TSSatisfiesExpression {
+        fields { span: Span }
+        paths {
+            expression: Expression<'a>
+                => TSSatisfiesExpressionExpression(TSSatisfiesExpressionWithoutExpression),
+            type_annotation: TSType<'a>
+                => TSSatisfiesExpressionTypeAnnotation(TSSatisfiesExpressionWithoutTypeAnnotation),
+        }
+    }
+    TSTypeAssertion {
+        fields { span: Span }
+        paths {
+            expression: Expression<'a> => TSTypeAssertionExpression(TSTypeAssertionWithoutExpression),
+            type_annotation: TSType<'a>
+                => TSTypeAssertionTypeAnnotation(TSTypeAssertionWithoutTypeAnnotation),
+        }
+    }
+    TSNonNullExpression {
+        fields { span: Span }
+        paths {
+            expression: Expression<'a>
+                => TSNonNullExpressionExpression(TSNonNullExpressionWithoutExpression),
+        }
+    }
+    TSInstantiationExpression {
+        fields { span: Span }
+        paths {
+            expression: Expression<'a>
+                => TSInstantiationExpressionExpression(TSInstantiationExpressionWithoutExpression),
+            type_parameters: Box<'a, TSTypeParameterInstantiation<'a>>
+                => TSInstantiationExpressionTypeParameters(
+                    TSInstantiationExpressionWithoutTypeParameters
+                ),
+        }
+    }
+    FlowTypeCastExpression {
+        fields { span: Span }
+        paths {
+            expression: Expression<'a>
+                => FlowTypeCastExpressionExpression(FlowTypeCastExpressionWithoutExpression),
+            type_annotation: Box<'a, TSTypeAnnotation<'a>>
+                => FlowTypeCastExpressionTypeAnnotation(FlowTypeCastExpressionWithoutTypeAnnotation),
+        }
+    }
+
+    Function {
+        fields {
+            r#type: FunctionType,
+            span: Span,
+            expression: bool,
+            generator: bool,
+            r#async: bool,
+            modifiers: Modifiers<'a>,
+            scope_id: Cell<Option<ScopeId>>,
+        }
+        paths {
+            id: Option<BindingIdentifier> => FunctionId(FunctionWithoutId),
+            params: Box<'a, FormalParameters<'a>> => FunctionParams(FunctionWithoutParams),
+            body: Option<Box<'a, FunctionBody<'a>>> => FunctionBody(FunctionWithoutBody),
+            type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>
+                => FunctionTypeParameters(FunctionWithoutTypeParameters),
+            return_type: Option<Box<'a, TSTypeAnnotation<'a>>>
+                => FunctionReturnType(FunctionWithoutReturnType),
+            predicate: Option<Box<'a, FlowPredicate<'a>>>
+                => FunctionPredicate(FunctionWithoutPredicate),
+        }
+    }
+    ArrowExpression {
+        fields {
+            span: Span,
+            expression: bool,
+            generator: bool,
+            r#async: bool,
+            return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+            predicate: Option<Box<'a, FlowPredicate<'a>>>,
+            scope_id: Cell<Option<ScopeId>>,
+        }
+        paths {
+            params: Box<'a, FormalParameters<'a>> => ArrowExpressionParams(ArrowExpressionWithoutParams),
+            body: Box<'a, FunctionBody<'a>> => ArrowExpressionBody(ArrowExpressionWithoutBody),
+            type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>
+                => ArrowExpressionTypeParameters(ArrowExpressionWithoutTypeParameters),
+        }
+    }
+    Class {
+        fields {
+            r#type: ClassType,
+            span: Span,
+            implements: Option<Vec<'a, Box<'a, TSClassImplements<'a>>>>,
+            modifiers: Modifiers<'a>,
+            scope_id: Cell<Option<ScopeId>>,
+        }
+        paths {
+            decorators: Vec<'a, Decorator<'a>> => ClassDecorators(ClassWithoutDecorators),
+            id: Option<BindingIdentifier> => ClassId(ClassWithoutId),
+            type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>
+                => ClassTypeParameters(ClassWithoutTypeParameters),
+            super_class: Option<Expression<'a>> => ClassSuperClass(ClassWithoutSuperClass),
+            super_type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>
+                => ClassSuperTypeParameters(ClassWithoutSuperTypeParameters),
+            body: Box<'a, ClassBody<'a>> => ClassBody(ClassWithoutBody),
+        }
+    }
+    MethodDefinition {
+        fields {
+            span: Span,
+            kind: MethodDefinitionKind,
+            computed: bool,
+            r#static: bool,
+            r#override: bool,
+            optional: bool,
+            accessibility: Option<TSAccessibility>,
+        }
+        paths {
+            decorators: Vec<'a, Decorator<'a>>
+                => MethodDefinitionDecorators(MethodDefinitionWithoutDecorators),
+            key: PropertyKey<'a> => MethodDefinitionKey(MethodDefinitionWithoutKey),
+            value: Box<'a, Function<'a>> => MethodDefinitionValue(MethodDefinitionWithoutValue),
+        }
+    }
+    PropertyDefinition {
+        fields {
+            span: Span,
+            computed: bool,
+            r#static: bool,
+            declare: bool,
+            r#override: bool,
+            optional: bool,
+            definite: bool,
+            readonly: bool,
+            variance: Option<FlowVariance>,
+            accessibility: Option<TSAccessibility>,
+        }
+        paths {
+            decorators: Vec<'a, Decorator<'a>>
+                => PropertyDefinitionDecorators(PropertyDefinitionWithoutDecorators),
+            key: PropertyKey<'a> => PropertyDefinitionKey(PropertyDefinitionWithoutKey),
+            value: Option<Expression<'a>> => PropertyDefinitionValue(PropertyDefinitionWithoutValue),
+            type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>
+                => PropertyDefinitionTypeAnnotation(PropertyDefinitionWithoutTypeAnnotation),
+        }
+    }
+
+    CatchClause {
+        fields { span: Span, scope_id: Cell<Option<ScopeId>> }
+        paths {
+            param: Option<BindingPattern<'a>> => CatchClauseParam(CatchClauseWithoutParam),
+            body: Box<'a, BlockStatement<'a>> => CatchClauseBody(CatchClauseWithoutBody),
+        }
+    }
+    VariableDeclarator {
+        fields { span: Span, kind: VariableDeclarationKind, definite: bool }
+        paths {
+            id: BindingPattern<'a> => VariableDeclaratorId(VariableDeclaratorWithoutId),
+            init: Option<Expression<'a>> => VariableDeclaratorInit(VariableDeclaratorWithoutInit),
+        }
+    }
+    ObjectProperty {
+        fields {
+            span: Span,
+            kind: PropertyKind,
+            init: Option<Expression<'a>>,
+            method: bool,
+            shorthand: bool,
+            computed: bool,
+        }
+        paths {
+            key: PropertyKey<'a> => ObjectPropertyKey(ObjectPropertyWithoutKey),
+            value: Expression<'a> => ObjectPropertyValue(ObjectPropertyWithoutValue),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::{ast::Statement, AstBuilder};
+use oxc_semantic::{ScopeId, ScopeTree, SymbolId, SymbolTable};
+use oxc_span::Atom;
+
+use crate::ancestor::{Ancestor, AstType};
+
+/// The state of a traversal, passed to the [crate::Traverse] hooks.
+///
+/// Gives access to the ancestors and the scope of the current node, and edits the
+/// statement list containing it.
+pub struct TraverseCtx<'a> {
+    pub ast: AstBuilder<'a>,
+    /// The ancestors of the current node, the parent last.
+    /// Their lifetime is the borrow of the context, see [TraverseCtx::parent].
+    ancestors: std::vec::Vec<Ancestor<'a, 'static>>,
+    scopes: ScopeTree,
+    symbols: SymbolTable,
+    scope_stack: std::vec::Vec<ScopeId>,
+    /// Edits of the statements in statement lists, innermost last.
+    statement_edits: std::vec::Vec<StatementEdits<'a>>,
+}
+
+pub struct StatementEdits<'a> {
+    pub before: Vec<'a, Statement<'a>>,
+    pub after: Vec<'a, Statement<'a>>,
+    pub remove: bool,
+}
+
+impl<'a> TraverseCtx<'a> {
+    pub(crate) fn new(allocator: &'a Allocator, symbols: SymbolTable, scopes: ScopeTree) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            ancestors: vec![],
+            scopes,
+            symbols,
+            scope_stack: vec![],
+            statement_edits: vec![],
+        }
+    }
+
+    pub(crate) fn into_symbol_table_and_scope_tree(self) -> (SymbolTable, ScopeTree) {
+        (self.symbols, self.scopes)
+    }
+
+    /* ----------  Ancestors ---------- */
+
+    /// The parent of the current node, `None` for the program.
+    pub fn parent(&self) -> Option<Ancestor<'a, '_>> {
+        self.ancestors.last().copied()
+    }
+
+    /// The ancestors of the current node, from the parent to the program.
+    pub fn ancestors(&self) -> impl Iterator<Item = Ancestor<'a, '_>> + '_ {
+        self.ancestors.iter().rev().copied()
+    }
+
+    /// The nearest ancestor of type `ty`.
+    pub fn find_ancestor(&self, ty: AstType) -> Option<Ancestor<'a, '_>> {
+        self.ancestors().find(|ancestor| ancestor.ty() == ty)
+    }
+
+    pub(crate) fn push_stack(&mut self, ancestor: Ancestor<'a, 'static>) {
+        self.ancestors.push(ancestor);
+    }
+
+    /// Replace the parent, when the traversal moves on to another field of it.
+    pub(crate) fn retag_stack(&mut self, ancestor: Ancestor<'a, 'static>) {
+        *self.ancestors.last_mut().unwrap() = ancestor;
+    }
+
+    pub(crate) fn pop_stack(&mut self) {
+        self.ancestors.pop();
+    }
+
+    /* ----------  Scopes ---------- */
+
+    /// The scope of the current node.
+    /// Nodes created by the traversal have no scope, they are in the enclosing scope.
+    pub fn current_scope_id(&self) -> ScopeId {
+        self.scope_stack.last().copied().unwrap_or_else(|| self.scopes.root_scope_id())
+    }
+
+    /// The nearest function or program scope.
+    pub fn current_var_scope_id(&self) -> ScopeId {
+        self.scopes
+            .ancestors(self.current_scope_id())
+            .find(|scope_id| self.scopes.get_flags(*scope_id).is_var())
+            .unwrap_or_else(|| self.scopes.root_scope_id())
+    }
+
+    /// Find the symbol `name` refers to in the current scope.
+    pub fn find_binding(&self, name: &Atom) -> Option<SymbolId> {
+        self.scopes
+            .ancestors(self.current_scope_id())
+            .find_map(|scope_id| self.scopes.get_binding(scope_id, name))
+    }
+
+    pub fn scopes(&self) -> &ScopeTree {
+        &self.scopes
+    }
+
+    pub fn scopes_mut(&mut self) -> &mut ScopeTree {
+        &mut self.scopes
+    }
+
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    pub fn symbols_mut(&mut self) -> &mut SymbolTable {
+        &mut self.symbols
+    }
+
+    /// Enter the scope `scope_id` of a node, if it creates one.
+    pub(crate) fn enter_scope(&mut self, scope_id: Option<ScopeId>) -> bool {
+        if let Some(scope_id) = scope_id {
+            self.scope_stack.push(scope_id);
+        }
+        scope_id.is_some()
+    }
+
+    pub(crate) fn leave_scope(&mut self, entered: bool) {
+        if entered {
+            self.scope_stack.pop();
+        }
+    }
+
+    /* ----------  Statement edits ---------- */
+
+    /// Insert `stmt` before the statement containing the current node in a statement list.
+    /// The inserted statements are not traversed.
+    ///
+    /// # Panics
+    /// * Outside of a statement list, e.g. in [crate::Traverse::enter_program]
+    pub fn insert_before(&mut self, stmt: Statement<'a>) {
+        self.current_statement_edits().before.push(stmt);
+    }
+
+    /// Insert `stmt` after the statement containing the current node in a statement list.
+    /// The inserted statements are not traversed.
+    ///
+    /// # Panics
+    /// * Outside of a statement list
+    pub fn insert_after(&mut self, stmt: Statement<'a>) {
+        self.current_statement_edits().after.push(stmt);
+    }
+
+    /// Remove the statement containing the current node from its statement list, after it is
+    /// traversed.
+    /// In `if (a) b;`, this removes the `if` statement when called for `b`.
+    ///
+    /// # Panics
+    /// * Outside of a statement list
+    pub fn remove_statement(&mut self) {
+        self.current_statement_edits().remove = true;
+    }
+
+    /// Replace the statement containing the current node in a statement list with `stmts`.
+    ///
+    /// # Panics
+    /// * Outside of a statement list
+    pub fn replace_statement_with(&mut self, stmts: impl IntoIterator<Item = Statement<'a>>) {
+        let edits = self.current_statement_edits();
+        edits.before.extend(stmts);
+        edits.remove = true;
+    }
+
+    fn current_statement_edits(&mut self) -> &mut StatementEdits<'a> {
+        self.statement_edits.last_mut().expect("not in a statement list")
+    }
+
+    pub(crate) fn push_statement_edits(&mut self) {
+        let edits =
+            StatementEdits { before: self.ast.new_vec(), after: self.ast.new_vec(), remove: false };
+        self.statement_edits.push(edits);
+    }
+
+    pub(crate) fn pop_statement_edits(&mut self) -> StatementEdits<'a> {
+        self.statement_edits.pop().unwrap()
+    }
+}
@@ -0,0 +1,226 @@
+//! Path aware AST traversal
+//!
+//! [oxc_ast::VisitMut] visits each node with an exclusive borrow and nothing else, so a
+//! transform can neither look at the parent of a node nor add statements around it.
+//! [traverse_mut] calls the [Traverse] hooks with a [TraverseCtx], which provides what
+//! [Babel's `NodePath`](https://github.com/babel/babel/tree/main/packages/babel-traverse) does:
+//!
+//! * the ancestors of the current node, with access to their other fields
+//!   ([TraverseCtx::parent], [TraverseCtx::ancestors], see [ancestor])
+//! * the scope of the current node in the [ScopeTree] of the semantic analysis
+//!   ([TraverseCtx::current_scope_id], [TraverseCtx::find_binding])
+//! * insertion and removal of the statement containing the current node in its statement list
+//!   ([TraverseCtx::insert_before], [TraverseCtx::insert_after],
+//!   [TraverseCtx::remove_statement], [TraverseCtx::replace_statement_with])
+//!
+//! The current node itself is replaced through the `&mut` reference given to its hooks.
+
+#![allow(clippy::wildcard_imports)]
+
+pub mod ancestor;
+mod context;
+mod traverse;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, VisitMut};
+use oxc_semantic::{ScopeTree, SymbolTable};
+
+use crate::traverse::Traverser;
+pub use crate::{
+    ancestor::{Ancestor, AstType},
+    context::TraverseCtx,
+    traverse::Traverse,
+};
+
+/// Traverse `program` with the hooks of `traverse`.
+///
+/// `symbols` and `scopes` are from the semantic analysis of `program`, which sets the scope ids
+/// of its nodes. They are returned with the changes made through [TraverseCtx::symbols_mut] and
+/// [TraverseCtx::scopes_mut].
+pub fn traverse_mut<'a, T: Traverse<'a>>(
+    traverse: &mut T,
+    program: &mut Program<'a>,
+    allocator: &'a Allocator,
+    symbols: SymbolTable,
+    scopes: ScopeTree,
+) -> (SymbolTable, ScopeTree) {
+    let ctx = TraverseCtx::new(allocator, symbols, scopes);
+    let mut traverser = Traverser { traverse, ctx };
+    traverser.visit_program(program);
+    traverser.ctx.into_symbol_table_and_scope_tree()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::*;
+    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use crate::{traverse_mut, Ancestor, AstType, Traverse, TraverseCtx};
+
+    fn run<'a>(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        traverse: &mut impl Traverse<'a>,
+    ) -> String {
+        let source_type = SourceType::default().with_module(true);
+        let program = Parser::new(allocator, source_text, source_type).parse().program;
+        let program = allocator.alloc(program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+        traverse_mut(traverse, program, allocator, symbols, scopes);
+        Codegen::<false>::new(source_text.len(), CodegenOptions).build(program)
+    }
+
+    fn parse_statement<'a>(allocator: &'a Allocator, source_text: &'a str) -> Statement<'a> {
+        let ret = Parser::new(allocator, source_text, SourceType::default()).parse();
+        ret.program.body.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn ancestors() {
+        #[derive(Default)]
+        struct Ancestors(Vec<Vec<AstType>>);
+        impl<'a> Traverse<'a> for Ancestors {
+            fn enter_identifier_reference(
+                &mut self,
+                ident: &mut IdentifierReference,
+                ctx: &mut TraverseCtx<'a>,
+            ) {
+                if ident.name == "b" {
+                    self.0.push(ctx.ancestors().map(|ancestor| ancestor.ty()).collect());
+                }
+            }
+        }
+
+        let allocator = Allocator::default();
+        let mut traverse = Ancestors::default();
+        run(&allocator, "a(b.c); () => { b }", &mut traverse);
+        assert_eq!(
+            traverse.0,
+            [
+                vec![
+                    AstType::StaticMemberExpression,
+                    AstType::CallExpression,
+                    AstType::ExpressionStatement,
+                    AstType::Program
+                ],
+                vec![
+                    AstType::ExpressionStatement,
+                    AstType::ArrowExpression,
+                    AstType::ExpressionStatement,
+                    AstType::Program
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn parents() {
+        #[derive(Default)]
+        struct Parents(Vec<String>);
+        impl<'a> Traverse<'a> for Parents {
+            fn enter_identifier_reference(
+                &mut self,
+                ident: &mut IdentifierReference,
+                ctx: &mut TraverseCtx<'a>,
+            ) {
+                let parent = match ctx.parent() {
+                    Some(Ancestor::CallExpressionArguments(call)) => match call.callee() {
+                        Expression::Identifier(callee) => format!("argument of {}", callee.name),
+                        _ => unreachable!(),
+                    },
+                    Some(Ancestor::VariableDeclaratorInit(declarator)) => {
+                        match &declarator.id().kind {
+                            BindingPatternKind::BindingIdentifier(id) => {
+                                format!("{:?} {}", declarator.kind(), id.name)
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                    Some(Ancestor::MethodDefinitionKey(method)) => {
+                        format!("key of {:?}", method.kind())
+                    }
+                    parent => format!("{:?}", parent.map(|parent| parent.ty())),
+                };
+                self.0.push(format!("{}: {parent}", ident.name));
+            }
+        }
+
+        let allocator = Allocator::default();
+        let mut traverse = Parents::default();
+        run(&allocator, "f(a); let b = c; class C { get [d]() {} }; e;", &mut traverse);
+        assert_eq!(
+            traverse.0,
+            [
+                "a: argument of f",
+                "f: Some(CallExpression)",
+                "c: Let b",
+                "d: key of Get",
+                "e: Some(ExpressionStatement)"
+            ]
+        );
+    }
+
+    #[test]
+    fn scopes() {
+        #[derive(Default)]
+        struct Bindings(Vec<Option<u32>>);
+        impl<'a> Traverse<'a> for Bindings {
+            fn enter_identifier_reference(
+                &mut self,
+                ident: &mut IdentifierReference,
+                ctx: &mut TraverseCtx<'a>,
+            ) {
+                let symbol_id = ctx.find_binding(&ident.name);
+                self.0.push(symbol_id.map(|symbol_id| ctx.symbols().get_span(symbol_id).start));
+            }
+        }
+
+        let allocator = Allocator::default();
+        let mut traverse = Bindings::default();
+        let source_text = "let a; function f(a) { a } { let a; a } try {} catch (a) { a } a; b";
+        run(&allocator, source_text, &mut traverse);
+        assert_eq!(traverse.0, [Some(18), Some(33), Some(54), Some(4), None]);
+    }
+
+    #[test]
+    fn statement_edits() {
+        struct Edits;
+        impl<'a> Traverse<'a> for Edits {
+            fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+                if matches!(stmt, Statement::DebuggerStatement(_)) {
+                    ctx.remove_statement();
+                }
+            }
+
+            fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+                let allocator = ctx.ast.allocator;
+                match expr {
+                    Expression::Identifier(ident) if ident.name == "before" => {
+                        ctx.insert_before(parse_statement(allocator, "let x = 1"));
+                        ctx.insert_after(parse_statement(allocator, "after()"));
+                    }
+                    Expression::Identifier(ident) if ident.name == "replace" => {
+                        let stmts = ["one()", "two()"].map(|s| parse_statement(allocator, s));
+                        ctx.replace_statement_with(stmts);
+                    }
+                    Expression::Identifier(ident) if ident.name == "expr" => {
+                        *expr = ctx.ast.this_expression(expr.span());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let allocator = Allocator::default();
+        let source_text = "debugger; f(before); function g() { if (a) replace; expr }";
+        assert_eq!(
+            run(&allocator, source_text, &mut Edits),
+            "let x = 1;\nf(before);\nafter();\nfunction g() {\n\tone();\n\ttwo();\n\tthis;\n}\n"
+        );
+    }
+}
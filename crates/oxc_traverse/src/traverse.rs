@@ -0,0 +1,1063 @@
+use std::ptr::addr_of_mut;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, VisitMut};
+
+use crate::{ancestor::*, context::TraverseCtx};
+
+/// Hooks called on entering and exiting nodes in [crate::traverse_mut].
+///
+/// The hooks of a node are called with the ancestors of the node in the context, i.e. the
+/// node itself is not an ancestor in its own hooks. The scope of the context is the scope
+/// of the program, function, arrow function or class in their hooks, and the enclosing scope
+/// in the hooks of statements.
+///
+/// Nodes can be replaced through the `&mut` references, and the enter hook of a node is called
+/// before its children are traversed, so a replacement is traversed instead of the original.
+#[allow(unused_variables)]
+pub trait Traverse<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {}
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {}
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {}
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {}
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {}
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_arrow_expression(
+        &mut self,
+        expr: &mut ArrowExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {}
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+}
+
+/// Drives a [Traverse] with [VisitMut], keeping track of the ancestors, scopes and
+/// statement edits.
+///
+/// Each node with ancestor views is visited through a raw pointer `node` to it: the fields of
+/// the node are borrowed mutably through `node` one at a time, while the ancestor on the stack
+/// is the view of the node without the borrowed field. Neither the `&mut` reference to the node
+/// nor a field outside of the view is used while the view is on the stack, which keeps the
+/// views sound. The fields in the views have no hooks and are not visited.
+pub struct Traverser<'t, 'a, T> {
+    pub traverse: &'t mut T,
+    pub ctx: TraverseCtx<'a>,
+}
+
+/// `Ancestor::$variant` for the node at `$node`
+macro_rules! ancestor {
+    ($variant:ident($view:ident), $node:expr) => {
+        Ancestor::$variant($view::new($node))
+    };
+}
+
+impl<'t, 'a, T: Traverse<'a>> VisitMut<'a> for Traverser<'t, 'a, T> {
+    fn visit_program(&mut self, program: &mut Program<'a>) {
+        let entered = self.ctx.enter_scope(program.scope_id.get());
+        self.traverse.enter_program(program, &mut self.ctx);
+        let node = addr_of_mut!(*program);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(ProgramBody(ProgramWithoutBody), node));
+            self.visit_statements(&mut (*node).body);
+            self.ctx.pop_stack();
+            self.traverse.exit_program(&mut *node, &mut self.ctx);
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    /* ----------  Statement ---------- */
+
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        self.traverse.enter_statements(stmts, &mut self.ctx);
+        let original = std::mem::replace(stmts, self.ctx.ast.new_vec());
+        for mut stmt in original {
+            self.ctx.push_statement_edits();
+            self.visit_statement(&mut stmt);
+            let edits = self.ctx.pop_statement_edits();
+            stmts.extend(edits.before);
+            if !edits.remove {
+                stmts.push(stmt);
+            }
+            stmts.extend(edits.after);
+        }
+        self.traverse.exit_statements(stmts, &mut self.ctx);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.traverse.enter_statement(stmt, &mut self.ctx);
+        self.visit_statement_match(stmt);
+        self.traverse.exit_statement(stmt, &mut self.ctx);
+    }
+
+    fn visit_block_statement(&mut self, stmt: &mut BlockStatement<'a>) {
+        let entered = self.ctx.enter_scope(stmt.scope_id.get());
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(BlockStatementBody(BlockStatementWithoutBody), node));
+            self.visit_statements(&mut (*node).body);
+            self.ctx.pop_stack();
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(DoWhileStatementBody(DoWhileStatementWithoutBody), node));
+            self.visit_statement(&mut (*node).body);
+            self.ctx
+                .retag_stack(ancestor!(DoWhileStatementTest(DoWhileStatementWithoutTest), node));
+            self.visit_expression(&mut (*node).test);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ExpressionStatementExpression(ExpressionStatementWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        let entered = self.ctx.enter_scope(stmt.scope_id.get());
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(ForStatementInit(ForStatementWithoutInit), node));
+            if let Some(init) = &mut (*node).init {
+                self.visit_for_statement_init(init);
+            }
+            self.ctx.retag_stack(ancestor!(ForStatementTest(ForStatementWithoutTest), node));
+            if let Some(test) = &mut (*node).test {
+                self.visit_expression(test);
+            }
+            self.ctx.retag_stack(ancestor!(ForStatementUpdate(ForStatementWithoutUpdate), node));
+            if let Some(update) = &mut (*node).update {
+                self.visit_expression(update);
+            }
+            self.ctx.retag_stack(ancestor!(ForStatementBody(ForStatementWithoutBody), node));
+            self.visit_statement(&mut (*node).body);
+            self.ctx.pop_stack();
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        let entered = self.ctx.enter_scope(stmt.scope_id.get());
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(ForInStatementLeft(ForInStatementWithoutLeft), node));
+            self.visit_for_statement_left(&mut (*node).left);
+            self.ctx.retag_stack(ancestor!(ForInStatementRight(ForInStatementWithoutRight), node));
+            self.visit_expression(&mut (*node).right);
+            self.ctx.retag_stack(ancestor!(ForInStatementBody(ForInStatementWithoutBody), node));
+            self.visit_statement(&mut (*node).body);
+            self.ctx.pop_stack();
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        let entered = self.ctx.enter_scope(stmt.scope_id.get());
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(ForOfStatementLeft(ForOfStatementWithoutLeft), node));
+            self.visit_for_statement_left(&mut (*node).left);
+            self.ctx.retag_stack(ancestor!(ForOfStatementRight(ForOfStatementWithoutRight), node));
+            self.visit_expression(&mut (*node).right);
+            self.ctx.retag_stack(ancestor!(ForOfStatementBody(ForOfStatementWithoutBody), node));
+            self.visit_statement(&mut (*node).body);
+            self.ctx.pop_stack();
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(IfStatementTest(IfStatementWithoutTest), node));
+            self.visit_expression(&mut (*node).test);
+            self.ctx
+                .retag_stack(ancestor!(IfStatementConsequent(IfStatementWithoutConsequent), node));
+            self.visit_statement(&mut (*node).consequent);
+            self.ctx
+                .retag_stack(ancestor!(IfStatementAlternate(IfStatementWithoutAlternate), node));
+            if let Some(alternate) = &mut (*node).alternate {
+                self.visit_statement(alternate);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &mut LabeledStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(LabeledStatementBody(LabeledStatementWithoutBody), node));
+            self.visit_statement(&mut (*node).body);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ReturnStatementArgument(ReturnStatementWithoutArgument),
+                node
+            ));
+            if let Some(argument) = &mut (*node).argument {
+                self.visit_expression(argument);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &mut SwitchStatement<'a>) {
+        let entered = self.ctx.enter_scope(stmt.scope_id.get());
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                SwitchStatementDiscriminant(SwitchStatementWithoutDiscriminant),
+                node
+            ));
+            self.visit_expression(&mut (*node).discriminant);
+            self.ctx
+                .retag_stack(ancestor!(SwitchStatementCases(SwitchStatementWithoutCases), node));
+            for case in (*node).cases.iter_mut() {
+                self.visit_switch_case(case);
+            }
+            self.ctx.pop_stack();
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_throw_statement(&mut self, stmt: &mut ThrowStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx
+                .push_stack(ancestor!(ThrowStatementArgument(ThrowStatementWithoutArgument), node));
+            self.visit_expression(&mut (*node).argument);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_try_statement(&mut self, stmt: &mut TryStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(TryStatementBlock(TryStatementWithoutBlock), node));
+            self.visit_block_statement(&mut (*node).block);
+            self.ctx.retag_stack(ancestor!(TryStatementHandler(TryStatementWithoutHandler), node));
+            if let Some(handler) = &mut (*node).handler {
+                self.visit_catch_clause(handler);
+            }
+            self.ctx
+                .retag_stack(ancestor!(TryStatementFinalizer(TryStatementWithoutFinalizer), node));
+            if let Some(finalizer) = &mut (*node).finalizer {
+                self.visit_finally_clause(finalizer);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
+        let entered = self.ctx.enter_scope(clause.scope_id.get());
+        let node = addr_of_mut!(*clause);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(CatchClauseParam(CatchClauseWithoutParam), node));
+            if let Some(param) = &mut (*node).param {
+                self.visit_binding_pattern(param);
+            }
+            // The body is in the scope of the clause
+            self.ctx.retag_stack(ancestor!(CatchClauseBody(CatchClauseWithoutBody), node));
+            self.visit_statements(&mut (*node).body.body);
+            self.ctx.pop_stack();
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(WhileStatementTest(WhileStatementWithoutTest), node));
+            self.visit_expression(&mut (*node).test);
+            self.ctx.retag_stack(ancestor!(WhileStatementBody(WhileStatementWithoutBody), node));
+            self.visit_statement(&mut (*node).body);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_with_statement(&mut self, stmt: &mut WithStatement<'a>) {
+        let node = addr_of_mut!(*stmt);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(WithStatementObject(WithStatementWithoutObject), node));
+            self.visit_expression(&mut (*node).object);
+            self.ctx.retag_stack(ancestor!(WithStatementBody(WithStatementWithoutBody), node));
+            self.visit_statement(&mut (*node).body);
+            self.ctx.pop_stack();
+        }
+    }
+
+    /* ----------  Declaration ---------- */
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        let node = addr_of_mut!(*decl);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                VariableDeclarationDeclarations(VariableDeclarationWithoutDeclarations),
+                node
+            ));
+            for declarator in (*node).declarations.iter_mut() {
+                self.visit_variable_declarator(declarator);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
+        let node = addr_of_mut!(*declarator);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(VariableDeclaratorId(VariableDeclaratorWithoutId), node));
+            self.visit_binding_pattern(&mut (*node).id);
+            self.ctx.retag_stack(ancestor!(
+                VariableDeclaratorInit(VariableDeclaratorWithoutInit),
+                node
+            ));
+            if let Some(init) = &mut (*node).init {
+                self.visit_expression(init);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_export_default_declaration(&mut self, decl: &mut ExportDefaultDeclaration<'a>) {
+        let node = addr_of_mut!(*decl);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ExportDefaultDeclarationDeclaration(ExportDefaultDeclarationWithoutDeclaration),
+                node
+            ));
+            match &mut (*node).declaration {
+                ExportDefaultDeclarationKind::Expression(expr) => self.visit_expression(expr),
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    self.visit_function(func);
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => self.visit_class(class),
+                _ => {}
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_export_named_declaration(&mut self, decl: &mut ExportNamedDeclaration<'a>) {
+        let node = addr_of_mut!(*decl);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ExportNamedDeclarationDeclaration(ExportNamedDeclarationWithoutDeclaration),
+                node
+            ));
+            if let Some(decl) = &mut (*node).declaration {
+                self.visit_declaration(decl);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    /* ----------  Function ---------- */
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        let entered = self.ctx.enter_scope(func.scope_id.get());
+        self.traverse.enter_function(func, &mut self.ctx);
+        let node = addr_of_mut!(*func);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(FunctionId(FunctionWithoutId), node));
+            if let Some(ident) = &mut (*node).id {
+                self.visit_binding_identifier(ident);
+            }
+            self.ctx.retag_stack(ancestor!(FunctionParams(FunctionWithoutParams), node));
+            self.visit_formal_parameters(&mut (*node).params);
+            self.ctx.retag_stack(ancestor!(FunctionBody(FunctionWithoutBody), node));
+            if let Some(body) = &mut (*node).body {
+                self.visit_function_body(body);
+            }
+            self.ctx.retag_stack(ancestor!(
+                FunctionTypeParameters(FunctionWithoutTypeParameters),
+                node
+            ));
+            if let Some(parameters) = &mut (*node).type_parameters {
+                self.visit_ts_type_parameter_declaration(parameters);
+            }
+            self.ctx.retag_stack(ancestor!(FunctionReturnType(FunctionWithoutReturnType), node));
+            if let Some(annotation) = &mut (*node).return_type {
+                self.visit_ts_type_annotation(annotation);
+            }
+            self.ctx.retag_stack(ancestor!(FunctionPredicate(FunctionWithoutPredicate), node));
+            if let Some(predicate) = &mut (*node).predicate {
+                self.visit_flow_predicate(predicate);
+            }
+            self.ctx.pop_stack();
+            self.traverse.exit_function(&mut *node, &mut self.ctx);
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    /* ----------  Class ---------- */
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        let entered = self.ctx.enter_scope(class.scope_id.get());
+        self.traverse.enter_class(class, &mut self.ctx);
+        let node = addr_of_mut!(*class);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(ClassDecorators(ClassWithoutDecorators), node));
+            for decorator in (*node).decorators.iter_mut() {
+                self.visit_decorator(decorator);
+            }
+            self.ctx.retag_stack(ancestor!(ClassId(ClassWithoutId), node));
+            if let Some(id) = &mut (*node).id {
+                self.visit_binding_identifier(id);
+            }
+            self.ctx.retag_stack(ancestor!(ClassTypeParameters(ClassWithoutTypeParameters), node));
+            if let Some(parameters) = &mut (*node).type_parameters {
+                self.visit_ts_type_parameter_declaration(parameters);
+            }
+            self.ctx.retag_stack(ancestor!(ClassSuperClass(ClassWithoutSuperClass), node));
+            if let Some(super_class) = &mut (*node).super_class {
+                self.visit_class_heritage(super_class);
+            }
+            self.ctx.retag_stack(ancestor!(
+                ClassSuperTypeParameters(ClassWithoutSuperTypeParameters),
+                node
+            ));
+            if let Some(super_parameters) = &mut (*node).super_type_parameters {
+                self.visit_ts_type_parameter_instantiation(super_parameters);
+            }
+            self.ctx.retag_stack(ancestor!(ClassBody(ClassWithoutBody), node));
+            self.visit_class_body(&mut (*node).body);
+            self.ctx.pop_stack();
+            self.traverse.exit_class(&mut *node, &mut self.ctx);
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        let entered = self.ctx.enter_scope(block.scope_id.get());
+        self.visit_statements(&mut block.body);
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
+        let node = addr_of_mut!(*def);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                MethodDefinitionDecorators(MethodDefinitionWithoutDecorators),
+                node
+            ));
+            for decorator in (*node).decorators.iter_mut() {
+                self.visit_decorator(decorator);
+            }
+            self.ctx.retag_stack(ancestor!(MethodDefinitionKey(MethodDefinitionWithoutKey), node));
+            self.visit_property_key(&mut (*node).key);
+            self.ctx
+                .retag_stack(ancestor!(MethodDefinitionValue(MethodDefinitionWithoutValue), node));
+            self.visit_function(&mut (*node).value);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_property_definition(&mut self, def: &mut PropertyDefinition<'a>) {
+        let node = addr_of_mut!(*def);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                PropertyDefinitionDecorators(PropertyDefinitionWithoutDecorators),
+                node
+            ));
+            for decorator in (*node).decorators.iter_mut() {
+                self.visit_decorator(decorator);
+            }
+            self.ctx
+                .retag_stack(ancestor!(PropertyDefinitionKey(PropertyDefinitionWithoutKey), node));
+            self.visit_property_key(&mut (*node).key);
+            self.ctx.retag_stack(ancestor!(
+                PropertyDefinitionValue(PropertyDefinitionWithoutValue),
+                node
+            ));
+            if let Some(value) = &mut (*node).value {
+                self.visit_expression(value);
+            }
+            self.ctx.retag_stack(ancestor!(
+                PropertyDefinitionTypeAnnotation(PropertyDefinitionWithoutTypeAnnotation),
+                node
+            ));
+            if let Some(annotation) = &mut (*node).type_annotation {
+                self.visit_ts_type_annotation(annotation);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    /* ----------  Expression ---------- */
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.traverse.enter_expression(expr, &mut self.ctx);
+        self.visit_expression_match(expr);
+        self.traverse.exit_expression(expr, &mut self.ctx);
+    }
+
+    fn visit_array_expression(&mut self, expr: &mut ArrayExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ArrayExpressionElements(ArrayExpressionWithoutElements),
+                node
+            ));
+            for elem in (*node).elements.iter_mut() {
+                self.visit_array_expression_element(elem);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &mut AssignmentExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                AssignmentExpressionLeft(AssignmentExpressionWithoutLeft),
+                node
+            ));
+            self.visit_assignment_target(&mut (*node).left);
+            self.ctx.retag_stack(ancestor!(
+                AssignmentExpressionRight(AssignmentExpressionWithoutRight),
+                node
+            ));
+            self.visit_expression(&mut (*node).right);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        let entered = self.ctx.enter_scope(expr.scope_id.get());
+        self.traverse.enter_arrow_expression(expr, &mut self.ctx);
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx
+                .push_stack(ancestor!(ArrowExpressionParams(ArrowExpressionWithoutParams), node));
+            self.visit_formal_parameters(&mut (*node).params);
+            self.ctx.retag_stack(ancestor!(ArrowExpressionBody(ArrowExpressionWithoutBody), node));
+            self.visit_function_body(&mut (*node).body);
+            self.ctx.retag_stack(ancestor!(
+                ArrowExpressionTypeParameters(ArrowExpressionWithoutTypeParameters),
+                node
+            ));
+            if let Some(parameters) = &mut (*node).type_parameters {
+                self.visit_ts_type_parameter_declaration(parameters);
+            }
+            self.ctx.pop_stack();
+            self.traverse.exit_arrow_expression(&mut *node, &mut self.ctx);
+        }
+        self.ctx.leave_scope(entered);
+    }
+
+    fn visit_await_expression(&mut self, expr: &mut AwaitExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                AwaitExpressionArgument(AwaitExpressionWithoutArgument),
+                node
+            ));
+            self.visit_expression(&mut (*node).argument);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_binary_expression(&mut self, expr: &mut BinaryExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(BinaryExpressionLeft(BinaryExpressionWithoutLeft), node));
+            self.visit_expression(&mut (*node).left);
+            self.ctx
+                .retag_stack(ancestor!(BinaryExpressionRight(BinaryExpressionWithoutRight), node));
+            self.visit_expression(&mut (*node).right);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                CallExpressionArguments(CallExpressionWithoutArguments),
+                node
+            ));
+            for arg in (*node).arguments.iter_mut() {
+                self.visit_argument(arg);
+            }
+            self.ctx
+                .retag_stack(ancestor!(CallExpressionCallee(CallExpressionWithoutCallee), node));
+            self.visit_expression(&mut (*node).callee);
+            self.ctx.retag_stack(ancestor!(
+                CallExpressionTypeParameters(CallExpressionWithoutTypeParameters),
+                node
+            ));
+            if let Some(parameters) = &mut (*node).type_parameters {
+                self.visit_ts_type_parameter_instantiation(parameters);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_chain_expression(&mut self, expr: &mut ChainExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ChainExpressionExpression(ChainExpressionWithoutExpression),
+                node
+            ));
+            self.visit_chain_element(&mut (*node).expression);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &mut ConditionalExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ConditionalExpressionTest(ConditionalExpressionWithoutTest),
+                node
+            ));
+            self.visit_expression(&mut (*node).test);
+            self.ctx.retag_stack(ancestor!(
+                ConditionalExpressionConsequent(ConditionalExpressionWithoutConsequent),
+                node
+            ));
+            self.visit_expression(&mut (*node).consequent);
+            self.ctx.retag_stack(ancestor!(
+                ConditionalExpressionAlternate(ConditionalExpressionWithoutAlternate),
+                node
+            ));
+            self.visit_expression(&mut (*node).alternate);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_import_expression(&mut self, expr: &mut ImportExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx
+                .push_stack(ancestor!(ImportExpressionSource(ImportExpressionWithoutSource), node));
+            self.visit_expression(&mut (*node).source);
+            self.ctx.retag_stack(ancestor!(
+                ImportExpressionArguments(ImportExpressionWithoutArguments),
+                node
+            ));
+            for arg in (*node).arguments.iter_mut() {
+                self.visit_expression(arg);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_logical_expression(&mut self, expr: &mut LogicalExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx
+                .push_stack(ancestor!(LogicalExpressionLeft(LogicalExpressionWithoutLeft), node));
+            self.visit_expression(&mut (*node).left);
+            self.ctx.retag_stack(ancestor!(
+                LogicalExpressionRight(LogicalExpressionWithoutRight),
+                node
+            ));
+            self.visit_expression(&mut (*node).right);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &mut ComputedMemberExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ComputedMemberExpressionObject(ComputedMemberExpressionWithoutObject),
+                node
+            ));
+            self.visit_expression(&mut (*node).object);
+            self.ctx.retag_stack(ancestor!(
+                ComputedMemberExpressionExpression(ComputedMemberExpressionWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_static_member_expression(&mut self, expr: &mut StaticMemberExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                StaticMemberExpressionObject(StaticMemberExpressionWithoutObject),
+                node
+            ));
+            self.visit_expression(&mut (*node).object);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_private_field_expression(&mut self, expr: &mut PrivateFieldExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                PrivateFieldExpressionObject(PrivateFieldExpressionWithoutObject),
+                node
+            ));
+            self.visit_expression(&mut (*node).object);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_new_expression(&mut self, expr: &mut NewExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(NewExpressionCallee(NewExpressionWithoutCallee), node));
+            self.visit_expression(&mut (*node).callee);
+            self.ctx.retag_stack(ancestor!(
+                NewExpressionTypeParameters(NewExpressionWithoutTypeParameters),
+                node
+            ));
+            if let Some(parameters) = &mut (*node).type_parameters {
+                self.visit_ts_type_parameter_instantiation(parameters);
+            }
+            self.ctx.retag_stack(ancestor!(
+                NewExpressionArguments(NewExpressionWithoutArguments),
+                node
+            ));
+            for arg in (*node).arguments.iter_mut() {
+                self.visit_argument(arg);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_object_expression(&mut self, expr: &mut ObjectExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ObjectExpressionProperties(ObjectExpressionWithoutProperties),
+                node
+            ));
+            for prop in (*node).properties.iter_mut() {
+                self.visit_object_property_kind(prop);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        let node = addr_of_mut!(*prop);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(ObjectPropertyKey(ObjectPropertyWithoutKey), node));
+            self.visit_property_key(&mut (*node).key);
+            self.ctx.retag_stack(ancestor!(ObjectPropertyValue(ObjectPropertyWithoutValue), node));
+            self.visit_expression(&mut (*node).value);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_parenthesized_expression(&mut self, expr: &mut ParenthesizedExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                ParenthesizedExpressionExpression(ParenthesizedExpressionWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_private_in_expression(&mut self, expr: &mut PrivateInExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                PrivateInExpressionRight(PrivateInExpressionWithoutRight),
+                node
+            ));
+            self.visit_expression(&mut (*node).right);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_sequence_expression(&mut self, expr: &mut SequenceExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                SequenceExpressionExpressions(SequenceExpressionWithoutExpressions),
+                node
+            ));
+            for expr in (*node).expressions.iter_mut() {
+                self.visit_expression(expr);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                TaggedTemplateExpressionTag(TaggedTemplateExpressionWithoutTag),
+                node
+            ));
+            self.visit_expression(&mut (*node).tag);
+            self.ctx.retag_stack(ancestor!(
+                TaggedTemplateExpressionQuasi(TaggedTemplateExpressionWithoutQuasi),
+                node
+            ));
+            self.visit_template_literal(&mut (*node).quasi);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_template_literal(&mut self, lit: &mut TemplateLiteral<'a>) {
+        let node = addr_of_mut!(*lit);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                TemplateLiteralExpressions(TemplateLiteralWithoutExpressions),
+                node
+            ));
+            for expr in (*node).expressions.iter_mut() {
+                self.visit_expression(expr);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_unary_expression(&mut self, expr: &mut UnaryExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                UnaryExpressionArgument(UnaryExpressionWithoutArgument),
+                node
+            ));
+            self.visit_expression(&mut (*node).argument);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_update_expression(&mut self, expr: &mut UpdateExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                UpdateExpressionArgument(UpdateExpressionWithoutArgument),
+                node
+            ));
+            self.visit_simple_assignment_target(&mut (*node).argument);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_yield_expression(&mut self, expr: &mut YieldExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                YieldExpressionArgument(YieldExpressionWithoutArgument),
+                node
+            ));
+            if let Some(argument) = &mut (*node).argument {
+                self.visit_expression(argument);
+            }
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_ts_as_expression(&mut self, expr: &mut TSAsExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                TSAsExpressionExpression(TSAsExpressionWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.retag_stack(ancestor!(
+                TSAsExpressionTypeAnnotation(TSAsExpressionWithoutTypeAnnotation),
+                node
+            ));
+            self.visit_ts_type(&mut (*node).type_annotation);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_ts_satisfies_expression(&mut self, expr: &mut TSSatisfiesExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                TSSatisfiesExpressionExpression(TSSatisfiesExpressionWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.retag_stack(ancestor!(
+                TSSatisfiesExpressionTypeAnnotation(TSSatisfiesExpressionWithoutTypeAnnotation),
+                node
+            ));
+            self.visit_ts_type(&mut (*node).type_annotation);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_ts_non_null_expression(&mut self, expr: &mut TSNonNullExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                TSNonNullExpressionExpression(TSNonNullExpressionWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_ts_type_assertion(&mut self, expr: &mut TSTypeAssertion<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                TSTypeAssertionExpression(TSTypeAssertionWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.retag_stack(ancestor!(
+                TSTypeAssertionTypeAnnotation(TSTypeAssertionWithoutTypeAnnotation),
+                node
+            ));
+            self.visit_ts_type(&mut (*node).type_annotation);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_ts_instantiation_expression(&mut self, expr: &mut TSInstantiationExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                TSInstantiationExpressionExpression(TSInstantiationExpressionWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.retag_stack(ancestor!(
+                TSInstantiationExpressionTypeParameters(
+                    TSInstantiationExpressionWithoutTypeParameters
+                ),
+                node
+            ));
+            self.visit_ts_type_parameter_instantiation(&mut (*node).type_parameters);
+            self.ctx.pop_stack();
+        }
+    }
+
+    fn visit_flow_type_cast_expression(&mut self, expr: &mut FlowTypeCastExpression<'a>) {
+        let node = addr_of_mut!(*expr);
+        // SAFETY: See `Traverser`
+        unsafe {
+            self.ctx.push_stack(ancestor!(
+                FlowTypeCastExpressionExpression(FlowTypeCastExpressionWithoutExpression),
+                node
+            ));
+            self.visit_expression(&mut (*node).expression);
+            self.ctx.retag_stack(ancestor!(
+                FlowTypeCastExpressionTypeAnnotation(FlowTypeCastExpressionWithoutTypeAnnotation),
+                node
+            ));
+            self.visit_ts_type_annotation(&mut (*node).type_annotation);
+            self.ctx.pop_stack();
+        }
+    }
+
+    /* ----------  Identifier ---------- */
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
+        self.traverse.enter_binding_identifier(ident, &mut self.ctx);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        self.traverse.enter_identifier_reference(ident, &mut self.ctx);
+    }
+}
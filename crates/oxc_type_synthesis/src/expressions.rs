@@ -381,6 +381,13 @@ pub(crate) fn synthesize_expression<T: FSResolver>(
             );
             return TypeId::ERROR_TYPE;
         }
+        ast::Expression::FlowTypeCastExpression(item) => {
+            checking_data.raise_unimplemented_error(
+                "FlowTypeCastExpression expression",
+                oxc_span_to_source_map_span(item.span),
+            );
+            return TypeId::ERROR_TYPE;
+        }
         ast::Expression::ArrowExpression(func) => Instance::RValue(environment.new_function(
             checking_data,
             &OxcArrowFunction(&**func),
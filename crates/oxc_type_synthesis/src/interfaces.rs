@@ -36,6 +36,12 @@ pub(crate) fn synthesize_signatures<T: ezno_checker::FSResolver>(
                     ezno_checker::Property::Value(value_ty),
                 );
             }
+            ast::TSSignature::FlowObjectTypeSpread(item) => {
+                checking_data.raise_unimplemented_error(
+                    "flow object type spread",
+                    oxc_span_to_source_map_span(item.span),
+                );
+            }
             ast::TSSignature::TSIndexSignature(item) => {
                 checking_data.raise_unimplemented_error(
                     "ts index signature",
@@ -61,6 +61,8 @@ pub(crate) fn hoist_statements<T: FSResolver>(
                 ast::Declaration::TSEnumDeclaration(_) => {}
                 ast::Declaration::TSModuleDeclaration(_) => {}
                 ast::Declaration::TSImportEqualsDeclaration(_) => {}
+                ast::Declaration::FlowOpaqueTypeDeclaration(_) => {}
+                ast::Declaration::FlowDeclareModuleExports(_) => {}
             }
         }
     }
@@ -136,6 +138,8 @@ pub(crate) fn hoist_statements<T: FSResolver>(
                 ast::Declaration::TSEnumDeclaration(_) => {}
                 ast::Declaration::TSModuleDeclaration(_) => {}
                 ast::Declaration::TSImportEqualsDeclaration(_) => {}
+                ast::Declaration::FlowOpaqueTypeDeclaration(_) => {}
+                ast::Declaration::FlowDeclareModuleExports(_) => {}
             },
             _ => {}
         }
@@ -424,5 +428,15 @@ pub(crate) fn synthesize_declaration<T: FSResolver>(
                 "import equals declaration",
                 oxc_span_to_source_map_span(item.span),
             ),
+        ast::Declaration::FlowOpaqueTypeDeclaration(item) => checking_data
+            .raise_unimplemented_error(
+                "opaque type declaration",
+                oxc_span_to_source_map_span(item.span),
+            ),
+        ast::Declaration::FlowDeclareModuleExports(item) => checking_data
+            .raise_unimplemented_error(
+                "declare module.exports",
+                oxc_span_to_source_map_span(item.span),
+            ),
     }
 }
@@ -253,5 +253,29 @@ pub(crate) fn synthesize_type_annotation<T: FSResolver>(
             );
             TypeId::ERROR_TYPE
         }
+        ast::TSType::FlowNullableType(item) => {
+            checking_data
+                .raise_unimplemented_error("flow nullable", oxc_span_to_source_map_span(item.span));
+            TypeId::ERROR_TYPE
+        }
+        ast::TSType::FlowExactObjectType(item) => {
+            checking_data.raise_unimplemented_error(
+                "flow exact object",
+                oxc_span_to_source_map_span(item.span),
+            );
+            TypeId::ERROR_TYPE
+        }
+        ast::TSType::FlowInexactObjectType(item) => {
+            checking_data.raise_unimplemented_error(
+                "flow inexact object",
+                oxc_span_to_source_map_span(item.span),
+            );
+            TypeId::ERROR_TYPE
+        }
+        ast::TSType::FlowExistsType(item) => {
+            checking_data
+                .raise_unimplemented_error("flow exists", oxc_span_to_source_map_span(item.span));
+            TypeId::ERROR_TYPE
+        }
     }
 }
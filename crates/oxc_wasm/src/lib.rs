@@ -187,7 +187,11 @@ impl Oxc {
         let trivias = ret.trivias.clone();
         self.save_diagnostics(ret.errors);
 
-        self.ast = ret.program.serialize(&self.serializer)?;
+        self.ast = if parser_options.estree {
+            ret.program.to_estree(source_text).serialize(&self.serializer)?
+        } else {
+            ret.program.serialize(&self.serializer)?
+        };
         self.ir = format!("{:#?}", ret.program.body).into();
 
         let program = allocator.alloc(ret.program);
@@ -295,10 +299,11 @@ impl Oxc {
             return "'test.tsx' source type invalid, this should never happen.\nPlease open an issue at https://github.com/oxc-project/oxc".to_string().serialize(&self.serializer);
         };
 
-        let ParserReturn { errors: parse_errors, panicked, program: returned_program, trivias } =
-            Parser::new(&allocator, source_text, source_type)
-                .allow_return_outside_function(parser_options.allow_return_outside_function)
-                .parse();
+        let ParserReturn {
+            errors: parse_errors, panicked, program: returned_program, trivias, ..
+        } = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(parser_options.allow_return_outside_function)
+            .parse();
 
         let allocated_program = allocator.alloc(returned_program);
 
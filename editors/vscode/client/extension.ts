@@ -103,10 +103,21 @@ export async function activate(context: ExtensionContext) {
       // Notify the server about file changes to '.clientrc files contained in the workspace
       fileEvents: workspace.createFileSystemWatcher("**/.clientrc"),
     },
+    // Read again when the server is restarted
+    initializationOptions: () => ({
+      enableFormatting: workspace.getConfiguration("oxc").get<boolean>("enableFormatting", false),
+    }),
     outputChannel,
     traceOutputChannel,
   };
 
+  // The formatting providers are registered when the server starts.
+  workspace.onDidChangeConfiguration(event => {
+    if (event.affectsConfiguration("oxc.enableFormatting")) {
+      commands.executeCommand(OxcCommands.RestartServer);
+    }
+  }, null, context.subscriptions);
+
   // Create the language client and start the client.
   client = new LanguageClient(languageClientId, languageClientName, serverOptions, clientOptions);
 
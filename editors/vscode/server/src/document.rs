@@ -0,0 +1,320 @@
+use std::{fmt, mem};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, Trivias};
+use oxc_diagnostics::Error;
+use oxc_parser::{Parser, ParserReturn};
+use oxc_span::{SourceType, Span};
+use ropey::Rope;
+use tower_lsp::lsp_types::{Position, TextDocumentContentChangeEvent};
+
+/// An open document, and the program parsed from its text.
+///
+/// The program is kept between changes, and the text is reparsed with [Parser::reparse],
+/// which reuses the statements and function bodies outside of the changes.
+#[derive(Default)]
+pub struct Document {
+    text: String,
+
+    /// The edit of the text since the last parse
+    edit: Option<Edit>,
+
+    /// `None` before the first parse, or after a parse with syntax errors
+    program: Option<ParsedProgram>,
+}
+
+impl fmt::Debug for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Document")
+            .field("text", &self.text)
+            .field("edit", &self.edit)
+            .field("parsed", &self.program.is_some())
+            .finish()
+    }
+}
+
+/// The program borrowing the text of a [Document].
+pub struct ParsedDocument<'a> {
+    pub source_text: &'a str,
+    pub program: &'a Program<'a>,
+    pub trivias: &'a Trivias,
+}
+
+impl Document {
+    pub fn new(text: String) -> Self {
+        Self { text, edit: None, program: None }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Whether the text has changed since the last parse without syntax errors.
+    pub fn is_changed(&self) -> bool {
+        self.edit.is_some() || self.program.is_none()
+    }
+
+    /// Apply the changes of a `textDocument/didChange` notification.
+    pub fn apply_changes(&mut self, changes: Vec<TextDocumentContentChangeEvent>) {
+        if let Some(edit) = apply_changes(&mut self.text, changes) {
+            self.edit = Some(self.edit.map_or(edit, |previous| previous.then(edit)));
+        }
+    }
+
+    /// Parse the text, or reparse it after the changes since the last parse.
+    ///
+    /// # Errors
+    ///
+    /// The syntax errors of the text. The program is dropped, and the next call parses the
+    /// whole text again.
+    pub fn parse(&mut self, source_type: SourceType) -> Result<ParsedDocument<'_>, Vec<Error>> {
+        let edit = self.edit.take();
+        let mut program = match self.program.take() {
+            Some(program) if program.source_type == source_type && !program.is_worn_out() => {
+                match edit {
+                    Some(edit) => program.reparse(&self.text, edit.range),
+                    None => program,
+                }
+            }
+            _ => ParsedProgram::new(&self.text, source_type),
+        };
+        if !program.ret.errors.is_empty() {
+            return Err(mem::take(&mut program.ret.errors));
+        }
+        let program = self.program.insert(program);
+        // SAFETY: The program borrows its allocator, which lives as long as `self`.
+        let ast =
+            unsafe { mem::transmute::<&Program<'static>, &Program<'_>>(&program.ret.program) };
+        Ok(ParsedDocument {
+            source_text: program.source_text,
+            program: ast,
+            trivias: &program.ret.trivias,
+        })
+    }
+}
+
+/// The return value of the parser, with the allocator and the source text it borrows.
+struct ParsedProgram {
+    // `ret` borrows `allocator`, it is declared first to be dropped first
+    ret: ParserReturn<'static>,
+
+    /// The text of the document at the last parse, in `allocator`
+    source_text: &'static str,
+
+    /// Boxed to keep the address of the arena when moved
+    allocator: Box<Allocator>,
+
+    source_type: SourceType,
+
+    /// The bytes allocated by the full parse
+    parsed_bytes: usize,
+}
+
+#[allow(clippy::non_send_fields_in_send_ty)]
+// SAFETY: `ret` and `source_text` only borrow `allocator`, and they are moved between threads
+// together. They are only accessed through `&mut Document`.
+unsafe impl Send for ParsedProgram {}
+// SAFETY: See above, `&Document` does not give access to the program.
+unsafe impl Sync for ParsedProgram {}
+
+impl ParsedProgram {
+    fn new(text: &str, source_type: SourceType) -> Self {
+        let allocator = Box::<Allocator>::default();
+        let source_text = allocator.alloc_str(text);
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .parse();
+        let parsed_bytes = allocator.allocated_bytes();
+        // SAFETY: `ret` and `source_text` borrow `allocator`, which is owned by `Self`
+        // and dropped after them.
+        let (ret, source_text) = unsafe { extend_lifetime(ret, source_text) };
+        Self { ret, source_text, allocator, source_type, parsed_bytes }
+    }
+
+    /// Reparse `text` after `edit`, in the same allocator.
+    fn reparse(mut self, text: &str, edit: Span) -> Self {
+        let allocator = &*self.allocator;
+        // The reused nodes borrow the previous text, which is kept in the allocator
+        let source_text = allocator.alloc_str(text);
+        // SAFETY: The previous program borrows `allocator`.
+        let previous =
+            unsafe { mem::transmute::<ParserReturn<'static>, ParserReturn<'_>>(self.ret) };
+        let ret = Parser::new(allocator, source_text, self.source_type)
+            .allow_return_outside_function(true)
+            .reparse(previous, edit);
+        // SAFETY: As in `ParsedProgram::new`.
+        let (ret, source_text) = unsafe { extend_lifetime(ret, source_text) };
+        Self { ret, source_text, ..self }
+    }
+
+    /// Whether the allocator is mostly filled by the previous texts and replaced nodes,
+    /// and should be replaced by a full parse.
+    fn is_worn_out(&self) -> bool {
+        self.allocator.allocated_bytes() > self.parsed_bytes.saturating_mul(4)
+    }
+}
+
+/// # Safety
+///
+/// The returned values must not outlive the allocator they borrow.
+unsafe fn extend_lifetime<'a>(
+    ret: ParserReturn<'a>,
+    source_text: &'a str,
+) -> (ParserReturn<'static>, &'static str) {
+    mem::transmute((ret, source_text))
+}
+
+/// A replaced range of a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edit {
+    /// The replaced range of the text before the edit
+    pub range: Span,
+
+    /// The end of the replacement in the text after the edit
+    pub end: u32,
+}
+
+impl Edit {
+    /// Merge with `next`, an edit of the text after `self`.
+    fn then(self, next: Self) -> Self {
+        let start = self.range.start.min(next.range.start);
+        if next.range.end > self.end {
+            let end = self.range.end + (next.range.end - self.end);
+            Self { range: Span::new(start, end), end: next.end }
+        } else {
+            let end = self.end - next.range.end + next.end;
+            Self { range: Span::new(start, self.range.end), end }
+        }
+    }
+}
+
+/// Apply the changes of a `textDocument/didChange` notification to the text of a document,
+/// in order. A change without a range replaces the whole text.
+///
+/// Returns the edit of the whole text, when there are changes.
+#[allow(clippy::cast_possible_truncation)]
+pub fn apply_changes(
+    text: &mut String,
+    changes: Vec<TextDocumentContentChangeEvent>,
+) -> Option<Edit> {
+    let mut edit: Option<Edit> = None;
+    for change in changes {
+        let len = text.len();
+        let (start, end) = change.range.map_or((0, len), |range| {
+            let rope = Rope::from_str(text);
+            let start = position_to_offset(range.start, &rope).map_or(len, |n| n as usize);
+            let end = position_to_offset(range.end, &rope).map_or(len, |n| n as usize);
+            (start, end.max(start))
+        });
+        text.replace_range(start..end, &change.text);
+        let range = Span::new(start as u32, end as u32);
+        let change = Edit { range, end: (start + change.text.len()) as u32 };
+        edit = Some(edit.map_or(change, |edit| edit.then(change)));
+    }
+    edit
+}
+
+/// Convert a position in UTF-16 code units to a byte offset.
+#[allow(clippy::cast_possible_truncation)]
+pub fn position_to_offset(position: Position, rope: &Rope) -> Option<u32> {
+    let line_start = rope.try_line_to_char(position.line as usize).ok()?;
+    let line_start_utf16 = rope.try_char_to_utf16_cu(line_start).ok()?;
+    let char_index =
+        rope.try_utf16_cu_to_char(line_start_utf16 + position.character as usize).ok()?;
+    rope.try_char_to_byte(char_index).ok().map(|offset| offset as u32)
+}
+
+/// Convert a byte offset to a position in UTF-16 code units.
+#[allow(clippy::cast_possible_truncation)]
+pub fn offset_to_position(offset: u32, rope: &Rope) -> Option<Position> {
+    let char_index = rope.try_byte_to_char(offset as usize).ok()?;
+    let line = rope.try_char_to_line(char_index).ok()?;
+    let line_start = rope.try_line_to_char(line).ok()?;
+    let column =
+        rope.try_char_to_utf16_cu(char_index).ok()? - rope.try_char_to_utf16_cu(line_start).ok()?;
+    Some(Position::new(line as u32, column as u32))
+}
+
+#[test]
+fn test() {
+    use tower_lsp::lsp_types::Range;
+
+    let change =
+        |range: Option<((u32, u32), (u32, u32))>, text: &str| TextDocumentContentChangeEvent {
+            range: range.map(|(start, end)| {
+                Range::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+            }),
+            range_length: None,
+            text: text.into(),
+        };
+
+    let mut text = "let a = 1;\nlet b = 2;\n".to_string();
+    // The changes are applied in order, each one to the result of the previous ones.
+    apply_changes(
+        &mut text,
+        vec![change(Some(((0, 4), (0, 5))), "foo"), change(Some(((1, 8), (1, 9))), "foo")],
+    );
+    assert_eq!(text, "let foo = 1;\nlet b = foo;\n");
+
+    // The columns are in UTF-16 code units.
+    let mut text = "'😀'; a;\n".to_string();
+    apply_changes(&mut text, vec![change(Some(((0, 6), (0, 7))), "b")]);
+    assert_eq!(text, "'😀'; b;\n");
+
+    // A range past the end of the text is clamped to the end.
+    let mut text = "a;\n".to_string();
+    apply_changes(&mut text, vec![change(Some(((5, 0), (5, 0))), "b;\n")]);
+    assert_eq!(text, "a;\nb;\n");
+
+    let mut text = "a;\n".to_string();
+    apply_changes(&mut text, vec![change(None, "b;\n")]);
+    assert_eq!(text, "b;\n");
+}
+
+#[test]
+fn reparse() {
+    use tower_lsp::lsp_types::Range;
+
+    let change =
+        |(line, character): (u32, u32), len: u32, text: &str| TextDocumentContentChangeEvent {
+            range: Some(Range::new(
+                Position::new(line, character),
+                Position::new(line, character + len),
+            )),
+            range_length: None,
+            text: text.into(),
+        };
+    let source_type = SourceType::default().with_module(true);
+    let assert_parsed = |document: &mut Document| {
+        let text = document.text().to_string();
+        let parsed = document.parse(source_type).unwrap();
+        let allocator = Allocator::default();
+        let ret =
+            Parser::new(&allocator, &text, source_type).allow_return_outside_function(true).parse();
+        assert_eq!(parsed.source_text, text);
+        assert_eq!(format!("{:?}", parsed.program), format!("{:?}", ret.program));
+        assert_eq!(parsed.trivias, &ret.trivias);
+    };
+
+    let mut document =
+        Document::new("import a from 'a';\nfunction f() {\n  return a;\n}\nf();\n".to_string());
+    assert!(document.is_changed());
+    assert_parsed(&mut document);
+    assert!(!document.is_changed());
+
+    // The edits of the changes are merged until the next parse
+    document.apply_changes(vec![change((2, 9), 1, "b"), change((4, 0), 0, "// c\n")]);
+    assert_eq!(document.edit, Some(Edit { range: Span::new(43, 48), end: 53 }));
+    assert!(document.is_changed());
+    assert_parsed(&mut document);
+
+    // A syntax error drops the program, which is parsed again after it is fixed
+    document.apply_changes(vec![change((1, 0), 0, "(")]);
+    assert!(document.parse(source_type).is_err());
+    assert!(document.is_changed());
+    document.apply_changes(vec![change((1, 0), 1, "")]);
+    assert_parsed(&mut document);
+
+    document.apply_changes(vec![change((3, 0), 1, "}\nasync function g() { await f(); }")]);
+    assert_parsed(&mut document);
+}
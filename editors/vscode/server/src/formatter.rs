@@ -0,0 +1,56 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::{SourceType, Span};
+use ropey::Rope;
+use tower_lsp::lsp_types::{Range, TextEdit, Url};
+
+use crate::document::{offset_to_position, position_to_offset};
+
+#[derive(Debug)]
+pub struct ServerFormatter {
+    options: PrettierOptions,
+}
+
+impl ServerFormatter {
+    pub fn new() -> Self {
+        Self { options: PrettierOptions::default() }
+    }
+
+    /// Format the whole document, or only the statements within `range`.
+    /// Returns `None` if the document can't be formatted, e.g. it has syntax errors.
+    pub fn run_format(
+        &self,
+        uri: &Url,
+        source_text: &str,
+        range: Option<Range>,
+    ) -> Option<Vec<TextEdit>> {
+        let path = uri.to_file_path().ok()?;
+        let source_type = SourceType::from_path(path).ok()?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            return None;
+        }
+
+        let rope = Rope::from_str(source_text);
+        let prettier = Prettier::new(&allocator, source_text, ret.trivias, self.options);
+        let edits = match range {
+            Some(range) => {
+                let start = position_to_offset(range.start, &rope)?;
+                let end = position_to_offset(range.end, &rope)?;
+                prettier.build_range(&ret.program, Span::new(start, end))
+            }
+            None => prettier.build_edits(&ret.program),
+        };
+
+        edits
+            .into_iter()
+            .map(|edit| {
+                let start = offset_to_position(edit.span.start, &rope)?;
+                let end = offset_to_position(edit.span.end, &rope)?;
+                Some(TextEdit { range: Range::new(start, end), new_text: edit.content })
+            })
+            .collect()
+    }
+}
@@ -8,14 +8,16 @@ use std::{
     },
 };
 
+use crate::document::Document;
 use crate::options::LintOptions;
 use crate::walk::Walk;
 use miette::NamedSource;
 use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, Trivias};
 use oxc_diagnostics::{miette, Error, Severity};
 use oxc_linter::{LintContext, Linter};
 use oxc_linter_plugin::{make_relative_path_parts, LinterPlugin};
-use oxc_parser::Parser;
+use oxc_parser::{has_flow_pragma, Parser};
 use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use ropey::Rope;
@@ -155,16 +157,37 @@ impl IsolatedLintHandler {
         Self::process_diagnostics(&rx_error)
     }
 
-    pub fn run_single(&self, path: &Path) -> Option<Vec<DiagnosticReport>> {
+    /// Lint an open document, reparsing it after its changes.
+    pub fn run_document(
+        &self,
+        path: &Path,
+        document: &mut Document,
+    ) -> Option<Vec<DiagnosticReport>> {
+        if !Self::is_wanted_ext(path) {
+            return None;
+        }
+        let plugin = Arc::clone(&self.plugin);
+        let result = Self::lint_document(&self.linter, path, document, plugin);
+        Some(result.map_or(vec![], |(p, errors)| {
+            errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect()
+        }))
+    }
+
+    /// Lint the file at `path`, or `source_text` when it has unsaved changes.
+    pub fn run_single(
+        &self,
+        path: &Path,
+        source_text: Option<&str>,
+    ) -> Option<Vec<DiagnosticReport>> {
         if Self::is_wanted_ext(path) {
-            Some(
-                Self::lint_path(&self.linter, path, Arc::clone(&self.plugin)).map_or(
-                    vec![],
-                    |(p, errors)| {
-                        errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect()
-                    },
-                ),
-            )
+            let plugin = Arc::clone(&self.plugin);
+            let result = match source_text {
+                Some(source_text) => Self::lint_source(&self.linter, path, source_text, plugin),
+                None => Self::lint_path(&self.linter, path, plugin),
+            };
+            Some(result.map_or(vec![], |(p, errors)| {
+                errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect()
+            }))
         } else {
             None
         }
@@ -231,36 +254,87 @@ impl IsolatedLintHandler {
     ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
         let source_text =
             fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"));
+        Self::lint_source(linter, path, &source_text, plugin)
+    }
+
+    fn lint_source(
+        linter: &Linter,
+        path: &Path,
+        source_text: &str,
+        plugin: Plugin,
+    ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
         let allocator = Allocator::default();
-        let source_type =
-            SourceType::from_path(path).unwrap_or_else(|_| panic!("Incorrect {path:?}"));
-        let ret = Parser::new(&allocator, &source_text, source_type)
+        let source_type = Self::source_type(path, source_text);
+        let ret = Parser::new(&allocator, source_text, source_type)
             .allow_return_outside_function(true)
             .parse();
 
         if !ret.errors.is_empty() {
-            let reports = ret
-                .errors
-                .into_iter()
-                .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                .collect();
-
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
+            return Some(Self::wrap_errors(path, source_text, ret.errors));
         };
 
         let program = allocator.alloc(ret.program);
-        let semantic_ret = SemanticBuilder::new(&source_text, source_type)
-            .with_trivias(ret.trivias)
+        Self::lint_program(linter, path, source_text, source_type, program, ret.trivias, plugin)
+    }
+
+    fn lint_document(
+        linter: &Linter,
+        path: &Path,
+        document: &mut Document,
+        plugin: Plugin,
+    ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
+        let source_type = Self::source_type(path, document.text());
+        match document.parse(source_type) {
+            Ok(parsed) => Self::lint_program(
+                linter,
+                path,
+                parsed.source_text,
+                source_type,
+                parsed.program,
+                parsed.trivias.clone(),
+                plugin,
+            ),
+            Err(errors) => Some(Self::wrap_errors(path, document.text(), errors)),
+        }
+    }
+
+    /// JavaScript files with a `@flow` pragma are parsed as Flow.
+    fn source_type(path: &Path, source_text: &str) -> SourceType {
+        let source_type =
+            SourceType::from_path(path).unwrap_or_else(|_| panic!("Incorrect {path:?}"));
+        source_type.with_flow(source_type.is_javascript() && has_flow_pragma(source_text))
+    }
+
+    fn wrap_errors(
+        path: &Path,
+        source_text: &str,
+        errors: Vec<Error>,
+    ) -> (PathBuf, Vec<ErrorWithPosition>) {
+        let reports = errors
+            .into_iter()
+            .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
+            .collect();
+        Self::wrap_diagnostics(path, source_text, reports)
+    }
+
+    /// Run semantic analysis and the lint rules on the whole program,
+    /// which is either parsed or reparsed after changes.
+    fn lint_program<'a>(
+        linter: &Linter,
+        path: &Path,
+        source_text: &'a str,
+        source_type: SourceType,
+        program: &'a Program<'a>,
+        trivias: Trivias,
+        plugin: Plugin,
+    ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
+        let semantic_ret = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(trivias)
             .with_check_syntax_error(true)
             .build(program);
 
         if !semantic_ret.errors.is_empty() {
-            let reports = semantic_ret
-                .errors
-                .into_iter()
-                .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                .collect();
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
+            return Some(Self::wrap_errors(path, source_text, semantic_ret.errors));
         };
 
         let mut lint_ctx =
@@ -290,9 +364,9 @@ impl IsolatedLintHandler {
                     let fixed_content = msg.fix.map(|f| FixedContent {
                         code: f.content.to_string(),
                         range: Range {
-                            start: offset_to_position(f.span.start as usize, &source_text)
+                            start: offset_to_position(f.span.start as usize, source_text)
                                 .unwrap_or_default(),
-                            end: offset_to_position(f.span.end as usize, &source_text)
+                            end: offset_to_position(f.span.end as usize, source_text)
                                 .unwrap_or_default(),
                         },
                     });
@@ -301,14 +375,14 @@ impl IsolatedLintHandler {
                 })
                 .collect::<Vec<ErrorReport>>();
 
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
+            return Some(Self::wrap_diagnostics(path, source_text, reports));
         }
 
         let errors = result
             .into_iter()
             .map(|diagnostic| ErrorReport { error: diagnostic.error, fixed_content: None })
             .collect();
-        Some(Self::wrap_diagnostics(path, &source_text, errors))
+        Some(Self::wrap_diagnostics(path, source_text, errors))
     }
 
     fn wrap_diagnostics(
@@ -379,7 +453,26 @@ impl ServerLinter {
         .run_full()
     }
 
-    pub fn run_single(&self, root_uri: &Url, uri: &Url) -> Option<Vec<DiagnosticReport>> {
+    pub fn run_single(
+        &self,
+        root_uri: &Url,
+        uri: &Url,
+        source_text: Option<&str>,
+    ) -> Option<Vec<DiagnosticReport>> {
+        self.isolated_lint_handler(root_uri).run_single(&uri.to_file_path().unwrap(), source_text)
+    }
+
+    /// Lint an open document, reusing its previous program for the unchanged parts.
+    pub fn run_document(
+        &self,
+        root_uri: &Url,
+        uri: &Url,
+        document: &mut Document,
+    ) -> Option<Vec<DiagnosticReport>> {
+        self.isolated_lint_handler(root_uri).run_document(&uri.to_file_path().unwrap(), document)
+    }
+
+    fn isolated_lint_handler(&self, root_uri: &Url) -> IsolatedLintHandler {
         let options = LintOptions {
             paths: vec![root_uri.to_file_path().unwrap()],
             ignore_path: "node_modules".into(),
@@ -393,6 +486,5 @@ impl ServerLinter {
             Arc::clone(&self.linter),
             Arc::clone(&self.plugin),
         )
-        .run_single(&uri.to_file_path().unwrap())
     }
 }
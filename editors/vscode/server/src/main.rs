@@ -1,11 +1,17 @@
 #![allow(unused)]
+mod document;
+mod formatter;
 mod linter;
 mod options;
 mod walk;
 
+use crate::document::Document;
+use crate::formatter::ServerFormatter;
 use crate::linter::{DiagnosticReport, ServerLinter};
+use crate::options::Options;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::fs;
 use std::path::PathBuf;
 
 use dashmap::DashMap;
@@ -15,9 +21,12 @@ use tower_lsp::jsonrpc::{Error, ErrorCode, Result};
 use tower_lsp::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
     CodeActionProviderCapability, CodeActionResponse, Diagnostic, DidChangeTextDocumentParams,
-    DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
-    InitializedParams, MessageType, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-    TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    DocumentFormattingParams, DocumentOnTypeFormattingOptions, DocumentOnTypeFormattingParams,
+    DocumentRangeFormattingParams, InitializeParams, InitializeResult, InitializedParams,
+    MessageType, OneOf, Position, Range, ServerCapabilities, ServerInfo,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions,
+    WorkspaceEdit,
 };
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 
@@ -25,21 +34,30 @@ use tower_lsp::{Client, LanguageServer, LspService, Server};
 struct Backend {
     client: Client,
     root_uri: OnceCell<Option<Url>>,
+    options: OnceCell<Options>,
     server_linter: ServerLinter,
+    server_formatter: ServerFormatter,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
+    /// The open documents, which may not be saved yet.
+    documents: DashMap<Url, Document>,
 }
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
-        self.init(params.root_uri)?;
+        let options = params
+            .initialization_options
+            .and_then(|value| serde_json::from_value::<Options>(value).ok())
+            .unwrap_or_default();
+        let enable_formatting = options.enable_formatting;
+        self.init(params.root_uri, options)?;
 
         Ok(InitializeResult {
             server_info: Some(ServerInfo { name: "oxc".into(), version: None }),
             offset_encoding: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 code_action_provider: Some(CodeActionProviderCapability::Options(
                     CodeActionOptions {
@@ -50,6 +68,14 @@ impl LanguageServer for Backend {
                         resolve_provider: None,
                     },
                 )),
+                document_formatting_provider: enable_formatting.then_some(OneOf::Left(true)),
+                document_range_formatting_provider: enable_formatting.then_some(OneOf::Left(true)),
+                document_on_type_formatting_provider: enable_formatting.then(|| {
+                    DocumentOnTypeFormattingOptions {
+                        first_trigger_character: "}".into(),
+                        more_trigger_character: Some(vec![";".into()]),
+                    }
+                }),
                 ..ServerCapabilities::default()
             },
         })
@@ -81,13 +107,44 @@ impl LanguageServer for Backend {
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        if let Some(mut document) = self.documents.get_mut(&params.text_document.uri) {
+            document.apply_changes(params.content_changes);
+        }
         self.handle_file_update(params.text_document.uri).await;
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        self.documents
+            .insert(params.text_document.uri.clone(), Document::new(params.text_document.text));
         self.handle_file_update(params.text_document.uri).await;
     }
 
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.documents.remove(&params.text_document.uri);
+    }
+
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        Ok(self.format(&params.text_document.uri, None))
+    }
+
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        Ok(self.format(&params.text_document.uri, Some(params.range)))
+    }
+
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        // Format the statement ending with the typed `;` or `}`
+        let position = params.text_document_position.position;
+        let start = Position::new(position.line, position.character.saturating_sub(1));
+        let uri = &params.text_document_position.text_document.uri;
+        Ok(self.format(uri, Some(Range::new(start, position))))
+    }
+
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri;
 
@@ -131,15 +188,18 @@ impl LanguageServer for Backend {
 }
 
 impl Backend {
-    fn init(&self, root_uri: Option<Url>) -> Result<()> {
-        self.root_uri.set(root_uri).map_err(|err| {
+    fn init(&self, root_uri: Option<Url>, options: Options) -> Result<()> {
+        fn set_error<T>(err: &SetError<T>, name: &str) -> Error {
             let message = match err {
-                SetError::AlreadyInitializedError(_) => "root uri already initialized".into(),
+                SetError::AlreadyInitializedError(_) => format!("{name} already initialized"),
                 SetError::InitializingError(_) => "initializing error".into(),
             };
 
-            Error { code: ErrorCode::ParseError, message, data: None }
-        })
+            Error { code: ErrorCode::ParseError, message: message.into(), data: None }
+        }
+
+        self.root_uri.set(root_uri).map_err(|err| set_error(&err, "root uri"))?;
+        self.options.set(options).map_err(|err| set_error(&err, "options"))
     }
 
     #[allow(clippy::ptr_arg)]
@@ -154,10 +214,33 @@ impl Backend {
         .await;
     }
 
+    fn format(&self, uri: &Url, range: Option<Range>) -> Option<Vec<TextEdit>> {
+        if !self.options.get().is_some_and(|options| options.enable_formatting) {
+            return None;
+        }
+        let source_text = match self.documents.get(uri) {
+            Some(document) => document.text().to_string(),
+            None => fs::read_to_string(uri.to_file_path().ok()?).ok()?,
+        };
+        self.server_formatter.run_format(uri, &source_text, range)
+    }
+
     async fn handle_file_update(&self, uri: Url) {
         if let Some(Some(root_uri)) = self.root_uri.get() {
             self.server_linter.make_plugin(root_uri);
-            if let Some(diagnostics) = self.server_linter.run_single(root_uri, &uri) {
+            let diagnostics = match self.documents.get_mut(&uri) {
+                Some(mut document) => {
+                    // e.g. saving after the changes were linted
+                    if !document.is_changed()
+                        && self.diagnostics_report_map.contains_key(uri.as_str())
+                    {
+                        return;
+                    }
+                    self.server_linter.run_document(root_uri, &uri, &mut document)
+                }
+                None => self.server_linter.run_single(root_uri, &uri, None),
+            };
+            if let Some(diagnostics) = diagnostics {
                 self.client
                     .publish_diagnostics(
                         uri.clone(),
@@ -180,15 +263,192 @@ async fn main() {
     let stdout = tokio::io::stdout();
 
     let server_linter = ServerLinter::new();
+    let server_formatter = ServerFormatter::new();
     let diagnostics_report_map = DashMap::new();
+    let documents = DashMap::new();
 
     let (service, socket) = LspService::build(|client| Backend {
         client,
         root_uri: OnceCell::new(),
+        options: OnceCell::new(),
         server_linter,
+        server_formatter,
         diagnostics_report_map,
+        documents,
     })
     .finish();
 
     Server::new(stdin, stdout, socket).serve(service).await;
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+    use tower_lsp::lsp_types::{
+        DocumentFormattingParams, DocumentOnTypeFormattingParams, DocumentRangeFormattingParams,
+        FormattingOptions, InitializeParams, Position, Range, TextDocumentContentChangeEvent,
+        TextDocumentIdentifier, TextDocumentItem, TextDocumentPositionParams, TextEdit, Url,
+        WorkDoneProgressParams,
+    };
+    use tower_lsp::{LanguageServer, LspService};
+
+    use super::{Backend, DidOpenTextDocumentParams, ServerFormatter, ServerLinter};
+    use crate::document;
+
+    const SOURCE_TEXT: &str = "const  a  =  1;\nif (a) {\nfoo(  a  );\n}\nlet  b  =  2;\n";
+
+    fn new_service() -> LspService<Backend> {
+        let (service, _socket) = LspService::new(|client| Backend {
+            client,
+            root_uri: tokio::sync::OnceCell::new(),
+            options: tokio::sync::OnceCell::new(),
+            server_linter: ServerLinter::new(),
+            server_formatter: ServerFormatter::new(),
+            diagnostics_report_map: dashmap::DashMap::new(),
+            documents: dashmap::DashMap::new(),
+        });
+        service
+    }
+
+    /// Start a server with the `initializationOptions`, and open a document with [SOURCE_TEXT].
+    async fn start(initialization_options: serde_json::Value) -> (LspService<Backend>, Url) {
+        let service = new_service();
+        let params = InitializeParams {
+            initialization_options: Some(initialization_options),
+            ..InitializeParams::default()
+        };
+        service.inner().initialize(params).await.unwrap();
+
+        let uri = Url::parse("file:///test.js").unwrap();
+        let text_document =
+            TextDocumentItem::new(uri.clone(), "javascript".into(), 0, SOURCE_TEXT.into());
+        service.inner().did_open(DidOpenTextDocumentParams { text_document }).await;
+        (service, uri)
+    }
+
+    /// Apply the edits, which are relative to the original text, from the last one.
+    fn apply_edits(edits: Vec<TextEdit>) -> String {
+        let mut text = SOURCE_TEXT.to_string();
+        let changes = edits
+            .into_iter()
+            .rev()
+            .map(|edit| TextDocumentContentChangeEvent {
+                range: Some(edit.range),
+                range_length: None,
+                text: edit.new_text,
+            })
+            .collect();
+        document::apply_changes(&mut text, changes);
+        text
+    }
+
+    fn formatting_params(uri: &Url) -> DocumentFormattingParams {
+        DocumentFormattingParams {
+            text_document: TextDocumentIdentifier::new(uri.clone()),
+            options: FormattingOptions::default(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        }
+    }
+
+    fn range_formatting_params(uri: &Url, range: Range) -> DocumentRangeFormattingParams {
+        DocumentRangeFormattingParams {
+            text_document: TextDocumentIdentifier::new(uri.clone()),
+            range,
+            options: FormattingOptions::default(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        }
+    }
+
+    fn on_type_formatting_params(uri: &Url, position: Position) -> DocumentOnTypeFormattingParams {
+        DocumentOnTypeFormattingParams {
+            text_document_position: TextDocumentPositionParams::new(
+                TextDocumentIdentifier::new(uri.clone()),
+                position,
+            ),
+            ch: "}".into(),
+            options: FormattingOptions::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn formatting_is_disabled_by_default() {
+        let service = new_service();
+        let result = service.inner().initialize(InitializeParams::default()).await.unwrap();
+        let capabilities = result.capabilities;
+        assert!(capabilities.document_formatting_provider.is_none());
+        assert!(capabilities.document_range_formatting_provider.is_none());
+        assert!(capabilities.document_on_type_formatting_provider.is_none());
+
+        let (service, uri) = start(json!({ "enableFormatting": false })).await;
+        let backend = service.inner();
+        assert_eq!(backend.formatting(formatting_params(&uri)).await.unwrap(), None);
+        let range = Range::new(Position::new(0, 0), Position::new(4, 0));
+        assert_eq!(
+            backend.range_formatting(range_formatting_params(&uri, range)).await.unwrap(),
+            None
+        );
+        let position = Position::new(3, 1);
+        assert_eq!(
+            backend.on_type_formatting(on_type_formatting_params(&uri, position)).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn formatting() {
+        let (service, uri) = start(json!({ "enableFormatting": true })).await;
+        let edits = service.inner().formatting(formatting_params(&uri)).await.unwrap().unwrap();
+        assert_eq!(apply_edits(edits), "const a = 1;\nif (a) {\n  foo(a);\n}\nlet b = 2;\n");
+    }
+
+    #[tokio::test]
+    async fn range_formatting() {
+        let (service, uri) = start(json!({ "enableFormatting": true })).await;
+        let backend = service.inner();
+
+        // The range is expanded to the enclosing statements.
+        let range = Range::new(Position::new(1, 0), Position::new(1, 3));
+        let edits = backend.range_formatting(range_formatting_params(&uri, range)).await.unwrap();
+        assert_eq!(
+            apply_edits(edits.unwrap()),
+            "const  a  =  1;\nif (a) {\n  foo(a);\n}\nlet  b  =  2;\n"
+        );
+
+        // A statement in a block keeps the indentation of its line.
+        let range = Range::new(Position::new(2, 0), Position::new(2, 3));
+        let edits = backend.range_formatting(range_formatting_params(&uri, range)).await.unwrap();
+        assert_eq!(
+            apply_edits(edits.unwrap()),
+            "const  a  =  1;\nif (a) {\nfoo(a);\n}\nlet  b  =  2;\n"
+        );
+
+        let range = Range::new(Position::new(0, 0), Position::new(0, 15));
+        let edits = backend.range_formatting(range_formatting_params(&uri, range)).await.unwrap();
+        assert_eq!(
+            apply_edits(edits.unwrap()),
+            "const a = 1;\nif (a) {\nfoo(  a  );\n}\nlet  b  =  2;\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn on_type_formatting() {
+        let (service, uri) = start(json!({ "enableFormatting": true })).await;
+        let backend = service.inner();
+
+        // `}` typed at the end of the `if` statement
+        let edits =
+            backend.on_type_formatting(on_type_formatting_params(&uri, Position::new(3, 1))).await;
+        assert_eq!(
+            apply_edits(edits.unwrap().unwrap()),
+            "const  a  =  1;\nif (a) {\n  foo(a);\n}\nlet  b  =  2;\n"
+        );
+
+        // `;` typed at the end of the last statement
+        let edits =
+            backend.on_type_formatting(on_type_formatting_params(&uri, Position::new(4, 13))).await;
+        assert_eq!(
+            apply_edits(edits.unwrap().unwrap()),
+            "const  a  =  1;\nif (a) {\nfoo(  a  );\n}\nlet b = 2;\n"
+        );
+    }
+}
@@ -1,5 +1,15 @@
 use std::path::PathBuf;
 
+use serde::Deserialize;
+
+/// The options sent by the client as `initializationOptions`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Options {
+    /// Advertise and serve the document, range and on type formatting providers.
+    pub enable_formatting: bool,
+}
+
 #[derive(Debug, Default)]
 pub struct LintOptions {
     pub paths: Vec<PathBuf>,
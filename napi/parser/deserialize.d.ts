@@ -0,0 +1,10 @@
+/**
+ * Deserialize the `program` buffer of `parseSyncBuffer`.
+ *
+ * Objects are materialized lazily: their properties are read from the buffer when they are
+ * first accessed. `JSON.stringify` gives the same JSON as `parseSync`.
+ *
+ * The properties which were not accessed yet are on the prototype, so they are listed by
+ * `for...in` but not by `Object.keys`.
+ */
+export function deserialize(buffer: Uint8Array): any
@@ -0,0 +1,172 @@
+'use strict'
+
+// Reads the binary AST of `parseSyncBuffer`, see `src/buffer.rs` for the layout.
+
+const VERSION = 1
+
+const NULL = 0
+const FALSE = 1
+const TRUE = 2
+const UINT = 3
+const NUMBER = 4
+const STRING = 5
+const ARRAY = 6
+const OBJECT = 7
+
+const HEADER_LENGTH = 10
+
+const decoder = new TextDecoder()
+
+const READER = Symbol('reader')
+const RECORD = Symbol('record')
+
+class Reader {
+  constructor(bytes) {
+    let offset = bytes.byteOffset
+    const take = (TypedArray, length) => {
+      const byteLength = length * TypedArray.BYTES_PER_ELEMENT
+      // A view of `bytes.buffer` needs an offset aligned to the element size, e.g. 8 bytes for
+      // the numbers, the section is copied otherwise
+      const array =
+        offset % TypedArray.BYTES_PER_ELEMENT === 0
+          ? new TypedArray(bytes.buffer, offset, length)
+          : new TypedArray(bytes.buffer.slice(offset, offset + byteLength))
+      offset += byteLength
+      return array
+    }
+
+    const header = take(Uint32Array, HEADER_LENGTH)
+    if (header[0] !== VERSION) {
+      throw new Error(`Unsupported AST buffer version ${header[0]}`)
+    }
+    const [, rootKind, rootPayload, numberCount, slotCount, stringCount, shapeCount, shapeKeyCount, stringByteCount] =
+      header
+    this.root = [rootKind, rootPayload]
+
+    this.numbers = take(Float64Array, numberCount)
+    this.slots = take(Uint32Array, slotCount * 2)
+    this.stringIndex = take(Uint32Array, stringCount * 2)
+    this.shapeIndex = take(Uint32Array, shapeCount * 2)
+    this.shapeKeys = take(Uint32Array, shapeKeyCount)
+    this.stringBytes = take(Uint8Array, stringByteCount)
+
+    this.strings = new Array(stringCount)
+    this.classes = new Array(shapeCount)
+  }
+
+  value(kind, payload) {
+    switch (kind) {
+      case NULL:
+        return null
+      case FALSE:
+        return false
+      case TRUE:
+        return true
+      case UINT:
+        return payload
+      case NUMBER:
+        return this.numbers[payload]
+      case STRING:
+        return this.string(payload)
+      case ARRAY:
+        return this.array(payload)
+      case OBJECT:
+        return this.object(payload)
+      default:
+        throw new Error(`Invalid AST buffer value kind ${kind}`)
+    }
+  }
+
+  slot(index) {
+    return this.value(this.slots[index * 2], this.slots[index * 2 + 1])
+  }
+
+  string(id) {
+    let string = this.strings[id]
+    if (string === undefined) {
+      const start = this.stringIndex[id * 2]
+      const end = start + this.stringIndex[id * 2 + 1]
+      string = this.strings[id] = decoder.decode(this.stringBytes.subarray(start, end))
+    }
+    return string
+  }
+
+  array(record) {
+    const length = this.slots[record * 2 + 1]
+    const array = new Array(length)
+    for (let i = 0; i < length; i++) {
+      array[i] = this.slot(record + 1 + i)
+    }
+    return array
+  }
+
+  object(record) {
+    const shape = this.slots[record * 2]
+    let NodeClass = this.classes[shape]
+    if (NodeClass === undefined) {
+      NodeClass = this.classes[shape] = this.createClass(shape)
+    }
+    return new NodeClass(this, record)
+  }
+
+  // A class for the objects with the keys of `shape`, reading each value on first access.
+  createClass(shape) {
+    const start = this.shapeIndex[shape * 2]
+    const length = this.shapeIndex[shape * 2 + 1]
+    const keys = []
+    for (let i = 0; i < length; i++) {
+      keys.push(this.string(this.shapeKeys[start + i]))
+    }
+
+    class Node {
+      constructor(reader, record) {
+        Object.defineProperty(this, READER, { value: reader })
+        Object.defineProperty(this, RECORD, { value: record })
+      }
+
+      toJSON() {
+        const object = {}
+        for (const key of keys) {
+          object[key] = this[key]
+        }
+        return object
+      }
+    }
+
+    keys.forEach((key, i) => {
+      const own = (node, value) =>
+        Object.defineProperty(node, key, { value, writable: true, enumerable: true, configurable: true })
+      Object.defineProperty(Node.prototype, key, {
+        get() {
+          const value = this[READER].slot(this[RECORD] + 1 + i)
+          own(this, value)
+          return value
+        },
+        set(value) {
+          own(this, value)
+        },
+        enumerable: true,
+        configurable: true,
+      })
+    })
+
+    return Node
+  }
+}
+
+/**
+ * Deserialize the `program` buffer of `parseSyncBuffer`.
+ *
+ * Objects are materialized lazily: their properties are read from the buffer when they are
+ * first accessed. `JSON.stringify` gives the same JSON as `parseSync`.
+ *
+ * The properties which were not accessed yet are on the prototype, so they are listed by
+ * `for...in` but not by `Object.keys`.
+ */
+function deserialize(buffer) {
+  const bytes = buffer instanceof Uint8Array ? buffer : new Uint8Array(buffer)
+  const reader = new Reader(bytes)
+  return reader.value(reader.root[0], reader.root[1])
+}
+
+module.exports.deserialize = deserialize
\ No newline at end of file
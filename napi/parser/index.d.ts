@@ -11,10 +11,24 @@
 export interface ParserOptions {
   sourceType?: 'script' | 'module' | 'unambiguous' | undefined
   sourceFilename?: string
+  /** Return the tokens consumed by the parser in `ParseResult.tokens`. */
+  tokens?: boolean
+  /**
+   * Serialize `ParseResult.program` as an ESTree (and TS-ESTree) compatible AST
+   * with `range` and `loc` instead of the oxc AST.
+   */
+  astFormat?: 'oxc' | 'estree' | undefined
 }
 export interface ParseResult {
   program: string
   errors: Array<string>
+  /** The tokens as a JSON array, when `ParserOptions.tokens` is set. */
+  tokens?: string
+}
+export interface ParseBufferResult {
+  /** The AST in a binary format, read with `deserialize` from `deserialize.js`. */
+  program: Buffer
+  errors: Array<string>
 }
 /**
  * Parse without returning anything.
@@ -33,6 +47,18 @@ export function parseWithoutReturn(sourceText: string, options?: ParserOptions |
  * * Serde JSON serialization
  */
 export function parseSync(sourceText: string, options?: ParserOptions | undefined | null): ParseResult
+/**
+ * Parse to the binary format of `ParseBufferResult.program` instead of JSON.
+ *
+ * `deserialize` from `deserialize.js` reads it into lazily materialized node objects, without
+ * parsing the whole AST as JSON. `ParserOptions.astFormat` is supported, `tokens` is not.
+ *
+ * # Panics
+ *
+ * * File extension is invalid
+ * * Serialization
+ */
+export function parseSyncBuffer(sourceText: string, options?: ParserOptions | undefined | null): ParseBufferResult
 /**
  * # Panics
  *
@@ -0,0 +1,687 @@
+//! Compact binary serialization of the AST, read lazily by `deserialize.js`.
+//!
+//! The buffer holds the same data model as the JSON serialization, so any [Serialize] value can
+//! be written, including the oxc and ESTree ASTs. Values are 2 x u32 slots `[kind, payload]`:
+//! small unsigned integers are stored inline, other numbers index into a `f64` table,
+//! strings index into a deduplicated string table, and arrays and objects point to records of
+//! slots. An object record starts with its shape, the list of its keys, so that objects with the
+//! same keys share a generated JS class.
+//!
+//! Layout, in little endian:
+//!
+//! | section      | content                                                                    |
+//! | ------------ | -------------------------------------------------------------------------- |
+//! | header       | 10 x u32: version, root kind, root payload, the lengths of the sections, 0 |
+//! | numbers      | f64                                                                        |
+//! | slots        | [kind, payload] u32 pairs                                                  |
+//! | strings      | [byte offset, byte length] u32 pairs into the string bytes                 |
+//! | shapes       | [offset, length] u32 pairs into the shape keys                             |
+//! | shape keys   | u32 string ids                                                             |
+//! | string bytes | UTF-8                                                                      |
+
+use std::{collections::HashMap, fmt};
+
+use serde::{ser, Serialize};
+
+const VERSION: u32 = 1;
+
+const NULL: u32 = 0;
+const FALSE: u32 = 1;
+const TRUE: u32 = 2;
+/// An unsigned integer stored in the payload
+const UINT: u32 = 3;
+const NUMBER: u32 = 4;
+const STRING: u32 = 5;
+/// Payload is the index of a `[0, length]` slot followed by the elements
+const ARRAY: u32 = 6;
+/// Payload is the index of a `[shape, length]` slot followed by the values
+const OBJECT: u32 = 7;
+
+type Slot = [u32; 2];
+
+/// Serialize `value` to the binary format.
+///
+/// # Errors
+///
+/// * `value` can't be serialized, e.g. a map has non string keys
+pub fn to_buffer<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut writer = BufferWriter::default();
+    let root = value.serialize(&mut writer)?;
+    Ok(writer.finish(root))
+}
+
+#[derive(Debug)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+#[derive(Default)]
+struct BufferWriter {
+    numbers: Vec<f64>,
+    slots: Vec<Slot>,
+    string_ids: HashMap<String, u32>,
+    strings: Vec<[u32; 2]>,
+    string_bytes: Vec<u8>,
+    shape_ids: HashMap<Vec<u32>, u32>,
+    shapes: Vec<[u32; 2]>,
+    shape_keys: Vec<u32>,
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl BufferWriter {
+    fn number(&mut self, value: f64) -> Slot {
+        // Same as JSON, which has no representation for them
+        if !value.is_finite() {
+            return [NULL, 0];
+        }
+        // `-0.0` is a number
+        if value.is_sign_positive() && value <= f64::from(u32::MAX) && value.fract() == 0.0 {
+            return [UINT, value as u32];
+        }
+        self.numbers.push(value);
+        [NUMBER, self.numbers.len() as u32 - 1]
+    }
+
+    fn string_id(&mut self, value: &str) -> u32 {
+        if let Some(id) = self.string_ids.get(value) {
+            return *id;
+        }
+        let id = self.strings.len() as u32;
+        self.strings.push([self.string_bytes.len() as u32, value.len() as u32]);
+        self.string_bytes.extend_from_slice(value.as_bytes());
+        self.string_ids.insert(value.to_string(), id);
+        id
+    }
+
+    fn array(&mut self, elements: &[Slot]) -> Slot {
+        let index = self.slots.len() as u32;
+        self.slots.push([0, elements.len() as u32]);
+        self.slots.extend_from_slice(elements);
+        [ARRAY, index]
+    }
+
+    fn object(&mut self, keys: Vec<u32>, values: &[Slot]) -> Slot {
+        let shape = if let Some(shape) = self.shape_ids.get(&keys) {
+            *shape
+        } else {
+            let shape = self.shapes.len() as u32;
+            self.shapes.push([self.shape_keys.len() as u32, keys.len() as u32]);
+            self.shape_keys.extend_from_slice(&keys);
+            self.shape_ids.insert(keys, shape);
+            shape
+        };
+        let index = self.slots.len() as u32;
+        self.slots.push([shape, values.len() as u32]);
+        self.slots.extend_from_slice(values);
+        [OBJECT, index]
+    }
+
+    /// `{ variant: value }`, for the externally tagged enum variants
+    fn variant(&mut self, variant: &str, value: Slot) -> Slot {
+        let key = self.string_id(variant);
+        self.object(vec![key], &[value])
+    }
+
+    fn finish(self, root: Slot) -> Vec<u8> {
+        let header = [
+            VERSION,
+            root[0],
+            root[1],
+            self.numbers.len() as u32,
+            self.slots.len() as u32,
+            self.strings.len() as u32,
+            self.shapes.len() as u32,
+            self.shape_keys.len() as u32,
+            self.string_bytes.len() as u32,
+            0,
+        ];
+        let mut bytes = Vec::with_capacity(
+            header.len() * 4
+                + self.numbers.len() * 8
+                + (self.slots.len() + self.strings.len() + self.shapes.len()) * 8
+                + self.shape_keys.len() * 4
+                + self.string_bytes.len(),
+        );
+        for word in header {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        // Right after the 40 bytes header to be aligned for a `Float64Array`
+        for number in &self.numbers {
+            bytes.extend_from_slice(&number.to_le_bytes());
+        }
+        let words = self
+            .slots
+            .iter()
+            .flatten()
+            .chain(self.strings.iter().flatten())
+            .chain(self.shapes.iter().flatten())
+            .chain(self.shape_keys.iter());
+        for word in words {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.string_bytes);
+        bytes
+    }
+}
+
+impl<'w> ser::Serializer for &'w mut BufferWriter {
+    type Ok = Slot;
+    type Error = Error;
+    type SerializeSeq = SeqWriter<'w>;
+    type SerializeTuple = SeqWriter<'w>;
+    type SerializeTupleStruct = SeqWriter<'w>;
+    type SerializeTupleVariant = SeqWriter<'w>;
+    type SerializeMap = MapWriter<'w>;
+    type SerializeStruct = MapWriter<'w>;
+    type SerializeStructVariant = MapWriter<'w>;
+
+    fn serialize_bool(self, v: bool) -> Result<Slot, Error> {
+        Ok([if v { TRUE } else { FALSE }, 0])
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Slot, Error> {
+        Ok(self.number(f64::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Slot, Error> {
+        Ok(self.number(f64::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Slot, Error> {
+        Ok(self.number(f64::from(v)))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn serialize_i64(self, v: i64) -> Result<Slot, Error> {
+        Ok(self.number(v as f64))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Slot, Error> {
+        Ok([UINT, u32::from(v)])
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Slot, Error> {
+        Ok([UINT, u32::from(v)])
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Slot, Error> {
+        Ok([UINT, v])
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn serialize_u64(self, v: u64) -> Result<Slot, Error> {
+        Ok(self.number(v as f64))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Slot, Error> {
+        Ok(self.number(f64::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Slot, Error> {
+        Ok(self.number(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Slot, Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Slot, Error> {
+        Ok([STRING, self.string_id(v)])
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Slot, Error> {
+        let elements = v.iter().map(|byte| [UINT, u32::from(*byte)]).collect::<Vec<_>>();
+        Ok(self.array(&elements))
+    }
+
+    fn serialize_none(self) -> Result<Slot, Error> {
+        Ok([NULL, 0])
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Slot, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Slot, Error> {
+        Ok([NULL, 0])
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Slot, Error> {
+        Ok([NULL, 0])
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Slot, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Slot, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Slot, Error> {
+        let value = value.serialize(&mut *self)?;
+        Ok(self.variant(variant, value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqWriter<'w>, Error> {
+        Ok(SeqWriter {
+            writer: self,
+            elements: Vec::with_capacity(len.unwrap_or_default()),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqWriter<'w>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqWriter<'w>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqWriter<'w>, Error> {
+        Ok(SeqWriter { writer: self, elements: Vec::with_capacity(len), variant: Some(variant) })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapWriter<'w>, Error> {
+        let len = len.unwrap_or_default();
+        Ok(MapWriter {
+            writer: self,
+            keys: Vec::with_capacity(len),
+            values: Vec::with_capacity(len),
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapWriter<'w>, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<MapWriter<'w>, Error> {
+        let mut map = self.serialize_map(Some(len))?;
+        map.variant = Some(variant);
+        Ok(map)
+    }
+}
+
+pub struct SeqWriter<'w> {
+    writer: &'w mut BufferWriter,
+    elements: Vec<Slot>,
+    variant: Option<&'static str>,
+}
+
+impl<'w> SeqWriter<'w> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let element = value.serialize(&mut *self.writer)?;
+        self.elements.push(element);
+        Ok(())
+    }
+
+    fn finish(self) -> Slot {
+        let array = self.writer.array(&self.elements);
+        match self.variant {
+            Some(variant) => self.writer.variant(variant, array),
+            None => array,
+        }
+    }
+}
+
+impl<'w> ser::SerializeSeq for SeqWriter<'w> {
+    type Ok = Slot;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Slot, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl<'w> ser::SerializeTuple for SeqWriter<'w> {
+    type Ok = Slot;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Slot, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl<'w> ser::SerializeTupleStruct for SeqWriter<'w> {
+    type Ok = Slot;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Slot, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl<'w> ser::SerializeTupleVariant for SeqWriter<'w> {
+    type Ok = Slot;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Slot, Error> {
+        Ok(self.finish())
+    }
+}
+
+pub struct MapWriter<'w> {
+    writer: &'w mut BufferWriter,
+    keys: Vec<u32>,
+    values: Vec<Slot>,
+    variant: Option<&'static str>,
+}
+
+impl<'w> MapWriter<'w> {
+    fn push<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), Error> {
+        let key = self.writer.string_id(key);
+        self.keys.push(key);
+        let value = value.serialize(&mut *self.writer)?;
+        self.values.push(value);
+        Ok(())
+    }
+
+    fn finish(self) -> Slot {
+        let object = self.writer.object(self.keys, &self.values);
+        match self.variant {
+            Some(variant) => self.writer.variant(variant, object),
+            None => object,
+        }
+    }
+}
+
+impl<'w> ser::SerializeMap for MapWriter<'w> {
+    type Ok = Slot;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        match key.serialize(&mut *self.writer)? {
+            [STRING, id] => {
+                self.keys.push(id);
+                Ok(())
+            }
+            _ => Err(Error("key must be a string".to_string())),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let value = value.serialize(&mut *self.writer)?;
+        self.values.push(value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Slot, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl<'w> ser::SerializeStruct for MapWriter<'w> {
+    type Ok = Slot;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Slot, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl<'w> ser::SerializeStructVariant for MapWriter<'w> {
+    type Ok = Slot;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Slot, Error> {
+        Ok(self.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+    use serde::Serialize;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// Reads the buffer of `value` back as JSON, like `deserialize.js`.
+    fn read(value: &(impl Serialize + ?Sized)) -> Value {
+        let bytes = to_buffer(value).unwrap();
+        let mut rest = bytes.as_slice();
+        let mut take = |len: usize| {
+            let (section, tail) = rest.split_at(len);
+            rest = tail;
+            section
+        };
+        let words = |section: &[u8]| -> Vec<u32> {
+            section.chunks(4).map(|word| u32::from_le_bytes(word.try_into().unwrap())).collect()
+        };
+        let counts = words(take(40));
+        assert_eq!(counts[0], VERSION);
+        let len = |i: usize| counts[i] as usize;
+        let numbers = take(len(3) * 8)
+            .chunks(8)
+            .map(|number| f64::from_le_bytes(number.try_into().unwrap()))
+            .collect();
+        let reader = Reader {
+            numbers,
+            slots: words(take(len(4) * 8)),
+            strings: words(take(len(5) * 8)),
+            shapes: words(take(len(6) * 8)),
+            shape_keys: words(take(len(7) * 4)),
+            string_bytes: take(len(8)),
+        };
+        assert!(rest.is_empty());
+        reader.value(counts[1], counts[2])
+    }
+
+    struct Reader<'b> {
+        numbers: Vec<f64>,
+        slots: Vec<u32>,
+        strings: Vec<u32>,
+        shapes: Vec<u32>,
+        shape_keys: Vec<u32>,
+        string_bytes: &'b [u8],
+    }
+
+    impl<'b> Reader<'b> {
+        fn value(&self, kind: u32, payload: u32) -> Value {
+            if kind == UINT {
+                return Value::from(f64::from(payload));
+            }
+            let payload = payload as usize;
+            match kind {
+                NULL => Value::Null,
+                FALSE => Value::Bool(false),
+                TRUE => Value::Bool(true),
+                NUMBER => Value::from(self.numbers[payload]),
+                STRING => Value::from(self.string(payload)),
+                ARRAY => (0..self.slots[payload * 2 + 1] as usize)
+                    .map(|i| self.slot(payload + 1 + i))
+                    .collect(),
+                OBJECT => {
+                    let shape = self.slots[payload * 2] as usize;
+                    let start = self.shapes[shape * 2] as usize;
+                    let keys = &self.shape_keys[start..start + self.shapes[shape * 2 + 1] as usize];
+                    let values = (0..keys.len()).map(|i| self.slot(payload + 1 + i));
+                    Value::Object(
+                        keys.iter()
+                            .map(|key| self.string(*key as usize).to_string())
+                            .zip(values)
+                            .collect(),
+                    )
+                }
+                _ => panic!("invalid kind {kind}"),
+            }
+        }
+
+        fn slot(&self, index: usize) -> Value {
+            self.value(self.slots[index * 2], self.slots[index * 2 + 1])
+        }
+
+        fn string(&self, id: usize) -> &'b str {
+            let start = self.strings[id * 2] as usize;
+            let end = start + self.strings[id * 2 + 1] as usize;
+            std::str::from_utf8(&self.string_bytes[start..end]).unwrap()
+        }
+    }
+
+    /// The JSON serialization of `value`, with all numbers as `f64` as in JS
+    fn json(value: &(impl Serialize + ?Sized)) -> Value {
+        fn numbers_as_f64(value: Value) -> Value {
+            match value {
+                Value::Number(number) => Value::from(number.as_f64().unwrap()),
+                Value::Array(array) => array.into_iter().map(numbers_as_f64).collect(),
+                Value::Object(object) => Value::Object(
+                    object.into_iter().map(|(key, value)| (key, numbers_as_f64(value))).collect(),
+                ),
+                value => value,
+            }
+        }
+        numbers_as_f64(serde_json::to_value(value).unwrap())
+    }
+
+    #[test]
+    fn values() {
+        let value = json!({
+            "numbers": [0, 1.5, -0.0, -1, u32::MAX, u64::from(u32::MAX) + 1, 1e300],
+            "strings": ["", "a", "é😀", "a"],
+            "nested": [[], {}, [{ "a": null, "b": true }, false]],
+        });
+        assert_eq!(read(&value), json(&value));
+        assert_eq!(read(&(f64::INFINITY, f64::NAN, 'c', ())), json!([null, null, "c", null]));
+        assert_eq!(read(&[0u8, 255][..]), json!([0.0, 255.0]));
+    }
+
+    #[test]
+    fn enums() {
+        #[derive(Serialize)]
+        enum Enum {
+            Unit,
+            Newtype(u32),
+            Tuple(u32, &'static str),
+            Struct { a: Option<u32> },
+        }
+        #[derive(Serialize)]
+        #[serde(tag = "type")]
+        enum Tagged {
+            A { b: u32 },
+        }
+
+        let value = (Enum::Unit, Enum::Newtype(1), Enum::Tuple(2, "c"), Enum::Struct { a: None });
+        assert_eq!(read(&value), json(&value));
+        assert_eq!(read(&Tagged::A { b: 1 }), json(&Tagged::A { b: 1 }));
+    }
+
+    #[test]
+    fn deduplication() {
+        let bytes = to_buffer(&json!([{ "a": "x" }, { "a": "x" }, { "a": "y" }])).unwrap();
+        let header = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        // "a", "x" and "y"
+        assert_eq!(header(5), 3);
+        // `{ a }`
+        assert_eq!(header(6), 1);
+    }
+
+    #[test]
+    fn non_string_keys() {
+        let error = to_buffer(&BTreeMap::from([(1, 2)])).unwrap_err();
+        assert_eq!(error.to_string(), "key must be a string");
+    }
+
+    #[test]
+    fn programs() {
+        let sources = [
+            (
+                "let a = { b: [1.5, -0, 2e400, 'c\u{1F600}', `d${e}`, /f/g, 10n] }; g?.(h) ?? i",
+                "a.js",
+            ),
+            ("async function* a(b = 1, ...c) { for await (const d of c) yield d }", "a.mjs"),
+            ("<A b='c' {...d}>{e}<></></A>", "a.jsx"),
+            ("class A<T> extends B implements C { #d?: T = 1; static { e } }", "a.ts"),
+            ("enum A { B = 1 } declare module 'c' { export type D = keyof E | F[] }", "a.ts"),
+        ];
+        for (source_text, path) in sources {
+            let allocator = Allocator::default();
+            let source_type = SourceType::from_path(path).unwrap();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source_text}");
+            assert_eq!(read(&ret.program), json(&ret.program), "{source_text}");
+            let estree = ret.program.to_estree(source_text);
+            assert_eq!(read(&estree), json(&estree), "{source_text}");
+        }
+    }
+}
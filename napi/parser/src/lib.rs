@@ -1,8 +1,11 @@
 #![allow(clippy::trailing_empty_array)]
 
+mod buffer;
+
 use std::sync::Arc;
 
-use miette::NamedSource;
+use miette::{Error, NamedSource};
+use napi::bindgen_prelude::Buffer;
 use napi_derive::napi;
 use oxc_allocator::Allocator;
 pub use oxc_ast::ast::Program;
@@ -18,12 +21,27 @@ pub struct ParserOptions {
     #[napi(ts_type = "'script' | 'module' | 'unambiguous' | undefined")]
     pub source_type: Option<String>,
     pub source_filename: Option<String>,
+    /// Return the tokens consumed by the parser in `ParseResult.tokens`.
+    pub tokens: Option<bool>,
+    /// Serialize `ParseResult.program` as an ESTree (and TS-ESTree) compatible AST
+    /// with `range` and `loc` instead of the oxc AST.
+    #[napi(ts_type = "'oxc' | 'estree' | undefined")]
+    pub ast_format: Option<String>,
 }
 
 #[napi(object)]
 pub struct ParseResult {
     pub program: String,
     pub errors: Vec<String>,
+    /// The tokens as a JSON array, when `ParserOptions.tokens` is set.
+    pub tokens: Option<String>,
+}
+
+#[napi(object)]
+pub struct ParseBufferResult {
+    /// The AST in a binary format, read with `deserialize` from `deserialize.js`.
+    pub program: Buffer,
+    pub errors: Vec<String>,
 }
 
 fn parse<'a>(
@@ -39,9 +57,12 @@ fn parse<'a>(
     let source_type = match options.source_type.as_deref() {
         Some("script") => source_type.with_script(true),
         Some("module") => source_type.with_module(true),
+        Some("unambiguous") => source_type.with_unambiguous(true),
         _ => source_type,
     };
-    Parser::new(allocator, source_text, source_type).parse()
+    Parser::new(allocator, source_text, source_type)
+        .collect_tokens(options.tokens.unwrap_or_default())
+        .parse()
 }
 
 /// Parse without returning anything.
@@ -70,21 +91,56 @@ pub fn parse_sync(source_text: String, options: Option<ParserOptions>) -> ParseR
 
     let allocator = Allocator::default();
     let ret = parse(&allocator, &source_text, &options);
-    let program = serde_json::to_string(&ret.program).unwrap();
+    let program = if options.ast_format.as_deref() == Some("estree") {
+        serde_json::to_string(&ret.program.to_estree(&source_text)).unwrap()
+    } else {
+        serde_json::to_string(&ret.program).unwrap()
+    };
+    let tokens =
+        options.tokens.unwrap_or_default().then(|| serde_json::to_string(&ret.tokens).unwrap());
+
+    let errors = format_errors(ret.errors, &source_text, options.source_filename);
+
+    ParseResult { program, errors, tokens }
+}
 
-    let errors = if ret.errors.is_empty() {
-        vec![]
+/// Parse to the binary format of `ParseBufferResult.program` instead of JSON.
+///
+/// `deserialize` from `deserialize.js` reads it into lazily materialized node objects, without
+/// parsing the whole AST as JSON. `ParserOptions.astFormat` is supported, `tokens` is not.
+///
+/// # Panics
+///
+/// * File extension is invalid
+/// * Serialization
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn parse_sync_buffer(source_text: String, options: Option<ParserOptions>) -> ParseBufferResult {
+    let options = options.unwrap_or_default();
+
+    let allocator = Allocator::default();
+    let ret = parse(&allocator, &source_text, &options);
+    let program = if options.ast_format.as_deref() == Some("estree") {
+        buffer::to_buffer(&ret.program.to_estree(&source_text))
     } else {
-        let file_name = options.source_filename.unwrap_or_default();
-        let source = Arc::new(NamedSource::new(file_name, source_text.to_string()));
-        ret.errors
-            .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
-            .map(|error| format!("{error:?}"))
-            .collect()
+        buffer::to_buffer(&ret.program)
     };
+    let program = program.unwrap().into();
+    let errors = format_errors(ret.errors, &source_text, options.source_filename);
+
+    ParseBufferResult { program, errors }
+}
 
-    ParseResult { program, errors }
+fn format_errors(errors: Vec<Error>, source_text: &str, file_name: Option<String>) -> Vec<String> {
+    if errors.is_empty() {
+        return vec![];
+    }
+    let source = Arc::new(NamedSource::new(file_name.unwrap_or_default(), source_text.to_string()));
+    errors
+        .into_iter()
+        .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+        .map(|error| format!("{error:?}"))
+        .collect()
 }
 
 /// # Panics
@@ -1,4 +1,5 @@
 import oxc from './index.js';
+import { deserialize } from './deserialize.js';
 import assert from 'assert';
 
 console.log(`Testing on ${process.platform}-${process.arch}`)
@@ -12,6 +13,45 @@ function test(ret) {
 
 test(oxc.parseSync("foo"));
 
+const { tokens } = oxc.parseSync("foo(/a/g)", { tokens: true });
+assert.deepEqual(JSON.parse(tokens).map((token) => token.kind), ["Ident", "LParen", "RegExp", "RParen"]);
+
+const sourceType = (source) =>
+  JSON.parse(oxc.parseSync(source, { sourceType: "unambiguous" }).program).sourceType.moduleKind;
+assert.equal(sourceType("export default 1"), "module");
+assert.equal(sourceType("var await = 1"), "script");
+
+const estree = JSON.parse(oxc.parseSync("a = 'b'", { astFormat: "estree" }).program);
+const { left, right } = estree.body[0].expression;
+assert.equal(left.type, "Identifier");
+assert.deepEqual([right.type, right.raw, right.range], ["Literal", "'b'", [4, 7]]);
+assert.deepEqual(right.loc, { start: { line: 1, column: 4 }, end: { line: 1, column: 7 } });
+
+// The binary AST is the same as the JSON one
+for (const [source, options] of [
+  ["let a = { b: [1.5, -0, 2e400, 'c\u{1F600}', `d${e}`, /f/g, 10n, null] }; g?.(h) ?? i", {}],
+  ["class A<T> extends B implements C { #d?: T = 1; static { e } }", { sourceFilename: "a.ts" }],
+  ["a = 'b'", { astFormat: "estree" }],
+  ["<A b='c' {...d}>{e}<></></A>", { sourceFilename: "a.jsx" }],
+  ["let a: string = b as any; enum C { D = 1 }", { sourceFilename: "a.ts", astFormat: "estree" }],
+]) {
+  const { program, errors } = oxc.parseSyncBuffer(source, options);
+  assert.equal(errors.length, 0);
+  assert.equal(JSON.stringify(deserialize(program)), JSON.stringify(JSON.parse(oxc.parseSync(source, options).program)));
+}
+// The sections are copied when the buffer is not aligned for the typed arrays
+for (const shift of [1, 4]) {
+  const { program } = oxc.parseSyncBuffer("a = [1.5, 'b']");
+  const unaligned = new Uint8Array(program.length + shift).subarray(shift);
+  unaligned.set(program);
+  assert.deepEqual(deserialize(unaligned).body[0].expression.right.elements.map((e) => e.value), [1.5, "b"]);
+}
+const lazy = deserialize(oxc.parseSyncBuffer("foo(bar)").program);
+assert.equal(lazy.body[0].expression.arguments[0].name, "bar");
+const keys = [];
+for (const key in lazy.body[0].expression.callee) keys.push(key);
+assert.deepEqual(keys, ["type", "start", "end", "name"]);
+
 async function main() {
   test(await oxc.parseAsync("foo"));
 }
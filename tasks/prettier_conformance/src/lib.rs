@@ -37,22 +37,31 @@ fn root() -> PathBuf {
 }
 
 fn fixtures_root() -> PathBuf {
-    project_root().join(root()).join("prettier/tests/format/js")
+    project_root().join(root()).join("prettier/tests/format")
 }
 
+/// The test suites, named after their snapshot file, and the fixture directories they cover.
+const SUITES: &[(&str, &[&str])] = &[("js", &["js", "jsx"]), ("ts", &["typescript"])];
+
 impl TestRunner {
     pub fn new(options: TestRunnerOptions) -> Self {
         Self { options, spec: SpecParser::default() }
     }
 
+    pub fn run(mut self) {
+        for (name, fixture_dirs) in SUITES {
+            self.run_suite(name, fixture_dirs);
+        }
+    }
+
     /// # Panics
     #[allow(clippy::cast_precision_loss)]
-    pub fn run(mut self) {
+    fn run_suite(&mut self, name: &str, fixture_dirs: &[&str]) {
         let fixture_root = fixtures_root();
         // Read the first level of directories that contain `__snapshots__`
-        let mut dirs = WalkDir::new(&fixture_root)
-            .min_depth(1)
-            .into_iter()
+        let mut dirs = fixture_dirs
+            .iter()
+            .flat_map(|dir| WalkDir::new(fixture_root.join(dir)).min_depth(1))
             .filter_map(Result::ok)
             .filter(|e| {
                 self.options
@@ -124,12 +133,12 @@ impl TestRunner {
         let passed = total - failed.len();
         let percentage = (passed as f64 / total as f64) * 100.0;
         let heading = format!("Compatibility: {passed}/{total} ({percentage:.2}%)");
-        println!("{heading}");
+        println!("{name} {heading}");
 
         if self.options.filter.is_none() {
             let failed = failed.join("\n");
             let snapshot = format!("{heading}\n\n# Failed\n\n{failed}");
-            fs::write(root().join("prettier.snap.md"), snapshot).unwrap();
+            fs::write(root().join(format!("prettier.{name}.snap.md")), snapshot).unwrap();
         }
     }
 
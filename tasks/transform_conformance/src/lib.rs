@@ -66,9 +66,9 @@ const CASES: &[&str] = &[
     // ES2018
     "babel-plugin-transform-async-generator-functions",
     "babel-plugin-transform-object-rest-spread",
-    // [Regex] "babel-plugin-transform-unicode-property-regex",
+    "babel-plugin-transform-unicode-property-regex",
     "babel-plugin-transform-dotall-regex",
-    // [Regex] "babel-plugin-transform-named-capturing-groups-regex",
+    "babel-plugin-transform-named-capturing-groups-regex",
     // ES2017
     "babel-plugin-transform-async-to-generator",
     // ES2016
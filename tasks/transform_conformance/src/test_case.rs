@@ -11,6 +11,7 @@ use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use oxc_tasks_common::{normalize_path, BabelOptions};
 use oxc_transformer::{
+    DecoratorsOptions, HelperLoaderMode, HelperLoaderOptions, ModuleFormat, ModulesOptions,
     NullishCoalescingOperatorOptions, ReactJsxOptions, TransformOptions, TransformTarget,
     Transformer,
 };
@@ -88,26 +89,88 @@ pub trait TestCase {
         let options = self.options();
         TransformOptions {
             target: TransformTarget::ESNext,
+            targets: None,
             react_jsx: options
                 .get_plugin("transform-react-jsx")
-                .map(get_options::<ReactJsxOptions>),
+                .map(get_options::<ReactJsxOptions>)
+                .or_else(|| {
+                    let options = options.get_plugin("transform-react-jsx-development")?;
+                    Some(ReactJsxOptions { development: true, ..get_options(options) })
+                }),
+            react_refresh: None,
+            filename: Some(self.path().to_string_lossy().to_string()),
+            decorators: options
+                .get_plugin("proposal-decorators")
+                .map(get_options::<DecoratorsOptions>),
+            modules: [
+                ("transform-modules-commonjs", ModuleFormat::CommonJs),
+                ("transform-modules-amd", ModuleFormat::Amd),
+                ("transform-modules-umd", ModuleFormat::Umd),
+            ]
+            .into_iter()
+            .find_map(|(plugin, format)| {
+                let options = get_options::<ModulesOptions>(options.get_plugin(plugin)?);
+                Some(ModulesOptions { format, ..options })
+            }),
+            helper_loader: HelperLoaderOptions {
+                mode: if options.get_plugin("transform-runtime").is_some() {
+                    HelperLoaderMode::Runtime
+                } else if options.get_plugin("external-helpers").is_some() {
+                    HelperLoaderMode::External
+                } else {
+                    HelperLoaderMode::Inline
+                },
+                ..HelperLoaderOptions::default()
+            },
             assumptions: options.assumptions,
+            unicode_sets_regex: options.get_plugin("transform-unicode-sets-regex").is_some(),
             class_static_block: options.get_plugin("transform-class-static-block").is_some(),
+            class_properties: [
+                "transform-class-properties",
+                "transform-private-methods",
+                "transform-private-property-in-object",
+            ]
+            .iter()
+            .any(|plugin| options.get_plugin(plugin).is_some()),
+            async_generator_functions: options
+                .get_plugin("transform-async-generator-functions")
+                .is_some(),
+            dotall_regex: options.get_plugin("transform-dotall-regex").is_some(),
+            named_capturing_groups_regex: options
+                .get_plugin("transform-named-capturing-groups-regex")
+                .is_some(),
+            object_rest_spread: options.get_plugin("transform-object-rest-spread").is_some(),
+            unicode_property_regex: options
+                .get_plugin("transform-unicode-property-regex")
+                .is_some(),
             logical_assignment_operators: options
                 .get_plugin("transform-logical-assignment-operators")
                 .is_some(),
             nullish_coalescing_operator: options
                 .get_plugin("transform-nullish-coalescing-operator")
                 .map(get_options::<NullishCoalescingOperatorOptions>),
+            optional_chaining: options.get_plugin("transform-optional-chaining").is_some(),
             optional_catch_binding: options
                 .get_plugin("transform-optional-catch-binding")
                 .is_some(),
             exponentiation_operator: options
                 .get_plugin("transform-exponentiation-operator")
                 .is_some(),
+            async_to_generator: options.get_plugin("transform-async-to-generator").is_some(),
+            arrow_functions: options.get_plugin("transform-arrow-functions").is_some(),
+            block_scoping: options.get_plugin("transform-block-scoping").is_some(),
+            classes: options.get_plugin("transform-classes").is_some(),
+            computed_properties: options.get_plugin("transform-computed-properties").is_some(),
+            destructuring: options.get_plugin("transform-destructuring").is_some(),
+            for_of: options.get_plugin("transform-for-of").is_some(),
+            new_target: options.get_plugin("transform-new-target").is_some(),
+            parameters: options.get_plugin("transform-parameters").is_some(),
+            regenerator: options.get_plugin("transform-regenerator").is_some(),
             shorthand_properties: options.get_plugin("transform-shorthand-properties").is_some(),
+            spread: options.get_plugin("transform-spread").is_some(),
             sticky_regex: options.get_plugin("transform-sticky-regex").is_some(),
             template_literals: options.get_plugin("transform-template-literals").is_some(),
+            unicode_regex: options.get_plugin("transform-unicode-regex").is_some(),
         }
     }
 